@@ -0,0 +1,181 @@
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, OnceLock,
+    },
+    time::Instant,
+};
+
+use anyhow::anyhow;
+use napi::{
+    threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+    Status,
+};
+use tokio::sync::broadcast;
+use turbo_tasks::ReadRef;
+use turbopack_core::{diagnostics::PlainDiagnostic, issue::PlainIssue};
+
+use super::utils::{NapiDiagnostic, NapiIssue};
+
+/// The number of events buffered for each subscriber. Subscribers which fall
+/// further behind miss events, which is fine for progress reporting since
+/// every event contains the current counts.
+const EVENT_CAPACITY: usize = 1024;
+
+/// An event of a build, emitted to the subscribers of
+/// `project_build_events_subscribe`.
+#[derive(Clone)]
+pub enum BuildEvent {
+    /// An endpoint started or finished writing.
+    Progress { written: u32, pending: u32 },
+    /// An endpoint was written with issues or diagnostics.
+    Diagnostics {
+        issues: Arc<Vec<ReadRef<PlainIssue>>>,
+        diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    },
+    /// The project was shut down after writing `written` endpoints.
+    Completion { written: u32, duration_ms: u32 },
+}
+
+/// Tracks the endpoints of a project being written, and broadcasts the
+/// progress to subscribers, so that the progress of builds can be rendered
+/// without polling.
+pub struct BuildProgress {
+    sender: broadcast::Sender<BuildEvent>,
+    written: AtomicU32,
+    pending: AtomicU32,
+    start: OnceLock<Instant>,
+}
+
+impl BuildProgress {
+    pub fn new() -> Arc<Self> {
+        Arc::new(BuildProgress {
+            sender: broadcast::channel(EVENT_CAPACITY).0,
+            written: AtomicU32::new(0),
+            pending: AtomicU32::new(0),
+            start: OnceLock::new(),
+        })
+    }
+
+    fn emit(&self, event: BuildEvent) {
+        // Sending only fails when there are no subscribers.
+        let _ = self.sender.send(event);
+    }
+
+    fn emit_progress(&self) {
+        self.emit(BuildEvent::Progress {
+            written: self.written.load(Ordering::SeqCst),
+            pending: self.pending.load(Ordering::SeqCst),
+        });
+    }
+
+    pub fn endpoint_started(&self) {
+        self.start.get_or_init(Instant::now);
+        self.pending.fetch_add(1, Ordering::SeqCst);
+        self.emit_progress();
+    }
+
+    pub fn endpoint_finished(
+        &self,
+        issues: &Arc<Vec<ReadRef<PlainIssue>>>,
+        diagnostics: &Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    ) {
+        if !issues.is_empty() || !diagnostics.is_empty() {
+            self.emit(BuildEvent::Diagnostics {
+                issues: issues.clone(),
+                diagnostics: diagnostics.clone(),
+            });
+        }
+        self.pending.fetch_sub(1, Ordering::SeqCst);
+        self.written.fetch_add(1, Ordering::SeqCst);
+        self.emit_progress();
+    }
+
+    /// Emits a [BuildEvent::Completion] if any endpoint was written.
+    pub fn complete(&self) {
+        if let Some(start) = self.start.get() {
+            self.emit(BuildEvent::Completion {
+                written: self.written.load(Ordering::SeqCst),
+                duration_ms: start.elapsed().as_millis() as u32,
+            });
+        }
+    }
+
+    /// Calls `func` with every event until the project is dropped or `func`
+    /// can't be called anymore.
+    pub fn subscribe(&self, func: ThreadsafeFunction<BuildEvent>) {
+        let mut receiver = self.sender.subscribe();
+        tokio::spawn(async move {
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let status = func.call(Ok(event), ThreadsafeFunctionCallMode::NonBlocking);
+                if !matches!(status, Status::Ok) {
+                    let error = anyhow!("Error calling JS function: {}", status);
+                    eprintln!("{}", error);
+                    break;
+                }
+            }
+        });
+    }
+}
+
+#[napi(object)]
+pub struct NapiBuildEvent {
+    /// `"progress"`, `"diagnostics"` or `"completion"`
+    pub event_type: String,
+    /// The number of endpoints written, for progress and completion events
+    pub written: Option<u32>,
+    /// The number of endpoints being written, for progress events
+    pub pending: Option<u32>,
+    pub issues: Option<Vec<NapiIssue>>,
+    pub diagnostics: Option<Vec<NapiDiagnostic>>,
+    /// The time since the first endpoint started writing, for completion
+    /// events
+    pub duration: Option<u32>,
+}
+
+impl From<BuildEvent> for NapiBuildEvent {
+    fn from(event: BuildEvent) -> Self {
+        match event {
+            BuildEvent::Progress { written, pending } => NapiBuildEvent {
+                event_type: "progress".to_string(),
+                written: Some(written),
+                pending: Some(pending),
+                issues: None,
+                diagnostics: None,
+                duration: None,
+            },
+            BuildEvent::Diagnostics {
+                issues,
+                diagnostics,
+            } => NapiBuildEvent {
+                event_type: "diagnostics".to_string(),
+                written: None,
+                pending: None,
+                issues: Some(issues.iter().map(|i| NapiIssue::from(&**i)).collect()),
+                diagnostics: Some(
+                    diagnostics
+                        .iter()
+                        .map(|d| NapiDiagnostic::from(d))
+                        .collect(),
+                ),
+                duration: None,
+            },
+            BuildEvent::Completion {
+                written,
+                duration_ms,
+            } => NapiBuildEvent {
+                event_type: "completion".to_string(),
+                written: Some(written),
+                pending: None,
+                issues: None,
+                diagnostics: None,
+                duration: Some(duration_ms),
+            },
+        }
+    }
+}
@@ -3,6 +3,8 @@ use std::{ops::Deref, sync::Arc};
 use anyhow::Result;
 use napi::{bindgen_prelude::External, JsFunction};
 use next_api::{
+    client_module_sizes::client_module_sizes,
+    module_sizes::ModuleSize,
     paths::ServerPath,
     route::{Endpoint, WrittenEndpoint},
 };
@@ -14,9 +16,12 @@ use turbopack_core::{
     issue::{IssueSeverity, PlainIssue},
 };
 
-use super::utils::{
-    get_diagnostics, get_issues, subscribe, NapiDiagnostic, NapiIssue, RootTask, TurbopackResult,
-    VcArc,
+use super::{
+    build_progress::BuildProgress,
+    utils::{
+        get_diagnostics, get_issues, subscribe, NapiDiagnostic, NapiIssue, RootTask,
+        TurbopackResult, VcArc,
+    },
 };
 
 #[napi(object)]
@@ -86,13 +91,25 @@ impl From<Option<WrittenEndpoint>> for NapiWrittenEndpoint {
 //    some async functions (in this case `endpoint_write_to_disk`) can cause
 //    higher-ranked lifetime errors. See https://github.com/rust-lang/rust/issues/102211
 // 2. the type_complexity clippy lint.
-pub struct ExternalEndpoint(pub VcArc<Vc<Box<dyn Endpoint>>>);
+pub struct ExternalEndpoint {
+    endpoint: VcArc<Vc<Box<dyn Endpoint>>>,
+    build_progress: Arc<BuildProgress>,
+}
+
+impl ExternalEndpoint {
+    pub fn new(endpoint: VcArc<Vc<Box<dyn Endpoint>>>, build_progress: Arc<BuildProgress>) -> Self {
+        ExternalEndpoint {
+            endpoint,
+            build_progress,
+        }
+    }
+}
 
 impl Deref for ExternalEndpoint {
     type Target = VcArc<Vc<Box<dyn Endpoint>>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.endpoint
     }
 }
 
@@ -150,8 +167,10 @@ pub async fn endpoint_write_to_disk(
     #[napi(ts_arg_type = "{ __napiType: \"Endpoint\" }")] endpoint: External<ExternalEndpoint>,
 ) -> napi::Result<TurbopackResult<NapiWrittenEndpoint>> {
     let turbo_tasks = endpoint.turbo_tasks().clone();
+    let build_progress = endpoint.build_progress.clone();
     let endpoint = ***endpoint;
-    let (written, issues, diags) = turbo_tasks
+    build_progress.endpoint_started();
+    let result = turbo_tasks
         .run_once(async move {
             let operation = get_written_endpoint_with_issues(endpoint);
             let WrittenEndpointWithIssues {
@@ -165,7 +184,15 @@ pub async fn endpoint_write_to_disk(
             Ok((written.clone(), issues.clone(), diagnostics.clone()))
         })
         .await
-        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()));
+    let (written, issues, diags) = match result {
+        Ok(result) => result,
+        Err(err) => {
+            build_progress.endpoint_finished(&Default::default(), &Default::default());
+            return Err(err);
+        }
+    };
+    build_progress.endpoint_finished(&issues, &diags);
     Ok(TurbopackResult {
         result: NapiWrittenEndpoint::from(written.map(|v| v.clone_value())),
         issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
@@ -173,6 +200,42 @@ pub async fn endpoint_write_to_disk(
     })
 }
 
+#[napi(object)]
+pub struct NapiClientModuleSize {
+    pub path: String,
+    pub package: Option<String>,
+    pub size: u32,
+}
+
+impl From<ModuleSize> for NapiClientModuleSize {
+    fn from(module: ModuleSize) -> Self {
+        Self {
+            path: module.path.to_string(),
+            package: module.package.map(|package| package.to_string()),
+            size: module.size.try_into().unwrap_or(u32::MAX),
+        }
+    }
+}
+
+/// Returns the modules in the client chunks of the endpoint with their size,
+/// see [client_module_sizes].
+#[napi]
+#[tracing::instrument(skip_all)]
+pub async fn endpoint_client_module_sizes(
+    #[napi(ts_arg_type = "{ __napiType: \"Endpoint\" }")] endpoint: External<ExternalEndpoint>,
+) -> napi::Result<Vec<NapiClientModuleSize>> {
+    let turbo_tasks = endpoint.turbo_tasks().clone();
+    let endpoint = ***endpoint;
+    let modules = turbo_tasks
+        .run_once(async move {
+            let modules = client_module_sizes(endpoint).strongly_consistent().await?;
+            Ok(modules.iter().cloned().collect::<Vec<_>>())
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+    Ok(modules.into_iter().map(From::from).collect())
+}
+
 #[napi(ts_return_type = "{ __napiType: \"RootTask\" }")]
 pub fn endpoint_server_changed_subscribe(
     #[napi(ts_arg_type = "{ __napiType: \"Endpoint\" }")] endpoint: External<ExternalEndpoint>,
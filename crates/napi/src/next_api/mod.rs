@@ -1,3 +1,4 @@
+pub mod build_progress;
 pub mod endpoint;
 pub mod project;
 pub mod utils;
@@ -1,22 +1,31 @@
-use std::{path::PathBuf, sync::Arc, thread, time::Duration};
+use std::{future::Future, path::PathBuf, pin::Pin, sync::Arc, thread, time::Duration};
 
 use anyhow::{anyhow, bail, Context, Result};
 use napi::{
-    bindgen_prelude::{within_runtime_if_available, External},
-    threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
-    JsFunction, Status,
+    bindgen_prelude::{within_runtime_if_available, External, Promise},
+    threadsafe_function::{
+        ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction, ThreadsafeFunctionCallMode,
+    },
+    Env, JsFunction, Status,
 };
 use next_api::{
     entrypoints::Entrypoints,
+    import_cost::ImportCosts,
     project::{
         DefineEnv, DraftModeOptions, Instrumentation, Middleware, PartialProjectOptions, Project,
         ProjectContainer, ProjectOptions, WatchOptions,
     },
     route::{Endpoint, Route},
 };
-use next_core::tracing_presets::{
-    TRACING_NEXT_OVERVIEW_TARGETS, TRACING_NEXT_TARGETS, TRACING_NEXT_TURBOPACK_TARGETS,
-    TRACING_NEXT_TURBO_TASKS_TARGETS,
+use next_core::{
+    next_resolve_interceptor::{
+        set_resolve_interceptor_callback, ResolveInterception, ResolveInterceptorCallback,
+    },
+    tracing_presets::{
+        TRACING_NEXT_OVERVIEW_TARGETS, TRACING_NEXT_TARGETS, TRACING_NEXT_TURBOPACK_TARGETS,
+        TRACING_NEXT_TURBO_TASKS_TARGETS,
+    },
+    FontFallbackDiagnostics,
 };
 use once_cell::sync::Lazy;
 use rand::Rng;
@@ -35,6 +44,7 @@ use turbopack_core::{
     diagnostics::PlainDiagnostic,
     error::PrettyPrintError,
     issue::PlainIssue,
+    resolve::trace::enable_resolve_trace,
     source_map::{SourceMap, Token},
     version::{PartialUpdate, TotalUpdate, Update, VersionState},
     SOURCE_MAP_PREFIX,
@@ -49,6 +59,7 @@ use turbopack_trace_utils::{
 use url::Url;
 
 use super::{
+    build_progress::{BuildEvent, BuildProgress, NapiBuildEvent},
     endpoint::ExternalEndpoint,
     utils::{
         create_turbo_tasks, get_diagnostics, get_issues, subscribe, NapiDiagnostic, NapiIssue,
@@ -140,6 +151,10 @@ pub struct NapiProjectOptions {
 
     /// The browserslist query to use for targeting browsers.
     pub browserslist_query: String,
+
+    /// Disable minification and annotate each module in the emitted chunks
+    /// with its path and package version.
+    pub annotate_chunks: Option<bool>,
 }
 
 /// [NapiProjectOptions] with all fields optional.
@@ -235,6 +250,7 @@ impl From<NapiProjectOptions> for ProjectOptions {
             build_id: val.build_id.into(),
             preview_props: val.preview_props.into(),
             browserslist_query: val.browserslist_query.into(),
+            annotate_chunks: val.annotate_chunks.unwrap_or(false),
         }
     }
 }
@@ -287,6 +303,7 @@ pub struct ProjectInstance {
     turbo_tasks: NextTurboTasks,
     container: Vc<ProjectContainer>,
     exit_receiver: tokio::sync::Mutex<Option<ExitReceiver>>,
+    build_progress: Arc<BuildProgress>,
 }
 
 #[napi(ts_return_type = "Promise<{ __napiType: \"Project\" }>")]
@@ -346,6 +363,15 @@ pub async fn project_new(
         subscriber.init();
     }
 
+    let trace_resolution = std::env::var("NEXT_TURBOPACK_TRACE_RESOLUTION").ok();
+    if let Some(glob) = trace_resolution.filter(|v| !v.is_empty()) {
+        let internal_dir = PathBuf::from(&options.project_path).join(&options.dist_dir);
+        std::fs::create_dir_all(&internal_dir)
+            .context("Unable to create .next directory")
+            .and_then(|_| enable_resolve_trace(&glob, &internal_dir.join("trace-resolution.jsonl")))
+            .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+    }
+
     let memory_limit = turbo_engine_options
         .memory_limit
         .map(|m| m as usize)
@@ -399,6 +425,7 @@ pub async fn project_new(
             turbo_tasks,
             container,
             exit_receiver: tokio::sync::Mutex::new(Some(exit_receiver)),
+            build_progress: BuildProgress::new(),
         },
         100,
     ))
@@ -483,6 +510,7 @@ pub async fn project_update(
 pub async fn project_shutdown(
     #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
 ) {
+    project.build_progress.complete();
     project.turbo_tasks.stop_and_wait().await;
 }
 
@@ -517,12 +545,17 @@ struct NapiRoute {
 }
 
 impl NapiRoute {
-    fn from_route(pathname: String, value: Route, turbo_tasks: &NextTurboTasks) -> Self {
+    fn from_route(
+        pathname: String,
+        value: Route,
+        turbo_tasks: &NextTurboTasks,
+        build_progress: &Arc<BuildProgress>,
+    ) -> Self {
         let convert_endpoint = |endpoint: Vc<Box<dyn Endpoint>>| {
-            Some(External::new(ExternalEndpoint(VcArc::new(
-                turbo_tasks.clone(),
-                endpoint,
-            ))))
+            Some(External::new(ExternalEndpoint::new(
+                VcArc::new(turbo_tasks.clone(), endpoint),
+                build_progress.clone(),
+            )))
         };
         match value {
             Route::Page {
@@ -581,12 +614,16 @@ struct NapiMiddleware {
 }
 
 impl NapiMiddleware {
-    fn from_middleware(value: &Middleware, turbo_tasks: &NextTurboTasks) -> Result<Self> {
+    fn from_middleware(
+        value: &Middleware,
+        turbo_tasks: &NextTurboTasks,
+        build_progress: &Arc<BuildProgress>,
+    ) -> Result<Self> {
         Ok(NapiMiddleware {
-            endpoint: External::new(ExternalEndpoint(VcArc::new(
-                turbo_tasks.clone(),
-                value.endpoint,
-            ))),
+            endpoint: External::new(ExternalEndpoint::new(
+                VcArc::new(turbo_tasks.clone(), value.endpoint),
+                build_progress.clone(),
+            )),
         })
     }
 }
@@ -598,16 +635,20 @@ struct NapiInstrumentation {
 }
 
 impl NapiInstrumentation {
-    fn from_instrumentation(value: &Instrumentation, turbo_tasks: &NextTurboTasks) -> Result<Self> {
+    fn from_instrumentation(
+        value: &Instrumentation,
+        turbo_tasks: &NextTurboTasks,
+        build_progress: &Arc<BuildProgress>,
+    ) -> Result<Self> {
         Ok(NapiInstrumentation {
-            node_js: External::new(ExternalEndpoint(VcArc::new(
-                turbo_tasks.clone(),
-                value.node_js,
-            ))),
-            edge: External::new(ExternalEndpoint(VcArc::new(
-                turbo_tasks.clone(),
-                value.edge,
-            ))),
+            node_js: External::new(ExternalEndpoint::new(
+                VcArc::new(turbo_tasks.clone(), value.node_js),
+                build_progress.clone(),
+            )),
+            edge: External::new(ExternalEndpoint::new(
+                VcArc::new(turbo_tasks.clone(), value.edge),
+                build_progress.clone(),
+            )),
         })
     }
 }
@@ -655,6 +696,7 @@ pub fn project_entrypoints_subscribe(
 ) -> napi::Result<External<RootTask>> {
     let turbo_tasks = project.turbo_tasks.clone();
     let container = project.container;
+    let build_progress = project.build_progress.clone();
     subscribe(
         turbo_tasks.clone(),
         func,
@@ -685,31 +727,38 @@ pub fn project_entrypoints_subscribe(
                                 pathname.clone().into(),
                                 route.clone(),
                                 &turbo_tasks,
+                                &build_progress,
                             )
                         })
                         .collect::<Vec<_>>(),
                     middleware: entrypoints
                         .middleware
                         .as_ref()
-                        .map(|m| NapiMiddleware::from_middleware(m, &turbo_tasks))
+                        .map(|m| NapiMiddleware::from_middleware(m, &turbo_tasks, &build_progress))
                         .transpose()?,
                     instrumentation: entrypoints
                         .instrumentation
                         .as_ref()
-                        .map(|m| NapiInstrumentation::from_instrumentation(m, &turbo_tasks))
+                        .map(|m| {
+                            NapiInstrumentation::from_instrumentation(
+                                m,
+                                &turbo_tasks,
+                                &build_progress,
+                            )
+                        })
                         .transpose()?,
-                    pages_document_endpoint: External::new(ExternalEndpoint(VcArc::new(
-                        turbo_tasks.clone(),
-                        *entrypoints.pages_document_endpoint,
-                    ))),
-                    pages_app_endpoint: External::new(ExternalEndpoint(VcArc::new(
-                        turbo_tasks.clone(),
-                        *entrypoints.pages_app_endpoint,
-                    ))),
-                    pages_error_endpoint: External::new(ExternalEndpoint(VcArc::new(
-                        turbo_tasks.clone(),
-                        *entrypoints.pages_error_endpoint,
-                    ))),
+                    pages_document_endpoint: External::new(ExternalEndpoint::new(
+                        VcArc::new(turbo_tasks.clone(), *entrypoints.pages_document_endpoint),
+                        build_progress.clone(),
+                    )),
+                    pages_app_endpoint: External::new(ExternalEndpoint::new(
+                        VcArc::new(turbo_tasks.clone(), *entrypoints.pages_app_endpoint),
+                        build_progress.clone(),
+                    )),
+                    pages_error_endpoint: External::new(ExternalEndpoint::new(
+                        VcArc::new(turbo_tasks.clone(), *entrypoints.pages_error_endpoint),
+                        build_progress.clone(),
+                    )),
                 },
                 issues: issues
                     .iter()
@@ -910,103 +959,1136 @@ pub fn project_hmr_identifiers_subscribe(
     )
 }
 
-enum UpdateMessage {
-    Start,
-    End(UpdateInfo),
+#[turbo_tasks::value(serialization = "none")]
+struct SitemapWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_sitemap_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<SitemapWithIssues>> {
+    let emit_sitemap_operation = container.emit_sitemap();
+    let paths = emit_sitemap_operation.strongly_consistent().await?;
+    let issues = get_issues(emit_sitemap_operation).await?;
+    let diagnostics = get_diagnostics(emit_sitemap_operation).await?;
+    let effects = Arc::new(get_effects(emit_sitemap_operation).await?);
+    Ok(SitemapWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
 }
 
 #[napi(object)]
-struct NapiUpdateMessage {
-    pub update_type: String,
-    pub value: Option<NapiUpdateInfo>,
+struct NapiWrittenSitemap {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
 }
 
-impl From<UpdateMessage> for NapiUpdateMessage {
-    fn from(update_message: UpdateMessage) -> Self {
-        match update_message {
-            UpdateMessage::Start => NapiUpdateMessage {
-                update_type: "start".to_string(),
-                value: None,
-            },
-            UpdateMessage::End(info) => NapiUpdateMessage {
-                update_type: "end".to_string(),
-                value: Some(info.into()),
-            },
-        }
+/// Writes the `sitemap.xml` and `robots.txt` configured through
+/// `experimental.sitemap` to the dist dir, returning their paths relative to
+/// it.
+#[napi]
+pub async fn project_write_sitemap(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenSitemap>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let SitemapWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_sitemap_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenSitemap {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct FontFallbacksWithIssues {
+    fallbacks: ReadRef<FontFallbackDiagnostics>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+}
+
+#[turbo_tasks::function]
+async fn font_fallbacks_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<FontFallbacksWithIssues>> {
+    let font_fallbacks_operation = container.font_fallbacks();
+    let fallbacks = font_fallbacks_operation.strongly_consistent().await?;
+    let issues = get_issues(font_fallbacks_operation).await?;
+    let diagnostics = get_diagnostics(font_fallbacks_operation).await?;
+    Ok(FontFallbacksWithIssues {
+        fallbacks,
+        issues,
+        diagnostics,
     }
+    .cell())
 }
 
 #[napi(object)]
-struct NapiUpdateInfo {
-    pub duration: u32,
-    pub tasks: u32,
+struct NapiFontFallback {
+    /// The scoped family of the web font, e.g. `__Roboto_c123b8`
+    pub font_family: Option<String>,
+    /// e.g. `__Roboto_Fallback_c123b8`
+    pub scoped_font_family: String,
+    /// The locally installed font the fallback renders with, e.g. `Arial`
+    pub local_font_family: String,
+    pub ascent_override: Option<f64>,
+    pub descent_override: Option<f64>,
+    pub line_gap_override: Option<f64>,
+    pub size_adjust: Option<f64>,
 }
 
-impl From<UpdateInfo> for NapiUpdateInfo {
-    fn from(update_info: UpdateInfo) -> Self {
-        Self {
-            duration: update_info.duration.as_millis() as u32,
-            tasks: update_info.tasks as u32,
-        }
+#[napi(object)]
+struct NapiFontFallbacks {
+    pub fallbacks: Vec<NapiFontFallback>,
+}
+
+/// Reports the fallback fonts generated by `next/font` across the app with
+/// their computed metric adjustments, e.g. to debug layout shift.
+#[napi]
+pub async fn project_font_fallbacks(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiFontFallbacks>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (fallbacks, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let FontFallbacksWithIssues {
+                fallbacks,
+                issues,
+                diagnostics,
+            } = &*font_fallbacks_with_issues(container)
+                .strongly_consistent()
+                .await?;
+
+            Ok((fallbacks.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiFontFallbacks {
+            fallbacks: fallbacks
+                .iter()
+                .map(|fallback| NapiFontFallback {
+                    font_family: fallback.font_family.as_ref().map(|f| f.to_string()),
+                    scoped_font_family: fallback.scoped_font_family.to_string(),
+                    local_font_family: fallback.local_font_family.to_string(),
+                    ascent_override: fallback.ascent_override,
+                    descent_override: fallback.descent_override,
+                    line_gap_override: fallback.line_gap_override,
+                    size_adjust: fallback.size_adjust,
+                })
+                .collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct FeatureUsageReportWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_feature_usage_report_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<FeatureUsageReportWithIssues>> {
+    let emit_operation = container.emit_feature_usage_report();
+    let paths = emit_operation.strongly_consistent().await?;
+    let issues = get_issues(emit_operation).await?;
+    let diagnostics = get_diagnostics(emit_operation).await?;
+    let effects = Arc::new(get_effects(emit_operation).await?);
+    Ok(FeatureUsageReportWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
     }
+    .cell())
 }
 
-/// Subscribes to lifecycle events of the compilation.
-///
-/// Emits an [UpdateMessage::Start] event when any computation starts.
-/// Emits an [UpdateMessage::End] event when there was no computation for the
-/// specified time (`aggregation_ms`). The [UpdateMessage::End] event contains
-/// information about the computations that happened since the
-/// [UpdateMessage::Start] event. It contains the duration of the computation
-/// (excluding the idle time that was spend waiting for `aggregation_ms`), and
-/// the number of tasks that were executed.
-///
-/// The signature of the `func` is `(update_message: UpdateMessage) => void`.
+#[napi(object)]
+struct NapiWrittenFeatureUsageReport {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the `feature-usage.json` enabled through
+/// `experimental.turbo.featureUsageReport` to the dist dir, returning its path
+/// relative to it.
 #[napi]
-pub fn project_update_info_subscribe(
+pub async fn project_write_feature_usage_report(
     #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
-    aggregation_ms: u32,
-    func: JsFunction,
-) -> napi::Result<()> {
-    let func: ThreadsafeFunction<UpdateMessage> = func.create_threadsafe_function(0, |ctx| {
-        let message = ctx.value;
-        Ok(vec![NapiUpdateMessage::from(message)])
-    })?;
+) -> napi::Result<TurbopackResult<NapiWrittenFeatureUsageReport>> {
     let turbo_tasks = project.turbo_tasks.clone();
-    tokio::spawn(async move {
-        loop {
-            let update_info = turbo_tasks
-                .aggregated_update_info(Duration::ZERO, Duration::ZERO)
-                .await;
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let FeatureUsageReportWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_feature_usage_report_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
 
-            func.call(
-                Ok(UpdateMessage::Start),
-                ThreadsafeFunctionCallMode::NonBlocking,
-            );
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
 
-            let update_info = match update_info {
-                Some(update_info) => update_info,
-                None => {
-                    turbo_tasks
-                        .get_or_wait_aggregated_update_info(Duration::from_millis(
-                            aggregation_ms.into(),
-                        ))
-                        .await
-                }
-            };
+    Ok(TurbopackResult {
+        result: NapiWrittenFeatureUsageReport {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
 
-            let status = func.call(
-                Ok(UpdateMessage::End(update_info)),
-                ThreadsafeFunctionCallMode::NonBlocking,
-            );
+#[turbo_tasks::value(serialization = "none")]
+struct EnvUsageReportWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
 
-            if !matches!(status, Status::Ok) {
-                let error = anyhow!("Error calling JS function: {}", status);
-                eprintln!("{}", error);
-                break;
-            }
-        }
-    });
+#[turbo_tasks::function]
+async fn emit_env_usage_report_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<EnvUsageReportWithIssues>> {
+    let emit_operation = container.emit_env_usage_report();
+    let paths = emit_operation.strongly_consistent().await?;
+    let issues = get_issues(emit_operation).await?;
+    let diagnostics = get_diagnostics(emit_operation).await?;
+    let effects = Arc::new(get_effects(emit_operation).await?);
+    Ok(EnvUsageReportWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiWrittenEnvUsageReport {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the `env-usage.json` enabled through
+/// `experimental.turbo.envUsageReport` to the dist dir, returning its path
+/// relative to it.
+#[napi]
+pub async fn project_write_env_usage_report(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenEnvUsageReport>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let EnvUsageReportWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_env_usage_report_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenEnvUsageReport {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct DuplicateModulesReportWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_duplicate_modules_report_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<DuplicateModulesReportWithIssues>> {
+    let emit_operation = container.emit_duplicate_modules_report();
+    let paths = emit_operation.strongly_consistent().await?;
+    let issues = get_issues(emit_operation).await?;
+    let diagnostics = get_diagnostics(emit_operation).await?;
+    let effects = Arc::new(get_effects(emit_operation).await?);
+    Ok(DuplicateModulesReportWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiWrittenDuplicateModulesReport {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the `duplicate-modules.json` of the build stats to the dist dir when
+/// `TURBOPACK_STATS` is set, returning its path relative to it.
+#[napi]
+pub async fn project_write_duplicate_modules_report(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenDuplicateModulesReport>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let DuplicateModulesReportWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_duplicate_modules_report_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenDuplicateModulesReport {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct ThemesWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_themes_with_issues(container: Vc<ProjectContainer>) -> Result<Vc<ThemesWithIssues>> {
+    let emit_themes_operation = container.emit_themes();
+    let paths = emit_themes_operation.strongly_consistent().await?;
+    let issues = get_issues(emit_themes_operation).await?;
+    let diagnostics = get_diagnostics(emit_themes_operation).await?;
+    let effects = Arc::new(get_effects(emit_themes_operation).await?);
+    Ok(ThemesWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiWrittenThemes {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the CSS chunks of the themes configured through
+/// `experimental.turbo.themes` and the `theme-manifest.json` listing them,
+/// returning the path of the manifest relative to the dist dir.
+#[napi]
+pub async fn project_write_themes(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenThemes>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let ThemesWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_themes_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenThemes {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct ChunkHashManifestWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_chunk_hash_manifest_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<ChunkHashManifestWithIssues>> {
+    let emit_chunk_hash_manifest_operation = container.emit_chunk_hash_manifest();
+    let paths = emit_chunk_hash_manifest_operation
+        .strongly_consistent()
+        .await?;
+    let issues = get_issues(emit_chunk_hash_manifest_operation).await?;
+    let diagnostics = get_diagnostics(emit_chunk_hash_manifest_operation).await?;
+    let effects = Arc::new(get_effects(emit_chunk_hash_manifest_operation).await?);
+    Ok(ChunkHashManifestWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiWrittenChunkHashManifest {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the `chunk-hash-manifest.json` enabled through
+/// `experimental.turbo.contentHashing` to the dist dir, returning its path
+/// relative to it.
+#[napi]
+pub async fn project_write_chunk_hash_manifest(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenChunkHashManifest>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let ChunkHashManifestWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_chunk_hash_manifest_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenChunkHashManifest {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct ChunkIntegrityManifestWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_chunk_integrity_manifest_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<ChunkIntegrityManifestWithIssues>> {
+    let emit_chunk_integrity_manifest_operation = container.emit_chunk_integrity_manifest();
+    let paths = emit_chunk_integrity_manifest_operation
+        .strongly_consistent()
+        .await?;
+    let issues = get_issues(emit_chunk_integrity_manifest_operation).await?;
+    let diagnostics = get_diagnostics(emit_chunk_integrity_manifest_operation).await?;
+    let effects = Arc::new(get_effects(emit_chunk_integrity_manifest_operation).await?);
+    Ok(ChunkIntegrityManifestWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiWrittenChunkIntegrityManifest {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the `static/chunks/integrity-manifest.json` enabled through
+/// `experimental.turbo.assetPrefixFallback` to the dist dir, returning its
+/// path relative to it.
+#[napi]
+pub async fn project_write_chunk_integrity_manifest(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenChunkIntegrityManifest>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let ChunkIntegrityManifestWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_chunk_integrity_manifest_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenChunkIntegrityManifest {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct ModuleFederationWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_module_federation_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<ModuleFederationWithIssues>> {
+    let emit_module_federation_operation = container.emit_module_federation();
+    let paths = emit_module_federation_operation
+        .strongly_consistent()
+        .await?;
+    let issues = get_issues(emit_module_federation_operation).await?;
+    let diagnostics = get_diagnostics(emit_module_federation_operation).await?;
+    let effects = Arc::new(get_effects(emit_module_federation_operation).await?);
+    Ok(ModuleFederationWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiWrittenModuleFederation {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the remote entry and the chunks of the container configured in
+/// `experimental.turbo.moduleFederation` to the dist dir, returning their
+/// paths relative to it.
+#[napi]
+pub async fn project_write_module_federation(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenModuleFederation>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let ModuleFederationWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_module_federation_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenModuleFederation {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct BrowserExtensionWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+    effects: Arc<Effects>,
+}
+
+#[turbo_tasks::function]
+async fn emit_browser_extension_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<BrowserExtensionWithIssues>> {
+    let emit_browser_extension_operation = container.emit_browser_extension();
+    let paths = emit_browser_extension_operation
+        .strongly_consistent()
+        .await?;
+    let issues = get_issues(emit_browser_extension_operation).await?;
+    let diagnostics = get_diagnostics(emit_browser_extension_operation).await?;
+    let effects = Arc::new(get_effects(emit_browser_extension_operation).await?);
+    Ok(BrowserExtensionWithIssues {
+        paths,
+        issues,
+        diagnostics,
+        effects,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiWrittenBrowserExtension {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Writes the browser extension configured through
+/// `experimental.turbo.browserExtension` to the `extension` directory of the
+/// dist dir, returning the paths of the written files relative to the dist dir.
+#[napi]
+pub async fn project_write_browser_extension(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiWrittenBrowserExtension>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let BrowserExtensionWithIssues {
+                paths,
+                issues,
+                diagnostics,
+                effects,
+            } = &*emit_browser_extension_with_issues(container)
+                .strongly_consistent()
+                .await?;
+            effects.apply().await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiWrittenBrowserExtension {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct OutputPathsWithIssues {
+    paths: ReadRef<Vec<RcStr>>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+}
+
+#[turbo_tasks::function]
+async fn output_paths_with_issues(
+    container: Vc<ProjectContainer>,
+) -> Result<Vc<OutputPathsWithIssues>> {
+    let output_paths_operation = container.output_paths();
+    let paths = output_paths_operation.strongly_consistent().await?;
+    let issues = get_issues(output_paths_operation).await?;
+    let diagnostics = get_diagnostics(output_paths_operation).await?;
+    Ok(OutputPathsWithIssues {
+        paths,
+        issues,
+        diagnostics,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiOutputPaths {
+    /// Relative to the dist dir
+    pub paths: Vec<String>,
+}
+
+/// Lists the paths of all assets written when building the app, so that the
+/// assets of a previous build which aren't written anymore can be removed.
+#[napi]
+pub async fn project_output_paths(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+) -> napi::Result<TurbopackResult<NapiOutputPaths>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (paths, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let OutputPathsWithIssues {
+                paths,
+                issues,
+                diagnostics,
+            } = &*output_paths_with_issues(container)
+                .strongly_consistent()
+                .await?;
+
+            Ok((paths.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiOutputPaths {
+            paths: paths.iter().map(|path| path.to_string()).collect(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct GraphExportWithIssues {
+    graph: ReadRef<RcStr>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+}
+
+#[turbo_tasks::function]
+async fn graph_export_with_issues(
+    container: Vc<ProjectContainer>,
+    include_sources: bool,
+) -> Result<Vc<GraphExportWithIssues>> {
+    let graph_export_operation = container.graph_export(include_sources);
+    let graph = graph_export_operation.strongly_consistent().await?;
+    let issues = get_issues(graph_export_operation).await?;
+    let diagnostics = get_diagnostics(graph_export_operation).await?;
+    Ok(GraphExportWithIssues {
+        graph,
+        issues,
+        diagnostics,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiGraphExport {
+    /// The module graph as JSON.
+    pub graph: String,
+}
+
+/// Serializes the module graph of the whole app, to reproduce issues without
+/// the project. The sources of the modules are only included when
+/// `include_sources` is set.
+#[napi]
+pub async fn project_graph_export(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    include_sources: bool,
+) -> napi::Result<TurbopackResult<NapiGraphExport>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (graph, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let GraphExportWithIssues {
+                graph,
+                issues,
+                diagnostics,
+            } = &*graph_export_with_issues(container, include_sources)
+                .strongly_consistent()
+                .await?;
+
+            Ok((graph.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: NapiGraphExport {
+            graph: graph.to_string(),
+        },
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+#[turbo_tasks::value(serialization = "none")]
+struct ImportCostsWithIssues {
+    costs: ReadRef<ImportCosts>,
+    issues: Arc<Vec<ReadRef<PlainIssue>>>,
+    diagnostics: Arc<Vec<ReadRef<PlainDiagnostic>>>,
+}
+
+#[turbo_tasks::function]
+async fn import_costs_with_issues(
+    container: Vc<ProjectContainer>,
+    file: RcStr,
+    specifier: RcStr,
+) -> Result<Vc<ImportCostsWithIssues>> {
+    let import_cost_operation = container.import_cost(file, specifier);
+    let costs = import_cost_operation.strongly_consistent().await?;
+    let issues = get_issues(import_cost_operation).await?;
+    let diagnostics = get_diagnostics(import_cost_operation).await?;
+    Ok(ImportCostsWithIssues {
+        costs,
+        issues,
+        diagnostics,
+    }
+    .cell())
+}
+
+#[napi(object)]
+struct NapiImportCost {
+    /// The layer of the importing module, e.g. `app-client`
+    pub layer: Option<String>,
+    /// The number of modules only the import adds to the app
+    pub modules: u32,
+    /// The size of the generated code of these modules, in bytes
+    pub size: u32,
+    pub gzip_size: u32,
+}
+
+/// Returns the code the import of `specifier` in `file` adds to the app, for
+/// each client layer `file` is part of. `file` is relative to the project
+/// directory.
+#[napi]
+pub async fn project_import_cost(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    file: String,
+    specifier: String,
+) -> napi::Result<TurbopackResult<Vec<NapiImportCost>>> {
+    let turbo_tasks = project.turbo_tasks.clone();
+    let container = project.container;
+    let (costs, issues, diagnostics) = turbo_tasks
+        .run_once(async move {
+            let ImportCostsWithIssues {
+                costs,
+                issues,
+                diagnostics,
+            } = &*import_costs_with_issues(container, file.into(), specifier.into())
+                .strongly_consistent()
+                .await?;
+
+            Ok((costs.clone(), issues.clone(), diagnostics.clone()))
+        })
+        .await
+        .map_err(|e| napi::Error::from_reason(PrettyPrintError(&e).to_string()))?;
+
+    Ok(TurbopackResult {
+        result: costs
+            .iter()
+            .map(|cost| NapiImportCost {
+                layer: cost.layer.as_ref().map(|layer| layer.to_string()),
+                modules: cost.modules.try_into().unwrap_or(u32::MAX),
+                size: cost.size.try_into().unwrap_or(u32::MAX),
+                gzip_size: cost.gzip_size.try_into().unwrap_or(u32::MAX),
+            })
+            .collect(),
+        issues: issues.iter().map(|i| NapiIssue::from(&**i)).collect(),
+        diagnostics: diagnostics
+            .iter()
+            .map(|d| NapiDiagnostic::from(d))
+            .collect(),
+    })
+}
+
+enum UpdateMessage {
+    Start,
+    End(UpdateInfo),
+}
+
+#[napi(object)]
+struct NapiUpdateMessage {
+    pub update_type: String,
+    pub value: Option<NapiUpdateInfo>,
+}
+
+impl From<UpdateMessage> for NapiUpdateMessage {
+    fn from(update_message: UpdateMessage) -> Self {
+        match update_message {
+            UpdateMessage::Start => NapiUpdateMessage {
+                update_type: "start".to_string(),
+                value: None,
+            },
+            UpdateMessage::End(info) => NapiUpdateMessage {
+                update_type: "end".to_string(),
+                value: Some(info.into()),
+            },
+        }
+    }
+}
+
+#[napi(object)]
+struct NapiUpdateInfo {
+    pub duration: u32,
+    pub tasks: u32,
+}
+
+impl From<UpdateInfo> for NapiUpdateInfo {
+    fn from(update_info: UpdateInfo) -> Self {
+        Self {
+            duration: update_info.duration.as_millis() as u32,
+            tasks: update_info.tasks as u32,
+        }
+    }
+}
+
+/// Subscribes to lifecycle events of the compilation.
+///
+/// Emits an [UpdateMessage::Start] event when any computation starts.
+/// Emits an [UpdateMessage::End] event when there was no computation for the
+/// specified time (`aggregation_ms`). The [UpdateMessage::End] event contains
+/// information about the computations that happened since the
+/// [UpdateMessage::Start] event. It contains the duration of the computation
+/// (excluding the idle time that was spend waiting for `aggregation_ms`), and
+/// the number of tasks that were executed.
+///
+/// The signature of the `func` is `(update_message: UpdateMessage) => void`.
+#[napi]
+pub fn project_update_info_subscribe(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    aggregation_ms: u32,
+    func: JsFunction,
+) -> napi::Result<()> {
+    let func: ThreadsafeFunction<UpdateMessage> = func.create_threadsafe_function(0, |ctx| {
+        let message = ctx.value;
+        Ok(vec![NapiUpdateMessage::from(message)])
+    })?;
+    let turbo_tasks = project.turbo_tasks.clone();
+    tokio::spawn(async move {
+        loop {
+            let update_info = turbo_tasks
+                .aggregated_update_info(Duration::ZERO, Duration::ZERO)
+                .await;
+
+            func.call(
+                Ok(UpdateMessage::Start),
+                ThreadsafeFunctionCallMode::NonBlocking,
+            );
+
+            let update_info = match update_info {
+                Some(update_info) => update_info,
+                None => {
+                    turbo_tasks
+                        .get_or_wait_aggregated_update_info(Duration::from_millis(
+                            aggregation_ms.into(),
+                        ))
+                        .await
+                }
+            };
+
+            let status = func.call(
+                Ok(UpdateMessage::End(update_info)),
+                ThreadsafeFunctionCallMode::NonBlocking,
+            );
+
+            if !matches!(status, Status::Ok) {
+                let error = anyhow!("Error calling JS function: {}", status);
+                eprintln!("{}", error);
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Subscribes to the progress of writing the endpoints of the project, e.g.
+/// during `next build`.
+///
+/// Emits a `progress` event whenever an endpoint starts or finishes writing,
+/// with the number of endpoints written and being written, a `diagnostics`
+/// event with the issues and diagnostics of every endpoint written with any,
+/// and a `completion` event when the project is shut down.
+///
+/// The signature of the `func` is `(build_event: NapiBuildEvent) => void`.
+#[napi]
+pub fn project_build_events_subscribe(
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] project: External<ProjectInstance>,
+    func: JsFunction,
+) -> napi::Result<()> {
+    let func: ThreadsafeFunction<BuildEvent> = func.create_threadsafe_function(0, |ctx| {
+        let event = ctx.value;
+        Ok(vec![NapiBuildEvent::from(event)])
+    })?;
+    project.build_progress.subscribe(func);
+    Ok(())
+}
+
+#[napi(object)]
+pub struct NapiResolveInterceptorRequest {
+    /// The index of the interceptor in `experimental.turbo.resolveInterceptors`
+    pub interceptor: u32,
+    pub request: String,
+    /// The directory of the importing module
+    pub context: String,
+}
+
+#[napi(object)]
+pub struct NapiResolveInterception {
+    /// The request to resolve instead, relative to the importing module
+    pub request: Option<String>,
+    /// Resolve to an empty module
+    pub empty: Option<bool>,
+}
+
+struct JsResolveInterceptorCallback(
+    ThreadsafeFunction<NapiResolveInterceptorRequest, ErrorStrategy::Fatal>,
+);
+
+impl ResolveInterceptorCallback for JsResolveInterceptorCallback {
+    fn intercept(
+        &self,
+        interceptor: u32,
+        request: RcStr,
+        context: RcStr,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolveInterception>> + Send>> {
+        let func = self.0.clone();
+        Box::pin(async move {
+            let interception: Promise<Option<NapiResolveInterception>> = func
+                .call_async(NapiResolveInterceptorRequest {
+                    interceptor,
+                    request: request.to_string(),
+                    context: context.to_string(),
+                })
+                .await
+                .map_err(|e| anyhow!("Error calling the resolve interceptor: {}", e.reason))?;
+            let interception = interception
+                .await
+                .map_err(|e| anyhow!("The resolve interceptor failed: {}", e.reason))?;
+            Ok(match interception {
+                Some(NapiResolveInterception {
+                    request: Some(request),
+                    ..
+                }) => ResolveInterception::Replace(request.into()),
+                Some(NapiResolveInterception {
+                    empty: Some(true), ..
+                }) => ResolveInterception::Empty,
+                _ => ResolveInterception::Unchanged,
+            })
+        })
+    }
+}
+
+/// Sets the callback calling the `resolve` functions of the
+/// `experimental.turbo.resolveInterceptors` configured in next.config.
+///
+/// The signature of the `func` is
+/// `(request: NapiResolveInterceptorRequest) => Promise<NapiResolveInterception | undefined>`.
+#[napi]
+pub fn project_set_resolve_interceptor(
+    env: Env,
+    #[napi(ts_arg_type = "{ __napiType: \"Project\" }")] _project: External<ProjectInstance>,
+    func: JsFunction,
+) -> napi::Result<()> {
+    let mut func: ThreadsafeFunction<NapiResolveInterceptorRequest, ErrorStrategy::Fatal> = func
+        .create_threadsafe_function(
+            0,
+            |ctx: ThreadSafeCallContext<NapiResolveInterceptorRequest>| Ok(vec![ctx.value]),
+        )?;
+    // Don't keep the process alive only to be able to call the interceptors.
+    func.unref(&env)?;
+    set_resolve_interceptor_callback(Some(Arc::new(JsResolveInterceptorCallback(func))));
     Ok(())
 }
 
@@ -3,7 +3,7 @@ use std::future::IntoFuture;
 use anyhow::{Context, Result};
 use next_core::{
     all_assets_from_entries,
-    app_segment_config::NextSegmentConfig,
+    app_segment_config::{static_params_from_loader_tree, NextSegmentConfig, StaticParamsManifest},
     app_structure::{
         get_entrypoints, AppPageLoaderTree, Entrypoint as AppEntrypoint,
         Entrypoints as AppEntrypoints, FileSystemPathVec, MetadataItem,
@@ -38,8 +38,8 @@ use serde::{Deserialize, Serialize};
 use tracing::Instrument;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{
-    fxindexmap, fxindexset, trace::TraceRawVcs, Completion, FxIndexSet, NonLocalValue, ResolvedVc,
-    TryJoinIterExt, Value, ValueToString, Vc,
+    fxindexmap, fxindexset, trace::TraceRawVcs, Completion, FxIndexMap, FxIndexSet, NonLocalValue,
+    ResolvedVc, TryJoinIterExt, Value, ValueToString, Vc,
 };
 use turbo_tasks_env::{CustomProcessEnv, ProcessEnv};
 use turbo_tasks_fs::{File, FileContent, FileSystemPath};
@@ -67,10 +67,11 @@ use turbopack_core::{
 use turbopack_ecmascript::resolve::cjs_resolve;
 
 use crate::{
+    chunking_budget::chunking_budget_report,
     dynamic_imports::{
         collect_next_dynamic_chunks, DynamicImportedChunks, NextDynamicChunkAvailability,
     },
-    font::create_font_manifest,
+    font::{create_font_manifest, used_font_families},
     loadable_manifest::create_react_loadable_manifest,
     module_graph::get_reduced_graphs_for_endpoint,
     nft_json::NftJsonAsset,
@@ -79,6 +80,7 @@ use crate::{
         get_wasm_paths_from_root, paths_to_bindings, wasm_paths_to_bindings,
     },
     project::Project,
+    resource_hints::collect_resource_hints,
     route::{AppPageRoute, Endpoint, Route, Routes, WrittenEndpoint},
     server_actions::create_server_actions_manifest,
     webpack_stats::generate_webpack_stats,
@@ -166,7 +168,7 @@ impl AppProject {
     }
 
     #[turbo_tasks::function]
-    fn app_entrypoints(&self) -> Vc<AppEntrypoints> {
+    pub(crate) fn app_entrypoints(&self) -> Vc<AppEntrypoints> {
         get_entrypoints(*self.app_dir, self.project.next_config().page_extensions())
     }
 
@@ -1062,6 +1064,41 @@ impl AppEndpoint {
                 client_assets.insert(polyfill_output_asset);
 
                 if emit_manifests {
+                    if let Some(budget) = &*this
+                        .app_project
+                        .project()
+                        .next_config()
+                        .chunking_budget()
+                        .await?
+                    {
+                        if this
+                            .app_project
+                            .project()
+                            .next_mode()
+                            .await?
+                            .is_production()
+                        {
+                            server_assets.insert(
+                                chunking_budget_report(
+                                    app_entry.original_name.clone(),
+                                    rsc_entry.ident().path(),
+                                    // The polyfills are only loaded by legacy browsers
+                                    client_assets
+                                        .iter()
+                                        .filter(|asset| **asset != polyfill_output_asset),
+                                    budget,
+                                    node_root.join(
+                                        format!(
+                                            "server/app{manifest_path_prefix}/chunking-budget.json"
+                                        )
+                                        .into(),
+                                    ),
+                                )
+                                .await?,
+                            );
+                        }
+                    }
+
                     if *this
                         .app_project
                         .project()
@@ -1085,9 +1122,46 @@ impl AppEndpoint {
                         server_assets.insert(ResolvedVc::upcast(stats_output));
                     }
 
+                    // The runtime chunk evaluates the entries, so it's the last of the shared
+                    // chunks
+                    let mut inlined_root_main_files = FxIndexMap::default();
+                    if process_ssr
+                        && this
+                            .app_project
+                            .project()
+                            .next_mode()
+                            .await?
+                            .is_production()
+                        && *this
+                            .app_project
+                            .project()
+                            .next_config()
+                            .experimental_inline_runtime_chunk(app_entry.original_name.clone())
+                            .await?
+                    {
+                        if let Some(runtime_chunk) = client_shared_chunks.last() {
+                            inlined_root_main_files
+                                .insert(app_entry.original_name.clone(), vec![*runtime_chunk]);
+                        }
+                    }
+
+                    let resource_hints = collect_resource_hints(&client_assets).await?;
+
                     let build_manifest = BuildManifest {
                         root_main_files: client_shared_chunks,
+                        inlined_root_main_files,
+                        resource_hints: if resource_hints.is_empty() {
+                            Default::default()
+                        } else {
+                            fxindexmap!(app_entry.original_name.clone() => resource_hints)
+                        },
                         polyfill_files: vec![polyfill_output_asset],
+                        esm_chunks: *this
+                            .app_project
+                            .project()
+                            .next_config()
+                            .esm_chunks()
+                            .await?,
                         ..Default::default()
                     };
                     let build_manifest_output =
@@ -1211,6 +1285,17 @@ impl AppEndpoint {
                 client_reference_manifest = Some(entry_manifest)
             }
 
+            let used_font_families = if this
+                .app_project
+                .project()
+                .next_mode()
+                .await?
+                .is_production()
+            {
+                Some(used_font_families(self.root_modules()))
+            } else {
+                None
+            };
             let next_font_manifest_output = create_font_manifest(
                 this.app_project.project().client_root(),
                 node_root,
@@ -1220,6 +1305,7 @@ impl AppEndpoint {
                 &app_entry.original_name,
                 *client_assets,
                 true,
+                used_font_families,
             )
             .await?;
             server_assets.insert(next_font_manifest_output);
@@ -1377,6 +1463,32 @@ impl AppEndpoint {
                     .await?;
                     server_assets.insert(app_paths_manifest_output);
 
+                    // create the manifest of the params of `generateStaticParams` that could be
+                    // evaluated at compile time, which the build uses instead of calling it. The
+                    // dev server always calls it.
+                    if let AppEndpointType::Page { loader_tree, .. } = this.ty {
+                        if this
+                            .app_project
+                            .project()
+                            .next_mode()
+                            .await?
+                            .is_production()
+                        {
+                            let static_params =
+                                static_params_from_loader_tree(*loader_tree).await?;
+                            if !static_params.is_empty() {
+                                server_assets.insert(
+                                    create_static_params_manifest(
+                                        node_root,
+                                        &app_entry.original_name,
+                                        &static_params,
+                                    )
+                                    .await?,
+                                );
+                            }
+                        }
+                    }
+
                     // create react-loadable-manifest for next/dynamic
                     let dynamic_import_entries =
                         if let (Some(next_dynamic_imports), Some(client_references_chunks)) =
@@ -1625,6 +1737,23 @@ async fn create_app_paths_manifest(
     ))
 }
 
+async fn create_static_params_manifest(
+    node_root: Vc<FileSystemPath>,
+    original_name: &str,
+    static_params: &StaticParamsManifest,
+) -> Result<ResolvedVc<Box<dyn OutputAsset>>> {
+    let path =
+        node_root.join(format!("server/app{original_name}/static-params-manifest.json").into());
+    Ok(ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            path,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&static_params)?).into()),
+        )
+        .to_resolved()
+        .await?,
+    ))
+}
+
 #[turbo_tasks::value_impl]
 impl Endpoint for AppEndpoint {
     #[turbo_tasks::function]
@@ -1727,6 +1856,11 @@ impl Endpoint for AppEndpoint {
             .client_changed(self.output().client_assets()))
     }
 
+    #[turbo_tasks::function]
+    fn output_assets(self: Vc<Self>) -> Vc<OutputAssets> {
+        self.output().output_assets()
+    }
+
     #[turbo_tasks::function]
     async fn root_modules(self: Vc<Self>) -> Result<Vc<Modules>> {
         let rsc_entry = self.app_endpoint_entry().await?.rsc_entry;
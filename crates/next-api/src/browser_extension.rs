@@ -0,0 +1,349 @@
+use anyhow::{bail, Context, Result};
+use serde_json::Value as JsonValue;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Value, ValueToString, Vc};
+use turbo_tasks_fs::{File, FileContent, FileSystemPath};
+use turbopack_core::{
+    asset::AssetContent,
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkingContext, EvaluatableAsset, EvaluatableAssets,
+    },
+    context::AssetContext,
+    file_source::FileSource,
+    module::Module,
+    output::{OutputAsset, OutputAssets},
+    reference_type::{EntryReferenceSubType, ReferenceType},
+    virtual_output::VirtualOutputAsset,
+};
+
+use crate::project::Project;
+
+/// The extensions of the files referenced by the manifest template which are
+/// compiled as entries. Other files are left as they are.
+const SCRIPT_EXTENSIONS: &[&str] = &[".js", ".mjs", ".jsx", ".ts", ".tsx"];
+
+/// The keys of the manifest referencing HTML pages. A script referenced by one
+/// of them is replaced with a page loading its chunks.
+const PAGE_POINTERS: &[&str] = &[
+    "/action/default_popup",
+    "/options_page",
+    "/options_ui/page",
+    "/side_panel/default_path",
+    "/devtools_page",
+    "/chrome_url_overrides/newtab",
+    "/chrome_url_overrides/history",
+    "/chrome_url_overrides/bookmarks",
+];
+
+fn is_script(value: &str) -> bool {
+    !value.starts_with('/')
+        && !value.contains("://")
+        && SCRIPT_EXTENSIONS.iter().any(|ext| value.ends_with(ext))
+}
+
+/// The chunks of an entry, relative to the root of the extension and in the
+/// order they're loaded.
+#[derive(Default)]
+struct EntryFiles {
+    js: Vec<RcStr>,
+    css: Vec<RcStr>,
+}
+
+/// Builds the browser extension configured through
+/// `experimental.turbo.browserExtension`. The scripts referenced by the
+/// `manifest.json` template are compiled with the client module context:
+///
+/// - `background.service_worker` is replaced with a service worker importing the chunks of the
+///   script,
+/// - the scripts listed in the `js` of `content_scripts` are replaced with their JavaScript chunks,
+///   their CSS chunks are added to `css`,
+/// - popup, options, side panel, devtools and override pages are replaced with an HTML page loading
+///   the chunks of the script.
+///
+/// Returns no assets when no browser extension is configured.
+#[turbo_tasks::function]
+async fn browser_extension_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    let Some(manifest_path) = &*project.next_config().browser_extension_manifest().await? else {
+        return Ok(OutputAssets::empty());
+    };
+    let manifest_path = project
+        .project_path()
+        .join(manifest_path.trim_start_matches("./").into());
+    let FileContent::Content(file) = &*manifest_path.read().await? else {
+        bail!(
+            "The browser extension manifest {} doesn't exist",
+            manifest_path.to_string().await?
+        );
+    };
+    let mut manifest: JsonValue =
+        serde_json::from_str(&file.content().to_str()?).with_context(|| {
+            "Unable to parse the browser extension manifest, it needs to be valid JSON"
+        })?;
+
+    let entries_dir = manifest_path.parent();
+    let extension_root = project.browser_extension_root();
+    let extension_root_value = extension_root.await?;
+    let mut assets: Vec<ResolvedVc<Box<dyn OutputAsset>>> = vec![];
+
+    let service_worker = match manifest.pointer("/background/service_worker") {
+        Some(JsonValue::String(entry)) if is_script(entry) => Some(entry.clone()),
+        _ => None,
+    };
+    if let Some(entry) = service_worker {
+        let files = compile_entry(
+            project,
+            entries_dir,
+            &extension_root_value,
+            &entry,
+            &mut assets,
+        )
+        .await?;
+        let content = service_worker_js(&files)?;
+        assets.push(emit_file(extension_root, "background.js", content).await?);
+        manifest["background"]["service_worker"] = "background.js".into();
+    }
+
+    if let Some(JsonValue::Array(content_scripts)) = manifest.get("content_scripts").cloned() {
+        let mut new_content_scripts = Vec::with_capacity(content_scripts.len());
+        for mut content_script in content_scripts {
+            if let Some(JsonValue::Array(scripts)) = content_script.get("js").cloned() {
+                let mut js: Vec<JsonValue> = vec![];
+                let mut css: Vec<JsonValue> = match content_script.get("css") {
+                    Some(JsonValue::Array(css)) => css.clone(),
+                    _ => vec![],
+                };
+                for script in scripts {
+                    let entry = match &script {
+                        JsonValue::String(entry) if is_script(entry) => entry.clone(),
+                        _ => {
+                            js.push(script);
+                            continue;
+                        }
+                    };
+                    let files = compile_entry(
+                        project,
+                        entries_dir,
+                        &extension_root_value,
+                        &entry,
+                        &mut assets,
+                    )
+                    .await?;
+                    for file in files.js {
+                        let file = JsonValue::String(file.to_string());
+                        if !js.contains(&file) {
+                            js.push(file);
+                        }
+                    }
+                    for file in files.css {
+                        let file = JsonValue::String(file.to_string());
+                        if !css.contains(&file) {
+                            css.push(file);
+                        }
+                    }
+                }
+                content_script["js"] = js.into();
+                if !css.is_empty() {
+                    content_script["css"] = css.into();
+                }
+            }
+            new_content_scripts.push(content_script);
+        }
+        manifest["content_scripts"] = new_content_scripts.into();
+    }
+
+    for pointer in PAGE_POINTERS {
+        let entry = match manifest.pointer(pointer) {
+            Some(JsonValue::String(entry)) if is_script(entry) => entry.clone(),
+            _ => continue,
+        };
+        let files = compile_entry(
+            project,
+            entries_dir,
+            &extension_root_value,
+            &entry,
+            &mut assets,
+        )
+        .await?;
+        let file_name = entry.rsplit('/').next().unwrap_or(&entry);
+        let name = format!(
+            "{}.html",
+            file_name
+                .rsplit_once('.')
+                .map_or(file_name, |(stem, _)| stem)
+        );
+        assets.push(emit_file(extension_root, &name, page_html(&files)).await?);
+        if let Some(value) = manifest.pointer_mut(pointer) {
+            *value = name.into();
+        }
+    }
+
+    assets.push(
+        emit_file(
+            extension_root,
+            "manifest.json",
+            serde_json::to_string_pretty(&manifest)?,
+        )
+        .await?,
+    );
+
+    Ok(Vc::cell(assets))
+}
+
+/// Compiles an entry referenced by the manifest, adding its chunks to
+/// `assets`.
+async fn compile_entry(
+    project: Vc<Project>,
+    entries_dir: Vc<FileSystemPath>,
+    extension_root: &FileSystemPath,
+    entry: &str,
+    assets: &mut Vec<ResolvedVc<Box<dyn OutputAsset>>>,
+) -> Result<EntryFiles> {
+    let chunks = browser_extension_entry_chunks(
+        project,
+        entries_dir.join(entry.trim_start_matches("./").into()),
+    )
+    .await?;
+    let mut files = EntryFiles::default();
+    for &chunk in chunks.iter() {
+        let path = chunk.ident().path().await?;
+        let Some(relative_path) = extension_root.get_path_to(&path) else {
+            continue;
+        };
+        match path.extension_ref() {
+            Some("js") => files.js.push(relative_path.into()),
+            Some("css") => files.css.push(relative_path.into()),
+            _ => {}
+        }
+    }
+    assets.extend(chunks.iter().copied());
+    Ok(files)
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn browser_extension_assets_operation(project: ResolvedVc<Project>) -> Vc<OutputAssets> {
+    browser_extension_assets(*project)
+}
+
+/// Compiles a script referenced by the manifest and chunks it on its own with
+/// the chunking context of the extension.
+#[turbo_tasks::function]
+async fn browser_extension_entry_chunks(
+    project: Vc<Project>,
+    path: Vc<FileSystemPath>,
+) -> Result<Vc<OutputAssets>> {
+    let module = project
+        .pages_project()
+        .client_module_context()
+        .process(
+            Vc::upcast(FileSource::new(path)),
+            Value::new(ReferenceType::Entry(EntryReferenceSubType::Undefined)),
+        )
+        .module()
+        .to_resolved()
+        .await?;
+    let Some(module) = ResolvedVc::try_sidecast::<Box<dyn EvaluatableAsset>>(module).await? else {
+        bail!(
+            "The browser extension entry {} can't be evaluated",
+            path.to_string().await?
+        );
+    };
+    let chunk_group = project
+        .browser_extension_chunking_context()
+        .evaluated_chunk_group(
+            module.ident(),
+            EvaluatableAssets::one(module),
+            Value::new(AvailabilityInfo::Root),
+        )
+        .await?;
+    Ok(*chunk_group.assets)
+}
+
+/// A service worker importing the chunks of an entry, relative to the origin
+/// of the extension.
+fn service_worker_js(files: &EntryFiles) -> Result<String> {
+    Ok(format!(
+        "importScripts({});\n",
+        files
+            .js
+            .iter()
+            .map(|file| serde_json::to_string(&format!("/{file}")))
+            .collect::<serde_json::Result<Vec<_>>>()?
+            .join(", ")
+    ))
+}
+
+fn page_html(files: &EntryFiles) -> String {
+    let mut html =
+        "<!DOCTYPE html>\n<html>\n  <head>\n    <meta charset=\"utf-8\" />\n".to_string();
+    for file in &files.css {
+        html.push_str(&format!(
+            "    <link rel=\"stylesheet\" href=\"/{file}\" />\n"
+        ));
+    }
+    html.push_str("  </head>\n  <body>\n");
+    for file in &files.js {
+        html.push_str(&format!("    <script src=\"/{file}\"></script>\n"));
+    }
+    html.push_str("  </body>\n</html>\n");
+    html
+}
+
+async fn emit_file(
+    extension_root: Vc<FileSystemPath>,
+    name: &str,
+    content: String,
+) -> Result<ResolvedVc<Box<dyn OutputAsset>>> {
+    Ok(ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            extension_root.join(name.into()).to_resolved().await?,
+            AssetContent::file(File::from(content).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_script, page_html, service_worker_js, EntryFiles};
+
+    fn files() -> EntryFiles {
+        EntryFiles {
+            js: vec!["chunks/runtime.js".into(), "chunks/popup.js".into()],
+            css: vec!["chunks/popup.css".into()],
+        }
+    }
+
+    #[test]
+    fn test_is_script() {
+        assert!(is_script("./background.ts"));
+        assert!(is_script("content/index.jsx"));
+        assert!(!is_script("icons/icon.png"));
+        assert!(!is_script("/background.js"));
+        assert!(!is_script("https://example.com/script.js"));
+    }
+
+    #[test]
+    fn test_service_worker_js() {
+        assert_eq!(
+            service_worker_js(&files()).unwrap(),
+            "importScripts(\"/chunks/runtime.js\", \"/chunks/popup.js\");\n"
+        );
+    }
+
+    #[test]
+    fn test_page_html() {
+        let html = page_html(&files());
+        assert!(html.contains("<link rel=\"stylesheet\" href=\"/chunks/popup.css\" />"));
+        // The chunks are loaded in order.
+        let runtime = html
+            .find("<script src=\"/chunks/runtime.js\"></script>")
+            .unwrap();
+        let popup = html
+            .find("<script src=\"/chunks/popup.js\"></script>")
+            .unwrap();
+        assert!(runtime < popup);
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::Result;
+use next_core::{all_assets_from_entries, next_manifests::ChunkHashManifest};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::File;
+use turbopack_core::{
+    asset::AssetContent, chunk::content_hash::logical_file_name, output::OutputAssets,
+    virtual_output::VirtualOutputAsset,
+};
+
+use crate::{project::Project, route::Endpoint};
+
+/// Builds `chunk-hash-manifest.json` from the client chunks of all endpoints,
+/// when `experimental.turbo.contentHashing` is enabled. Returns no assets
+/// otherwise.
+#[turbo_tasks::function]
+async fn chunk_hash_manifest_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    if !*project
+        .next_config()
+        .content_hashing(project.next_mode())
+        .await?
+    {
+        return Ok(OutputAssets::empty());
+    }
+
+    let mut output_assets = vec![];
+    for endpoint in project.entrypoints().await?.all_endpoints() {
+        output_assets.extend(endpoint.output_assets().await?.iter().copied());
+    }
+
+    let client_relative_path = project.client_relative_path().await?;
+    let mut manifest = ChunkHashManifest::default();
+    for asset in all_assets_from_entries(Vc::cell(output_assets))
+        .await?
+        .iter()
+    {
+        let path = asset.ident().path().await?;
+        let Some(path) = client_relative_path.get_path_to(&path) else {
+            continue;
+        };
+        // Static assets have a content hash in their file name too, but no
+        // logical name to look them up by.
+        let Some(file_name) = path.strip_prefix("static/chunks/") else {
+            continue;
+        };
+        if let Some(logical_file_name) = logical_file_name(file_name) {
+            manifest.chunks.insert(
+                format!("static/chunks/{logical_file_name}").into(),
+                RcStr::from(path),
+            );
+        }
+    }
+
+    Ok(Vc::cell(vec![ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            project
+                .node_root()
+                .join("chunk-hash-manifest.json".into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&manifest)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    )]))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn chunk_hash_manifest_assets_operation(
+    project: ResolvedVc<Project>,
+) -> Vc<OutputAssets> {
+    chunk_hash_manifest_assets(*project)
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use base64::{display::Base64Display, engine::general_purpose::STANDARD};
+use next_core::all_assets_from_entries;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{File, FileContent};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    output::OutputAssets,
+    virtual_output::VirtualOutputAsset,
+};
+
+use crate::{project::Project, route::Endpoint};
+
+/// The path of the manifest relative to the client root. The runtime fetches it
+/// from `CHUNK_FALLBACK_BASE_PATH`.
+const CHUNK_INTEGRITY_MANIFEST_PATH: &str = "static/chunks/integrity-manifest.json";
+
+/// Builds `static/chunks/integrity-manifest.json` from the client chunks of all
+/// endpoints when `experimental.turbo.assetPrefixFallback` is enabled. It maps
+/// the path of each chunk to its integrity, which the runtime verifies the
+/// chunks it retries from the same origin with. Returns no assets otherwise.
+#[turbo_tasks::function]
+async fn chunk_integrity_manifest_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    if project
+        .next_config()
+        .asset_prefix_fallback()
+        .await?
+        .is_none()
+    {
+        return Ok(OutputAssets::empty());
+    }
+    let algorithm = project
+        .next_config()
+        .experimental_sri()
+        .await?
+        .as_ref()
+        .and_then(|sri| sri.algorithm.clone())
+        .unwrap_or_else(|| "sha256".into());
+
+    let mut output_assets = vec![];
+    for endpoint in project.entrypoints().await?.all_endpoints() {
+        output_assets.extend(endpoint.output_assets().await?.iter().copied());
+    }
+
+    let client_relative_path = project.client_relative_path().await?;
+    let mut manifest: BTreeMap<RcStr, RcStr> = BTreeMap::new();
+    for asset in all_assets_from_entries(Vc::cell(output_assets))
+        .await?
+        .iter()
+    {
+        let path = asset.ident().path().await?;
+        let Some(path) = client_relative_path.get_path_to(&path) else {
+            continue;
+        };
+        if !path.starts_with("static/chunks/") || !(path.ends_with(".js") || path.ends_with(".css"))
+        {
+            continue;
+        }
+        let AssetContent::File(file) = &*asset.content().await? else {
+            continue;
+        };
+        let FileContent::Content(file) = &*file.await? else {
+            continue;
+        };
+        manifest.insert(
+            path.into(),
+            integrity(&algorithm, &file.content().to_bytes()?)?.into(),
+        );
+    }
+
+    Ok(Vc::cell(vec![ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            project
+                .client_relative_path()
+                .join(CHUNK_INTEGRITY_MANIFEST_PATH.into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(serde_json::to_string(&manifest)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    )]))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn chunk_integrity_manifest_assets_operation(
+    project: ResolvedVc<Project>,
+) -> Vc<OutputAssets> {
+    chunk_integrity_manifest_assets(*project)
+}
+
+/// The value of an `integrity` attribute for `content`, e.g.
+/// `sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=`.
+fn integrity(algorithm: &str, content: &[u8]) -> Result<String> {
+    let digest = match algorithm {
+        "sha256" => Sha256::digest(content).to_vec(),
+        "sha384" => Sha384::digest(content).to_vec(),
+        "sha512" => Sha512::digest(content).to_vec(),
+        _ => bail!("Unsupported subresource integrity algorithm `{algorithm}`"),
+    };
+    Ok(format!(
+        "{algorithm}-{}",
+        Base64Display::new(&digest, &STANDARD)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::integrity;
+
+    #[test]
+    fn test_integrity() {
+        assert_eq!(
+            integrity("sha256", b"").unwrap(),
+            "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+        );
+        assert_eq!(
+            integrity("sha384", b"").unwrap(),
+            "sha384-OLBgp1GsljhM2TJ+sbHjaiH9txEUvgdDTAzHv2P24donTt6/529l+9Ua0vFImLlb"
+        );
+        assert!(integrity("md5", b"").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use anyhow::Result;
+use next_core::next_config::ChunkingBudgetConfig;
+use serde::Serialize;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, ResolvedVc, Vc};
+use turbo_tasks_fs::{File, FileSystemPath};
+use turbopack_browser::ecmascript::EcmascriptDevChunk;
+use turbopack_core::{
+    asset::AssetContent,
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    output::OutputAsset,
+    virtual_output::VirtualOutputAsset,
+};
+
+use crate::module_sizes::{chunk_module_sizes, ModuleSize};
+
+/// The number of modules listed in a [ChunkingBudgetReport].
+const TOP_MODULES: usize = 10;
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkingBudgetReport {
+    pub route: RcStr,
+    /// The maximum size of the JS chunks loaded initially, in bytes.
+    pub budget: u64,
+    /// The size of the JS chunks loaded initially, in bytes.
+    pub size: u64,
+    pub exceeded: bool,
+    pub chunks: Vec<ChunkingBudgetChunk>,
+    /// The largest modules of the chunks, by their estimated size.
+    pub top_modules: Vec<ModuleSize>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkingBudgetChunk {
+    pub path: RcStr,
+    pub size: u64,
+}
+
+/// Checks the size of the JS chunks in `client_assets`, the assets loaded
+/// initially by `route`, against the budget of
+/// `experimental.turbo.chunkingBudget`. Emits an issue for `path` when it's
+/// exceeded, which fails the build when `failOnExceed` is set, and returns the
+/// report written to `report_path`.
+pub async fn chunking_budget_report<'a, I>(
+    route: RcStr,
+    path: Vc<FileSystemPath>,
+    client_assets: I,
+    budget: &ChunkingBudgetConfig,
+    report_path: Vc<FileSystemPath>,
+) -> Result<ResolvedVc<Box<dyn OutputAsset>>>
+where
+    I: IntoIterator<Item = &'a ResolvedVc<Box<dyn OutputAsset>>>,
+{
+    let mut size = 0;
+    let mut chunks = vec![];
+    let mut modules = FxIndexMap::default();
+    for asset in client_assets {
+        let asset_path = asset.ident().path().await?;
+        if asset_path.extension_ref() != Some("js") {
+            continue;
+        }
+        let Some(asset_size) = *asset.size_bytes().await? else {
+            continue;
+        };
+        size += asset_size;
+        chunks.push(ChunkingBudgetChunk {
+            path: asset_path.path.clone(),
+            size: asset_size,
+        });
+
+        let Some(chunk) = ResolvedVc::try_downcast_type::<EcmascriptDevChunk>(*asset).await? else {
+            continue;
+        };
+        for (module, module_size) in chunk_module_sizes(chunk).await? {
+            // A module can be part of multiple chunks loaded by the route.
+            modules.entry(module).or_insert(module_size);
+        }
+    }
+
+    let mut top_modules: Vec<_> = modules.into_values().collect();
+    top_modules.sort_by(|a, b| b.size.cmp(&a.size));
+    top_modules.truncate(TOP_MODULES);
+
+    let report = ChunkingBudgetReport {
+        route,
+        budget: budget.max_initial_js_bytes,
+        size,
+        exceeded: size > budget.max_initial_js_bytes,
+        chunks,
+        top_modules,
+    };
+
+    if report.exceeded {
+        ChunkingBudgetIssue {
+            path: path.to_resolved().await?,
+            severity: if budget.fail_on_exceed.unwrap_or(false) {
+                IssueSeverity::Error
+            } else {
+                IssueSeverity::Warning
+            }
+            .resolved_cell(),
+            description: StyledString::Text(report_description(&report).into()).resolved_cell(),
+            route: report.route.clone(),
+        }
+        .resolved_cell()
+        .emit();
+    }
+
+    Ok(ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            report_path,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&report)?).into()),
+        )
+        .to_resolved()
+        .await?,
+    ))
+}
+
+fn report_description(report: &ChunkingBudgetReport) -> String {
+    let mut description = format!(
+        "The JS chunks loaded initially are {} bytes, {} bytes over the budget of {} bytes. The \
+         largest modules are:\n",
+        report.size,
+        report.size - report.budget,
+        report.budget
+    );
+    for module in &report.top_modules {
+        description.push_str(&format!("  {} (~{} bytes)\n", module.path, module.size));
+    }
+    description
+}
+
+#[turbo_tasks::value(shared)]
+struct ChunkingBudgetIssue {
+    path: ResolvedVc<FileSystemPath>,
+    route: RcStr,
+    description: ResolvedVc<StyledString>,
+    severity: ResolvedVc<IssueSeverity>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for ChunkingBudgetIssue {
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Other("chunking budget".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        *self.severity
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.path
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(format!("{} exceeds the chunking budget", self.route).into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(self.description))
+    }
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use turbo_tasks::{FxIndexSet, ResolvedVc, Vc};
+use turbopack_browser::ecmascript::EcmascriptDevChunk;
+use turbopack_core::reference::all_assets_from_entries;
+
+use crate::{
+    module_sizes::{chunk_module_sizes, ModuleSize},
+    route::Endpoint,
+};
+
+/// A list of client modules
+#[turbo_tasks::value(transparent)]
+pub struct ClientModuleSizes(Vec<ModuleSize>);
+
+/// Returns the modules in the development client chunks of an endpoint with
+/// their size. Each module is listed once, even if it's part of more than one
+/// chunk. Comparing the result across rebuilds shows what an edit added to
+/// the client.
+#[turbo_tasks::function]
+pub async fn client_module_sizes(endpoint: Vc<Box<dyn Endpoint>>) -> Result<Vc<ClientModuleSizes>> {
+    let mut seen = FxIndexSet::default();
+    let mut modules = vec![];
+    for asset in all_assets_from_entries(endpoint.output_assets()).await? {
+        let Some(chunk) = ResolvedVc::try_downcast_type::<EcmascriptDevChunk>(*asset).await? else {
+            continue;
+        };
+        for (module, size) in chunk_module_sizes(chunk).await? {
+            if seen.insert(module) {
+                modules.push(size);
+            }
+        }
+    }
+    Ok(Vc::cell(modules))
+}
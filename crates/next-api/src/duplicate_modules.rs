@@ -0,0 +1,250 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::Result;
+use petgraph::graph::NodeIndex;
+use serde::Serialize;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, FxIndexSet, ResolvedVc, ValueToString, Vc};
+use turbo_tasks_fs::File;
+use turbopack_browser::ecmascript::EcmascriptDevChunk;
+use turbopack_core::{
+    asset::AssetContent,
+    module::Module,
+    output::{OutputAsset, OutputAssets},
+    reference::all_assets_from_entries,
+    virtual_output::VirtualOutputAsset,
+};
+
+use crate::{
+    module_graph::SingleModuleGraph,
+    module_sizes::{chunk_module_sizes, package_of, ModuleSize},
+    project::Project,
+};
+
+/// The number of import chains listed for each duplicated module.
+const MAX_IMPORT_CHAINS: usize = 5;
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateModulesReport {
+    /// The bytes which would be saved if every duplicated module was only
+    /// loaded once.
+    duplicated_size: u64,
+    /// Modules which are part of more than one chunk, by duplicated size.
+    modules: Vec<DuplicateModule>,
+    /// Packages of which more than one copy is bundled, e.g. two versions of
+    /// `react-dom` in different `node_modules` directories.
+    packages: Vec<DuplicatePackage>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct DuplicateModule {
+    #[serde(flatten)]
+    module: ModuleSize,
+    /// The chunks containing the module. The chunks of a chunk group don't
+    /// overlap, so each of them belongs to a different chunk group.
+    chunks: Vec<RcStr>,
+    /// The shortest chains of imports from an entry to the module, one
+    /// through each of its importers.
+    import_chains: Vec<Vec<RcStr>>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct DuplicatePackage {
+    name: RcStr,
+    /// The directories of the copies, e.g.
+    /// `[project]/node_modules/a/node_modules/react-dom`.
+    directories: Vec<RcStr>,
+}
+
+/// Builds `duplicate-modules.json` from the client chunks of all endpoints
+/// when the build stats are enabled through `TURBOPACK_STATS`. Returns no
+/// assets otherwise.
+#[turbo_tasks::function]
+async fn duplicate_modules_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    if !*project.should_create_webpack_stats().await? {
+        return Ok(OutputAssets::empty());
+    }
+
+    let mut output_assets = vec![];
+    for endpoint in project.entrypoints().await?.all_endpoints() {
+        output_assets.extend(endpoint.output_assets().await?.iter().copied());
+    }
+
+    let client_relative_path = project.client_relative_path().await?;
+    // The size and the chunks of each module, in the order they are found
+    let mut modules: FxIndexMap<ResolvedVc<Box<dyn Module>>, (ModuleSize, FxIndexSet<RcStr>)> =
+        FxIndexMap::default();
+    for asset in all_assets_from_entries(Vc::cell(output_assets)).await? {
+        let Some(chunk) = ResolvedVc::try_downcast_type::<EcmascriptDevChunk>(*asset).await? else {
+            continue;
+        };
+        let chunk_path = chunk.ident().path().await?;
+        let chunk_path: RcStr = client_relative_path
+            .get_path_to(&chunk_path)
+            .unwrap_or(&chunk_path.path)
+            .into();
+        for (module, module_size) in chunk_module_sizes(chunk).await? {
+            modules
+                .entry(module)
+                .or_insert((module_size, FxIndexSet::default()))
+                .1
+                .insert(chunk_path.clone());
+        }
+    }
+
+    let graph = project.whole_app_module_graph().await?;
+    let import_chains = ImportChains::new(&graph);
+
+    let mut report = DuplicateModulesReport::default();
+    let mut packages: FxIndexMap<RcStr, FxIndexSet<RcStr>> = FxIndexMap::default();
+    for (module, (module_size, chunks)) in modules {
+        if let Some((name, directory)) = package_of(&module_size.path) {
+            packages
+                .entry(name.into())
+                .or_default()
+                .insert(directory.into());
+        }
+        if chunks.len() < 2 {
+            continue;
+        }
+        report.duplicated_size += module_size.size * (chunks.len() as u64 - 1);
+        let mut chains = vec![];
+        for chain in import_chains.chains_to(module) {
+            let mut names = vec![];
+            for module in chain {
+                names.push(module.ident().path().to_string().await?.clone_value());
+            }
+            chains.push(names);
+        }
+        report.modules.push(DuplicateModule {
+            module: module_size,
+            chunks: chunks.into_iter().collect(),
+            import_chains: chains,
+        });
+    }
+    report.modules.sort_by_key(|module| {
+        std::cmp::Reverse(module.module.size * (module.chunks.len() as u64 - 1))
+    });
+    report.packages = packages
+        .into_iter()
+        .filter(|(_, directories)| directories.len() > 1)
+        .map(|(name, directories)| DuplicatePackage {
+            name,
+            directories: directories.into_iter().collect(),
+        })
+        .collect();
+
+    Ok(Vc::cell(vec![ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            project
+                .node_root()
+                .join("duplicate-modules.json".into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&report)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    )]))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn duplicate_modules_assets_operation(project: ResolvedVc<Project>) -> Vc<OutputAssets> {
+    duplicate_modules_assets(*project)
+}
+
+/// The shortest paths from the entries of a module graph to each module.
+struct ImportChains {
+    nodes: HashMap<ResolvedVc<Box<dyn Module>>, NodeIndex>,
+    modules: HashMap<NodeIndex, ResolvedVc<Box<dyn Module>>>,
+    /// The importer on the shortest path from an entry, `None` for entries.
+    parents: HashMap<NodeIndex, Option<NodeIndex>>,
+    importers: HashMap<NodeIndex, Vec<NodeIndex>>,
+}
+
+impl ImportChains {
+    fn new(graph: &SingleModuleGraph) -> Self {
+        let modules: HashMap<_, _> = graph
+            .enumerate_nodes()
+            .map(|(index, node)| (index, node.module))
+            .collect();
+        let nodes = modules
+            .iter()
+            .map(|(&index, &module)| (module, index))
+            .collect();
+        let mut children: HashMap<NodeIndex, Vec<NodeIndex>> = HashMap::new();
+        let mut importers: HashMap<NodeIndex, Vec<NodeIndex>> = HashMap::new();
+        for (from, to) in graph.enumerate_edges() {
+            children.entry(from).or_default().push(to);
+            importers.entry(to).or_default().push(from);
+        }
+
+        let mut parents = HashMap::new();
+        let mut queue = VecDeque::new();
+        // The entries are stored in a hash map, they are sorted so that the
+        // chains are the same across builds
+        let mut entries = graph.entry_nodes().collect::<Vec<_>>();
+        entries.sort_unstable();
+        for entry in entries {
+            parents.insert(entry, None);
+            queue.push_back(entry);
+        }
+        while let Some(node) = queue.pop_front() {
+            for &child in children.get(&node).into_iter().flatten() {
+                if !parents.contains_key(&child) {
+                    parents.insert(child, Some(node));
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        ImportChains {
+            nodes,
+            modules,
+            parents,
+            importers,
+        }
+    }
+
+    fn chain_to(&self, mut node: NodeIndex) -> Vec<ResolvedVc<Box<dyn Module>>> {
+        let mut chain = vec![];
+        loop {
+            chain.push(self.modules[&node]);
+            match self.parents.get(&node) {
+                Some(Some(parent)) => node = *parent,
+                _ => break,
+            }
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// The shortest chain through each importer of `module`, up to
+    /// [MAX_IMPORT_CHAINS].
+    fn chains_to(
+        &self,
+        module: ResolvedVc<Box<dyn Module>>,
+    ) -> Vec<Vec<ResolvedVc<Box<dyn Module>>>> {
+        let Some(&node) = self.nodes.get(&module) else {
+            return vec![];
+        };
+        let Some(importers) = self.importers.get(&node) else {
+            return vec![self.chain_to(node)];
+        };
+        importers
+            .iter()
+            .filter(|importer| self.parents.contains_key(importer))
+            .take(MAX_IMPORT_CHAINS)
+            .map(|&importer| {
+                let mut chain = self.chain_to(importer);
+                chain.push(module);
+                chain
+            })
+            .collect()
+    }
+}
@@ -1,6 +1,6 @@
 use anyhow::{bail, Result};
 use turbo_tasks::{Completion, Vc};
-use turbopack_core::module::Modules;
+use turbopack_core::{module::Modules, output::OutputAssets};
 
 use crate::route::{Endpoint, WrittenEndpoint};
 
@@ -32,6 +32,11 @@ impl Endpoint for EmptyEndpoint {
         Completion::new()
     }
 
+    #[turbo_tasks::function]
+    fn output_assets(self: Vc<Self>) -> Vc<OutputAssets> {
+        OutputAssets::empty()
+    }
+
     #[turbo_tasks::function]
     fn root_modules(self: Vc<Self>) -> Vc<Modules> {
         Vc::cell(vec![])
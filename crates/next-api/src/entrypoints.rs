@@ -1,9 +1,9 @@
 use turbo_rcstr::RcStr;
-use turbo_tasks::{FxIndexMap, ResolvedVc};
+use turbo_tasks::{FxIndexMap, ResolvedVc, Vc};
 
 use crate::{
     project::{Instrumentation, Middleware},
-    route::{Endpoint, Route},
+    route::{AppPageRoute, Endpoint, Route},
 };
 
 #[turbo_tasks::value(shared, local)]
@@ -15,3 +15,70 @@ pub struct Entrypoints {
     pub pages_app_endpoint: ResolvedVc<Box<dyn Endpoint>>,
     pub pages_error_endpoint: ResolvedVc<Box<dyn Endpoint>>,
 }
+
+impl Entrypoints {
+    /// The endpoints written when building the app. Of the routes, only the
+    /// HTML endpoints are included, as the data and RSC endpoints are written
+    /// along with them.
+    pub fn all_endpoints(&self) -> Vec<Vc<Box<dyn Endpoint>>> {
+        self.named_endpoints()
+            .into_iter()
+            .map(|(_, endpoint)| endpoint)
+            .collect()
+    }
+
+    /// Like [Entrypoints::all_endpoints], with the name of each endpoint: the
+    /// pathname of pages, the original name of app routes, or `middleware`,
+    /// `instrumentation` and `instrumentation-edge`.
+    pub fn named_endpoints(&self) -> Vec<(RcStr, Vc<Box<dyn Endpoint>>)> {
+        let mut endpoints = vec![
+            ("/_error".into(), *self.pages_error_endpoint),
+            ("/_app".into(), *self.pages_app_endpoint),
+            ("/_document".into(), *self.pages_document_endpoint),
+        ];
+
+        if let Some(middleware) = &self.middleware {
+            endpoints.push(("middleware".into(), middleware.endpoint));
+        }
+
+        if let Some(instrumentation) = &self.instrumentation {
+            endpoints.push(("instrumentation".into(), instrumentation.node_js));
+            endpoints.push(("instrumentation-edge".into(), instrumentation.edge));
+        }
+
+        for (pathname, route) in self.routes.iter() {
+            match route {
+                Route::Page {
+                    html_endpoint,
+                    data_endpoint: _,
+                } => {
+                    endpoints.push((pathname.clone(), **html_endpoint));
+                }
+                Route::PageApi { endpoint } => {
+                    endpoints.push((pathname.clone(), **endpoint));
+                }
+                Route::AppPage(page_routes) => {
+                    for AppPageRoute {
+                        original_name,
+                        html_endpoint,
+                        rsc_endpoint: _,
+                    } in page_routes
+                    {
+                        endpoints.push((original_name.as_str().into(), *html_endpoint));
+                    }
+                }
+                Route::AppRoute {
+                    original_name,
+                    endpoint,
+                } => {
+                    endpoints.push((original_name.as_str().into(), **endpoint));
+                }
+                Route::Conflict => {
+                    tracing::info!("WARN: conflict");
+                }
+            }
+        }
+
+        endpoints
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use next_core::next_env_usage::{collect_env_usage, EntryEnvUsage, EnvUsageReport, ModuleEnvUsage};
+use swc_core::common::GLOBALS;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::File;
+use turbopack_core::{
+    asset::AssetContent, compile_time_info::DefineableNameSegment, module::Module,
+    output::OutputAssets, virtual_output::VirtualOutputAsset,
+};
+use turbopack_ecmascript::{parse::ParseResult, EcmascriptModuleAsset};
+
+use crate::{module_graph::SingleModuleGraph, project::Project};
+
+/// Builds `env-usage.json` from the modules of each route, middleware and
+/// instrumentation, as configured by `experimental.turbo.envUsageReport`.
+/// Returns no assets when it isn't enabled.
+#[turbo_tasks::function]
+async fn env_usage_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    if !*project.next_config().env_usage_report().await? {
+        return Ok(OutputAssets::empty());
+    }
+
+    let mut report = EnvUsageReport::default();
+    for (name, endpoint) in project.entrypoints().await?.named_endpoints() {
+        let graph = SingleModuleGraph::new_with_entries(endpoint.root_modules()).await?;
+        let mut entry = EntryEnvUsage::default();
+        for node in graph.iter_nodes() {
+            let Some(module) =
+                ResolvedVc::try_downcast_type::<EcmascriptModuleAsset>(node.module).await?
+            else {
+                continue;
+            };
+            let usage = module_env_usage(*module).await?;
+            if usage.is_empty() {
+                continue;
+            }
+            let defines = module.await?.compile_time_info.await?.defines.await?;
+            let path = module.ident().path().await?;
+            entry.add(path.path.clone(), &usage, |name| {
+                defines.contains_key(&vec![
+                    DefineableNameSegment::Name("process".into()),
+                    DefineableNameSegment::Name("env".into()),
+                    DefineableNameSegment::Name(name.clone()),
+                ])
+            });
+        }
+        report.entries.insert(name, entry);
+    }
+
+    Ok(Vc::cell(vec![ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            project
+                .node_root()
+                .join("env-usage.json".into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&report)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    )]))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn env_usage_assets_operation(project: ResolvedVc<Project>) -> Vc<OutputAssets> {
+    env_usage_assets(*project)
+}
+
+async fn module_env_usage(module: Vc<EcmascriptModuleAsset>) -> Result<ModuleEnvUsage> {
+    let parsed = module.parse().await?;
+    let ParseResult::Ok {
+        program,
+        eval_context,
+        globals,
+        ..
+    } = &*parsed
+    else {
+        return Ok(Default::default());
+    };
+    Ok(GLOBALS.set(globals, || {
+        collect_env_usage(program, eval_context.unresolved_mark())
+    }))
+}
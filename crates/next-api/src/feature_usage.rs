@@ -0,0 +1,89 @@
+use anyhow::Result;
+use next_core::next_feature_usage::{
+    collect_feature_usage, node_modules_package_name, FeatureUsageReport, ModuleFeatureUsage,
+    ModuleFeatureUsageEntry,
+};
+use swc_core::common::GLOBALS;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::File;
+use turbopack_core::{
+    asset::AssetContent, module::Module, output::OutputAssets, virtual_output::VirtualOutputAsset,
+};
+use turbopack_ecmascript::{
+    chunk::{EcmascriptChunkPlaceable, EcmascriptExports},
+    parse::ParseResult,
+    EcmascriptModuleAsset,
+};
+
+use crate::project::Project;
+
+/// Builds `feature-usage.json` from the modules of the whole app, as
+/// configured by `experimental.turbo.featureUsageReport`. Returns no assets
+/// when it isn't enabled.
+#[turbo_tasks::function]
+async fn feature_usage_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    if !*project.next_config().feature_usage_report().await? {
+        return Ok(OutputAssets::empty());
+    }
+
+    let graph = project.whole_app_module_graph().await?;
+    let mut modules = vec![];
+    let mut packages = vec![];
+    for node in graph.iter_nodes() {
+        let Some(module) =
+            ResolvedVc::try_downcast_type::<EcmascriptModuleAsset>(node.module).await?
+        else {
+            continue;
+        };
+        let path = module.ident().path().await?;
+        if let Some(package) = node_modules_package_name(&path.path) {
+            let exports = module.get_exports().await?;
+            packages.push((
+                package.into(),
+                matches!(*exports, EcmascriptExports::CommonJs),
+            ));
+        }
+        modules.push(ModuleFeatureUsageEntry {
+            path: path.path.clone(),
+            layer: node.layer.as_ref().map(|layer| (**layer).clone()),
+            usage: module_feature_usage(*module).await?,
+        });
+    }
+    let report = FeatureUsageReport::new(modules, packages);
+
+    Ok(Vc::cell(vec![ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            project
+                .node_root()
+                .join("feature-usage.json".into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&report)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    )]))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn feature_usage_assets_operation(project: ResolvedVc<Project>) -> Vc<OutputAssets> {
+    feature_usage_assets(*project)
+}
+
+async fn module_feature_usage(module: Vc<EcmascriptModuleAsset>) -> Result<ModuleFeatureUsage> {
+    let parsed = module.parse().await?;
+    let ParseResult::Ok {
+        program,
+        eval_context,
+        globals,
+        ..
+    } = &*parsed
+    else {
+        return Ok(Default::default());
+    };
+    Ok(GLOBALS.set(globals, || {
+        collect_feature_usage(program, eval_context.unresolved_mark())
+    }))
+}
@@ -1,15 +1,23 @@
+use std::collections::{BTreeMap, HashMap};
+
 use anyhow::Result;
-use next_core::{all_assets_from_entries, next_manifests::NextFontManifest};
+use next_core::{
+    all_assets_from_entries, next_font_face_files, next_font_manifest_fonts,
+    next_manifests::{NextFontManifest, NextFontManifestFont},
+    FontFallbackDiagnostic, FontFallbackDiagnostics, NextFontStylesheetSource,
+};
 use turbo_rcstr::RcStr;
-use turbo_tasks::{ResolvedVc, ValueToString, Vc};
-use turbo_tasks_fs::{File, FileSystemPath};
+use turbo_tasks::{FxIndexSet, ResolvedVc, ValueToString, Vc};
+use turbo_tasks_fs::{File, FileContent, FileSystemPath};
+use turbopack::css::{CssModuleAsset, ModuleCssAsset};
 use turbopack_core::{
-    asset::AssetContent,
+    asset::{Asset, AssetContent},
+    module::{Module, Modules},
     output::{OutputAsset, OutputAssets},
     virtual_output::VirtualOutputAsset,
 };
 
-use crate::paths::get_font_paths_from_root;
+use crate::{module_graph::SingleModuleGraph, paths::get_font_paths_from_root, project::Project};
 
 pub(crate) async fn create_font_manifest(
     client_root: Vc<FileSystemPath>,
@@ -20,18 +28,41 @@ pub(crate) async fn create_font_manifest(
     pathname: &str,
     client_assets: Vc<OutputAssets>,
     app_dir: bool,
+    used_font_families: Option<Vc<Vec<RcStr>>>,
 ) -> Result<ResolvedVc<Box<dyn OutputAsset>>> {
     let all_client_output_assets = all_assets_from_entries(client_assets).await?;
 
     // `_next` gets added again later, so we "strip" it here via
     // `get_font_paths_from_root`.
-    let font_paths: Vec<String> =
+    let mut font_paths: Vec<String> =
         get_font_paths_from_root(&*client_root.await?, &all_client_output_assets)
             .await?
             .iter()
             .filter_map(|p| p.split("_next/").last().map(|f| f.to_string()))
             .collect();
 
+    let mut fonts = fonts_from_stylesheets(&all_client_output_assets).await?;
+
+    // The stylesheets of the page can declare fonts no module of the page uses,
+    // e.g. when a CSS chunk is shared with other pages. Their files aren't
+    // preloaded.
+    let mut pruned_preloads = vec![];
+    if let Some(used_font_families) = used_font_families {
+        let used_font_families = used_font_families.await?;
+        let font_file_families = font_file_families(&all_client_output_assets).await?;
+        font_paths.retain(|path| {
+            let file_name = path.rsplit('/').next().unwrap_or(path);
+            let used = font_file_families
+                .get(file_name)
+                .is_none_or(|family| used_font_families.contains(family));
+            if !used && path.contains(".p.") {
+                pruned_preloads.push(RcStr::from(path.as_str()));
+            }
+            used
+        });
+        fonts.retain(|family, _| used_font_families.contains(family));
+    }
+
     let path = if app_dir {
         node_root.join(format!("server/app{manifest_path_prefix}/next-font-manifest.json",).into())
     } else {
@@ -51,17 +82,21 @@ pub(crate) async fn create_font_manifest(
         Default::default()
     } else if app_dir {
         let dir_str = dir.to_string().await?;
-        let page_path = format!("{}{}", dir_str, original_name).into();
+        let page_path: RcStr = format!("{}{}", dir_str, original_name).into();
 
         NextFontManifest {
-            app: [(page_path, font_paths)].into_iter().collect(),
+            app: [(page_path.clone(), font_paths)].into_iter().collect(),
             app_using_size_adjust: using_size_adjust,
+            fonts,
+            pruned_preloads: pruned_preloads_entry(page_path, pruned_preloads),
             ..Default::default()
         }
     } else {
         NextFontManifest {
             pages: [(pathname.into(), font_paths)].into_iter().collect(),
             pages_using_size_adjust: using_size_adjust,
+            fonts,
+            pruned_preloads: pruned_preloads_entry(pathname.into(), pruned_preloads),
             ..Default::default()
         }
     };
@@ -77,3 +112,129 @@ pub(crate) async fn create_font_manifest(
         .await?,
     ))
 }
+
+fn pruned_preloads_entry(page: RcStr, pruned: Vec<RcStr>) -> HashMap<RcStr, Vec<RcStr>> {
+    if pruned.is_empty() {
+        HashMap::new()
+    } else {
+        [(page, pruned)].into_iter().collect()
+    }
+}
+
+/// Maps the names of the font files declared by the stylesheets emitted for
+/// the client to their font family.
+async fn font_file_families(
+    output_assets: &[ResolvedVc<Box<dyn OutputAsset>>],
+) -> Result<HashMap<RcStr, RcStr>> {
+    let mut families = HashMap::new();
+    for asset in output_assets {
+        if !asset.ident().path().await?.path.ends_with(".css") {
+            continue;
+        }
+        let AssetContent::File(file) = &*asset.content().await? else {
+            continue;
+        };
+        let FileContent::Content(file) = &*file.await? else {
+            continue;
+        };
+        families.extend(next_font_face_files(&file.content().to_str()?));
+    }
+    Ok(families)
+}
+
+/// The font families declared by the `next/font` stylesheets in the module
+/// graph of `root_modules`, i.e. the fonts used by a page.
+#[turbo_tasks::function]
+pub(crate) async fn used_font_families(root_modules: Vc<Modules>) -> Result<Vc<Vec<RcStr>>> {
+    let graph = SingleModuleGraph::new_with_entries(root_modules).await?;
+
+    let mut families = FxIndexSet::default();
+    for node in graph.iter_nodes() {
+        let Some(css_module) = ResolvedVc::try_downcast_type::<CssModuleAsset>(node.module).await?
+        else {
+            continue;
+        };
+        families.extend(module_font_families(*css_module).await?.iter().cloned());
+    }
+
+    Ok(Vc::cell(families.into_iter().collect()))
+}
+
+#[turbo_tasks::function]
+async fn module_font_families(css_module: Vc<CssModuleAsset>) -> Result<Vc<Vec<RcStr>>> {
+    if !css_module
+        .ident()
+        .path()
+        .await?
+        .path
+        .contains("internal/font/")
+    {
+        return Ok(Vc::cell(vec![]));
+    }
+    let AssetContent::File(file) = &*css_module.content().await? else {
+        return Ok(Vc::cell(vec![]));
+    };
+    let FileContent::Content(file) = &*file.await? else {
+        return Ok(Vc::cell(vec![]));
+    };
+    Ok(Vc::cell(
+        next_font_face_files(&file.content().to_str()?)
+            .into_iter()
+            .map(|(_, family)| family)
+            .collect(),
+    ))
+}
+
+/// Collects the fonts `next/font` generated, with their fallbacks, from the
+/// stylesheets emitted for the client.
+async fn fonts_from_stylesheets(
+    output_assets: &[ResolvedVc<Box<dyn OutputAsset>>],
+) -> Result<BTreeMap<RcStr, NextFontManifestFont>> {
+    let mut fonts = BTreeMap::new();
+    for asset in output_assets {
+        if !asset.ident().path().await?.path.ends_with(".css") {
+            continue;
+        }
+        let AssetContent::File(file) = &*asset.content().await? else {
+            continue;
+        };
+        let FileContent::Content(file) = &*file.await? else {
+            continue;
+        };
+        for (font_family, font) in next_font_manifest_fonts(&file.content().to_str()?) {
+            fonts.entry(font_family).or_insert(font);
+        }
+    }
+    Ok(fonts)
+}
+
+/// Collects the fallback fonts `next/font` generated for the stylesheets in the
+/// whole app, including their computed adjustments.
+#[turbo_tasks::function]
+pub(crate) async fn app_font_fallback_diagnostics(
+    project: Vc<Project>,
+) -> Result<Vc<FontFallbackDiagnostics>> {
+    let graph = project.whole_app_module_graph().await?;
+
+    let mut fallbacks: Vec<FontFallbackDiagnostic> = vec![];
+    for node in graph.iter_nodes() {
+        let Some(css_module) = ResolvedVc::try_downcast_type::<ModuleCssAsset>(node.module).await?
+        else {
+            continue;
+        };
+        let Some(stylesheet) =
+            ResolvedVc::try_downcast_type::<NextFontStylesheetSource>(css_module.await?.source)
+                .await?
+        else {
+            continue;
+        };
+        for fallback in stylesheet.fallback_diagnostics().await?.iter() {
+            // The same stylesheet is part of the client and server graphs
+            if !fallbacks.contains(fallback) {
+                fallbacks.push(fallback.clone());
+            }
+        }
+    }
+
+    Ok(Vc::cell(fallbacks))
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use serde::Serialize;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ValueToString, Vc};
+use turbo_tasks_fs::FileContent;
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    module::Module,
+};
+
+use crate::project::Project;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphExportModule {
+    /// The path of the module, prefixed with the name of its file system, e.g.
+    /// `[project]/app/page.tsx`.
+    path: RcStr,
+    layer: Option<RcStr>,
+    /// The size of the source of the module in bytes.
+    size: Option<usize>,
+    source: Option<RcStr>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphExport {
+    modules: Vec<GraphExportModule>,
+    /// The indices of the modules the app starts from.
+    entries: Vec<usize>,
+    /// References between modules, as indices of the importing and the
+    /// imported module.
+    edges: Vec<(usize, usize)>,
+}
+
+/// Serializes the module graph of the whole app for reproducing issues
+/// without the project. The sources of the modules are only included when
+/// `include_sources` is set.
+#[turbo_tasks::function]
+pub(crate) async fn graph_export(project: Vc<Project>, include_sources: bool) -> Result<Vc<RcStr>> {
+    let graph = project.whole_app_module_graph().await?;
+
+    let mut indices = HashMap::new();
+    let mut modules = vec![];
+    for (node_index, node) in graph.enumerate_nodes() {
+        let source = module_source(*node.module).await?;
+        indices.insert(node_index, modules.len());
+        modules.push(GraphExportModule {
+            path: node.module.ident().path().to_string().await?.clone_value(),
+            layer: node.layer.as_ref().map(|layer| (**layer).clone()),
+            size: source.as_ref().map(|source| source.len()),
+            source: source.filter(|_| include_sources),
+        });
+    }
+    let mut entries = graph
+        .entry_nodes()
+        .filter_map(|node| indices.get(&node).copied())
+        .collect::<Vec<_>>();
+    entries.sort_unstable();
+    let edges = graph
+        .enumerate_edges()
+        .filter_map(|(from, to)| Some((*indices.get(&from)?, *indices.get(&to)?)))
+        .collect();
+
+    Ok(Vc::cell(
+        serde_json::to_string(&GraphExport {
+            modules,
+            entries,
+            edges,
+        })?
+        .into(),
+    ))
+}
+
+async fn module_source(module: Vc<Box<dyn Module>>) -> Result<Option<RcStr>> {
+    // Some modules, e.g. CSS modules, have no content of their own and fail to
+    // read it.
+    let Ok(content) = module.content().await else {
+        return Ok(None);
+    };
+    let AssetContent::File(file) = &*content else {
+        return Ok(None);
+    };
+    let FileContent::Content(file) = &*file.await? else {
+        return Ok(None);
+    };
+    Ok(file.content().to_str().ok().map(|source| source.into()))
+}
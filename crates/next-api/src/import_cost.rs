@@ -0,0 +1,241 @@
+use std::{collections::BTreeMap, io::Write};
+
+use anyhow::Result;
+use flate2::{write::GzEncoder, Compression};
+use petgraph::graph::NodeIndex;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, Value, Vc};
+use turbo_tasks_fs::rope::Rope;
+use turbopack::css::chunk::CssChunkItem;
+use turbopack_core::{
+    chunk::{ChunkableModule, ChunkingContext},
+    module::Module,
+    reference_type::EcmaScriptModulesReferenceSubType,
+    resolve::{origin::ResolveOrigin, parse::Request},
+};
+use turbopack_ecmascript::{chunk::EcmascriptChunkItem, resolve::esm_resolve};
+
+use crate::project::Project;
+
+/// The code an import adds to a layer of the app.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+pub struct ImportCost {
+    /// The layer of the importing module, e.g. `app-client`.
+    pub layer: Option<RcStr>,
+    /// The number of modules which are only part of the app because of the
+    /// import.
+    pub modules: u64,
+    /// The size of the generated code of these modules, in bytes.
+    pub size: u64,
+    /// The size of the generated code of these modules when compressed with
+    /// gzip together, in bytes.
+    pub gzip_size: u64,
+}
+
+/// The costs of an import, one for each layer of the importing module.
+#[turbo_tasks::value(transparent)]
+pub struct ImportCosts(Vec<ImportCost>);
+
+/// The nodes reachable from `start`, without following the edges `skip`
+/// returns true for.
+fn reachable(
+    start: impl IntoIterator<Item = NodeIndex>,
+    edges: &FxHashMap<NodeIndex, Vec<NodeIndex>>,
+    skip: impl Fn(NodeIndex, NodeIndex) -> bool,
+) -> FxHashSet<NodeIndex> {
+    let mut visited = FxHashSet::default();
+    let mut queue: Vec<NodeIndex> = start.into_iter().collect();
+    while let Some(node) = queue.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        for &to in edges.get(&node).into_iter().flatten() {
+            if !skip(node, to) {
+                queue.push(to);
+            }
+        }
+    }
+    visited
+}
+
+/// The nodes which are only reachable from the `entries` through the edges
+/// from the `importers` to the `imported` nodes, i.e. which removing the
+/// import would remove from the graph.
+fn added_by_import(
+    entries: impl IntoIterator<Item = NodeIndex>,
+    edges: &FxHashMap<NodeIndex, Vec<NodeIndex>>,
+    importers: &FxHashSet<NodeIndex>,
+    imported: &FxHashSet<NodeIndex>,
+) -> FxHashSet<NodeIndex> {
+    let retained = reachable(entries, edges, |from, to| {
+        importers.contains(&from) && imported.contains(&to)
+    });
+    reachable(
+        imported
+            .iter()
+            .copied()
+            .filter(|node| !retained.contains(node)),
+        edges,
+        |_, to| retained.contains(&to),
+    )
+}
+
+/// The generated code of a module, as it's placed in a chunk.
+async fn module_code(
+    module: ResolvedVc<Box<dyn Module>>,
+    chunking_context: Vc<Box<dyn ChunkingContext>>,
+) -> Result<Option<Rope>> {
+    let Some(module) = ResolvedVc::try_sidecast::<Box<dyn ChunkableModule>>(module).await? else {
+        return Ok(None);
+    };
+    let chunk_item = module.as_chunk_item(chunking_context);
+    if let Some(chunk_item) =
+        Vc::try_resolve_downcast::<Box<dyn EcmascriptChunkItem>>(chunk_item).await?
+    {
+        return Ok(Some(chunk_item.content().await?.inner_code.clone()));
+    }
+    if let Some(chunk_item) = Vc::try_resolve_downcast::<Box<dyn CssChunkItem>>(chunk_item).await? {
+        return Ok(Some(chunk_item.content().await?.inner_code.clone()));
+    }
+    Ok(None)
+}
+
+fn gzip_size(code: &[u8]) -> Result<u64> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(code)?;
+    Ok(encoder.finish()?.len() as u64)
+}
+
+/// Returns the code the import of `specifier` in `file` adds to the app, for
+/// each client layer `file` is part of, e.g. to show the cost of imports in an
+/// editor. Modules which are also imported elsewhere in the app aren't counted,
+/// as removing the import wouldn't remove them. `file` is relative to the
+/// project directory.
+///
+/// Server layers aren't reported, as their code isn't sent to the browser. The
+/// code is generated by the client chunking context, so it's only minified
+/// when the project is.
+#[turbo_tasks::function]
+pub async fn import_cost(
+    project: Vc<Project>,
+    file: RcStr,
+    specifier: RcStr,
+) -> Result<Vc<ImportCosts>> {
+    let path = project.project_path().join(file).await?;
+    let graph = project.whole_app_module_graph().await?;
+
+    let nodes: FxHashMap<ResolvedVc<Box<dyn Module>>, NodeIndex> = graph
+        .enumerate_nodes()
+        .map(|(index, node)| (node.module, index))
+        .collect();
+    let mut edges: FxHashMap<NodeIndex, Vec<NodeIndex>> = FxHashMap::default();
+    for (from, to) in graph.enumerate_edges() {
+        edges.entry(from).or_default().push(to);
+    }
+
+    // The same file is a module in each layer, or more than one when it's split
+    // into parts by tree shaking
+    let mut imports: BTreeMap<Option<RcStr>, (FxHashSet<NodeIndex>, FxHashSet<NodeIndex>)> =
+        BTreeMap::new();
+    for (index, node) in graph.enumerate_nodes() {
+        let is_client = node
+            .layer
+            .as_deref()
+            .is_some_and(|layer| &**layer == "app-client" || &**layer == "client");
+        if !is_client || *node.module.ident().path().await? != *path {
+            continue;
+        }
+        let Some(origin) = ResolvedVc::try_sidecast::<Box<dyn ResolveOrigin>>(node.module).await?
+        else {
+            continue;
+        };
+        let imported = esm_resolve(
+            *origin,
+            Request::parse(Value::new(specifier.clone().into())),
+            Value::new(EcmaScriptModulesReferenceSubType::Import),
+            true,
+            None,
+        )
+        .primary_modules()
+        .await?;
+        let (importers, targets) = imports.entry(node.layer.as_deref().cloned()).or_default();
+        importers.insert(index);
+        targets.extend(
+            imported
+                .iter()
+                .filter_map(|module| nodes.get(module).copied()),
+        );
+    }
+
+    let chunking_context = project.client_chunking_context();
+    let mut costs = vec![];
+    for (layer, (importers, imported)) in imports {
+        if imported.is_empty() {
+            continue;
+        }
+        let added = added_by_import(graph.entry_nodes(), &edges, &importers, &imported);
+        let mut code = vec![];
+        let mut modules = 0;
+        for (index, node) in graph.enumerate_nodes() {
+            if !added.contains(&index) {
+                continue;
+            }
+            modules += 1;
+            if let Some(module_code) = module_code(node.module, chunking_context).await? {
+                code.extend_from_slice(&module_code.to_bytes()?);
+            }
+        }
+        costs.push(ImportCost {
+            layer,
+            modules,
+            size: code.len() as u64,
+            gzip_size: gzip_size(&code)?,
+        });
+    }
+    Ok(Vc::cell(costs))
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::graph::NodeIndex;
+    use rustc_hash::{FxHashMap, FxHashSet};
+
+    use super::added_by_import;
+
+    fn nodes(indices: &[usize]) -> FxHashSet<NodeIndex> {
+        indices.iter().map(|&index| NodeIndex::new(index)).collect()
+    }
+
+    #[test]
+    fn test_added_by_import() {
+        // 0 -> 1 -> 2 -> 3
+        // |    '--> 5    ^
+        // '--> 4 --------'
+        let edges: FxHashMap<NodeIndex, Vec<NodeIndex>> =
+            [(0, vec![1, 4]), (1, vec![2, 5]), (2, vec![3]), (4, vec![3])]
+                .into_iter()
+                .map(|(from, to)| {
+                    (
+                        NodeIndex::new(from),
+                        to.into_iter().map(NodeIndex::new).collect(),
+                    )
+                })
+                .collect();
+        let added = |importers: &[usize], imported: &[usize]| {
+            added_by_import(
+                [NodeIndex::new(0)],
+                &edges,
+                &nodes(importers),
+                &nodes(imported),
+            )
+        };
+
+        // 3 is also imported by 4
+        assert_eq!(added(&[1], &[2]), nodes(&[2]));
+        assert_eq!(added(&[0], &[1]), nodes(&[1, 2, 5]));
+        // 3 is also imported by 2
+        assert_eq!(added(&[4], &[3]), nodes(&[]));
+    }
+}
@@ -176,6 +176,58 @@ impl InstrumentationEndpoint {
             .await?;
         Ok(*chunk)
     }
+}
+
+#[turbo_tasks::value]
+struct InstrumentationCoreModules {
+    pub userland_module: ResolvedVc<Box<dyn Module>>,
+    pub edge_entry_module: ResolvedVc<Box<dyn Module>>,
+}
+
+#[turbo_tasks::value_impl]
+impl Endpoint for InstrumentationEndpoint {
+    #[turbo_tasks::function]
+    async fn write_to_disk(self: ResolvedVc<Self>) -> Result<Vc<WrittenEndpoint>> {
+        let span = tracing::info_span!("instrumentation endpoint");
+        async move {
+            let this = self.await?;
+            let output_assets_op = output_assets_operation(self);
+            let output_assets = output_assets_op.connect();
+            let _ = output_assets.resolve().await?;
+            let _ = this
+                .project
+                .emit_all_output_assets(output_assets_op)
+                .resolve()
+                .await?;
+
+            let server_paths = if this.project.next_mode().await?.is_development() {
+                let node_root = this.project.node_root();
+                all_server_paths(output_assets, node_root)
+                    .await?
+                    .clone_value()
+            } else {
+                vec![]
+            };
+
+            Ok(WrittenEndpoint::Edge {
+                server_paths,
+                client_paths: vec![],
+            }
+            .cell())
+        }
+        .instrument(span)
+        .await
+    }
+
+    #[turbo_tasks::function]
+    async fn server_changed(self: Vc<Self>) -> Result<Vc<Completion>> {
+        Ok(self.await?.project.server_changed(self.output_assets()))
+    }
+
+    #[turbo_tasks::function]
+    fn client_changed(self: Vc<Self>) -> Vc<Completion> {
+        Completion::immutable()
+    }
 
     #[turbo_tasks::function]
     async fn output_assets(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
@@ -233,58 +285,6 @@ impl InstrumentationEndpoint {
             Ok(Vc::cell(output_assets))
         }
     }
-}
-
-#[turbo_tasks::value]
-struct InstrumentationCoreModules {
-    pub userland_module: ResolvedVc<Box<dyn Module>>,
-    pub edge_entry_module: ResolvedVc<Box<dyn Module>>,
-}
-
-#[turbo_tasks::value_impl]
-impl Endpoint for InstrumentationEndpoint {
-    #[turbo_tasks::function]
-    async fn write_to_disk(self: ResolvedVc<Self>) -> Result<Vc<WrittenEndpoint>> {
-        let span = tracing::info_span!("instrumentation endpoint");
-        async move {
-            let this = self.await?;
-            let output_assets_op = output_assets_operation(self);
-            let output_assets = output_assets_op.connect();
-            let _ = output_assets.resolve().await?;
-            let _ = this
-                .project
-                .emit_all_output_assets(output_assets_op)
-                .resolve()
-                .await?;
-
-            let server_paths = if this.project.next_mode().await?.is_development() {
-                let node_root = this.project.node_root();
-                all_server_paths(output_assets, node_root)
-                    .await?
-                    .clone_value()
-            } else {
-                vec![]
-            };
-
-            Ok(WrittenEndpoint::Edge {
-                server_paths,
-                client_paths: vec![],
-            }
-            .cell())
-        }
-        .instrument(span)
-        .await
-    }
-
-    #[turbo_tasks::function]
-    async fn server_changed(self: Vc<Self>) -> Result<Vc<Completion>> {
-        Ok(self.await?.project.server_changed(self.output_assets()))
-    }
-
-    #[turbo_tasks::function]
-    fn client_changed(self: Vc<Self>) -> Vc<Completion> {
-        Completion::immutable()
-    }
 
     #[turbo_tasks::function]
     async fn root_modules(self: Vc<Self>) -> Result<Vc<Modules>> {
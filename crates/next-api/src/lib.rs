@@ -4,22 +4,38 @@
 #![feature(impl_trait_in_assoc_type)]
 
 mod app;
+mod browser_extension;
+mod chunk_hash_manifest;
+mod chunk_integrity_manifest;
+mod chunking_budget;
+pub mod client_module_sizes;
 mod client_references;
+mod duplicate_modules;
 mod dynamic_imports;
 mod empty;
 pub mod entrypoints;
+mod env_usage;
+mod feature_usage;
 mod font;
 pub mod global_module_id_strategy;
+mod graph_export;
+pub mod import_cost;
 mod instrumentation;
 mod loadable_manifest;
 mod middleware;
+mod module_federation;
 mod module_graph;
+pub mod module_sizes;
 mod nft_json;
 mod pages;
 pub mod paths;
 pub mod project;
+mod resource_hints;
 pub mod route;
 mod server_actions;
+mod sitemap;
+mod themes;
+mod tree_shaking_report;
 mod versioned_content_map;
 mod webpack_stats;
 
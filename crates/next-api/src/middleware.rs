@@ -120,6 +120,71 @@ impl MiddlewareEndpoint {
         Ok(edge_files)
     }
 
+    #[turbo_tasks::function]
+    fn userland_module(&self) -> Vc<Box<dyn Module>> {
+        self.asset_context
+            .process(
+                *self.source,
+                Value::new(ReferenceType::Entry(EntryReferenceSubType::Middleware)),
+            )
+            .module()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Endpoint for MiddlewareEndpoint {
+    #[turbo_tasks::function]
+    async fn write_to_disk(self: ResolvedVc<Self>) -> Result<Vc<WrittenEndpoint>> {
+        let span = tracing::info_span!("middleware endpoint");
+        async move {
+            let this = self.await?;
+            let output_assets_op = output_assets_operation(self);
+            let output_assets = output_assets_op.connect();
+            let _ = output_assets.resolve().await?;
+            let _ = this
+                .project
+                .emit_all_output_assets(output_assets_op)
+                .resolve()
+                .await?;
+
+            let (server_paths, client_paths) = if this.project.next_mode().await?.is_development() {
+                let node_root = this.project.node_root();
+                let server_paths = all_server_paths(output_assets, node_root)
+                    .await?
+                    .clone_value();
+
+                // Middleware could in theory have a client path (e.g. `new URL`).
+                let client_relative_root = this.project.client_relative_path();
+                let client_paths = all_paths_in_root(output_assets, client_relative_root)
+                    .into_future()
+                    .instrument(tracing::info_span!("client_paths"))
+                    .await?
+                    .clone_value();
+                (server_paths, client_paths)
+            } else {
+                (vec![], vec![])
+            };
+
+            Ok(WrittenEndpoint::Edge {
+                server_paths,
+                client_paths,
+            }
+            .cell())
+        }
+        .instrument(span)
+        .await
+    }
+
+    #[turbo_tasks::function]
+    async fn server_changed(self: Vc<Self>) -> Result<Vc<Completion>> {
+        Ok(self.await?.project.server_changed(self.output_assets()))
+    }
+
+    #[turbo_tasks::function]
+    fn client_changed(self: Vc<Self>) -> Vc<Completion> {
+        Completion::immutable()
+    }
+
     #[turbo_tasks::function]
     async fn output_assets(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
         let this = self.await?;
@@ -255,71 +320,6 @@ impl MiddlewareEndpoint {
         Ok(Vc::cell(output_assets))
     }
 
-    #[turbo_tasks::function]
-    fn userland_module(&self) -> Vc<Box<dyn Module>> {
-        self.asset_context
-            .process(
-                *self.source,
-                Value::new(ReferenceType::Entry(EntryReferenceSubType::Middleware)),
-            )
-            .module()
-    }
-}
-
-#[turbo_tasks::value_impl]
-impl Endpoint for MiddlewareEndpoint {
-    #[turbo_tasks::function]
-    async fn write_to_disk(self: ResolvedVc<Self>) -> Result<Vc<WrittenEndpoint>> {
-        let span = tracing::info_span!("middleware endpoint");
-        async move {
-            let this = self.await?;
-            let output_assets_op = output_assets_operation(self);
-            let output_assets = output_assets_op.connect();
-            let _ = output_assets.resolve().await?;
-            let _ = this
-                .project
-                .emit_all_output_assets(output_assets_op)
-                .resolve()
-                .await?;
-
-            let (server_paths, client_paths) = if this.project.next_mode().await?.is_development() {
-                let node_root = this.project.node_root();
-                let server_paths = all_server_paths(output_assets, node_root)
-                    .await?
-                    .clone_value();
-
-                // Middleware could in theory have a client path (e.g. `new URL`).
-                let client_relative_root = this.project.client_relative_path();
-                let client_paths = all_paths_in_root(output_assets, client_relative_root)
-                    .into_future()
-                    .instrument(tracing::info_span!("client_paths"))
-                    .await?
-                    .clone_value();
-                (server_paths, client_paths)
-            } else {
-                (vec![], vec![])
-            };
-
-            Ok(WrittenEndpoint::Edge {
-                server_paths,
-                client_paths,
-            }
-            .cell())
-        }
-        .instrument(span)
-        .await
-    }
-
-    #[turbo_tasks::function]
-    async fn server_changed(self: Vc<Self>) -> Result<Vc<Completion>> {
-        Ok(self.await?.project.server_changed(self.output_assets()))
-    }
-
-    #[turbo_tasks::function]
-    fn client_changed(self: Vc<Self>) -> Vc<Completion> {
-        Completion::immutable()
-    }
-
     #[turbo_tasks::function]
     async fn root_modules(self: Vc<Self>) -> Result<Vc<Modules>> {
         Ok(Vc::cell(vec![self.userland_module().to_resolved().await?]))
@@ -0,0 +1,151 @@
+use anyhow::{bail, Result};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, ResolvedVc, Value, ValueToString, Vc};
+use turbo_tasks_fs::{File, FileContent};
+use turbopack_core::{
+    asset::AssetContent,
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkingContext, EvaluatableAsset, EvaluatableAssets,
+    },
+    context::AssetContext,
+    module::Module,
+    output::{OutputAsset, OutputAssets},
+    reference_type::{EntryReferenceSubType, ReferenceType},
+    virtual_output::VirtualOutputAsset,
+    virtual_source::VirtualSource,
+};
+use turbopack_ecmascript::federation::{container_code, FEDERATION_DIR};
+
+use crate::project::Project;
+
+/// Builds `static/remoteEntry.js`, the remote entry of the container exposing
+/// the modules configured in `experimental.turbo.moduleFederation.exposes`.
+/// The remote entry loads the chunks of the container, which register the
+/// exposed modules with the federation runtime of the page.
+///
+/// Returns no assets when no modules are exposed.
+#[turbo_tasks::function]
+async fn module_federation_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    let Some(options) = *project
+        .next_config()
+        .module_federation_options(project.project_path())
+        .await?
+    else {
+        return Ok(OutputAssets::empty());
+    };
+    let options = options.await?;
+    let Some(name) = &options.name else {
+        return Ok(OutputAssets::empty());
+    };
+    if options.exposes.is_empty() {
+        return Ok(OutputAssets::empty());
+    }
+
+    // The container is placed in a directory of the project, so exposed modules
+    // are imported relative to its parent.
+    let exposes: FxIndexMap<RcStr, RcStr> = options
+        .exposes
+        .iter()
+        .map(|(module, path)| {
+            (
+                module.clone(),
+                format!("../{}", path.trim_start_matches("./")).into(),
+            )
+        })
+        .collect();
+    let container_path = project
+        .project_path()
+        .join(format!("{FEDERATION_DIR}/container.js").into());
+    let source = VirtualSource::new(
+        container_path,
+        AssetContent::file(FileContent::Content(container_code(name, &exposes)?.into()).cell()),
+    );
+    let module = project
+        .pages_project()
+        .client_module_context()
+        .process(
+            Vc::upcast(source),
+            Value::new(ReferenceType::Entry(EntryReferenceSubType::Undefined)),
+        )
+        .module()
+        .to_resolved()
+        .await?;
+    let Some(module) = ResolvedVc::try_sidecast::<Box<dyn EvaluatableAsset>>(module).await? else {
+        bail!(
+            "The module federation container {} can't be evaluated",
+            container_path.to_string().await?
+        );
+    };
+    let chunk_group = project
+        .client_chunking_context()
+        .evaluated_chunk_group(
+            module.ident(),
+            EvaluatableAssets::one(module),
+            Value::new(AvailabilityInfo::Root),
+        )
+        .await?;
+
+    let static_path = project.client_relative_path().join("static".into());
+    let static_path_value = static_path.await?;
+    let chunks = chunk_group.assets.await?;
+    let mut js: Vec<RcStr> = vec![];
+    let mut css: Vec<RcStr> = vec![];
+    for chunk in chunks.iter() {
+        let path = chunk.ident().path().await?;
+        let Some(relative_path) = static_path_value.get_path_to(&path) else {
+            continue;
+        };
+        match path.extension_ref() {
+            Some("js") => js.push(relative_path.into()),
+            Some("css") => css.push(relative_path.into()),
+            _ => {}
+        }
+    }
+
+    let mut assets: Vec<ResolvedVc<Box<dyn OutputAsset>>> = chunks.iter().copied().collect();
+    assets.push(ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            static_path
+                .join("remoteEntry.js".into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(remote_entry_code(&js, &css)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    ));
+    Ok(Vc::cell(assets))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn module_federation_assets_operation(project: ResolvedVc<Project>) -> Vc<OutputAssets> {
+    module_federation_assets(*project)
+}
+
+/// Returns code loading the chunks of the container, relative to the URL of
+/// the remote entry. Scripts are executed in order, so the chunk evaluating
+/// the container is only executed after the other chunks registered.
+fn remote_entry_code(js: &[RcStr], css: &[RcStr]) -> Result<String> {
+    Ok(format!(
+        r#"(() => {{
+  const base = document.currentScript.src;
+  for (const file of {css}) {{
+    const link = document.createElement("link");
+    link.rel = "stylesheet";
+    link.href = new URL(file, base).href;
+    document.head.appendChild(link);
+  }}
+  for (const file of {js}) {{
+    const script = document.createElement("script");
+    script.src = new URL(file, base).href;
+    script.async = false;
+    document.head.appendChild(script);
+  }}
+}})();
+"#,
+        css = serde_json::to_string(css)?,
+        js = serde_json::to_string(js)?,
+    ))
+}
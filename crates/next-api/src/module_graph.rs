@@ -416,6 +416,30 @@ impl SingleModuleGraph {
             .map(move |idx| (idx, self.graph.node_weight(idx).unwrap()))
     }
 
+    /// Enumerate over all edges in the graph, as pairs of the source and target
+    /// node.
+    pub fn enumerate_edges(&self) -> impl Iterator<Item = (NodeIndex, NodeIndex)> + '_ {
+        self.graph
+            .edge_indices()
+            .filter_map(move |edge| self.graph.edge_endpoints(edge))
+    }
+
+    /// Enumerate over all edges in the graph, as the source and target node and
+    /// the chunking type of the reference.
+    pub fn enumerate_edges_with_chunking_type(
+        &self,
+    ) -> impl Iterator<Item = (NodeIndex, NodeIndex, &'_ ChunkingType)> + '_ {
+        self.graph.edge_indices().filter_map(move |edge| {
+            let (from, to) = self.graph.edge_endpoints(edge)?;
+            Some((from, to, self.graph.edge_weight(edge)?))
+        })
+    }
+
+    /// The nodes of the entries of the graph.
+    pub fn entry_nodes(&self) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.entries.values().copied()
+    }
+
     /// Traverses all reachable nodes (once)
     pub fn traverse_from_entry<'a>(
         &'a self,
@@ -542,7 +566,7 @@ impl SingleModuleGraph {
 #[turbo_tasks::value_impl]
 impl SingleModuleGraph {
     #[turbo_tasks::function]
-    async fn new_with_entries(entries: Vc<Modules>) -> Result<Vc<Self>> {
+    pub(crate) async fn new_with_entries(entries: Vc<Modules>) -> Result<Vc<Self>> {
         SingleModuleGraph::new_inner(None, &*entries.await?, &Default::default()).await
     }
 
@@ -1114,13 +1138,9 @@ async fn get_reduced_graphs_for_endpoint_inner_operation(
         NextMode::Build => (
             false,
             vec![
-                async move {
-                    SingleModuleGraph::new_with_entries(project.get_all_entries())
-                        .to_resolved()
-                        .await
-                }
-                .instrument(tracing::info_span!("module graph for app"))
-                .await?,
+                async move { project.whole_app_module_graph().to_resolved().await }
+                    .instrument(tracing::info_span!("module graph for app"))
+                    .await?,
             ],
         ),
     };
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, ValueToString};
+use turbopack_browser::ecmascript::EcmascriptDevChunk;
+use turbopack_core::{
+    chunk::{Chunk, ChunkItem, ChunkType},
+    module::Module,
+};
+
+/// A module in the client chunks, with the size of its generated code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleSize {
+    /// The path of the module, prefixed with the name of its file system, e.g.
+    /// `[project]/node_modules/date-fns/index.js`.
+    pub path: RcStr,
+    /// The name of the package containing the module, if it's in
+    /// `node_modules`.
+    pub package: Option<RcStr>,
+    /// The size of the generated code of the module, in bytes. It's rounded,
+    /// so it's only an estimate.
+    pub size: u64,
+}
+
+/// Returns the modules of a client chunk with their size, in the order of the
+/// chunk items.
+pub(crate) async fn chunk_module_sizes(
+    chunk: ResolvedVc<EcmascriptDevChunk>,
+) -> Result<Vec<(ResolvedVc<Box<dyn Module>>, ModuleSize)>> {
+    let mut modules = vec![];
+    for item in chunk.chunk().chunk_items().await? {
+        let module = item.module().to_resolved().await?;
+        let size = *item
+            .ty()
+            .chunk_item_size(item.chunking_context(), **item, None)
+            .await?;
+        let path = module.ident().path().to_string().await?.clone_value();
+        modules.push((
+            module,
+            ModuleSize {
+                package: package_of(&path).map(|(name, _)| name.into()),
+                path,
+                size: size as u64,
+            },
+        ));
+    }
+    Ok(modules)
+}
+
+/// Returns the name and the directory of the package containing the module at
+/// `path`, e.g. `react-dom` and `[project]/node_modules/react-dom`.
+pub(crate) fn package_of(path: &str) -> Option<(&str, &str)> {
+    let index = path.rfind("/node_modules/")? + "/node_modules/".len();
+    let rest = &path[index..];
+    let mut segments = rest.splitn(3, '/');
+    let first = segments.next()?;
+    let name_len = if first.starts_with('@') {
+        first.len() + 1 + segments.next()?.len()
+    } else {
+        first.len()
+    };
+    Some((&rest[..name_len], &path[..index + name_len]))
+}
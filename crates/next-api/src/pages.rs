@@ -56,7 +56,12 @@ use turbopack_core::{
     module::{Module, Modules},
     output::{OptionOutputAsset, OutputAsset, OutputAssets},
     reference_type::{EcmaScriptModulesReferenceSubType, EntryReferenceSubType, ReferenceType},
-    resolve::{origin::PlainResolveOrigin, parse::Request, pattern::Pattern},
+    resolve::{
+        options::{ImportMap, ImportMapping},
+        origin::PlainResolveOrigin,
+        parse::Request,
+        pattern::Pattern,
+    },
     source::Source,
     virtual_output::VirtualOutputAsset,
 };
@@ -64,10 +69,11 @@ use turbopack_ecmascript::resolve::esm_resolve;
 use turbopack_nodejs::NodeJsChunkingContext;
 
 use crate::{
+    chunking_budget::chunking_budget_report,
     dynamic_imports::{
         collect_next_dynamic_chunks, DynamicImportedChunks, NextDynamicChunkAvailability,
     },
-    font::create_font_manifest,
+    font::{create_font_manifest, used_font_families},
     loadable_manifest::create_react_loadable_manifest,
     module_graph::get_reduced_graphs_for_endpoint,
     nft_json::NftJsonAsset,
@@ -76,6 +82,7 @@ use crate::{
         get_wasm_paths_from_root, paths_to_bindings, wasm_paths_to_bindings,
     },
     project::Project,
+    resource_hints::collect_resource_hints,
     route::{Endpoint, Route, Routes, WrittenEndpoint},
     webpack_stats::generate_webpack_stats,
 };
@@ -266,7 +273,7 @@ impl PagesProject {
     }
 
     #[turbo_tasks::function]
-    fn pages_structure(&self) -> Vc<PagesStructure> {
+    pub(crate) fn pages_structure(&self) -> Vc<PagesStructure> {
         let next_router_fs = Vc::upcast::<Box<dyn FileSystem>>(VirtualFileSystem::new());
         let next_router_root = next_router_fs.root();
         find_pages_structure(
@@ -357,6 +364,43 @@ impl PagesProject {
         ))
     }
 
+    /// Like [PagesProject::client_module_context], with the import map aliases
+    /// of a theme from `experimental.turbo.themes` added. The modules are put in
+    /// a layer of their own, so that they don't collide with the ones of the
+    /// app.
+    #[turbo_tasks::function]
+    pub(super) async fn theme_client_module_context(
+        self: Vc<Self>,
+        theme: RcStr,
+    ) -> Result<Vc<Box<dyn AssetContext>>> {
+        let themes = self.project().next_config().themes().await?;
+        let aliases = themes
+            .get(&theme)
+            .with_context(|| format!("The theme {theme} isn't configured"))?;
+        let project_path = self.project().project_path().to_resolved().await?;
+        let mut resolve_options_context =
+            self.client_resolve_options_context().await?.clone_value();
+        let mut import_map = match resolve_options_context.import_map {
+            Some(import_map) => import_map.await?.clone_value(),
+            None => ImportMap::empty(),
+        };
+        for (alias, request) in aliases {
+            import_map.insert_exact_alias(
+                alias.clone(),
+                ImportMapping::PrimaryAlternative(request.clone(), Some(project_path))
+                    .resolved_cell(),
+            );
+        }
+        resolve_options_context.import_map = Some(import_map.resolved_cell());
+        Ok(Vc::upcast(ModuleAssetContext::new(
+            self.transitions(),
+            self.project().client_compile_time_info(),
+            self.client_module_options_context(),
+            resolve_options_context.cell(),
+            Vc::cell(format!("client-theme-{theme}").into()),
+        )))
+    }
+
     #[turbo_tasks::function]
     pub(super) fn ssr_module_context(self: Vc<Self>) -> Vc<ModuleAssetContext> {
         ModuleAssetContext::new(
@@ -1105,8 +1149,21 @@ impl PageEndpoint {
     ) -> Result<Vc<Box<dyn OutputAsset>>> {
         let node_root = self.pages_project.project().node_root();
         let client_relative_path = self.pages_project.project().client_relative_path();
+        let pathname = self.pathname.await?.clone_value();
+        let resource_hints = collect_resource_hints(&client_chunks.await?).await?;
         let build_manifest = BuildManifest {
-            pages: fxindexmap!(self.pathname.await?.clone_value() => client_chunks),
+            resource_hints: if resource_hints.is_empty() {
+                Default::default()
+            } else {
+                fxindexmap!(pathname.clone() => resource_hints)
+            },
+            pages: fxindexmap!(pathname => client_chunks),
+            esm_chunks: *self
+                .pages_project
+                .project()
+                .next_config()
+                .esm_chunks()
+                .await?,
             ..Default::default()
         };
         let manifest_path_prefix = get_asset_prefix_from_pathname(&self.pathname.await?);
@@ -1152,6 +1209,17 @@ impl PageEndpoint {
 
         let manifest_path_prefix = get_asset_prefix_from_pathname(&pathname);
         let node_root = this.pages_project.project().node_root();
+        let used_font_families = if this
+            .pages_project
+            .project()
+            .next_mode()
+            .await?
+            .is_production()
+        {
+            Some(used_font_families(self.root_modules()))
+        } else {
+            None
+        };
         let next_font_manifest_output = create_font_manifest(
             this.pages_project.project().client_root(),
             node_root,
@@ -1161,10 +1229,42 @@ impl PageEndpoint {
             &pathname,
             *client_assets,
             false,
+            used_font_families,
         )
         .await?;
         server_assets.push(next_font_manifest_output);
 
+        if let Some(budget) = &*this
+            .pages_project
+            .project()
+            .next_config()
+            .chunking_budget()
+            .await?
+        {
+            if matches!(this.ty, PageEndpointType::Html)
+                && this
+                    .pages_project
+                    .project()
+                    .next_mode()
+                    .await?
+                    .is_production()
+            {
+                server_assets.push(
+                    chunking_budget_report(
+                        original_name.to_owned(),
+                        *this.page.await?.base_path,
+                        &client_assets.await?,
+                        budget,
+                        node_root.join(
+                            format!("server/pages{manifest_path_prefix}/chunking-budget.json")
+                                .into(),
+                        ),
+                    )
+                    .await?,
+                );
+            }
+        }
+
         if *this
             .pages_project
             .project()
@@ -1429,6 +1529,11 @@ impl Endpoint for PageEndpoint {
             .client_changed(self.output().client_assets()))
     }
 
+    #[turbo_tasks::function]
+    fn output_assets(self: Vc<Self>) -> Vc<OutputAssets> {
+        self.output().output_assets()
+    }
+
     #[turbo_tasks::function]
     async fn root_modules(self: Vc<Self>) -> Result<Vc<Modules>> {
         let this = self.await?;
@@ -10,7 +10,10 @@ use next_core::{
     instrumentation::instrumentation_files,
     middleware::middleware_files,
     mode::NextMode,
-    next_client::{get_client_chunking_context, get_client_compile_time_info},
+    next_client::{
+        get_browser_extension_chunking_context, get_client_chunking_context,
+        get_client_compile_time_info,
+    },
     next_config::{JsConfig, ModuleIdStrategy as ModuleIdStrategyConfig, NextConfig},
     next_server::{
         get_server_chunking_context, get_server_chunking_context_with_client_assets,
@@ -19,6 +22,7 @@ use next_core::{
     },
     next_telemetry::NextFeatureTelemetry,
     util::NextRuntime,
+    FontFallbackDiagnostics,
 };
 use serde::{Deserialize, Serialize};
 use tracing::Instrument;
@@ -28,8 +32,9 @@ use turbo_tasks::{
     fxindexmap,
     graph::{AdjacencyMap, GraphTraversal},
     trace::TraceRawVcs,
-    Completion, Completions, FxIndexMap, IntoTraitRef, NonLocalValue, OperationValue, OperationVc,
-    ReadRef, ResolvedVc, State, TaskInput, TransientInstance, TryFlatJoinIterExt, Value, Vc,
+    Completion, Completions, FxIndexMap, FxIndexSet, IntoTraitRef, NonLocalValue, OperationValue,
+    OperationVc, ReadRef, ResolvedVc, State, TaskInput, TransientInstance, TryFlatJoinIterExt,
+    Value, Vc,
 };
 use turbo_tasks_env::{EnvMap, ProcessEnv};
 use turbo_tasks_fs::{DiskFileSystem, FileSystem, FileSystemPath, VirtualFileSystem};
@@ -61,14 +66,28 @@ use turbopack_nodejs::NodeJsChunkingContext;
 
 use crate::{
     app::{AppProject, OptionAppProject, ECMASCRIPT_CLIENT_TRANSITION_NAME},
+    browser_extension::browser_extension_assets_operation,
     build,
+    chunk_hash_manifest::chunk_hash_manifest_assets_operation,
+    chunk_integrity_manifest::chunk_integrity_manifest_assets_operation,
+    duplicate_modules::duplicate_modules_assets_operation,
     empty::EmptyEndpoint,
     entrypoints::Entrypoints,
+    env_usage::env_usage_assets_operation,
+    feature_usage::feature_usage_assets_operation,
+    font::app_font_fallback_diagnostics,
     global_module_id_strategy::GlobalModuleIdStrategyBuilder,
+    graph_export::graph_export,
+    import_cost::{import_cost, ImportCosts},
     instrumentation::InstrumentationEndpoint,
     middleware::MiddlewareEndpoint,
+    module_federation::module_federation_assets_operation,
+    module_graph::SingleModuleGraph,
     pages::PagesProject,
-    route::{AppPageRoute, Endpoint, Route},
+    route::{Endpoint, Route},
+    sitemap::sitemap_assets_operation,
+    themes::theme_assets_operation,
+    tree_shaking_report::tree_shaking_report_assets_operation,
     versioned_content_map::VersionedContentMap,
 };
 
@@ -169,6 +188,11 @@ pub struct ProjectOptions {
 
     /// The browserslist query to use for targeting browsers.
     pub browserslist_query: RcStr,
+
+    /// Disable minification and annotate each module in the emitted chunks
+    /// with its path and package version.
+    #[serde(default)]
+    pub annotate_chunks: bool,
 }
 
 #[derive(
@@ -393,6 +417,7 @@ impl ProjectContainer {
         let build_id;
         let preview_props;
         let browserslist_query;
+        let annotate_chunks;
         {
             let options = self.options_state.get();
             let options = options
@@ -415,6 +440,7 @@ impl ProjectContainer {
             build_id = options.build_id.clone();
             preview_props = options.preview_props.clone();
             browserslist_query = options.browserslist_query.clone();
+            annotate_chunks = options.annotate_chunks;
         }
 
         let dist_dir = next_config
@@ -423,9 +449,22 @@ impl ProjectContainer {
             .as_ref()
             .map_or_else(|| ".next".into(), |d| d.clone());
 
+        let project_relative_path = project_path
+            .strip_prefix(&*root_path)
+            .context("Project path need to be in root path")?;
+        let project_relative_path = project_relative_path
+            .strip_prefix(MAIN_SEPARATOR)
+            .unwrap_or(project_relative_path)
+            .replace(MAIN_SEPARATOR, "/")
+            .into();
+
         Ok(Project {
-            root_path,
-            project_path,
+            location: ProjectLocation {
+                root_path,
+                project_path,
+            }
+            .resolved_cell(),
+            project_relative_path,
             watch,
             next_config: next_config.to_resolved().await?,
             js_config: js_config.to_resolved().await?,
@@ -433,6 +472,7 @@ impl ProjectContainer {
             env: ResolvedVc::upcast(env_map.to_resolved().await?),
             define_env: define_env.to_resolved().await?,
             browserslist_query,
+            annotate_chunks,
             mode: if dev {
                 NextMode::Development.resolved_cell()
             } else {
@@ -458,6 +498,84 @@ impl ProjectContainer {
         self.project().hmr_identifiers()
     }
 
+    /// See [Project::emit_sitemap].
+    #[turbo_tasks::function]
+    pub fn emit_sitemap(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_sitemap()
+    }
+
+    /// See [Project::emit_feature_usage_report].
+    #[turbo_tasks::function]
+    pub fn emit_feature_usage_report(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_feature_usage_report()
+    }
+
+    /// See [Project::emit_env_usage_report].
+    #[turbo_tasks::function]
+    pub fn emit_env_usage_report(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_env_usage_report()
+    }
+
+    /// See [Project::emit_duplicate_modules_report].
+    #[turbo_tasks::function]
+    pub fn emit_duplicate_modules_report(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_duplicate_modules_report()
+    }
+
+    /// See [Project::emit_themes].
+    #[turbo_tasks::function]
+    pub fn emit_themes(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_themes()
+    }
+
+    /// See [Project::emit_browser_extension].
+    #[turbo_tasks::function]
+    pub fn emit_browser_extension(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_browser_extension()
+    }
+
+    /// See [Project::emit_chunk_hash_manifest].
+    #[turbo_tasks::function]
+    pub fn emit_chunk_hash_manifest(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_chunk_hash_manifest()
+    }
+
+    /// See [Project::emit_chunk_integrity_manifest].
+    #[turbo_tasks::function]
+    pub fn emit_chunk_integrity_manifest(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_chunk_integrity_manifest()
+    }
+
+    /// See [Project::emit_module_federation].
+    #[turbo_tasks::function]
+    pub fn emit_module_federation(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().emit_module_federation()
+    }
+
+    /// See [Project::output_paths].
+    #[turbo_tasks::function]
+    pub fn output_paths(self: Vc<Self>) -> Vc<Vec<RcStr>> {
+        self.project().output_paths()
+    }
+
+    /// See [Project::font_fallbacks].
+    #[turbo_tasks::function]
+    pub fn font_fallbacks(self: Vc<Self>) -> Vc<FontFallbackDiagnostics> {
+        self.project().font_fallbacks()
+    }
+
+    /// See [Project::graph_export].
+    #[turbo_tasks::function]
+    pub fn graph_export(self: Vc<Self>, include_sources: bool) -> Vc<RcStr> {
+        self.project().graph_export(include_sources)
+    }
+
+    /// See [Project::import_cost].
+    #[turbo_tasks::function]
+    pub fn import_cost(self: Vc<Self>, file: RcStr, specifier: RcStr) -> Vc<ImportCosts> {
+        self.project().import_cost(file, specifier)
+    }
+
     /// Gets a source map for a particular `file_path`. If `dev` mode is
     /// disabled, this will always return [`OptionSourceMap::none`].
     #[turbo_tasks::function]
@@ -474,18 +592,31 @@ impl ProjectContainer {
     }
 }
 
+/// Where a [Project] is on disk. It's kept in a cell of its own, so that the
+/// tasks reading the project don't depend on its location, and moving the
+/// project only invalidates the file systems created from it.
 #[turbo_tasks::value]
-pub struct Project {
+struct ProjectLocation {
     /// A root path from which all files must be nested under. Trying to access
     /// a file outside this root will fail. Think of this as a chroot.
     root_path: RcStr,
 
+    /// A path inside the root_path which contains the app/pages directories.
+    project_path: RcStr,
+}
+
+#[turbo_tasks::value]
+pub struct Project {
+    /// The absolute root and project paths.
+    location: ResolvedVc<ProjectLocation>,
+
+    /// The path of the directory containing the app/pages directories,
+    /// relative to the root and with `/` as separator.
+    project_relative_path: RcStr,
+
     /// A path where to emit the build outputs. next.config.js's distDir.
     dist_dir: RcStr,
 
-    /// A path inside the root_path which contains the app/pages directories.
-    pub project_path: RcStr,
-
     /// Filesystem watcher options.
     watch: WatchOptions,
 
@@ -505,6 +636,10 @@ pub struct Project {
     /// The browserslist query to use for targeting browsers.
     browserslist_query: RcStr,
 
+    /// Disable minification and annotate each module in the emitted chunks
+    /// with its path and package version.
+    annotate_chunks: bool,
+
     mode: ResolvedVc<NextMode>,
 
     versioned_content_map: Option<ResolvedVc<VersionedContentMap>>,
@@ -594,13 +729,32 @@ impl Project {
         PagesProject::new(self)
     }
 
+    /// The absolute root path, in a cell of its own so that reading it only
+    /// depends on changes of the root.
+    #[turbo_tasks::function]
+    async fn absolute_root_path(&self) -> Result<Vc<RcStr>> {
+        Ok(Vc::cell(self.location.await?.root_path.clone()))
+    }
+
+    /// The absolute project path, see [Project::absolute_root_path].
+    #[turbo_tasks::function]
+    async fn absolute_project_path(&self) -> Result<Vc<RcStr>> {
+        Ok(Vc::cell(self.location.await?.project_path.clone()))
+    }
+
+    /// The file system is created in this task instead of through
+    /// [DiskFileSystem::new], which is keyed by the absolute root, so that the
+    /// tasks taking paths of the project as arguments aren't keyed by its
+    /// location on disk. Moving the project only re-runs this task and the
+    /// reads of the file system, which find the same contents.
     #[turbo_tasks::function]
-    pub fn project_fs(&self) -> Vc<DiskFileSystem> {
-        DiskFileSystem::new(
+    pub async fn project_fs(self: Vc<Self>) -> Result<Vc<DiskFileSystem>> {
+        let root_path = self.absolute_root_path().await?;
+        Ok(DiskFileSystem::cell(DiskFileSystem::create(
             PROJECT_FILESYSTEM_NAME.into(),
-            self.root_path.clone(),
+            root_path.clone_value(),
             vec![],
-        )
+        )))
     }
 
     #[turbo_tasks::function]
@@ -609,9 +763,15 @@ impl Project {
         Vc::upcast(virtual_fs)
     }
 
+    /// See [Project::project_fs].
     #[turbo_tasks::function]
-    pub fn output_fs(&self) -> Vc<DiskFileSystem> {
-        DiskFileSystem::new("output".into(), self.project_path.clone(), vec![])
+    pub async fn output_fs(self: Vc<Self>) -> Result<Vc<DiskFileSystem>> {
+        let project_path = self.absolute_project_path().await?;
+        Ok(DiskFileSystem::cell(DiskFileSystem::create(
+            "output".into(),
+            project_path.clone_value(),
+            vec![],
+        )))
     }
 
     #[turbo_tasks::function]
@@ -662,13 +822,9 @@ impl Project {
     #[turbo_tasks::function]
     pub async fn project_path(self: Vc<Self>) -> Result<Vc<FileSystemPath>> {
         let this = self.await?;
-        let root = self.project_root_path();
-        let project_relative = this.project_path.strip_prefix(&*this.root_path).unwrap();
-        let project_relative = project_relative
-            .strip_prefix(MAIN_SEPARATOR)
-            .unwrap_or(project_relative)
-            .replace(MAIN_SEPARATOR, "/");
-        Ok(root.join(project_relative.into()))
+        Ok(self
+            .project_root_path()
+            .join(this.project_relative_path.clone()))
     }
 
     #[turbo_tasks::function]
@@ -721,7 +877,9 @@ impl Project {
             self.project_path(),
             node_execution_chunking_context,
             self.env(),
-        ))
+        )
+        .with_timeouts(self.next_config().worker_timeouts())
+        .with_loader_permissions(self.next_config().loader_permissions(self.project_path())))
     }
 
     #[turbo_tasks::function]
@@ -729,6 +887,14 @@ impl Project {
         get_client_compile_time_info(self.browserslist_query.clone(), self.define_env.client())
     }
 
+    /// The module graph of all the entries of the app. `next build` reduces it
+    /// to the graphs of the endpoints, and the reports about the whole app
+    /// read it, so it's only computed once.
+    #[turbo_tasks::function]
+    pub(crate) fn whole_app_module_graph(self: Vc<Self>) -> Vc<SingleModuleGraph> {
+        SingleModuleGraph::new_with_entries(self.get_all_entries())
+    }
+
     #[turbo_tasks::function]
     pub async fn get_all_entries(self: Vc<Self>) -> Result<Vc<Modules>> {
         let mut modules = Vec::new();
@@ -747,52 +913,8 @@ impl Project {
         let entrypoints = self.entrypoints().await?;
 
         modules.extend(self.client_main_modules().await?.iter().copied());
-        add_endpoint(*entrypoints.pages_error_endpoint, &mut modules).await?;
-        add_endpoint(*entrypoints.pages_app_endpoint, &mut modules).await?;
-        add_endpoint(*entrypoints.pages_document_endpoint, &mut modules).await?;
-
-        if let Some(middleware) = &entrypoints.middleware {
-            add_endpoint(middleware.endpoint, &mut modules).await?;
-        }
-
-        if let Some(instrumentation) = &entrypoints.instrumentation {
-            let node_js = instrumentation.node_js;
-            let edge = instrumentation.edge;
-            add_endpoint(node_js, &mut modules).await?;
-            add_endpoint(edge, &mut modules).await?;
-        }
-
-        for (_, route) in entrypoints.routes.iter() {
-            match route {
-                Route::Page {
-                    html_endpoint,
-                    data_endpoint: _,
-                } => {
-                    add_endpoint(**html_endpoint, &mut modules).await?;
-                }
-                Route::PageApi { endpoint } => {
-                    add_endpoint(**endpoint, &mut modules).await?;
-                }
-                Route::AppPage(page_routes) => {
-                    for AppPageRoute {
-                        original_name: _,
-                        html_endpoint,
-                        rsc_endpoint: _,
-                    } in page_routes
-                    {
-                        add_endpoint(*html_endpoint, &mut modules).await?;
-                    }
-                }
-                Route::AppRoute {
-                    original_name: _,
-                    endpoint,
-                } => {
-                    add_endpoint(**endpoint, &mut modules).await?;
-                }
-                Route::Conflict => {
-                    tracing::info!("WARN: conflict");
-                }
-            }
+        for endpoint in entrypoints.all_endpoints() {
+            add_endpoint(endpoint, &mut modules).await?;
         }
 
         Ok(Vc::cell(modules))
@@ -830,6 +952,16 @@ impl Project {
         Vc::cell(edge_env)
     }
 
+    /// Whether to minify the emitted chunks. Annotated chunks aren't minified,
+    /// as that would strip the annotations.
+    #[turbo_tasks::function]
+    async fn turbo_minify(self: Vc<Self>) -> Result<Vc<bool>> {
+        if self.await?.annotate_chunks {
+            return Ok(Vc::cell(false));
+        }
+        Ok(self.next_config().turbo_minify(self.next_mode()))
+    }
+
     #[turbo_tasks::function]
     pub(super) fn client_chunking_context(self: Vc<Self>) -> Vc<Box<dyn ChunkingContext>> {
         get_client_chunking_context(
@@ -837,10 +969,40 @@ impl Project {
             self.client_relative_path(),
             Vc::cell("/ROOT".into()),
             self.next_config().computed_asset_prefix(),
+            self.next_config().asset_prefix_fallback(),
             self.client_compile_time_info().environment(),
             self.next_mode(),
             self.module_id_strategy(),
-            self.next_config().turbo_minify(self.next_mode()),
+            self.next_config().chunk_cache_groups(),
+            self.next_config().chunk_module_groups(self.project_path()),
+            self.next_config().chunk_size_limits(),
+            self.turbo_minify(),
+            self.next_config().content_hashing(self.next_mode()),
+            self.next_config().runtime_chunk(),
+            self.next_config().esm_chunks(),
+            self.next_config().chunk_loading_global(),
+            self.next_config().css_chunk_splitting(),
+            self.next_config().max_css_chunk_size(),
+        )
+    }
+
+    /// The directory the browser extension configured through
+    /// `experimental.turbo.browserExtension` is emitted to.
+    #[turbo_tasks::function]
+    pub fn browser_extension_root(self: Vc<Self>) -> Vc<FileSystemPath> {
+        self.node_root().join("extension".into())
+    }
+
+    #[turbo_tasks::function]
+    pub(super) fn browser_extension_chunking_context(
+        self: Vc<Self>,
+    ) -> Vc<Box<dyn ChunkingContext>> {
+        get_browser_extension_chunking_context(
+            self.project_root_path(),
+            self.browser_extension_root(),
+            Vc::cell("/ROOT".into()),
+            self.module_id_strategy(),
+            self.turbo_minify(),
         )
     }
 
@@ -859,7 +1021,7 @@ impl Project {
                 self.next_config().computed_asset_prefix(),
                 self.server_compile_time_info().environment(),
                 self.module_id_strategy(),
-                self.next_config().turbo_minify(self.next_mode()),
+                self.turbo_minify(),
             )
         } else {
             get_server_chunking_context(
@@ -869,7 +1031,7 @@ impl Project {
                 self.node_root_to_root_path(),
                 self.server_compile_time_info().environment(),
                 self.module_id_strategy(),
-                self.next_config().turbo_minify(self.next_mode()),
+                self.turbo_minify(),
             )
         }
     }
@@ -889,7 +1051,7 @@ impl Project {
                 self.next_config().computed_asset_prefix(),
                 self.edge_compile_time_info().environment(),
                 self.module_id_strategy(),
-                self.next_config().turbo_minify(self.next_mode()),
+                self.turbo_minify(),
             )
         } else {
             get_edge_chunking_context(
@@ -899,7 +1061,7 @@ impl Project {
                 self.node_root_to_root_path(),
                 self.edge_compile_time_info().environment(),
                 self.module_id_strategy(),
-                self.next_config().turbo_minify(self.next_mode()),
+                self.turbo_minify(),
             )
         }
     }
@@ -1356,6 +1518,244 @@ impl Project {
         .await
     }
 
+    /// Emits the `sitemap.xml` and `robots.txt` configured through
+    /// `experimental.sitemap`, returning their paths relative to the dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_sitemap(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = sitemap_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// The fallback fonts generated by `next/font` across the app, with their
+    /// computed metric adjustments, for debugging layout shift.
+    #[turbo_tasks::function]
+    pub fn font_fallbacks(self: Vc<Self>) -> Vc<FontFallbackDiagnostics> {
+        app_font_fallback_diagnostics(self)
+    }
+
+    /// The module graph of the whole app as JSON, to reproduce issues without
+    /// the project. See [graph_export].
+    #[turbo_tasks::function]
+    pub fn graph_export(self: Vc<Self>, include_sources: bool) -> Vc<RcStr> {
+        graph_export(self, include_sources)
+    }
+
+    /// The code the import of `specifier` in `file` adds to the app, for each
+    /// layer of `file`. See [import_cost].
+    #[turbo_tasks::function]
+    pub fn import_cost(self: Vc<Self>, file: RcStr, specifier: RcStr) -> Vc<ImportCosts> {
+        import_cost(self, file, specifier)
+    }
+
+    /// Emits the `feature-usage.json` enabled through
+    /// `experimental.turbo.featureUsageReport`, returning its path relative to
+    /// the dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_feature_usage_report(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = feature_usage_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// Emits the `env-usage.json` enabled through
+    /// `experimental.turbo.envUsageReport`, returning its path relative to the
+    /// dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_env_usage_report(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = env_usage_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// Emits the `duplicate-modules.json` written along with the build stats
+    /// when `TURBOPACK_STATS` is set, returning its path relative to the dist
+    /// dir.
+    #[turbo_tasks::function]
+    pub async fn emit_duplicate_modules_report(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = duplicate_modules_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// Emits the CSS chunks of the themes configured through
+    /// `experimental.turbo.themes` and the `theme-manifest.json` listing them,
+    /// returning the path of the manifest relative to the dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_themes(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = theme_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// Emits the browser extension configured through
+    /// `experimental.turbo.browserExtension`, returning the paths of the
+    /// written files relative to the dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_browser_extension(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = browser_extension_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// Emits the `chunk-hash-manifest.json` enabled through
+    /// `experimental.turbo.contentHashing`, returning its path relative to the
+    /// dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_chunk_hash_manifest(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = chunk_hash_manifest_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// Emits the integrity manifest of the client chunks enabled through
+    /// `experimental.turbo.assetPrefixFallback`, returning its path relative
+    /// to the dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_chunk_integrity_manifest(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = chunk_integrity_manifest_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let client_relative_path = self.client_relative_path().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = client_relative_path.get_path_to(&path) {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// Emits the remote entry and the chunks of the container exposing the
+    /// modules configured in `experimental.turbo.moduleFederation`, returning
+    /// their paths relative to the dist dir.
+    #[turbo_tasks::function]
+    pub async fn emit_module_federation(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let assets_op = module_federation_assets_operation(self.to_resolved().await?);
+        let _ = self.emit_all_output_assets(assets_op).resolve().await?;
+
+        let node_root = self.node_root().await?;
+        let client_relative_path = self.client_relative_path().await?;
+        let mut paths = vec![];
+        for asset in assets_op.connect().await?.iter() {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root
+                .get_path_to(&path)
+                .or_else(|| client_relative_path.get_path_to(&path))
+            {
+                paths.push(path.into());
+            }
+        }
+        Ok(Vc::cell(paths))
+    }
+
+    /// The paths of all assets written when building the app, relative to the
+    /// dist dir. Client assets are included with the path they're emitted to,
+    /// see [Project::emit_all_output_assets].
+    #[turbo_tasks::function]
+    pub async fn output_paths(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
+        let self_resolved = self.to_resolved().await?;
+        let mut output_assets = vec![];
+        for endpoint in self.entrypoints().await?.all_endpoints() {
+            output_assets.extend(endpoint.output_assets().await?.iter().copied());
+        }
+        for assets_op in [
+            sitemap_assets_operation(self_resolved),
+            feature_usage_assets_operation(self_resolved),
+            env_usage_assets_operation(self_resolved),
+            duplicate_modules_assets_operation(self_resolved),
+            tree_shaking_report_assets_operation(self_resolved),
+            theme_assets_operation(self_resolved),
+            browser_extension_assets_operation(self_resolved),
+            chunk_hash_manifest_assets_operation(self_resolved),
+            chunk_integrity_manifest_assets_operation(self_resolved),
+            module_federation_assets_operation(self_resolved),
+        ] {
+            output_assets.extend(assets_op.connect().await?.iter().copied());
+        }
+
+        let node_root = self.node_root().await?;
+        let client_relative_path = self.client_relative_path().await?;
+        let mut paths = FxIndexSet::default();
+        for asset in all_assets_from_entries(Vc::cell(output_assets))
+            .await?
+            .iter()
+        {
+            let path = asset.ident().path().await?;
+            if let Some(path) = node_root
+                .get_path_to(&path)
+                .or_else(|| client_relative_path.get_path_to(&path))
+            {
+                paths.insert(RcStr::from(path));
+            }
+        }
+        Ok(Vc::cell(paths.into_iter().collect()))
+    }
+
     #[turbo_tasks::function]
     async fn hmr_content(self: Vc<Self>, identifier: RcStr) -> Result<Vc<OptionVersionedContent>> {
         if let Some(map) = self.await?.versioned_content_map {
@@ -0,0 +1,51 @@
+use anyhow::Result;
+use next_core::next_manifests::ResourceHintFiles;
+use turbo_tasks::{FxIndexSet, ResolvedVc};
+use turbopack_browser::ecmascript::EcmascriptDevChunk;
+use turbopack_core::{
+    chunk::{Chunk, ResourceHint},
+    output::OutputAsset,
+};
+use turbopack_ecmascript::async_chunk::chunk_item::AsyncLoaderChunkItem;
+
+/// Collects the chunks of the dynamic imports with a prefetch or preload hint
+/// which are loaded by the JS chunks in `client_assets`. Chunks which are part
+/// of `client_assets` already, or which are preloaded, aren't prefetched.
+pub async fn collect_resource_hints<'a, I>(client_assets: I) -> Result<ResourceHintFiles>
+where
+    I: IntoIterator<Item = &'a ResolvedVc<Box<dyn OutputAsset>>>,
+{
+    let client_assets: FxIndexSet<_> = client_assets.into_iter().copied().collect();
+    let mut prefetch = FxIndexSet::default();
+    let mut preload = FxIndexSet::default();
+    for asset in &client_assets {
+        let Some(chunk) = ResolvedVc::try_downcast_type::<EcmascriptDevChunk>(*asset).await? else {
+            continue;
+        };
+        for item in chunk.chunk().chunk_items().await? {
+            let Some(loader) = ResolvedVc::try_downcast_type::<AsyncLoaderChunkItem>(*item).await?
+            else {
+                continue;
+            };
+            let Some(resource_hint) = loader.await?.module.await?.resource_hint else {
+                continue;
+            };
+            let chunks = loader.chunks().await?;
+            let chunks = chunks
+                .iter()
+                .filter(|chunk| !client_assets.contains(*chunk))
+                .copied();
+            match resource_hint {
+                ResourceHint::Prefetch => prefetch.extend(chunks),
+                ResourceHint::Preload => preload.extend(chunks),
+            }
+        }
+    }
+    Ok(ResourceHintFiles {
+        prefetch: prefetch
+            .into_iter()
+            .filter(|chunk| !preload.contains(chunk))
+            .collect(),
+        preload: preload.into_iter().collect(),
+    })
+}
@@ -4,7 +4,7 @@ use turbo_rcstr::RcStr;
 use turbo_tasks::{
     debug::ValueDebugFormat, trace::TraceRawVcs, Completion, FxIndexMap, ResolvedVc, Vc,
 };
-use turbopack_core::module::Modules;
+use turbopack_core::{module::Modules, output::OutputAssets};
 
 use crate::paths::ServerPath;
 
@@ -63,6 +63,8 @@ pub trait Endpoint {
     fn write_to_disk(self: Vc<Self>) -> Vc<WrittenEndpoint>;
     fn server_changed(self: Vc<Self>) -> Vc<Completion>;
     fn client_changed(self: Vc<Self>) -> Vc<Completion>;
+    /// All assets written by [Endpoint::write_to_disk].
+    fn output_assets(self: Vc<Self>) -> Vc<OutputAssets>;
     fn root_modules(self: Vc<Self>) -> Vc<Modules>;
 }
 
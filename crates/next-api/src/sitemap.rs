@@ -0,0 +1,160 @@
+use anyhow::Result;
+use next_core::{
+    app_structure::{AppPageLoaderTree, Entrypoint},
+    next_sitemap::{
+        build_robots_txt, build_sitemap_xml, format_last_modified, is_excluded_from_sitemap,
+        merge_sitemap_urls, SitemapUrl,
+    },
+    pages_structure::{PagesDirectoryStructure, PagesStructure, PagesStructureItem},
+};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{to_sys_path, File, FileSystemPath};
+use turbopack_core::{
+    asset::AssetContent,
+    output::{OutputAsset, OutputAssets},
+    virtual_output::VirtualOutputAsset,
+};
+
+use crate::project::Project;
+
+/// Builds `sitemap.xml` and `robots.txt` from the static pages of the app and
+/// pages directories, as configured by `experimental.sitemap`. They're emitted
+/// with the static files, and `next build` rewrites `/sitemap.xml` and
+/// `/robots.txt` to them. Returns no assets when it isn't configured.
+#[turbo_tasks::function]
+async fn sitemap_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    let Some(config) = &*project.next_config().experimental_sitemap().await? else {
+        return Ok(OutputAssets::empty());
+    };
+
+    let mut discovered = vec![];
+    if let Some(app_project) = *project.app_project().await? {
+        for (pathname, entrypoint) in app_project.app_entrypoints().await?.iter() {
+            let Entrypoint::AppPage { loader_tree, .. } = entrypoint else {
+                continue;
+            };
+            if pathname.is_dynamic() {
+                continue;
+            }
+            let page = find_page_file(&*loader_tree.await?);
+            discovered.push((pathname.to_string().into(), page));
+        }
+    }
+    let pages_structure = project.pages_project().pages_structure().await?;
+    let PagesStructure { pages, .. } = &*pages_structure;
+    if let Some(pages) = *pages {
+        collect_pages(*pages, &mut discovered).await?;
+    }
+
+    let mut urls = vec![];
+    for (path, source) in discovered {
+        if is_internal_path(&path) || is_excluded_from_sitemap(&path, &config.exclude) {
+            continue;
+        }
+        let last_modified = match source {
+            Some(source) => (*last_modified(*source).await?).clone(),
+            None => None,
+        };
+        urls.push(SitemapUrl {
+            url: path,
+            last_modified,
+            change_frequency: None,
+            priority: None,
+        });
+    }
+    let urls = merge_sitemap_urls(urls, &config.entries);
+
+    let static_dir = project.node_root().join("static".into());
+    let mut assets: Vec<ResolvedVc<Box<dyn OutputAsset>>> = vec![ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            static_dir.join("sitemap.xml".into()).to_resolved().await?,
+            AssetContent::file(File::from(build_sitemap_xml(&config.base_url, &urls)).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    )];
+    if config.robots.unwrap_or(true) {
+        assets.push(ResolvedVc::upcast(
+            VirtualOutputAsset::new(
+                static_dir.join("robots.txt".into()).to_resolved().await?,
+                AssetContent::file(
+                    File::from(build_robots_txt(&config.base_url, &config.exclude)).into(),
+                )
+                .to_resolved()
+                .await?,
+            )
+            .to_resolved()
+            .await?,
+        ));
+    }
+
+    Ok(Vc::cell(assets))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn sitemap_assets_operation(project: ResolvedVc<Project>) -> Vc<OutputAssets> {
+    sitemap_assets(*project)
+}
+
+/// Follows the `children` parallel route down to the segment defining the
+/// page.
+fn find_page_file(loader_tree: &AppPageLoaderTree) -> Option<ResolvedVc<FileSystemPath>> {
+    loader_tree.modules.page.or_else(|| {
+        loader_tree
+            .parallel_routes
+            .get("children")
+            .and_then(find_page_file)
+    })
+}
+
+async fn collect_pages(
+    dir: Vc<PagesDirectoryStructure>,
+    discovered: &mut Vec<(RcStr, Option<ResolvedVc<FileSystemPath>>)>,
+) -> Result<()> {
+    let mut queue = vec![dir];
+    while let Some(dir) = queue.pop() {
+        let PagesDirectoryStructure {
+            items, children, ..
+        } = &*dir.await?;
+        for &item in items {
+            let PagesStructureItem {
+                next_router_path, ..
+            } = &*item.await?;
+            let path = &next_router_path.await?.path;
+            if path.contains('[') {
+                continue;
+            }
+            discovered.push((
+                format!("/{}", path).into(),
+                Some(item.project_path().to_resolved().await?),
+            ));
+        }
+        queue.extend(children.iter().map(|child| **child));
+    }
+    Ok(())
+}
+
+/// Error pages and other routes prefixed with an underscore, e.g.
+/// `/_not-found`, aren't meant to be indexed.
+fn is_internal_path(path: &str) -> bool {
+    path == "/404" || path == "/500" || path.split('/').any(|segment| segment.starts_with('_'))
+}
+
+/// The modification date of a source file, read to invalidate the sitemap when
+/// the file changes.
+#[turbo_tasks::function]
+async fn last_modified(path: Vc<FileSystemPath>) -> Result<Vc<Option<RcStr>>> {
+    path.read().await?;
+    let Some(sys_path) = to_sys_path(path).await? else {
+        return Ok(Vc::cell(None));
+    };
+    Ok(Vc::cell(
+        std::fs::metadata(sys_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .map(format_last_modified),
+    ))
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use next_core::next_manifests::ThemeManifest;
+use rustc_hash::{FxHashMap, FxHashSet};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexSet, ResolvedVc, TryJoinIterExt, Value, Vc};
+use turbo_tasks_fs::{File, FileSystemPath};
+use turbopack_core::{
+    asset::AssetContent,
+    chunk::{availability_info::AvailabilityInfo, ChunkableModule, ChunkingContext},
+    context::AssetContext,
+    file_source::FileSource,
+    module::{Module, Modules},
+    output::{OutputAsset, OutputAssets},
+    reference_type::{EntryReferenceSubType, ReferenceType},
+    virtual_output::VirtualOutputAsset,
+};
+
+use crate::{module_graph::SingleModuleGraph, project::Project};
+
+/// Builds the client CSS of each route for every theme configured through
+/// `experimental.turbo.themes`, and the `theme-manifest.json` listing it. The
+/// module graph of the app is shared by all themes: the stylesheets imported by
+/// the client modules of a route are found in it once, and only those are
+/// compiled again with the aliases of each theme. Returns no assets when no
+/// theme is configured.
+#[turbo_tasks::function]
+async fn theme_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    let themes = project.next_config().themes().await?;
+    if themes.is_empty() {
+        return Ok(OutputAssets::empty());
+    }
+
+    let mut routes = vec![];
+    for (name, endpoint) in project.entrypoints().await?.named_endpoints() {
+        let stylesheets = route_stylesheets(endpoint.root_modules()).await?;
+        if !stylesheets.is_empty() {
+            routes.push((name, stylesheets));
+        }
+    }
+
+    let chunking_context = project.client_chunking_context();
+    let client_relative_path = project.client_relative_path().await?;
+
+    let mut assets: FxIndexSet<ResolvedVc<Box<dyn OutputAsset>>> = FxIndexSet::default();
+    let mut manifest = ThemeManifest::default();
+    for theme in themes.keys() {
+        let asset_context = project
+            .pages_project()
+            .theme_client_module_context(theme.clone());
+        let mut theme_routes = BTreeMap::new();
+        for (route, stylesheets) in &routes {
+            let mut chunks = FxIndexSet::default();
+            for &path in stylesheets.iter() {
+                let chunk_group = theme_chunk_group(asset_context, chunking_context, *path).await?;
+                for &asset in chunk_group.iter() {
+                    let path = asset.ident().path().await?;
+                    if path.extension_ref() == Some("css") {
+                        if let Some(path) = client_relative_path.get_path_to(&path) {
+                            chunks.insert(RcStr::from(path));
+                        }
+                    }
+                }
+                assets.extend(chunk_group.iter().copied());
+            }
+            theme_routes.insert(route.clone(), chunks.into_iter().collect());
+        }
+        manifest.themes.insert(theme.clone(), theme_routes);
+    }
+
+    assets.insert(ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            project
+                .node_root()
+                .join("server/theme-manifest.json".into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&manifest)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    ));
+
+    Ok(Vc::cell(assets.into_iter().collect()))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn theme_assets_operation(project: ResolvedVc<Project>) -> Vc<OutputAssets> {
+    theme_assets(*project)
+}
+
+#[turbo_tasks::value(transparent)]
+struct StylesheetPaths(Vec<ResolvedVc<FileSystemPath>>);
+
+/// The stylesheets imported by the client modules of a route, in the order of
+/// the graph. Stylesheets imported by other stylesheets aren't listed, they're
+/// part of the chunk group of the stylesheet importing them.
+#[turbo_tasks::function]
+async fn route_stylesheets(root_modules: Vc<Modules>) -> Result<Vc<StylesheetPaths>> {
+    let graph = SingleModuleGraph::new_with_entries(root_modules).await?;
+
+    let mut stylesheets = FxHashSet::default();
+    for (index, node) in graph.enumerate_nodes() {
+        let is_client = node
+            .layer
+            .as_deref()
+            .is_some_and(|layer| &**layer == "app-client" || &**layer == "client");
+        if is_client && is_stylesheet(&node.module.ident().path().await?) {
+            stylesheets.insert(index);
+        }
+    }
+
+    let mut imported_by_module: FxHashMap<_, bool> = FxHashMap::default();
+    for (from, to) in graph.enumerate_edges() {
+        if stylesheets.contains(&to) {
+            *imported_by_module.entry(to).or_default() |= !stylesheets.contains(&from);
+        }
+    }
+
+    let paths = graph
+        .enumerate_nodes()
+        .filter(|(index, _)| imported_by_module.get(index).copied().unwrap_or(false))
+        .map(|(_, node)| async move { node.module.ident().path().to_resolved().await })
+        .try_join()
+        .await?;
+    Ok(Vc::cell(
+        paths
+            .into_iter()
+            .collect::<FxIndexSet<_>>()
+            .into_iter()
+            .collect(),
+    ))
+}
+
+fn is_stylesheet(path: &FileSystemPath) -> bool {
+    matches!(path.extension_ref(), Some("css" | "scss" | "sass"))
+}
+
+/// Compiles a stylesheet of a route with the module context of a theme and
+/// chunks it on its own, so that it doesn't depend on the chunks of the app.
+#[turbo_tasks::function]
+async fn theme_chunk_group(
+    asset_context: Vc<Box<dyn AssetContext>>,
+    chunking_context: Vc<Box<dyn ChunkingContext>>,
+    path: Vc<FileSystemPath>,
+) -> Result<Vc<OutputAssets>> {
+    let module = asset_context
+        .process(
+            Vc::upcast(FileSource::new(path)),
+            Value::new(ReferenceType::Entry(EntryReferenceSubType::Undefined)),
+        )
+        .module()
+        .to_resolved()
+        .await?;
+    let Some(module) = ResolvedVc::try_sidecast::<Box<dyn ChunkableModule>>(module).await? else {
+        return Ok(OutputAssets::empty());
+    };
+    let chunk_group = chunking_context
+        .chunk_group(module.ident(), *module, Value::new(AvailabilityInfo::Root))
+        .await?;
+    Ok(*chunk_group.assets)
+}
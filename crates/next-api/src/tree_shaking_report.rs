@@ -0,0 +1,176 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use anyhow::Result;
+use petgraph::graph::NodeIndex;
+use serde::Serialize;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, ValueToString, Vc};
+use turbo_tasks_fs::File;
+use turbopack_core::{
+    asset::AssetContent, module::Module, output::OutputAssets, resolve::ModulePart,
+    virtual_output::VirtualOutputAsset,
+};
+use turbopack_ecmascript::{
+    chunk::{EcmascriptChunkPlaceable, EcmascriptExports},
+    tree_shake::asset::EcmascriptModulePartAsset,
+    EcmascriptModuleAsset,
+};
+
+use crate::{
+    module_graph::{SingleModuleGraph, SingleModuleGraphNode},
+    project::Project,
+};
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct TreeShakingReport {
+    /// The modules which were split into parts, keyed by the module.
+    modules: BTreeMap<RcStr, ModuleTreeShaking>,
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct ModuleTreeShaking {
+    /// The exports which are part of the output, with the modules importing
+    /// them.
+    kept: BTreeMap<RcStr, KeptExport>,
+    /// The exports of the module which aren't part of the output.
+    dropped: BTreeSet<RcStr>,
+    /// The modules which import the namespace object of the module, which
+    /// retains all exports.
+    namespace_importers: BTreeSet<RcStr>,
+}
+
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct KeptExport {
+    /// The modules importing the export, i.e. the import edges retaining it.
+    importers: BTreeSet<RcStr>,
+}
+
+/// The part of a module a module graph node represents, as far as it's
+/// relevant to the exports of the module.
+enum PartKind {
+    Export(RcStr),
+    Namespace,
+    Other,
+}
+
+async fn part_kind(part: Vc<ModulePart>) -> Result<PartKind> {
+    Ok(match &*part.await? {
+        ModulePart::Export(export) | ModulePart::RenamedExport { export, .. } => {
+            PartKind::Export(export.await?.clone_value())
+        }
+        ModulePart::RenamedNamespace { .. } | ModulePart::Exports | ModulePart::Facade => {
+            PartKind::Namespace
+        }
+        _ => PartKind::Other,
+    })
+}
+
+async fn module_name(node: &SingleModuleGraphNode) -> Result<RcStr> {
+    Ok(node.module.ident().to_string().await?.clone_value())
+}
+
+/// Lists the kept and dropped exports of the modules of a module graph which
+/// were split into parts, and the importers retaining each kept export.
+async fn tree_shaking_report(graph: &SingleModuleGraph) -> Result<TreeShakingReport> {
+    let nodes: HashMap<NodeIndex, &SingleModuleGraphNode> = graph.enumerate_nodes().collect();
+
+    let mut importers: HashMap<NodeIndex, Vec<NodeIndex>> = HashMap::new();
+    for (from, to, _) in graph.enumerate_edges_with_chunking_type() {
+        importers.entry(to).or_default().push(from);
+    }
+
+    let mut parts: HashMap<ResolvedVc<EcmascriptModuleAsset>, Vec<(NodeIndex, PartKind)>> =
+        HashMap::new();
+    for (&index, node) in &nodes {
+        let Some(part_asset) =
+            ResolvedVc::try_downcast_type::<EcmascriptModulePartAsset>(node.module).await?
+        else {
+            continue;
+        };
+        let part_asset = part_asset.await?;
+        parts
+            .entry(part_asset.full_module)
+            .or_default()
+            .push((index, part_kind(*part_asset.part).await?));
+    }
+
+    let mut report = TreeShakingReport::default();
+    for (full_module, parts) in parts {
+        let mut module = ModuleTreeShaking::default();
+        for (index, kind) in parts {
+            let node_importers = importers.get(&index).map(|v| &v[..]).unwrap_or_default();
+            match kind {
+                PartKind::Export(export) => {
+                    let kept = module.kept.entry(export).or_default();
+                    for importer in node_importers {
+                        kept.importers.insert(module_name(nodes[importer]).await?);
+                    }
+                }
+                PartKind::Namespace => {
+                    for importer in node_importers {
+                        module
+                            .namespace_importers
+                            .insert(module_name(nodes[importer]).await?);
+                    }
+                }
+                PartKind::Other => {}
+            }
+        }
+
+        let exports = Vc::upcast::<Box<dyn EcmascriptChunkPlaceable>>(*full_module)
+            .get_exports()
+            .await?;
+        if let EcmascriptExports::EsmExports(exports) = &*exports {
+            for export in exports.await?.exports.keys() {
+                if !module.kept.contains_key(export) {
+                    module.dropped.insert(export.clone());
+                }
+            }
+        }
+
+        report
+            .modules
+            .insert(full_module.ident().to_string().await?.clone_value(), module);
+    }
+    Ok(report)
+}
+
+/// Builds `tree-shaking.json` for production builds when the build stats are
+/// enabled through `TURBOPACK_STATS`. It lists the exports of each module
+/// which were kept or dropped by tree shaking, and the importers retaining
+/// the kept ones. Returns no assets otherwise.
+#[turbo_tasks::function]
+async fn tree_shaking_report_assets(project: Vc<Project>) -> Result<Vc<OutputAssets>> {
+    if !*project.should_create_webpack_stats().await? || !project.next_mode().await?.is_production()
+    {
+        return Ok(OutputAssets::empty());
+    }
+
+    let graph = project.whole_app_module_graph().await?;
+    let report = tree_shaking_report(&graph).await?;
+
+    Ok(Vc::cell(vec![ResolvedVc::upcast(
+        VirtualOutputAsset::new(
+            project
+                .node_root()
+                .join("tree-shaking.json".into())
+                .to_resolved()
+                .await?,
+            AssetContent::file(File::from(serde_json::to_string_pretty(&report)?).into())
+                .to_resolved()
+                .await?,
+        )
+        .to_resolved()
+        .await?,
+    )]))
+}
+
+#[turbo_tasks::function(operation)]
+pub(crate) fn tree_shaking_report_assets_operation(
+    project: ResolvedVc<Project>,
+) -> Vc<OutputAssets> {
+    tree_shaking_report_assets(*project)
+}
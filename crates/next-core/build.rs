@@ -1,5 +1,46 @@
+use std::{collections::BTreeMap, env, fmt::Write, fs, path::Path};
+
+use serde::Deserialize;
 use turbo_tasks_build::generate_register;
 
 fn main() {
     generate_register();
+    generate_fallback_font_metrics();
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FallbackFontMetricsEntry {
+    category: String,
+    capsize_key: String,
+    az_avg_width: f64,
+    units_per_em: u32,
+}
+
+/// Bakes the metrics of the fallback fonts next/font adjusts by default into
+/// the crate. Refresh them with the `capsize_fallback_metrics` task in
+/// `packages/next/taskfile.js`.
+fn generate_fallback_font_metrics() {
+    let metrics_path = "src/next_font/fallback-font-metrics.json";
+    println!("cargo:rerun-if-changed={metrics_path}");
+
+    let metrics: BTreeMap<String, FallbackFontMetricsEntry> =
+        serde_json::from_str(&fs::read_to_string(metrics_path).unwrap())
+            .expect("invalid fallback font metrics");
+
+    let mut code =
+        "pub(crate) static BUILTIN_FALLBACK_FONTS: &[BuiltinFallbackFont] = &[\n".to_string();
+    for (name, entry) in metrics {
+        writeln!(
+            code,
+            "    BuiltinFallbackFont {{ name: {:?}, category: {:?}, capsize_key: {:?}, \
+             az_avg_width: {:?}, units_per_em: {} }},",
+            name, entry.category, entry.capsize_key, entry.az_avg_width, entry.units_per_em
+        )
+        .unwrap();
+    }
+    code.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("fallback_font_metrics.rs"), code).unwrap();
 }
@@ -5,12 +5,16 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use swc_core::{
     common::{source_map::SmallPos, Span, Spanned, GLOBALS},
-    ecma::ast::{Decl, Expr, FnExpr, Ident, Program},
+    ecma::ast::{
+        ArrowExpr, BlockStmt, BlockStmtOrExpr, Callee, Decl, Expr, FnExpr, Ident, MemberExpr,
+        MemberProp, Module, ModuleDecl, ModuleItem, Pat, Program, Prop, PropName, PropOrSpread,
+        ReturnStmt, Stmt, VarDeclKind,
+    },
 };
 use turbo_rcstr::RcStr;
 use turbo_tasks::{
-    trace::TraceRawVcs, util::WrapFuture, NonLocalValue, ResolvedVc, TryJoinIterExt, ValueDefault,
-    Vc,
+    trace::TraceRawVcs, util::WrapFuture, FxIndexMap, NonLocalValue, ResolvedVc, TryJoinIterExt,
+    ValueDefault, Vc,
 };
 use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::{
@@ -23,7 +27,7 @@ use turbopack_core::{
     source::Source,
 };
 use turbopack_ecmascript::{
-    analyzer::{graph::EvalContext, ConstantNumber, ConstantValue, JsValue},
+    analyzer::{graph::EvalContext, ConstantNumber, ConstantValue, JsValue, ObjectPart},
     parse::{parse, ParseResult},
     EcmascriptInputTransforms, EcmascriptModuleAssetType,
 };
@@ -69,6 +73,18 @@ pub enum NextRevalidate {
     },
 }
 
+/// The value of a param returned by `generateStaticParams`, an array for
+/// catch-all segments.
+#[derive(PartialEq, Eq, Clone, Debug, TraceRawVcs, Serialize, Deserialize, NonLocalValue)]
+#[serde(untagged)]
+pub enum StaticParamValue {
+    Single(RcStr),
+    CatchAll(Vec<RcStr>),
+}
+
+/// One entry of the array returned by `generateStaticParams`.
+pub type StaticParams = FxIndexMap<RcStr, StaticParamValue>;
+
 #[turbo_tasks::value(into = "shared")]
 #[derive(Debug, Default, Clone)]
 pub struct NextSegmentConfig {
@@ -82,6 +98,10 @@ pub struct NextSegmentConfig {
     /// Whether these metadata exports are defined in the source file.
     pub generate_image_metadata: bool,
     pub generate_sitemaps: bool,
+    /// The result of `generateStaticParams`, when it could be evaluated at
+    /// compile time. Like the metadata exports, it only applies to the segment
+    /// defining it.
+    pub static_params: Option<Vec<StaticParams>>,
 }
 
 #[turbo_tasks::value_impl]
@@ -322,8 +342,15 @@ pub async fn parse_segment_config_from_source(
                             };
 
                             if let Some(init) = decl.init.as_ref() {
-                                parse_config_value(source, &mut config, ident, init, eval_context)
-                                    .await?;
+                                parse_config_value(
+                                    source,
+                                    &mut config,
+                                    ident,
+                                    init,
+                                    module_ast,
+                                    eval_context,
+                                )
+                                .await?;
                             }
                         }
                     }
@@ -334,7 +361,15 @@ pub async fn parse_segment_config_from_source(
                             ident: None,
                             function: fn_decl.function.clone(),
                         });
-                        parse_config_value(source, &mut config, ident, &init, eval_context).await?;
+                        parse_config_value(
+                            source,
+                            &mut config,
+                            ident,
+                            &init,
+                            module_ast,
+                            eval_context,
+                        )
+                        .await?;
                     }
                     _ => {}
                 }
@@ -357,6 +392,7 @@ async fn parse_config_value(
     config: &mut NextSegmentConfig,
     ident: &Ident,
     init: &Expr,
+    module: &Module,
     eval_context: &EvalContext,
 ) -> Result<()> {
     let span = init.span();
@@ -536,6 +572,9 @@ async fn parse_config_value(
         "generateSitemaps" => {
             config.generate_sitemaps = true;
         }
+        "generateStaticParams" => {
+            config.static_params = evaluate_static_params(init, module, eval_context);
+        }
         "experimental_ppr" => {
             let value = eval_context.eval(init);
             let Some(val) = value.as_bool() else {
@@ -556,6 +595,216 @@ async fn parse_config_value(
     Ok(())
 }
 
+/// Evaluates trivial implementations of `generateStaticParams` at compile
+/// time, which return a literal array of params or map over a literal array:
+///
+/// ```js
+/// export function generateStaticParams() {
+///   return ['intro', 'setup'].map((slug) => ({ slug }))
+/// }
+/// ```
+///
+/// The arrays can also be referenced through top-level constants. Returns
+/// `None` when the params can only be known by calling the function, e.g.
+/// when it reads the params of the parent segments or fetches data.
+fn evaluate_static_params(
+    init: &Expr,
+    module: &Module,
+    eval_context: &EvalContext,
+) -> Option<Vec<StaticParams>> {
+    let returned = match init.unwrap_parens() {
+        Expr::Fn(FnExpr { function, .. }) => {
+            if !function.params.is_empty() || function.is_generator {
+                return None;
+            }
+            single_return(function.body.as_ref()?)?
+        }
+        Expr::Arrow(ArrowExpr {
+            params,
+            body,
+            is_generator,
+            ..
+        }) => {
+            if !params.is_empty() || *is_generator {
+                return None;
+            }
+            match &**body {
+                BlockStmtOrExpr::Expr(expr) => expr,
+                BlockStmtOrExpr::BlockStmt(block) => single_return(block)?,
+            }
+        }
+        _ => return None,
+    };
+
+    match returned.unwrap_parens() {
+        Expr::Call(call) => {
+            let Callee::Expr(callee) = &call.callee else {
+                return None;
+            };
+            let Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(method),
+                ..
+            }) = callee.unwrap_parens()
+            else {
+                return None;
+            };
+            let [callback] = &call.args[..] else {
+                return None;
+            };
+            if &*method.sym != "map" || callback.spread.is_some() {
+                return None;
+            }
+            let JsValue::Array { items, .. } = eval_literal(obj, module, eval_context)? else {
+                return None;
+            };
+            let Expr::Arrow(ArrowExpr {
+                params,
+                body,
+                is_async: false,
+                is_generator: false,
+                ..
+            }) = callback.expr.unwrap_parens()
+            else {
+                return None;
+            };
+            let [Pat::Ident(param)] = &params[..] else {
+                return None;
+            };
+            let body = match &**body {
+                BlockStmtOrExpr::Expr(expr) => expr,
+                BlockStmtOrExpr::BlockStmt(block) => single_return(block)?,
+            };
+            let Expr::Object(object) = body.unwrap_parens() else {
+                return None;
+            };
+            let param = param.id.to_id();
+            items
+                .iter()
+                .map(|item| {
+                    // The values of the params can be the item or one of its properties, or
+                    // constants.
+                    let eval_mapped = |expr: &Expr| match expr.unwrap_parens() {
+                        Expr::Ident(ident) if ident.to_id() == param => Some(item.clone()),
+                        Expr::Member(MemberExpr {
+                            obj,
+                            prop: MemberProp::Ident(name),
+                            ..
+                        }) if matches!(&**obj, Expr::Ident(ident) if ident.to_id() == param) => {
+                            object_property(item, &name.sym)
+                        }
+                        expr => Some(eval_context.eval(expr)),
+                    };
+                    object
+                        .props
+                        .iter()
+                        .map(|prop| {
+                            let PropOrSpread::Prop(prop) = prop else {
+                                return None;
+                            };
+                            let (key, value) = match &**prop {
+                                Prop::Shorthand(ident) => {
+                                    (&ident.sym, eval_mapped(&Expr::Ident(ident.clone()))?)
+                                }
+                                Prop::KeyValue(key_value) => {
+                                    let key = match &key_value.key {
+                                        PropName::Ident(ident) => &ident.sym,
+                                        PropName::Str(str) => &str.value,
+                                        _ => return None,
+                                    };
+                                    (key, eval_mapped(&key_value.value)?)
+                                }
+                                _ => return None,
+                            };
+                            Some((key.as_str().into(), static_param_value(&value)?))
+                        })
+                        .collect()
+                })
+                .collect()
+        }
+        returned => {
+            let JsValue::Array { items, .. } = eval_literal(returned, module, eval_context)? else {
+                return None;
+            };
+            items.iter().map(static_params_from_object).collect()
+        }
+    }
+}
+
+/// The argument of the only statement of `block`, if it is a `return`.
+fn single_return(block: &BlockStmt) -> Option<&Expr> {
+    let [Stmt::Return(ReturnStmt { arg: Some(arg), .. })] = &block.stmts[..] else {
+        return None;
+    };
+    Some(arg)
+}
+
+/// Evaluates a literal, or an identifier referencing a top-level `const`
+/// initialized with a literal.
+fn eval_literal(expr: &Expr, module: &Module, eval_context: &EvalContext) -> Option<JsValue> {
+    let Expr::Ident(ident) = expr.unwrap_parens() else {
+        return Some(eval_context.eval(expr));
+    };
+    let id = ident.to_id();
+    module
+        .body
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var_decl))) => Some(var_decl),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+                export_decl.decl.as_var()
+            }
+            _ => None,
+        })
+        .filter(|var_decl| var_decl.kind == VarDeclKind::Const)
+        .flat_map(|var_decl| &var_decl.decls)
+        .find(|decl| decl.name.as_ident().is_some_and(|name| name.to_id() == id))
+        .and_then(|decl| decl.init.as_deref())
+        .map(|init| eval_context.eval(init))
+}
+
+fn object_property(object: &JsValue, key: &str) -> Option<JsValue> {
+    let JsValue::Object { parts, .. } = object else {
+        return None;
+    };
+    parts.iter().rev().find_map(|part| match part {
+        ObjectPart::KeyValue(name, value) if name.as_str() == Some(key) => Some(value.clone()),
+        _ => None,
+    })
+}
+
+fn static_params_from_object(object: &JsValue) -> Option<StaticParams> {
+    let JsValue::Object { parts, .. } = object else {
+        return None;
+    };
+    parts
+        .iter()
+        .map(|part| match part {
+            ObjectPart::KeyValue(key, value) => {
+                Some((key.as_str()?.into(), static_param_value(value)?))
+            }
+            ObjectPart::Spread(_) => None,
+        })
+        .collect()
+}
+
+/// Params have to be strings, or arrays of strings for catch-all segments.
+/// Other values are left for the runtime evaluation to report.
+fn static_param_value(value: &JsValue) -> Option<StaticParamValue> {
+    match value {
+        JsValue::Constant(ConstantValue::Str(str)) => {
+            Some(StaticParamValue::Single(str.as_str().into()))
+        }
+        JsValue::Array { items, .. } => Some(StaticParamValue::CatchAll(
+            items
+                .iter()
+                .map(|item| item.as_str().map(RcStr::from))
+                .collect::<Option<_>>()?,
+        )),
+        _ => None,
+    }
+}
+
 #[turbo_tasks::function]
 pub async fn parse_segment_config_from_loader_tree(
     loader_tree: Vc<AppPageLoaderTree>,
@@ -596,3 +845,263 @@ pub async fn parse_segment_config_from_loader_tree_internal(
 
     Ok(config)
 }
+
+/// The params of the segments of an app page evaluated at compile time, by the
+/// path of the module defining `generateStaticParams` as it appears in the
+/// loader tree.
+#[turbo_tasks::value(transparent)]
+pub struct StaticParamsManifest(FxIndexMap<RcStr, Vec<StaticParams>>);
+
+#[turbo_tasks::function]
+pub async fn static_params_from_loader_tree(
+    loader_tree: Vc<AppPageLoaderTree>,
+) -> Result<Vc<StaticParamsManifest>> {
+    let mut manifest = FxIndexMap::default();
+    collect_static_params(&*loader_tree.await?, &mut manifest).await?;
+    Ok(Vc::cell(manifest))
+}
+
+async fn collect_static_params(
+    loader_tree: &AppPageLoaderTree,
+    manifest: &mut FxIndexMap<RcStr, Vec<StaticParams>>,
+) -> Result<()> {
+    let modules = &loader_tree.modules;
+    for path in [modules.page, modules.default, modules.layout]
+        .into_iter()
+        .flatten()
+    {
+        let source = Vc::upcast(FileSource::new(*path));
+        if let Some(static_params) = &parse_segment_config_from_source(source)
+            .await?
+            .static_params
+        {
+            manifest.insert(path.to_string().await?.clone_value(), static_params.clone());
+        }
+    }
+    for loader_tree in loader_tree.parallel_routes.values() {
+        Box::pin(collect_static_params(loader_tree, manifest)).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{
+        common::{FileName, Globals, Mark, SourceMap},
+        ecma::{
+            ast::EsVersion,
+            parser::{parse_file_as_program, Syntax},
+            transforms::base::resolver,
+            visit::VisitMutWith,
+        },
+    };
+
+    use super::*;
+
+    fn static_params(source: &str) -> Option<Vec<StaticParams>> {
+        GLOBALS.set(&Globals::new(), || {
+            let cm = SourceMap::default();
+            let fm = cm.new_source_file(FileName::Anon.into(), source.into());
+            let mut program = parse_file_as_program(
+                &fm,
+                Syntax::Es(Default::default()),
+                EsVersion::latest(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+            program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+            let eval_context =
+                EvalContext::new(&program, unresolved_mark, top_level_mark, None, None);
+            let Program::Module(module) = &program else {
+                panic!("expected a module");
+            };
+            let init = module
+                .body
+                .iter()
+                .filter_map(|item| item.as_module_decl()?.as_export_decl())
+                .find_map(|export_decl| match &export_decl.decl {
+                    Decl::Fn(fn_decl) if &*fn_decl.ident.sym == "generateStaticParams" => {
+                        Some(Expr::Fn(FnExpr {
+                            ident: None,
+                            function: fn_decl.function.clone(),
+                        }))
+                    }
+                    Decl::Var(var_decl) => var_decl
+                        .decls
+                        .iter()
+                        .find(|decl| {
+                            decl.name
+                                .as_ident()
+                                .is_some_and(|ident| &*ident.sym == "generateStaticParams")
+                        })
+                        .and_then(|decl| decl.init.as_deref().cloned()),
+                    _ => None,
+                })
+                .expect("generateStaticParams should be exported");
+            evaluate_static_params(&init, module, &eval_context)
+        })
+    }
+
+    fn single(params: &[(&str, &str)]) -> StaticParams {
+        params
+            .iter()
+            .map(|&(key, value)| (key.into(), StaticParamValue::Single(value.into())))
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_literal_static_params() {
+        let expected = Some(vec![
+            single(&[("slug", "intro")]),
+            single(&[("slug", "setup")]),
+        ]);
+        assert_eq!(
+            static_params(
+                r#"
+                export function generateStaticParams() {
+                    return [{ slug: "intro" }, { slug: "setup" }];
+                }
+                "#
+            ),
+            expected
+        );
+        assert_eq!(
+            static_params(
+                r#"
+                export const generateStaticParams = async () => [
+                    { slug: "intro" },
+                    { "slug": "setup" },
+                ];
+                "#
+            ),
+            expected
+        );
+        assert_eq!(
+            static_params(
+                r#"
+                const params = [{ slug: "intro" }, { slug: "setup" }];
+                export async function generateStaticParams() {
+                    return params;
+                }
+                "#
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_evaluate_mapped_static_params() {
+        assert_eq!(
+            static_params(
+                r#"
+                const slugs = ["intro", "setup"];
+                export function generateStaticParams() {
+                    return slugs.map((slug) => ({ slug, lang: "en" }));
+                }
+                "#
+            ),
+            Some(vec![
+                single(&[("slug", "intro"), ("lang", "en")]),
+                single(&[("slug", "setup"), ("lang", "en")]),
+            ])
+        );
+        assert_eq!(
+            static_params(
+                r#"
+                export const posts = [{ id: "1" }, { id: "2" }];
+                export const generateStaticParams = () =>
+                    posts.map((post) => {
+                        return { id: post.id };
+                    });
+                "#
+            ),
+            Some(vec![single(&[("id", "1")]), single(&[("id", "2")])])
+        );
+    }
+
+    #[test]
+    fn test_evaluate_catch_all_static_params() {
+        assert_eq!(
+            static_params(
+                r#"
+                export function generateStaticParams() {
+                    return [{ path: ["docs", "intro"] }, { path: [] }];
+                }
+                "#
+            ),
+            Some(vec![
+                FxIndexMap::from_iter([(
+                    "path".into(),
+                    StaticParamValue::CatchAll(vec!["docs".into(), "intro".into()])
+                )]),
+                FxIndexMap::from_iter([("path".into(), StaticParamValue::CatchAll(vec![]))]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_evaluate_static_params_bail_out() {
+        for source in [
+            // Params of the parent segments
+            r#"
+            export function generateStaticParams({ params }) {
+                return [{ slug: params.lang }];
+            }
+            "#,
+            // Awaited data
+            r#"
+            export async function generateStaticParams() {
+                const posts = await fetch("https://example.com/posts");
+                return posts.map((post) => ({ slug: post.slug }));
+            }
+            "#,
+            r#"
+            export async function generateStaticParams() {
+                return (await getSlugs()).map((slug) => ({ slug }));
+            }
+            "#,
+            r#"
+            const slugs = ["intro"];
+            export function generateStaticParams() {
+                return slugs.map(async (slug) => ({ slug }));
+            }
+            "#,
+            // Spreads
+            r#"
+            const base = { lang: "en" };
+            export function generateStaticParams() {
+                return [{ ...base, slug: "intro" }];
+            }
+            "#,
+            r#"
+            const slugs = [{ slug: "intro" }];
+            export function generateStaticParams() {
+                return slugs.map((slug) => ({ ...slug }));
+            }
+            "#,
+            // Values which aren't strings
+            r#"
+            export function generateStaticParams() {
+                return [{ id: 1 }];
+            }
+            "#,
+            r#"
+            export function generateStaticParams() {
+                return [{ path: ["docs", 1] }];
+            }
+            "#,
+            // Mutable bindings
+            r#"
+            let slugs = ["intro"];
+            export function generateStaticParams() {
+                return slugs.map((slug) => ({ slug }));
+            }
+            "#,
+        ] {
+            assert_eq!(static_params(source), None, "{source}");
+        }
+    }
+}
@@ -23,16 +23,20 @@ pub mod next_client_reference;
 pub mod next_config;
 pub mod next_dynamic;
 pub mod next_edge;
+pub mod next_env_usage;
+pub mod next_feature_usage;
 mod next_font;
 mod next_image;
 mod next_import_map;
 pub mod next_manifests;
 pub mod next_pages;
+pub mod next_resolve_interceptor;
 mod next_route_matcher;
 pub mod next_server;
 pub mod next_server_component;
 pub mod next_server_utility;
 mod next_shared;
+pub mod next_sitemap;
 pub mod next_telemetry;
 mod page_loader;
 pub mod pages_structure;
@@ -49,6 +53,18 @@ pub use next_edge::context::{
     get_edge_chunking_context, get_edge_chunking_context_with_client_assets,
     get_edge_compile_time_info, get_edge_resolve_options_context,
 };
+pub use next_font::{
+    diagnostics::{
+        next_font_face_files, next_font_manifest_fonts, FontFallbackDiagnostic,
+        FontFallbackDiagnostics, NextFontStylesheetSource,
+    },
+    font_fallback::{FontMetricsOverride, FontMetricsOverrides},
+    google::{
+        options::{Axis, FontDataEntry, FontWeights, NextFontGoogleOptions},
+        provider::{get_font_provider, FontProvider, OptionHttpResponseBody},
+        FontData,
+    },
+};
 pub use next_import_map::get_next_package;
 pub use page_loader::{create_page_loader_entry_module, PageLoaderAsset};
 pub use util::{get_asset_path_from_pathname, pathname_for_path, PathType};
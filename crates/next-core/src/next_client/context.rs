@@ -14,16 +14,22 @@ use turbopack::{
 };
 use turbopack_browser::{react_refresh::assert_can_resolve_react_refresh, BrowserChunkingContext};
 use turbopack_core::{
-    chunk::{module_id_strategies::ModuleIdStrategy, ChunkingContext, MinifyType},
+    chunk::{
+        chunking::{ChunkCacheGroups, ChunkModuleGroups, ChunkSizeLimits},
+        module_id_strategies::ModuleIdStrategy,
+        ChunkingContext, MinifyType,
+    },
     compile_time_info::{
         CompileTimeDefineValue, CompileTimeDefines, CompileTimeInfo, DefineableNameSegment,
         FreeVarReference, FreeVarReferences,
     },
     condition::ContextCondition,
-    environment::{BrowserEnvironment, Environment, ExecutionEnvironment},
+    environment::{BrowserEnvironment, EdgeWorkerEnvironment, Environment, ExecutionEnvironment},
     free_var_references,
     resolve::{parse::Request, pattern::Pattern},
 };
+use turbopack_ecmascript::federation::resolve::ModuleFederationResolvePlugin;
+use turbopack_ecmascript_runtime::RuntimeType;
 use turbopack_node::{
     execution_context::ExecutionContext,
     transforms::postcss::{PostCssConfigLocation, PostCssTransformOptions},
@@ -35,13 +41,14 @@ use crate::{
     mode::NextMode,
     next_build::get_postcss_package_mapping,
     next_client::runtime_entry::{RuntimeEntries, RuntimeEntry},
-    next_config::NextConfig,
+    next_config::{NextConfig, ReactSingletonMode},
     next_font::local::NextFontLocalResolvePlugin,
     next_import_map::{
         get_next_client_fallback_import_map, get_next_client_import_map,
         get_next_client_resolved_map,
     },
     next_shared::{
+        react_singleton::ReactSingletonResolvePlugin,
         resolve::{
             get_invalid_server_only_resolve_plugin, ModuleFeatureReportResolvePlugin,
             NextSharedRuntimeResolvePlugin,
@@ -60,7 +67,10 @@ use crate::{
         get_decorators_transform_options, get_jsx_transform_options,
         get_typescript_transform_options,
     },
-    util::foreign_code_context_condition,
+    util::{
+        foreign_code_context_condition, get_package_store, get_resolve_modules,
+        get_restricted_imports,
+    },
 };
 
 fn defines(define_env: &FxIndexMap<RcStr, RcStr>) -> CompileTimeDefines {
@@ -165,37 +175,71 @@ pub async fn get_client_resolve_options_context(
         get_next_client_resolved_map(*project_path, project_path, *mode.await?)
             .to_resolved()
             .await?;
-    let custom_conditions = vec![mode.await?.condition().into()];
+    let mut custom_conditions = vec![mode.await?.condition().into()];
+    custom_conditions.extend(
+        next_config
+            .condition_names(vec!["client".into()])
+            .await?
+            .iter()
+            .cloned(),
+    );
+    let mut before_resolve_plugins = vec![
+        ResolvedVc::upcast(
+            get_invalid_server_only_resolve_plugin(project_path)
+                .to_resolved()
+                .await?,
+        ),
+        ResolvedVc::upcast(
+            ModuleFeatureReportResolvePlugin::new(*project_path)
+                .to_resolved()
+                .await?,
+        ),
+        ResolvedVc::upcast(
+            NextFontLocalResolvePlugin::new(*project_path, next_config)
+                .to_resolved()
+                .await?,
+        ),
+    ];
+    if let Some(options) = *next_config.module_federation_options(*project_path).await? {
+        before_resolve_plugins.push(ResolvedVc::upcast(
+            ModuleFederationResolvePlugin::new(*project_path, *options)
+                .to_resolved()
+                .await?,
+        ));
+    }
+    let mut after_resolve_plugins = vec![ResolvedVc::upcast(
+        NextSharedRuntimeResolvePlugin::new(*project_path)
+            .to_resolved()
+            .await?,
+    )];
+    if let Some(mode) = *next_config.react_singleton().await? {
+        after_resolve_plugins.push(ResolvedVc::upcast(
+            ReactSingletonResolvePlugin::new(*project_path, mode == ReactSingletonMode::Enforce)
+                .to_resolved()
+                .await?,
+        ));
+    }
     let module_options_context = ResolveOptionsContext {
         enable_node_modules: Some(project_path.root().to_resolved().await?),
+        enable_yarn_pnp: Some(project_path),
         custom_conditions,
+        package_conditions: next_config
+            .package_conditions(vec!["client".into()])
+            .await?
+            .clone_value(),
         import_map: Some(next_client_import_map),
         fallback_import_map: Some(next_client_fallback_import_map),
         resolved_map: Some(next_client_resolved_map),
         browser: true,
         module: true,
-        before_resolve_plugins: vec![
-            ResolvedVc::upcast(
-                get_invalid_server_only_resolve_plugin(project_path)
-                    .to_resolved()
-                    .await?,
-            ),
-            ResolvedVc::upcast(
-                ModuleFeatureReportResolvePlugin::new(*project_path)
-                    .to_resolved()
-                    .await?,
-            ),
-            ResolvedVc::upcast(
-                NextFontLocalResolvePlugin::new(*project_path)
-                    .to_resolved()
-                    .await?,
-            ),
-        ],
-        after_resolve_plugins: vec![ResolvedVc::upcast(
-            NextSharedRuntimeResolvePlugin::new(*project_path)
-                .to_resolved()
-                .await?,
-        )],
+        preserve_symlinks: *next_config.preserve_symlinks().await?,
+        case_sensitive_paths: *next_config.case_sensitive_paths().await?,
+        restricted_imports: get_restricted_imports(next_config, *project_path).await?,
+        pnpm_injected_from_source: *next_config.pnpm_injected_from_source().await?,
+        package_store: get_package_store(next_config, *project_path).await?,
+        modules: get_resolve_modules(next_config, *project_path).await?,
+        before_resolve_plugins,
+        after_resolve_plugins,
         ..Default::default()
     };
     Ok(ResolveOptionsContext {
@@ -292,8 +336,13 @@ pub async fn get_client_module_options_context(
         get_emotion_transform_rule(next_config).await?,
         get_styled_components_transform_rule(next_config).await?,
         get_styled_jsx_transform_rule(next_config, target_browsers).await?,
-        get_react_remove_properties_transform_rule(next_config).await?,
-        get_remove_console_transform_rule(next_config).await?,
+        get_react_remove_properties_transform_rule(
+            next_config,
+            project_path,
+            next_mode.is_development(),
+        )
+        .await?,
+        get_remove_console_transform_rule(next_config, next_mode.is_development()).await?,
     ]
     .into_iter()
     .flatten()
@@ -328,7 +377,8 @@ pub async fn get_client_module_options_context(
         execution_context: Some(execution_context),
         tree_shaking_mode: tree_shaking_mode_for_user_code,
         enable_postcss_transform,
-        side_effect_free_packages: next_config.optimize_package_imports().await?.clone_value(),
+        side_effect_free_packages: next_config.side_effect_free_packages().await?.clone_value(),
+        asset_inline_limit: *next_config.asset_inline_limit().await?,
         ..Default::default()
     };
 
@@ -402,10 +452,20 @@ pub async fn get_client_chunking_context(
     client_root: ResolvedVc<FileSystemPath>,
     client_root_to_root_path: ResolvedVc<RcStr>,
     asset_prefix: ResolvedVc<Option<RcStr>>,
+    asset_prefix_fallback: ResolvedVc<Option<RcStr>>,
     environment: ResolvedVc<Environment>,
     mode: Vc<NextMode>,
     module_id_strategy: ResolvedVc<Box<dyn ModuleIdStrategy>>,
+    chunk_cache_groups: ResolvedVc<ChunkCacheGroups>,
+    chunk_module_groups: ResolvedVc<ChunkModuleGroups>,
+    chunk_size_limits: ResolvedVc<ChunkSizeLimits>,
     turbo_minify: Vc<bool>,
+    content_hashing: Vc<bool>,
+    runtime_chunk: Vc<bool>,
+    esm_chunks: Vc<bool>,
+    chunk_loading_global: Vc<Option<RcStr>>,
+    css_chunk_splitting: Vc<bool>,
+    max_css_chunk_size: Vc<usize>,
 ) -> Result<Vc<Box<dyn ChunkingContext>>> {
     let next_mode = mode.await?;
     let mut builder = BrowserChunkingContext::builder(
@@ -422,13 +482,26 @@ pub async fn get_client_chunking_context(
         next_mode.runtime_type(),
     )
     .chunk_base_path(asset_prefix)
+    .chunk_fallback_base_path(asset_prefix_fallback)
     .minify_type(if *turbo_minify.await? {
         MinifyType::Minify
     } else {
         MinifyType::NoMinify
     })
+    .content_hashing(*content_hashing.await?)
+    .runtime_chunk(*runtime_chunk.await?)
+    .esm_chunks(*esm_chunks.await?)
     .asset_base_path(asset_prefix)
-    .module_id_strategy(module_id_strategy);
+    .module_id_strategy(module_id_strategy)
+    .chunk_cache_groups(chunk_cache_groups)
+    .chunk_module_groups(chunk_module_groups)
+    .chunk_size_limits(chunk_size_limits)
+    .split_ordered_chunks(*css_chunk_splitting.await?)
+    .max_ordered_chunk_size(*max_css_chunk_size.await?);
+
+    if let Some(chunk_loading_global) = &*chunk_loading_global.await? {
+        builder = builder.chunk_loading_global(chunk_loading_global.clone());
+    }
 
     if next_mode.is_development() {
         builder = builder.hot_module_replacement().use_file_source_map_uris();
@@ -442,6 +515,53 @@ pub fn get_client_assets_path(client_root: Vc<FileSystemPath>) -> Vc<FileSystemP
     client_root.join("static/media".into())
 }
 
+/// The chunking context of the entries of a browser extension, emitted to
+/// `extension_root`. Manifest V3 doesn't allow remote code or `eval`, so the
+/// production runtime is always used and assets are only loaded from the root
+/// of the extension, regardless of the asset prefix of the app.
+///
+/// Chunks can't be loaded on demand: the service worker has no DOM, and the
+/// DOM of content scripts is the one of the page. Like in the edge runtime, the
+/// modules of dynamic `import()`s are placed in the chunk group of the entry
+/// instead, and the runtime only registers the chunks listed in the manifest.
+#[turbo_tasks::function]
+pub async fn get_browser_extension_chunking_context(
+    root_path: ResolvedVc<FileSystemPath>,
+    extension_root: ResolvedVc<FileSystemPath>,
+    extension_root_to_root_path: ResolvedVc<RcStr>,
+    module_id_strategy: ResolvedVc<Box<dyn ModuleIdStrategy>>,
+    turbo_minify: Vc<bool>,
+) -> Result<Vc<Box<dyn ChunkingContext>>> {
+    let base_path = ResolvedVc::cell(Some("/".into()));
+    let environment = Environment::new(Value::new(ExecutionEnvironment::EdgeWorker(
+        EdgeWorkerEnvironment {}.resolved_cell(),
+    )))
+    .to_resolved()
+    .await?;
+    Ok(Vc::upcast(
+        BrowserChunkingContext::builder(
+            root_path,
+            extension_root,
+            extension_root_to_root_path,
+            extension_root,
+            extension_root.join("chunks".into()).to_resolved().await?,
+            extension_root.join("media".into()).to_resolved().await?,
+            environment,
+            RuntimeType::Production,
+        )
+        .name("browser extension".into())
+        .chunk_base_path(base_path)
+        .asset_base_path(base_path)
+        .minify_type(if *turbo_minify.await? {
+            MinifyType::Minify
+        } else {
+            MinifyType::NoMinify
+        })
+        .module_id_strategy(module_id_strategy)
+        .build(),
+    ))
+}
+
 #[turbo_tasks::function]
 pub async fn get_client_runtime_entries(
     project_root: Vc<FileSystemPath>,
@@ -7,16 +7,19 @@ use crate::{
     mode::NextMode,
     next_client::context::ClientContextType,
     next_config::NextConfig,
-    next_shared::transforms::{
-        debug_fn_name::get_debug_fn_name_rule, get_next_dynamic_transform_rule,
-        get_next_font_transform_rule, get_next_image_rule, get_next_lint_transform_rule,
-        get_next_modularize_imports_rule, get_next_pages_transforms_rule,
-        get_server_actions_transform_rule, next_amp_attributes::get_next_amp_attr_rule,
-        next_cjs_optimizer::get_next_cjs_optimizer_rule,
-        next_disallow_re_export_all_in_page::get_next_disallow_export_all_in_page_rule,
-        next_page_config::get_next_page_config_rule,
-        next_page_static_info::get_next_page_static_info_assert_rule,
-        next_pure::get_next_pure_rule, server_actions::ActionsTransform,
+    next_shared::{
+        module_federation::get_module_federation_rule,
+        transforms::{
+            debug_fn_name::get_debug_fn_name_rule, get_next_dynamic_transform_rule,
+            get_next_font_transform_rule, get_next_image_rule, get_next_lint_transform_rule,
+            get_next_modularize_imports_rule, get_next_pages_transforms_rule,
+            get_server_actions_transform_rule, next_amp_attributes::get_next_amp_attr_rule,
+            next_cjs_optimizer::get_next_cjs_optimizer_rule,
+            next_disallow_re_export_all_in_page::get_next_disallow_export_all_in_page_rule,
+            next_page_config::get_next_page_config_rule,
+            next_page_static_info::get_next_page_static_info_assert_rule,
+            next_pure::get_next_pure_rule, server_actions::ActionsTransform,
+        },
     },
 };
 
@@ -91,6 +94,7 @@ pub async fn get_next_client_transforms_rules(
         );
 
         rules.push(get_next_image_rule().await?);
+        rules.push(get_module_federation_rule().await?);
         rules.push(get_next_page_static_info_assert_rule(
             enable_mdx_rs,
             None,
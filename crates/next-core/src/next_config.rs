@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use anyhow::{bail, Context, Result};
 use rustc_hash::FxHashSet;
@@ -7,23 +7,34 @@ use serde_json::Value as JsonValue;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{
     debug::ValueDebugFormat, trace::TraceRawVcs, FxIndexMap, NonLocalValue, OperationValue,
-    ResolvedVc, TaskInput, Vc,
+    ResolvedVc, TaskInput, TryJoinIterExt, Vc,
 };
 use turbo_tasks_env::EnvMap;
-use turbo_tasks_fs::FileSystemPath;
+use turbo_tasks_fs::{FileJsonContent, FileSystemPath};
 use turbopack::module_options::{
     module_options_context::MdxTransformOptions, LoaderRuleItem, OptionWebpackRules,
 };
 use turbopack_core::{
+    chunk::chunking::{
+        ChunkCacheGroup, ChunkCacheGroups, ChunkModuleGroup, ChunkModuleGroups, ChunkSizeLimits,
+    },
     issue::{Issue, IssueSeverity, IssueStage, OptionStyledString, StyledString},
     resolve::ResolveAliasMap,
 };
-use turbopack_ecmascript::{OptionTreeShaking, TreeShakingMode};
+use turbopack_ecmascript::{
+    federation::{ModuleFederationOptions, OptionModuleFederationOptions, SharedModule},
+    OptionTreeShaking, TreeShakingMode,
+};
 use turbopack_ecmascript_plugins::transform::{
     emotion::EmotionTransformConfig, relay::RelayConfig,
     styled_components::StyledComponentsTransformConfig,
 };
-use turbopack_node::transforms::webpack::{WebpackLoaderItem, WebpackLoaderItems};
+use turbopack_node::{
+    evaluate::{EvaluateTimeout, EvaluateTimeouts},
+    loader_permissions::{LoaderPermissions, OptionLoaderPermissions},
+    transforms::webpack::{WebpackLoaderItem, WebpackLoaderItems},
+};
+use turbopack_static::inline::AssetInlineLimit;
 
 use crate::{
     mode::NextMode, next_import_map::mdx_import_source_file,
@@ -521,12 +532,347 @@ pub struct ExperimentalTurboConfig {
     pub rules: Option<FxIndexMap<RcStr, RuleConfigItemOrShortcut>>,
     pub resolve_alias: Option<FxIndexMap<RcStr, JsonValue>>,
     pub resolve_extensions: Option<Vec<RcStr>>,
+    /// Requests to resolve differently, either as a static replacement or
+    /// through a `resolve` callback in next.config. They take precedence over
+    /// `resolve_alias` and the aliases set up by Next.js.
+    pub resolve_interceptors: Option<Vec<ResolveInterceptorConfig>>,
+    /// Keep symlinked paths of resolved modules instead of following them to
+    /// the real path, like webpack's `resolve.symlinks: false`. A linked
+    /// package is then identified by its path in `node_modules`.
+    pub preserve_symlinks: Option<bool>,
+    /// Report requests whose casing doesn't match the resolved file on disk,
+    /// like `case-sensitive-paths-webpack-plugin`. They resolve on
+    /// case-insensitive file systems only.
+    pub case_sensitive_paths: Option<bool>,
+    /// Imports to forbid in parts of the app, reported as errors at the
+    /// location of the import, like ESLint's `no-restricted-imports`.
+    pub restricted_imports: Option<Vec<RestrictedImportConfig>>,
+    /// Allow edge runtime code to import modules from `https://` URLs, which
+    /// are verified against the hashes of a lockfile and cached on disk.
+    pub remote_imports: Option<RemoteImportsConfig>,
+    /// Read the files of pnpm's injected dependencies from the workspace
+    /// package they were copied from instead of the copy in the virtual
+    /// store. Defaults to `true`.
+    pub pnpm_injected_from_source: Option<bool>,
+    /// Resolve the packages of `package-lock.json` from a global
+    /// content-addressed store instead of `node_modules`.
+    pub package_store: Option<PackageStoreConfig>,
+    /// Additional directories to resolve packages in before node_modules, like
+    /// webpack's `resolve.modules`. Names like `src` are looked up in the
+    /// directory of the importing module and its parents, paths like
+    /// `./vendor` are relative to the project.
+    pub resolve_modules: Option<Vec<RcStr>>,
+    /// Conditions to resolve the exports field of specific packages with, per
+    /// graph (`client`, `server`, `edge` or `react-server`), e.g.
+    /// `{ "some-package": { "server": ["browser", "default"] } }`.
+    pub package_conditions: Option<FxIndexMap<RcStr, FxIndexMap<RcStr, Vec<RcStr>>>>,
+    /// Additional conditions to resolve the exports and imports fields of all
+    /// packages with, per graph (`client`, `server`, `edge` or
+    /// `react-server`), e.g. `{ "edge": ["worker"] }`.
+    pub condition_names: Option<FxIndexMap<RcStr, Vec<RcStr>>>,
+    /// Retry loading chunks from the same origin when loading them from the
+    /// `assetPrefix` fails. In production builds, retried chunks are
+    /// verified with the hashes of `static/chunks/integrity-manifest.json`.
+    pub asset_prefix_fallback: Option<bool>,
+    /// Named groups of packages or modules which are placed in client chunks
+    /// of their own, e.g.
+    /// `{ "react-vendor": ["react", "react-dom", "@radix-ui/*"] }`.
+    pub chunk_cache_groups: Option<FxIndexMap<RcStr, ChunkCacheGroupConfig>>,
+    /// Named groups of packages or modules which are never split across
+    /// client chunks, e.g. a WASM wrapper and its JavaScript glue code.
+    pub module_groups: Option<FxIndexMap<RcStr, ModuleGroupConfig>>,
+    /// Client chunks smaller than this many bytes are merged with others.
+    /// Defaults to 100kB.
+    pub min_chunk_size: Option<usize>,
+    /// Client chunks larger than this many bytes are split at module
+    /// boundaries. Defaults to 1MB.
+    pub max_chunk_size: Option<usize>,
+    /// Theme variants built in the same compilation, as import map aliases
+    /// keyed by theme id, e.g.
+    /// `{ "acme": { "@/theme/tokens.css": "./themes/acme/tokens.css" } }`. The
+    /// client CSS of each route is built again for every theme, with its aliases
+    /// applied to the imports of the stylesheets, and listed in
+    /// `theme-manifest.json`.
+    pub themes: Option<FxIndexMap<RcStr, FxIndexMap<RcStr, RcStr>>>,
+    /// Build a browser extension (Manifest V3) from the entries referenced by
+    /// a `manifest.json` template, in the same compilation as the app.
+    pub browser_extension: Option<BrowserExtensionConfig>,
+    /// Write a report of the dynamic imports, `eval`, Node.js builtins, legacy
+    /// React context and CommonJS-only packages used by the app to
+    /// `feature-usage.json` in the dist dir when building.
+    pub feature_usage_report: Option<bool>,
+    /// Write a report of the `process.env` variables referenced by the modules
+    /// of each route, middleware and instrumentation, and whether they're
+    /// inlined when building, to `env-usage.json` in the dist dir.
+    pub env_usage_report: Option<bool>,
+    /// Thresholds after which loaders and PostCSS running in the Node.js
+    /// workers are reported as slow or aborted.
+    pub worker_timeouts: Option<WorkerTimeoutsConfig>,
+    /// Inline static files smaller than a size referenced from JavaScript and
+    /// CSS as `data:` URLs instead of emitting them.
+    pub inline_assets: Option<InlineAssetsConfig>,
     pub tree_shaking: Option<bool>,
+    /// Defaults to `Named`. `Deterministic` derives the module ids from the
+    /// hashed module idents, so they stay the same across production builds.
     pub module_id_strategy: Option<ModuleIdStrategy>,
     pub minify: Option<bool>,
+    /// Add a hash of their content to the file names of client chunks in
+    /// production builds, and list them in `chunk-hash-manifest.json`.
+    pub content_hashing: Option<bool>,
+    /// Emit the runtime as a chunk of its own, which is shared by all pages,
+    /// instead of including it in the entry chunks of every page.
+    pub runtime_chunk: Option<bool>,
+    /// Emit client chunks as ES modules, which are loaded through
+    /// `<script type="module">` and `import()` instead of classic scripts.
+    /// Only supported by browsers with native module support, and modules are
+    /// evaluated in strict mode.
+    pub esm_chunks: Option<bool>,
+    /// Split the CSS of a chunk group into multiple chunks, which are loaded in
+    /// import order, instead of a single one.
+    pub css_chunk_splitting: Option<bool>,
+    /// Split the CSS chunk of a chunk group into consecutive chunks of at most
+    /// this size, when `css_chunk_splitting` isn't enabled.
+    pub max_css_chunk_size: Option<usize>,
+    /// Expose modules to and consume modules of other builds at runtime.
+    pub module_federation: Option<ModuleFederationConfig>,
+    /// Restrict the file system access of the webpack loaders of `rules` which
+    /// aren't trusted.
+    pub loader_permissions: Option<LoaderPermissionsConfig>,
+    /// Limit the size of the JS chunks routes load initially in production
+    /// builds.
+    pub chunking_budget: Option<ChunkingBudgetConfig>,
+    /// Check that `react`, `react-dom` and `scheduler` resolve to a single
+    /// copy in each runtime. Duplicates of the same version are aliased to
+    /// the hoisted copy in `enforce` mode.
+    pub react_singleton: Option<ReactSingletonMode>,
+    /// Packages whose modules are treated as side-effect-free regardless of
+    /// the `sideEffects` field of their `package.json`, in addition to
+    /// `optimize_package_imports`.
+    pub side_effect_free_packages: Option<Vec<RcStr>>,
     pub unstable_persistent_caching: Option<bool>,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ReactSingletonMode {
+    /// Report duplicate copies as warnings.
+    Warn,
+    /// Alias duplicate copies of the same version to the hoisted copy, and
+    /// report the others as errors.
+    Enforce,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkingBudgetConfig {
+    /// The maximum size of the JS chunks a route loads initially, in bytes.
+    pub max_initial_js_bytes: u64,
+    /// Fail the build when a route exceeds the budget instead of warning.
+    pub fail_on_exceed: Option<bool>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LoaderPermissionsConfig {
+    /// Loaders which run without restrictions, e.g. `@svgr/webpack`.
+    pub trusted: Option<Vec<RcStr>>,
+    /// Paths relative to the project which untrusted loaders can read in
+    /// addition to the project.
+    pub allow_read: Option<Vec<RcStr>>,
+    /// Paths relative to the project which untrusted loaders can write.
+    pub allow_write: Option<Vec<RcStr>>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleFederationConfig {
+    /// The name of the container exposing `exposes`, which other builds use
+    /// as the name of this remote.
+    pub name: Option<RcStr>,
+    /// Modules exposed to other builds, e.g. `{ "./Button":
+    /// "./components/Button" }`.
+    pub exposes: Option<FxIndexMap<RcStr, RcStr>>,
+    /// The URLs of the remote entries of other builds by their name, e.g.
+    /// `{ "app2": "https://app2.example.com/_next/static/remoteEntry.js" }`.
+    pub remotes: Option<FxIndexMap<RcStr, RcStr>>,
+    /// Packages shared with other builds.
+    pub shared: Option<FxIndexMap<RcStr, SharedModuleConfig>>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedModuleConfig {
+    /// Only a single version of the package is used on a page.
+    pub singleton: Option<bool>,
+    /// The semver range of versions of the package this build works with.
+    pub required_version: Option<RcStr>,
+    /// The version of the package provided by this build. Read from its
+    /// `package.json` by default.
+    pub version: Option<RcStr>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolveInterceptorConfig {
+    /// The requests to intercept, an exact request or a pattern with a `*`
+    /// wildcard like the keys of `resolveAlias`.
+    pub test: RcStr,
+    /// The request to resolve instead, relative to the importing module. A `*`
+    /// is replaced with the part of the request matched by the wildcard. When
+    /// it's missing, the `resolve` callback of the interceptor is called.
+    pub replacement: Option<RcStr>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserExtensionConfig {
+    /// The `manifest.json` template, relative to the project. The scripts it
+    /// references are compiled and replaced with the generated files.
+    pub manifest: RcStr,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RestrictedImportConfig {
+    /// A glob of the importing modules, relative to the project, e.g.
+    /// `app/**`.
+    pub from: RcStr,
+    /// Globs of the forbidden requests as written in the source, e.g.
+    /// `lodash` or `@/server/**`.
+    pub disallow: Vec<RcStr>,
+    pub message: Option<RcStr>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteImportsConfig {
+    /// The lockfile with the hashes of the remote modules, relative to the
+    /// project. Defaults to `remote-imports.lock`.
+    pub lockfile: Option<RcStr>,
+    /// The directory, relative to the project, remote modules are cached in.
+    /// Defaults to `<distDir>/cache/remote-imports`.
+    pub cache_directory: Option<RcStr>,
+    /// Whether modules missing from the lockfile are errors. Defaults to
+    /// `true` in `next build`.
+    pub frozen_lockfile: Option<bool>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageStoreConfig {
+    /// The root of the store, relative to the project. Packages are stored in
+    /// `<directory>/<algorithm>/<digest>` by the integrity of their tarball,
+    /// with the digest base64url encoded without padding.
+    pub directory: RcStr,
+    /// The lockfile, relative to the project. Defaults to `package-lock.json`.
+    pub lockfile: Option<RcStr>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct InlineAssetsConfig {
+    /// Files smaller than this many bytes are inlined, unless their extension
+    /// has a limit of its own.
+    pub limit: Option<u64>,
+    /// Limits in bytes by file extension, e.g. `{ "svg": 4096, "png": 0 }`.
+    pub extensions: Option<FxIndexMap<RcStr, u64>>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerTimeoutsConfig {
+    /// The thresholds of all operations. Slow operations are reported after
+    /// [DEFAULT_WORKER_SLOW_AFTER_MS] unless configured otherwise.
+    #[serde(flatten)]
+    pub default: WorkerTimeoutConfig,
+    /// Thresholds of specific operations, keyed by the name of a loader or
+    /// `postcss`. Missing thresholds are taken from the default ones.
+    pub operations: Option<FxIndexMap<RcStr, WorkerTimeoutConfig>>,
+}
+
+const DEFAULT_WORKER_SLOW_AFTER_MS: u64 = 30_000;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerTimeoutConfig {
+    /// Warn about the operation once it runs longer than this.
+    pub slow_after_ms: Option<u64>,
+    /// Abort the operation and stop its worker once it runs longer than this.
+    pub timeout_ms: Option<u64>,
+}
+
+impl From<WorkerTimeoutConfig> for EvaluateTimeout {
+    fn from(config: WorkerTimeoutConfig) -> Self {
+        EvaluateTimeout {
+            slow_after_ms: config.slow_after_ms,
+            timeout_ms: config.timeout_ms,
+        }
+    }
+}
+
 #[derive(
     Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
 )]
@@ -556,6 +902,34 @@ pub enum RuleConfigItem {
     Boolean(bool),
 }
 
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(untagged)]
+pub enum ChunkCacheGroupConfig {
+    Packages(Vec<RcStr>),
+    Options {
+        #[serde(default)]
+        packages: Vec<RcStr>,
+        /// The source of a regular expression matched against module
+        /// identifiers.
+        test: Option<RcStr>,
+    },
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleGroupConfig {
+    #[serde(default)]
+    pub packages: Vec<RcStr>,
+    /// Globs matched against the paths of modules, relative to the project
+    /// directory.
+    #[serde(default)]
+    pub modules: Vec<RcStr>,
+}
+
 #[derive(
     Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
 )]
@@ -662,6 +1036,28 @@ pub struct ExperimentalConfig {
     web_vitals_attribution: Option<Vec<RcStr>>,
     server_actions: Option<ServerActionsOrLegacyBool>,
     sri: Option<SubResourceIntegrity>,
+    /// Serve `next/font/google` fonts from a Google Fonts-compatible provider
+    /// instead, e.g. Bunny Fonts or a self-hosted mirror.
+    font_provider: Option<FontProviderConfig>,
+    /// Path to a PEM bundle of additional CA certificates to trust when
+    /// fetching fonts, e.g. for proxies intercepting TLS.
+    font_ca_certificates: Option<RcStr>,
+    /// A project-local directory caching the stylesheets and font files
+    /// fetched for `next/font/google`, allowing builds without network access.
+    font_cache: Option<FontCacheConfig>,
+    font_downloads: Option<FontDownloadsConfig>,
+    /// Subsets self-hosted `next/font/google` fonts to the declared `subsets`.
+    /// Font files with layout tables are kept complete. Defaults to `true`.
+    font_subsetting: Option<bool>,
+    /// Metrics of additional locally installed fonts, keyed by font name, that
+    /// `next/font/local` can adjust as a fallback with `adjustFontFallback`.
+    font_metrics: Option<BTreeMap<RcStr, FontMetricsConfig>>,
+    /// How the suffix scoping `next/font` requests (in generated filenames and
+    /// class names) is derived.
+    font_family_hashing: Option<FontFamilyHashing>,
+    /// Generates `sitemap.xml` and `robots.txt` from the static routes at
+    /// build time.
+    sitemap: Option<SitemapConfig>,
     react_compiler: Option<ReactCompilerOptionsOrBoolean>,
     #[serde(rename = "dynamicIO")]
     dynamic_io: Option<bool>,
@@ -692,6 +1088,9 @@ pub struct ExperimentalConfig {
     gzip_size: Option<bool>,
 
     pub inline_css: Option<bool>,
+    /// Inline the runtime chunk into the HTML of app pages, saving a request
+    /// before hydration. Only applies to production builds.
+    inline_runtime_chunk: Option<InlineRuntimeChunk>,
     instrumentation_hook: Option<bool>,
     client_trace_metadata: Option<Vec<String>>,
     large_page_data_bytes: Option<f64>,
@@ -841,6 +1240,150 @@ pub struct SubResourceIntegrity {
     pub algorithm: Option<RcStr>,
 }
 
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct FontProviderConfig {
+    /// The API of the provider. Defaults to [FontProviderKind::Css2].
+    #[serde(rename = "type", default)]
+    pub kind: FontProviderKind,
+    /// The css2 API endpoint stylesheets are requested from, e.g.
+    /// `https://fonts.bunny.net/css2`. Required for css2 providers.
+    pub stylesheet_url: Option<RcStr>,
+    /// The user agent sent when fetching stylesheets and font files. Defaults
+    /// to the one used for Google Fonts.
+    pub user_agent: Option<RcStr>,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    OperationValue,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FontProviderKind {
+    /// The Google Fonts css2 API, which is also implemented by e.g. Bunny
+    /// Fonts.
+    #[default]
+    Css2,
+    /// Fontsource's metadata API, with the font files served from its CDN.
+    Fontsource,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct FontCacheConfig {
+    /// The cache directory, relative to the project.
+    pub directory: RcStr,
+    /// Resolve fonts only from the cache instead of populating it from the
+    /// network. Missing entries are errors.
+    pub offline: Option<bool>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct FontDownloadsConfig {
+    /// The maximum number of font files downloaded at the same time, across
+    /// all `next/font/google` requests. Defaults to 8.
+    pub concurrency: Option<usize>,
+    /// How often failed downloads are retried. Defaults to 3.
+    pub retries: Option<u32>,
+    /// The directory, relative to the project, downloaded font files are
+    /// cached in across builds. Defaults to `<distDir>/cache/fonts`.
+    pub cache_directory: Option<RcStr>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct FontMetricsConfig {
+    /// The key of the font in the capsize metrics. Defaults to its name.
+    pub capsize_key: Option<RcStr>,
+    /// The average advance width of `a-z` and spaces, in font units.
+    pub az_avg_width: f64,
+    pub units_per_em: u32,
+}
+
+#[turbo_tasks::value(shared, operation)]
+#[derive(Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum FontFamilyHashing {
+    /// A hash of the content of the font request.
+    Content,
+    /// A hash of the content of the font request and the salt.
+    Salt(RcStr),
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SitemapConfig {
+    /// The origin the site is served from, e.g. `https://example.com`.
+    pub base_url: RcStr,
+    /// Entries to add to the discovered routes, e.g. for dynamic routes.
+    /// Entries for a discovered route replace it.
+    #[serde(default)]
+    pub entries: Vec<SitemapEntry>,
+    /// Path prefixes to leave out of the sitemap, which are also disallowed in
+    /// `robots.txt`.
+    #[serde(default)]
+    pub exclude: Vec<RcStr>,
+    /// Whether to generate `robots.txt` referencing the sitemap. Defaults to
+    /// true.
+    pub robots: Option<bool>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct SitemapEntry {
+    /// An absolute url, or a path relative to `baseUrl`.
+    pub url: RcStr,
+    pub last_modified: Option<RcStr>,
+    pub change_frequency: Option<RcStr>,
+    pub priority: Option<f64>,
+}
+
+#[derive(
+    Clone, Debug, PartialEq, Deserialize, Serialize, TraceRawVcs, NonLocalValue, OperationValue,
+)]
+#[serde(untagged)]
+pub enum InlineRuntimeChunk {
+    All(bool),
+    /// The route groups, e.g. `(marketing)`, whose pages inline the runtime
+    /// chunk.
+    RouteGroups(Vec<RcStr>),
+}
+
+impl InlineRuntimeChunk {
+    /// Whether the app page, e.g. `/(marketing)/about/page`, inlines the runtime
+    /// chunk.
+    pub fn applies_to(&self, page: &str) -> bool {
+        match self {
+            InlineRuntimeChunk::All(enabled) => *enabled,
+            InlineRuntimeChunk::RouteGroups(groups) => page
+                .split('/')
+                .any(|segment| groups.iter().any(|group| group == segment)),
+        }
+    }
+}
+
 #[derive(
     Clone, Debug, PartialEq, Deserialize, Serialize, TraceRawVcs, NonLocalValue, OperationValue,
 )]
@@ -991,7 +1534,12 @@ pub struct CompilerConfig {
 #[serde(untagged, rename_all = "camelCase")]
 pub enum ReactRemoveProperties {
     Boolean(bool),
-    Config { properties: Option<Vec<String>> },
+    #[serde(rename_all = "camelCase")]
+    Config {
+        properties: Option<Vec<String>>,
+        /// Keep the properties in development.
+        production_only: Option<bool>,
+    },
 }
 
 impl ReactRemoveProperties {
@@ -1009,7 +1557,12 @@ impl ReactRemoveProperties {
 #[serde(untagged)]
 pub enum RemoveConsoleConfig {
     Boolean(bool),
-    Config { exclude: Option<Vec<String>> },
+    #[serde(rename_all = "camelCase")]
+    Config {
+        exclude: Option<Vec<String>>,
+        /// Keep `console` calls in development.
+        production_only: Option<bool>,
+    },
 }
 
 impl RemoveConsoleConfig {
@@ -1024,6 +1577,12 @@ impl RemoveConsoleConfig {
 #[turbo_tasks::value(transparent)]
 pub struct ResolveExtensions(Option<Vec<RcStr>>);
 
+#[turbo_tasks::value(transparent)]
+pub struct PackageConditions(BTreeMap<RcStr, Vec<RcStr>>);
+
+#[turbo_tasks::value(transparent)]
+pub struct RestrictedImportConfigs(Vec<RestrictedImportConfig>);
+
 #[turbo_tasks::value(transparent)]
 pub struct SwcPlugins(Vec<(RcStr, serde_json::Value)>);
 
@@ -1034,9 +1593,45 @@ pub struct OptionalMdxTransformOptions(Option<ResolvedVc<MdxTransformOptions>>);
 
 pub struct OptionSubResourceIntegrity(Option<SubResourceIntegrity>);
 
+#[turbo_tasks::value(transparent)]
+pub struct OptionFontProviderConfig(Option<FontProviderConfig>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionFontCacheConfig(Option<FontCacheConfig>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionFontDownloadsConfig(Option<FontDownloadsConfig>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionRemoteImportsConfig(Option<RemoteImportsConfig>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionPackageStoreConfig(Option<PackageStoreConfig>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionAssetInlineLimit(Option<ResolvedVc<AssetInlineLimit>>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionChunkingBudget(Option<ChunkingBudgetConfig>);
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionReactSingletonMode(Option<ReactSingletonMode>);
+
+#[turbo_tasks::value(transparent, eq = "manual")]
+pub struct FontMetricsConfigs(BTreeMap<RcStr, FontMetricsConfig>);
+
+#[turbo_tasks::value(transparent, eq = "manual")]
+pub struct OptionSitemapConfig(Option<SitemapConfig>);
+
 #[turbo_tasks::value(transparent)]
 pub struct OptionServerActions(Option<ServerActions>);
 
+#[turbo_tasks::value(transparent)]
+pub struct ResolveInterceptors(Vec<ResolveInterceptorConfig>);
+
+#[turbo_tasks::value(transparent)]
+pub struct Themes(FxIndexMap<RcStr, FxIndexMap<RcStr, RcStr>>);
+
 #[turbo_tasks::value_impl]
 impl NextConfig {
     #[turbo_tasks::function]
@@ -1234,6 +1829,17 @@ impl NextConfig {
         Ok(alias_map.cell())
     }
 
+    #[turbo_tasks::function]
+    pub fn resolve_interceptors(&self) -> Vc<ResolveInterceptors> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.resolve_interceptors.clone())
+                .unwrap_or_default(),
+        )
+    }
+
     #[turbo_tasks::function]
     pub fn resolve_extension(&self) -> Vc<ResolveExtensions> {
         let Some(resolve_extensions) = self
@@ -1247,18 +1853,69 @@ impl NextConfig {
         Vc::cell(Some(resolve_extensions.clone()))
     }
 
+    /// Returns the configured conditions for resolving packages in a graph.
+    /// `graphs` are the names that apply to the graph, by precedence.
     #[turbo_tasks::function]
-    pub async fn import_externals(&self) -> Result<Vc<bool>> {
-        Ok(Vc::cell(match self.experimental.esm_externals {
-            Some(EsmExternals::Bool(b)) => b,
-            Some(EsmExternals::Loose(_)) => bail!("esmExternals = \"loose\" is not supported"),
-            None => true,
-        }))
+    pub fn package_conditions(&self, graphs: Vec<RcStr>) -> Vc<PackageConditions> {
+        let Some(package_conditions) = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.package_conditions.as_ref())
+        else {
+            return Vc::cell(Default::default());
+        };
+        Vc::cell(
+            package_conditions
+                .iter()
+                .filter_map(|(package, conditions_by_graph)| {
+                    let conditions = graphs
+                        .iter()
+                        .find_map(|graph| conditions_by_graph.get(graph))?;
+                    Some((package.clone(), conditions.clone()))
+                })
+                .collect(),
+        )
     }
 
+    /// Returns the additional conditions for resolving all packages in a
+    /// graph. The conditions of all `graphs` that apply to the graph are
+    /// combined.
     #[turbo_tasks::function]
-    pub fn mdx_rs(&self) -> Vc<OptionalMdxTransformOptions> {
-        let options = &self.experimental.mdx_rs;
+    pub fn condition_names(&self, graphs: Vec<RcStr>) -> Vc<Vec<RcStr>> {
+        let Some(condition_names) = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.condition_names.as_ref())
+        else {
+            return Vc::cell(Default::default());
+        };
+        let mut conditions = Vec::new();
+        for condition in graphs
+            .iter()
+            .filter_map(|graph| condition_names.get(graph))
+            .flatten()
+        {
+            if !conditions.contains(condition) {
+                conditions.push(condition.clone());
+            }
+        }
+        Vc::cell(conditions)
+    }
+
+    #[turbo_tasks::function]
+    pub async fn import_externals(&self) -> Result<Vc<bool>> {
+        Ok(Vc::cell(match self.experimental.esm_externals {
+            Some(EsmExternals::Bool(b)) => b,
+            Some(EsmExternals::Loose(_)) => bail!("esmExternals = \"loose\" is not supported"),
+            None => true,
+        }))
+    }
+
+    #[turbo_tasks::function]
+    pub fn mdx_rs(&self) -> Vc<OptionalMdxTransformOptions> {
+        let options = &self.experimental.mdx_rs;
 
         let options = match options {
             Some(MdxRsOptions::Boolean(true)) => OptionalMdxTransformOptions(Some(
@@ -1301,6 +1958,60 @@ impl NextConfig {
         Vc::cell(self.experimental.sri.clone())
     }
 
+    #[turbo_tasks::function]
+    pub fn experimental_font_provider(&self) -> Vc<OptionFontProviderConfig> {
+        Vc::cell(self.experimental.font_provider.clone())
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_font_ca_certificates(&self) -> Vc<Option<RcStr>> {
+        Vc::cell(self.experimental.font_ca_certificates.clone())
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_font_cache(&self) -> Vc<OptionFontCacheConfig> {
+        Vc::cell(self.experimental.font_cache.clone())
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_font_downloads(&self) -> Vc<OptionFontDownloadsConfig> {
+        Vc::cell(self.experimental.font_downloads.clone())
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_font_subsetting(&self) -> Vc<bool> {
+        Vc::cell(self.experimental.font_subsetting.unwrap_or(true))
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_font_metrics(&self) -> Vc<FontMetricsConfigs> {
+        Vc::cell(self.experimental.font_metrics.clone().unwrap_or_default())
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_font_family_hashing(&self) -> Vc<FontFamilyHashing> {
+        self.experimental
+            .font_family_hashing
+            .clone()
+            .unwrap_or(FontFamilyHashing::Content)
+            .cell()
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_sitemap(&self) -> Vc<OptionSitemapConfig> {
+        Vc::cell(self.experimental.sitemap.clone())
+    }
+
+    #[turbo_tasks::function]
+    pub fn experimental_inline_runtime_chunk(&self, page: RcStr) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .inline_runtime_chunk
+                .as_ref()
+                .is_some_and(|inline| inline.applies_to(&page)),
+        )
+    }
+
     #[turbo_tasks::function]
     pub fn experimental_server_actions(&self) -> Vc<OptionServerActions> {
         Vc::cell(match self.experimental.server_actions.as_ref() {
@@ -1370,6 +2081,291 @@ impl NextConfig {
         )))
     }
 
+    #[turbo_tasks::function]
+    pub fn chunk_cache_groups(&self) -> Vc<ChunkCacheGroups> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.chunk_cache_groups.as_ref())
+                .map(|groups| {
+                    groups
+                        .iter()
+                        .map(|(name, config)| match config {
+                            ChunkCacheGroupConfig::Packages(packages) => ChunkCacheGroup {
+                                name: name.clone(),
+                                packages: packages.clone(),
+                                test: None,
+                            },
+                            ChunkCacheGroupConfig::Options { packages, test } => ChunkCacheGroup {
+                                name: name.clone(),
+                                packages: packages.clone(),
+                                test: test.clone(),
+                            },
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub async fn chunk_module_groups(
+        &self,
+        project_path: Vc<FileSystemPath>,
+    ) -> Result<Vc<ChunkModuleGroups>> {
+        let Some(groups) = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.module_groups.as_ref())
+        else {
+            return Ok(ChunkModuleGroups::empty());
+        };
+        let project_path = project_path.await?;
+        Ok(Vc::cell(
+            groups
+                .iter()
+                .map(|(name, config)| ChunkModuleGroup {
+                    name: name.clone(),
+                    packages: config.packages.clone(),
+                    modules: config
+                        .modules
+                        .iter()
+                        .map(|glob| {
+                            let glob = glob.trim_start_matches("./");
+                            if project_path.path.is_empty() {
+                                glob.into()
+                            } else {
+                                format!("{}/{glob}", project_path.path).into()
+                            }
+                        })
+                        .collect(),
+                })
+                .collect(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    pub fn chunk_size_limits(&self) -> Vc<ChunkSizeLimits> {
+        let defaults = ChunkSizeLimits::default();
+        let turbo = self.experimental.turbo.as_ref();
+        let max = turbo.and_then(|t| t.max_chunk_size).unwrap_or(defaults.max);
+        let min = turbo.and_then(|t| t.min_chunk_size).unwrap_or(defaults.min);
+        ChunkSizeLimits {
+            min: min.min(max),
+            max,
+        }
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    pub fn themes(&self) -> Vc<Themes> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.themes.clone())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// The `manifest.json` template of the browser extension, if one is built.
+    #[turbo_tasks::function]
+    pub fn browser_extension_manifest(&self) -> Vc<Option<RcStr>> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.browser_extension.as_ref())
+                .map(|b| b.manifest.clone()),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn preserve_symlinks(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.preserve_symlinks)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn case_sensitive_paths(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.case_sensitive_paths)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn restricted_imports(&self) -> Vc<RestrictedImportConfigs> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.restricted_imports.clone())
+                .unwrap_or_default(),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn pnpm_injected_from_source(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.pnpm_injected_from_source)
+                .unwrap_or(true),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn remote_imports(&self) -> Vc<OptionRemoteImportsConfig> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.remote_imports.clone()),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn package_store(&self) -> Vc<OptionPackageStoreConfig> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.package_store.clone()),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn asset_inline_limit(&self) -> Vc<OptionAssetInlineLimit> {
+        let Some(config) = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.inline_assets.as_ref())
+        else {
+            return Vc::cell(None);
+        };
+        Vc::cell(Some(
+            AssetInlineLimit {
+                default: config.limit,
+                extensions: config
+                    .extensions
+                    .iter()
+                    .flatten()
+                    .map(|(extension, limit)| (extension.trim_start_matches('.').into(), *limit))
+                    .collect(),
+            }
+            .resolved_cell(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    pub fn resolve_modules(&self) -> Vc<Vec<RcStr>> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.resolve_modules.clone())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// The thresholds of the operations running in the Node.js workers.
+    #[turbo_tasks::function]
+    pub fn worker_timeouts(&self) -> Vc<EvaluateTimeouts> {
+        let config = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.worker_timeouts.as_ref());
+        let mut default = config
+            .map(|config| EvaluateTimeout::from(config.default))
+            .unwrap_or_default();
+        if config.is_none_or(|config| config.default.slow_after_ms.is_none()) {
+            default.slow_after_ms = Some(DEFAULT_WORKER_SLOW_AFTER_MS);
+        }
+        EvaluateTimeouts {
+            default,
+            operations: config
+                .and_then(|config| config.operations.as_ref())
+                .map(|operations| {
+                    operations
+                        .iter()
+                        .map(|(name, timeout)| {
+                            let timeout = EvaluateTimeout {
+                                slow_after_ms: timeout.slow_after_ms.or(default.slow_after_ms),
+                                timeout_ms: timeout.timeout_ms.or(default.timeout_ms),
+                            };
+                            (name.clone(), timeout)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    pub fn feature_usage_report(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.feature_usage_report)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn env_usage_report(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.env_usage_report)
+                .unwrap_or(false),
+        )
+    }
+
+    /// Returns the same-origin path chunks are loaded from when loading them
+    /// from the `assetPrefix` fails, or `None` when
+    /// `experimental.turbo.assetPrefixFallback` is disabled or there is no
+    /// `assetPrefix` to fall back from.
+    #[turbo_tasks::function]
+    pub fn asset_prefix_fallback(&self) -> Vc<Option<RcStr>> {
+        let enabled = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.asset_prefix_fallback)
+            .unwrap_or(false);
+        if !enabled || self.asset_prefix.as_ref().is_none_or(|p| p.is_empty()) {
+            return Vc::cell(None);
+        }
+
+        Vc::cell(Some(
+            format!(
+                "{}/_next/",
+                self.base_path
+                    .as_ref()
+                    .map_or("", |b| b.as_str())
+                    .trim_end_matches('/')
+            )
+            .into(),
+        ))
+    }
+
     #[turbo_tasks::function]
     pub fn enable_ppr(&self) -> Vc<bool> {
         Vc::cell(
@@ -1422,6 +2418,27 @@ impl NextConfig {
         )
     }
 
+    /// The packages whose modules are treated as side-effect-free, i.e. those
+    /// of `experimental.optimizePackageImports` and
+    /// `experimental.turbo.sideEffectFreePackages`.
+    #[turbo_tasks::function]
+    pub fn side_effect_free_packages(&self) -> Vc<Vec<RcStr>> {
+        let mut packages = self
+            .experimental
+            .optimize_package_imports
+            .clone()
+            .unwrap_or_default();
+        if let Some(turbo_packages) = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.side_effect_free_packages.as_ref())
+        {
+            packages.extend(turbo_packages.iter().cloned());
+        }
+        Vc::cell(packages)
+    }
+
     #[turbo_tasks::function]
     pub fn tree_shaking_mode_for_foreign_code(
         &self,
@@ -1478,6 +2495,189 @@ impl NextConfig {
             minify.unwrap_or(matches!(*mode.await?, NextMode::Build)),
         ))
     }
+
+    #[turbo_tasks::function]
+    pub async fn content_hashing(&self, mode: Vc<NextMode>) -> Result<Vc<bool>> {
+        let content_hashing = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.content_hashing)
+            .unwrap_or(false);
+
+        Ok(Vc::cell(
+            content_hashing && matches!(*mode.await?, NextMode::Build),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    pub fn runtime_chunk(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.runtime_chunk)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn esm_chunks(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.esm_chunks)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn css_chunk_splitting(&self) -> Vc<bool> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.css_chunk_splitting)
+                .unwrap_or(false),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn max_css_chunk_size(&self) -> Vc<usize> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.max_css_chunk_size)
+                .unwrap_or(usize::MAX),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn react_singleton(&self) -> Vc<OptionReactSingletonMode> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.react_singleton),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub fn chunking_budget(&self) -> Vc<OptionChunkingBudget> {
+        Vc::cell(
+            self.experimental
+                .turbo
+                .as_ref()
+                .and_then(|t| t.chunking_budget.clone()),
+        )
+    }
+
+    #[turbo_tasks::function]
+    pub async fn loader_permissions(
+        &self,
+        project_path: Vc<FileSystemPath>,
+    ) -> Result<Vc<OptionLoaderPermissions>> {
+        let Some(config) = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.loader_permissions.as_ref())
+        else {
+            return Ok(Vc::cell(None));
+        };
+        let paths = |paths: &Option<Vec<RcStr>>| {
+            paths
+                .iter()
+                .flatten()
+                .map(|path| {
+                    project_path
+                        .join(path.trim_start_matches("./").into())
+                        .to_resolved()
+                })
+                .try_join()
+        };
+        Ok(Vc::cell(Some(
+            LoaderPermissions {
+                trusted: config.trusted.clone().unwrap_or_default(),
+                allow_read: paths(&config.allow_read).await?,
+                allow_write: paths(&config.allow_write).await?,
+            }
+            .resolved_cell(),
+        )))
+    }
+
+    /// The global the client chunks are registered in. The chunks of a build
+    /// exposing modules with module federation are loaded on the pages of
+    /// other builds, so it registers them in a global named after it, and
+    /// they're picked up by its own runtime instead of the one of the page.
+    #[turbo_tasks::function]
+    pub fn chunk_loading_global(&self) -> Vc<Option<RcStr>> {
+        let name = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.module_federation.as_ref())
+            .and_then(|config| config.name.as_ref());
+        Vc::cell(name.map(|name| {
+            let name: String = name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            format!("TURBOPACK_{name}").into()
+        }))
+    }
+
+    #[turbo_tasks::function]
+    pub async fn module_federation_options(
+        &self,
+        project_path: Vc<FileSystemPath>,
+    ) -> Result<Vc<OptionModuleFederationOptions>> {
+        let Some(config) = self
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|t| t.module_federation.as_ref())
+        else {
+            return Ok(Vc::cell(None));
+        };
+        let mut shared = FxIndexMap::default();
+        for (name, module) in config.shared.iter().flatten() {
+            let version = match &module.version {
+                Some(version) => version.clone(),
+                None => {
+                    let package_json = project_path
+                        .join(format!("node_modules/{name}/package.json").into())
+                        .read_json()
+                        .await?;
+                    match &*package_json {
+                        FileJsonContent::Content(json) => {
+                            json["version"].as_str().unwrap_or("0.0.0").into()
+                        }
+                        _ => "0.0.0".into(),
+                    }
+                }
+            };
+            shared.insert(
+                name.clone(),
+                SharedModule {
+                    version,
+                    required_version: module.required_version.clone(),
+                    singleton: module.singleton.unwrap_or(false),
+                },
+            );
+        }
+        Ok(Vc::cell(Some(
+            ModuleFederationOptions {
+                name: config.name.clone(),
+                exposes: config.exposes.clone().unwrap_or_default(),
+                remotes: config.remotes.clone().unwrap_or_default(),
+                shared,
+            }
+            .resolved_cell(),
+        )))
+    }
 }
 
 /// A subset of ts/jsconfig that next.js implicitly
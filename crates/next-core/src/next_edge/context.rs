@@ -18,15 +18,21 @@ use turbopack_node::execution_context::ExecutionContext;
 
 use crate::{
     mode::NextMode,
-    next_config::NextConfig,
+    next_config::{NextConfig, ReactSingletonMode},
     next_font::local::NextFontLocalResolvePlugin,
     next_import_map::get_next_edge_import_map,
     next_server::context::ServerContextType,
-    next_shared::resolve::{
-        get_invalid_client_only_resolve_plugin, get_invalid_styled_jsx_resolve_plugin,
-        ModuleFeatureReportResolvePlugin, NextSharedRuntimeResolvePlugin,
+    next_shared::{
+        react_singleton::ReactSingletonResolvePlugin,
+        resolve::{
+            get_invalid_client_only_resolve_plugin, get_invalid_styled_jsx_resolve_plugin,
+            ModuleFeatureReportResolvePlugin, NextSharedRuntimeResolvePlugin,
+        },
+    },
+    util::{
+        foreign_code_context_condition, get_package_store, get_remote_imports, get_resolve_modules,
+        get_restricted_imports, NextRuntime,
     },
-    util::{foreign_code_context_condition, NextRuntime},
 };
 
 fn defines(define_env: &FxIndexMap<RcStr, RcStr>) -> CompileTimeDefines {
@@ -124,7 +130,7 @@ pub async fn get_edge_resolve_options_context(
             | ServerContextType::AppRSC { .. }
     ) {
         before_resolve_plugins.push(ResolvedVc::upcast(
-            NextFontLocalResolvePlugin::new(*project_path)
+            NextFontLocalResolvePlugin::new(*project_path, next_config)
                 .to_resolved()
                 .await?,
         ));
@@ -150,11 +156,18 @@ pub async fn get_edge_resolve_options_context(
         ));
     }
 
-    let after_resolve_plugins = vec![ResolvedVc::upcast(
+    let mut after_resolve_plugins = vec![ResolvedVc::upcast(
         NextSharedRuntimeResolvePlugin::new(*project_path)
             .to_resolved()
             .await?,
     )];
+    if let Some(mode) = *next_config.react_singleton().await? {
+        after_resolve_plugins.push(ResolvedVc::upcast(
+            ReactSingletonResolvePlugin::new(*project_path, mode == ReactSingletonMode::Enforce)
+                .to_resolved()
+                .await?,
+        ));
+    }
 
     // https://github.com/vercel/next.js/blob/bf52c254973d99fed9d71507a2e818af80b8ade7/packages/next/src/build/webpack-config.ts#L96-L102
     let mut custom_conditions = vec![mode.await?.condition().into()];
@@ -170,13 +183,43 @@ pub async fn get_edge_resolve_options_context(
         custom_conditions.push("react-server".into());
     };
 
+    custom_conditions.extend(
+        next_config
+            .condition_names(if ty.supports_react_server() {
+                vec!["edge".into(), "react-server".into()]
+            } else {
+                vec!["edge".into()]
+            })
+            .await?
+            .iter()
+            .cloned(),
+    );
+
+    let package_conditions = next_config
+        .package_conditions(if ty.supports_react_server() {
+            vec!["react-server".into(), "edge".into()]
+        } else {
+            vec!["edge".into()]
+        })
+        .await?
+        .clone_value();
+
     let resolve_options_context = ResolveOptionsContext {
         enable_node_modules: Some(project_path.root().to_resolved().await?),
+        enable_yarn_pnp: Some(project_path),
         enable_edge_node_externals: true,
         custom_conditions,
+        package_conditions,
         import_map: Some(next_edge_import_map),
         module: true,
         browser: true,
+        preserve_symlinks: *next_config.preserve_symlinks().await?,
+        case_sensitive_paths: *next_config.case_sensitive_paths().await?,
+        restricted_imports: get_restricted_imports(next_config, *project_path).await?,
+        pnpm_injected_from_source: *next_config.pnpm_injected_from_source().await?,
+        remote_imports: get_remote_imports(next_config, *project_path, mode).await?,
+        package_store: get_package_store(next_config, *project_path).await?,
+        modules: get_resolve_modules(next_config, *project_path).await?,
         after_resolve_plugins,
         before_resolve_plugins,
         ..Default::default()
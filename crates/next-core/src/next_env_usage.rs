@@ -0,0 +1,277 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+use swc_core::{
+    common::Mark,
+    ecma::{
+        ast::{
+            Expr, Ident, Lit, MemberExpr, MemberProp, ObjectPatProp, Pat, Program, PropName,
+            VarDeclarator,
+        },
+        visit::{Visit, VisitWith},
+    },
+};
+use turbo_rcstr::RcStr;
+
+/// The `process.env` variables referenced by a single module.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleEnvUsage {
+    /// The names of the variables referenced, sorted.
+    pub names: Vec<RcStr>,
+    /// Whether `process.env` is accessed with a computed key or used as a
+    /// whole, e.g. `process.env[name]` or `{ ...process.env }`, so that any
+    /// variable can be read.
+    pub dynamic_access: bool,
+}
+
+impl ModuleEnvUsage {
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty() && !self.dynamic_access
+    }
+}
+
+/// Collects the `process.env` variables referenced by a parsed module.
+/// `unresolved_mark` is used to only count references to the global `process`,
+/// not to local bindings shadowing it.
+pub fn collect_env_usage(program: &Program, unresolved_mark: Mark) -> ModuleEnvUsage {
+    let mut visitor = EnvUsageVisitor {
+        unresolved_mark,
+        usage: Default::default(),
+    };
+    program.visit_with(&mut visitor);
+
+    let mut usage = visitor.usage;
+    usage.names.sort();
+    usage.names.dedup();
+    usage
+}
+
+struct EnvUsageVisitor {
+    unresolved_mark: Mark,
+    usage: ModuleEnvUsage,
+}
+
+impl EnvUsageVisitor {
+    fn is_global(&self, ident: &Ident, name: &str) -> bool {
+        &*ident.sym == name && ident.ctxt.outer() == self.unresolved_mark
+    }
+
+    /// Whether `expr` is `process.env`.
+    fn is_process_env(&self, expr: &Expr) -> bool {
+        let Expr::Member(MemberExpr { obj, prop, .. }) = expr else {
+            return false;
+        };
+        matches!(&**obj, Expr::Ident(ident) if self.is_global(ident, "process"))
+            && matches!(prop, MemberProp::Ident(prop) if &*prop.sym == "env")
+    }
+}
+
+impl Visit for EnvUsageVisitor {
+    fn visit_member_expr(&mut self, member: &MemberExpr) {
+        if !self.is_process_env(&member.obj) {
+            member.visit_children_with(self);
+            return;
+        }
+        match &member.prop {
+            MemberProp::Ident(name) => self.usage.names.push(name.sym.as_str().into()),
+            MemberProp::Computed(computed) => {
+                if let Expr::Lit(Lit::Str(name)) = &*computed.expr {
+                    self.usage.names.push(name.value.as_str().into());
+                } else {
+                    self.usage.dynamic_access = true;
+                    computed.visit_children_with(self);
+                }
+            }
+            MemberProp::PrivateName(_) => {}
+        }
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        // `const { A, B: b } = process.env` only reads `A` and `B`.
+        if let (Pat::Object(pat), Some(init)) = (&declarator.name, &declarator.init) {
+            if self.is_process_env(init) {
+                for prop in &pat.props {
+                    match prop {
+                        ObjectPatProp::Assign(prop) => {
+                            self.usage.names.push(prop.key.sym.as_str().into());
+                        }
+                        ObjectPatProp::KeyValue(prop) => match &prop.key {
+                            PropName::Ident(name) => {
+                                self.usage.names.push(name.sym.as_str().into())
+                            }
+                            PropName::Str(name) => {
+                                self.usage.names.push(name.value.as_str().into())
+                            }
+                            _ => self.usage.dynamic_access = true,
+                        },
+                        ObjectPatProp::Rest(_) => self.usage.dynamic_access = true,
+                    }
+                }
+                pat.visit_children_with(self);
+                return;
+            }
+        }
+        declarator.visit_children_with(self);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        // Member expressions of `process.env` are handled by
+        // `visit_member_expr`, so this is `process.env` used as a whole.
+        if self.is_process_env(expr) {
+            self.usage.dynamic_access = true;
+            return;
+        }
+        expr.visit_children_with(self);
+    }
+}
+
+/// How an entry uses a `process.env` variable.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvVarUsage {
+    /// Whether references to the variable are replaced with its value when
+    /// building, e.g. `NEXT_PUBLIC_` variables in client code.
+    pub inlined: bool,
+    /// Whether the variable is read when the code runs.
+    pub dynamic: bool,
+    /// The modules referencing the variable, sorted.
+    pub modules: BTreeSet<RcStr>,
+}
+
+/// The `process.env` variables the modules of an entry can observe.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryEnvUsage {
+    pub variables: BTreeMap<RcStr, EnvVarUsage>,
+    /// The modules accessing `process.env` with a computed key or as a whole,
+    /// which can read any variable available at runtime, sorted.
+    pub dynamic_access: BTreeSet<RcStr>,
+}
+
+impl EntryEnvUsage {
+    /// Adds the `usage` of the module at `path`. `is_inlined` tells whether a
+    /// variable is replaced with its value in that module.
+    pub fn add(
+        &mut self,
+        path: RcStr,
+        usage: &ModuleEnvUsage,
+        is_inlined: impl Fn(&RcStr) -> bool,
+    ) {
+        for name in &usage.names {
+            let variable = self.variables.entry(name.clone()).or_default();
+            if is_inlined(name) {
+                variable.inlined = true;
+            } else {
+                variable.dynamic = true;
+            }
+            variable.modules.insert(path.clone());
+        }
+        if usage.dynamic_access {
+            self.dynamic_access.insert(path);
+        }
+    }
+}
+
+/// The `env-usage.json` written by `experimental.turbo.envUsageReport`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvUsageReport {
+    /// The usage of the routes, middleware and instrumentation, by their name.
+    pub entries: BTreeMap<RcStr, EntryEnvUsage>,
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{
+        common::{FileName, Globals, SourceMap, GLOBALS},
+        ecma::{
+            ast::EsVersion,
+            parser::{parse_file_as_program, Syntax},
+            transforms::base::resolver,
+            visit::VisitMutWith,
+        },
+    };
+
+    use super::*;
+
+    fn usage(source: &str) -> ModuleEnvUsage {
+        GLOBALS.set(&Globals::new(), || {
+            let cm = SourceMap::default();
+            let fm = cm.new_source_file(FileName::Anon.into(), source.into());
+            let mut program = parse_file_as_program(
+                &fm,
+                Syntax::Es(Default::default()),
+                EsVersion::latest(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+            let unresolved_mark = Mark::new();
+            program.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), false));
+            collect_env_usage(&program, unresolved_mark)
+        })
+    }
+
+    #[test]
+    fn test_collect_env_usage() {
+        assert_eq!(
+            usage(
+                r#"
+                const url = process.env.API_URL;
+                const key = process.env["API_KEY"];
+                const { SECRET, REGION: region } = process.env;
+                if (process.env.NODE_ENV === "production") {}
+                "#
+            ),
+            ModuleEnvUsage {
+                names: vec![
+                    "API_KEY".into(),
+                    "API_URL".into(),
+                    "NODE_ENV".into(),
+                    "REGION".into(),
+                    "SECRET".into(),
+                ],
+                dynamic_access: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_collect_env_usage_dynamic_access() {
+        assert!(usage("const value = process.env[name];").dynamic_access);
+        assert!(usage("const env = { ...process.env };").dynamic_access);
+        assert!(usage("const { A, ...rest } = process.env;").dynamic_access);
+        assert!(usage("Object.keys(process.env);").dynamic_access);
+    }
+
+    #[test]
+    fn test_collect_env_usage_shadowed() {
+        assert!(usage(
+            r#"
+            function f(process) {
+                return process.env.API_URL;
+            }
+            "#
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_entry_env_usage() {
+        let mut entry = EntryEnvUsage::default();
+        let usage = ModuleEnvUsage {
+            names: vec!["NEXT_PUBLIC_URL".into(), "SECRET".into()],
+            dynamic_access: false,
+        };
+        entry.add("client.js".into(), &usage, |name| {
+            name.starts_with("NEXT_PUBLIC_")
+        });
+        entry.add("server.js".into(), &usage, |_| false);
+        let public = &entry.variables["NEXT_PUBLIC_URL"];
+        assert!(public.inlined && public.dynamic);
+        let secret = &entry.variables["SECRET"];
+        assert!(!secret.inlined && secret.dynamic);
+        assert_eq!(secret.modules.len(), 2);
+        assert!(entry.dynamic_access.is_empty());
+    }
+}
@@ -0,0 +1,453 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use swc_core::{
+    common::Mark,
+    ecma::{
+        ast::{
+            CallExpr, Callee, ExportAll, Expr, Ident, IdentName, ImportDecl, Lit, NamedExport,
+            NewExpr, Program,
+        },
+        visit::{Visit, VisitWith},
+    },
+};
+use turbo_rcstr::RcStr;
+
+/// Node.js builtin modules, which can be imported with or without the `node:`
+/// prefix.
+const NODE_BUILTINS: &[&str] = &[
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Node.js globals which aren't available in browsers.
+const NODE_GLOBALS: &[&str] = &["Buffer"];
+
+/// Members of the legacy React context API, which is removed in React 19.
+const LEGACY_CONTEXT_API: &[&str] = &["contextTypes", "childContextTypes", "getChildContext"];
+
+/// Returns the builtin module name of a `node:` prefixed or bare specifier,
+/// e.g. `fs/promises` for `node:fs/promises`.
+pub fn node_builtin_name(specifier: &str) -> Option<&str> {
+    if let Some(name) = specifier.strip_prefix("node:") {
+        return Some(name);
+    }
+    let module = specifier.split('/').next().unwrap_or(specifier);
+    NODE_BUILTINS.contains(&module).then_some(specifier)
+}
+
+/// Returns the name of the package a path inside of `node_modules` belongs to,
+/// including its scope.
+pub fn node_modules_package_name(path: &str) -> Option<&str> {
+    let (_, rest) = path.rsplit_once("node_modules/")?;
+    let mut segments = rest.splitn(3, '/');
+    let first = segments.next()?;
+    if first.starts_with('@') {
+        let second = segments.next()?;
+        Some(&rest[..first.len() + 1 + second.len()])
+    } else {
+        Some(first)
+    }
+}
+
+/// The features used by a single module.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleFeatureUsage {
+    /// The number of `import()` expressions.
+    pub dynamic_imports: u32,
+    /// The number of `eval()` calls and `new Function()` expressions.
+    pub eval: u32,
+    /// The Node.js builtin modules imported and Node.js globals referenced,
+    /// sorted.
+    pub node_builtins: Vec<RcStr>,
+    pub legacy_context_api: bool,
+}
+
+impl ModuleFeatureUsage {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Collects the feature usage of a parsed module. `unresolved_mark` is used to
+/// only count references to globals, not to local bindings shadowing them.
+pub fn collect_feature_usage(program: &Program, unresolved_mark: Mark) -> ModuleFeatureUsage {
+    let mut visitor = FeatureUsageVisitor {
+        unresolved_mark,
+        usage: Default::default(),
+    };
+    program.visit_with(&mut visitor);
+
+    let mut usage = visitor.usage;
+    usage.node_builtins.sort();
+    usage.node_builtins.dedup();
+    usage
+}
+
+struct FeatureUsageVisitor {
+    unresolved_mark: Mark,
+    usage: ModuleFeatureUsage,
+}
+
+impl FeatureUsageVisitor {
+    fn is_global(&self, ident: &Ident, name: &str) -> bool {
+        &*ident.sym == name && ident.ctxt.outer() == self.unresolved_mark
+    }
+
+    fn add_specifier(&mut self, specifier: &str) {
+        if let Some(name) = node_builtin_name(specifier) {
+            self.usage.node_builtins.push(name.into());
+        }
+    }
+}
+
+impl Visit for FeatureUsageVisitor {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        match &call.callee {
+            Callee::Import(_) => {
+                self.usage.dynamic_imports += 1;
+            }
+            Callee::Expr(callee) => {
+                if let Expr::Ident(ident) = &**callee {
+                    if self.is_global(ident, "eval") {
+                        self.usage.eval += 1;
+                    } else if self.is_global(ident, "require") {
+                        if let Some(Expr::Lit(Lit::Str(str))) =
+                            call.args.first().map(|arg| &*arg.expr)
+                        {
+                            self.add_specifier(&str.value);
+                        }
+                    }
+                }
+            }
+            Callee::Super(_) => {}
+        }
+        call.visit_children_with(self);
+    }
+
+    fn visit_new_expr(&mut self, new: &NewExpr) {
+        if let Expr::Ident(ident) = &*new.callee {
+            if self.is_global(ident, "Function") {
+                self.usage.eval += 1;
+            }
+        }
+        new.visit_children_with(self);
+    }
+
+    fn visit_import_decl(&mut self, import: &ImportDecl) {
+        self.add_specifier(&import.src.value);
+    }
+
+    fn visit_named_export(&mut self, export: &NamedExport) {
+        if let Some(src) = &export.src {
+            self.add_specifier(&src.value);
+        }
+    }
+
+    fn visit_export_all(&mut self, export: &ExportAll) {
+        self.add_specifier(&export.src.value);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if NODE_GLOBALS
+            .iter()
+            .any(|global| self.is_global(ident, global))
+        {
+            self.usage.node_builtins.push(ident.sym.as_str().into());
+        }
+    }
+
+    fn visit_ident_name(&mut self, ident: &IdentName) {
+        if LEGACY_CONTEXT_API.contains(&&*ident.sym) {
+            self.usage.legacy_context_api = true;
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleFeatureUsageEntry {
+    pub path: RcStr,
+    pub layer: Option<RcStr>,
+    #[serde(flatten)]
+    pub usage: ModuleFeatureUsage,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureUsageSummary {
+    pub dynamic_imports: u32,
+    pub eval: u32,
+    /// The Node.js builtins used by client modules, which have to be
+    /// polyfilled or fail at runtime.
+    pub client_node_builtins: Vec<RcStr>,
+    /// The number of modules using the legacy React context API.
+    pub legacy_context_api: u32,
+    pub common_js_only_packages: u32,
+}
+
+/// The `feature-usage.json` written by `experimental.turbo.featureUsageReport`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureUsageReport {
+    pub summary: FeatureUsageSummary,
+    /// The modules using any of the reported features, sorted by path.
+    pub modules: Vec<ModuleFeatureUsageEntry>,
+    /// The packages in `node_modules` only shipping CommonJS modules, which
+    /// can't be tree shaken.
+    pub common_js_only_packages: Vec<RcStr>,
+}
+
+impl FeatureUsageReport {
+    /// `packages` contains a `(package name, is CommonJS)` pair for every
+    /// module in `node_modules`. A package is CommonJS-only when none of its
+    /// modules are ESM.
+    pub fn new(
+        mut modules: Vec<ModuleFeatureUsageEntry>,
+        packages: impl IntoIterator<Item = (RcStr, bool)>,
+    ) -> Self {
+        modules.retain(|module| !module.usage.is_empty());
+        modules.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.layer.cmp(&b.layer)));
+
+        let mut all_common_js = BTreeMap::<RcStr, bool>::new();
+        for (package, is_common_js) in packages {
+            *all_common_js.entry(package).or_insert(true) &= is_common_js;
+        }
+        let common_js_only_packages: Vec<RcStr> = all_common_js
+            .into_iter()
+            .filter_map(|(package, all_common_js)| all_common_js.then_some(package))
+            .collect();
+
+        let mut summary = FeatureUsageSummary {
+            common_js_only_packages: common_js_only_packages.len() as u32,
+            ..Default::default()
+        };
+        for module in &modules {
+            summary.dynamic_imports += module.usage.dynamic_imports;
+            summary.eval += module.usage.eval;
+            if module.usage.legacy_context_api {
+                summary.legacy_context_api += 1;
+            }
+            if module
+                .layer
+                .as_deref()
+                .is_some_and(|layer| layer == "app-client" || layer == "client")
+            {
+                summary
+                    .client_node_builtins
+                    .extend(module.usage.node_builtins.iter().cloned());
+            }
+        }
+        summary.client_node_builtins.sort();
+        summary.client_node_builtins.dedup();
+
+        FeatureUsageReport {
+            summary,
+            modules,
+            common_js_only_packages,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{
+        common::{FileName, Globals, SourceMap, GLOBALS},
+        ecma::{
+            ast::EsVersion,
+            parser::{parse_file_as_program, EsSyntax, Syntax},
+            transforms::base::resolver,
+            visit::VisitMutWith,
+        },
+    };
+
+    use super::*;
+
+    fn usage(source: &str) -> ModuleFeatureUsage {
+        GLOBALS.set(&Globals::new(), || {
+            let cm = SourceMap::default();
+            let fm = cm.new_source_file(FileName::Anon.into(), source.into());
+            let mut program = parse_file_as_program(
+                &fm,
+                Syntax::Es(EsSyntax {
+                    jsx: true,
+                    ..Default::default()
+                }),
+                EsVersion::latest(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+            let unresolved_mark = Mark::new();
+            program.visit_mut_with(&mut resolver(unresolved_mark, Mark::new(), false));
+            collect_feature_usage(&program, unresolved_mark)
+        })
+    }
+
+    #[test]
+    fn test_collect_feature_usage() {
+        assert_eq!(
+            usage(
+                r#"
+                import fs from "node:fs/promises";
+                import { join } from "path";
+                import React from "react";
+                const os = require("os");
+                export * from "crypto";
+                const a = import("./a");
+                eval("1");
+                new Function("return 1");
+                Buffer.from("");
+                "#
+            ),
+            ModuleFeatureUsage {
+                dynamic_imports: 1,
+                eval: 2,
+                node_builtins: vec![
+                    "Buffer".into(),
+                    "crypto".into(),
+                    "fs/promises".into(),
+                    "os".into(),
+                    "path".into(),
+                ],
+                legacy_context_api: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_collect_feature_usage_ignores_shadowed_globals() {
+        assert!(usage(
+            r#"
+            function f(eval, require, Buffer) {
+                eval("1");
+                require("fs");
+                Buffer.from("");
+            }
+            "#
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_collect_legacy_context_api() {
+        assert!(
+            usage(
+                r#"
+                class Provider extends React.Component {
+                    getChildContext() {
+                        return { theme: "dark" };
+                    }
+                }
+                Provider.childContextTypes = { theme: PropTypes.string };
+                "#
+            )
+            .legacy_context_api
+        );
+    }
+
+    #[test]
+    fn test_node_modules_package_name() {
+        assert_eq!(
+            node_modules_package_name("node_modules/lodash/index.js"),
+            Some("lodash")
+        );
+        assert_eq!(
+            node_modules_package_name("node_modules/a/node_modules/@scope/b/lib/index.js"),
+            Some("@scope/b")
+        );
+        assert_eq!(node_modules_package_name("src/index.js"), None);
+    }
+
+    #[test]
+    fn test_feature_usage_report() {
+        let module = |path: &str, layer: &str, usage: ModuleFeatureUsage| ModuleFeatureUsageEntry {
+            path: path.into(),
+            layer: Some(layer.into()),
+            usage,
+        };
+        let report = FeatureUsageReport::new(
+            vec![
+                module(
+                    "src/server.js",
+                    "app-rsc",
+                    ModuleFeatureUsage {
+                        node_builtins: vec!["fs".into()],
+                        ..Default::default()
+                    },
+                ),
+                module("src/empty.js", "app-client", Default::default()),
+                module(
+                    "src/client.js",
+                    "app-client",
+                    ModuleFeatureUsage {
+                        dynamic_imports: 2,
+                        node_builtins: vec!["Buffer".into()],
+                        ..Default::default()
+                    },
+                ),
+            ],
+            [
+                ("cjs".into(), true),
+                ("mixed".into(), true),
+                ("mixed".into(), false),
+            ],
+        );
+        assert_eq!(
+            report.modules.iter().map(|m| &*m.path).collect::<Vec<_>>(),
+            ["src/client.js", "src/server.js"]
+        );
+        assert_eq!(
+            report.summary,
+            FeatureUsageSummary {
+                dynamic_imports: 2,
+                eval: 0,
+                client_node_builtins: vec!["Buffer".into()],
+                legacy_context_api: 0,
+                common_js_only_packages: 1,
+            }
+        );
+        assert_eq!(report.common_js_only_packages, vec![RcStr::from("cjs")]);
+    }
+}
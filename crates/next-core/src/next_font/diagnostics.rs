@@ -0,0 +1,328 @@
+use anyhow::Result;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    source::Source,
+    virtual_source::VirtualSource,
+};
+
+use crate::{
+    next_font::font_fallback::{FontAdjustment, FontFallback, FontFallbacks},
+    next_manifests::NextFontManifestFont,
+};
+
+/// A fallback font generated by `next/font`, as reported to debug layout
+/// shift between the fallback and the web font.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+#[serde(rename_all = "camelCase")]
+pub struct FontFallbackDiagnostic {
+    /// The scoped family of the web font, e.g. `__Roboto_c123b8`.
+    pub font_family: Option<RcStr>,
+    /// e.g. `__Roboto_Fallback_c123b8`
+    pub scoped_font_family: RcStr,
+    /// The locally installed font the fallback renders with, e.g. `Arial`.
+    pub local_font_family: RcStr,
+    /// The `ascent-override` percentage, if the fallback is adjusted.
+    pub ascent_override: Option<f64>,
+    pub descent_override: Option<f64>,
+    pub line_gap_override: Option<f64>,
+    pub size_adjust: Option<f64>,
+}
+
+#[turbo_tasks::value(transparent, eq = "manual")]
+pub struct FontFallbackDiagnostics(Vec<FontFallbackDiagnostic>);
+
+/// The stylesheet generated for a `next/font` request. It keeps the fallbacks
+/// computed for the font, so that they can be reported as they were computed.
+#[turbo_tasks::value]
+pub struct NextFontStylesheetSource {
+    source: ResolvedVc<VirtualSource>,
+    /// The scoped family of the web font, e.g. `__Roboto_c123b8`.
+    font_family: ResolvedVc<RcStr>,
+    fallbacks: ResolvedVc<FontFallbacks>,
+}
+
+#[turbo_tasks::value_impl]
+impl NextFontStylesheetSource {
+    #[turbo_tasks::function]
+    pub(crate) async fn new(
+        path: Vc<FileSystemPath>,
+        content: ResolvedVc<AssetContent>,
+        font_family: ResolvedVc<RcStr>,
+        fallbacks: ResolvedVc<FontFallbacks>,
+    ) -> Result<Vc<Self>> {
+        Ok(NextFontStylesheetSource {
+            source: VirtualSource::new(path, *content).to_resolved().await?,
+            font_family,
+            fallbacks,
+        }
+        .cell())
+    }
+
+    /// The fallback fonts generated for the font, with their adjustments.
+    #[turbo_tasks::function]
+    pub async fn fallback_diagnostics(&self) -> Result<Vc<FontFallbackDiagnostics>> {
+        let font_family = self.font_family.await?;
+        let mut diagnostics = vec![];
+        for fallback in self.fallbacks.await?.iter() {
+            let FontFallback::Automatic(fallbacks) = &*fallback.await? else {
+                continue;
+            };
+            for fallback in fallbacks {
+                diagnostics.push(FontFallbackDiagnostic::new(
+                    font_family.clone_value(),
+                    fallback.scoped_font_family.await?.clone_value(),
+                    fallback.local_font_family.await?.clone_value(),
+                    fallback.adjustment.as_ref(),
+                ));
+            }
+        }
+        Ok(Vc::cell(diagnostics))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Source for NextFontStylesheetSource {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        self.source.ident()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for NextFontStylesheetSource {
+    #[turbo_tasks::function]
+    fn content(&self) -> Vc<AssetContent> {
+        self.source.content()
+    }
+}
+
+impl FontFallbackDiagnostic {
+    fn new(
+        font_family: RcStr,
+        scoped_font_family: RcStr,
+        local_font_family: RcStr,
+        adjustment: Option<&FontAdjustment>,
+    ) -> Self {
+        // The percentages of the overrides, as rendered in the stylesheet but
+        // without rounding
+        let percentage = |value: fn(&FontAdjustment) -> f64| adjustment.map(|a| value(a) * 100.0);
+        FontFallbackDiagnostic {
+            font_family: Some(font_family),
+            scoped_font_family,
+            local_font_family,
+            ascent_override: percentage(|a| a.ascent),
+            descent_override: percentage(|a| a.descent.abs()),
+            line_gap_override: percentage(|a| a.line_gap),
+            size_adjust: percentage(|a| a.size_adjust),
+        }
+    }
+}
+
+/// Reads the fonts generated by `next/font` and their fallbacks from a
+/// stylesheet emitted for the client, which may be minified and have its class
+/// names scoped.
+pub fn next_font_manifest_fonts(css: &str) -> Vec<(RcStr, NextFontManifestFont)> {
+    let font_face_re = lazy_regex::regex!(r#"@font-face\s*\{([^}]*)\}"#);
+
+    let mut web_fonts = FxHashSet::default();
+    let mut size_adjusted_fallbacks = FxHashSet::default();
+    for captures in font_face_re.captures_iter(css) {
+        let mut family = None;
+        let mut src = "";
+        let mut has_size_adjust = false;
+        for declaration in captures[1].split(';') {
+            let Some((property, value)) = declaration.split_once(':') else {
+                continue;
+            };
+            match property.trim() {
+                "font-family" => family = Some(unquote(value)),
+                "src" => src = value.trim(),
+                "size-adjust" => has_size_adjust = true,
+                _ => {}
+            }
+        }
+        let Some(family) = family else {
+            continue;
+        };
+        if src.starts_with("local(") {
+            if has_size_adjust {
+                size_adjusted_fallbacks.insert(family);
+            }
+        } else {
+            web_fonts.insert(family);
+        }
+    }
+
+    let mut fonts: Vec<(RcStr, NextFontManifestFont)> = vec![];
+    let rules = font_face_re.replace_all(css, "");
+    for captures in lazy_regex::regex!(r#"font-family\s*:\s*([^;}]+)"#).captures_iter(&rules) {
+        let mut families = captures[1]
+            .trim_end_matches("!important")
+            .split(',')
+            .map(unquote);
+        let Some(font_family) = families.next().filter(|family| web_fonts.contains(family)) else {
+            continue;
+        };
+        if fonts.iter().any(|(family, _)| *family == font_family) {
+            continue;
+        }
+        let fallbacks: Vec<RcStr> = families.collect();
+        let has_size_adjust = fallbacks
+            .iter()
+            .any(|fallback| size_adjusted_fallbacks.contains(fallback));
+        fonts.push((
+            font_family,
+            NextFontManifestFont {
+                fallbacks,
+                has_size_adjust,
+            },
+        ));
+    }
+    fonts
+}
+
+/// Reads the web font files declared by the `@font-face` rules of a
+/// stylesheet, as pairs of the file name and the font family it belongs to.
+/// Fallback fonts, which are loaded with `local()`, have no file.
+pub fn next_font_face_files(css: &str) -> Vec<(RcStr, RcStr)> {
+    let mut files = vec![];
+    for captures in lazy_regex::regex!(r#"@font-face\s*\{([^}]*)\}"#).captures_iter(css) {
+        let mut family = None;
+        let mut src = "";
+        for declaration in captures[1].split(';') {
+            let Some((property, value)) = declaration.split_once(':') else {
+                continue;
+            };
+            match property.trim() {
+                "font-family" => family = Some(unquote(value)),
+                "src" => src = value,
+                _ => {}
+            }
+        }
+        let Some(family) = family else {
+            continue;
+        };
+        for url in lazy_regex::regex!(r#"url\(\s*['"]?([^'")]+)['"]?\s*\)"#).captures_iter(src) {
+            let path = url[1].split(['?', '#']).next().unwrap_or_default();
+            let file_name = path.rsplit('/').next().unwrap_or(path);
+            files.push((file_name.into(), family.clone()));
+        }
+    }
+    files
+}
+
+fn unquote(value: &str) -> RcStr {
+    let value = value.trim();
+    value
+        .strip_prefix('\'')
+        .and_then(|value| value.strip_suffix('\''))
+        .or_else(|| {
+            value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+        })
+        .unwrap_or(value)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::{next_font_face_files, next_font_manifest_fonts, FontFallbackDiagnostic};
+    use crate::{next_font::font_fallback::FontAdjustment, next_manifests::NextFontManifestFont};
+
+    #[test]
+    fn test_font_fallback_diagnostic() {
+        let adjustment = FontAdjustment {
+            ascent: 0.902,
+            descent: -0.2248,
+            line_gap: 0.0,
+            size_adjust: 1.074,
+        };
+
+        assert_eq!(
+            FontFallbackDiagnostic::new(
+                "__Inter_c123b8".into(),
+                "__Inter_Fallback_c123b8".into(),
+                "Arial".into(),
+                Some(&adjustment),
+            ),
+            FontFallbackDiagnostic {
+                font_family: Some("__Inter_c123b8".into()),
+                scoped_font_family: "__Inter_Fallback_c123b8".into(),
+                local_font_family: "Arial".into(),
+                ascent_override: Some(0.902 * 100.0),
+                descent_override: Some(0.2248 * 100.0),
+                line_gap_override: Some(0.0),
+                size_adjust: Some(1.074 * 100.0),
+            }
+        );
+        assert_eq!(
+            FontFallbackDiagnostic::new(
+                "__Inter_c123b8".into(),
+                "__Inter_Fallback_c123b8 Yu Gothic".into(),
+                "Yu Gothic".into(),
+                None,
+            ),
+            FontFallbackDiagnostic {
+                font_family: Some("__Inter_c123b8".into()),
+                scoped_font_family: "__Inter_Fallback_c123b8 Yu Gothic".into(),
+                local_font_family: "Yu Gothic".into(),
+                ascent_override: None,
+                descent_override: None,
+                line_gap_override: None,
+                size_adjust: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_font_manifest_fonts() {
+        let css = "@font-face{font-family:Inter;src:url(/_next/static/media/inter.p.woff2) \
+                   format(\"woff2\")}@font-face{font-family:Inter \
+                   Fallback;src:local(\"Arial\");ascent-override:90.2%;size-adjust:107.4%}.\
+                   inter-module__a1b2__className{font-family:Inter,Inter \
+                   Fallback,sans-serif}.other{font-family:system-ui}";
+
+        assert_eq!(
+            next_font_manifest_fonts(css),
+            vec![(
+                "Inter".into(),
+                NextFontManifestFont {
+                    fallbacks: vec!["Inter Fallback".into(), "sans-serif".into()],
+                    has_size_adjust: true,
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_next_font_face_files() {
+        let css = indoc! {r#"
+            @font-face {
+                font-family: '__Inter_c123b8';
+                src: url(/_next/static/media/a1b2.p.woff2?v=1) format("woff2"),
+                    url('/_next/static/media/a1b2.woff') format("woff");
+            }
+            @font-face {
+                font-family: '__Inter_Fallback_c123b8';
+                src: local("Arial");
+            }
+        "#};
+
+        assert_eq!(
+            next_font_face_files(css),
+            vec![
+                ("a1b2.p.woff2".into(), "__Inter_c123b8".into()),
+                ("a1b2.woff".into(), "__Inter_c123b8".into()),
+            ]
+        );
+    }
+}
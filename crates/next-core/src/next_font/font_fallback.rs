@@ -1,9 +1,18 @@
-use anyhow::Result;
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+};
+
+use allsorts::{font::MatchingPresentation, font_data::DynamicFontTableProvider, Font};
+use anyhow::{bail, Result};
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 use turbo_rcstr::RcStr;
 use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, Vc};
 
+use crate::next_config::FontMetricsConfig;
+
 pub(crate) struct DefaultFallbackFont {
     pub name: RcStr,
     pub capsize_key: RcStr,
@@ -11,22 +20,245 @@ pub(crate) struct DefaultFallbackFont {
     pub units_per_em: u32,
 }
 
-// From https://github.com/vercel/next.js/blob/a3893bf69c83fb08e88c87bf8a21d987a0448c8e/packages/font/src/utils.ts#L4
+/// Metrics of a fallback font baked in from `fallback-font-metrics.json`.
+pub(crate) struct BuiltinFallbackFont {
+    pub name: &'static str,
+    pub category: &'static str,
+    pub capsize_key: &'static str,
+    pub az_avg_width: f64,
+    pub units_per_em: u32,
+}
+
+include!(concat!(env!("OUT_DIR"), "/fallback_font_metrics.rs"));
+
+impl DefaultFallbackFont {
+    fn builtin(category: &str) -> Self {
+        let font = BUILTIN_FALLBACK_FONTS
+            .iter()
+            .find(|font| font.category == category)
+            .unwrap_or_else(|| panic!("missing builtin {} fallback font metrics", category));
+
+        DefaultFallbackFont::from_builtin(font)
+    }
+
+    fn from_builtin(font: &BuiltinFallbackFont) -> Self {
+        DefaultFallbackFont {
+            name: font.name.into(),
+            capsize_key: font.capsize_key.into(),
+            az_avg_width: font.az_avg_width,
+            units_per_em: font.units_per_em,
+        }
+    }
+
+    /// A fallback font registered through `experimental.fontMetrics`.
+    pub(crate) fn from_config(name: RcStr, metrics: &FontMetricsConfig) -> Self {
+        DefaultFallbackFont {
+            capsize_key: metrics.capsize_key.clone().unwrap_or_else(|| name.clone()),
+            name,
+            az_avg_width: metrics.az_avg_width,
+            units_per_em: metrics.units_per_em,
+        }
+    }
+
+    /// Finds the metrics of a locally installed font by name, either registered
+    /// through `experimental.fontMetrics` or baked in.
+    pub(crate) fn by_name(
+        name: &RcStr,
+        font_metrics: &BTreeMap<RcStr, FontMetricsConfig>,
+    ) -> Option<Self> {
+        if let Some(metrics) = font_metrics.get(name) {
+            return Some(DefaultFallbackFont::from_config(name.clone(), metrics));
+        }
+        BUILTIN_FALLBACK_FONTS
+            .iter()
+            .find(|font| font.name == name.as_str())
+            .map(DefaultFallbackFont::from_builtin)
+    }
+}
+
 pub(crate) static DEFAULT_SANS_SERIF_FONT: Lazy<DefaultFallbackFont> =
-    Lazy::new(|| DefaultFallbackFont {
-        name: "Arial".into(),
-        capsize_key: "arial".into(),
-        az_avg_width: 934.5116279069767,
-        units_per_em: 2048,
-    });
+    Lazy::new(|| DefaultFallbackFont::builtin("sans-serif"));
 
 pub(crate) static DEFAULT_SERIF_FONT: Lazy<DefaultFallbackFont> =
-    Lazy::new(|| DefaultFallbackFont {
-        name: "Times New Roman".into(),
-        capsize_key: "timesNewRoman".into(),
-        az_avg_width: 854.3953488372093,
-        units_per_em: 2048,
-    });
+    Lazy::new(|| DefaultFallbackFont::builtin("serif"));
+
+// Courier New is monospaced, so every character shares the same advance width.
+pub(crate) static DEFAULT_MONOSPACE_FONT: Lazy<DefaultFallbackFont> =
+    Lazy::new(|| DefaultFallbackFont::builtin("monospace"));
+
+/// Scripts written with full-width glyphs, for which the average width of
+/// `a-z` says nothing about the width of the rendered text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CjkScript {
+    Japanese,
+    Korean,
+    SimplifiedChinese,
+    TraditionalChinese,
+}
+
+impl CjkScript {
+    /// Maps a Google Fonts subset, e.g. `japanese`, to its script.
+    pub(crate) fn from_subset(subset: &str) -> Option<Self> {
+        match subset {
+            "japanese" => Some(CjkScript::Japanese),
+            "korean" => Some(CjkScript::Korean),
+            "chinese-simplified" => Some(CjkScript::SimplifiedChinese),
+            "chinese-traditional" | "chinese-hongkong" => Some(CjkScript::TraditionalChinese),
+            _ => None,
+        }
+    }
+
+    /// Locally installed fonts covering the script, in order of preference
+    /// across macOS and Windows.
+    pub(crate) fn fallback_fonts(self) -> &'static [&'static str] {
+        match self {
+            CjkScript::Japanese => &[
+                "Hiragino Sans",
+                "Hiragino Kaku Gothic ProN",
+                "Yu Gothic",
+                "Meiryo",
+            ],
+            CjkScript::Korean => &["Apple SD Gothic Neo", "Malgun Gothic"],
+            CjkScript::SimplifiedChinese => &["PingFang SC", "Microsoft YaHei"],
+            CjkScript::TraditionalChinese => &["PingFang TC", "Microsoft JhengHei"],
+        }
+    }
+
+    /// Common characters of the script, used both to detect the script a font
+    /// covers and to average the width of its full-width glyphs.
+    pub(crate) fn sample_characters(self) -> &'static str {
+        match self {
+            CjkScript::Japanese => "あいうえおかきくけこのアイウエオカキクケコ",
+            CjkScript::Korean => "가나다라마바사아자차카타파하는",
+            CjkScript::SimplifiedChinese => "们这说时来对过还没发",
+            CjkScript::TraditionalChinese => "們這說時來對過還沒發",
+        }
+    }
+}
+
+/// The advances of the glyphs for `characters` in font units, `None` for the
+/// characters the font has no glyph for.
+pub(crate) fn glyph_advances(
+    font: &mut Font<DynamicFontTableProvider>,
+    characters: &str,
+) -> Vec<Option<u16>> {
+    characters
+        .chars()
+        .map(|c| {
+            let glyph_index = font
+                .lookup_glyph_index(c, MatchingPresentation::NotRequired, None)
+                .0;
+            if glyph_index == 0 {
+                return None;
+            }
+            font.horizontal_advance(glyph_index)
+        })
+        .collect()
+}
+
+/// Computes the adjustment for a CJK fallback font from the average advance
+/// of the main font's full-width glyphs, in font units. The default CJK
+/// fallback fonts draw their full-width glyphs exactly 1em wide.
+pub(crate) fn get_cjk_font_adjustment(
+    full_width_avg: f64,
+    units_per_em: f64,
+    ascent: f64,
+    descent: f64,
+    line_gap: f64,
+) -> FontAdjustment {
+    let size_adjust = full_width_avg / units_per_em;
+
+    FontAdjustment {
+        ascent: ascent / (units_per_em * size_adjust),
+        descent: descent / (units_per_em * size_adjust),
+        line_gap: line_gap / (units_per_em * size_adjust),
+        size_adjust,
+    }
+}
+
+/// Builds automatic fallbacks for `fallbacks`, in font stack order. Every
+/// fallback after the first gets its own scoped font family, e.g.
+/// `__Noto_Sans_JP_Fallback_c123b8 Yu Gothic`, as sharing one would make its
+/// `@font-face` replace the previous ones.
+pub(crate) fn stack_automatic_fallbacks(
+    scoped_font_family: &RcStr,
+    fallbacks: impl IntoIterator<Item = (RcStr, Option<FontAdjustment>)>,
+) -> Vec<AutomaticFontFallback> {
+    fallbacks
+        .into_iter()
+        .enumerate()
+        .map(
+            |(i, (local_font_family, adjustment))| AutomaticFontFallback {
+                scoped_font_family: ResolvedVc::cell(if i == 0 {
+                    scoped_font_family.clone()
+                } else {
+                    format!("{} {}", scoped_font_family, local_font_family).into()
+                }),
+                local_font_family: ResolvedVc::cell(local_font_family),
+                adjustment,
+            },
+        )
+        .collect()
+}
+
+/// Whether `font_family` is a CSS generic family, e.g. `sans-serif`, which
+/// doesn't name a font that could be adjusted.
+pub(crate) fn is_generic_font_family(font_family: &str) -> bool {
+    matches!(
+        font_family,
+        "serif"
+            | "sans-serif"
+            | "monospace"
+            | "cursive"
+            | "fantasy"
+            | "system-ui"
+            | "ui-serif"
+            | "ui-sans-serif"
+            | "ui-monospace"
+            | "ui-rounded"
+            | "emoji"
+            | "math"
+            | "fangsong"
+    )
+}
+
+/// Builds the fallbacks for a manual `fallback` font stack, keeping its order.
+/// Every family with an adjustment is replaced with an automatic fallback
+/// adjusted to the webfont, the others, like generic families, are kept as-is.
+/// Returns `None` when none of the families could be adjusted.
+pub(crate) fn stack_manual_fallbacks(
+    scoped_font_family: &RcStr,
+    fallbacks: Vec<(RcStr, Option<FontAdjustment>)>,
+) -> Option<Vec<FontFallback>> {
+    let mut automatic_fallbacks = stack_automatic_fallbacks(
+        scoped_font_family,
+        fallbacks
+            .iter()
+            .filter(|(_, adjustment)| adjustment.is_some())
+            .cloned(),
+    )
+    .into_iter();
+    if automatic_fallbacks.as_slice().is_empty() {
+        return None;
+    }
+
+    // Consecutive fallbacks of the same kind are grouped, so that the font
+    // stack lists them in the order they were configured.
+    let mut font_fallbacks: Vec<FontFallback> = vec![];
+    for (font_family, adjustment) in fallbacks {
+        match (adjustment, font_fallbacks.last_mut()) {
+            (Some(_), Some(FontFallback::Automatic(group))) => {
+                group.extend(automatic_fallbacks.next())
+            }
+            (Some(_), _) => font_fallbacks.push(FontFallback::Automatic(
+                automatic_fallbacks.next().into_iter().collect(),
+            )),
+            (None, Some(FontFallback::Manual(group))) => group.push(font_family),
+            (None, _) => font_fallbacks.push(FontFallback::Manual(vec![font_family])),
+        }
+    }
+    Some(font_fallbacks)
+}
 
 /// An automatically generated fallback font generated by next/font.
 #[turbo_tasks::value(shared)]
@@ -40,9 +272,10 @@ pub(crate) struct AutomaticFontFallback {
 
 #[turbo_tasks::value(shared)]
 pub(crate) enum FontFallback {
-    /// An automatically generated fallback font generated by next/font. May
-    /// include an optional [[FontAdjustment]].
-    Automatic(AutomaticFontFallback),
+    /// Automatically generated fallback fonts generated by next/font, in the
+    /// order they appear in the font stack. Each may include an optional
+    /// [[FontAdjustment]].
+    Automatic(Vec<AutomaticFontFallback>),
     /// There was an issue preparing the font fallback. Since resolving the
     /// font css cannot fail, proper Errors cannot be returned. Emit an issue,
     /// return this and omit fallback information instead.
@@ -55,7 +288,11 @@ pub(crate) enum FontFallback {
 impl FontFallback {
     #[turbo_tasks::function]
     pub(crate) fn has_size_adjust(&self) -> Vc<bool> {
-        Vc::cell(matches!(self, FontFallback::Automatic(auto) if auto.adjustment.is_some()))
+        Vc::cell(matches!(
+            self,
+            FontFallback::Automatic(fallbacks)
+                if fallbacks.iter().any(|fallback| fallback.adjustment.is_some())
+        ))
     }
 }
 
@@ -79,7 +316,7 @@ impl FontFallbacks {
 /// An adjustment to be made to a fallback font to approximate the geometry of
 /// the main webfont. Rendered as e.g. `ascent-override: 56.8%;` in the
 /// stylesheet
-#[derive(Debug, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
 pub(crate) struct FontAdjustment {
     pub ascent: f64,
     pub descent: f64,
@@ -90,3 +327,118 @@ pub(crate) struct FontAdjustment {
 // Necessary since floating points in this struct don't implement Eq, but it's
 // required for turbo tasks values.
 impl Eq for FontAdjustment {}
+
+impl FontAdjustment {
+    /// Replaces the computed values with any explicitly provided by the user.
+    pub(crate) fn with_overrides(self, overrides: &FontMetricsOverride) -> Self {
+        FontAdjustment {
+            ascent: overrides.ascent.unwrap_or(self.ascent),
+            descent: overrides.descent.unwrap_or(self.descent),
+            line_gap: overrides.line_gap.unwrap_or(self.line_gap),
+            size_adjust: overrides.size_adjust.unwrap_or(self.size_adjust),
+        }
+    }
+}
+
+/// User-provided metrics for an automatic fallback font, taking precedence
+/// over the values computed from the font's metrics. Values are ratios of the
+/// font size, like those in [[FontAdjustment]] (e.g. `0.9` renders as
+/// `ascent-override: 90.00%;`).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+#[serde(rename_all = "camelCase")]
+pub struct FontMetricsOverride {
+    pub ascent: Option<f64>,
+    pub descent: Option<f64>,
+    pub line_gap: Option<f64>,
+    pub size_adjust: Option<f64>,
+}
+
+/// Metric overrides keyed by the local name of the fallback font they apply
+/// to, e.g. `{ "Arial": { "sizeAdjust": 1.07 } }`.
+pub type FontMetricsOverrides = BTreeMap<RcStr, FontMetricsOverride>;
+
+impl FontMetricsOverride {
+    /// Constructs a [[FontAdjustment]] purely from explicit values. Returns
+    /// `None` unless all of them were provided.
+    pub(crate) fn to_adjustment(&self) -> Option<FontAdjustment> {
+        Some(FontAdjustment {
+            ascent: self.ascent?,
+            descent: self.descent?,
+            line_gap: self.line_gap?,
+            size_adjust: self.size_adjust?,
+        })
+    }
+
+    fn bits(&self) -> [Option<u64>; 4] {
+        [self.ascent, self.descent, self.line_gap, self.size_adjust].map(|v| v.map(f64::to_bits))
+    }
+}
+
+// Floating points don't implement Eq, Hash or Ord, but these are required for
+// the options structs this is part of.
+impl Eq for FontMetricsOverride {}
+
+impl Hash for FontMetricsOverride {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+impl PartialOrd for FontMetricsOverride {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FontMetricsOverride {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bits().cmp(&other.bits())
+    }
+}
+
+/// Combines the computed adjustment for a fallback font with the user's
+/// overrides for it, if any. When no adjustment could be computed, one is only
+/// returned if the user provided every value.
+pub(crate) fn apply_metrics_override(
+    adjustment: Option<FontAdjustment>,
+    overrides: Option<&FontMetricsOverride>,
+) -> Option<FontAdjustment> {
+    match (adjustment, overrides) {
+        (Some(adjustment), Some(overrides)) => Some(adjustment.with_overrides(overrides)),
+        (None, Some(overrides)) => overrides.to_adjustment(),
+        (adjustment, None) => adjustment,
+    }
+}
+
+/// Validates user-provided fallback metrics, similar to how the other font
+/// loader options are validated.
+pub(crate) fn validate_metrics_overrides(overrides: &FontMetricsOverrides) -> Result<()> {
+    for (font_family, metrics) in overrides {
+        if let Some(size_adjust) = metrics.size_adjust {
+            if !(size_adjust.is_finite() && size_adjust > 0.0) {
+                bail!(
+                    "Invalid sizeAdjust {} in fallbackMetrics for fallback font {}. Expected a \
+                     positive number",
+                    size_adjust,
+                    font_family
+                )
+            }
+        }
+
+        for (name, value) in [
+            ("ascent", metrics.ascent),
+            ("descent", metrics.descent),
+            ("lineGap", metrics.line_gap),
+        ] {
+            if value.is_some_and(|value| !value.is_finite()) {
+                bail!(
+                    "Invalid {} in fallbackMetrics for fallback font {}. Expected a finite number",
+                    name,
+                    font_family
+                )
+            }
+        }
+    }
+
+    Ok(())
+}
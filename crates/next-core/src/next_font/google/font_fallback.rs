@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use allsorts::{binary::read::ReadScope, font_data::FontData, Font};
 use anyhow::{Context, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -9,11 +10,14 @@ use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, Vc};
 use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::issue::{IssueExt, IssueSeverity, StyledString};
 
-use super::options::NextFontGoogleOptions;
+use super::{options::NextFontGoogleOptions, provider::FontProvider, subset::parse_unicode_range};
 use crate::{
     next_font::{
         font_fallback::{
-            AutomaticFontFallback, FontAdjustment, FontFallback, DEFAULT_SANS_SERIF_FONT,
+            apply_metrics_override, get_cjk_font_adjustment, glyph_advances,
+            is_generic_font_family, stack_automatic_fallbacks, stack_manual_fallbacks, CjkScript,
+            DefaultFallbackFont, FontAdjustment, FontFallback, FontFallbacks, FontMetricsOverride,
+            FontMetricsOverrides, DEFAULT_MONOSPACE_FONT, DEFAULT_SANS_SERIF_FONT,
             DEFAULT_SERIF_FONT,
         },
         issue::NextFontIssue,
@@ -43,61 +47,307 @@ struct Fallback {
     pub adjustment: Option<FontAdjustment>,
 }
 
+/// The average advance of the full-width glyphs of a webfont, in ems.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+pub(super) struct FullWidthAdvance(pub f64);
+
+// Necessary since floating points don't implement Eq, but it's required for
+// turbo tasks values.
+impl Eq for FullWidthAdvance {}
+
+#[turbo_tasks::value(transparent)]
+pub(super) struct OptionFullWidthAdvance(Option<FullWidthAdvance>);
+
+/// The fallbacks following the webfont in the font stack: the automatically
+/// generated ones, followed by the manual `fallback` fonts. Setting
+/// `adjustFontFallback: false` alongside manual fallbacks leaves out the
+/// automatic ones. When the manual fallbacks name fonts with known metrics,
+/// those are adjusted in place of the default automatic fallback.
+///
+/// `full_width_advance` is the measured advance of the full-width glyphs of a
+/// CJK webfont, see [get_full_width_advance].
 #[turbo_tasks::function]
 pub(super) async fn get_font_fallback(
     lookup_path: ResolvedVc<FileSystemPath>,
     options_vc: Vc<NextFontGoogleOptions>,
+    full_width_advance: Vc<OptionFullWidthAdvance>,
+) -> Result<Vc<FontFallbacks>> {
+    let options = options_vc.await?;
+    if let Some(font_fallbacks) = get_manual_font_fallbacks(lookup_path, options_vc).await? {
+        return Ok(Vc::cell(
+            font_fallbacks
+                .into_iter()
+                .map(|fallback| fallback.resolved_cell())
+                .collect(),
+        ));
+    }
+
+    let mut font_fallbacks = vec![];
+    if options.adjust_font_fallback || options.fallback.is_none() {
+        font_fallbacks.push(
+            get_automatic_font_fallback(*lookup_path, options_vc, full_width_advance)
+                .to_resolved()
+                .await?,
+        );
+    }
+    if let Some(fallback) = &options.fallback {
+        font_fallbacks.push(FontFallback::Manual(fallback.clone()).resolved_cell());
+    }
+
+    Ok(Vc::cell(font_fallbacks))
+}
+
+/// Adjusts each font of the manual `fallback` stack found in the metrics to the
+/// webfont. Returns `None` when the fallbacks aren't adjusted, none of the
+/// fonts has known metrics, or the font covers a CJK script, for which the
+/// script's fallbacks are used.
+async fn get_manual_font_fallbacks(
+    lookup_path: ResolvedVc<FileSystemPath>,
+    options_vc: Vc<NextFontGoogleOptions>,
+) -> Result<Option<Vec<FontFallback>>> {
+    let options = options_vc.await?;
+    let Some(fallback) = &options.fallback else {
+        return Ok(None);
+    };
+    let is_cjk = requested_cjk_script(options.subsets.as_deref()).is_some();
+    if !options.adjust_font_fallback || is_cjk {
+        return Ok(None);
+    }
+
+    let metrics_json: FontMetricsMap =
+        load_next_js_templateon(lookup_path, "dist/server/capsize-font-metrics.json".into())
+            .await?;
+    let fallbacks = fallback
+        .iter()
+        .map(|font_family| {
+            let adjustment = if is_generic_font_family(font_family)
+                || options.unadjusted_fallbacks.contains(font_family)
+            {
+                None
+            } else {
+                apply_metrics_override(
+                    lookup_fallback_adjustment(&options.font_family, font_family, &metrics_json),
+                    options.fallback_metrics.get(font_family),
+                )
+            };
+            (font_family.clone(), adjustment)
+        })
+        .collect();
+
+    Ok(stack_manual_fallbacks(
+        &*get_scoped_font_family(FontFamilyType::Fallback.cell(), options_vc.font_family()).await?,
+        fallbacks,
+    ))
+}
+
+#[turbo_tasks::function]
+async fn get_automatic_font_fallback(
+    lookup_path: ResolvedVc<FileSystemPath>,
+    options_vc: Vc<NextFontGoogleOptions>,
+    full_width_advance: Vc<OptionFullWidthAdvance>,
 ) -> Result<Vc<FontFallback>> {
     let options = options_vc.await?;
-    Ok(match &options.fallback {
-        Some(fallback) => FontFallback::Manual(fallback.clone()).cell(),
-        None => {
-            let metrics_json = load_next_js_templateon(
-                lookup_path,
-                "dist/server/capsize-font-metrics.json".into(),
-            )
+    let metrics_json =
+        load_next_js_templateon(lookup_path, "dist/server/capsize-font-metrics.json".into())
             .await?;
-            let fallback = lookup_fallback(
-                &options.font_family,
-                metrics_json,
-                options.adjust_font_fallback,
-            );
-
-            match fallback {
-                Ok(fallback) => FontFallback::Automatic(AutomaticFontFallback {
-                    scoped_font_family: get_scoped_font_family(
-                        FontFamilyType::Fallback.cell(),
-                        options_vc.font_family(),
+    let fallbacks = match requested_cjk_script(options.subsets.as_deref()) {
+        Some(script) => lookup_cjk_fallbacks(
+            &options.font_family,
+            metrics_json,
+            script,
+            options.adjust_font_fallback,
+            full_width_advance.await?.map(|advance| advance.0),
+        ),
+        None => lookup_fallback(
+            &options.font_family,
+            metrics_json,
+            options.adjust_font_fallback,
+        )
+        .map(|fallback| vec![fallback]),
+    }
+    .map(|fallbacks| {
+        fallbacks
+            .into_iter()
+            .map(|fallback| Fallback {
+                adjustment: if options.unadjusted_fallbacks.contains(&fallback.font_family) {
+                    None
+                } else {
+                    apply_metrics_override(
+                        fallback.adjustment,
+                        options.fallback_metrics.get(&fallback.font_family),
                     )
-                    .to_resolved()
-                    .await?,
-                    local_font_family: ResolvedVc::cell(fallback.font_family),
-                    adjustment: fallback.adjustment,
-                })
-                .cell(),
-                Err(_) => {
-                    NextFontIssue {
-                        path: lookup_path,
-                        title: StyledString::Text(
-                            format!(
-                                "Failed to find font override values for font `{}`",
-                                &options.font_family,
-                            )
-                            .into(),
-                        )
-                        .resolved_cell(),
-                        description: StyledString::Text(
-                            "Skipping generating a fallback font.".into(),
-                        )
-                        .resolved_cell(),
-                        severity: IssueSeverity::Warning.resolved_cell(),
-                    }
-                    .resolved_cell()
-                    .emit();
-                    FontFallback::Error.cell()
+                },
+                font_family: fallback.font_family,
+            })
+            .collect::<Vec<_>>()
+    })
+    .or_else(|err| {
+        // Fonts missing from the metrics can still get an adjusted fallback when
+        // the user provided all of its metrics.
+        fallback_from_metrics_overrides(options.fallback.as_deref(), &options.fallback_metrics)
+            .map(|fallback| vec![fallback])
+            .ok_or(err)
+    });
+
+    Ok(match fallbacks {
+        Ok(fallbacks) => FontFallback::Automatic(stack_automatic_fallbacks(
+            &*get_scoped_font_family(FontFamilyType::Fallback.cell(), options_vc.font_family())
+                .await?,
+            fallbacks
+                .into_iter()
+                .map(|fallback| (fallback.font_family, fallback.adjustment)),
+        ))
+        .cell(),
+        Err(_) => {
+            NextFontIssue {
+                path: lookup_path,
+                title: StyledString::Text(
+                    format!(
+                        "Failed to find font override values for font `{}`",
+                        &options.font_family,
+                    )
+                    .into(),
+                )
+                .resolved_cell(),
+                description: StyledString::Text("Skipping generating a fallback font.".into())
+                    .resolved_cell(),
+                severity: IssueSeverity::Warning.resolved_cell(),
+            }
+            .resolved_cell()
+            .emit();
+            FontFallback::Error.cell()
+        }
+    })
+}
+
+/// The CJK script of the font, from the first CJK subset in the order the
+/// subsets were requested, e.g. Japanese for `['japanese', 'korean']`.
+fn requested_cjk_script(subsets: Option<&[RcStr]>) -> Option<CjkScript> {
+    subsets
+        .into_iter()
+        .flatten()
+        .find_map(|subset| CjkScript::from_subset(subset))
+}
+
+/// Measures the average advance of the full-width glyphs of a CJK webfont, as
+/// Google's metrics only describe its Latin glyphs. The glyphs of the sample
+/// characters of the requested script are measured in the font file of
+/// `stylesheet` covering most of them. Returns `None` for other fonts, or when
+/// the font file can't be fetched or read.
+#[turbo_tasks::function]
+pub(super) async fn get_full_width_advance(
+    font_provider: Vc<Box<dyn FontProvider>>,
+    stylesheet: Vc<RcStr>,
+    options: Vc<NextFontGoogleOptions>,
+    virtual_path: Vc<FileSystemPath>,
+) -> Result<Vc<OptionFullWidthAdvance>> {
+    let options = options.await?;
+    let Some(script) = requested_cjk_script(options.subsets.as_deref()) else {
+        return Ok(Vc::cell(None));
+    };
+    let characters = script.sample_characters();
+    let Some(font_url) = find_font_file_covering(&stylesheet.await?, characters) else {
+        return Ok(Vc::cell(None));
+    };
+    let Some(font_file) = *font_provider.fetch(font_url.into(), virtual_path).await? else {
+        return Ok(Vc::cell(None));
+    };
+
+    Ok(Vc::cell(
+        measure_full_width_advance(&font_file.await?.0, characters).map(FullWidthAdvance),
+    ))
+}
+
+/// Returns the url of the font file whose `unicode-range` covers most of
+/// `characters`, as CJK fonts are served in many slices. A `@font-face`
+/// without a `unicode-range` covers all of them.
+fn find_font_file_covering(stylesheet: &str, characters: &str) -> Option<String> {
+    let mut best: Option<(usize, &str)> = None;
+    let mut font_url = None;
+    let mut unicode_range = None;
+    for line in stylesheet.lines() {
+        let line = line.trim();
+        if let Some((_, url)) = lazy_regex::regex_captures!(r#"src: url\((.+?)\)"#, line) {
+            font_url = Some(url);
+        } else if let Some(range) = line.strip_prefix("unicode-range:") {
+            unicode_range = Some(range.trim().trim_end_matches(';'));
+        } else if line == "}" {
+            let covered = match unicode_range.take().map(parse_unicode_range) {
+                Some(Ok(ranges)) => characters
+                    .chars()
+                    .filter(|&c| ranges.iter().any(|range| range.contains(&(c as u32))))
+                    .count(),
+                Some(Err(_)) => 0,
+                None => characters.chars().count(),
+            };
+            if let Some(url) = font_url.take() {
+                if covered > best.map_or(0, |(most_covered, _)| most_covered) {
+                    best = Some((covered, url));
                 }
             }
         }
+    }
+
+    best.map(|(_, url)| url.to_string())
+}
+
+/// Averages the advance of the glyphs the font file has for `characters`, in
+/// ems.
+fn measure_full_width_advance(font_file: &[u8], characters: &str) -> Option<f64> {
+    let font_data = ReadScope::new(font_file).read::<FontData>().ok()?;
+    let mut font = Font::new(font_data.table_provider(0).ok()?).ok()??;
+    let units_per_em = font.head_table().ok()??.units_per_em as f64;
+    let advances = glyph_advances(&mut font, characters)
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    if advances.is_empty() {
+        return None;
+    }
+
+    Some(
+        advances.iter().map(|&advance| advance as f64).sum::<f64>()
+            / advances.len() as f64
+            / units_per_em,
+    )
+}
+
+/// The adjusted fallback for a font missing from the metrics, whose category
+/// is unknown: the default fallback font of the generic family in the
+/// `fallback` stack, e.g. Times New Roman for `serif`, or else the first
+/// default fallback font the user provided all metrics for.
+fn fallback_from_metrics_overrides(
+    fallback: Option<&[RcStr]>,
+    fallback_metrics: &FontMetricsOverrides,
+) -> Option<Fallback> {
+    let defaults: [&DefaultFallbackFont; 3] = [
+        &DEFAULT_SANS_SERIF_FONT,
+        &DEFAULT_SERIF_FONT,
+        &DEFAULT_MONOSPACE_FONT,
+    ];
+    let category =
+        fallback
+            .into_iter()
+            .flatten()
+            .find_map(|font_family| match font_family.as_str() {
+                "sans-serif" => Some(defaults[0]),
+                "serif" => Some(defaults[1]),
+                "monospace" => Some(defaults[2]),
+                _ => None,
+            });
+    let candidates = match category {
+        Some(default) => vec![default],
+        None => defaults.to_vec(),
+    };
+
+    candidates.into_iter().find_map(|default| {
+        let adjustment = fallback_metrics
+            .get(&default.name)
+            .and_then(FontMetricsOverride::to_adjustment)?;
+        Some(Fallback {
+            font_family: default.name.clone(),
+            adjustment: Some(adjustment),
+        })
     })
 }
 
@@ -136,30 +386,21 @@ fn lookup_fallback(
         .get(&font_family)
         .context("Font not found in metrics")?;
 
-    let fallback = if metrics.category == "serif" {
-        &DEFAULT_SERIF_FONT
-    } else {
-        &DEFAULT_SANS_SERIF_FONT
+    let fallback = match metrics.category.as_str() {
+        "serif" => &DEFAULT_SERIF_FONT,
+        "monospace" => &DEFAULT_MONOSPACE_FONT,
+        _ => &DEFAULT_SANS_SERIF_FONT,
     };
 
     let metrics = if adjust {
-        // Derived from
-        // https://github.com/vercel/next.js/blob/7bfd5829999b1d203e447d30de7e29108c31934a/packages/next/src/server/font-utils.ts#L131
-        let main_font_avg_width = metrics.x_width_avg / metrics.units_per_em as f64;
-        let fallback_metrics = font_metrics_map.0.get(&fallback.capsize_key).unwrap();
-        let fallback_font_avg_width = fallback_metrics.x_width_avg / fallback.units_per_em as f64;
-        let size_adjust = main_font_avg_width / fallback_font_avg_width;
-
-        let ascent = metrics.ascent as f64 / (metrics.units_per_em as f64 * size_adjust);
-        let descent = metrics.descent as f64 / (metrics.units_per_em as f64 * size_adjust);
-        let line_gap = metrics.line_gap as f64 / (metrics.units_per_em as f64 * size_adjust);
-
-        Some(FontAdjustment {
-            ascent,
-            descent,
-            line_gap,
-            size_adjust,
-        })
+        let fallback_metrics = font_metrics_map
+            .0
+            .get(&fallback.capsize_key)
+            .context("Fallback font not found in metrics")?;
+        Some(get_font_adjustment(
+            metrics,
+            fallback_metrics.x_width_avg / fallback.units_per_em as f64,
+        ))
     } else {
         None
     };
@@ -170,13 +411,138 @@ fn lookup_fallback(
     })
 }
 
+/// Adjusts the locally installed `fallback_font_family` to `font_family`, or
+/// returns `None` if either is missing from the metrics.
+fn lookup_fallback_adjustment(
+    font_family: &str,
+    fallback_font_family: &str,
+    font_metrics_map: &FontMetricsMap,
+) -> Option<FontAdjustment> {
+    let metrics = font_metrics_map
+        .0
+        .get(&format_fallback_font_name(font_family))?;
+    let fallback_metrics = font_metrics_map
+        .0
+        .get(&format_fallback_font_name(fallback_font_family))?;
+    Some(get_font_adjustment(
+        metrics,
+        fallback_metrics.x_width_avg / fallback_metrics.units_per_em as f64,
+    ))
+}
+
+/// Computes the adjustment of a fallback font whose average character width is
+/// `fallback_font_avg_width` ems to the font with `metrics`.
+fn get_font_adjustment(
+    metrics: &FontMetricsMapEntry,
+    fallback_font_avg_width: f64,
+) -> FontAdjustment {
+    // Derived from
+    // https://github.com/vercel/next.js/blob/7bfd5829999b1d203e447d30de7e29108c31934a/packages/next/src/server/font-utils.ts#L131
+    let main_font_avg_width = metrics.x_width_avg / metrics.units_per_em as f64;
+    let size_adjust = main_font_avg_width / fallback_font_avg_width;
+
+    FontAdjustment {
+        ascent: metrics.ascent as f64 / (metrics.units_per_em as f64 * size_adjust),
+        descent: metrics.descent as f64 / (metrics.units_per_em as f64 * size_adjust),
+        line_gap: metrics.line_gap as f64 / (metrics.units_per_em as f64 * size_adjust),
+        size_adjust,
+    }
+}
+
+/// Google's metrics only describe the Latin glyphs of CJK fonts, so the
+/// average advance of their full-width glyphs is measured from the font file,
+/// in ems. When it couldn't be measured, they're assumed to be 1em wide, like
+/// those of the fallback fonts for the script.
+fn lookup_cjk_fallbacks(
+    font_family: &str,
+    font_metrics_map: FontMetricsMap,
+    script: CjkScript,
+    adjust: bool,
+    full_width_advance: Option<f64>,
+) -> Result<Vec<Fallback>> {
+    let font_family = format_fallback_font_name(font_family);
+    let metrics = font_metrics_map
+        .0
+        .get(&font_family)
+        .context("Font not found in metrics")?;
+
+    let adjustment = adjust.then(|| {
+        let units_per_em = metrics.units_per_em as f64;
+        get_cjk_font_adjustment(
+            full_width_advance.map_or(units_per_em, |advance| advance * units_per_em),
+            units_per_em,
+            metrics.ascent as f64,
+            metrics.descent as f64,
+            metrics.line_gap as f64,
+        )
+    });
+
+    Ok(script
+        .fallback_fonts()
+        .iter()
+        .map(|name| Fallback {
+            font_family: (*name).into(),
+            adjustment: adjustment.clone(),
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
     use turbo_tasks_fs::json::parse_json_with_source_context;
 
     use super::{FontAdjustment, FontMetricsMap};
-    use crate::next_font::google::font_fallback::{lookup_fallback, Fallback};
+    use crate::next_font::{
+        font_fallback::{CjkScript, FontMetricsOverride, FontMetricsOverrides},
+        google::font_fallback::{
+            fallback_from_metrics_overrides, find_font_file_covering, lookup_cjk_fallbacks,
+            lookup_fallback, lookup_fallback_adjustment, requested_cjk_script, Fallback,
+        },
+    };
+
+    #[test]
+    fn test_fallback_from_metrics_overrides() {
+        let metrics = FontMetricsOverride {
+            ascent: Some(0.9),
+            descent: Some(-0.2),
+            line_gap: Some(0.0),
+            size_adjust: Some(1.1),
+        };
+        let adjustment = FontAdjustment {
+            ascent: 0.9,
+            descent: -0.2,
+            line_gap: 0.0,
+            size_adjust: 1.1,
+        };
+        let overrides: FontMetricsOverrides = [
+            ("Times New Roman".into(), metrics.clone()),
+            ("Courier New".into(), metrics),
+        ]
+        .into_iter()
+        .collect();
+
+        // The generic family of the fallback stack picks the category
+        assert_eq!(
+            fallback_from_metrics_overrides(Some(&["monospace".into()]), &overrides),
+            Some(Fallback {
+                font_family: "Courier New".into(),
+                adjustment: Some(adjustment.clone()),
+            })
+        );
+        assert_eq!(
+            fallback_from_metrics_overrides(Some(&["sans-serif".into()]), &overrides),
+            None
+        );
+        // Otherwise the first default fallback font with metrics is used
+        assert_eq!(
+            fallback_from_metrics_overrides(None, &overrides),
+            Some(Fallback {
+                font_family: "Times New Roman".into(),
+                adjustment: Some(adjustment),
+            })
+        );
+    }
 
     #[test]
     fn test_fallback_from_metrics_sans_serif() -> Result<()> {
@@ -269,4 +635,240 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_fallback_from_metrics_monospace() -> Result<()> {
+        let font_metrics: FontMetricsMap = parse_json_with_source_context(
+            r#"
+            {
+                "robotoMono": {
+                    "familyName": "Roboto Mono",
+                    "category": "monospace",
+                    "capHeight": 1456,
+                    "ascent": 2146,
+                    "descent": -555,
+                    "lineGap": 0,
+                    "unitsPerEm": 2048,
+                    "xHeight": 1082,
+                    "xWidthAvg": 1229
+                },
+                "courierNew": {
+                    "familyName": "Courier New",
+                    "category": "monospace",
+                    "capHeight": 1170,
+                    "ascent": 1705,
+                    "descent": -615,
+                    "lineGap": 0,
+                    "unitsPerEm": 2048,
+                    "xHeight": 866,
+                    "xWidthAvg": 1229
+                }
+            }
+        "#,
+        )?;
+
+        assert_eq!(
+            lookup_fallback("Roboto Mono", font_metrics, true)?,
+            Fallback {
+                font_family: "Courier New".into(),
+                adjustment: Some(FontAdjustment {
+                    ascent: 1.047_851_562_5,
+                    descent: -0.270_996_093_75,
+                    line_gap: 0.0,
+                    size_adjust: 1.0
+                })
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fallback_from_metrics_cjk() -> Result<()> {
+        let font_metrics: FontMetricsMap = parse_json_with_source_context(
+            r#"
+            {
+                "notoSansJp": {
+                    "familyName": "Noto Sans JP",
+                    "category": "sans-serif",
+                    "capHeight": 733,
+                    "ascent": 1160,
+                    "descent": -288,
+                    "lineGap": 0,
+                    "unitsPerEm": 1000,
+                    "xHeight": 543,
+                    "xWidthAvg": 432
+                }
+            }
+        "#,
+        )?;
+
+        let adjustment = FontAdjustment {
+            ascent: 1.16,
+            descent: -0.288,
+            line_gap: 0.0,
+            size_adjust: 1.0,
+        };
+        assert_eq!(
+            lookup_cjk_fallbacks(
+                "Noto Sans JP",
+                font_metrics,
+                CjkScript::Japanese,
+                true,
+                None
+            )?,
+            [
+                "Hiragino Sans",
+                "Hiragino Kaku Gothic ProN",
+                "Yu Gothic",
+                "Meiryo"
+            ]
+            .into_iter()
+            .map(|font_family| Fallback {
+                font_family: font_family.into(),
+                adjustment: Some(adjustment.clone()),
+            })
+            .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fallback_from_metrics_cjk_with_full_width_advance() -> Result<()> {
+        let font_metrics: FontMetricsMap = parse_json_with_source_context(
+            r#"
+            {
+                "notoSansKr": {
+                    "familyName": "Noto Sans KR",
+                    "category": "sans-serif",
+                    "capHeight": 733,
+                    "ascent": 1160,
+                    "descent": -288,
+                    "lineGap": 0,
+                    "unitsPerEm": 1000,
+                    "xHeight": 543,
+                    "xWidthAvg": 432
+                }
+            }
+        "#,
+        )?;
+
+        // The full-width glyphs measured from the font file are 0.92em wide
+        let adjustment = FontAdjustment {
+            ascent: 1.260_869_565_217_391_4,
+            descent: -0.313_043_478_260_869_6,
+            line_gap: 0.0,
+            size_adjust: 0.92,
+        };
+        assert_eq!(
+            lookup_cjk_fallbacks(
+                "Noto Sans KR",
+                font_metrics,
+                CjkScript::Korean,
+                true,
+                Some(0.92)
+            )?,
+            ["Apple SD Gothic Neo", "Malgun Gothic"]
+                .into_iter()
+                .map(|font_family| Fallback {
+                    font_family: font_family.into(),
+                    adjustment: Some(adjustment.clone()),
+                })
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_requested_cjk_script() {
+        assert_eq!(
+            requested_cjk_script(Some(&["latin".into(), "japanese".into(), "korean".into()])),
+            Some(CjkScript::Japanese)
+        );
+        assert_eq!(
+            requested_cjk_script(Some(&["korean".into(), "chinese-simplified".into()])),
+            Some(CjkScript::Korean)
+        );
+        assert_eq!(requested_cjk_script(Some(&["latin".into()])), None);
+        assert_eq!(requested_cjk_script(None), None);
+    }
+
+    #[test]
+    fn test_find_font_file_covering() {
+        let stylesheet = r#"
+            /* [0] */
+            @font-face {
+              font-family: 'Noto Sans JP';
+              src: url(https://fonts.gstatic.com/s/notosansjp/v53/0.woff2) format('woff2');
+              unicode-range: U+25ee8, U+25f23, U+25f5c;
+            }
+            /* [119] */
+            @font-face {
+              font-family: 'Noto Sans JP';
+              src: url(https://fonts.gstatic.com/s/notosansjp/v53/119.woff2) format('woff2');
+              unicode-range: U+3000-303f, U+3041-3096, U+30a1-30fa;
+            }
+            /* latin */
+            @font-face {
+              font-family: 'Noto Sans JP';
+              src: url(https://fonts.gstatic.com/s/notosansjp/v53/latin.woff2) format('woff2');
+              unicode-range: U+0000-00FF;
+            }
+        "#;
+
+        assert_eq!(
+            find_font_file_covering(stylesheet, CjkScript::Japanese.sample_characters()),
+            Some("https://fonts.gstatic.com/s/notosansjp/v53/119.woff2".to_string())
+        );
+        assert_eq!(
+            find_font_file_covering(stylesheet, CjkScript::Korean.sample_characters()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fallback_adjustment_for_manual_fallback() -> Result<()> {
+        let font_metrics: FontMetricsMap = parse_json_with_source_context(
+            r#"
+            {
+                "robotoMono": {
+                    "familyName": "Roboto Mono",
+                    "category": "monospace",
+                    "capHeight": 1456,
+                    "ascent": 2146,
+                    "descent": -555,
+                    "lineGap": 0,
+                    "unitsPerEm": 2048,
+                    "xHeight": 1082,
+                    "xWidthAvg": 1229
+                },
+                "courierNew": {
+                    "familyName": "Courier New",
+                    "category": "monospace",
+                    "capHeight": 1170,
+                    "ascent": 1705,
+                    "descent": -615,
+                    "lineGap": 0,
+                    "unitsPerEm": 2048,
+                    "xHeight": 866,
+                    "xWidthAvg": 1229
+                }
+            }
+        "#,
+        )?;
+
+        assert_eq!(
+            lookup_fallback_adjustment("Roboto Mono", "Courier New", &font_metrics),
+            Some(FontAdjustment {
+                ascent: 1.047_851_562_5,
+                descent: -0.270_996_093_75,
+                line_gap: 0.0,
+                size_adjust: 1.0
+            })
+        );
+        assert_eq!(
+            lookup_fallback_adjustment("Roboto Mono", "Menlo", &font_metrics),
+            None
+        );
+        Ok(())
+    }
 }
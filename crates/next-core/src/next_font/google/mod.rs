@@ -8,7 +8,6 @@ use turbo_rcstr::RcStr;
 use turbo_tasks::{Completion, FxIndexMap, ResolvedVc, Value, Vc};
 use turbo_tasks_bytes::stream::SingleValue;
 use turbo_tasks_env::{CommandLineProcessEnv, ProcessEnv};
-use turbo_tasks_fetch::{fetch, HttpResponseBody};
 use turbo_tasks_fs::{
     json::parse_json_with_source_context, DiskFileSystem, File, FileContent, FileSystem,
     FileSystemPath,
@@ -18,7 +17,7 @@ use turbopack_core::{
     asset::AssetContent,
     context::AssetContext,
     ident::AssetIdent,
-    issue::{IssueExt, IssueSeverity},
+    issue::{IssueExt, IssueSeverity, StyledString},
     reference_type::{InnerAssets, ReferenceType},
     resolve::{
         options::{ImportMapResult, ImportMappingReplacement, ReplacedImportMapping},
@@ -33,28 +32,39 @@ use turbopack_node::{
 };
 
 use self::{
-    font_fallback::get_font_fallback,
-    options::{options_from_request, FontDataEntry, FontWeights, NextFontGoogleOptions},
+    font_fallback::{get_font_fallback, get_full_width_advance},
+    options::{
+        options_from_request, remove_unsupported_axes, FontDataEntry, FontWeights,
+        NextFontGoogleOptions, UnsupportedAxes,
+    },
+    provider::{get_font_provider, FontProvider},
     stylesheet::build_stylesheet,
-    util::{get_font_axes, get_stylesheet_url},
+    subset::{subset_font, subset_stylesheet},
 };
 use super::{
-    font_fallback::FontFallback,
+    diagnostics::NextFontStylesheetSource,
+    font_fallback::{FontFallback, FontFallbacks},
+    issue::NextFontIssue,
     util::{
         can_use_next_font, get_request_hash, get_request_id, get_scoped_font_family,
         FontCssProperties, FontFamilyType,
     },
 };
 use crate::{
-    embed_js::next_js_file_path, next_app::metadata::split_extension, util::load_next_js_templateon,
+    embed_js::next_js_file_path, next_app::metadata::split_extension, next_config::NextConfig,
+    util::load_next_js_templateon,
 };
 
 pub mod font_fallback;
 pub mod options;
+pub mod provider;
 pub mod request;
 pub mod stylesheet;
+mod subset;
 pub mod util;
 
+pub(crate) use provider::get_fetch_config;
+
 pub const GOOGLE_FONTS_STYLESHEET_URL: &str = "https://fonts.googleapis.com/css2";
 // Always sending this user agent ensures consistent results from Google Fonts.
 // Google Fonts will vary responses based on user agent, e.g. only returning
@@ -67,32 +77,47 @@ pub const USER_AGENT_FOR_GOOGLE_FONTS: &str = "Mozilla/5.0 (Macintosh; Intel Mac
 /// specific format that is then intercepted later. This is the prefix we use for the new url.
 pub const GOOGLE_FONTS_INTERNAL_PREFIX: &str = "@vercel/turbopack-next/internal/font/google/font";
 
+/// Metadata of the fonts of a [provider::FontProvider], keyed by font family.
 #[turbo_tasks::value(transparent)]
-struct FontData(FxIndexMap<RcStr, FontDataEntry>);
+pub struct FontData(FxIndexMap<RcStr, FontDataEntry>);
 
 #[turbo_tasks::value(shared)]
 pub(crate) struct NextFontGoogleReplacer {
     project_path: ResolvedVc<FileSystemPath>,
+    next_config: ResolvedVc<NextConfig>,
 }
 
 #[turbo_tasks::value_impl]
 impl NextFontGoogleReplacer {
     #[turbo_tasks::function]
-    pub fn new(project_path: ResolvedVc<FileSystemPath>) -> Vc<Self> {
-        Self::cell(NextFontGoogleReplacer { project_path })
+    pub fn new(
+        project_path: ResolvedVc<FileSystemPath>,
+        next_config: ResolvedVc<NextConfig>,
+    ) -> Vc<Self> {
+        Self::cell(NextFontGoogleReplacer {
+            project_path,
+            next_config,
+        })
     }
 
     #[turbo_tasks::function]
     async fn import_map_result(&self, query: RcStr) -> Result<Vc<ImportMapResult>> {
-        let request_hash = get_request_hash(&query).await?;
+        let request_hash = get_request_hash(
+            &query,
+            &*self.next_config.experimental_font_family_hashing().await?,
+        )
+        .await?;
         let qstr = qstring::QString::from(query.as_str());
 
         let query_vc = Vc::cell(query);
 
-        let font_data = load_font_data(*self.project_path);
-        let options = font_options_from_query_map(query_vc, font_data);
+        let font_provider = get_font_provider(*self.project_path, *self.next_config);
+        let options =
+            font_options_from_query_map(query_vc, font_provider.font_data(), *self.project_path);
 
-        let fallback = get_font_fallback(*self.project_path, options);
+        // Only the names of the fallbacks are used here, which don't depend on
+        // the measured full-width advance
+        let fallback = get_font_fallback(*self.project_path, options, Vc::cell(None));
         let properties = get_font_css_properties(options, fallback).await?;
         let js_asset = VirtualSource::new(
             next_js_file_path("internal/font/google".into())
@@ -182,6 +207,7 @@ impl ImportMappingReplacement for NextFontGoogleReplacer {
 pub struct NextFontGoogleCssModuleReplacer {
     project_path: ResolvedVc<FileSystemPath>,
     execution_context: ResolvedVc<ExecutionContext>,
+    next_config: ResolvedVc<NextConfig>,
 }
 
 #[turbo_tasks::value_impl]
@@ -190,20 +216,27 @@ impl NextFontGoogleCssModuleReplacer {
     pub fn new(
         project_path: ResolvedVc<FileSystemPath>,
         execution_context: ResolvedVc<ExecutionContext>,
+        next_config: ResolvedVc<NextConfig>,
     ) -> Vc<Self> {
         Self::cell(NextFontGoogleCssModuleReplacer {
             project_path,
             execution_context,
+            next_config,
         })
     }
 
     #[turbo_tasks::function]
     async fn import_map_result(&self, query: RcStr) -> Result<Vc<ImportMapResult>> {
-        let request_hash = get_request_hash(&query).await?;
+        let request_hash = get_request_hash(
+            &query,
+            &*self.next_config.experimental_font_family_hashing().await?,
+        )
+        .await?;
         let query_vc = Vc::cell(query);
-        let font_data = load_font_data(*self.project_path);
-        let options = font_options_from_query_map(query_vc, font_data);
-        let stylesheet_url = get_stylesheet_url_from_options(options, font_data);
+        let font_provider = get_font_provider(*self.project_path, *self.next_config);
+        let options =
+            font_options_from_query_map(query_vc, font_provider.font_data(), *self.project_path);
+        let stylesheet_url = font_provider.stylesheet_url(options);
         let scoped_font_family =
             get_scoped_font_family(FontFamilyType::WebFont.cell(), options.font_family());
         let css_virtual_path = next_js_file_path("internal/font/google".into()).join(
@@ -222,12 +255,20 @@ impl NextFontGoogleCssModuleReplacer {
         let stylesheet_str = mocked_responses_path
             .as_ref()
             .map_or_else(
-                || fetch_real_stylesheet(stylesheet_url, css_virtual_path).boxed(),
+                || fetch_real_stylesheet(font_provider, stylesheet_url, css_virtual_path).boxed(),
                 |p| get_mock_stylesheet(stylesheet_url, p, *self.execution_context).boxed(),
             )
             .await?;
 
-        let font_fallback = get_font_fallback(*self.project_path, options);
+        // Font files referenced by mocked stylesheets aren't fetched to measure
+        // them
+        let full_width_advance = match stylesheet_str {
+            Some(stylesheet) if mocked_responses_path.is_none() => {
+                get_full_width_advance(font_provider, stylesheet, options, css_virtual_path)
+            }
+            _ => Vc::cell(None),
+        };
+        let font_fallbacks = get_font_fallback(*self.project_path, options, full_width_advance);
 
         let stylesheet = match stylesheet_str {
             Some(s) => Some(
@@ -235,34 +276,38 @@ impl NextFontGoogleCssModuleReplacer {
                     s,
                     options,
                     scoped_font_family,
-                    font_fallback.has_size_adjust(),
+                    font_fallbacks.has_size_adjust(),
+                    self.next_config.experimental_font_subsetting(),
                 )
                 .await?
                 .clone_value(),
             ),
             None => {
                 println!(
-                    "Failed to download `{}` from Google Fonts. Using fallback font instead.",
-                    options.await?.font_family
+                    "Failed to download `{}` from {}. Using fallback font instead.",
+                    options.await?.font_family,
+                    font_provider.name().await?
                 );
                 None
             }
         };
 
-        let css_asset = VirtualSource::new(
+        let css_asset = NextFontStylesheetSource::new(
             css_virtual_path,
             AssetContent::file(
                 FileContent::Content(
                     build_stylesheet(
                         Vc::cell(stylesheet),
-                        get_font_css_properties(options, font_fallback),
-                        font_fallback,
+                        get_font_css_properties(options, font_fallbacks),
+                        font_fallbacks,
                     )
                     .await?
                     .into(),
                 )
                 .cell(),
             ),
+            scoped_font_family,
+            font_fallbacks,
         )
         .to_resolved()
         .await?;
@@ -282,9 +327,9 @@ impl ImportMappingReplacement for NextFontGoogleCssModuleReplacer {
     }
 
     /// Intercepts requests for the css module made by the virtual JavaScript
-    /// asset generated by the above replacer. Returns a VirtualSource of a CSS
-    /// Module containing font face definitions and exporting class names for
-    /// the font and an optional css variable.
+    /// asset generated by the above replacer. Returns a source of a CSS Module
+    /// containing font face definitions and exporting class names for the font
+    /// and an optional css variable.
     #[turbo_tasks::function]
     async fn result(
         self: Vc<Self>,
@@ -311,18 +356,28 @@ struct NextFontGoogleFontFileOptions {
     pub url: String,
     pub preload: bool,
     pub has_size_adjust: bool,
+    /// The `unicode-range` to subset the font file to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subset: Option<String>,
 }
 
 #[turbo_tasks::value(shared)]
 pub struct NextFontGoogleFontFileReplacer {
     project_path: ResolvedVc<FileSystemPath>,
+    next_config: ResolvedVc<NextConfig>,
 }
 
 #[turbo_tasks::value_impl]
 impl NextFontGoogleFontFileReplacer {
     #[turbo_tasks::function]
-    pub fn new(project_path: ResolvedVc<FileSystemPath>) -> Vc<Self> {
-        Self::cell(NextFontGoogleFontFileReplacer { project_path })
+    pub fn new(
+        project_path: ResolvedVc<FileSystemPath>,
+        next_config: ResolvedVc<NextConfig>,
+    ) -> Vc<Self> {
+        Self::cell(NextFontGoogleFontFileReplacer {
+            project_path,
+            next_config,
+        })
     }
 }
 
@@ -357,6 +412,7 @@ impl ImportMappingReplacement for NextFontGoogleFontFileReplacer {
             url,
             preload,
             has_size_adjust: size_adjust,
+            subset,
         } = font_file_options_from_query_map(**query_vc).await?;
 
         let (filename, ext) = split_extension(&url);
@@ -377,17 +433,52 @@ impl ImportMappingReplacement for NextFontGoogleFontFileReplacer {
 
         // doesn't seem ideal to download the font into a string, but probably doesn't
         // really matter either.
-        let Some(font) = fetch_from_google_fonts(Vc::cell(url.into()), font_virtual_path).await?
+        let font_provider = get_font_provider(*self.project_path, *self.next_config);
+        let Some(font) = *font_provider
+            .fetch(url.as_str().into(), font_virtual_path)
+            .await?
         else {
             return Ok(
                 ImportMapResult::Result(ResolveResult::unresolvable().resolved_cell()).cell(),
             );
         };
 
-        let font_source = VirtualSource::new(
-            font_virtual_path,
-            AssetContent::file(FileContent::Content(font.await?.0.as_slice().into()).cell()),
-        )
+        let font = &font.await?.0;
+
+        let subsetted_font = match subset.map(|subset| subset_font(font, &subset)) {
+            Some(Ok(subsetted_font)) => subsetted_font,
+            Some(Err(err)) => {
+                NextFontIssue {
+                    path: font_virtual_path.to_resolved().await?,
+                    title: StyledString::Text(
+                        format!("Failed to subset font file `{}`", &url).into(),
+                    )
+                    .resolved_cell(),
+                    description: StyledString::Text(
+                        format!("Using the complete font file instead: {}", err).into(),
+                    )
+                    .resolved_cell(),
+                    severity: IssueSeverity::Warning.resolved_cell(),
+                }
+                .resolved_cell()
+                .emit();
+                None
+            }
+            None => None,
+        };
+
+        let font_source = match subsetted_font {
+            Some(subsetted_font) => VirtualSource::new(
+                next_js_file_path("internal/font/google".into())
+                    .join(format!("/{}.woff", name).into())
+                    .truncate_file_name_with_hash_vc(),
+                AssetContent::file(FileContent::Content(subsetted_font.into()).cell()),
+            ),
+            None => VirtualSource::new(
+                font_virtual_path,
+                AssetContent::file(FileContent::Content(font.as_slice().into()).cell()),
+            ),
+        }
         .to_resolved()
         .await?;
 
@@ -417,6 +508,7 @@ async fn update_google_stylesheet(
     options: Vc<NextFontGoogleOptions>,
     scoped_font_family: Vc<RcStr>,
     has_size_adjust: Vc<bool>,
+    subsetting: Vc<bool>,
 ) -> Result<Vc<RcStr>> {
     let options = &*options.await?;
 
@@ -427,6 +519,19 @@ async fn update_google_stylesheet(
         &format!("font-family: '{}';", &*scoped_font_family.await?),
     );
 
+    let mut font_files_to_subset = HashMap::new();
+    if let Some(subsets) = options
+        .subsets
+        .as_deref()
+        .filter(|subsets| !subsets.is_empty())
+    {
+        if *subsetting.await? {
+            let subsetted = subset_stylesheet(&stylesheet, subsets);
+            stylesheet = subsetted.css;
+            font_files_to_subset = subsetted.font_files_to_subset;
+        }
+    }
+
     let font_files = find_font_files_in_css(
         &stylesheet,
         if options.preload {
@@ -443,6 +548,7 @@ async fn update_google_stylesheet(
             url: font_url.clone(),
             preload,
             has_size_adjust,
+            subset: font_files_to_subset.get(&font_url).cloned(),
         };
         let query_str = qstring::QString::from(serde_json::to_string(&query)?.as_str());
 
@@ -491,60 +597,28 @@ fn find_font_files_in_css(css: &str, subsets_to_preload: &[RcStr]) -> Vec<FontFi
     font_files
 }
 
-#[turbo_tasks::function]
-async fn get_stylesheet_url_from_options(
-    options: Vc<NextFontGoogleOptions>,
-    font_data: Vc<FontData>,
-) -> Result<Vc<RcStr>> {
-    #[allow(unused_mut, unused_assignments)] // This is used in test environments
-    let mut css_url: Option<String> = None;
-    #[cfg(debug_assertions)]
-    {
-        use turbo_tasks_env::{CommandLineProcessEnv, ProcessEnv};
-
-        let env = CommandLineProcessEnv::new();
-        if let Some(url) = &*env.read("TURBOPACK_TEST_ONLY_MOCK_SERVER".into()).await? {
-            css_url = Some(format!("{}/css2", url));
-        }
-    }
-
-    let options = options.await?;
-    Ok(Vc::cell(
-        get_stylesheet_url(
-            css_url.as_deref().unwrap_or(GOOGLE_FONTS_STYLESHEET_URL),
-            &options.font_family,
-            &get_font_axes(
-                &*font_data.await?,
-                &options.font_family,
-                &options.weights,
-                &options.styles,
-                &options.selected_variable_axes,
-            )?,
-            &options.display,
-        )?
-        .into(),
-    ))
-}
-
 #[turbo_tasks::function]
 async fn get_font_css_properties(
     options_vc: Vc<NextFontGoogleOptions>,
-    font_fallback: Vc<FontFallback>,
+    font_fallbacks: Vc<FontFallbacks>,
 ) -> Result<Vc<FontCssProperties>> {
     let options = &*options_vc.await?;
     let scoped_font_family =
         &*get_scoped_font_family(FontFamilyType::WebFont.cell(), options_vc.font_family()).await?;
 
     let mut font_families = vec![format!("'{}'", scoped_font_family.clone()).into()];
-    let font_fallback = &*font_fallback.await?;
-    match font_fallback {
-        FontFallback::Manual(fonts) => {
-            font_families.extend_from_slice(fonts);
-        }
-        FontFallback::Automatic(fallback) => {
-            font_families.push(format!("'{}'", *fallback.scoped_font_family.await?).into());
+    for font_fallback in &*font_fallbacks.await? {
+        match &*font_fallback.await? {
+            FontFallback::Manual(fonts) => {
+                font_families.extend_from_slice(fonts);
+            }
+            FontFallback::Automatic(fallbacks) => {
+                for fallback in fallbacks {
+                    font_families.push(format!("'{}'", *fallback.scoped_font_family.await?).into());
+                }
+            }
+            FontFallback::Error => {}
         }
-        FontFallback::Error => {}
     }
 
     Ok(FontCssProperties::cell(FontCssProperties {
@@ -574,6 +648,7 @@ async fn get_font_css_properties(
 async fn font_options_from_query_map(
     query: Vc<RcStr>,
     font_data: Vc<FontData>,
+    lookup_path: ResolvedVc<FileSystemPath>,
 ) -> Result<Vc<NextFontGoogleOptions>> {
     let query_map = qstring::QString::from(&**query.await?);
 
@@ -585,8 +660,45 @@ async fn font_options_from_query_map(
         bail!("Expected one entry");
     };
 
-    let options =
-        options_from_request(&parse_json_with_source_context(&json)?, &*font_data.await?)?;
+    let font_data = &*font_data.await?;
+    let mut request = parse_json_with_source_context(&json)?;
+    if let Some(UnsupportedAxes {
+        font_family,
+        unsupported,
+        supported,
+    }) = remove_unsupported_axes(&mut request, font_data)
+    {
+        NextFontIssue {
+            path: lookup_path,
+            title: StyledString::Text(
+                format!(
+                    "Unknown variable axes {} for font `{}`",
+                    unsupported
+                        .iter()
+                        .map(|tag| format!("`{}`", tag))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    font_family
+                )
+                .into(),
+            )
+            .resolved_cell(),
+            description: StyledString::Text(
+                if supported.is_empty() {
+                    format!("`{}` doesn't define any variable axes.", font_family)
+                } else {
+                    format!("Available axes: {}", supported.join(", "))
+                }
+                .into(),
+            )
+            .resolved_cell(),
+            severity: IssueSeverity::Error.resolved_cell(),
+        }
+        .resolved_cell()
+        .emit();
+    }
+
+    let options = options_from_request(&request, font_data)?;
     Ok(NextFontGoogleOptions::new(Value::new(options)))
 }
 
@@ -607,45 +719,17 @@ async fn font_file_options_from_query_map(
 }
 
 async fn fetch_real_stylesheet(
+    font_provider: Vc<Box<dyn FontProvider>>,
     stylesheet_url: Vc<RcStr>,
     css_virtual_path: Vc<FileSystemPath>,
 ) -> Result<Option<Vc<RcStr>>> {
-    let body = fetch_from_google_fonts(stylesheet_url, css_virtual_path).await?;
+    let body = *font_provider
+        .fetch(stylesheet_url.await?.clone_value(), css_virtual_path)
+        .await?;
 
     Ok(body.map(|body| body.to_string()))
 }
 
-async fn fetch_from_google_fonts(
-    url: Vc<RcStr>,
-    virtual_path: Vc<FileSystemPath>,
-) -> Result<Option<Vc<HttpResponseBody>>> {
-    let result = fetch(
-        url,
-        Vc::cell(Some(USER_AGENT_FOR_GOOGLE_FONTS.into())),
-        Vc::cell(None),
-    )
-    .await?;
-
-    Ok(match &*result {
-        Ok(r) => Some(*r.await?.body),
-        Err(err) => {
-            // Inform the user of the failure to retreive the stylesheet / font, but don't
-            // propagate this error. We don't want e.g. offline connections to prevent page
-            // renders during development. During production builds, however, this error
-            // should propagate.
-            //
-            // TODO(WEB-283): Use fallback in dev in this case
-            // TODO(WEB-293): Fail production builds (not dev) in this case
-            err.to_issue(IssueSeverity::Warning.into(), virtual_path)
-                .to_resolved()
-                .await?
-                .emit();
-
-            None
-        }
-    })
-}
-
 async fn get_mock_stylesheet(
     stylesheet_url: Vc<RcStr>,
     mocked_responses_path: &str,
@@ -665,8 +749,8 @@ async fn get_mock_stylesheet(
 
     let ExecutionContext {
         env,
-        project_path: _,
         chunking_context,
+        ..
     } = *execution_context.await?;
     let asset_context =
         node_evaluate_asset_context(execution_context, None, None, "next_font".into(), false);
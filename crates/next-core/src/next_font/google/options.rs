@@ -5,7 +5,8 @@ use turbo_tasks::{
     fxindexset, trace::TraceRawVcs, FxIndexMap, FxIndexSet, NonLocalValue, Value, Vc,
 };
 
-use super::request::{NextFontRequest, OneOrManyStrings};
+use super::request::{FallbackMetricsArgument, NextFontRequest, OneOrManyStrings};
+use crate::next_font::font_fallback::{validate_metrics_overrides, FontMetricsOverrides};
 
 const ALLOWED_DISPLAY_VALUES: &[&str] = &["auto", "block", "swap", "fallback", "optional"];
 
@@ -13,7 +14,7 @@ pub(super) type FontData = FxIndexMap<RcStr, FontDataEntry>;
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
 #[derive(Clone, Debug, PartialOrd, Ord, Hash)]
-pub(super) struct NextFontGoogleOptions {
+pub struct NextFontGoogleOptions {
     /// Name of the requested font from Google. Contains literal spaces.
     pub font_family: RcStr,
     pub weights: FontWeights,
@@ -23,6 +24,12 @@ pub(super) struct NextFontGoogleOptions {
     pub selected_variable_axes: Option<Vec<RcStr>>,
     pub fallback: Option<Vec<RcStr>>,
     pub adjust_font_fallback: bool,
+    /// User-provided metrics for the automatic fallback font, taking
+    /// precedence over the computed ones.
+    pub fallback_metrics: FontMetricsOverrides,
+    /// Automatic fallback fonts to generate without adjusting their metrics,
+    /// from `fallbackMetrics` entries set to `false`.
+    pub unadjusted_fallbacks: Vec<RcStr>,
     /// An optional name for a css custom property (css variable) that applies
     /// the font family when used.
     pub variable: Option<RcStr>,
@@ -55,13 +62,13 @@ impl NextFontGoogleOptions {
     TraceRawVcs,
     NonLocalValue,
 )]
-pub(super) enum FontWeights {
+pub enum FontWeights {
     Variable,
     Fixed(Vec<u16>),
 }
 
 #[derive(Debug, PartialEq, Eq, Deserialize, Serialize, TraceRawVcs, NonLocalValue)]
-pub(super) struct FontDataEntry {
+pub struct FontDataEntry {
     pub weights: Vec<RcStr>,
     pub styles: Vec<RcStr>,
     pub axes: Option<Vec<Axis>>,
@@ -69,7 +76,7 @@ pub(super) struct FontDataEntry {
 
 #[derive(Debug, PartialEq, Deserialize, Serialize, TraceRawVcs, NonLocalValue)]
 #[serde(rename_all = "camelCase")]
-pub(super) struct Axis {
+pub struct Axis {
     pub tag: RcStr,
     pub min: f64,
     pub max: f64,
@@ -196,6 +203,19 @@ pub(super) fn options_from_request(
         }
     }
 
+    let mut fallback_metrics = FontMetricsOverrides::new();
+    let mut unadjusted_fallbacks = vec![];
+    for (font_family, metrics) in argument.fallback_metrics.unwrap_or_default() {
+        match metrics {
+            FallbackMetricsArgument::Adjust(true) => {}
+            FallbackMetricsArgument::Adjust(false) => unadjusted_fallbacks.push(font_family),
+            FallbackMetricsArgument::Override(metrics) => {
+                fallback_metrics.insert(font_family, metrics);
+            }
+        }
+    }
+    validate_metrics_overrides(&fallback_metrics)?;
+
     Ok(NextFontGoogleOptions {
         font_family,
         weights,
@@ -205,11 +225,58 @@ pub(super) fn options_from_request(
         selected_variable_axes: argument.axes,
         fallback: argument.fallback,
         adjust_font_fallback: argument.adjust_font_fallback.unwrap_or(true),
+        fallback_metrics,
+        unadjusted_fallbacks,
         variable: argument.variable,
         subsets: argument.subsets,
     })
 }
 
+/// Variable axes requested for a font that it doesn't define.
+#[derive(Debug, PartialEq)]
+pub(super) struct UnsupportedAxes {
+    pub font_family: RcStr,
+    pub unsupported: Vec<RcStr>,
+    pub supported: Vec<RcStr>,
+}
+
+/// Removes the requested variable `axes` that the font doesn't define, so they
+/// can be reported as an issue while the rest of the request still resolves.
+pub(super) fn remove_unsupported_axes(
+    request: &mut NextFontRequest,
+    data: &FontData,
+) -> Option<UnsupportedAxes> {
+    let font_family: RcStr = request.import.replace('_', " ").into();
+    let font_data = data.get(&font_family)?;
+    if !font_data.weights.iter().any(|el| el == "variable") {
+        // Reported by `options_from_request`, axes require a variable font.
+        return None;
+    }
+
+    let axes = request.arguments.last_mut()?.axes.as_mut()?;
+    let supported: Vec<RcStr> = font_data
+        .axes
+        .iter()
+        .flatten()
+        .map(|axis| axis.tag.clone())
+        .collect();
+    let unsupported: Vec<RcStr> = axes
+        .iter()
+        .filter(|tag| !supported.contains(tag))
+        .cloned()
+        .collect();
+    if unsupported.is_empty() {
+        return None;
+    }
+
+    axes.retain(|tag| supported.contains(tag));
+    Some(UnsupportedAxes {
+        font_family,
+        unsupported,
+        supported,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
@@ -217,8 +284,14 @@ mod tests {
     use turbo_tasks::FxIndexMap;
     use turbo_tasks_fs::json::parse_json_with_source_context;
 
-    use super::{options_from_request, FontDataEntry, NextFontGoogleOptions};
-    use crate::next_font::google::{options::FontWeights, request::NextFontRequest};
+    use super::{
+        options_from_request, remove_unsupported_axes, FontDataEntry, NextFontGoogleOptions,
+        UnsupportedAxes,
+    };
+    use crate::next_font::{
+        font_fallback::FontAdjustment,
+        google::{options::FontWeights, request::NextFontRequest},
+    };
 
     #[test]
     fn test_errors_on_unknown_font() -> Result<()> {
@@ -288,6 +361,8 @@ mod tests {
                 selected_variable_axes: None,
                 fallback: None,
                 adjust_font_fallback: true,
+                fallback_metrics: Default::default(),
+                unadjusted_fallbacks: vec![],
                 variable: None,
                 subsets: None,
             },
@@ -554,6 +629,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_fallback_metrics() -> Result<()> {
+        let data: FxIndexMap<RcStr, FontDataEntry> = parse_json_with_source_context(
+            r#"
+            {
+                "ABeeZee": {
+                    "weights": ["400", "variable"],
+                    "styles": ["normal", "italic"]
+                }
+            }
+            "#,
+        )?;
+
+        let request: NextFontRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "ABeeZee",
+                "path": "index.js",
+                "variableName": "abeezee",
+                "arguments": [{
+                    "fallbackMetrics": {
+                        "Arial": {
+                            "ascent": 0.9,
+                            "descent": -0.22,
+                            "lineGap": 0,
+                            "sizeAdjust": 1.07
+                        }
+                    }
+                }]
+            }
+            "#,
+        )?;
+
+        let options = options_from_request(&request, &data)?;
+        assert_eq!(
+            options.fallback_metrics["Arial"].to_adjustment(),
+            Some(FontAdjustment {
+                ascent: 0.9,
+                descent: -0.22,
+                line_gap: 0.0,
+                size_adjust: 1.07,
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_errors_on_axes_without_variable_weight() -> Result<()> {
         let data: FxIndexMap<RcStr, FontDataEntry> = parse_json_with_source_context(
@@ -634,4 +756,92 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_fallback_metrics_disable_adjustment() -> Result<()> {
+        let data: FxIndexMap<RcStr, FontDataEntry> = parse_json_with_source_context(
+            r#"
+            {
+                "Noto Sans JP": {
+                    "weights": ["400", "variable"],
+                    "styles": ["normal"]
+                }
+            }
+            "#,
+        )?;
+
+        let request: NextFontRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "Noto_Sans_JP",
+                "path": "index.js",
+                "variableName": "notoSansJp",
+                "arguments": [{
+                    "fallback": ["system-ui"],
+                    "fallbackMetrics": {
+                        "Yu Gothic": false,
+                        "Meiryo": true,
+                        "Hiragino Sans": { "sizeAdjust": 1.1 }
+                    }
+                }]
+            }
+            "#,
+        )?;
+
+        let options = options_from_request(&request, &data)?;
+        assert_eq!(options.unadjusted_fallbacks, vec![RcStr::from("Yu Gothic")]);
+        assert_eq!(
+            options.fallback_metrics.keys().collect::<Vec<_>>(),
+            vec![&RcStr::from("Hiragino Sans")]
+        );
+        assert_eq!(options.fallback, Some(vec!["system-ui".into()]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_removes_unsupported_axes() -> Result<()> {
+        let data: FxIndexMap<RcStr, FontDataEntry> = parse_json_with_source_context(
+            r#"
+            {
+                "Inter": {
+                    "weights": ["400", "variable"],
+                    "styles": ["normal"],
+                    "axes": [
+                        { "tag": "slnt", "min": -10, "max": 0 },
+                        { "tag": "wght", "min": 100, "max": 900 }
+                    ]
+                }
+            }
+            "#,
+        )?;
+
+        let mut request: NextFontRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "Inter",
+                "path": "index.js",
+                "variableName": "inter",
+                "arguments": [{
+                    "axes": ["slnt", "opsz", "wdth"]
+                }]
+            }
+            "#,
+        )?;
+
+        assert_eq!(
+            remove_unsupported_axes(&mut request, &data),
+            Some(UnsupportedAxes {
+                font_family: "Inter".into(),
+                unsupported: vec!["opsz".into(), "wdth".into()],
+                supported: vec!["slnt".into(), "wght".into()],
+            })
+        );
+        assert_eq!(
+            options_from_request(&request, &data)?.selected_variable_axes,
+            Some(vec!["slnt".into()])
+        );
+
+        Ok(())
+    }
 }
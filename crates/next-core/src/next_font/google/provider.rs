@@ -0,0 +1,862 @@
+use std::sync::OnceLock;
+
+use anyhow::{bail, Context, Result};
+use indoc::formatdoc;
+use serde::Deserialize;
+use tokio::sync::Semaphore;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, ResolvedVc, Vc};
+use turbo_tasks_env::{CommandLineProcessEnv, ProcessEnv};
+use turbo_tasks_fetch::{fetch_with_config, FetchConfig, HttpResponseBody, ProxyConfig};
+use turbo_tasks_fs::{File, FileContent, FileSystemPath};
+use turbo_tasks_hash::{encode_hex, hash_xxh3_hash64};
+use turbopack_core::issue::{IssueExt, IssueSeverity, StyledString};
+
+use super::{
+    load_font_data,
+    options::NextFontGoogleOptions,
+    util::{get_font_axes, get_stylesheet_url, FontAxes, FontAxesWeights, FontStyle},
+    FontData, GOOGLE_FONTS_STYLESHEET_URL, USER_AGENT_FOR_GOOGLE_FONTS,
+};
+use crate::{
+    next_config::{FontProviderConfig, FontProviderKind, NextConfig},
+    next_font::issue::NextFontIssue,
+};
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionHttpResponseBody(Option<ResolvedVc<HttpResponseBody>>);
+
+/// A source of the fonts requested through `next/font/google`, e.g. Google
+/// Fonts itself, Bunny Fonts, Fontsource or a self-hosted mirror. Providers
+/// which don't implement the css2 API, like [FontsourceFontProvider], build the
+/// stylesheet from their own metadata in [FontProvider::fetch].
+#[turbo_tasks::value_trait]
+pub trait FontProvider {
+    /// A human readable name for the provider, used in messages.
+    fn name(self: Vc<Self>) -> Vc<RcStr>;
+
+    /// Metadata about the fonts served by the provider, used to validate
+    /// requests and to select the axes to request.
+    fn font_data(self: Vc<Self>) -> Vc<FontData>;
+
+    /// The url of the stylesheet with the `@font-face` definitions for the
+    /// requested font. It identifies the stylesheet in caches and mocked
+    /// responses, and is passed to [FontProvider::fetch].
+    fn stylesheet_url(self: Vc<Self>, options: Vc<NextFontGoogleOptions>) -> Vc<RcStr>;
+
+    /// Fetches a stylesheet or a font file referenced by it. Failures are
+    /// emitted as issues on `virtual_path` rather than propagated.
+    fn fetch(
+        self: Vc<Self>,
+        url: RcStr,
+        virtual_path: Vc<FileSystemPath>,
+    ) -> Vc<OptionHttpResponseBody>;
+}
+
+/// Returns the provider configured through `experimental.fontProvider`, or
+/// Google Fonts by default, behind the cache configured through
+/// `experimental.fontCache`.
+#[turbo_tasks::function]
+pub async fn get_font_provider(
+    project_path: ResolvedVc<FileSystemPath>,
+    next_config: Vc<NextConfig>,
+) -> Result<Vc<Box<dyn FontProvider>>> {
+    let fetch_config = get_fetch_config(*project_path, next_config)
+        .to_resolved()
+        .await?;
+    let downloads = next_config.experimental_font_downloads().await?;
+    let downloads = downloads.as_ref();
+    let concurrency = downloads
+        .and_then(|downloads| downloads.concurrency)
+        .unwrap_or(DEFAULT_DOWNLOAD_CONCURRENCY);
+    let download_cache = project_path
+        .join(
+            match downloads.and_then(|downloads| downloads.cache_directory.clone()) {
+                Some(directory) => directory,
+                None => format!(
+                    "{}/cache/fonts",
+                    next_config.await?.dist_dir.as_deref().unwrap_or(".next")
+                )
+                .into(),
+            },
+        )
+        .to_resolved()
+        .await?;
+    let fetcher = |user_agent| {
+        FontFetcher {
+            fetch_config,
+            concurrency,
+            download_cache,
+            user_agent,
+        }
+        .resolved_cell()
+    };
+    let provider: ResolvedVc<Box<dyn FontProvider>> =
+        match &*next_config.experimental_font_provider().await? {
+            Some(FontProviderConfig {
+                kind: FontProviderKind::Css2,
+                stylesheet_url,
+                user_agent,
+            }) => {
+                let stylesheet_url = stylesheet_url.clone().context(
+                    "experimental.fontProvider.stylesheetUrl is required for css2 providers",
+                )?;
+                ResolvedVc::upcast(
+                    Css2FontProvider {
+                        project_path,
+                        fetcher: fetcher(Some(
+                            user_agent
+                                .clone()
+                                .unwrap_or_else(|| USER_AGENT_FOR_GOOGLE_FONTS.into()),
+                        )),
+                        name: stylesheet_url.clone(),
+                        stylesheet_url,
+                    }
+                    .resolved_cell(),
+                )
+            }
+            Some(FontProviderConfig {
+                kind: FontProviderKind::Fontsource,
+                user_agent,
+                ..
+            }) => ResolvedVc::upcast(
+                FontsourceFontProvider {
+                    project_path,
+                    fetcher: fetcher(user_agent.clone()),
+                }
+                .resolved_cell(),
+            ),
+            None => ResolvedVc::upcast(
+                Css2FontProvider {
+                    project_path,
+                    fetcher: fetcher(Some(USER_AGENT_FOR_GOOGLE_FONTS.into())),
+                    name: "Google Fonts".into(),
+                    stylesheet_url: google_fonts_stylesheet_url().await?,
+                }
+                .resolved_cell(),
+            ),
+        };
+
+    Ok(match &*next_config.experimental_font_cache().await? {
+        Some(cache) => Vc::upcast(
+            CachedFontProvider {
+                inner: provider,
+                directory: project_path
+                    .join(cache.directory.clone())
+                    .to_resolved()
+                    .await?,
+                offline: cache.offline.unwrap_or(false),
+            }
+            .cell(),
+        ),
+        None => *provider,
+    })
+}
+
+/// Reads the proxies to fetch fonts through from the environment, and the CA
+/// certificates to trust from `experimental.fontCaCertificates`.
+#[turbo_tasks::function]
+pub(crate) async fn get_fetch_config(
+    project_path: Vc<FileSystemPath>,
+    next_config: Vc<NextConfig>,
+) -> Result<Vc<FetchConfig>> {
+    let env = CommandLineProcessEnv::new();
+    let read_env = |name: &'static str| async move {
+        // Both spellings are common, with the uppercase one taking precedence
+        Ok::<_, anyhow::Error>(match &*env.read(name.into()).await? {
+            Some(value) => Some(value.clone()),
+            None => env.read(name.to_lowercase().into()).await?.clone_value(),
+        })
+    };
+
+    let mut proxies = vec![];
+    if let Some(proxy) = read_env("HTTPS_PROXY").await? {
+        proxies.push(ProxyConfig::Https(proxy.to_string()));
+    }
+    if let Some(proxy) = read_env("HTTP_PROXY").await? {
+        proxies.push(ProxyConfig::Http(proxy.to_string()));
+    }
+
+    let ca_certificates = match &*next_config.experimental_font_ca_certificates().await? {
+        Some(path) => match &*project_path.join(path.clone()).read().await? {
+            FileContent::Content(file) => Some(
+                file.content()
+                    .to_str()
+                    .with_context(|| format!("CA certificates at {} must be PEM encoded", path))?
+                    .into(),
+            ),
+            FileContent::NotFound => {
+                bail!(
+                    "CA certificates configured in fontCaCertificates not found at {}",
+                    path
+                )
+            }
+        },
+        None => None,
+    };
+
+    Ok(FetchConfig {
+        proxies,
+        no_proxy: read_env("NO_PROXY").await?,
+        ca_certificates,
+        retries: next_config
+            .experimental_font_downloads()
+            .await?
+            .as_ref()
+            .and_then(|downloads| downloads.retries)
+            .unwrap_or(DEFAULT_DOWNLOAD_RETRIES),
+    }
+    .cell())
+}
+
+async fn google_fonts_stylesheet_url() -> Result<RcStr> {
+    #[cfg(debug_assertions)]
+    {
+        // This is used in test environments
+        let env = CommandLineProcessEnv::new();
+        if let Some(url) = &*env.read("TURBOPACK_TEST_ONLY_MOCK_SERVER".into()).await? {
+            return Ok(format!("{}/css2", url).into());
+        }
+    }
+
+    Ok(GOOGLE_FONTS_STYLESHEET_URL.into())
+}
+
+/// Fetches the stylesheets and font files of a provider. Font files are
+/// downloaded to a cache directory, and failures are emitted as issues.
+#[turbo_tasks::value(shared)]
+struct FontFetcher {
+    fetch_config: ResolvedVc<FetchConfig>,
+    /// The maximum number of concurrent downloads, see [download_permits].
+    concurrency: usize,
+    /// Font files are immutable, so they're served from this directory once
+    /// downloaded.
+    download_cache: ResolvedVc<FileSystemPath>,
+    user_agent: Option<RcStr>,
+}
+
+#[turbo_tasks::value_impl]
+impl FontFetcher {
+    #[turbo_tasks::function]
+    async fn fetch(
+        &self,
+        url: RcStr,
+        virtual_path: Vc<FileSystemPath>,
+    ) -> Result<Vc<OptionHttpResponseBody>> {
+        let cache_path =
+            is_font_file_url(&url).then(|| self.download_cache.join(cache_file_name(&url).into()));
+        if let Some(cache_path) = cache_path {
+            if let FileContent::Content(file) = &*cache_path.read().await? {
+                return Ok(Vc::cell(Some(
+                    HttpResponseBody(file.content().to_bytes()?.into_owned()).resolved_cell(),
+                )));
+            }
+        }
+
+        let result = {
+            let _permit = download_permits(self.concurrency).acquire().await?;
+            fetch_with_config(
+                Vc::cell(url),
+                Vc::cell(self.user_agent.clone()),
+                *self.fetch_config,
+            )
+            .await?
+        };
+
+        Ok(Vc::cell(match &*result {
+            Ok(r) => {
+                let body = r.await?.body;
+                if let Some(cache_path) = cache_path {
+                    cache_path
+                        .write(FileContent::Content(File::from(body.await?.0.clone())).cell())
+                        .await?;
+                }
+                Some(body)
+            }
+            Err(err) => {
+                // Inform the user of the failure to retreive the stylesheet / font, but don't
+                // propagate this error. We don't want e.g. offline connections to prevent page
+                // renders during development. During production builds, however, this error
+                // should propagate.
+                //
+                // TODO(WEB-283): Use fallback in dev in this case
+                // TODO(WEB-293): Fail production builds (not dev) in this case
+                err.to_issue(IssueSeverity::Warning.into(), virtual_path)
+                    .to_resolved()
+                    .await?
+                    .emit();
+
+                None
+            }
+        }))
+    }
+}
+
+/// A provider implementing the Google Fonts css2 API, serving the fonts listed
+/// in Google's font data.
+#[turbo_tasks::value(shared)]
+struct Css2FontProvider {
+    project_path: ResolvedVc<FileSystemPath>,
+    fetcher: ResolvedVc<FontFetcher>,
+    name: RcStr,
+    stylesheet_url: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl FontProvider for Css2FontProvider {
+    #[turbo_tasks::function]
+    fn name(&self) -> Vc<RcStr> {
+        Vc::cell(self.name.clone())
+    }
+
+    #[turbo_tasks::function]
+    fn font_data(&self) -> Vc<FontData> {
+        load_font_data(*self.project_path)
+    }
+
+    #[turbo_tasks::function]
+    async fn stylesheet_url(
+        self: Vc<Self>,
+        options: Vc<NextFontGoogleOptions>,
+    ) -> Result<Vc<RcStr>> {
+        let this = self.await?;
+        let options = options.await?;
+        Ok(Vc::cell(
+            get_stylesheet_url(
+                &this.stylesheet_url,
+                &options.font_family,
+                &get_font_axes(
+                    &*self.font_data().await?,
+                    &options.font_family,
+                    &options.weights,
+                    &options.styles,
+                    &options.selected_variable_axes,
+                )?,
+                &options.display,
+            )?
+            .into(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    fn fetch(&self, url: RcStr, virtual_path: Vc<FileSystemPath>) -> Vc<OptionHttpResponseBody> {
+        self.fetcher.fetch(url, virtual_path)
+    }
+}
+
+const FONTSOURCE_API_URL: &str = "https://api.fontsource.org/v1/fonts";
+const FONTSOURCE_CDN_URL: &str = "https://cdn.jsdelivr.net/fontsource/fonts";
+
+/// A provider serving the fonts of Fontsource, which doesn't implement the
+/// css2 API. The stylesheet url points to the metadata of the font in
+/// Fontsource's API, with the requested weights, styles and display as query,
+/// and the stylesheet is built from the metadata. Requests are validated
+/// against Google's font data, so only fonts which are also on Google Fonts
+/// can be requested.
+#[turbo_tasks::value(shared)]
+struct FontsourceFontProvider {
+    project_path: ResolvedVc<FileSystemPath>,
+    fetcher: ResolvedVc<FontFetcher>,
+}
+
+#[turbo_tasks::value_impl]
+impl FontProvider for FontsourceFontProvider {
+    #[turbo_tasks::function]
+    fn name(&self) -> Vc<RcStr> {
+        Vc::cell("Fontsource".into())
+    }
+
+    #[turbo_tasks::function]
+    fn font_data(&self) -> Vc<FontData> {
+        load_font_data(*self.project_path)
+    }
+
+    #[turbo_tasks::function]
+    async fn stylesheet_url(
+        self: Vc<Self>,
+        options: Vc<NextFontGoogleOptions>,
+    ) -> Result<Vc<RcStr>> {
+        let options = options.await?;
+        Ok(Vc::cell(
+            fontsource_stylesheet_url(
+                &options.font_family,
+                &get_font_axes(
+                    &*self.font_data().await?,
+                    &options.font_family,
+                    &options.weights,
+                    &options.styles,
+                    &options.selected_variable_axes,
+                )?,
+                &options.display,
+            )?
+            .into(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn fetch(
+        &self,
+        url: RcStr,
+        virtual_path: Vc<FileSystemPath>,
+    ) -> Result<Vc<OptionHttpResponseBody>> {
+        let Some((metadata_url, query)) = url
+            .strip_prefix(FONTSOURCE_API_URL)
+            .and_then(|_| url.split_once('?'))
+        else {
+            return Ok(self.fetcher.fetch(url, virtual_path));
+        };
+
+        let Some(metadata) = *self
+            .fetcher
+            .fetch(metadata_url.into(), virtual_path)
+            .await?
+        else {
+            return Ok(Vc::cell(None));
+        };
+        let metadata: FontsourceMetadata = serde_json::from_str(&metadata.to_string().await?)
+            .with_context(|| format!("Invalid font metadata from {}", metadata_url))?;
+        let stylesheet = fontsource_stylesheet(&metadata, query)?;
+
+        Ok(Vc::cell(Some(
+            HttpResponseBody(stylesheet.into_bytes()).resolved_cell(),
+        )))
+    }
+}
+
+/// The metadata of a font in Fontsource's API, e.g.
+/// `https://api.fontsource.org/v1/fonts/inter`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FontsourceMetadata {
+    id: RcStr,
+    family: RcStr,
+    subsets: Vec<RcStr>,
+    unicode_range: FxIndexMap<RcStr, RcStr>,
+    variable: bool,
+    /// The files of the font by weight, style and subset.
+    variants: FxIndexMap<RcStr, FxIndexMap<RcStr, FxIndexMap<RcStr, FontsourceVariant>>>,
+}
+
+#[derive(Deserialize)]
+struct FontsourceVariant {
+    url: FontsourceVariantUrls,
+}
+
+#[derive(Deserialize)]
+struct FontsourceVariantUrls {
+    woff2: RcStr,
+}
+
+/// Fontsource ids are the lowercase font family, with dashes instead of
+/// spaces, e.g. `open-sans`.
+fn fontsource_id(font_family: &str) -> String {
+    font_family.to_lowercase().replace(' ', "-")
+}
+
+fn fontsource_stylesheet_url(font_family: &str, axes: &FontAxes, display: &str) -> Result<String> {
+    if axes
+        .variable_axes
+        .as_ref()
+        .is_some_and(|axes| !axes.is_empty())
+    {
+        bail!("Fontsource fonts only support the `wght` axis");
+    }
+    let weights = match &axes.wght {
+        FontAxesWeights::Fixed(weights) => weights
+            .iter()
+            .map(|weight| weight.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+        FontAxesWeights::Variable(Some(range)) => range.to_string(),
+        FontAxesWeights::Variable(None) => bail!("Font {} has no `wght` axis", font_family),
+    };
+    let styles = if axes.ital.is_empty() {
+        "normal".to_string()
+    } else {
+        axes.ital
+            .iter()
+            .map(|style| match style {
+                FontStyle::Normal => "normal",
+                FontStyle::Italic => "italic",
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    Ok(format!(
+        "{}/{}?weights={}&styles={}&display={}",
+        FONTSOURCE_API_URL,
+        fontsource_id(font_family),
+        weights,
+        styles,
+        display
+    ))
+}
+
+/// Builds the stylesheet of a font from its Fontsource `metadata` and the
+/// query of its [fontsource_stylesheet_url], in the format of the css2 API:
+/// one `@font-face` per subset, preceded by a comment naming the subset.
+fn fontsource_stylesheet(metadata: &FontsourceMetadata, query: &str) -> Result<String> {
+    let query = qstring::QString::from(query);
+    let (Some(weights), Some(styles), Some(display)) = (
+        query.get("weights"),
+        query.get("styles"),
+        query.get("display"),
+    ) else {
+        bail!("Invalid Fontsource stylesheet query {}", query);
+    };
+
+    let mut stylesheet = String::new();
+    for style in styles.split(',') {
+        for subset in &metadata.subsets {
+            let Some(unicode_range) = metadata.unicode_range.get(subset) else {
+                continue;
+            };
+            let mut font_face = |weight: &str, url: &str| {
+                stylesheet.push_str(&formatdoc!(
+                    r#"
+                        /* {subset} */
+                        @font-face {{
+                          font-family: '{family}';
+                          font-style: {style};
+                          font-weight: {weight};
+                          font-display: {display};
+                          src: url({url}) format('woff2');
+                          unicode-range: {unicode_range};
+                        }}
+                    "#,
+                    subset = subset,
+                    family = metadata.family,
+                    style = style,
+                    weight = weight,
+                    display = display,
+                    url = url,
+                    unicode_range = unicode_range,
+                ));
+            };
+            match weights.split_once("..") {
+                Some((min, max)) => {
+                    if !metadata.variable {
+                        bail!("{} is not a variable font on Fontsource", metadata.family);
+                    }
+                    font_face(
+                        &format!("{} {}", min, max),
+                        &format!(
+                            "{}/{}:vf@latest/{}-wght-{}.woff2",
+                            FONTSOURCE_CDN_URL, metadata.id, subset, style
+                        ),
+                    );
+                }
+                None => {
+                    for weight in weights.split(',') {
+                        if let Some(variant) = metadata
+                            .variants
+                            .get(weight)
+                            .and_then(|styles| styles.get(style))
+                            .and_then(|subsets| subsets.get(subset))
+                        {
+                            font_face(weight, &variant.url.woff2);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if stylesheet.is_empty() {
+        bail!(
+            "Fontsource has no files for the requested weights and styles of {}",
+            metadata.family
+        );
+    }
+    Ok(stylesheet)
+}
+
+/// Serves the stylesheets and font files of another provider from a
+/// project-local directory. Unless offline, everything fetched from the wrapped
+/// provider is written to the directory, which can then be committed for
+/// builds without network access.
+#[turbo_tasks::value(shared)]
+struct CachedFontProvider {
+    inner: ResolvedVc<Box<dyn FontProvider>>,
+    directory: ResolvedVc<FileSystemPath>,
+    offline: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl FontProvider for CachedFontProvider {
+    #[turbo_tasks::function]
+    async fn name(&self) -> Result<Vc<RcStr>> {
+        Ok(if self.offline {
+            Vc::cell(format!("the font cache at {}", self.directory.to_string().await?).into())
+        } else {
+            self.inner.name()
+        })
+    }
+
+    #[turbo_tasks::function]
+    fn font_data(&self) -> Vc<FontData> {
+        self.inner.font_data()
+    }
+
+    #[turbo_tasks::function]
+    fn stylesheet_url(&self, options: Vc<NextFontGoogleOptions>) -> Vc<RcStr> {
+        self.inner.stylesheet_url(options)
+    }
+
+    #[turbo_tasks::function]
+    async fn fetch(
+        &self,
+        url: RcStr,
+        virtual_path: Vc<FileSystemPath>,
+    ) -> Result<Vc<OptionHttpResponseBody>> {
+        let cache_path = self.directory.join(cache_file_name(&url).into());
+
+        if !self.offline {
+            if let Some(body) = *self.inner.fetch(url.clone(), virtual_path).await? {
+                cache_path
+                    .write(FileContent::Content(File::from(body.await?.0.clone())).cell())
+                    .await?;
+                return Ok(Vc::cell(Some(body)));
+            }
+            // Fall back to an earlier download when the network is unavailable
+        }
+
+        Ok(Vc::cell(match &*cache_path.read().await? {
+            FileContent::Content(file) => {
+                Some(HttpResponseBody(file.content().to_bytes()?.into_owned()).resolved_cell())
+            }
+            FileContent::NotFound => {
+                if self.offline {
+                    NextFontIssue {
+                        path: virtual_path.to_resolved().await?,
+                        title: StyledString::Line(vec![
+                            StyledString::Code("next/font/google".into()),
+                            StyledString::Text(" font missing from the offline font cache".into()),
+                        ])
+                        .resolved_cell(),
+                        description: StyledString::Text(
+                            format!(
+                                "{} was not found at {}. Run a build with network access and \
+                                 `experimental.fontCache.offline` disabled to populate the cache.",
+                                url,
+                                cache_path.to_string().await?
+                            )
+                            .into(),
+                        )
+                        .resolved_cell(),
+                        severity: IssueSeverity::Error.resolved_cell(),
+                    }
+                    .resolved_cell()
+                    .emit();
+                }
+                None
+            }
+        }))
+    }
+}
+
+const DEFAULT_DOWNLOAD_CONCURRENCY: usize = 8;
+const DEFAULT_DOWNLOAD_RETRIES: u32 = 3;
+
+static DOWNLOAD_PERMITS: OnceLock<Semaphore> = OnceLock::new();
+
+/// Limits the downloads of all `next/font/google` requests in the process, so
+/// apps requesting many fonts don't get rate limited. The concurrency of the
+/// first download applies to the whole process.
+fn download_permits(concurrency: usize) -> &'static Semaphore {
+    DOWNLOAD_PERMITS.get_or_init(|| Semaphore::new(concurrency.max(1)))
+}
+
+/// Whether `url` points to a font file rather than a stylesheet, which can
+/// change over time.
+fn is_font_file_url(url: &str) -> bool {
+    url.rsplit_once('.')
+        .is_some_and(|(_, ext)| matches!(ext, "woff" | "woff2" | "ttf" | "otf" | "eot"))
+}
+
+/// Names cache entries after the hash of their url, keeping the extension of
+/// font files. Stylesheet urls don't have one, e.g.
+/// `https://fonts.googleapis.com/css2?family=Inter&display=swap`.
+fn cache_file_name(url: &str) -> String {
+    let hash = encode_hex(hash_xxh3_hash64(url));
+    match url.rsplit_once('.') {
+        Some((_, ext)) if ext.len() <= 5 && ext.chars().all(|c| c.is_ascii_alphanumeric()) => {
+            format!("{hash}.{ext}")
+        }
+        _ => format!("{hash}.css"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use indoc::indoc;
+    use turbo_tasks::fxindexset;
+
+    use super::{
+        cache_file_name, fontsource_id, fontsource_stylesheet, fontsource_stylesheet_url,
+        is_font_file_url, FontsourceMetadata,
+    };
+    use crate::next_font::google::util::{FontAxes, FontAxesWeights, FontStyle};
+
+    fn inter_metadata(variable: bool) -> FontsourceMetadata {
+        serde_json::from_value(serde_json::json!({
+            "id": "inter",
+            "family": "Inter",
+            "subsets": ["cyrillic", "latin"],
+            "unicodeRange": {
+                "cyrillic": "U+0301,U+0400-045F",
+                "latin": "U+0000-00FF,U+0131",
+            },
+            "variable": variable,
+            "variants": {
+                "400": {
+                    "normal": {
+                        "cyrillic": { "url": {
+                            "woff2": "https://cdn.jsdelivr.net/fontsource/fonts/inter@latest/cyrillic-400-normal.woff2",
+                            "woff": "https://cdn.jsdelivr.net/fontsource/fonts/inter@latest/cyrillic-400-normal.woff",
+                        } },
+                        "latin": { "url": {
+                            "woff2": "https://cdn.jsdelivr.net/fontsource/fonts/inter@latest/latin-400-normal.woff2",
+                            "woff": "https://cdn.jsdelivr.net/fontsource/fonts/inter@latest/latin-400-normal.woff",
+                        } },
+                    },
+                },
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fontsource_id() {
+        assert_eq!(fontsource_id("Inter"), "inter");
+        assert_eq!(fontsource_id("Open Sans"), "open-sans");
+    }
+
+    #[test]
+    fn test_fontsource_stylesheet_url() {
+        assert_eq!(
+            fontsource_stylesheet_url(
+                "Open Sans",
+                &FontAxes {
+                    wght: FontAxesWeights::Fixed(BTreeSet::from([400, 700])),
+                    ital: fxindexset![FontStyle::Normal, FontStyle::Italic],
+                    variable_axes: None,
+                },
+                "swap"
+            )
+            .unwrap(),
+            "https://api.fontsource.org/v1/fonts/open-sans?weights=400,700&styles=normal,italic&display=swap"
+        );
+        assert_eq!(
+            fontsource_stylesheet_url(
+                "Inter",
+                &FontAxes {
+                    wght: FontAxesWeights::Variable(Some("100..900".into())),
+                    ital: Default::default(),
+                    variable_axes: Some(vec![]),
+                },
+                "optional"
+            )
+            .unwrap(),
+            "https://api.fontsource.org/v1/fonts/inter?weights=100..900&styles=normal&display=optional"
+        );
+        assert!(fontsource_stylesheet_url(
+            "Inter",
+            &FontAxes {
+                wght: FontAxesWeights::Variable(Some("100..900".into())),
+                ital: Default::default(),
+                variable_axes: Some(vec![("slnt".into(), "-10..0".into())]),
+            },
+            "swap"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_fontsource_stylesheet() {
+        assert_eq!(
+            fontsource_stylesheet(
+                &inter_metadata(false),
+                "weights=400&styles=normal&display=swap"
+            )
+            .unwrap(),
+            indoc! {r#"
+                /* cyrillic */
+                @font-face {
+                  font-family: 'Inter';
+                  font-style: normal;
+                  font-weight: 400;
+                  font-display: swap;
+                  src: url(https://cdn.jsdelivr.net/fontsource/fonts/inter@latest/cyrillic-400-normal.woff2) format('woff2');
+                  unicode-range: U+0301,U+0400-045F;
+                }
+                /* latin */
+                @font-face {
+                  font-family: 'Inter';
+                  font-style: normal;
+                  font-weight: 400;
+                  font-display: swap;
+                  src: url(https://cdn.jsdelivr.net/fontsource/fonts/inter@latest/latin-400-normal.woff2) format('woff2');
+                  unicode-range: U+0000-00FF,U+0131;
+                }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_fontsource_variable_stylesheet() {
+        let stylesheet = fontsource_stylesheet(
+            &inter_metadata(true),
+            "weights=100..900&styles=italic&display=swap",
+        )
+        .unwrap();
+        assert!(stylesheet.contains("font-weight: 100 900;"));
+        assert!(stylesheet.contains(
+            "src: url(https://cdn.jsdelivr.net/fontsource/fonts/inter:vf@latest/latin-wght-italic.woff2)"
+        ));
+
+        assert!(fontsource_stylesheet(
+            &inter_metadata(false),
+            "weights=100..900&styles=normal&display=swap"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_fontsource_stylesheet_without_files() {
+        assert!(fontsource_stylesheet(
+            &inter_metadata(false),
+            "weights=700&styles=normal&display=swap"
+        )
+        .is_err());
+        assert!(fontsource_stylesheet(&inter_metadata(false), "weights=400").is_err());
+    }
+
+    #[test]
+    fn test_cache_file_name() {
+        assert!(cache_file_name(
+            "https://fonts.gstatic.com/s/inter/v13/UcC73FwrK3iLTeHuS_fvQtMwCp50KnMa1ZL7.woff2"
+        )
+        .ends_with(".woff2"));
+        assert!(
+            cache_file_name("https://fonts.googleapis.com/css2?family=Inter&display=swap")
+                .ends_with(".css")
+        );
+        assert_ne!(
+            cache_file_name("https://fonts.googleapis.com/css2?family=Inter&display=swap"),
+            cache_file_name("https://fonts.googleapis.com/css2?family=Roboto&display=swap")
+        );
+    }
+
+    #[test]
+    fn test_is_font_file_url() {
+        assert!(is_font_file_url(
+            "https://fonts.gstatic.com/s/inter/v13/UcC73FwrK3iLTeHuS_fvQtMwCp50KnMa1ZL7.woff2"
+        ));
+        assert!(!is_font_file_url(
+            "https://fonts.googleapis.com/css2?family=Inter&display=swap"
+        ));
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
 use turbo_rcstr::RcStr;
 
+use crate::next_font::font_fallback::FontMetricsOverride;
+
 /// The top-most structure encoded into the query param in requests to
 /// `next/font/google` generated by the next/font swc transform. e.g.
 /// `next/font/google/target.css?{"path": "index.js", "import": "Inter"...`
@@ -21,9 +25,21 @@ pub(super) struct NextFontRequestArguments {
     pub axes: Option<Vec<RcStr>>,
     pub fallback: Option<Vec<RcStr>>,
     pub adjust_font_fallback: Option<bool>,
+    #[serde(rename = "fallbackMetrics")]
+    pub fallback_metrics: Option<BTreeMap<RcStr, FallbackMetricsArgument>>,
     pub variable: Option<RcStr>,
 }
 
+/// An entry of `fallbackMetrics`: either overrides for the fallback font's
+/// metrics, or `false` to leave that fallback font unadjusted, like
+/// `adjustFontFallback: false` does for all of them.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub(super) enum FallbackMetricsArgument {
+    Adjust(bool),
+    Override(FontMetricsOverride),
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(untagged)]
 pub(super) enum OneOrManyStrings {
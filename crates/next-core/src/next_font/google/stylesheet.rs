@@ -1,10 +1,10 @@
 use anyhow::Result;
 use turbo_rcstr::RcStr;
-use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks::Vc;
 
 use super::FontCssProperties;
 use crate::next_font::{
-    font_fallback::FontFallback,
+    font_fallback::FontFallbacks,
     stylesheet::{build_fallback_definition, build_font_class_rules},
 };
 
@@ -12,14 +12,14 @@ use crate::next_font::{
 pub(super) async fn build_stylesheet(
     base_stylesheet: Vc<Option<RcStr>>,
     font_css_properties: Vc<FontCssProperties>,
-    font_fallback: ResolvedVc<FontFallback>,
+    font_fallbacks: Vc<FontFallbacks>,
 ) -> Result<Vc<RcStr>> {
     let base_stylesheet = &*base_stylesheet.await?;
     let mut stylesheet = base_stylesheet
         .as_ref()
         .map_or_else(|| "".to_owned(), |s| s.to_string());
 
-    stylesheet.push_str(&build_fallback_definition(Vc::cell(vec![font_fallback])).await?);
+    stylesheet.push_str(&build_fallback_definition(font_fallbacks).await?);
     stylesheet.push_str(&build_font_class_rules(font_css_properties).await?);
     Ok(Vc::cell(stylesheet.into()))
 }
@@ -0,0 +1,479 @@
+use std::{collections::HashMap, io::Write, ops::RangeInclusive};
+
+use allsorts::{
+    binary::read::ReadScope, font::MatchingPresentation, font_data::FontData,
+    tables::FontTableProvider, tag, Font,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use flate2::{write::ZlibEncoder, Compression};
+use turbo_rcstr::RcStr;
+
+/// The `unicode-range`s Google Fonts serves the most common subsets with. Used
+/// to subset fonts from providers serving a single file for all subsets.
+const SUBSET_UNICODE_RANGES: &[(&str, &str)] = &[
+    (
+        "latin",
+        "U+0000-00FF, U+0131, U+0152-0153, U+02BB-02BC, U+02C6, U+02DA, U+02DC, U+0304, U+0308, \
+         U+0329, U+2000-206F, U+20AC, U+2122, U+2191, U+2193, U+2212, U+2215, U+FEFF, U+FFFD",
+    ),
+    (
+        "latin-ext",
+        "U+0100-02BA, U+02BD-02C5, U+02C7-02CC, U+02CE-02D7, U+02DD-02FF, U+0304, U+0308, U+0329, \
+         U+1D00-1DBF, U+1E00-1E9F, U+1EF2-1EFF, U+2020, U+20A0-20AB, U+20AD-20C0, U+2113, \
+         U+2C60-2C7F, U+A720-A7FF",
+    ),
+    (
+        "cyrillic",
+        "U+0301, U+0400-045F, U+0490-0491, U+04B0-04B1, U+2116",
+    ),
+    (
+        "cyrillic-ext",
+        "U+0460-052F, U+1C80-1C8A, U+20B4, U+2DE0-2DFF, U+A640-A69F, U+FE2E-FE2F",
+    ),
+    (
+        "greek",
+        "U+0370-0377, U+037A-037F, U+0384-038A, U+038C, U+038E-03A1, U+03A3-03FF",
+    ),
+    ("greek-ext", "U+1F00-1FFF"),
+    (
+        "vietnamese",
+        "U+0102-0103, U+0110-0111, U+0128-0129, U+0168-0169, U+01A0-01A1, U+01AF-01B0, \
+         U+0300-0301, U+0303-0304, U+0308-0309, U+0323, U+0329, U+1EA0-1EF9, U+20AB",
+    ),
+];
+
+/// The `unicode-range` covering all of `subsets`, or `None` if the range of
+/// any of them is unknown.
+pub(super) fn unicode_range_for_subsets(subsets: &[RcStr]) -> Option<String> {
+    subsets
+        .iter()
+        .map(|subset| {
+            SUBSET_UNICODE_RANGES
+                .iter()
+                .find(|(name, _)| subset == *name)
+                .map(|(_, range)| *range)
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(|ranges| ranges.join(", "))
+}
+
+/// A stylesheet limited to the declared subsets.
+#[derive(Debug, PartialEq)]
+pub(super) struct SubsettedStylesheet {
+    pub css: String,
+    /// Font files covering more than the declared subsets, mapped to the
+    /// `unicode-range` they need to be subset to.
+    pub font_files_to_subset: HashMap<String, String>,
+}
+
+enum StylesheetChunk<'a> {
+    Line(&'a str),
+    FontFace {
+        comment: Option<&'a str>,
+        subset: Option<&'a str>,
+        lines: Vec<&'a str>,
+    },
+}
+
+/// Drops the `@font-face` rules of subsets that weren't declared. Rules
+/// without a subset or `unicode-range`, as served by providers not splitting
+/// fonts by subset, get the `unicode-range` of the declared subsets and their
+/// font files are recorded to be subset to it.
+pub(super) fn subset_stylesheet(css: &str, subsets: &[RcStr]) -> SubsettedStylesheet {
+    let mut chunks = vec![];
+    let mut comment = None;
+    let mut font_face: Option<Vec<&str>> = None;
+    for line in css.lines() {
+        if let Some(lines) = &mut font_face {
+            lines.push(line);
+            if line.trim() == "}" {
+                let (comment, subset) = comment.take().unzip();
+                chunks.push(StylesheetChunk::FontFace {
+                    comment,
+                    subset,
+                    lines: font_face.take().unwrap(),
+                });
+            }
+        } else if let Some((_, subset)) = lazy_regex::regex_captures!(r#"/\* (.+?) \*/"#, line) {
+            chunks.extend(
+                comment
+                    .replace((line, subset))
+                    .map(|(line, _)| StylesheetChunk::Line(line)),
+            );
+        } else if line.trim_start().starts_with("@font-face") {
+            font_face = Some(vec![line]);
+        } else {
+            chunks.extend(comment.take().map(|(line, _)| StylesheetChunk::Line(line)));
+            chunks.push(StylesheetChunk::Line(line));
+        }
+    }
+
+    let is_declared = |subset: &str| subsets.iter().any(|declared| declared == subset);
+    // Keep everything when the subsets are named differently than declared,
+    // rather than dropping all rules.
+    let drop_undeclared = chunks.iter().any(|chunk| {
+        matches!(chunk, StylesheetChunk::FontFace { subset: Some(subset), .. } if is_declared(subset))
+    });
+    let unicode_range = unicode_range_for_subsets(subsets);
+
+    let mut result = SubsettedStylesheet {
+        css: String::with_capacity(css.len()),
+        font_files_to_subset: HashMap::new(),
+    };
+    for chunk in chunks {
+        let (comment, subset, lines) = match chunk {
+            StylesheetChunk::Line(line) => {
+                result.css.push_str(line);
+                result.css.push('\n');
+                continue;
+            }
+            StylesheetChunk::FontFace {
+                comment,
+                subset,
+                lines,
+            } => (comment, subset, lines),
+        };
+
+        if let Some(subset) = subset {
+            if drop_undeclared && !is_declared(subset) {
+                continue;
+            }
+        }
+        if let Some(comment) = comment {
+            result.css.push_str(comment);
+            result.css.push('\n');
+        }
+
+        let unicode_range = unicode_range.as_ref().filter(|_| {
+            subset.is_none() && !lines.iter().any(|line| line.contains("unicode-range"))
+        });
+        let Some(unicode_range) = unicode_range else {
+            for line in lines {
+                result.css.push_str(line);
+                result.css.push('\n');
+            }
+            continue;
+        };
+
+        for line in lines {
+            if line.trim() == "}" {
+                result
+                    .css
+                    .push_str(&format!("  unicode-range: {};\n", unicode_range));
+            }
+            if let Some((_, font_url)) = lazy_regex::regex_captures!(r#"src: url\((.+?)\)"#, line) {
+                result
+                    .font_files_to_subset
+                    .insert(font_url.to_string(), unicode_range.clone());
+                // The subset is served as WOFF, so the format of the original
+                // file no longer applies.
+                result
+                    .css
+                    .push_str(&lazy_regex::regex!(r#" format\([^)]*\)"#).replace(line, ""));
+            } else {
+                result.css.push_str(line);
+            }
+            result.css.push('\n');
+        }
+    }
+
+    result
+}
+
+/// Parses a css `unicode-range`, e.g. `U+0000-00FF, U+0131, U+4??`.
+pub(super) fn parse_unicode_range(unicode_range: &str) -> Result<Vec<RangeInclusive<u32>>> {
+    unicode_range
+        .split(',')
+        .map(|range| {
+            let range = range.trim();
+            let range = range
+                .strip_prefix("U+")
+                .or_else(|| range.strip_prefix("u+"))
+                .with_context(|| format!("invalid unicode-range {}", range))?;
+            let parse = |value: &str| {
+                u32::from_str_radix(value, 16)
+                    .with_context(|| format!("invalid unicode-range {}", range))
+            };
+            Ok(match range.split_once('-') {
+                Some((start, end)) => parse(start)?..=parse(end)?,
+                None if range.contains('?') => {
+                    parse(&range.replace('?', "0"))?..=parse(&range.replace('?', "F"))?
+                }
+                None => parse(range)?..=parse(range)?,
+            })
+        })
+        .collect()
+}
+
+/// Strips the glyphs outside of `unicode_range` from a font file, returning
+/// the subset as WOFF. Variable fonts and fonts with layout tables are returned
+/// as `None`, as subsetting them would drop their variations, ligatures and
+/// kerning.
+pub(super) fn subset_font(font_file: &[u8], unicode_range: &str) -> Result<Option<Vec<u8>>> {
+    let ranges = parse_unicode_range(unicode_range)?;
+
+    let font_data = ReadScope::new(font_file).read::<FontData>()?;
+    let provider = font_data.table_provider(0)?;
+    if [tag::FVAR, tag::GSUB, tag::GPOS, tag::KERN]
+        .into_iter()
+        .any(|tag| provider.has_table(tag))
+    {
+        return Ok(None);
+    }
+    let mut font = Font::new(provider)?.context("Unable to read font file for subsetting")?;
+
+    // The subset must start with the `.notdef` glyph
+    let mut glyph_ids = vec![0];
+    for range in ranges {
+        for c in range.filter_map(char::from_u32) {
+            let (glyph_id, _) = font.lookup_glyph_index(c, MatchingPresentation::NotRequired, None);
+            if glyph_id != 0 {
+                glyph_ids.push(glyph_id);
+            }
+        }
+    }
+    glyph_ids.sort_unstable();
+    glyph_ids.dedup();
+
+    let sfnt = allsorts::subset::subset(&font.font_table_provider, &glyph_ids)
+        .map_err(|err| anyhow!("Failed to subset font: {:?}", err))?;
+    Ok(Some(sfnt_to_woff(&sfnt)?))
+}
+
+/// Wraps an OpenType/TrueType font into WOFF 1.0, compressing each table with
+/// zlib unless that doesn't make it smaller.
+/// See <https://www.w3.org/TR/WOFF/>.
+fn sfnt_to_woff(sfnt: &[u8]) -> Result<Vec<u8>> {
+    let read_u16 = |offset: usize| -> Result<u16> {
+        Ok(u16::from_be_bytes(
+            sfnt.get(offset..offset + 2)
+                .context("truncated font file")?
+                .try_into()?,
+        ))
+    };
+    let read_u32 = |offset: usize| -> Result<u32> {
+        Ok(u32::from_be_bytes(
+            sfnt.get(offset..offset + 4)
+                .context("truncated font file")?
+                .try_into()?,
+        ))
+    };
+
+    let flavor = read_u32(0)?;
+    let num_tables = read_u16(4)?;
+
+    struct Table {
+        tag: u32,
+        checksum: u32,
+        orig_length: u32,
+        data: Vec<u8>,
+    }
+
+    let mut tables = vec![];
+    for i in 0..num_tables as usize {
+        let record = 12 + i * 16;
+        let offset = read_u32(record + 8)? as usize;
+        let length = read_u32(record + 12)? as usize;
+        let Some(table) = sfnt.get(offset..offset + length) else {
+            bail!("truncated font file");
+        };
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(table)?;
+        let compressed = encoder.finish()?;
+
+        tables.push(Table {
+            tag: read_u32(record)?,
+            checksum: read_u32(record + 4)?,
+            orig_length: length as u32,
+            data: if compressed.len() < table.len() {
+                compressed
+            } else {
+                table.to_vec()
+            },
+        });
+    }
+
+    let align = |length: usize| (length + 3) & !3;
+    let header_length = 44 + 20 * tables.len();
+    let total_sfnt_size = 12
+        + 16 * tables.len()
+        + tables
+            .iter()
+            .map(|table| align(table.orig_length as usize))
+            .sum::<usize>();
+
+    let mut directory = Vec::with_capacity(20 * tables.len());
+    let mut data = vec![];
+    for table in &tables {
+        directory.extend(table.tag.to_be_bytes());
+        directory.extend(((header_length + data.len()) as u32).to_be_bytes());
+        directory.extend((table.data.len() as u32).to_be_bytes());
+        directory.extend(table.orig_length.to_be_bytes());
+        directory.extend(table.checksum.to_be_bytes());
+        data.extend(&table.data);
+        data.resize(align(data.len()), 0);
+    }
+
+    let mut woff = Vec::with_capacity(header_length + data.len());
+    woff.extend(b"wOFF");
+    woff.extend(flavor.to_be_bytes());
+    woff.extend(((header_length + data.len()) as u32).to_be_bytes());
+    woff.extend(num_tables.to_be_bytes());
+    woff.extend(0u16.to_be_bytes());
+    woff.extend((total_sfnt_size as u32).to_be_bytes());
+    // Version of the font, which isn't tracked
+    woff.extend(0u16.to_be_bytes());
+    woff.extend(0u16.to_be_bytes());
+    // No metadata or private data blocks
+    woff.extend([0; 20]);
+    woff.extend(directory);
+    woff.extend(data);
+    Ok(woff)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use indoc::indoc;
+
+    use super::{
+        parse_unicode_range, sfnt_to_woff, subset_font, subset_stylesheet,
+        unicode_range_for_subsets,
+    };
+
+    static NOTO_SANS: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../packages/next/src/compiled/@vercel/og/noto-sans-v27-latin-regular.ttf"
+    ));
+    static TYPEWRITER: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../test/e2e/og-routes-custom-font/assets/typewr__.ttf"
+    ));
+
+    #[test]
+    fn test_parse_unicode_range() -> Result<()> {
+        assert_eq!(
+            parse_unicode_range("U+0000-00FF, U+0131, U+4??")?,
+            vec![0x0..=0xff, 0x131..=0x131, 0x400..=0x4ff]
+        );
+        assert!(parse_unicode_range("0000-00FF").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unicode_range_for_subsets() {
+        assert_eq!(
+            unicode_range_for_subsets(&["greek-ext".into(), "cyrillic".into()]).as_deref(),
+            Some("U+1F00-1FFF, U+0301, U+0400-045F, U+0490-0491, U+04B0-04B1, U+2116")
+        );
+        assert_eq!(unicode_range_for_subsets(&["khmer".into()]), None);
+    }
+
+    #[test]
+    fn test_subset_font_keeps_layout_tables() -> Result<()> {
+        // Noto Sans has `GSUB` and `GPOS` tables, the typewriter font a `kern`
+        // table. Both are served complete rather than losing them.
+        assert_eq!(subset_font(NOTO_SANS, "U+0000-00FF")?, None);
+        assert_eq!(subset_font(TYPEWRITER, "U+0000-00FF")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sfnt_to_woff() -> Result<()> {
+        // An sfnt with a single 5 byte `abcd` table
+        let mut sfnt = vec![0, 1, 0, 0, 0, 1, 0, 16, 0, 0, 0, 0];
+        sfnt.extend(b"abcd");
+        sfnt.extend(42u32.to_be_bytes());
+        sfnt.extend(28u32.to_be_bytes());
+        sfnt.extend(5u32.to_be_bytes());
+        sfnt.extend(b"hello\0\0\0");
+
+        let woff = sfnt_to_woff(&sfnt)?;
+        assert_eq!(&woff[0..4], b"wOFF");
+        // flavor, length, numTables, reserved, totalSfntSize
+        assert_eq!(&woff[4..8], &[0, 1, 0, 0]);
+        assert_eq!(
+            u32::from_be_bytes(woff[8..12].try_into()?),
+            woff.len() as u32
+        );
+        assert_eq!(&woff[12..16], &[0, 1, 0, 0]);
+        assert_eq!(u32::from_be_bytes(woff[16..20].try_into()?), 12 + 16 + 8);
+        // The table is stored uncompressed as zlib doesn't make it smaller
+        assert_eq!(&woff[44..48], b"abcd");
+        assert_eq!(u32::from_be_bytes(woff[48..52].try_into()?), 64);
+        assert_eq!(u32::from_be_bytes(woff[52..56].try_into()?), 5);
+        assert_eq!(u32::from_be_bytes(woff[56..60].try_into()?), 5);
+        assert_eq!(u32::from_be_bytes(woff[60..64].try_into()?), 42);
+        assert_eq!(&woff[64..], b"hello\0\0\0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_subset_stylesheet_drops_undeclared_subsets() {
+        let css = indoc! {"
+            /* cyrillic */
+            @font-face {
+              font-family: 'Inter';
+              src: url(https://fonts.gstatic.com/cyrillic.woff2) format('woff2');
+              unicode-range: U+0301, U+0400-045F;
+            }
+            /* latin */
+            @font-face {
+              font-family: 'Inter';
+              src: url(https://fonts.gstatic.com/latin.woff2) format('woff2');
+              unicode-range: U+0000-00FF;
+            }
+        "};
+
+        let result = subset_stylesheet(css, &["latin".into()]);
+        assert_eq!(
+            result.css,
+            indoc! {"
+                /* latin */
+                @font-face {
+                  font-family: 'Inter';
+                  src: url(https://fonts.gstatic.com/latin.woff2) format('woff2');
+                  unicode-range: U+0000-00FF;
+                }
+            "}
+        );
+        assert!(result.font_files_to_subset.is_empty());
+
+        // Subsets named differently than declared are kept
+        assert_eq!(subset_stylesheet(css, &["khmer".into()]).css, css);
+    }
+
+    #[test]
+    fn test_subset_stylesheet_unsplit_font() {
+        let css = indoc! {"
+            @font-face {
+              font-family: 'Inter';
+              font-style: normal;
+              src: url(https://fonts.example.com/inter.woff2) format('woff2');
+            }
+        "};
+
+        let result = subset_stylesheet(css, &["greek-ext".into()]);
+        assert_eq!(
+            result.css,
+            indoc! {"
+                @font-face {
+                  font-family: 'Inter';
+                  font-style: normal;
+                  src: url(https://fonts.example.com/inter.woff2);
+                  unicode-range: U+1F00-1FFF;
+                }
+            "}
+        );
+        assert_eq!(
+            result
+                .font_files_to_subset
+                .get("https://fonts.example.com/inter.woff2"),
+            Some(&"U+1F00-1FFF".to_string())
+        );
+    }
+}
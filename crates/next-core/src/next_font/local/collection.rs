@@ -0,0 +1,194 @@
+use anyhow::{bail, Context, Result};
+use turbo_tasks::Vc;
+use turbo_tasks_fs::{File, FileContent};
+
+/// The tag at the start of a TrueType Collection.
+const TTC_TAG: &[u8] = b"ttcf";
+/// The `sfntVersion` of fonts with CFF outlines.
+const CFF_SFNT_VERSION: &[u8] = b"OTTO";
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    let bytes = data
+        .get(offset..offset + 2)
+        .context("Unexpected end of the font file")?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    let bytes = data
+        .get(offset..offset + 4)
+        .context("Unexpected end of the font file")?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Returns the offset of the table directory of the face at `index` of a
+/// TrueType Collection.
+fn face_offset(collection: &[u8], index: u32) -> Result<usize> {
+    if collection.get(..4) != Some(TTC_TAG) {
+        bail!("The font file isn't a TrueType Collection");
+    }
+    let num_fonts = read_u32(collection, 8)?;
+    if index >= num_fonts {
+        bail!(
+            "`collectionIndex` is {index}, but the TrueType Collection only contains {num_fonts} \
+             fonts"
+        );
+    }
+    Ok(read_u32(collection, 12 + 4 * index as usize)? as usize)
+}
+
+/// Returns the extension of the font file extracted from the face at `index`
+/// of a TrueType Collection, `otf` for CFF outlines and `ttf` otherwise.
+pub(super) fn collection_face_ext(collection: &[u8], index: u32) -> Result<&'static str> {
+    let offset = face_offset(collection, index)?;
+    let is_cff = collection.get(offset..offset + 4) == Some(CFF_SFNT_VERSION);
+    Ok(if is_cff { "otf" } else { "ttf" })
+}
+
+/// Copies the face at `index` of a TrueType Collection into a font file of its
+/// own, as browsers don't support selecting a face of a collection in `url()`.
+/// The tables shared with other faces are copied as well.
+pub(super) fn extract_collection_face(collection: &[u8], index: u32) -> Result<Vec<u8>> {
+    let offset = face_offset(collection, index)?;
+    let num_tables = read_u16(collection, offset + 4)? as usize;
+    let tables = (0..num_tables)
+        .map(|i| {
+            let record = offset + 12 + 16 * i;
+            let table_offset = read_u32(collection, record + 8)? as usize;
+            let length = read_u32(collection, record + 12)? as usize;
+            let data = collection
+                .get(table_offset..table_offset + length)
+                .context("A table of the face is outside of the font file")?;
+            Ok((&collection[record..record + 8], data))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // The header keeps the `sfntVersion` and search parameters of the face, the
+    // table records keep their order, tag and checksum.
+    let header_len = 12 + 16 * num_tables;
+    let mut font = Vec::with_capacity(
+        header_len
+            + tables
+                .iter()
+                .map(|(_, data)| data.len().next_multiple_of(4))
+                .sum::<usize>(),
+    );
+    font.extend_from_slice(&collection[offset..offset + 12]);
+    let mut table_offset = header_len;
+    for (tag_and_checksum, data) in &tables {
+        font.extend_from_slice(tag_and_checksum);
+        font.extend_from_slice(&(table_offset as u32).to_be_bytes());
+        font.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        table_offset += data.len().next_multiple_of(4);
+    }
+    for (_, data) in &tables {
+        font.extend_from_slice(data);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+    Ok(font)
+}
+
+/// The font file of the face at `index` of a TrueType Collection.
+#[turbo_tasks::function]
+pub(super) async fn collection_face_content(
+    collection: Vc<FileContent>,
+    index: u32,
+) -> Result<Vc<FileContent>> {
+    let FileContent::Content(file) = &*collection.await? else {
+        return Ok(FileContent::NotFound.cell());
+    };
+    let face = extract_collection_face(&file.content().to_bytes()?, index)?;
+    Ok(FileContent::Content(File::from(face)).cell())
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use allsorts::{binary::read::ReadScope, font_data::FontData, tables::FontTableProvider, tag};
+    use anyhow::Result;
+
+    use super::{collection_face_ext, extract_collection_face, read_u16, read_u32, TTC_TAG};
+
+    pub(in crate::next_font::local) static NOTO_SANS: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../packages/next/src/compiled/@vercel/og/noto-sans-v27-latin-regular.ttf"
+    ));
+    pub(in crate::next_font::local) static TYPEWRITER: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../test/e2e/og-routes-custom-font/assets/typewr__.ttf"
+    ));
+
+    /// Packs single fonts into a TrueType Collection, without sharing tables.
+    pub(in crate::next_font::local) fn pack_collection(fonts: &[&[u8]]) -> Vec<u8> {
+        let mut collection = TTC_TAG.to_vec();
+        collection.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+        collection.extend_from_slice(&(fonts.len() as u32).to_be_bytes());
+        let directories_start = 12 + 4 * fonts.len();
+        let directory_len = |font: &[u8]| 12 + 16 * read_u16(font, 4).unwrap() as usize;
+        let mut directory_offset = directories_start;
+        for font in fonts {
+            collection.extend_from_slice(&(directory_offset as u32).to_be_bytes());
+            directory_offset += directory_len(font);
+        }
+
+        // The data of the tables follows all table directories.
+        let mut data = vec![];
+        let data_start = directory_offset;
+        for font in fonts {
+            collection.extend_from_slice(&font[..12]);
+            for i in 0..read_u16(font, 4).unwrap() as usize {
+                let record = 12 + 16 * i;
+                let offset = read_u32(font, record + 8).unwrap() as usize;
+                let length = read_u32(font, record + 12).unwrap() as usize;
+                collection.extend_from_slice(&font[record..record + 8]);
+                collection.extend_from_slice(&((data_start + data.len()) as u32).to_be_bytes());
+                collection.extend_from_slice(&(length as u32).to_be_bytes());
+                data.extend_from_slice(&font[offset..offset + length]);
+                data.resize(data.len().next_multiple_of(4), 0);
+            }
+        }
+        collection.extend_from_slice(&data);
+        collection
+    }
+
+    fn table(font: &[u8], tag: u32) -> Result<Vec<u8>> {
+        let font_data = ReadScope::new(font).read::<FontData>()?;
+        Ok(font_data
+            .table_provider(0)?
+            .read_table_data(tag)?
+            .into_owned())
+    }
+
+    #[test]
+    fn test_extracts_face_by_index() -> Result<()> {
+        let collection = pack_collection(&[NOTO_SANS, TYPEWRITER]);
+
+        for (index, font) in [NOTO_SANS, TYPEWRITER].into_iter().enumerate() {
+            let face = extract_collection_face(&collection, index as u32)?;
+            // The extracted face is a font of its own with the tables of the face
+            for tag in [tag::HEAD, tag::HHEA, tag::CMAP, tag::NAME] {
+                assert_eq!(table(&face, tag)?, table(font, tag)?);
+            }
+            assert_eq!(collection_face_ext(&collection, index as u32)?, "ttf");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors_on_index_out_of_range() {
+        let collection = pack_collection(&[NOTO_SANS, TYPEWRITER]);
+
+        assert_eq!(
+            extract_collection_face(&collection, 2)
+                .unwrap_err()
+                .to_string(),
+            "`collectionIndex` is 2, but the TrueType Collection only contains 2 fonts"
+        );
+        assert_eq!(
+            extract_collection_face(NOTO_SANS, 0)
+                .unwrap_err()
+                .to_string(),
+            "The font file isn't a TrueType Collection"
+        );
+    }
+}
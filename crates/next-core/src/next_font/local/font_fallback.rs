@@ -3,20 +3,26 @@ use allsorts::{
     Font,
 };
 use anyhow::{bail, Context, Result};
-use turbo_tasks::{ResolvedVc, Vc};
+use turbo_rcstr::RcStr;
+use turbo_tasks::Vc;
 use turbo_tasks_fs::{FileContent, FileSystemPath};
 
 use super::{
     options::{FontDescriptor, FontDescriptors, FontWeight, NextFontLocalOptions},
     request::AdjustFontFallback,
 };
-use crate::next_font::{
-    font_fallback::{
-        AutomaticFontFallback, DefaultFallbackFont, FontAdjustment, FontFallback, FontFallbacks,
-        DEFAULT_SANS_SERIF_FONT, DEFAULT_SERIF_FONT,
+use crate::{
+    next_config::FontMetricsConfigs,
+    next_font::{
+        font_fallback::{
+            get_cjk_font_adjustment, glyph_advances, is_generic_font_family,
+            stack_automatic_fallbacks, stack_manual_fallbacks, CjkScript, DefaultFallbackFont,
+            FontAdjustment, FontFallback, FontFallbacks, FontMetricsOverride,
+            DEFAULT_MONOSPACE_FONT, DEFAULT_SANS_SERIF_FONT, DEFAULT_SERIF_FONT,
+        },
+        local::errors::FontError,
+        util::{get_scoped_font_family, FontFamilyType},
     },
-    local::errors::FontError,
-    util::{get_scoped_font_family, FontFamilyType},
 };
 
 // From
@@ -29,36 +35,52 @@ static BOLD_WEIGHT: f64 = 700.0;
 pub(super) async fn get_font_fallbacks(
     lookup_path: Vc<FileSystemPath>,
     options_vc: Vc<NextFontLocalOptions>,
+    font_metrics: Vc<FontMetricsConfigs>,
 ) -> Result<Vc<FontFallbacks>> {
     let options = &*options_vc.await?;
     let mut font_fallbacks = vec![];
     let scoped_font_family =
         get_scoped_font_family(FontFamilyType::Fallback.cell(), options_vc.font_family());
+    let mut main_font = MainFont::new(lookup_path, options);
 
-    match options.adjust_font_fallback {
-        AdjustFontFallback::Arial => font_fallbacks.push(
-            FontFallback::Automatic(AutomaticFontFallback {
-                scoped_font_family: scoped_font_family.to_resolved().await?,
-                local_font_family: ResolvedVc::cell("Arial".into()),
-                adjustment: Some(
-                    get_font_adjustment(lookup_path, options_vc, &DEFAULT_SANS_SERIF_FONT).await?,
-                ),
-            })
-            .resolved_cell(),
-        ),
-        AdjustFontFallback::TimesNewRoman => font_fallbacks.push(
-            FontFallback::Automatic(AutomaticFontFallback {
-                scoped_font_family: scoped_font_family.to_resolved().await?,
-                local_font_family: ResolvedVc::cell("Times New Roman".into()),
-                adjustment: Some(
-                    get_font_adjustment(lookup_path, options_vc, &DEFAULT_SERIF_FONT).await?,
-                ),
-            })
-            .resolved_cell(),
-        ),
-        AdjustFontFallback::None => (),
+    let cjk_fallbacks = match options.adjust_font_fallback {
+        AdjustFontFallback::Automatic => get_cjk_fallbacks(main_font.metrics().await?, options),
+        _ => None,
+    };
+    if cjk_fallbacks.is_none() {
+        if let Some(manual_fallbacks) =
+            get_manual_fallbacks(&mut main_font, options, font_metrics).await?
+        {
+            if let Some(font_fallbacks) =
+                stack_manual_fallbacks(&*scoped_font_family.await?, manual_fallbacks)
+            {
+                return Ok(Vc::cell(
+                    font_fallbacks
+                        .into_iter()
+                        .map(|fallback| fallback.resolved_cell())
+                        .collect(),
+                ));
+            }
+        }
+    }
+
+    let automatic_fallbacks = match cjk_fallbacks {
+        Some(cjk_fallbacks) => Some(cjk_fallbacks),
+        None => get_latin_fallback(&mut main_font, options, font_metrics)
+            .await?
+            .map(|fallback| vec![fallback]),
     };
 
+    if let Some(automatic_fallbacks) = automatic_fallbacks {
+        font_fallbacks.push(
+            FontFallback::Automatic(stack_automatic_fallbacks(
+                &*scoped_font_family.await?,
+                automatic_fallbacks,
+            ))
+            .resolved_cell(),
+        );
+    }
+
     if let Some(fallback) = &options.fallback {
         font_fallbacks.push(FontFallback::Manual(fallback.clone()).resolved_cell());
     }
@@ -66,54 +88,293 @@ pub(super) async fn get_font_fallbacks(
     Ok(Vc::cell(font_fallbacks))
 }
 
-async fn get_font_adjustment(
+/// The font picked for the fallback generation. Its file is read and measured
+/// the first time its metrics are needed, and then shared by all fallbacks.
+struct MainFont<'a> {
     lookup_path: Vc<FileSystemPath>,
-    options: Vc<NextFontLocalOptions>,
-    fallback_font: &DefaultFallbackFont,
-) -> Result<FontAdjustment> {
-    let options = &*options.await?;
-    let main_descriptor = pick_font_for_fallback_generation(&options.fonts)?;
-    let font_file = &*lookup_path
-        .join(main_descriptor.path.clone())
-        .read()
-        .await?;
-    let font_file_rope = match font_file {
-        FileContent::NotFound => bail!(FontError::FontFileNotFound(main_descriptor.path.clone())),
-        FileContent::Content(file) => file.content(),
+    options: &'a NextFontLocalOptions,
+    metrics: Option<MainFontMetrics>,
+}
+
+impl<'a> MainFont<'a> {
+    fn new(lookup_path: Vc<FileSystemPath>, options: &'a NextFontLocalOptions) -> Self {
+        MainFont {
+            lookup_path,
+            options,
+            metrics: None,
+        }
+    }
+
+    async fn metrics(&mut self) -> Result<&MainFontMetrics> {
+        if self.metrics.is_none() {
+            let main_descriptor = pick_font_for_fallback_generation(&self.options.fonts)?;
+            let font_file_binary = read_font_file(self.lookup_path, main_descriptor).await?;
+            self.metrics = Some(MainFontMetrics::measure(
+                &font_file_binary,
+                main_descriptor,
+            )?);
+        }
+
+        Ok(self.metrics.as_ref().unwrap())
+    }
+}
+
+/// The metrics of a font file needed to adjust fallbacks to it, in font units.
+struct MainFontMetrics {
+    units_per_em: f64,
+    ascent: f64,
+    descent: f64,
+    line_gap: f64,
+    /// The average width of `a-z`, if the font has all of their glyphs.
+    az_avg_width: Option<f64>,
+    /// Whether the font is monospaced, in which case it's best approximated by
+    /// a monospaced fallback font.
+    is_monospace: bool,
+    /// The CJK script the font covers, along with the average advance of its
+    /// full-width glyphs.
+    cjk_script: Option<(CjkScript, f64)>,
+}
+
+impl MainFontMetrics {
+    fn measure(font_file_binary: &[u8], descriptor: &FontDescriptor) -> Result<Self> {
+        let mut font = parse_font(font_file_binary, descriptor)?;
+        let units_per_em = font
+            .head_table()?
+            .context(format!(
+                "Unable to read font scale from font file at {}",
+                &descriptor.path
+            ))?
+            .units_per_em as f64;
+
+        Ok(MainFontMetrics {
+            units_per_em,
+            ascent: font.hhea_table.ascender as f64,
+            descent: font.hhea_table.descender as f64,
+            line_gap: font.hhea_table.line_gap as f64,
+            az_avg_width: calc_average_width(&mut font).map(f64::from),
+            is_monospace: is_monospace_font(&mut font),
+            cjk_script: detect_cjk_script(&mut font),
+        })
+    }
+
+    /// Computes the adjustment of `fallback_font` to the font.
+    fn adjustment(&self, fallback_font: &DefaultFallbackFont) -> FontAdjustment {
+        let fallback_avg_width = fallback_font.az_avg_width / fallback_font.units_per_em as f64;
+        // TODO: Use xWidthAvg like next/google.
+        //       JS implementation: https://github.com/seek-oss/capsize/blob/42d6dc39d58247bc6b9e013a4b1c4463bf287dca/packages/unpack/src/index.ts#L7-L83
+        let size_adjust = match self.az_avg_width {
+            Some(az_avg_width) => az_avg_width / self.units_per_em / fallback_avg_width,
+            None => 1.0,
+        };
+
+        FontAdjustment {
+            ascent: self.ascent / (self.units_per_em * size_adjust),
+            descent: self.descent / (self.units_per_em * size_adjust),
+            line_gap: self.line_gap / (self.units_per_em * size_adjust),
+            size_adjust,
+        }
+    }
+}
+
+/// Adjusts each font of the manual `fallback` stack with known metrics, either
+/// baked in or registered in `experimental.fontMetrics`, to the font. Returns
+/// `None` unless the fallback is adjusted automatically.
+async fn get_manual_fallbacks(
+    main_font: &mut MainFont<'_>,
+    options: &NextFontLocalOptions,
+    font_metrics: Vc<FontMetricsConfigs>,
+) -> Result<Option<Vec<(RcStr, Option<FontAdjustment>)>>> {
+    let (AdjustFontFallback::Automatic, Some(fallback)) =
+        (&options.adjust_font_fallback, &options.fallback)
+    else {
+        return Ok(None);
     };
 
-    let font_file_binary = font_file_rope.to_bytes()?;
-    let scope = allsorts::binary::read::ReadScope::new(&font_file_binary);
-    let mut font = Font::new(scope.read::<FontData>()?.table_provider(0)?)?.context(format!(
-        "Unable to read font metrics from font file at {}",
-        &main_descriptor.path,
-    ))?;
-
-    let az_avg_width = calc_average_width(&mut font);
-    let units_per_em = font
-        .head_table()?
-        .context(format!(
-            "Unable to read font scale from font file at {}",
-            &main_descriptor.path
-        ))?
-        .units_per_em as f64;
-
-    let fallback_avg_width = fallback_font.az_avg_width / fallback_font.units_per_em as f64;
-    // TODO: Use xWidthAvg like next/google.
-    //       JS implementation: https://github.com/seek-oss/capsize/blob/42d6dc39d58247bc6b9e013a4b1c4463bf287dca/packages/unpack/src/index.ts#L7-L83
-    let size_adjust = match az_avg_width {
-        Some(az_avg_width) => az_avg_width as f64 / units_per_em / fallback_avg_width,
-        None => 1.0,
+    let font_metrics = font_metrics.await?;
+    let mut fallbacks = vec![];
+    for font_family in fallback {
+        if is_generic_font_family(font_family) {
+            fallbacks.push((font_family.clone(), None));
+            continue;
+        }
+        let overrides = options.fallback_metrics.get(font_family);
+        let adjustment = match overrides.and_then(FontMetricsOverride::to_adjustment) {
+            // No need to read the font file when the user provided every metric
+            Some(adjustment) => Some(adjustment),
+            None => match DefaultFallbackFont::by_name(font_family, &font_metrics) {
+                Some(fallback_font) => {
+                    let adjustment = main_font.metrics().await?.adjustment(&fallback_font);
+                    Some(match overrides {
+                        Some(overrides) => adjustment.with_overrides(overrides),
+                        None => adjustment,
+                    })
+                }
+                None => None,
+            },
+        };
+        fallbacks.push((font_family.clone(), adjustment));
+    }
+
+    Ok(Some(fallbacks))
+}
+
+async fn get_latin_fallback(
+    main_font: &mut MainFont<'_>,
+    options: &NextFontLocalOptions,
+    font_metrics: Vc<FontMetricsConfigs>,
+) -> Result<Option<(RcStr, Option<FontAdjustment>)>> {
+    let custom_fallback_font;
+    let fallback_font = match &options.adjust_font_fallback {
+        AdjustFontFallback::Automatic => {
+            if main_font.metrics().await?.is_monospace {
+                &*DEFAULT_MONOSPACE_FONT
+            } else {
+                &*DEFAULT_SANS_SERIF_FONT
+            }
+        }
+        AdjustFontFallback::Arial => &*DEFAULT_SANS_SERIF_FONT,
+        AdjustFontFallback::TimesNewRoman => &*DEFAULT_SERIF_FONT,
+        AdjustFontFallback::CourierNew => &*DEFAULT_MONOSPACE_FONT,
+        AdjustFontFallback::Custom(name) => {
+            let font_metrics = font_metrics.await?;
+            let Some(metrics) = font_metrics.get(name) else {
+                bail!(
+                    "Unknown fallback font {}. Register its metrics in \
+                     `experimental.fontMetrics`, or use \"Arial\", \"Times New Roman\" or \
+                     \"Courier New\".",
+                    name
+                )
+            };
+            custom_fallback_font = DefaultFallbackFont::from_config(name.clone(), metrics);
+            &custom_fallback_font
+        }
+        AdjustFontFallback::None => return Ok(None),
     };
 
-    Ok(FontAdjustment {
-        ascent: font.hhea_table.ascender as f64 / (units_per_em * size_adjust),
-        descent: font.hhea_table.descender as f64 / (units_per_em * size_adjust),
-        line_gap: font.hhea_table.line_gap as f64 / (units_per_em * size_adjust),
-        size_adjust,
+    let overrides = options.fallback_metrics.get(&fallback_font.name);
+    let adjustment = match overrides.and_then(FontMetricsOverride::to_adjustment) {
+        // No need to read the font file when the user provided every metric
+        Some(adjustment) => adjustment,
+        None => {
+            let adjustment = main_font.metrics().await?.adjustment(fallback_font);
+            match overrides {
+                Some(overrides) => adjustment.with_overrides(overrides),
+                None => adjustment,
+            }
+        }
+    };
+
+    Ok(Some((fallback_font.name.clone(), Some(adjustment))))
+}
+
+/// Returns a stack of locale-appropriate fallbacks when the font predominantly
+/// covers a CJK script, as the average width of `a-z` doesn't describe the
+/// width of its text.
+fn get_cjk_fallbacks(
+    metrics: &MainFontMetrics,
+    options: &NextFontLocalOptions,
+) -> Option<Vec<(RcStr, Option<FontAdjustment>)>> {
+    let (script, full_width_avg) = metrics.cjk_script?;
+    let adjustment = get_cjk_font_adjustment(
+        full_width_avg,
+        metrics.units_per_em,
+        metrics.ascent,
+        metrics.descent,
+        metrics.line_gap,
+    );
+
+    Some(
+        script
+            .fallback_fonts()
+            .iter()
+            .map(|name| {
+                let name: RcStr = (*name).into();
+                let adjustment = match options.fallback_metrics.get(&name) {
+                    Some(overrides) => adjustment.clone().with_overrides(overrides),
+                    None => adjustment.clone(),
+                };
+                (name, Some(adjustment))
+            })
+            .collect(),
+    )
+}
+
+/// Detects the CJK script the font covers, if any, along with the average
+/// advance of its full-width glyphs. Hangul is checked first as Korean fonts
+/// commonly include kana, and Japanese fonts in turn include Han characters.
+fn detect_cjk_script(font: &mut Font<DynamicFontTableProvider>) -> Option<(CjkScript, f64)> {
+    [
+        CjkScript::Korean,
+        CjkScript::Japanese,
+        CjkScript::SimplifiedChinese,
+        CjkScript::TraditionalChinese,
+    ]
+    .into_iter()
+    .find_map(|script| {
+        Some((
+            script,
+            calc_full_width_average(font, script.sample_characters())?,
+        ))
     })
 }
 
+/// Averages the advance of the glyphs for `characters`, or returns `None` if
+/// the font is missing any of them.
+fn calc_full_width_average(
+    font: &mut Font<DynamicFontTableProvider>,
+    characters: &str,
+) -> Option<f64> {
+    let advances = glyph_advances(font, characters)
+        .into_iter()
+        .collect::<Option<Vec<u16>>>()?;
+
+    Some(advances.iter().map(|&advance| advance as f64).sum::<f64>() / advances.len() as f64)
+}
+
+/// Whether the advances of all of the font's glyphs for `a-z` are the same.
+fn is_monospace_font(font: &mut Font<DynamicFontTableProvider>) -> bool {
+    let glyphs = font.map_glyphs(
+        AVG_CHARACTERS,
+        allsorts::tag::LATN,
+        allsorts::font::MatchingPresentation::NotRequired,
+    );
+    let mut advances = glyphs
+        .iter()
+        .filter(|g| g.glyph_index > 0)
+        .map(|g| font.horizontal_advance(g.glyph_index));
+
+    match advances.next() {
+        Some(Some(first)) => advances.all(|advance| advance == Some(first)),
+        _ => false,
+    }
+}
+
+pub(super) async fn read_font_file(
+    lookup_path: Vc<FileSystemPath>,
+    descriptor: &FontDescriptor,
+) -> Result<Vec<u8>> {
+    let font_file = &*lookup_path.join(descriptor.path.clone()).read().await?;
+    let font_file_rope = match font_file {
+        FileContent::NotFound => bail!(FontError::FontFileNotFound(descriptor.path.clone())),
+        FileContent::Content(file) => file.content(),
+    };
+
+    Ok(font_file_rope.to_bytes()?.into_owned())
+}
+
+fn parse_font<'a>(
+    font_file_binary: &'a [u8],
+    descriptor: &FontDescriptor,
+) -> Result<Font<DynamicFontTableProvider<'a>>> {
+    let scope = allsorts::binary::read::ReadScope::new(font_file_binary);
+    // Fonts other than collections only contain a single face
+    let index = descriptor.collection_index.unwrap_or(0) as usize;
+    Font::new(scope.read::<FontData>()?.table_provider(index)?)?.context(format!(
+        "Unable to read font metrics from font file at {}",
+        &descriptor.path,
+    ))
+}
+
 fn calc_average_width(font: &mut Font<DynamicFontTableProvider>) -> Option<f32> {
     let has_all_glyphs = AVG_CHARACTERS.chars().all(|c| {
         font.lookup_glyph_index(c, allsorts::font::MatchingPresentation::NotRequired, None)
@@ -257,13 +518,17 @@ mod tests {
     use turbo_rcstr::RcStr;
 
     use crate::next_font::local::{
-        font_fallback::pick_font_for_fallback_generation,
+        collection::tests::{pack_collection, NOTO_SANS, TYPEWRITER},
+        font_fallback::{pick_font_for_fallback_generation, MainFontMetrics},
         options::{FontDescriptor, FontDescriptors, FontWeight},
     };
 
     fn generate_font_descriptor(weight: &FontWeight, style: &Option<String>) -> FontDescriptor {
         FontDescriptor {
             ext: "ttf".into(),
+            collection_index: None,
+            display: None,
+            preload: None,
             path: "foo.ttf".into(),
             style: style.clone().map(RcStr::from),
             weight: Some(weight.clone()),
@@ -382,4 +647,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_measures_collection_face_by_index() -> Result<()> {
+        let collection = pack_collection(&[NOTO_SANS, TYPEWRITER]);
+        let measure = |font: &[u8], collection_index| {
+            MainFontMetrics::measure(
+                font,
+                &FontDescriptor {
+                    ext: "ttc".into(),
+                    collection_index,
+                    ..generate_font_descriptor(&FontWeight::Fixed("400".into()), &None)
+                },
+            )
+        };
+
+        for (collection_index, font) in [(None, NOTO_SANS), (Some(1), TYPEWRITER)] {
+            let face = measure(&collection, collection_index)?;
+            let expected = measure(font, None)?;
+            assert_eq!(face.units_per_em, expected.units_per_em);
+            assert_eq!(face.ascent, expected.ascent);
+            assert_eq!(face.descent, expected.descent);
+            assert_eq!(face.az_avg_width, expected.az_avg_width);
+            assert_eq!(face.is_monospace, expected.is_monospace);
+        }
+        // The faces are told apart
+        assert_ne!(
+            measure(&collection, Some(0))?.az_avg_width,
+            measure(&collection, Some(1))?.az_avg_width
+        );
+
+        Ok(())
+    }
 }
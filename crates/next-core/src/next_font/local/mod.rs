@@ -19,23 +19,27 @@ use turbopack_core::{
 };
 
 use self::{
+    collection::{collection_face_content, collection_face_ext},
     font_fallback::get_font_fallbacks,
     options::{options_from_request, FontDescriptors, NextFontLocalOptions},
     stylesheet::build_stylesheet,
     util::build_font_family_string,
 };
 use super::{
+    diagnostics::NextFontStylesheetSource,
     font_fallback::FontFallbacks,
-    util::{can_use_next_font, FontCssProperties},
+    util::{can_use_next_font, get_scoped_font_family, FontCssProperties, FontFamilyType},
 };
 use crate::{
     next_app::metadata::split_extension,
+    next_config::NextConfig,
     next_font::{
         local::{errors::FontError, options::FontWeight},
         util::{get_request_hash, get_request_id},
     },
 };
 
+mod collection;
 mod errors;
 pub mod font_fallback;
 pub mod options;
@@ -48,18 +52,21 @@ struct NextFontLocalFontFileOptions {
     pub path: RcStr,
     pub preload: bool,
     pub has_size_adjust: bool,
+    /// The face to extract from a TrueType Collection.
+    pub collection_index: Option<u32>,
 }
 
 #[turbo_tasks::value]
 pub(crate) struct NextFontLocalResolvePlugin {
     root: ResolvedVc<FileSystemPath>,
+    next_config: ResolvedVc<NextConfig>,
 }
 
 #[turbo_tasks::value_impl]
 impl NextFontLocalResolvePlugin {
     #[turbo_tasks::function]
-    pub fn new(root: ResolvedVc<FileSystemPath>) -> Vc<Self> {
-        NextFontLocalResolvePlugin { root }.cell()
+    pub fn new(root: ResolvedVc<FileSystemPath>, next_config: ResolvedVc<NextConfig>) -> Vc<Self> {
+        NextFontLocalResolvePlugin { root, next_config }.cell()
     }
 }
 
@@ -103,10 +110,18 @@ impl BeforeResolvePlugin for NextFontLocalResolvePlugin {
                 }
 
                 let query = query_vc.await?.to_string();
-                let request_hash = get_request_hash(&query).await?;
+                let request_hash = get_request_hash(
+                    &query,
+                    &*this.next_config.experimental_font_family_hashing().await?,
+                )
+                .await?;
                 let qstr = qstring::QString::from(query.as_str());
                 let options_vc = font_options_from_query_map(**query_vc);
-                let font_fallbacks = get_font_fallbacks(lookup_path, options_vc);
+                let font_fallbacks = get_font_fallbacks(
+                    lookup_path,
+                    options_vc,
+                    this.next_config.experimental_font_metrics(),
+                );
                 let properties = get_font_css_properties(options_vc, font_fallbacks).await;
 
                 let lookup_path = lookup_path.to_resolved().await?;
@@ -186,7 +201,11 @@ impl BeforeResolvePlugin for NextFontLocalResolvePlugin {
             }
             "@vercel/turbopack-next/internal/font/local/cssmodule.module.css" => {
                 let query = query_vc.await?.to_string();
-                let request_hash = get_request_hash(&query).await?;
+                let request_hash = get_request_hash(
+                    &query,
+                    &*this.next_config.experimental_font_family_hashing().await?,
+                )
+                .await?;
                 let options = font_options_from_query_map(**query_vc);
                 let css_virtual_path = lookup_path.join(
                     format!(
@@ -195,18 +214,25 @@ impl BeforeResolvePlugin for NextFontLocalResolvePlugin {
                     )
                     .into(),
                 );
-                let fallback = get_font_fallbacks(lookup_path, options);
+                let fallback = get_font_fallbacks(
+                    lookup_path,
+                    options,
+                    this.next_config.experimental_font_metrics(),
+                );
 
                 let stylesheet = build_stylesheet(
+                    lookup_path,
                     font_options_from_query_map(**query_vc),
                     fallback,
                     get_font_css_properties(options, fallback),
                 )
                 .await?;
 
-                let css_asset = VirtualSource::new(
+                let css_asset = NextFontStylesheetSource::new(
                     css_virtual_path,
                     AssetContent::file(FileContent::Content(stylesheet.into()).cell()),
+                    get_scoped_font_family(FontFamilyType::WebFont.cell(), options.font_family()),
+                    fallback,
                 )
                 .to_resolved()
                 .await?;
@@ -220,13 +246,25 @@ impl BeforeResolvePlugin for NextFontLocalResolvePlugin {
                     path,
                     preload,
                     has_size_adjust: size_adjust,
+                    collection_index,
                 } = font_file_options_from_query_map(**query_vc).await?;
 
                 let (filename, ext) = split_extension(&path);
-                let ext = ext.with_context(|| format!("font {} needs an extension", &path))?;
+                let mut ext = ext.with_context(|| format!("font {} needs an extension", &path))?;
 
                 // remove dashes and dots as they might be used for the markers below.
                 let mut name = filename.replace(['-', '.'], "_");
+                let mut font_file = lookup_path.join(path.clone()).read();
+                if ext == "ttc" {
+                    // Browsers can't pick a face of a collection, so the face is
+                    // emitted as a font file of its own.
+                    let index = collection_index.unwrap_or(0);
+                    if let FileContent::Content(file) = &*font_file.await? {
+                        ext = collection_face_ext(&file.content().to_bytes()?, index)?;
+                    }
+                    name.push_str(&format!("_{index}"));
+                    font_file = collection_face_content(font_file, index);
+                }
                 if size_adjust {
                     name.push_str("-s")
                 }
@@ -236,8 +274,6 @@ impl BeforeResolvePlugin for NextFontLocalResolvePlugin {
 
                 let font_virtual_path = lookup_path.join(format!("/{}.{}", name, ext).into());
 
-                let font_file = lookup_path.join(path.clone()).read();
-
                 let font_source =
                     VirtualSource::new(font_virtual_path, AssetContent::file(font_file))
                         .to_resolved()
@@ -1,6 +1,6 @@
 use std::{fmt::Display, str::FromStr};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 use turbo_rcstr::RcStr;
 use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, Value, Vc};
@@ -9,6 +9,7 @@ use super::request::{
     AdjustFontFallback, NextFontLocalRequest, NextFontLocalRequestArguments, SrcDescriptor,
     SrcRequest,
 };
+use crate::next_font::font_fallback::{validate_metrics_overrides, FontMetricsOverrides};
 
 /// A normalized, Vc-friendly struct derived from validating and transforming
 /// [[NextFontLocalRequest]]
@@ -26,6 +27,9 @@ pub(super) struct NextFontLocalOptions {
     pub fallback: Option<Vec<RcStr>>,
     /// The user's desired fallback font
     pub adjust_font_fallback: AdjustFontFallback,
+    /// User-provided metrics for the automatic fallback font, taking
+    /// precedence over the computed ones.
+    pub fallback_metrics: FontMetricsOverrides,
     /// An optional name for a css custom property (css variable) that applies
     /// the font family when used.
     pub variable: Option<RcStr>,
@@ -67,17 +71,33 @@ pub(super) struct FontDescriptor {
     pub style: Option<RcStr>,
     pub path: RcStr,
     pub ext: RcStr,
+    /// The index of the face within a TrueType Collection (`.ttc`).
+    pub collection_index: Option<u32>,
+    /// The css `font-display` property of this file, if it differs from
+    /// [NextFontLocalOptions::display].
+    pub display: Option<RcStr>,
+    /// Whether to preload this file, if it differs from
+    /// [NextFontLocalOptions::preload].
+    pub preload: Option<bool>,
 }
 
 impl FontDescriptor {
     fn from_src_request(src_descriptor: &SrcDescriptor) -> Result<Self> {
-        let ext = src_descriptor
+        let ext: RcStr = src_descriptor
             .path
             .rsplit('.')
             .next()
             .context("Extension required")?
             .into();
 
+        if src_descriptor.collection_index.is_some() && ext.as_str() != "ttc" {
+            bail!(
+                "`collectionIndex` can only be used with TrueType Collections (.ttc), but {} is \
+                 not one",
+                src_descriptor.path
+            );
+        }
+
         Ok(Self {
             path: src_descriptor.path.clone(),
             weight: src_descriptor
@@ -86,6 +106,9 @@ impl FontDescriptor {
                 .and_then(|w| FontWeight::from_str(w).ok()),
             style: src_descriptor.style.clone(),
             ext,
+            collection_index: src_descriptor.collection_index,
+            display: src_descriptor.display.clone(),
+            preload: src_descriptor.preload,
         })
     }
 }
@@ -169,9 +192,13 @@ pub(super) fn options_from_request(request: &NextFontLocalRequest) -> Result<Nex
         fallback,
         src,
         adjust_font_fallback,
+        fallback_metrics,
         variable,
+        collection_index,
     } = &request.arguments.0;
 
+    validate_metrics_overrides(fallback_metrics)?;
+
     let fonts = match src {
         SrcRequest::Many(descriptors) => FontDescriptors::Many(
             descriptors
@@ -184,6 +211,9 @@ pub(super) fn options_from_request(request: &NextFontLocalRequest) -> Result<Nex
                 path: path.as_str().into(),
                 weight: weight.as_deref().map(RcStr::from),
                 style: style.as_deref().map(RcStr::from),
+                collection_index: *collection_index,
+                display: None,
+                preload: None,
             })?)
         }
     };
@@ -194,6 +224,7 @@ pub(super) fn options_from_request(request: &NextFontLocalRequest) -> Result<Nex
         preload: preload.to_owned(),
         fallback: fallback.to_owned(),
         adjust_font_fallback: adjust_font_fallback.to_owned(),
+        fallback_metrics: fallback_metrics.to_owned(),
         variable: variable.to_owned(),
         variable_name: request.variable_name.to_owned(),
         default_weight: weight.as_ref().and_then(|s| s.parse().ok()),
@@ -207,9 +238,12 @@ mod tests {
     use turbo_tasks_fs::json::parse_json_with_source_context;
 
     use super::{options_from_request, NextFontLocalOptions};
-    use crate::next_font::local::{
-        options::{FontDescriptor, FontDescriptors, FontWeight},
-        request::{AdjustFontFallback, NextFontLocalRequest},
+    use crate::next_font::{
+        font_fallback::FontMetricsOverride,
+        local::{
+            options::{FontDescriptor, FontDescriptors, FontWeight},
+            request::{AdjustFontFallback, NextFontLocalRequest},
+        },
     };
 
     #[test]
@@ -235,13 +269,17 @@ mod tests {
                     weight: None,
                     style: None,
                     ext: "ttf".into(),
+                    collection_index: None,
+                    display: None,
+                    preload: None,
                 }),
                 default_style: None,
                 default_weight: None,
                 display: "swap".into(),
                 preload: true,
                 fallback: None,
-                adjust_font_fallback: AdjustFontFallback::Arial,
+                adjust_font_fallback: AdjustFontFallback::Automatic,
+                fallback_metrics: Default::default(),
                 variable: None,
                 variable_name: "myFont".into()
             },
@@ -283,12 +321,18 @@ mod tests {
                         weight: Some(FontWeight::Fixed("400".into())),
                         style: Some("normal".into()),
                         ext: "ttf".into(),
+                        collection_index: None,
+                        display: None,
+                        preload: None,
                     },
                     FontDescriptor {
                         path: "./Roboto-Italic.ttf".into(),
                         weight: Some(FontWeight::Fixed("400".into())),
                         style: None,
                         ext: "ttf".into(),
+                        collection_index: None,
+                        display: None,
+                        preload: None,
                     }
                 ]),
                 default_weight: Some(FontWeight::Fixed("300".into())),
@@ -296,7 +340,8 @@ mod tests {
                 display: "swap".into(),
                 preload: true,
                 fallback: None,
-                adjust_font_fallback: AdjustFontFallback::Arial,
+                adjust_font_fallback: AdjustFontFallback::Automatic,
+                fallback_metrics: Default::default(),
                 variable: None,
                 variable_name: "myFont".into()
             },
@@ -363,6 +408,9 @@ mod tests {
                     weight: Some(FontWeight::Fixed("500".into())),
                     style: Some("italic".into()),
                     ext: "woff".into(),
+                    collection_index: None,
+                    display: None,
+                    preload: None,
                 }),
                 default_style: Some("italic".into()),
                 default_weight: Some(FontWeight::Fixed("500".into())),
@@ -370,6 +418,7 @@ mod tests {
                 preload: false,
                 fallback: Some(vec!["Fallback".into()]),
                 adjust_font_fallback: AdjustFontFallback::TimesNewRoman,
+                fallback_metrics: Default::default(),
                 variable: Some("myvar".into()),
                 variable_name: "myFont".into()
             },
@@ -377,4 +426,188 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_fallback_metrics() -> Result<()> {
+        let request: NextFontLocalRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "",
+                "path": "index.js",
+                "variableName": "myFont",
+                "arguments": [{
+                    "src": "./Roboto-Regular.woff",
+                    "fallbackMetrics": {
+                        "Arial": {
+                            "ascent": 0.9,
+                            "sizeAdjust": 1.07
+                        }
+                    }
+                }]
+            }
+        "#,
+        )?;
+
+        let options = options_from_request(&request)?;
+        assert_eq!(
+            options.fallback_metrics.get("Arial"),
+            Some(&FontMetricsOverride {
+                ascent: Some(0.9),
+                descent: None,
+                line_gap: None,
+                size_adjust: Some(1.07),
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors_on_invalid_fallback_metrics() -> Result<()> {
+        let request: NextFontLocalRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "",
+                "path": "index.js",
+                "variableName": "myFont",
+                "arguments": [{
+                    "src": "./Roboto-Regular.woff",
+                    "fallbackMetrics": {
+                        "Arial": {
+                            "sizeAdjust": 0
+                        }
+                    }
+                }]
+            }
+        "#,
+        )?;
+
+        match options_from_request(&request) {
+            Ok(_) => panic!(),
+            Err(err) => {
+                assert_eq!(
+                    err.to_string(),
+                    "Invalid sizeAdjust 0 in fallbackMetrics for fallback font Arial. Expected a \
+                     positive number"
+                )
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collection_index() -> Result<()> {
+        let request: NextFontLocalRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "",
+                "path": "index.js",
+                "variableName": "myFont",
+                "arguments": [{
+                    "src": [{
+                        "path": "./NotoSansCJK.ttc",
+                        "collectionIndex": 1
+                    }]
+                }]
+            }
+        "#,
+        )?;
+
+        assert_eq!(
+            options_from_request(&request)?.fonts,
+            FontDescriptors::Many(vec![FontDescriptor {
+                path: "./NotoSansCJK.ttc".into(),
+                weight: None,
+                style: None,
+                ext: "ttc".into(),
+                collection_index: Some(1),
+                display: None,
+                preload: None,
+            }])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_per_file_display_and_preload() -> Result<()> {
+        let request: NextFontLocalRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "",
+                "path": "index.js",
+                "variableName": "myFont",
+                "arguments": [{
+                    "src": [{
+                        "path": "./Roboto-Regular.woff2",
+                        "weight": "400"
+                    }, {
+                        "path": "./Roboto-Black.woff2",
+                        "weight": "900",
+                        "display": "optional",
+                        "preload": false
+                    }],
+                    "display": "block"
+                }]
+            }
+        "#,
+        )?;
+
+        assert_eq!(
+            options_from_request(&request)?.fonts,
+            FontDescriptors::Many(vec![
+                FontDescriptor {
+                    path: "./Roboto-Regular.woff2".into(),
+                    weight: Some(FontWeight::Fixed("400".into())),
+                    style: None,
+                    ext: "woff2".into(),
+                    collection_index: None,
+                    display: None,
+                    preload: None,
+                },
+                FontDescriptor {
+                    path: "./Roboto-Black.woff2".into(),
+                    weight: Some(FontWeight::Fixed("900".into())),
+                    style: None,
+                    ext: "woff2".into(),
+                    collection_index: None,
+                    display: Some("optional".into()),
+                    preload: Some(false),
+                }
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors_on_collection_index_for_single_font() -> Result<()> {
+        let request: NextFontLocalRequest = parse_json_with_source_context(
+            r#"
+            {
+                "import": "",
+                "path": "index.js",
+                "variableName": "myFont",
+                "arguments": [{
+                    "src": "./Roboto-Regular.ttf",
+                    "collectionIndex": 1
+                }]
+            }
+        "#,
+        )?;
+
+        match options_from_request(&request) {
+            Ok(_) => panic!(),
+            Err(err) => {
+                assert_eq!(
+                    err.to_string(),
+                    "`collectionIndex` can only be used with TrueType Collections (.ttc), but \
+                     ./Roboto-Regular.ttf is not one"
+                )
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use turbo_rcstr::RcStr;
 use turbo_tasks::{trace::TraceRawVcs, NonLocalValue};
 
+use crate::next_font::font_fallback::FontMetricsOverrides;
+
 /// The top-most structure encoded into the query param in requests to
 /// `next/font/local` generated by the next/font swc transform. e.g.
 /// `next/font/local/target.css?{"path": "index.js", "arguments": {"src":...
@@ -28,7 +30,11 @@ pub(super) struct NextFontLocalRequestArguments {
         deserialize_with = "deserialize_adjust_font_fallback"
     )]
     pub adjust_font_fallback: AdjustFontFallback,
+    #[serde(default)]
+    pub fallback_metrics: FontMetricsOverrides,
     pub variable: Option<RcStr>,
+    /// The face to use when `src` is a TrueType Collection (`.ttc`).
+    pub collection_index: Option<u32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -39,10 +45,17 @@ pub(super) enum SrcRequest {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(super) struct SrcDescriptor {
     pub path: RcStr,
     pub weight: Option<RcStr>,
     pub style: Option<RcStr>,
+    pub collection_index: Option<u32>,
+    /// Overrides the top-level `display` for this file.
+    pub display: Option<RcStr>,
+    /// Overrides the top-level `preload` for this file, e.g. to only preload
+    /// the weights used above the fold.
+    pub preload: Option<bool>,
 }
 
 /// The user's desired fallback font
@@ -60,17 +73,22 @@ pub(super) struct SrcDescriptor {
     NonLocalValue,
 )]
 pub(super) enum AdjustFontFallback {
+    /// Courier New for monospaced fonts, Arial otherwise
+    Automatic,
     Arial,
     TimesNewRoman,
+    CourierNew,
+    /// A font registered with its metrics in `experimental.fontMetrics`
+    Custom(RcStr),
     None,
 }
 
 fn default_adjust_font_fallback() -> AdjustFontFallback {
-    AdjustFontFallback::Arial
+    AdjustFontFallback::Automatic
 }
 
 /// Deserializes and validates JS (bool | string) into [[AdjustFontFallback]]'s
-/// None, Arial, TimesNewRoman
+/// None, Arial, TimesNewRoman, CourierNew or Custom
 fn deserialize_adjust_font_fallback<'de, D>(
     de: D,
 ) -> std::result::Result<AdjustFontFallback, D::Error>
@@ -88,10 +106,8 @@ where
         AdjustFontFallbackInner::Named(name) => match name.as_str() {
             "Arial" => Ok(AdjustFontFallback::Arial),
             "Times New Roman" => Ok(AdjustFontFallback::TimesNewRoman),
-            _ => Err(serde::de::Error::invalid_value(
-                serde::de::Unexpected::Other("adjust_font_fallback"),
-                &"Expected either \"Arial\" or \"Times New Roman\"",
-            )),
+            "Courier New" => Ok(AdjustFontFallback::CourierNew),
+            _ => Ok(AdjustFontFallback::Custom(name.into())),
         },
         AdjustFontFallbackInner::None(val) => {
             if val {
@@ -145,15 +161,15 @@ mod tests {
     }
 
     #[test]
-    fn test_deserialize_adjust_font_fallback_fails_on_unknown_string() {
-        match serde_json::from_str::<TestFallback>(r#"{"adjustFontFallback": "Roboto"}"#) {
-            Ok(_) => panic!("Should fail"),
-            Err(error) => assert!(
-                error.to_string().contains(
-                    r#"invalid value: adjust_font_fallback, expected Expected either "Arial" or "Times New Roman""#
-                )
-            ),
-        };
+    fn test_deserializes_custom_font() -> Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TestFallback>(r#"{"adjustFontFallback": "Corporate Sans"}"#)?,
+            TestFallback {
+                adjust_font_fallback: AdjustFontFallback::Custom("Corporate Sans".into())
+            }
+        );
+
+        Ok(())
     }
 
     #[test]
@@ -179,4 +195,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_deserializes_courier_new() -> Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TestFallback>(r#"{"adjustFontFallback": "Courier New"}"#)?,
+            TestFallback {
+                adjust_font_fallback: AdjustFontFallback::CourierNew
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_defaults_to_automatic() -> Result<()> {
+        assert_eq!(
+            serde_json::from_str::<TestFallback>(r#"{}"#)?,
+            TestFallback {
+                adjust_font_fallback: AdjustFontFallback::Automatic
+            }
+        );
+
+        Ok(())
+    }
 }
@@ -2,8 +2,13 @@ use anyhow::{bail, Result};
 use indoc::formatdoc;
 use turbo_rcstr::RcStr;
 use turbo_tasks::Vc;
+use turbo_tasks_fs::FileSystemPath;
 
-use super::options::{FontDescriptors, NextFontLocalOptions};
+use super::{
+    collection::collection_face_ext,
+    font_fallback::read_font_file,
+    options::{FontDescriptors, NextFontLocalOptions},
+};
 use crate::next_font::{
     font_fallback::FontFallbacks,
     local::NextFontLocalFontFileOptions,
@@ -13,6 +18,7 @@ use crate::next_font::{
 
 #[turbo_tasks::function]
 pub(super) async fn build_stylesheet(
+    lookup_path: Vc<FileSystemPath>,
     options: Vc<NextFontLocalOptions>,
     fallbacks: Vc<FontFallbacks>,
     css_properties: Vc<FontCssProperties>,
@@ -27,8 +33,13 @@ pub(super) async fn build_stylesheet(
             {}
             {}
         "#,
-            *build_font_face_definitions(scoped_font_family, options, fallbacks.has_size_adjust())
-                .await?,
+            *build_font_face_definitions(
+                lookup_path,
+                scoped_font_family,
+                options,
+                fallbacks.has_size_adjust()
+            )
+            .await?,
             (*build_fallback_definition(fallbacks).await?),
             *build_font_class_rules(css_properties).await?
         )
@@ -39,6 +50,7 @@ pub(super) async fn build_stylesheet(
 /// Builds a string of `@font-face` definitions for each local font file
 #[turbo_tasks::function]
 pub(super) async fn build_font_face_definitions(
+    lookup_path: Vc<FileSystemPath>,
     scoped_font_family: Vc<RcStr>,
     options: Vc<NextFontLocalOptions>,
     has_size_adjust: Vc<bool>,
@@ -56,11 +68,23 @@ pub(super) async fn build_font_face_definitions(
     for font in fonts {
         let query = NextFontLocalFontFileOptions {
             path: font.path.clone(),
-            preload: options.preload,
+            preload: font.preload.unwrap_or(options.preload),
             has_size_adjust,
+            collection_index: font.collection_index,
         };
         let query_str = qstring::QString::from(serde_json::to_string(&query)?.as_str());
 
+        // The face of a collection is served as a font file of its own
+        let format = if font.ext == "ttc" {
+            let collection = read_font_file(lookup_path, &font).await?;
+            ext_to_format(collection_face_ext(
+                &collection,
+                font.collection_index.unwrap_or(0),
+            )?)?
+        } else {
+            ext_to_format(&font.ext)?
+        };
+
         definitions.push_str(&formatdoc!(
             r#"
                 @font-face {{
@@ -72,8 +96,8 @@ pub(super) async fn build_font_face_definitions(
             "#,
             *scoped_font_family.await?,
             query_str,
-            ext_to_format(&font.ext)?,
-            options.display,
+            format,
+            font.display.as_ref().unwrap_or(&options.display),
             &font
                 .weight
                 .as_ref()
@@ -22,8 +22,10 @@ pub(super) async fn build_font_family_string(
 
     for font_fallback in &*font_fallbacks.await? {
         match &*font_fallback.await? {
-            FontFallback::Automatic(fallback) => {
-                font_families.push(format!("'{}'", *fallback.scoped_font_family.await?).into());
+            FontFallback::Automatic(fallbacks) => {
+                for fallback in fallbacks {
+                    font_families.push(format!("'{}'", *fallback.scoped_font_family.await?).into());
+                }
             }
             FontFallback::Manual(fallbacks) => {
                 font_families.extend_from_slice(fallbacks);
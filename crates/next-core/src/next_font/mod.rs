@@ -1,3 +1,4 @@
+pub(crate) mod diagnostics;
 pub(crate) mod font_fallback;
 pub(crate) mod google;
 pub(crate) mod issue;
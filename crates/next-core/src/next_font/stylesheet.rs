@@ -13,7 +13,11 @@ use super::{
 pub(crate) async fn build_fallback_definition(fallbacks: Vc<FontFallbacks>) -> Result<Vc<RcStr>> {
     let mut res = "".to_owned();
     for fallback_vc in &*fallbacks.await? {
-        if let FontFallback::Automatic(fallback) = &*fallback_vc.await? {
+        let FontFallback::Automatic(automatic_fallbacks) = &*fallback_vc.await? else {
+            continue;
+        };
+
+        for fallback in automatic_fallbacks {
             let override_properties = match &fallback.adjustment {
                 None => "".to_owned(),
                 Some(adjustment) => formatdoc!(
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::Deserialize;
+use serde_json::Value;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{ResolvedVc, Vc};
 use turbo_tasks_fs::{json::parse_json_with_source_context, FileSystemPath};
@@ -7,6 +8,7 @@ use turbo_tasks_hash::hash_xxh3_hash64;
 use turbopack_core::issue::{IssueExt, IssueSeverity, StyledString};
 
 use super::issue::NextFontIssue;
+use crate::next_config::FontFamilyHashing;
 
 /// CSS properties and values for a given font variation. These are rendered as
 /// values in both the returned JavaScript object and in the referenced css
@@ -22,19 +24,53 @@ pub(crate) struct FontCssProperties {
 /// A hash of the requested querymap derived from how the user invoked
 /// next/font. Used to uniquely identify font requests for generated filenames
 /// and scoped font family names.
-pub(crate) async fn get_request_hash(query: &str) -> Result<u32> {
+///
+/// The hash is derived from the content of the request rather than its
+/// serialization, so it's stable across platforms and formatting differences.
+pub(crate) async fn get_request_hash(query: &str, hashing: &FontFamilyHashing) -> Result<u32> {
     let query = qstring::QString::from(query);
     let mut to_hash = vec![];
     for (k, v) in query {
-        to_hash.push(k);
+        to_hash.push(canonicalize_request(&k));
         to_hash.push(v);
     }
 
-    Ok(
+    Ok(match hashing {
         // Truncate the hash to u32. These hashes are ultimately displayed as 6- or 8-character
         // hexadecimal values.
-        hash_xxh3_hash64(to_hash) as u32,
-    )
+        FontFamilyHashing::Content => hash_xxh3_hash64(to_hash) as u32,
+        FontFamilyHashing::Salt(salt) => hash_xxh3_hash64((salt.as_str(), to_hash)) as u32,
+    })
+}
+
+/// Returns a canonical serialization of the JSON encoded font request: object
+/// keys are sorted and the path of the importing module uses forward slashes.
+/// Requests which aren't JSON are returned as is.
+fn canonicalize_request(request: &str) -> String {
+    fn canonicalize(value: Value) -> Value {
+        match value {
+            Value::Object(map) => {
+                let mut entries = map.into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(k, v)| (k, canonicalize(v)))
+                        .collect(),
+                )
+            }
+            Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+            value => value,
+        }
+    }
+
+    let Ok(mut value) = serde_json::from_str::<Value>(request) else {
+        return request.to_owned();
+    };
+    if let Some(Value::String(path)) = value.get_mut("path") {
+        *path = path.replace('\\', "/");
+    }
+    canonicalize(value).to_string()
 }
 
 #[turbo_tasks::value(shared)]
@@ -116,3 +152,28 @@ pub(crate) async fn can_use_next_font(
     }
     Ok(can_use)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::canonicalize_request;
+
+    #[test]
+    fn test_canonicalize_request_sorts_keys() {
+        assert_eq!(
+            canonicalize_request(
+                r#"{"path":"app/layout.js","import":"Inter","arguments":[{"subsets":["latin"],"display":"swap"}],"variableName":"inter"}"#
+            ),
+            canonicalize_request(
+                r#"{ "variableName": "inter", "arguments": [{ "display": "swap", "subsets": ["latin"] }], "import": "Inter", "path": "app/layout.js" }"#
+            ),
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_request_normalizes_path() {
+        assert_eq!(
+            canonicalize_request(r#"{"path":"app\\fonts.js","import":"","variableName":"myFont"}"#),
+            r#"{"import":"","path":"app/fonts.js","variableName":"myFont"}"#,
+        );
+    }
+}
@@ -27,6 +27,7 @@ use crate::{
         NextFontGoogleCssModuleReplacer, NextFontGoogleFontFileReplacer, NextFontGoogleReplacer,
         GOOGLE_FONTS_INTERNAL_PREFIX,
     },
+    next_resolve_interceptor::insert_resolve_interceptors,
     next_server::context::ServerContextType,
     util::NextRuntime,
 };
@@ -239,6 +240,8 @@ pub async fn get_next_client_import_map(
 
     insert_turbopack_dev_alias(&mut import_map).await?;
 
+    insert_resolve_interceptors(&mut import_map, next_config).await?;
+
     Ok(import_map.cell())
 }
 
@@ -386,6 +389,8 @@ pub async fn get_next_server_import_map(
     )
     .await?;
 
+    insert_resolve_interceptors(&mut import_map, next_config).await?;
+
     Ok(import_map.cell())
 }
 
@@ -513,6 +518,8 @@ pub async fn get_next_edge_import_map(
         }
     }
 
+    insert_resolve_interceptors(&mut import_map, next_config).await?;
+
     Ok(import_map.cell())
 }
 
@@ -886,7 +893,7 @@ async fn insert_next_shared_aliases(
     // TODO: Add BeforeResolve plugins for `@next/font/google`
 
     let next_font_google_replacer_mapping = ImportMapping::Dynamic(ResolvedVc::upcast(
-        NextFontGoogleReplacer::new(*project_path)
+        NextFontGoogleReplacer::new(*project_path, next_config)
             .to_resolved()
             .await?,
     ))
@@ -907,7 +914,7 @@ async fn insert_next_shared_aliases(
     import_map.insert_alias(
         AliasPattern::exact("@vercel/turbopack-next/internal/font/google/cssmodule.module.css"),
         ImportMapping::Dynamic(ResolvedVc::upcast(
-            NextFontGoogleCssModuleReplacer::new(*project_path, execution_context)
+            NextFontGoogleCssModuleReplacer::new(*project_path, execution_context, next_config)
                 .to_resolved()
                 .await?,
         ))
@@ -917,7 +924,7 @@ async fn insert_next_shared_aliases(
     import_map.insert_alias(
         AliasPattern::exact(GOOGLE_FONTS_INTERNAL_PREFIX),
         ImportMapping::Dynamic(ResolvedVc::upcast(
-            NextFontGoogleFontFileReplacer::new(*project_path)
+            NextFontGoogleFontFileReplacer::new(*project_path, next_config)
                 .to_resolved()
                 .await?,
         ))
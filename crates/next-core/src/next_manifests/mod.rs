@@ -2,7 +2,7 @@
 
 pub(crate) mod client_reference_manifest;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -30,7 +30,28 @@ pub struct PagesManifest {
 pub struct BuildManifest {
     pub polyfill_files: Vec<ResolvedVc<Box<dyn OutputAsset>>>,
     pub root_main_files: Vec<ResolvedVc<Box<dyn OutputAsset>>>,
+    /// The root main files whose content is inlined into the HTML, keyed by
+    /// the app page.
+    pub inlined_root_main_files: FxIndexMap<RcStr, Vec<ResolvedVc<Box<dyn OutputAsset>>>>,
+    /// The chunks of the dynamic imports with a `webpackPrefetch` or
+    /// `webpackPreload` comment, keyed by the page.
+    pub resource_hints: FxIndexMap<RcStr, ResourceHintFiles>,
     pub pages: FxIndexMap<RcStr, Vc<OutputAssets>>,
+    /// Whether the client chunks are ES modules, which have to be loaded with
+    /// `<script type="module">`.
+    pub esm_chunks: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct ResourceHintFiles {
+    pub prefetch: Vec<ResolvedVc<Box<dyn OutputAsset>>>,
+    pub preload: Vec<ResolvedVc<Box<dyn OutputAsset>>>,
+}
+
+impl ResourceHintFiles {
+    pub fn is_empty(&self) -> bool {
+        self.prefetch.is_empty() && self.preload.is_empty()
+    }
 }
 
 impl BuildManifest {
@@ -49,8 +70,44 @@ impl BuildManifest {
             pub polyfill_files: Vec<RcStr>,
             pub low_priority_files: Vec<RcStr>,
             pub root_main_files: Vec<RcStr>,
+            #[serde(skip_serializing_if = "FxIndexMap::is_empty")]
+            pub inlined_root_main_files: FxIndexMap<RcStr, FxIndexMap<RcStr, RcStr>>,
+            #[serde(skip_serializing_if = "FxIndexMap::is_empty")]
+            pub resource_hints: FxIndexMap<RcStr, SerializedResourceHints>,
             pub pages: FxIndexMap<RcStr, Vec<RcStr>>,
             pub amp_first_pages: Vec<RcStr>,
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            pub esm_chunks: bool,
+        }
+
+        #[derive(Serialize, Default, Debug)]
+        pub struct SerializedResourceHints {
+            pub prefetch: Vec<RcStr>,
+            pub preload: Vec<RcStr>,
+        }
+
+        let client_relative_paths = |chunks: &[ResolvedVc<Box<dyn OutputAsset>>]| {
+            chunks
+                .iter()
+                .copied()
+                .map(|chunk| async move {
+                    let chunk_path = chunk.ident().path().await?;
+                    Ok(client_relative_path_ref
+                        .get_path_to(&chunk_path)
+                        .context("failed to resolve client-relative path to hinted chunk")?
+                        .into())
+                })
+                .try_join()
+        };
+        let mut resource_hints = FxIndexMap::default();
+        for (page, files) in &self.resource_hints {
+            resource_hints.insert(
+                page.clone(),
+                SerializedResourceHints {
+                    prefetch: client_relative_paths(&files.prefetch).await?,
+                    preload: client_relative_paths(&files.preload).await?,
+                },
+            );
         }
 
         let pages: Vec<(RcStr, Vec<RcStr>)> = self
@@ -105,10 +162,40 @@ impl BuildManifest {
             .try_join()
             .await?;
 
+        let inlined_root_main_files: Vec<(RcStr, FxIndexMap<RcStr, RcStr>)> = self
+            .inlined_root_main_files
+            .iter()
+            .map(|(page, chunks)| async move {
+                let files = chunks
+                    .iter()
+                    .copied()
+                    .map(|chunk| async move {
+                        let chunk_path = chunk.ident().path().await?;
+                        let path: RcStr = client_relative_path_ref
+                            .get_path_to(&chunk_path)
+                            .context("failed to resolve client-relative path to inlined file")?
+                            .into();
+                        let content = chunk.content().file_content().await?;
+                        let content = match content.as_content() {
+                            Some(file) => strip_source_mapping_url(file.content().to_str()?),
+                            None => "".into(),
+                        };
+                        Ok((path, content))
+                    })
+                    .try_join()
+                    .await?;
+                Ok((page.clone(), FxIndexMap::from_iter(files)))
+            })
+            .try_join()
+            .await?;
+
         let manifest = SerializedBuildManifest {
             pages: FxIndexMap::from_iter(pages.into_iter()),
             polyfill_files,
             root_main_files,
+            inlined_root_main_files: FxIndexMap::from_iter(inlined_root_main_files),
+            resource_hints,
+            esm_chunks: self.esm_chunks,
             ..Default::default()
         };
 
@@ -134,6 +221,18 @@ impl BuildManifest {
     }
 }
 
+/// Removes the trailing `//# sourceMappingURL=` comment, as it's relative to
+/// the chunk file, which doesn't apply to an inline script.
+fn strip_source_mapping_url(content: &str) -> RcStr {
+    let trimmed = content.trim_end();
+    match trimmed.rfind('\n') {
+        Some(index) if trimmed[index + 1..].starts_with("//# sourceMappingURL=") => {
+            trimmed[..index].into()
+        }
+        _ => content.into(),
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase", tag = "version")]
 #[allow(clippy::large_enum_variant)]
@@ -260,6 +359,46 @@ pub struct NextFontManifest {
     pub app: HashMap<RcStr, Vec<RcStr>>,
     pub app_using_size_adjust: bool,
     pub pages_using_size_adjust: bool,
+    /// The fonts generated by `next/font`, keyed by their font family, for
+    /// runtime tooling, e.g. to debug layout shift.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub fonts: BTreeMap<RcStr, NextFontManifestFont>,
+    /// The font files left out of the preloads because no module of the page
+    /// uses their font, e.g. when they're part of a CSS chunk shared with other
+    /// pages. Keyed like `pages` and `app`.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub pruned_preloads: HashMap<RcStr, Vec<RcStr>>,
+}
+
+#[derive(Serialize, Default, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NextFontManifestFont {
+    /// The font families rendered until the font is loaded, in order, e.g.
+    /// `["Roboto Fallback", "sans-serif"]`.
+    pub fallbacks: Vec<RcStr>,
+    /// Whether one of the fallbacks is adjusted with `size-adjust` to match
+    /// the metrics of the font.
+    pub has_size_adjust: bool,
+}
+
+/// The CSS chunks of each route for the themes configured through
+/// `experimental.turbo.themes`, keyed by theme id and then by route, and
+/// relative to the `_next` directory. They replace the stylesheets of the
+/// route when rendering it with the theme.
+#[derive(Serialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeManifest {
+    pub themes: BTreeMap<RcStr, BTreeMap<RcStr, Vec<RcStr>>>,
+}
+
+/// Maps the logical file names of client chunks to the file names they're
+/// emitted with when `experimental.turbo.contentHashing` is enabled, both
+/// relative to the `_next` directory, e.g. `static/chunks/pages_index_2a0a5f._.js`
+/// to `static/chunks/pages_index_2a0a5f._.4f1c2b9e.js`.
+#[derive(Serialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkHashManifest {
+    pub chunks: BTreeMap<RcStr, RcStr>,
 }
 
 #[derive(Serialize, Default, Debug)]
@@ -528,4 +667,13 @@ mod tests {
 
         assert_eq!(matchers, deserialized);
     }
+
+    #[test]
+    fn test_strip_source_mapping_url() {
+        assert_eq!(
+            strip_source_mapping_url("runtime();\n//# sourceMappingURL=runtime.js.map\n"),
+            "runtime();"
+        );
+        assert_eq!(strip_source_mapping_url("runtime();\n"), "runtime();\n");
+    }
 }
@@ -0,0 +1,141 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, RwLock},
+};
+
+use anyhow::{bail, Result};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Value, Vc};
+use turbo_tasks_fs::{to_sys_path, FileSystemPath};
+use turbopack_core::resolve::{
+    options::{
+        ImportMap, ImportMapResult, ImportMapping, ImportMappingReplacement, ReplacedImportMapping,
+    },
+    parse::Request,
+    pattern::Pattern,
+    AliasPattern, ResolveResult, ResolveResultItem,
+};
+
+use crate::next_config::NextConfig;
+
+/// What to do with a request intercepted by a `resolve` callback of
+/// `experimental.turbo.resolveInterceptors`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResolveInterception {
+    /// Resolve the request as usual.
+    Unchanged,
+    /// Resolve this request instead, relative to the importing module.
+    Replace(RcStr),
+    /// Resolve to an empty module.
+    Empty,
+}
+
+/// Calls the `resolve` callbacks configured in next.config, which live in the
+/// Node.js process running Next.js.
+pub trait ResolveInterceptorCallback: Send + Sync {
+    /// Calls the callback of the interceptor at index `interceptor` with the
+    /// request and the directory of the importing module.
+    fn intercept(
+        &self,
+        interceptor: u32,
+        request: RcStr,
+        context: RcStr,
+    ) -> Pin<Box<dyn Future<Output = Result<ResolveInterception>> + Send>>;
+}
+
+static RESOLVE_INTERCEPTOR_CALLBACK: RwLock<Option<Arc<dyn ResolveInterceptorCallback>>> =
+    RwLock::new(None);
+
+/// Sets the callback used for interceptors without a static replacement.
+///
+/// The results of the callback are cached like any other resolve result, so
+/// the callback has to return the same result for the same request.
+pub fn set_resolve_interceptor_callback(callback: Option<Arc<dyn ResolveInterceptorCallback>>) {
+    *RESOLVE_INTERCEPTOR_CALLBACK.write().unwrap() = callback;
+}
+
+/// Adds the `experimental.turbo.resolveInterceptors` to the import map. They
+/// replace existing aliases for the same requests, so this is called after all
+/// other aliases are inserted.
+pub async fn insert_resolve_interceptors(
+    import_map: &mut ImportMap,
+    next_config: Vc<NextConfig>,
+) -> Result<()> {
+    for (index, interceptor) in next_config.resolve_interceptors().await?.iter().enumerate() {
+        let mapping = match &interceptor.replacement {
+            Some(replacement) => {
+                ImportMapping::PrimaryAlternative(replacement.clone(), None).resolved_cell()
+            }
+            None => ImportMapping::Dynamic(ResolvedVc::upcast(
+                NextResolveInterceptor::new(index as u32)
+                    .to_resolved()
+                    .await?,
+            ))
+            .resolved_cell(),
+        };
+        import_map.insert_alias(AliasPattern::parse(interceptor.test.clone()), mapping);
+    }
+    Ok(())
+}
+
+/// Resolves requests through the `resolve` callback of an interceptor.
+#[turbo_tasks::value(shared)]
+pub struct NextResolveInterceptor {
+    interceptor: u32,
+}
+
+#[turbo_tasks::value_impl]
+impl NextResolveInterceptor {
+    #[turbo_tasks::function]
+    pub fn new(interceptor: u32) -> Vc<Self> {
+        NextResolveInterceptor { interceptor }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ImportMappingReplacement for NextResolveInterceptor {
+    /// Defers to [Self::result] for requests matching a wildcard pattern.
+    #[turbo_tasks::function]
+    fn replace(self: ResolvedVc<Self>, _capture: Vc<Pattern>) -> Vc<ReplacedImportMapping> {
+        ReplacedImportMapping::Dynamic(ResolvedVc::upcast(self)).cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn result(
+        &self,
+        lookup_path: Vc<FileSystemPath>,
+        request: Vc<Request>,
+    ) -> Result<Vc<ImportMapResult>> {
+        let Some(request) = request.await?.request() else {
+            return Ok(ImportMapResult::NoEntry.cell());
+        };
+        let context: RcStr = match to_sys_path(lookup_path).await? {
+            Some(path) => path.to_string_lossy().into(),
+            None => lookup_path.await?.path.clone(),
+        };
+        let Some(callback) = RESOLVE_INTERCEPTOR_CALLBACK.read().unwrap().clone() else {
+            bail!(
+                "The resolve interceptor for \"{request}\" has neither a replacement nor a \
+                 resolve callback"
+            );
+        };
+
+        Ok(match callback
+            .intercept(self.interceptor, request, context)
+            .await?
+        {
+            ResolveInterception::Unchanged => ImportMapResult::NoEntry,
+            ResolveInterception::Replace(request) => ImportMapResult::Alias(
+                Request::parse(Value::new(Pattern::Constant(request)))
+                    .to_resolved()
+                    .await?,
+                None,
+            ),
+            ResolveInterception::Empty => ImportMapResult::Result(
+                ResolveResult::primary(ResolveResultItem::Empty).resolved_cell(),
+            ),
+        }
+        .cell())
+    }
+}
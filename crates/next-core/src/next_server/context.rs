@@ -26,7 +26,10 @@ use turbopack_core::{
     free_var_references,
     target::CompileTarget,
 };
-use turbopack_ecmascript::references::esm::UrlRewriteBehavior;
+use turbopack_ecmascript::{
+    federation::{resolve::ModuleFederationResolvePlugin, ModuleFederationOptions},
+    references::esm::UrlRewriteBehavior,
+};
 use turbopack_ecmascript_plugins::transform::directives::{
     client::ClientDirectiveTransformer, client_disallowed::ClientDisallowedDirectiveTransformer,
 };
@@ -45,11 +48,12 @@ use crate::{
     mode::NextMode,
     next_build::get_postcss_package_mapping,
     next_client::RuntimeEntries,
-    next_config::NextConfig,
+    next_config::{NextConfig, ReactSingletonMode},
     next_font::local::NextFontLocalResolvePlugin,
     next_import_map::get_next_server_import_map,
     next_server::resolve::ExternalPredicate,
     next_shared::{
+        react_singleton::ReactSingletonResolvePlugin,
         resolve::{
             get_invalid_client_only_resolve_plugin, get_invalid_styled_jsx_resolve_plugin,
             ModuleFeatureReportResolvePlugin, NextExternalResolvePlugin,
@@ -71,8 +75,8 @@ use crate::{
         get_typescript_transform_options,
     },
     util::{
-        foreign_code_context_condition, get_transpiled_packages, load_next_js_templateon,
-        NextRuntime,
+        foreign_code_context_condition, get_package_store, get_resolve_modules,
+        get_restricted_imports, get_transpiled_packages, load_next_js_templateon, NextRuntime,
     },
 };
 
@@ -210,6 +214,18 @@ pub async fn get_server_resolve_options_context(
         custom_conditions.push("react-server".into());
     };
 
+    custom_conditions.extend(
+        next_config
+            .condition_names(if ty.supports_react_server() {
+                vec!["server".into(), "react-server".into()]
+            } else {
+                vec!["server".into()]
+            })
+            .await?
+            .iter()
+            .cloned(),
+    );
+
     let external_cjs_modules_plugin = if *next_config.bundle_pages_router_dependencies().await? {
         server_external_packages_plugin
     } else {
@@ -237,7 +253,7 @@ pub async fn get_server_resolve_options_context(
         | ServerContextType::AppRSC { .. } => {
             vec![
                 ResolvedVc::upcast(
-                    NextFontLocalResolvePlugin::new(*project_path)
+                    NextFontLocalResolvePlugin::new(*project_path, next_config)
                         .to_resolved()
                         .await?,
                 ),
@@ -253,7 +269,7 @@ pub async fn get_server_resolve_options_context(
         }
     };
 
-    let after_resolve_plugins = match ty {
+    let mut after_resolve_plugins = match ty {
         ServerContextType::Pages { .. }
         | ServerContextType::PagesApi { .. }
         | ServerContextType::PagesData { .. } => {
@@ -284,6 +300,16 @@ pub async fn get_server_resolve_options_context(
         }
     };
 
+    // Runs after the externals plugins, so that externals are left to the
+    // Node.js resolution.
+    if let Some(mode) = *next_config.react_singleton().await? {
+        after_resolve_plugins.push(ResolvedVc::upcast(
+            ReactSingletonResolvePlugin::new(*project_path, mode == ReactSingletonMode::Enforce)
+                .to_resolved()
+                .await?,
+        ));
+    }
+
     // Inject resolve plugin to assert incorrect import to client|server-only for
     // the corresponding context. Refer https://github.com/vercel/next.js/blob/ad15817f0368ba154bed6d85320335d4b67b7348/packages/next/src/build/webpack-config.ts#L1205-L1235
     // how it is applied in the webpack config.
@@ -291,6 +317,26 @@ pub async fn get_server_resolve_options_context(
     // code, we use resolve plugin to detect original import directly. This
     // means each resolve plugin must be injected only for the context where the
     // alias resolves into the error. The alias lives in here: https://github.com/vercel/next.js/blob/0060de1c4905593ea875fa7250d4b5d5ce10897d/packages/next-swc/crates/next-core/src/next_import_map.rs#L534
+    // Modules of remotes are only loaded in the browser, but need to resolve
+    // for server rendering. Shared modules don't need to be negotiated, as
+    // the server only runs this build.
+    if matches!(
+        ty,
+        ServerContextType::Pages { .. } | ServerContextType::AppSSR { .. }
+    ) {
+        if let Some(options) = *next_config.module_federation_options(*project_path).await? {
+            let options = ModuleFederationOptions {
+                shared: Default::default(),
+                ..(*options.await?).clone()
+            };
+            before_resolve_plugins.push(ResolvedVc::upcast(
+                ModuleFederationResolvePlugin::new(*project_path, options.cell())
+                    .to_resolved()
+                    .await?,
+            ));
+        }
+    }
+
     match ty {
         ServerContextType::Pages { .. } | ServerContextType::PagesApi { .. } => {
             //noop
@@ -311,14 +357,31 @@ pub async fn get_server_resolve_options_context(
         }
     }
 
+    let package_conditions = next_config
+        .package_conditions(if ty.supports_react_server() {
+            vec!["react-server".into(), "server".into()]
+        } else {
+            vec!["server".into()]
+        })
+        .await?
+        .clone_value();
+
     let resolve_options_context = ResolveOptionsContext {
         enable_node_modules: Some(root_dir),
+        enable_yarn_pnp: Some(project_path),
         enable_node_externals: true,
         enable_node_native_modules: true,
         module: true,
         custom_conditions,
+        package_conditions,
         import_map: Some(next_server_import_map),
         before_resolve_plugins,
+        preserve_symlinks: *next_config.preserve_symlinks().await?,
+        case_sensitive_paths: *next_config.case_sensitive_paths().await?,
+        restricted_imports: get_restricted_imports(next_config, *project_path).await?,
+        pnpm_injected_from_source: *next_config.pnpm_injected_from_source().await?,
+        package_store: get_package_store(next_config, *project_path).await?,
+        modules: get_resolve_modules(next_config, *project_path).await?,
         after_resolve_plugins,
         ..Default::default()
     };
@@ -519,8 +582,13 @@ pub async fn get_server_module_options_context(
         get_swc_ecma_transform_plugin_rule(next_config, project_path).await?,
         get_relay_transform_rule(next_config, project_path).await?,
         get_emotion_transform_rule(next_config).await?,
-        get_react_remove_properties_transform_rule(next_config).await?,
-        get_remove_console_transform_rule(next_config).await?,
+        get_react_remove_properties_transform_rule(
+            next_config,
+            project_path,
+            next_mode.is_development(),
+        )
+        .await?,
+        get_remove_console_transform_rule(next_config, next_mode.is_development()).await?,
     ]
     .into_iter()
     .flatten()
@@ -544,12 +612,14 @@ pub async fn get_server_module_options_context(
             ..Default::default()
         },
         tree_shaking_mode: tree_shaking_mode_for_user_code,
-        side_effect_free_packages: next_config.optimize_package_imports().await?.clone_value(),
+        side_effect_free_packages: next_config.side_effect_free_packages().await?.clone_value(),
         enable_externals_tracing: if next_mode.is_production() {
             Some(project_path)
         } else {
             None
         },
+        // Must match the client, so that the server rendered URLs hydrate
+        asset_inline_limit: *next_config.asset_inline_limit().await?,
         ..Default::default()
     };
 
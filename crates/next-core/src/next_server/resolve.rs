@@ -1,7 +1,7 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use turbo_rcstr::RcStr;
-use turbo_tasks::{trace::TraceRawVcs, ResolvedVc, Value, Vc};
+use turbo_tasks::{trace::TraceRawVcs, FxIndexMap, NonLocalValue, ResolvedVc, Value, Vc};
 use turbo_tasks_fs::{self, glob::Glob, FileJsonContent, FileSystemPath};
 use turbopack_core::{
     issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
@@ -103,12 +103,32 @@ impl AfterResolvePlugin for ExternalCjsModulesResolvePlugin {
             return Ok(ResolveResultOption::none());
         }
 
+        let project_dependencies =
+            package_dependencies(self.project_path.join("package.json".into())).await?;
+        let importer_dependencies = importer_package_dependencies(*lookup_path).await?;
+
+        // Packages of the workspace (`workspace:` dependencies) are linked sources, which are
+        // bundled like the code of the project.
+        if project_dependencies.is_workspace_package(package)
+            || importer_dependencies.is_workspace_package(package)
+        {
+            return Ok(ResolveResultOption::none());
+        }
+
         let raw_fs_path = &*fs_path.await?;
 
         let predicate = self.predicate.await?;
         let must_be_external = match &*predicate {
             ExternalPredicate::AllExcept(exceptions) => {
-                if *condition(*self.root).matches(*lookup_path).await? {
+                // A workspace package can end up in node_modules, e.g. when it's injected or
+                // hoisted. Its imports are treated like the ones of the project.
+                let importer_is_workspace_package = importer_dependencies
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| project_dependencies.is_workspace_package(name));
+                if !importer_is_workspace_package
+                    && *condition(*self.root).matches(*lookup_path).await?
+                {
                     return Ok(ResolveResultOption::none());
                 }
 
@@ -262,6 +282,13 @@ impl AfterResolvePlugin for ExternalCjsModulesResolvePlugin {
 
         let Some(result) = *node_resolved.first_source().await? else {
             // this can't resolve with node.js from the project directory, so bundle it
+            let install_command = match importer_dependencies.dependencies.get(package) {
+                // pnpm resolves catalog dependencies to the same version in every package
+                Some(DependencySpecifier::Catalog(catalog)) => {
+                    format!("pnpm add {package}@catalog:{catalog}")
+                }
+                _ => format!("npm install {package}"),
+            };
             return unable_to_externalize(vec![
                 StyledString::Text(
                     "The request could not be resolved by Node.js from the project \
@@ -270,7 +297,7 @@ impl AfterResolvePlugin for ExternalCjsModulesResolvePlugin {
                      install it into the project directory by running "
                         .into(),
                 ),
-                StyledString::Code(format!("npm install {package}").into()),
+                StyledString::Code(install_command.into()),
                 StyledString::Text(" from the project directory.".into()),
             ]);
         };
@@ -428,6 +455,90 @@ impl AfterResolvePlugin for ExternalCjsModulesResolvePlugin {
     }
 }
 
+/// How a dependency is declared in a package.json.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+enum DependencySpecifier {
+    /// `workspace:*`, `workspace:^` etc., a package of the same pnpm or yarn workspace.
+    Workspace,
+    /// `catalog:` or `catalog:<name>`, a version defined by a pnpm catalog. Holds the name of the
+    /// catalog, empty for the default one.
+    Catalog(RcStr),
+    /// A version range, tag, URL or any other specifier.
+    Other,
+}
+
+impl DependencySpecifier {
+    fn parse(specifier: &str) -> Self {
+        if specifier.starts_with("workspace:") {
+            DependencySpecifier::Workspace
+        } else if let Some(catalog) = specifier.strip_prefix("catalog:") {
+            DependencySpecifier::Catalog(catalog.trim().into())
+        } else {
+            DependencySpecifier::Other
+        }
+    }
+}
+
+/// The name and the dependencies of a package, from its package.json.
+#[turbo_tasks::value]
+#[derive(Default)]
+struct PackageDependencies {
+    name: Option<RcStr>,
+    dependencies: FxIndexMap<RcStr, DependencySpecifier>,
+}
+
+impl PackageDependencies {
+    fn is_workspace_package(&self, name: &str) -> bool {
+        matches!(
+            self.dependencies.get(name),
+            Some(DependencySpecifier::Workspace)
+        )
+    }
+}
+
+#[turbo_tasks::function]
+async fn package_dependencies(package_json: Vc<FileSystemPath>) -> Result<Vc<PackageDependencies>> {
+    let FileJsonContent::Content(package) = &*package_json.read_json().await? else {
+        return Ok(PackageDependencies::default().cell());
+    };
+    let mut dependencies = FxIndexMap::default();
+    for field in [
+        "dependencies",
+        "devDependencies",
+        "optionalDependencies",
+        "peerDependencies",
+    ] {
+        let Some(field) = package[field].as_object() else {
+            continue;
+        };
+        for (name, specifier) in field {
+            if let Some(specifier) = specifier.as_str() {
+                dependencies
+                    .entry(name.as_str().into())
+                    .or_insert_with(|| DependencySpecifier::parse(specifier));
+            }
+        }
+    }
+    Ok(PackageDependencies {
+        name: package["name"].as_str().map(RcStr::from),
+        dependencies,
+    }
+    .cell())
+}
+
+/// The dependencies of the package containing `lookup_path`.
+#[turbo_tasks::function]
+async fn importer_package_dependencies(
+    lookup_path: Vc<FileSystemPath>,
+) -> Result<Vc<PackageDependencies>> {
+    let FindContextFileResult::Found(package_json, _) =
+        *find_context_file(lookup_path, package_json()).await?
+    else {
+        return Ok(PackageDependencies::default().cell());
+    };
+    Ok(package_dependencies(*package_json))
+}
+
 #[derive(Serialize, Deserialize, TraceRawVcs, PartialEq, Eq, Debug)]
 pub struct PackagesGlobs {
     path_glob: Vc<Glob>,
@@ -505,3 +616,32 @@ impl Issue for ExternalizeIssue {
         )))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DependencySpecifier;
+
+    #[test]
+    fn test_dependency_specifier() {
+        assert_eq!(
+            DependencySpecifier::parse("workspace:*"),
+            DependencySpecifier::Workspace
+        );
+        assert_eq!(
+            DependencySpecifier::parse("workspace:^1.0.0"),
+            DependencySpecifier::Workspace
+        );
+        assert_eq!(
+            DependencySpecifier::parse("catalog:"),
+            DependencySpecifier::Catalog("".into())
+        );
+        assert_eq!(
+            DependencySpecifier::parse("catalog:react18"),
+            DependencySpecifier::Catalog("react18".into())
+        );
+        assert_eq!(
+            DependencySpecifier::parse("^18.2.0"),
+            DependencySpecifier::Other
+        );
+    }
+}
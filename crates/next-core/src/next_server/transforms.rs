@@ -7,17 +7,20 @@ use crate::{
     mode::NextMode,
     next_config::NextConfig,
     next_server::context::ServerContextType,
-    next_shared::transforms::{
-        get_next_dynamic_transform_rule, get_next_font_transform_rule, get_next_image_rule,
-        get_next_lint_transform_rule, get_next_modularize_imports_rule,
-        get_next_pages_transforms_rule, get_server_actions_transform_rule,
-        next_amp_attributes::get_next_amp_attr_rule,
-        next_cjs_optimizer::get_next_cjs_optimizer_rule,
-        next_disallow_re_export_all_in_page::get_next_disallow_export_all_in_page_rule,
-        next_edge_node_api_assert::next_edge_node_api_assert,
-        next_middleware_dynamic_assert::get_middleware_dynamic_assert_rule,
-        next_page_static_info::get_next_page_static_info_assert_rule,
-        next_pure::get_next_pure_rule, server_actions::ActionsTransform,
+    next_shared::{
+        module_federation::get_module_federation_rule,
+        transforms::{
+            get_next_dynamic_transform_rule, get_next_font_transform_rule, get_next_image_rule,
+            get_next_lint_transform_rule, get_next_modularize_imports_rule,
+            get_next_pages_transforms_rule, get_server_actions_transform_rule,
+            next_amp_attributes::get_next_amp_attr_rule,
+            next_cjs_optimizer::get_next_cjs_optimizer_rule,
+            next_disallow_re_export_all_in_page::get_next_disallow_export_all_in_page_rule,
+            next_edge_node_api_assert::next_edge_node_api_assert,
+            next_middleware_dynamic_assert::get_middleware_dynamic_assert_rule,
+            next_page_static_info::get_next_page_static_info_assert_rule,
+            next_pure::get_next_pure_rule, server_actions::ActionsTransform,
+        },
     },
     util::NextRuntime,
 };
@@ -142,6 +145,7 @@ pub async fn get_next_server_transforms_rules(
         // optimize_use_state))
 
         rules.push(get_next_image_rule().await?);
+        rules.push(get_module_federation_rule().await?);
     }
 
     if let NextRuntime::Edge = next_runtime {
@@ -1,3 +1,5 @@
+pub(crate) mod module_federation;
+pub(crate) mod react_singleton;
 pub(crate) mod resolve;
 pub(crate) mod transforms;
 pub(crate) mod webpack_rules;
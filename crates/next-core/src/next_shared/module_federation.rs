@@ -0,0 +1,64 @@
+use anyhow::{bail, Result};
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::FileJsonContent;
+use turbopack::{
+    module_options::{CustomModuleType, ModuleRule, ModuleRuleEffect, ModuleType, RuleCondition},
+    ModuleAssetContext,
+};
+use turbopack_core::{module::Module, resolve::ModulePart, source::Source};
+use turbopack_ecmascript::federation::{
+    remote_module::RemoteModule, resolve::REMOTE_MODULE_EXTENSION,
+};
+
+/// Module type that turns the sources created by the
+/// `ModuleFederationResolvePlugin` for modules of remotes into
+/// [RemoteModule]s.
+#[turbo_tasks::value]
+pub struct ModuleFederationRemoteModuleType {}
+
+#[turbo_tasks::value_impl]
+impl ModuleFederationRemoteModuleType {
+    #[turbo_tasks::function]
+    pub fn new() -> Vc<Self> {
+        ModuleFederationRemoteModuleType {}.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl CustomModuleType for ModuleFederationRemoteModuleType {
+    #[turbo_tasks::function]
+    async fn create_module(
+        &self,
+        source: Vc<Box<dyn Source>>,
+        _module_asset_context: Vc<ModuleAssetContext>,
+        _part: Option<Vc<ModulePart>>,
+    ) -> Result<Vc<Box<dyn Module>>> {
+        let FileJsonContent::Content(json) = &*source.content().parse_json().await? else {
+            bail!("Invalid module federation remote module");
+        };
+        let field = |name: &str| -> Result<_> {
+            match json[name].as_str() {
+                Some(value) => Ok(value.into()),
+                None => bail!("Module federation remote module is missing `{name}`"),
+            }
+        };
+        Ok(Vc::upcast(RemoteModule::new(
+            field("remote")?,
+            field("entry")?,
+            field("module")?,
+        )))
+    }
+}
+
+pub async fn get_module_federation_rule() -> Result<ModuleRule> {
+    Ok(ModuleRule::new(
+        RuleCondition::ResourcePathEndsWith(REMOTE_MODULE_EXTENSION.to_string()),
+        vec![ModuleRuleEffect::ModuleType(ModuleType::Custom(
+            ResolvedVc::upcast(
+                ModuleFederationRemoteModuleType::new()
+                    .to_resolved()
+                    .await?,
+            ),
+        ))],
+    ))
+}
@@ -0,0 +1,216 @@
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Value, ValueToString, Vc};
+use turbo_tasks_fs::{glob::Glob, FileJsonContent, FileSystemEntryType, FileSystemPath};
+use turbopack_core::{
+    file_source::FileSource,
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    reference_type::ReferenceType,
+    resolve::{
+        parse::Request,
+        plugin::{AfterResolvePlugin, AfterResolvePluginCondition},
+        ResolveResult, ResolveResultOption,
+    },
+};
+
+/// Packages which break at runtime ("Invalid hook call") when more than one
+/// copy of them is loaded.
+const SINGLETON_PACKAGES: [&str; 3] = ["react", "react-dom", "scheduler"];
+
+/// Checks that `react`, `react-dom` and `scheduler` resolve to the copy
+/// hoisted to the `node_modules` of the project (or one of its parent
+/// directories, for monorepos). Files of other copies are aliased to the
+/// hoisted copy when `enforce` is set and both have the same version, and
+/// are reported otherwise.
+#[turbo_tasks::value]
+pub(crate) struct ReactSingletonResolvePlugin {
+    root: ResolvedVc<FileSystemPath>,
+    enforce: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl ReactSingletonResolvePlugin {
+    #[turbo_tasks::function]
+    pub fn new(root: ResolvedVc<FileSystemPath>, enforce: bool) -> Vc<Self> {
+        ReactSingletonResolvePlugin { root, enforce }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl AfterResolvePlugin for ReactSingletonResolvePlugin {
+    #[turbo_tasks::function]
+    fn after_resolve_condition(&self) -> Vc<AfterResolvePluginCondition> {
+        AfterResolvePluginCondition::new(
+            self.root.root(),
+            Glob::new("**/node_modules/{react,react-dom,scheduler}/**".into()),
+        )
+    }
+
+    #[turbo_tasks::function]
+    async fn after_resolve(
+        &self,
+        fs_path: Vc<FileSystemPath>,
+        _lookup_path: Vc<FileSystemPath>,
+        _reference_type: Value<ReferenceType>,
+        _request: Vc<Request>,
+    ) -> Result<Vc<ResolveResultOption>> {
+        let raw_fs_path = &*fs_path.await?;
+        // The glob also matches the dependencies nested in the packages, so
+        // only the innermost `node_modules` is relevant.
+        let Some(index) = raw_fs_path.path.rfind("node_modules/") else {
+            return Ok(ResolveResultOption::none());
+        };
+        let package_start = index + "node_modules/".len();
+        let Some((package, subpath)) = raw_fs_path.path[package_start..].split_once('/') else {
+            return Ok(ResolveResultOption::none());
+        };
+        if !SINGLETON_PACKAGES.contains(&package) {
+            return Ok(ResolveResultOption::none());
+        }
+
+        let Some(hoisted_root) = find_hoisted_package(*self.root, package).await? else {
+            return Ok(ResolveResultOption::none());
+        };
+        let package_root = fs_path
+            .root()
+            .join(raw_fs_path.path[..package_start + package.len()].into());
+        if package_root.realpath().await?.path == hoisted_root.realpath().await?.path {
+            return Ok(ResolveResultOption::none());
+        }
+
+        let version = package_version(package_root).await?;
+        let hoisted_version = package_version(hoisted_root).await?;
+        if self.enforce && version.is_some() && version == hoisted_version {
+            let new_path = hoisted_root.join(subpath.into());
+            if matches!(&*new_path.get_type().await?, FileSystemEntryType::File) {
+                return Ok(Vc::cell(Some(
+                    ResolveResult::source(ResolvedVc::upcast(
+                        FileSource::new(new_path).to_resolved().await?,
+                    ))
+                    .resolved_cell(),
+                )));
+            }
+        }
+
+        DuplicateReactPackageIssue {
+            file_path: package_root
+                .join("package.json".into())
+                .to_resolved()
+                .await?,
+            package: package.into(),
+            duplicate_path: package_root.to_resolved().await?,
+            duplicate_version: version,
+            hoisted_path: hoisted_root.to_resolved().await?,
+            hoisted_version,
+            severity: if self.enforce {
+                IssueSeverity::Error
+            } else {
+                IssueSeverity::Warning
+            }
+            .resolved_cell(),
+        }
+        .resolved_cell()
+        .emit();
+
+        Ok(ResolveResultOption::none())
+    }
+}
+
+/// Looks up `node_modules/<package>` in `root` and its parent directories.
+async fn find_hoisted_package(
+    root: Vc<FileSystemPath>,
+    package: &str,
+) -> Result<Option<Vc<FileSystemPath>>> {
+    let mut dir = root;
+    loop {
+        let package_root = dir.join(format!("node_modules/{package}").into());
+        if matches!(
+            &*package_root.join("package.json".into()).get_type().await?,
+            FileSystemEntryType::File
+        ) {
+            return Ok(Some(package_root));
+        }
+        if dir.await?.is_root() {
+            return Ok(None);
+        }
+        dir = dir.parent();
+    }
+}
+
+async fn package_version(package_root: Vc<FileSystemPath>) -> Result<Option<RcStr>> {
+    let package_json = package_root.join("package.json".into()).read_json().await?;
+    Ok(match &*package_json {
+        FileJsonContent::Content(json) => json["version"].as_str().map(RcStr::from),
+        _ => None,
+    })
+}
+
+#[turbo_tasks::value(shared)]
+pub struct DuplicateReactPackageIssue {
+    pub file_path: ResolvedVc<FileSystemPath>,
+    pub package: RcStr,
+    pub duplicate_path: ResolvedVc<FileSystemPath>,
+    pub duplicate_version: Option<RcStr>,
+    pub hoisted_path: ResolvedVc<FileSystemPath>,
+    pub hoisted_version: Option<RcStr>,
+    pub severity: ResolvedVc<IssueSeverity>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for DuplicateReactPackageIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        *self.severity
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.into()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Text("Multiple copies of ".into()),
+            StyledString::Code(self.package.clone()),
+            StyledString::Text(" are bundled".into()),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.file_path
+    }
+
+    #[turbo_tasks::function]
+    async fn description(&self) -> Result<Vc<OptionStyledString>> {
+        let version = |version: &Option<RcStr>| version.clone().unwrap_or_else(|| "unknown".into());
+        Ok(Vc::cell(Some(
+            StyledString::Stack(vec![
+                StyledString::Line(vec![
+                    StyledString::Text("A module resolved to ".into()),
+                    StyledString::Code(self.duplicate_path.to_string().await?.clone_value()),
+                    StyledString::Text(
+                        format!(" (version {}),", version(&self.duplicate_version)).into(),
+                    ),
+                ]),
+                StyledString::Line(vec![
+                    StyledString::Text("but the app uses ".into()),
+                    StyledString::Code(self.hoisted_path.to_string().await?.clone_value()),
+                    StyledString::Text(
+                        format!(" (version {}).", version(&self.hoisted_version)).into(),
+                    ),
+                ]),
+                StyledString::Text(
+                    "Loading more than one copy of React causes \"Invalid hook call\" errors. \
+                     Deduplicate the package in your lockfile, or align its version with the \
+                     hoisted copy so that `experimental.turbo.reactSingleton: 'enforce'` can \
+                     alias it."
+                        .into(),
+                ),
+            ])
+            .resolved_cell(),
+        )))
+    }
+}
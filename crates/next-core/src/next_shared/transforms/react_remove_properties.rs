@@ -1,59 +1,127 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use next_custom_transforms::transforms::react_remove_properties::{
+    react_remove_properties, Config, Options,
+};
 use swc_core::ecma::ast::Program;
-use turbo_tasks::Vc;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::FileSystemPath;
 use turbopack::module_options::ModuleRule;
+use turbopack_core::issue::{
+    Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString,
+};
 use turbopack_ecmascript::{CustomTransformer, TransformContext};
 
 use super::get_ecma_transform_rule;
 use crate::next_config::{NextConfig, ReactRemoveProperties};
 
-/// Returns a rule which applies the react_remove_properties transform.
+/// Returns a rule which applies the react_remove_properties transform. Patterns
+/// which aren't valid regular expressions are reported as issues and skipped.
 pub async fn get_react_remove_properties_transform_rule(
     next_config: Vc<NextConfig>,
+    project_path: ResolvedVc<FileSystemPath>,
+    is_development: bool,
 ) -> Result<Option<ModuleRule>> {
     let enable_mdx_rs = next_config.mdx_rs().await?.is_some();
 
-    let module_rule = next_config
-        .compiler()
-        .await?
-        .react_remove_properties
-        .as_ref()
-        .and_then(|config| match config {
-            ReactRemoveProperties::Boolean(false) => None,
-            ReactRemoveProperties::Boolean(true) => {
-                Some(react_remove_properties::Config::All(true))
+    let config = match &next_config.compiler().await?.react_remove_properties {
+        None => return Ok(None),
+        Some(ReactRemoveProperties::Boolean(enabled)) => Config::All(*enabled),
+        Some(ReactRemoveProperties::Config {
+            properties,
+            production_only,
+        }) => {
+            let configured = properties.as_deref().unwrap_or_default();
+            let mut properties = Vec::with_capacity(configured.len());
+            for property in configured {
+                match lazy_regex::Regex::new(property) {
+                    Ok(_) => properties.push(property.clone()),
+                    Err(err) => InvalidPropertyPatternIssue {
+                        config_path: project_path
+                            .join(next_config.await?.config_file_name.clone())
+                            .to_resolved()
+                            .await?,
+                        pattern: property.as_str().into(),
+                        error: err.to_string().into(),
+                    }
+                    .resolved_cell()
+                    .emit(),
+                }
             }
-            ReactRemoveProperties::Config { properties } => Some(
-                react_remove_properties::Config::WithOptions(react_remove_properties::Options {
-                    properties: properties.as_deref().unwrap_or_default().to_owned(),
-                }),
-            ),
-        })
-        .map(|config| {
-            get_ecma_transform_rule(
-                Box::new(ReactRemovePropertiesTransformer { config }),
-                enable_mdx_rs,
-                true,
-            )
-        });
-
-    Ok(module_rule)
+            // Removing the default properties instead would be surprising
+            if properties.is_empty() && !configured.is_empty() {
+                return Ok(None);
+            }
+            Config::WithOptions(Options {
+                properties,
+                production_only: production_only.unwrap_or(false),
+            })
+        }
+    };
+    if !config.is_enabled(is_development) {
+        return Ok(None);
+    }
+
+    Ok(Some(get_ecma_transform_rule(
+        Box::new(ReactRemovePropertiesTransformer { config }),
+        enable_mdx_rs,
+        true,
+    )))
 }
 
 #[derive(Debug)]
 struct ReactRemovePropertiesTransformer {
-    config: react_remove_properties::Config,
+    config: Config,
 }
 
 #[async_trait]
 impl CustomTransformer for ReactRemovePropertiesTransformer {
     #[tracing::instrument(level = tracing::Level::TRACE, name = "react_remove_properties", skip_all)]
     async fn transform(&self, program: &mut Program, _ctx: &TransformContext<'_>) -> Result<()> {
-        program.mutate(react_remove_properties::react_remove_properties(
-            self.config.clone(),
-        ));
+        program.mutate(react_remove_properties(self.config.clone()));
 
         Ok(())
     }
 }
+
+#[turbo_tasks::value]
+struct InvalidPropertyPatternIssue {
+    config_path: ResolvedVc<FileSystemPath>,
+    pattern: RcStr,
+    error: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for InvalidPropertyPatternIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        IssueSeverity::Error.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Config.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.config_path
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Text("Invalid pattern ".into()),
+            StyledString::Code(self.pattern.clone()),
+            StyledString::Text(" in ".into()),
+            StyledString::Code("compiler.reactRemoveProperties".into()),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(StyledString::Text(self.error.clone()).resolved_cell()))
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use next_custom_transforms::transforms::remove_console::{remove_console, Config, Options};
 use swc_core::{common::SyntaxContext, ecma::ast::Program};
 use turbo_tasks::Vc;
 use turbopack::module_options::ModuleRule;
@@ -11,6 +12,7 @@ use crate::next_config::{NextConfig, RemoveConsoleConfig};
 /// Returns a rule which applies the remove_console transform.
 pub async fn get_remove_console_transform_rule(
     next_config: Vc<NextConfig>,
+    is_development: bool,
 ) -> Result<Option<ModuleRule>> {
     let enable_mdx_rs = next_config.mdx_rs().await?.is_some();
 
@@ -19,20 +21,22 @@ pub async fn get_remove_console_transform_rule(
         .await?
         .remove_console
         .as_ref()
-        .and_then(|config| match config {
-            RemoveConsoleConfig::Boolean(false) => None,
-            RemoveConsoleConfig::Boolean(true) => Some(remove_console::Config::All(true)),
-            RemoveConsoleConfig::Config { exclude } => Some(remove_console::Config::WithOptions(
-                remove_console::Options {
-                    exclude: exclude
-                        .as_deref()
-                        .unwrap_or_default()
-                        .iter()
-                        .map(|v| v.clone().into())
-                        .collect(),
-                },
-            )),
+        .map(|config| match config {
+            RemoveConsoleConfig::Boolean(enabled) => Config::All(*enabled),
+            RemoveConsoleConfig::Config {
+                exclude,
+                production_only,
+            } => Config::WithOptions(Options {
+                exclude: exclude
+                    .as_deref()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|v| v.clone().into())
+                    .collect(),
+                production_only: production_only.unwrap_or(false),
+            }),
         })
+        .filter(|config| config.is_enabled(is_development))
         .map(|config| {
             get_ecma_transform_rule(
                 Box::new(RemoveConsoleTransformer { config }),
@@ -46,14 +50,14 @@ pub async fn get_remove_console_transform_rule(
 
 #[derive(Debug)]
 struct RemoveConsoleTransformer {
-    config: remove_console::Config,
+    config: Config,
 }
 
 #[async_trait]
 impl CustomTransformer for RemoveConsoleTransformer {
     #[tracing::instrument(level = tracing::Level::TRACE, name = "remove_console", skip_all)]
     async fn transform(&self, program: &mut Program, ctx: &TransformContext<'_>) -> Result<()> {
-        program.mutate(remove_console::remove_console(
+        program.mutate(remove_console(
             self.config.clone(),
             SyntaxContext::empty().apply_mark(ctx.unresolved_mark),
         ));
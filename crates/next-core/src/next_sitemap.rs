@@ -0,0 +1,245 @@
+use std::{
+    fmt::Write,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use turbo_rcstr::RcStr;
+
+use crate::next_config::SitemapEntry;
+
+/// A `<url>` of a `sitemap.xml`, either discovered from a static route or
+/// configured through `experimental.sitemap.entries`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SitemapUrl {
+    /// An absolute url, or a path relative to the base url.
+    pub url: RcStr,
+    pub last_modified: Option<RcStr>,
+    pub change_frequency: Option<RcStr>,
+    pub priority: Option<f64>,
+}
+
+impl From<&SitemapEntry> for SitemapUrl {
+    fn from(entry: &SitemapEntry) -> Self {
+        SitemapUrl {
+            url: entry.url.clone(),
+            last_modified: entry.last_modified.clone(),
+            change_frequency: entry.change_frequency.clone(),
+            priority: entry.priority,
+        }
+    }
+}
+
+/// Merges the configured entries into the discovered urls. Entries for a
+/// discovered path replace it, the others are appended in order.
+pub fn merge_sitemap_urls(
+    discovered: Vec<SitemapUrl>,
+    entries: &[SitemapEntry],
+) -> Vec<SitemapUrl> {
+    let mut urls = discovered;
+    for entry in entries {
+        let entry = SitemapUrl::from(entry);
+        match urls.iter_mut().find(|url| url.url == entry.url) {
+            Some(url) => *url = entry,
+            None => urls.push(entry),
+        }
+    }
+    urls
+}
+
+/// Whether `path` is `prefix` or nested below it.
+pub fn is_excluded_from_sitemap(path: &str, exclude: &[RcStr]) -> bool {
+    exclude.iter().any(|prefix| {
+        let prefix = prefix.trim_end_matches('/');
+        path == prefix
+            || path
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+pub fn build_sitemap_xml(base_url: &str, urls: &[SitemapUrl]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset \
+         xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for url in urls {
+        xml.push_str("<url>\n");
+        writeln!(
+            xml,
+            "<loc>{}</loc>",
+            escape_xml(&absolute_url(base_url, &url.url))
+        )
+        .unwrap();
+        if let Some(last_modified) = &url.last_modified {
+            writeln!(xml, "<lastmod>{}</lastmod>", escape_xml(last_modified)).unwrap();
+        }
+        if let Some(change_frequency) = &url.change_frequency {
+            writeln!(
+                xml,
+                "<changefreq>{}</changefreq>",
+                escape_xml(change_frequency)
+            )
+            .unwrap();
+        }
+        if let Some(priority) = url.priority {
+            writeln!(xml, "<priority>{priority}</priority>").unwrap();
+        }
+        xml.push_str("</url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+pub fn build_robots_txt(base_url: &str, disallow: &[RcStr]) -> String {
+    let mut robots = String::from("User-Agent: *\nAllow: /\n");
+    for path in disallow {
+        writeln!(robots, "Disallow: {path}").unwrap();
+    }
+    writeln!(
+        robots,
+        "\nSitemap: {}",
+        absolute_url(base_url, "/sitemap.xml")
+    )
+    .unwrap();
+    robots
+}
+
+/// Formats a modification time as the `YYYY-MM-DD` date used in `<lastmod>`.
+pub fn format_last_modified(time: SystemTime) -> RcStr {
+    let days = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() / 86400) as i64,
+        Err(err) => -(err.duration().as_secs().div_ceil(86400) as i64),
+    };
+    let (year, month, day) = civil_from_days(days);
+    format!("{year:04}-{month:02}-{day:02}").into()
+}
+
+/// Converts days since the unix epoch to a proleptic gregorian date, see
+/// <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn absolute_url(base_url: &str, url: &str) -> String {
+    if url.starts_with('/') {
+        format!("{}{}", base_url.trim_end_matches('/'), url)
+    } else {
+        url.to_string()
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn url(url: &str) -> SitemapUrl {
+        SitemapUrl {
+            url: url.into(),
+            last_modified: None,
+            change_frequency: None,
+            priority: None,
+        }
+    }
+
+    #[test]
+    fn test_build_sitemap_xml() {
+        let xml = build_sitemap_xml(
+            "https://example.com/",
+            &[
+                SitemapUrl {
+                    last_modified: Some("2024-01-02".into()),
+                    priority: Some(0.8),
+                    ..url("/")
+                },
+                url("/a?b=1&c=2"),
+                url("https://cdn.example.com/page"),
+            ],
+        );
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset \
+             xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n<url>\n<loc>https://example.com/</loc>\n<lastmod>2024-01-02</lastmod>\n<priority>0.8</priority>\n</url>\n<url>\n<loc>https://example.com/a?b=1&amp;c=2</loc>\n</url>\n<url>\n<loc>https://cdn.example.com/page</loc>\n</url>\n</urlset>\n"
+        );
+    }
+
+    #[test]
+    fn test_build_robots_txt() {
+        assert_eq!(
+            build_robots_txt("https://example.com", &["/admin".into()]),
+            "User-Agent: *\nAllow: /\nDisallow: /admin\n\nSitemap: https://example.com/sitemap.xml\n"
+        );
+    }
+
+    #[test]
+    fn test_merge_sitemap_urls() {
+        let merged = merge_sitemap_urls(
+            vec![url("/"), url("/about")],
+            &[
+                SitemapEntry {
+                    url: "/about".into(),
+                    last_modified: None,
+                    change_frequency: Some("weekly".into()),
+                    priority: None,
+                },
+                SitemapEntry {
+                    url: "/blog/first".into(),
+                    last_modified: None,
+                    change_frequency: None,
+                    priority: None,
+                },
+            ],
+        );
+        assert_eq!(
+            merged,
+            vec![
+                url("/"),
+                SitemapUrl {
+                    change_frequency: Some("weekly".into()),
+                    ..url("/about")
+                },
+                url("/blog/first"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_excluded_from_sitemap() {
+        let exclude = ["/admin/".into()];
+        assert!(is_excluded_from_sitemap("/admin", &exclude));
+        assert!(is_excluded_from_sitemap("/admin/users", &exclude));
+        assert!(!is_excluded_from_sitemap("/administrators", &exclude));
+    }
+
+    #[test]
+    fn test_format_last_modified() {
+        assert_eq!(format_last_modified(UNIX_EPOCH), "1970-01-01");
+        assert_eq!(
+            format_last_modified(UNIX_EPOCH + Duration::from_secs(1_709_251_199)),
+            "2024-02-29"
+        );
+        assert_eq!(
+            format_last_modified(UNIX_EPOCH + Duration::from_secs(951_868_800)),
+            "2000-03-01"
+        );
+    }
+}
@@ -11,16 +11,20 @@ use turbo_tasks::{
     trace::TraceRawVcs, util::WrapFuture, FxIndexMap, FxIndexSet, NonLocalValue, ResolvedVc,
     TaskInput, ValueDefault, ValueToString, Vc,
 };
+use turbo_tasks_fetch::HttpRemoteFetcher;
 use turbo_tasks_fs::{
     self, json::parse_json_rope_with_source_context, rope::Rope, util::join_path, File,
     FileContent, FileSystemPath,
 };
+use turbopack::package_store::PackageStore;
 use turbopack_core::{
     asset::AssetContent,
     condition::ContextCondition,
     ident::AssetIdent,
     issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
     module::Module,
+    remote_source::RemoteImports,
+    resolve::options::{ResolveModules, RestrictedImport},
     source::Source,
     virtual_source::VirtualSource,
 };
@@ -32,7 +36,9 @@ use turbopack_ecmascript::{
 };
 
 use crate::{
+    mode::NextMode,
     next_config::{NextConfig, RouteHas},
+    next_font::google::get_fetch_config,
     next_import_map::get_next_package,
     next_manifests::MiddlewareMatcher,
 };
@@ -110,6 +116,131 @@ pub async fn get_transpiled_packages(
     Ok(Vc::cell(transpile_packages))
 }
 
+/// Maps the directories of `experimental.turbo.resolveModules` to the
+/// locations searched by the resolver before node_modules. Paths starting with
+/// `.` or `/` are relative to the project, other names are looked up in every
+/// parent directory of the importing module, like node_modules.
+pub async fn get_resolve_modules(
+    next_config: Vc<NextConfig>,
+    project_path: Vc<FileSystemPath>,
+) -> Result<Vec<ResolveModules>> {
+    let mut modules = vec![];
+    for dir in next_config.resolve_modules().await?.iter() {
+        if dir.starts_with('.') || dir.starts_with('/') {
+            let Some(dir) = &*project_path
+                .try_join(dir.trim_start_matches('/').into())
+                .await?
+            else {
+                continue;
+            };
+            modules.push(ResolveModules::Path {
+                dir: *dir,
+                excluded_extensions: ResolvedVc::cell(FxIndexSet::default()),
+            });
+        } else {
+            modules.push(ResolveModules::Nested(
+                project_path.root().to_resolved().await?,
+                vec![dir.clone()],
+            ));
+        }
+    }
+    Ok(modules)
+}
+
+/// Maps the rules of `experimental.turbo.restrictedImports` to the resolver,
+/// which matches the importing modules relative to the root of the file
+/// system instead of the project.
+pub async fn get_restricted_imports(
+    next_config: Vc<NextConfig>,
+    project_path: Vc<FileSystemPath>,
+) -> Result<Vec<RestrictedImport>> {
+    let project_path = project_path.await?;
+    Ok(next_config
+        .restricted_imports()
+        .await?
+        .iter()
+        .map(|rule| {
+            let from = rule.from.trim_start_matches("./");
+            RestrictedImport {
+                from: if project_path.path.is_empty() {
+                    from.into()
+                } else {
+                    format!("{}/{from}", project_path.path).into()
+                },
+                disallow: rule.disallow.clone(),
+                message: rule.message.clone(),
+            }
+        })
+        .collect())
+}
+
+/// Sets up importing modules from `https://` URLs when
+/// `experimental.turbo.remoteImports` is configured. The lockfile is frozen in
+/// production builds unless configured otherwise.
+pub async fn get_remote_imports(
+    next_config: Vc<NextConfig>,
+    project_path: Vc<FileSystemPath>,
+    mode: Vc<NextMode>,
+) -> Result<Option<ResolvedVc<RemoteImports>>> {
+    let Some(config) = &*next_config.remote_imports().await? else {
+        return Ok(None);
+    };
+    let cache_dir = match &config.cache_directory {
+        Some(directory) => directory.clone(),
+        None => format!(
+            "{}/cache/remote-imports",
+            next_config.await?.dist_dir.as_deref().unwrap_or(".next")
+        )
+        .into(),
+    };
+    let lockfile = config
+        .lockfile
+        .clone()
+        .unwrap_or_else(|| "remote-imports.lock".into());
+    let fetcher = HttpRemoteFetcher::new(
+        None,
+        get_fetch_config(project_path, next_config)
+            .to_resolved()
+            .await?,
+    );
+    Ok(Some(
+        RemoteImports {
+            cache_dir: project_path.join(cache_dir).to_resolved().await?,
+            lockfile: project_path.join(lockfile).to_resolved().await?,
+            frozen_lockfile: config
+                .frozen_lockfile
+                .unwrap_or(mode.await?.is_production()),
+            fetcher: ResolvedVc::upcast(fetcher.to_resolved().await?),
+        }
+        .resolved_cell(),
+    ))
+}
+
+/// Sets up resolving packages from a global content-addressed store when
+/// `experimental.turbo.packageStore` is configured.
+pub async fn get_package_store(
+    next_config: Vc<NextConfig>,
+    project_path: Vc<FileSystemPath>,
+) -> Result<Option<ResolvedVc<PackageStore>>> {
+    let Some(config) = &*next_config.package_store().await? else {
+        return Ok(None);
+    };
+    let lockfile = config
+        .lockfile
+        .clone()
+        .unwrap_or_else(|| "package-lock.json".into());
+    Ok(Some(
+        PackageStore {
+            lockfile: project_path.join(lockfile).to_resolved().await?,
+            store_dir: project_path
+                .join(config.directory.clone())
+                .to_resolved()
+                .await?,
+        }
+        .resolved_cell(),
+    ))
+}
+
 pub async fn foreign_code_context_condition(
     next_config: Vc<NextConfig>,
     project_path: ResolvedVc<FileSystemPath>,
@@ -25,7 +25,7 @@ use crate::{
         dynamic::{next_dynamic, NextDynamicMode},
         fonts::next_font_loaders,
         lint_codemod_comments::lint_codemod_comments,
-        react_server_components,
+        react_remove_properties, react_server_components, remove_console,
     },
 };
 
@@ -232,14 +232,16 @@ where
             ),
             relay_plugin,
             match &opts.remove_console {
-                Some(config) if config.truthy() => Either::Left(remove_console::remove_console(
-                    config.clone(),
-                    SyntaxContext::empty().apply_mark(unresolved_mark),
-                )),
+                Some(config) if config.is_enabled(opts.is_development) => {
+                    Either::Left(remove_console::remove_console(
+                        config.clone(),
+                        SyntaxContext::empty().apply_mark(unresolved_mark),
+                    ))
+                }
                 _ => Either::Right(noop_pass()),
             },
             match &opts.react_remove_properties {
-                Some(config) if config.truthy() => Either::Left(
+                Some(config) if config.is_enabled(opts.is_development) => Either::Left(
                     react_remove_properties::react_remove_properties(config.clone()),
                 ),
                 _ => Either::Right(noop_pass()),
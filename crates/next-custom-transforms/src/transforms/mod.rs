@@ -14,7 +14,9 @@ pub mod optimize_server_react;
 pub mod page_config;
 pub mod page_static_info;
 pub mod pure;
+pub mod react_remove_properties;
 pub mod react_server_components;
+pub mod remove_console;
 pub mod server_actions;
 pub mod shake_exports;
 pub mod strip_page_exports;
@@ -0,0 +1,92 @@
+use regex::Regex;
+use serde::Deserialize;
+use swc_core::{
+    common::errors::HANDLER,
+    ecma::{
+        ast::*,
+        visit::{visit_mut_pass, VisitMut, VisitMutWith},
+    },
+};
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Config {
+    All(bool),
+    WithOptions(Options),
+}
+
+impl Config {
+    /// Whether properties are removed, taking `productionOnly` into account.
+    pub fn is_enabled(&self, is_development: bool) -> bool {
+        match self {
+            Config::All(b) => *b,
+            Config::WithOptions(options) => !(options.production_only && is_development),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Options {
+    /// Regular expressions matching the names of the properties to remove.
+    /// Defaults to `^data-test`.
+    #[serde(default)]
+    pub properties: Vec<String>,
+    /// Only remove the properties when building for production.
+    #[serde(default)]
+    pub production_only: bool,
+}
+
+const DEFAULT_PROPERTIES: &str = "^data-test";
+
+/// Removes JSX attributes matching the configured patterns, e.g.
+/// `data-testid`, like `babel-plugin-react-remove-properties`.
+///
+/// Patterns which aren't valid regular expressions are reported as errors and
+/// don't remove anything.
+pub fn react_remove_properties(config: Config) -> impl Pass {
+    let properties = match config {
+        Config::WithOptions(options) if !options.properties.is_empty() => options.properties,
+        _ => vec![DEFAULT_PROPERTIES.to_string()],
+    };
+
+    visit_mut_pass(RemoveProperties {
+        properties: properties
+            .iter()
+            .filter_map(|property| match Regex::new(property) {
+                Ok(regex) => Some(regex),
+                Err(err) => {
+                    HANDLER.with(|handler| {
+                        handler
+                            .struct_err(&format!(
+                                "Invalid pattern `{property}` in reactRemoveProperties: {err}"
+                            ))
+                            .emit()
+                    });
+                    None
+                }
+            })
+            .collect(),
+    })
+}
+
+struct RemoveProperties {
+    properties: Vec<Regex>,
+}
+
+impl VisitMut for RemoveProperties {
+    fn visit_mut_jsx_opening_element(&mut self, el: &mut JSXOpeningElement) {
+        el.attrs.retain(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                ..
+            }) => !self
+                .properties
+                .iter()
+                .any(|property| property.is_match(&name.sym)),
+            _ => true,
+        });
+
+        el.visit_mut_children_with(self);
+    }
+}
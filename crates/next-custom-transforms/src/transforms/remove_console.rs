@@ -0,0 +1,157 @@
+use serde::Deserialize;
+use swc_core::{
+    common::{util::take::Take, SyntaxContext, DUMMY_SP},
+    ecma::{
+        ast::*,
+        atoms::Atom,
+        utils::{ExprCtx, ExprExt},
+        visit::{visit_mut_pass, VisitMut, VisitMutWith},
+    },
+};
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Config {
+    All(bool),
+    WithOptions(Options),
+}
+
+impl Config {
+    /// Whether `console` calls are removed, taking `productionOnly` into
+    /// account.
+    pub fn is_enabled(&self, is_development: bool) -> bool {
+        match self {
+            Config::All(b) => *b,
+            Config::WithOptions(options) => !(options.production_only && is_development),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Options {
+    /// The `console` methods to keep, e.g. `error`.
+    #[serde(default)]
+    pub exclude: Vec<Atom>,
+    /// Only remove `console` calls when building for production.
+    #[serde(default)]
+    pub production_only: bool,
+}
+
+/// Removes calls to the global `console`, keeping the arguments which may have
+/// side effects, e.g. `console.log(count++)` becomes `count++`.
+pub fn remove_console(config: Config, unresolved_ctxt: SyntaxContext) -> impl Pass {
+    let exclude = match config {
+        Config::All(_) => vec![],
+        Config::WithOptions(options) => options.exclude,
+    };
+
+    visit_mut_pass(RemoveConsole {
+        exclude,
+        ctx: ExprCtx {
+            unresolved_ctxt,
+            is_unresolved_ref_safe: false,
+            in_strict: false,
+        },
+    })
+}
+
+struct RemoveConsole {
+    exclude: Vec<Atom>,
+    ctx: ExprCtx,
+}
+
+impl RemoveConsole {
+    fn is_removed_call(&self, expr: &Expr) -> bool {
+        let Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) = expr
+        else {
+            return false;
+        };
+        let Expr::Member(MemberExpr { obj, prop, .. }) = &**callee else {
+            return false;
+        };
+        if !obj.is_global_ref_to(&self.ctx, "console") {
+            return false;
+        }
+
+        let method = match prop {
+            MemberProp::Ident(ident) => &ident.sym,
+            MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                Expr::Lit(Lit::Str(str)) => &str.value,
+                _ => return false,
+            },
+            MemberProp::PrivateName(_) => return false,
+        };
+        !self.exclude.contains(method)
+    }
+
+    /// Returns the arguments of the removed call which may have side effects.
+    fn take_side_effects(&mut self, expr: &mut Expr) -> Vec<Box<Expr>> {
+        let Expr::Call(CallExpr { args, .. }) = expr else {
+            unreachable!("only calls are removed");
+        };
+
+        args.take()
+            .into_iter()
+            .map(|mut arg| {
+                arg.expr.visit_mut_with(self);
+                arg.expr
+            })
+            .filter(|arg| arg.may_have_side_effects(&self.ctx))
+            .collect()
+    }
+}
+
+impl VisitMut for RemoveConsole {
+    fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+        let Stmt::Expr(ExprStmt { span, expr }) = stmt else {
+            stmt.visit_mut_children_with(self);
+            return;
+        };
+        if !self.is_removed_call(expr) {
+            stmt.visit_mut_children_with(self);
+            return;
+        }
+
+        let span = *span;
+        let mut side_effects = self.take_side_effects(expr);
+        *stmt = match side_effects.len() {
+            0 => Stmt::Empty(EmptyStmt { span }),
+            1 => Stmt::Expr(ExprStmt {
+                span,
+                expr: side_effects.remove(0),
+            }),
+            _ => Stmt::Expr(ExprStmt {
+                span,
+                expr: SeqExpr {
+                    span: DUMMY_SP,
+                    exprs: side_effects,
+                }
+                .into(),
+            }),
+        };
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        if !self.is_removed_call(expr) {
+            expr.visit_mut_children_with(self);
+            return;
+        }
+
+        // The call is used as a value, which is `undefined` after the side effects
+        let mut exprs = self.take_side_effects(expr);
+        exprs.push(Expr::undefined(DUMMY_SP));
+        *expr = if exprs.len() == 1 {
+            *exprs.remove(0)
+        } else {
+            SeqExpr {
+                span: DUMMY_SP,
+                exprs,
+            }
+            .into()
+        };
+    }
+}
@@ -5,6 +5,7 @@ use next_custom_transforms::transforms::{
     dynamic::{next_dynamic, NextDynamicMode},
     fonts::{next_font_loaders, Config as FontLoaderConfig},
     next_ssg::next_ssg,
+    react_remove_properties::{self, react_remove_properties},
     react_server_components::server_components,
     server_actions::{
         server_actions, {self},
@@ -89,6 +90,26 @@ fn next_ssg_errors(input: PathBuf) {
     );
 }
 
+#[fixture("tests/errors/react-remove-properties/**/input.js")]
+fn react_remove_properties_errors(input: PathBuf) {
+    let output = input.parent().unwrap().join("output.js");
+    let config = react_remove_properties::Config::WithOptions(react_remove_properties::Options {
+        properties: vec!["[".into()],
+        production_only: false,
+    });
+    test_fixture(
+        syntax(),
+        &|_tr| react_remove_properties(config.clone()),
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            module: Some(true),
+            ..Default::default()
+        },
+    );
+}
+
 #[fixture("tests/errors/react-server-components/server-graph/**/input.js")]
 fn react_server_components_server_graph_errors(input: PathBuf) {
     use next_custom_transforms::transforms::react_server_components::{Config, Options};
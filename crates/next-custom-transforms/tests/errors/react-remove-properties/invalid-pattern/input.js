@@ -0,0 +1,3 @@
+export default function Home() {
+  return <div data-test-id="1" />
+}
@@ -16,7 +16,9 @@ use next_custom_transforms::transforms::{
     optimize_server_react::{self, optimize_server_react},
     page_config::page_config_test,
     pure::pure_magic,
+    react_remove_properties::{self, react_remove_properties},
     react_server_components::server_components,
+    remove_console::{self, remove_console},
     server_actions::{self, server_actions},
     shake_exports::{shake_exports, Config as ShakeExportsConfig},
     strip_page_exports::{next_transform_strip_page_exports, ExportFilter},
@@ -810,6 +812,57 @@ fn pure(input: PathBuf) {
     );
 }
 
+#[fixture("tests/fixture/react-remove-properties/**/input.js")]
+fn react_remove_properties_fixture(input: PathBuf) {
+    let output = input.parent().unwrap().join("output.js");
+    let config = if input.to_string_lossy().contains("custom") {
+        react_remove_properties::Config::WithOptions(react_remove_properties::Options {
+            properties: vec!["^data-custom$".into()],
+            production_only: false,
+        })
+    } else {
+        react_remove_properties::Config::All(true)
+    };
+    test_fixture(
+        syntax(),
+        &|_tr| react_remove_properties(config.clone()),
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+#[fixture("tests/fixture/remove-console/**/input.js")]
+fn remove_console_fixture(input: PathBuf) {
+    let output = input.parent().unwrap().join("output.js");
+    let config = if input.to_string_lossy().contains("exclude") {
+        remove_console::Config::WithOptions(remove_console::Options {
+            exclude: vec!["error".into()],
+            production_only: false,
+        })
+    } else {
+        remove_console::Config::All(true)
+    };
+    test_fixture(
+        syntax(),
+        &|_tr| {
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+
+            (
+                resolver(unresolved_mark, top_level_mark, false),
+                remove_console(
+                    config.clone(),
+                    SyntaxContext::empty().apply_mark(unresolved_mark),
+                ),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
 fn run_stip_page_exports_test(input: &Path, output: &Path, mode: ExportFilter) {
     test_fixture(
         syntax(),
@@ -0,0 +1,9 @@
+export default function Home() {
+  return (
+    <div data-test-id="1" data-custom="1a">
+      <div data-custom="2" qa-id="kept">
+        <span className="kept" />
+      </div>
+    </div>
+  )
+}
@@ -0,0 +1,7 @@
+export default function Home() {
+    return <div data-test-id="1">
+      <div qa-id="kept">
+        <span className="kept"/>
+      </div>
+    </div>;
+}
@@ -0,0 +1,7 @@
+export default function Home() {
+    return <div data-custom="1a">
+      <div data-custom="2">
+        <input value="kept" {...props}/>
+      </div>
+    </div>;
+}
@@ -0,0 +1,12 @@
+console.log('hello')
+console.error('oops', 42)
+const result = console.warn('value')
+let count = 0
+console.info(count++, 'count')
+console.debug(track(), 'tracked')
+function log(message) {
+  return console.log(message.toString())
+}
+function shadowed(console) {
+  console.log('kept')
+}
@@ -0,0 +1,12 @@
+;
+;
+const result = void 0;
+let count = 0;
+count++;
+track();
+function log(message) {
+    return message.toString(), void 0;
+}
+function shadowed(console) {
+    console.log('kept');
+}
@@ -0,0 +1,3 @@
+console.log('removed')
+console.error('kept')
+console['warn']('removed')
@@ -0,0 +1,3 @@
+;
+console.error('kept');
+;
@@ -6,6 +6,7 @@ import type {
   NextFont,
   NextFontWithVariable,
   Display,
+  FallbackMetrics,
 } from '../types'
 export declare function ABeeZee<
   T extends CssVariable | undefined = undefined,
@@ -17,6 +18,7 @@ export declare function ABeeZee<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function ADLaM_Display<
@@ -29,6 +31,7 @@ export declare function ADLaM_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'adlam' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function AR_One_Sans<
@@ -47,6 +50,7 @@ export declare function AR_One_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'ARRR'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -60,6 +64,7 @@ export declare function Abel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Abhaya_Libre<
@@ -78,6 +83,7 @@ export declare function Abhaya_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sinhala'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aboreto<
@@ -90,6 +96,7 @@ export declare function Aboreto<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Abril_Fatface<
@@ -102,6 +109,7 @@ export declare function Abril_Fatface<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Abyssinica_SIL<
@@ -114,6 +122,7 @@ export declare function Abyssinica_SIL<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'ethiopic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aclonica<
@@ -126,6 +135,7 @@ export declare function Aclonica<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Acme<
@@ -138,6 +148,7 @@ export declare function Acme<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Actor<
@@ -150,6 +161,7 @@ export declare function Actor<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Adamina<
@@ -162,6 +174,7 @@ export declare function Adamina<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Advent_Pro<
@@ -187,6 +200,7 @@ export declare function Advent_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -206,6 +220,7 @@ export declare function Afacad<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic-ext' | 'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'
   >
@@ -243,6 +258,7 @@ export declare function Afacad_Flux<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'slnt'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -256,6 +272,7 @@ export declare function Agbalumo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Agdasima<
@@ -268,6 +285,7 @@ export declare function Agdasima<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aguafina_Script<
@@ -280,6 +298,7 @@ export declare function Aguafina_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Akatab<
@@ -299,6 +318,7 @@ export declare function Akatab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tifinagh'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Akaya_Kanadaka<
@@ -311,6 +331,7 @@ export declare function Akaya_Kanadaka<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Akaya_Telivigala<
@@ -323,6 +344,7 @@ export declare function Akaya_Telivigala<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Akronim<
@@ -335,6 +357,7 @@ export declare function Akronim<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Akshar<
@@ -354,6 +377,7 @@ export declare function Akshar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aladin<
@@ -366,6 +390,7 @@ export declare function Aladin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alata<
@@ -378,6 +403,7 @@ export declare function Alata<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alatsi<
@@ -390,6 +416,7 @@ export declare function Alatsi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Albert_Sans<
@@ -415,6 +442,7 @@ export declare function Albert_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aldrich<
@@ -427,6 +455,7 @@ export declare function Aldrich<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alef<
@@ -439,6 +468,7 @@ export declare function Alef<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alegreya<
@@ -459,6 +489,7 @@ export declare function Alegreya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -485,6 +516,7 @@ export declare function Alegreya_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -513,6 +545,7 @@ export declare function Alegreya_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -541,6 +574,7 @@ export declare function Alegreya_Sans_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -574,6 +608,7 @@ export declare function Aleo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alex_Brush<
@@ -586,6 +621,7 @@ export declare function Alex_Brush<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alexandria<
@@ -611,6 +647,7 @@ export declare function Alexandria<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alfa_Slab_One<
@@ -623,6 +660,7 @@ export declare function Alfa_Slab_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alice<
@@ -635,6 +673,7 @@ export declare function Alice<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alike<
@@ -647,6 +686,7 @@ export declare function Alike<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alike_Angular<
@@ -659,6 +699,7 @@ export declare function Alike_Angular<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alkalami<
@@ -671,6 +712,7 @@ export declare function Alkalami<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alkatra<
@@ -689,6 +731,7 @@ export declare function Alkatra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'devanagari' | 'latin' | 'latin-ext' | 'oriya'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Allan<
@@ -701,6 +744,7 @@ export declare function Allan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Allerta<
@@ -713,6 +757,7 @@ export declare function Allerta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Allerta_Stencil<
@@ -725,6 +770,7 @@ export declare function Allerta_Stencil<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Allison<
@@ -737,6 +783,7 @@ export declare function Allison<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Allura<
@@ -749,6 +796,7 @@ export declare function Allura<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Almarai<
@@ -761,6 +809,7 @@ export declare function Almarai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Almendra<
@@ -773,6 +822,7 @@ export declare function Almendra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Almendra_Display<
@@ -785,6 +835,7 @@ export declare function Almendra_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Almendra_SC<
@@ -797,6 +848,7 @@ export declare function Almendra_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alumni_Sans<
@@ -822,6 +874,7 @@ export declare function Alumni_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -836,6 +889,7 @@ export declare function Alumni_Sans_Collegiate_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alumni_Sans_Inline_One<
@@ -848,6 +902,7 @@ export declare function Alumni_Sans_Inline_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Alumni_Sans_Pinstripe<
@@ -860,6 +915,7 @@ export declare function Alumni_Sans_Pinstripe<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -874,6 +930,7 @@ export declare function Amarante<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Amaranth<
@@ -886,6 +943,7 @@ export declare function Amaranth<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Amatic_SC<
@@ -898,6 +956,7 @@ export declare function Amatic_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'hebrew' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Amethysta<
@@ -910,6 +969,7 @@ export declare function Amethysta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Amiko<
@@ -922,6 +982,7 @@ export declare function Amiko<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Amiri<
@@ -934,6 +995,7 @@ export declare function Amiri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Amiri_Quran<
@@ -946,6 +1008,7 @@ export declare function Amiri_Quran<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Amita<
@@ -958,6 +1021,7 @@ export declare function Amita<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Anaheim<
@@ -977,6 +1041,7 @@ export declare function Anaheim<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Andada_Pro<
@@ -996,6 +1061,7 @@ export declare function Andada_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Andika<
@@ -1008,6 +1074,7 @@ export declare function Andika<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -1032,6 +1099,7 @@ export declare function Anek_Bangla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1055,6 +1123,7 @@ export declare function Anek_Devanagari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1078,6 +1147,7 @@ export declare function Anek_Gujarati<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1101,6 +1171,7 @@ export declare function Anek_Gurmukhi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1124,6 +1195,7 @@ export declare function Anek_Kannada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1147,6 +1219,7 @@ export declare function Anek_Latin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1170,6 +1243,7 @@ export declare function Anek_Malayalam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'malayalam'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1193,6 +1267,7 @@ export declare function Anek_Odia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'oriya'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1216,6 +1291,7 @@ export declare function Anek_Tamil<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1239,6 +1315,7 @@ export declare function Anek_Telugu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1252,6 +1329,7 @@ export declare function Angkor<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Annapurna_SIL<
@@ -1264,6 +1342,7 @@ export declare function Annapurna_SIL<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Annie_Use_Your_Telescope<
@@ -1276,6 +1355,7 @@ export declare function Annie_Use_Your_Telescope<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Anonymous_Pro<
@@ -1288,6 +1368,7 @@ export declare function Anonymous_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Anta<
@@ -1300,6 +1381,7 @@ export declare function Anta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Antic<
@@ -1312,6 +1394,7 @@ export declare function Antic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Antic_Didone<
@@ -1324,6 +1407,7 @@ export declare function Antic_Didone<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Antic_Slab<
@@ -1336,6 +1420,7 @@ export declare function Antic_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Anton<
@@ -1348,6 +1433,7 @@ export declare function Anton<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Anton_SC<
@@ -1360,6 +1446,7 @@ export declare function Anton_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Antonio<
@@ -1381,6 +1468,7 @@ export declare function Antonio<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Anuphan<
@@ -1402,6 +1490,7 @@ export declare function Anuphan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Anybody<
@@ -1427,6 +1516,7 @@ export declare function Anybody<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1440,6 +1530,7 @@ export declare function Aoboshi_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Arapey<
@@ -1452,6 +1543,7 @@ export declare function Arapey<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Arbutus<
@@ -1464,6 +1556,7 @@ export declare function Arbutus<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Arbutus_Slab<
@@ -1476,6 +1569,7 @@ export declare function Arbutus_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Architects_Daughter<
@@ -1488,6 +1582,7 @@ export declare function Architects_Daughter<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Archivo<
@@ -1513,6 +1608,7 @@ export declare function Archivo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1526,6 +1622,7 @@ export declare function Archivo_Black<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Archivo_Narrow<
@@ -1544,6 +1641,7 @@ export declare function Archivo_Narrow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Are_You_Serious<
@@ -1556,6 +1654,7 @@ export declare function Are_You_Serious<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aref_Ruqaa<
@@ -1568,6 +1667,7 @@ export declare function Aref_Ruqaa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aref_Ruqaa_Ink<
@@ -1580,6 +1680,7 @@ export declare function Aref_Ruqaa_Ink<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Arima<
@@ -1601,6 +1702,7 @@ export declare function Arima<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'greek'
     | 'greek-ext'
@@ -1627,6 +1729,7 @@ export declare function Arimo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -1648,6 +1751,7 @@ export declare function Arizonia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Armata<
@@ -1660,6 +1764,7 @@ export declare function Armata<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Arsenal<
@@ -1672,6 +1777,7 @@ export declare function Arsenal<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -1686,6 +1792,7 @@ export declare function Arsenal_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -1700,6 +1807,7 @@ export declare function Artifika<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Arvo<
@@ -1712,6 +1820,7 @@ export declare function Arvo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Arya<
@@ -1724,6 +1833,7 @@ export declare function Arya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Asap<
@@ -1749,6 +1859,7 @@ export declare function Asap<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -1771,6 +1882,7 @@ export declare function Asap_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Asar<
@@ -1783,6 +1895,7 @@ export declare function Asar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Asset<
@@ -1795,6 +1908,7 @@ export declare function Asset<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Assistant<
@@ -1816,6 +1930,7 @@ export declare function Assistant<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Astloch<
@@ -1828,6 +1943,7 @@ export declare function Astloch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Asul<
@@ -1840,6 +1956,7 @@ export declare function Asul<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Athiti<
@@ -1859,6 +1976,7 @@ export declare function Athiti<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Atkinson_Hyperlegible<
@@ -1871,6 +1989,7 @@ export declare function Atkinson_Hyperlegible<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Atma<
@@ -1889,6 +2008,7 @@ export declare function Atma<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Atomic_Age<
@@ -1901,6 +2021,7 @@ export declare function Atomic_Age<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Aubrey<
@@ -1913,6 +2034,7 @@ export declare function Aubrey<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Audiowide<
@@ -1925,6 +2047,7 @@ export declare function Audiowide<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Autour_One<
@@ -1937,6 +2060,7 @@ export declare function Autour_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Average<
@@ -1949,6 +2073,7 @@ export declare function Average<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Average_Sans<
@@ -1961,6 +2086,7 @@ export declare function Average_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Averia_Gruesa_Libre<
@@ -1973,6 +2099,7 @@ export declare function Averia_Gruesa_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Averia_Libre<
@@ -1985,6 +2112,7 @@ export declare function Averia_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Averia_Sans_Libre<
@@ -1997,6 +2125,7 @@ export declare function Averia_Sans_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Averia_Serif_Libre<
@@ -2009,6 +2138,7 @@ export declare function Averia_Serif_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Azeret_Mono<
@@ -2034,6 +2164,7 @@ export declare function Azeret_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function B612<
@@ -2046,6 +2177,7 @@ export declare function B612<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function B612_Mono<
@@ -2058,6 +2190,7 @@ export declare function B612_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function BIZ_UDGothic<
@@ -2070,6 +2203,7 @@ export declare function BIZ_UDGothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function BIZ_UDMincho<
@@ -2082,6 +2216,7 @@ export declare function BIZ_UDMincho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function BIZ_UDPGothic<
@@ -2094,6 +2229,7 @@ export declare function BIZ_UDPGothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function BIZ_UDPMincho<
@@ -2106,6 +2242,7 @@ export declare function BIZ_UDPMincho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Babylonica<
@@ -2118,6 +2255,7 @@ export declare function Babylonica<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bacasime_Antique<
@@ -2130,6 +2268,7 @@ export declare function Bacasime_Antique<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bad_Script<
@@ -2142,6 +2281,7 @@ export declare function Bad_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bagel_Fat_One<
@@ -2154,6 +2294,7 @@ export declare function Bagel_Fat_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bahiana<
@@ -2166,6 +2307,7 @@ export declare function Bahiana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bahianita<
@@ -2178,6 +2320,7 @@ export declare function Bahianita<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bai_Jamjuree<
@@ -2197,6 +2340,7 @@ export declare function Bai_Jamjuree<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bakbak_One<
@@ -2209,6 +2353,7 @@ export declare function Bakbak_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ballet<
@@ -2221,6 +2366,7 @@ export declare function Ballet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -2241,6 +2387,7 @@ export declare function Baloo_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Bhai_2<
@@ -2260,6 +2407,7 @@ export declare function Baloo_Bhai_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Bhaijaan_2<
@@ -2279,6 +2427,7 @@ export declare function Baloo_Bhaijaan_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Bhaina_2<
@@ -2298,6 +2447,7 @@ export declare function Baloo_Bhaina_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'oriya' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Chettan_2<
@@ -2317,6 +2467,7 @@ export declare function Baloo_Chettan_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'malayalam' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Da_2<
@@ -2336,6 +2487,7 @@ export declare function Baloo_Da_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Paaji_2<
@@ -2355,6 +2507,7 @@ export declare function Baloo_Paaji_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Tamma_2<
@@ -2374,6 +2527,7 @@ export declare function Baloo_Tamma_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Tammudu_2<
@@ -2393,6 +2547,7 @@ export declare function Baloo_Tammudu_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baloo_Thambi_2<
@@ -2412,6 +2567,7 @@ export declare function Baloo_Thambi_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Balsamiq_Sans<
@@ -2424,6 +2580,7 @@ export declare function Balsamiq_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Balthazar<
@@ -2436,6 +2593,7 @@ export declare function Balthazar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bangers<
@@ -2448,6 +2606,7 @@ export declare function Bangers<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Barlow<
@@ -2472,6 +2631,7 @@ export declare function Barlow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Barlow_Condensed<
@@ -2496,6 +2656,7 @@ export declare function Barlow_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Barlow_Semi_Condensed<
@@ -2520,6 +2681,7 @@ export declare function Barlow_Semi_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Barriecito<
@@ -2532,6 +2694,7 @@ export declare function Barriecito<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Barrio<
@@ -2544,6 +2707,7 @@ export declare function Barrio<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Basic<
@@ -2556,6 +2720,7 @@ export declare function Basic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baskervville<
@@ -2568,6 +2733,7 @@ export declare function Baskervville<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baskervville_SC<
@@ -2580,6 +2746,7 @@ export declare function Baskervville_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Battambang<
@@ -2598,6 +2765,7 @@ export declare function Battambang<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Baumans<
@@ -2610,6 +2778,7 @@ export declare function Baumans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bayon<
@@ -2622,6 +2791,7 @@ export declare function Bayon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Be_Vietnam_Pro<
@@ -2646,6 +2816,7 @@ export declare function Be_Vietnam_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Beau_Rivage<
@@ -2658,6 +2829,7 @@ export declare function Beau_Rivage<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bebas_Neue<
@@ -2670,6 +2842,7 @@ export declare function Bebas_Neue<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Beiruti<
@@ -2692,6 +2865,7 @@ export declare function Beiruti<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Belanosima<
@@ -2704,6 +2878,7 @@ export declare function Belanosima<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Belgrano<
@@ -2716,6 +2891,7 @@ export declare function Belgrano<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bellefair<
@@ -2728,6 +2904,7 @@ export declare function Bellefair<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Belleza<
@@ -2740,6 +2917,7 @@ export declare function Belleza<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bellota<
@@ -2752,6 +2930,7 @@ export declare function Bellota<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bellota_Text<
@@ -2764,6 +2943,7 @@ export declare function Bellota_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function BenchNine<
@@ -2776,6 +2956,7 @@ export declare function BenchNine<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Benne<
@@ -2788,6 +2969,7 @@ export declare function Benne<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bentham<
@@ -2800,6 +2982,7 @@ export declare function Bentham<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Berkshire_Swash<
@@ -2812,6 +2995,7 @@ export declare function Berkshire_Swash<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Besley<
@@ -2832,6 +3016,7 @@ export declare function Besley<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Beth_Ellen<
@@ -2844,6 +3029,7 @@ export declare function Beth_Ellen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bevan<
@@ -2856,6 +3042,7 @@ export declare function Bevan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function BhuTuka_Expanded_One<
@@ -2868,6 +3055,7 @@ export declare function BhuTuka_Expanded_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Big_Shoulders_Display<
@@ -2893,6 +3081,7 @@ export declare function Big_Shoulders_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Big_Shoulders_Inline_Display<
@@ -2918,6 +3107,7 @@ export declare function Big_Shoulders_Inline_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Big_Shoulders_Inline_Text<
@@ -2943,6 +3133,7 @@ export declare function Big_Shoulders_Inline_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Big_Shoulders_Stencil_Display<
@@ -2968,6 +3159,7 @@ export declare function Big_Shoulders_Stencil_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Big_Shoulders_Stencil_Text<
@@ -2993,6 +3185,7 @@ export declare function Big_Shoulders_Stencil_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Big_Shoulders_Text<
@@ -3018,6 +3211,7 @@ export declare function Big_Shoulders_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bigelow_Rules<
@@ -3030,6 +3224,7 @@ export declare function Bigelow_Rules<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bigshot_One<
@@ -3042,6 +3237,7 @@ export declare function Bigshot_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bilbo<
@@ -3054,6 +3250,7 @@ export declare function Bilbo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bilbo_Swash_Caps<
@@ -3066,6 +3263,7 @@ export declare function Bilbo_Swash_Caps<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function BioRhyme<
@@ -3087,6 +3285,7 @@ export declare function BioRhyme<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -3106,6 +3305,7 @@ export declare function BioRhyme_Expanded<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Birthstone<
@@ -3118,6 +3318,7 @@ export declare function Birthstone<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Birthstone_Bounce<
@@ -3130,6 +3331,7 @@ export declare function Birthstone_Bounce<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Biryani<
@@ -3150,6 +3352,7 @@ export declare function Biryani<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bitter<
@@ -3175,6 +3378,7 @@ export declare function Bitter<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -3189,6 +3393,7 @@ export declare function Black_And_White_Picture<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Black_Han_Sans<
@@ -3201,6 +3406,7 @@ export declare function Black_Han_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Black_Ops_One<
@@ -3213,6 +3419,7 @@ export declare function Black_Ops_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Blaka<
@@ -3225,6 +3432,7 @@ export declare function Blaka<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Blaka_Hollow<
@@ -3237,6 +3445,7 @@ export declare function Blaka_Hollow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Blaka_Ink<
@@ -3249,6 +3458,7 @@ export declare function Blaka_Ink<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Blinker<
@@ -3270,6 +3480,7 @@ export declare function Blinker<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bodoni_Moda<
@@ -3290,6 +3501,7 @@ export declare function Bodoni_Moda<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -3311,6 +3523,7 @@ export declare function Bodoni_Moda_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -3324,6 +3537,7 @@ export declare function Bokor<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bona_Nova<
@@ -3336,6 +3550,7 @@ export declare function Bona_Nova<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -3356,6 +3571,7 @@ export declare function Bona_Nova_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -3376,6 +3592,7 @@ export declare function Bonbon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bonheur_Royale<
@@ -3388,6 +3605,7 @@ export declare function Bonheur_Royale<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Boogaloo<
@@ -3400,6 +3618,7 @@ export declare function Boogaloo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Borel<
@@ -3412,6 +3631,7 @@ export declare function Borel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bowlby_One<
@@ -3424,6 +3644,7 @@ export declare function Bowlby_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bowlby_One_SC<
@@ -3436,6 +3657,7 @@ export declare function Bowlby_One_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Braah_One<
@@ -3448,6 +3670,7 @@ export declare function Braah_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Brawler<
@@ -3460,6 +3683,7 @@ export declare function Brawler<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bree_Serif<
@@ -3472,6 +3696,7 @@ export declare function Bree_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bricolage_Grotesque<
@@ -3493,6 +3718,7 @@ export declare function Bricolage_Grotesque<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('opsz' | 'wdth')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -3506,6 +3732,7 @@ export declare function Bruno_Ace<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bruno_Ace_SC<
@@ -3518,6 +3745,7 @@ export declare function Bruno_Ace_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Brygada_1918<
@@ -3536,6 +3764,7 @@ export declare function Brygada_1918<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -3550,6 +3779,7 @@ export declare function Bubblegum_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bubbler_One<
@@ -3562,6 +3792,7 @@ export declare function Bubbler_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Buda<
@@ -3574,6 +3805,7 @@ export declare function Buda<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Buenard<
@@ -3586,6 +3818,7 @@ export declare function Buenard<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bungee<
@@ -3598,6 +3831,7 @@ export declare function Bungee<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bungee_Hairline<
@@ -3610,6 +3844,7 @@ export declare function Bungee_Hairline<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bungee_Inline<
@@ -3622,6 +3857,7 @@ export declare function Bungee_Inline<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bungee_Outline<
@@ -3634,6 +3870,7 @@ export declare function Bungee_Outline<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bungee_Shade<
@@ -3646,6 +3883,7 @@ export declare function Bungee_Shade<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bungee_Spice<
@@ -3658,6 +3896,7 @@ export declare function Bungee_Spice<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Bungee_Tint<
@@ -3670,6 +3909,7 @@ export declare function Bungee_Tint<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Butcherman<
@@ -3682,6 +3922,7 @@ export declare function Butcherman<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Butterfly_Kids<
@@ -3694,6 +3935,7 @@ export declare function Butterfly_Kids<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cabin<
@@ -3712,6 +3954,7 @@ export declare function Cabin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -3725,6 +3968,7 @@ export declare function Cabin_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cabin_Sketch<
@@ -3737,6 +3981,7 @@ export declare function Cabin_Sketch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cactus_Classical_Serif<
@@ -3749,6 +3994,7 @@ export declare function Cactus_Classical_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Caesar_Dressing<
@@ -3761,6 +4007,7 @@ export declare function Caesar_Dressing<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cagliostro<
@@ -3773,6 +4020,7 @@ export declare function Cagliostro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cairo<
@@ -3798,6 +4046,7 @@ export declare function Cairo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
   axes?: 'slnt'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -3824,6 +4073,7 @@ export declare function Cairo_Play<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
   axes?: 'slnt'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -3837,6 +4087,7 @@ export declare function Caladea<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Calistoga<
@@ -3849,6 +4100,7 @@ export declare function Calistoga<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Calligraffitti<
@@ -3861,6 +4113,7 @@ export declare function Calligraffitti<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cambay<
@@ -3873,6 +4126,7 @@ export declare function Cambay<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cambo<
@@ -3885,6 +4139,7 @@ export declare function Cambo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Candal<
@@ -3897,6 +4152,7 @@ export declare function Candal<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cantarell<
@@ -3909,6 +4165,7 @@ export declare function Cantarell<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cantata_One<
@@ -3921,6 +4178,7 @@ export declare function Cantata_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cantora_One<
@@ -3933,6 +4191,7 @@ export declare function Cantora_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Caprasimo<
@@ -3945,6 +4204,7 @@ export declare function Caprasimo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Capriola<
@@ -3957,6 +4217,7 @@ export declare function Capriola<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Caramel<
@@ -3969,6 +4230,7 @@ export declare function Caramel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Carattere<
@@ -3981,6 +4243,7 @@ export declare function Carattere<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cardo<
@@ -3993,6 +4256,7 @@ export declare function Cardo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Carlito<
@@ -4005,6 +4269,7 @@ export declare function Carlito<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -4025,6 +4290,7 @@ export declare function Carme<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Carrois_Gothic<
@@ -4037,6 +4303,7 @@ export declare function Carrois_Gothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Carrois_Gothic_SC<
@@ -4049,6 +4316,7 @@ export declare function Carrois_Gothic_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Carter_One<
@@ -4061,6 +4329,7 @@ export declare function Carter_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Castoro<
@@ -4073,6 +4342,7 @@ export declare function Castoro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Castoro_Titling<
@@ -4085,6 +4355,7 @@ export declare function Castoro_Titling<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Catamaran<
@@ -4110,6 +4381,7 @@ export declare function Catamaran<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Caudex<
@@ -4122,6 +4394,7 @@ export declare function Caudex<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Caveat<
@@ -4140,6 +4413,7 @@ export declare function Caveat<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Caveat_Brush<
@@ -4152,6 +4426,7 @@ export declare function Caveat_Brush<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cedarville_Cursive<
@@ -4164,6 +4439,7 @@ export declare function Cedarville_Cursive<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ceviche_One<
@@ -4176,6 +4452,7 @@ export declare function Ceviche_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chakra_Petch<
@@ -4194,6 +4471,7 @@ export declare function Chakra_Petch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Changa<
@@ -4215,6 +4493,7 @@ export declare function Changa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Changa_One<
@@ -4227,6 +4506,7 @@ export declare function Changa_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chango<
@@ -4239,6 +4519,7 @@ export declare function Chango<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Charis_SIL<
@@ -4251,6 +4532,7 @@ export declare function Charis_SIL<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4265,6 +4547,7 @@ export declare function Charm<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Charmonman<
@@ -4277,6 +4560,7 @@ export declare function Charmonman<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chathura<
@@ -4295,6 +4579,7 @@ export declare function Chathura<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chau_Philomene_One<
@@ -4307,6 +4592,7 @@ export declare function Chau_Philomene_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chela_One<
@@ -4319,6 +4605,7 @@ export declare function Chela_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chelsea_Market<
@@ -4331,6 +4618,7 @@ export declare function Chelsea_Market<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chenla<
@@ -4343,6 +4631,7 @@ export declare function Chenla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cherish<
@@ -4355,6 +4644,7 @@ export declare function Cherish<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cherry_Bomb_One<
@@ -4367,6 +4657,7 @@ export declare function Cherry_Bomb_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cherry_Cream_Soda<
@@ -4379,6 +4670,7 @@ export declare function Cherry_Cream_Soda<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cherry_Swash<
@@ -4391,6 +4683,7 @@ export declare function Cherry_Swash<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chewy<
@@ -4403,6 +4696,7 @@ export declare function Chewy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chicle<
@@ -4415,6 +4709,7 @@ export declare function Chicle<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chilanka<
@@ -4427,6 +4722,7 @@ export declare function Chilanka<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'malayalam'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chivo<
@@ -4452,6 +4748,7 @@ export declare function Chivo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chivo_Mono<
@@ -4477,6 +4774,7 @@ export declare function Chivo_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chocolate_Classical_Sans<
@@ -4489,6 +4787,7 @@ export declare function Chocolate_Classical_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chokokutai<
@@ -4501,6 +4800,7 @@ export declare function Chokokutai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Chonburi<
@@ -4513,6 +4813,7 @@ export declare function Chonburi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cinzel<
@@ -4533,6 +4834,7 @@ export declare function Cinzel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cinzel_Decorative<
@@ -4545,6 +4847,7 @@ export declare function Cinzel_Decorative<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Clicker_Script<
@@ -4557,6 +4860,7 @@ export declare function Clicker_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Climate_Crisis<
@@ -4569,6 +4873,7 @@ export declare function Climate_Crisis<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
   axes?: 'YEAR'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -4582,6 +4887,7 @@ export declare function Coda<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Codystar<
@@ -4594,6 +4900,7 @@ export declare function Codystar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Coiny<
@@ -4606,6 +4913,7 @@ export declare function Coiny<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Combo<
@@ -4618,6 +4926,7 @@ export declare function Combo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Comfortaa<
@@ -4637,6 +4946,7 @@ export declare function Comfortaa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4651,6 +4961,7 @@ export declare function Comforter<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Comforter_Brush<
@@ -4663,6 +4974,7 @@ export declare function Comforter_Brush<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Comic_Neue<
@@ -4675,6 +4987,7 @@ export declare function Comic_Neue<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Coming_Soon<
@@ -4687,6 +5000,7 @@ export declare function Coming_Soon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Comme<
@@ -4712,6 +5026,7 @@ export declare function Comme<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Commissioner<
@@ -4737,6 +5052,7 @@ export declare function Commissioner<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4752,6 +5068,7 @@ export declare function Concert_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Condiment<
@@ -4764,6 +5081,7 @@ export declare function Condiment<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Content<
@@ -4776,6 +5094,7 @@ export declare function Content<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Contrail_One<
@@ -4788,6 +5107,7 @@ export declare function Contrail_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Convergence<
@@ -4800,6 +5120,7 @@ export declare function Convergence<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cookie<
@@ -4812,6 +5133,7 @@ export declare function Cookie<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Copse<
@@ -4824,6 +5146,7 @@ export declare function Copse<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Corben<
@@ -4836,6 +5159,7 @@ export declare function Corben<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Corinthia<
@@ -4848,6 +5172,7 @@ export declare function Corinthia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cormorant<
@@ -4867,6 +5192,7 @@ export declare function Cormorant<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4887,6 +5213,7 @@ export declare function Cormorant_Garamond<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4907,6 +5234,7 @@ export declare function Cormorant_Infant<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4927,6 +5255,7 @@ export declare function Cormorant_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4947,6 +5276,7 @@ export declare function Cormorant_Unicase<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -4967,6 +5297,7 @@ export declare function Cormorant_Upright<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Courgette<
@@ -4979,6 +5310,7 @@ export declare function Courgette<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Courier_Prime<
@@ -4991,6 +5323,7 @@ export declare function Courier_Prime<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cousine<
@@ -5003,6 +5336,7 @@ export declare function Cousine<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -5024,6 +5358,7 @@ export declare function Coustard<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Covered_By_Your_Grace<
@@ -5036,6 +5371,7 @@ export declare function Covered_By_Your_Grace<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Crafty_Girls<
@@ -5048,6 +5384,7 @@ export declare function Crafty_Girls<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Creepster<
@@ -5060,6 +5397,7 @@ export declare function Creepster<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Crete_Round<
@@ -5072,6 +5410,7 @@ export declare function Crete_Round<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Crimson_Pro<
@@ -5094,6 +5433,7 @@ export declare function Crimson_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Crimson_Text<
@@ -5106,6 +5446,7 @@ export declare function Crimson_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Croissant_One<
@@ -5118,6 +5459,7 @@ export declare function Croissant_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Crushed<
@@ -5130,6 +5472,7 @@ export declare function Crushed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cuprum<
@@ -5148,6 +5491,7 @@ export declare function Cuprum<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -5162,6 +5506,7 @@ export declare function Cute_Font<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cutive<
@@ -5174,6 +5519,7 @@ export declare function Cutive<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Cutive_Mono<
@@ -5186,6 +5532,7 @@ export declare function Cutive_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function DM_Mono<
@@ -5198,6 +5545,7 @@ export declare function DM_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function DM_Sans<
@@ -5233,6 +5581,7 @@ export declare function DM_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -5246,6 +5595,7 @@ export declare function DM_Serif_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function DM_Serif_Text<
@@ -5258,6 +5608,7 @@ export declare function DM_Serif_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dai_Banna_SIL<
@@ -5276,6 +5627,7 @@ export declare function Dai_Banna_SIL<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'new-tai-lue'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Damion<
@@ -5288,6 +5640,7 @@ export declare function Damion<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dancing_Script<
@@ -5306,6 +5659,7 @@ export declare function Dancing_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Danfo<
@@ -5318,6 +5672,7 @@ export declare function Danfo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'ELSH'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -5331,6 +5686,7 @@ export declare function Dangrek<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Darker_Grotesque<
@@ -5352,6 +5708,7 @@ export declare function Darker_Grotesque<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Darumadrop_One<
@@ -5364,6 +5721,7 @@ export declare function Darumadrop_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function David_Libre<
@@ -5376,6 +5734,7 @@ export declare function David_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'hebrew' | 'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'
   >
@@ -5390,6 +5749,7 @@ export declare function Dawning_of_a_New_Day<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Days_One<
@@ -5402,6 +5762,7 @@ export declare function Days_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dekko<
@@ -5414,6 +5775,7 @@ export declare function Dekko<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dela_Gothic_One<
@@ -5426,6 +5788,7 @@ export declare function Dela_Gothic_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Delicious_Handrawn<
@@ -5438,6 +5801,7 @@ export declare function Delicious_Handrawn<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Delius<
@@ -5450,6 +5814,7 @@ export declare function Delius<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Delius_Swash_Caps<
@@ -5462,6 +5827,7 @@ export declare function Delius_Swash_Caps<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Delius_Unicase<
@@ -5474,6 +5840,7 @@ export declare function Delius_Unicase<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Della_Respira<
@@ -5486,6 +5853,7 @@ export declare function Della_Respira<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Denk_One<
@@ -5498,6 +5866,7 @@ export declare function Denk_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Devonshire<
@@ -5510,6 +5879,7 @@ export declare function Devonshire<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dhurjati<
@@ -5522,6 +5892,7 @@ export declare function Dhurjati<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Didact_Gothic<
@@ -5534,6 +5905,7 @@ export declare function Didact_Gothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -5548,6 +5920,7 @@ export declare function Diphylleia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Diplomata<
@@ -5560,6 +5933,7 @@ export declare function Diplomata<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Diplomata_SC<
@@ -5572,6 +5946,7 @@ export declare function Diplomata_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Do_Hyeon<
@@ -5584,6 +5959,7 @@ export declare function Do_Hyeon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dokdo<
@@ -5596,6 +5972,7 @@ export declare function Dokdo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Domine<
@@ -5614,6 +5991,7 @@ export declare function Domine<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Donegal_One<
@@ -5626,6 +6004,7 @@ export declare function Donegal_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dongle<
@@ -5638,6 +6017,7 @@ export declare function Dongle<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Doppio_One<
@@ -5650,6 +6030,7 @@ export declare function Doppio_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dorsa<
@@ -5662,6 +6043,7 @@ export declare function Dorsa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Dosis<
@@ -5683,6 +6065,7 @@ export declare function Dosis<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function DotGothic16<
@@ -5695,6 +6078,7 @@ export declare function DotGothic16<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Doto<
@@ -5720,6 +6104,7 @@ export declare function Doto<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
   axes?: 'ROND'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -5733,6 +6118,7 @@ export declare function Dr_Sugiyama<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Duru_Sans<
@@ -5745,6 +6131,7 @@ export declare function Duru_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function DynaPuff<
@@ -5763,6 +6150,7 @@ export declare function DynaPuff<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -5776,6 +6164,7 @@ export declare function Dynalight<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function EB_Garamond<
@@ -5795,6 +6184,7 @@ export declare function EB_Garamond<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -5815,6 +6205,7 @@ export declare function Eagle_Lake<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function East_Sea_Dokdo<
@@ -5827,6 +6218,7 @@ export declare function East_Sea_Dokdo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Eater<
@@ -5839,6 +6231,7 @@ export declare function Eater<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Economica<
@@ -5851,6 +6244,7 @@ export declare function Economica<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Eczar<
@@ -5870,6 +6264,7 @@ export declare function Eczar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_AU_VIC_WA_NT_Arrows<
@@ -5888,6 +6283,7 @@ export declare function Edu_AU_VIC_WA_NT_Arrows<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_AU_VIC_WA_NT_Dots<
@@ -5906,6 +6302,7 @@ export declare function Edu_AU_VIC_WA_NT_Dots<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_AU_VIC_WA_NT_Guides<
@@ -5924,6 +6321,7 @@ export declare function Edu_AU_VIC_WA_NT_Guides<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_AU_VIC_WA_NT_Hand<
@@ -5942,6 +6340,7 @@ export declare function Edu_AU_VIC_WA_NT_Hand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_AU_VIC_WA_NT_Pre<
@@ -5960,6 +6359,7 @@ export declare function Edu_AU_VIC_WA_NT_Pre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_NSW_ACT_Foundation<
@@ -5978,6 +6378,7 @@ export declare function Edu_NSW_ACT_Foundation<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_QLD_Beginner<
@@ -5996,6 +6397,7 @@ export declare function Edu_QLD_Beginner<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_SA_Beginner<
@@ -6014,6 +6416,7 @@ export declare function Edu_SA_Beginner<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_TAS_Beginner<
@@ -6032,6 +6435,7 @@ export declare function Edu_TAS_Beginner<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Edu_VIC_WA_NT_Beginner<
@@ -6050,6 +6454,7 @@ export declare function Edu_VIC_WA_NT_Beginner<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function El_Messiri<
@@ -6068,6 +6473,7 @@ export declare function El_Messiri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Electrolize<
@@ -6080,6 +6486,7 @@ export declare function Electrolize<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Elsie<
@@ -6092,6 +6499,7 @@ export declare function Elsie<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Elsie_Swash_Caps<
@@ -6104,6 +6512,7 @@ export declare function Elsie_Swash_Caps<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Emblema_One<
@@ -6116,6 +6525,7 @@ export declare function Emblema_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Emilys_Candy<
@@ -6128,6 +6538,7 @@ export declare function Emilys_Candy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Encode_Sans<
@@ -6153,6 +6564,7 @@ export declare function Encode_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -6178,6 +6590,7 @@ export declare function Encode_Sans_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Encode_Sans_Expanded<
@@ -6202,6 +6615,7 @@ export declare function Encode_Sans_Expanded<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Encode_Sans_SC<
@@ -6227,6 +6641,7 @@ export declare function Encode_Sans_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -6252,6 +6667,7 @@ export declare function Encode_Sans_Semi_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Encode_Sans_Semi_Expanded<
@@ -6276,6 +6692,7 @@ export declare function Encode_Sans_Semi_Expanded<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Engagement<
@@ -6288,6 +6705,7 @@ export declare function Engagement<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Englebert<
@@ -6300,6 +6718,7 @@ export declare function Englebert<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Enriqueta<
@@ -6312,6 +6731,7 @@ export declare function Enriqueta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ephesis<
@@ -6324,6 +6744,7 @@ export declare function Ephesis<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Epilogue<
@@ -6349,6 +6770,7 @@ export declare function Epilogue<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Erica_One<
@@ -6361,6 +6783,7 @@ export declare function Erica_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Esteban<
@@ -6373,6 +6796,7 @@ export declare function Esteban<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Estonia<
@@ -6385,6 +6809,7 @@ export declare function Estonia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Euphoria_Script<
@@ -6397,6 +6822,7 @@ export declare function Euphoria_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ewert<
@@ -6409,6 +6835,7 @@ export declare function Ewert<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Exo<
@@ -6434,6 +6861,7 @@ export declare function Exo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Exo_2<
@@ -6459,6 +6887,7 @@ export declare function Exo_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -6479,6 +6908,7 @@ export declare function Expletus_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Explora<
@@ -6491,6 +6921,7 @@ export declare function Explora<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cherokee' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Faculty_Glyphic<
@@ -6503,6 +6934,7 @@ export declare function Faculty_Glyphic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fahkwang<
@@ -6522,6 +6954,7 @@ export declare function Fahkwang<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Familjen_Grotesk<
@@ -6540,6 +6973,7 @@ export declare function Familjen_Grotesk<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fanwood_Text<
@@ -6552,6 +6986,7 @@ export declare function Fanwood_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Farro<
@@ -6564,6 +6999,7 @@ export declare function Farro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Farsan<
@@ -6576,6 +7012,7 @@ export declare function Farsan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fascinate<
@@ -6588,6 +7025,7 @@ export declare function Fascinate<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fascinate_Inline<
@@ -6600,6 +7038,7 @@ export declare function Fascinate_Inline<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Faster_One<
@@ -6612,6 +7051,7 @@ export declare function Faster_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fasthand<
@@ -6624,6 +7064,7 @@ export declare function Fasthand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fauna_One<
@@ -6636,6 +7077,7 @@ export declare function Fauna_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Faustina<
@@ -6656,6 +7098,7 @@ export declare function Faustina<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Federant<
@@ -6668,6 +7111,7 @@ export declare function Federant<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Federo<
@@ -6680,6 +7124,7 @@ export declare function Federo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Felipa<
@@ -6692,6 +7137,7 @@ export declare function Felipa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fenix<
@@ -6704,6 +7150,7 @@ export declare function Fenix<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Festive<
@@ -6716,6 +7163,7 @@ export declare function Festive<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Figtree<
@@ -6737,6 +7185,7 @@ export declare function Figtree<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Finger_Paint<
@@ -6749,6 +7198,7 @@ export declare function Finger_Paint<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Finlandica<
@@ -6767,6 +7217,7 @@ export declare function Finlandica<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fira_Code<
@@ -6786,6 +7237,7 @@ export declare function Fira_Code<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -6800,6 +7252,7 @@ export declare function Fira_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -6826,6 +7279,7 @@ export declare function Fira_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -6858,6 +7312,7 @@ export declare function Fira_Sans_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -6890,6 +7345,7 @@ export declare function Fira_Sans_Extra_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -6910,6 +7366,7 @@ export declare function Fjalla_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fjord_One<
@@ -6922,6 +7379,7 @@ export declare function Fjord_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Flamenco<
@@ -6934,6 +7392,7 @@ export declare function Flamenco<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Flavors<
@@ -6946,6 +7405,7 @@ export declare function Flavors<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fleur_De_Leah<
@@ -6958,6 +7418,7 @@ export declare function Fleur_De_Leah<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Flow_Block<
@@ -6970,6 +7431,7 @@ export declare function Flow_Block<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -6984,6 +7446,7 @@ export declare function Flow_Circular<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -6998,6 +7461,7 @@ export declare function Flow_Rounded<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -7025,6 +7489,7 @@ export declare function Foldit<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fondamento<
@@ -7037,6 +7502,7 @@ export declare function Fondamento<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fontdiner_Swanky<
@@ -7049,6 +7515,7 @@ export declare function Fontdiner_Swanky<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Forum<
@@ -7061,6 +7528,7 @@ export declare function Forum<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fragment_Mono<
@@ -7073,6 +7541,7 @@ export declare function Fragment_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Francois_One<
@@ -7085,6 +7554,7 @@ export declare function Francois_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Frank_Ruhl_Libre<
@@ -7106,6 +7576,7 @@ export declare function Frank_Ruhl_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fraunces<
@@ -7131,6 +7602,7 @@ export declare function Fraunces<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('SOFT' | 'WONK' | 'opsz')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -7144,6 +7616,7 @@ export declare function Freckle_Face<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fredericka_the_Great<
@@ -7156,6 +7629,7 @@ export declare function Fredericka_the_Great<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fredoka<
@@ -7175,6 +7649,7 @@ export declare function Fredoka<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -7188,6 +7663,7 @@ export declare function Freehand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Freeman<
@@ -7200,6 +7676,7 @@ export declare function Freeman<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fresca<
@@ -7212,6 +7689,7 @@ export declare function Fresca<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Frijole<
@@ -7224,6 +7702,7 @@ export declare function Frijole<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fruktur<
@@ -7236,6 +7715,7 @@ export declare function Fruktur<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fugaz_One<
@@ -7248,6 +7728,7 @@ export declare function Fugaz_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fuggles<
@@ -7260,6 +7741,7 @@ export declare function Fuggles<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Funnel_Display<
@@ -7280,6 +7762,7 @@ export declare function Funnel_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Funnel_Sans<
@@ -7300,6 +7783,7 @@ export declare function Funnel_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fustat<
@@ -7321,6 +7805,7 @@ export declare function Fustat<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Fuzzy_Bubbles<
@@ -7333,6 +7818,7 @@ export declare function Fuzzy_Bubbles<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function GFS_Didot<
@@ -7345,6 +7831,7 @@ export declare function GFS_Didot<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function GFS_Neohellenic<
@@ -7357,6 +7844,7 @@ export declare function GFS_Neohellenic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ga_Maamli<
@@ -7369,6 +7857,7 @@ export declare function Ga_Maamli<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gabarito<
@@ -7389,6 +7878,7 @@ export declare function Gabarito<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gabriela<
@@ -7401,6 +7891,7 @@ export declare function Gabriela<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gaegu<
@@ -7413,6 +7904,7 @@ export declare function Gaegu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gafata<
@@ -7425,6 +7917,7 @@ export declare function Gafata<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gajraj_One<
@@ -7437,6 +7930,7 @@ export declare function Gajraj_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Galada<
@@ -7449,6 +7943,7 @@ export declare function Galada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Galdeano<
@@ -7461,6 +7956,7 @@ export declare function Galdeano<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Galindo<
@@ -7473,6 +7969,7 @@ export declare function Galindo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gamja_Flower<
@@ -7485,6 +7982,7 @@ export declare function Gamja_Flower<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gantari<
@@ -7510,6 +8008,7 @@ export declare function Gantari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gasoek_One<
@@ -7522,6 +8021,7 @@ export declare function Gasoek_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gayathri<
@@ -7534,6 +8034,7 @@ export declare function Gayathri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'malayalam'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Geist<
@@ -7559,6 +8060,7 @@ export declare function Geist<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Geist_Mono<
@@ -7584,6 +8086,7 @@ export declare function Geist_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gelasio<
@@ -7602,6 +8105,7 @@ export declare function Gelasio<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gemunu_Libre<
@@ -7623,6 +8127,7 @@ export declare function Gemunu_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sinhala'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Genos<
@@ -7648,6 +8153,7 @@ export declare function Genos<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cherokee' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gentium_Book_Plus<
@@ -7660,6 +8166,7 @@ export declare function Gentium_Book_Plus<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -7680,6 +8187,7 @@ export declare function Gentium_Plus<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -7700,6 +8208,7 @@ export declare function Geo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Geologica<
@@ -7725,6 +8234,7 @@ export declare function Geologica<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -7753,6 +8263,7 @@ export declare function Georama<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -7766,6 +8277,7 @@ export declare function Geostar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Geostar_Fill<
@@ -7778,6 +8290,7 @@ export declare function Geostar_Fill<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Germania_One<
@@ -7790,6 +8303,7 @@ export declare function Germania_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gideon_Roman<
@@ -7802,6 +8316,7 @@ export declare function Gideon_Roman<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gidugu<
@@ -7814,6 +8329,7 @@ export declare function Gidugu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gilda_Display<
@@ -7826,6 +8342,7 @@ export declare function Gilda_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Girassol<
@@ -7838,6 +8355,7 @@ export declare function Girassol<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Give_You_Glory<
@@ -7850,6 +8368,7 @@ export declare function Give_You_Glory<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Glass_Antiqua<
@@ -7862,6 +8381,7 @@ export declare function Glass_Antiqua<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Glegoo<
@@ -7874,6 +8394,7 @@ export declare function Glegoo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gloock<
@@ -7886,6 +8407,7 @@ export declare function Gloock<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gloria_Hallelujah<
@@ -7898,6 +8420,7 @@ export declare function Gloria_Hallelujah<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Glory<
@@ -7920,6 +8443,7 @@ export declare function Glory<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gluten<
@@ -7945,6 +8469,7 @@ export declare function Gluten<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'slnt'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -7958,6 +8483,7 @@ export declare function Goblin_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gochi_Hand<
@@ -7970,6 +8496,7 @@ export declare function Gochi_Hand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Goldman<
@@ -7982,6 +8509,7 @@ export declare function Goldman<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Golos_Text<
@@ -8002,6 +8530,7 @@ export declare function Golos_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gorditas<
@@ -8014,6 +8543,7 @@ export declare function Gorditas<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gothic_A1<
@@ -8038,6 +8568,7 @@ export declare function Gothic_A1<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -8058,6 +8589,7 @@ export declare function Gotu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Goudy_Bookletter_1911<
@@ -8070,6 +8602,7 @@ export declare function Goudy_Bookletter_1911<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gowun_Batang<
@@ -8082,6 +8615,7 @@ export declare function Gowun_Batang<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gowun_Dodum<
@@ -8094,6 +8628,7 @@ export declare function Gowun_Dodum<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Graduate<
@@ -8106,6 +8641,7 @@ export declare function Graduate<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Grand_Hotel<
@@ -8118,6 +8654,7 @@ export declare function Grand_Hotel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Grandiflora_One<
@@ -8130,6 +8667,7 @@ export declare function Grandiflora_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Grandstander<
@@ -8155,6 +8693,7 @@ export declare function Grandstander<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Grape_Nuts<
@@ -8167,6 +8706,7 @@ export declare function Grape_Nuts<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gravitas_One<
@@ -8179,6 +8719,7 @@ export declare function Gravitas_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Great_Vibes<
@@ -8191,6 +8732,7 @@ export declare function Great_Vibes<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -8210,6 +8752,7 @@ export declare function Grechen_Fuemen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Grenze<
@@ -8234,6 +8777,7 @@ export declare function Grenze<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Grenze_Gotisch<
@@ -8259,6 +8803,7 @@ export declare function Grenze_Gotisch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Grey_Qo<
@@ -8271,6 +8816,7 @@ export declare function Grey_Qo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Griffy<
@@ -8283,6 +8829,7 @@ export declare function Griffy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gruppo<
@@ -8295,6 +8842,7 @@ export declare function Gruppo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gudea<
@@ -8307,6 +8855,7 @@ export declare function Gudea<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gugi<
@@ -8319,6 +8868,7 @@ export declare function Gugi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gulzar<
@@ -8331,6 +8881,7 @@ export declare function Gulzar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gupter<
@@ -8343,6 +8894,7 @@ export declare function Gupter<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gurajada<
@@ -8355,6 +8907,7 @@ export declare function Gurajada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Gwendolyn<
@@ -8367,6 +8920,7 @@ export declare function Gwendolyn<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Habibi<
@@ -8379,6 +8933,7 @@ export declare function Habibi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hachi_Maru_Pop<
@@ -8391,6 +8946,7 @@ export declare function Hachi_Maru_Pop<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hahmlet<
@@ -8416,6 +8972,7 @@ export declare function Hahmlet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Halant<
@@ -8434,6 +8991,7 @@ export declare function Halant<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hammersmith_One<
@@ -8446,6 +9004,7 @@ export declare function Hammersmith_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hanalei<
@@ -8458,6 +9017,7 @@ export declare function Hanalei<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hanalei_Fill<
@@ -8470,6 +9030,7 @@ export declare function Hanalei_Fill<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Handjet<
@@ -8495,6 +9056,7 @@ export declare function Handjet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'arabic'
     | 'armenian'
@@ -8518,6 +9080,7 @@ export declare function Handlee<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hanken_Grotesk<
@@ -8543,6 +9106,7 @@ export declare function Hanken_Grotesk<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hanuman<
@@ -8561,6 +9125,7 @@ export declare function Hanuman<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Happy_Monkey<
@@ -8573,6 +9138,7 @@ export declare function Happy_Monkey<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Harmattan<
@@ -8585,6 +9151,7 @@ export declare function Harmattan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Headland_One<
@@ -8597,6 +9164,7 @@ export declare function Headland_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hedvig_Letters_Sans<
@@ -8609,6 +9177,7 @@ export declare function Hedvig_Letters_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hedvig_Letters_Serif<
@@ -8621,6 +9190,7 @@ export declare function Hedvig_Letters_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -8647,6 +9217,7 @@ export declare function Heebo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Henny_Penny<
@@ -8659,6 +9230,7 @@ export declare function Henny_Penny<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hepta_Slab<
@@ -8694,6 +9266,7 @@ export declare function Hepta_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Herr_Von_Muellerhoff<
@@ -8706,6 +9279,7 @@ export declare function Herr_Von_Muellerhoff<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hi_Melody<
@@ -8718,6 +9292,7 @@ export declare function Hi_Melody<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hina_Mincho<
@@ -8730,6 +9305,7 @@ export declare function Hina_Mincho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hind<
@@ -8748,6 +9324,7 @@ export declare function Hind<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hind_Guntur<
@@ -8766,6 +9343,7 @@ export declare function Hind_Guntur<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hind_Madurai<
@@ -8784,6 +9362,7 @@ export declare function Hind_Madurai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hind_Siliguri<
@@ -8802,6 +9381,7 @@ export declare function Hind_Siliguri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hind_Vadodara<
@@ -8820,6 +9400,7 @@ export declare function Hind_Vadodara<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Holtwood_One_SC<
@@ -8832,6 +9413,7 @@ export declare function Holtwood_One_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Homemade_Apple<
@@ -8844,6 +9426,7 @@ export declare function Homemade_Apple<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Homenaje<
@@ -8856,6 +9439,7 @@ export declare function Homenaje<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Honk<
@@ -8868,6 +9452,7 @@ export declare function Honk<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
   axes?: ('MORF' | 'SHLN')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -8889,6 +9474,7 @@ export declare function Host_Grotesk<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hubballi<
@@ -8901,6 +9487,7 @@ export declare function Hubballi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Hubot_Sans<
@@ -8923,6 +9510,7 @@ export declare function Hubot_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -8936,6 +9524,7 @@ export declare function Hurricane<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Mono<
@@ -8956,6 +9545,7 @@ export declare function IBM_Plex_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -8978,6 +9568,7 @@ export declare function IBM_Plex_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -9000,6 +9591,7 @@ export declare function IBM_Plex_Sans_Arabic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Sans_Condensed<
@@ -9020,6 +9612,7 @@ export declare function IBM_Plex_Sans_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Sans_Devanagari<
@@ -9040,6 +9633,7 @@ export declare function IBM_Plex_Sans_Devanagari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Sans_Hebrew<
@@ -9060,6 +9654,7 @@ export declare function IBM_Plex_Sans_Hebrew<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Sans_JP<
@@ -9080,6 +9675,7 @@ export declare function IBM_Plex_Sans_JP<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Sans_KR<
@@ -9100,6 +9696,7 @@ export declare function IBM_Plex_Sans_KR<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Sans_Thai<
@@ -9120,6 +9717,7 @@ export declare function IBM_Plex_Sans_Thai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'thai'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Sans_Thai_Looped<
@@ -9140,6 +9738,7 @@ export declare function IBM_Plex_Sans_Thai_Looped<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'thai'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IBM_Plex_Serif<
@@ -9160,6 +9759,7 @@ export declare function IBM_Plex_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -9174,6 +9774,7 @@ export declare function IM_Fell_DW_Pica<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_DW_Pica_SC<
@@ -9186,6 +9787,7 @@ export declare function IM_Fell_DW_Pica_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_Double_Pica<
@@ -9198,6 +9800,7 @@ export declare function IM_Fell_Double_Pica<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_Double_Pica_SC<
@@ -9210,6 +9813,7 @@ export declare function IM_Fell_Double_Pica_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_English<
@@ -9222,6 +9826,7 @@ export declare function IM_Fell_English<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_English_SC<
@@ -9234,6 +9839,7 @@ export declare function IM_Fell_English_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_French_Canon<
@@ -9246,6 +9852,7 @@ export declare function IM_Fell_French_Canon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_French_Canon_SC<
@@ -9258,6 +9865,7 @@ export declare function IM_Fell_French_Canon_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_Great_Primer<
@@ -9270,6 +9878,7 @@ export declare function IM_Fell_Great_Primer<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function IM_Fell_Great_Primer_SC<
@@ -9282,6 +9891,7 @@ export declare function IM_Fell_Great_Primer_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ibarra_Real_Nova<
@@ -9300,6 +9910,7 @@ export declare function Ibarra_Real_Nova<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Iceberg<
@@ -9312,6 +9923,7 @@ export declare function Iceberg<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Iceland<
@@ -9324,6 +9936,7 @@ export declare function Iceland<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Imbue<
@@ -9349,6 +9962,7 @@ export declare function Imbue<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -9362,6 +9976,7 @@ export declare function Imperial_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Imprima<
@@ -9374,6 +9989,7 @@ export declare function Imprima<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inclusive_Sans<
@@ -9386,6 +10002,7 @@ export declare function Inclusive_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inconsolata<
@@ -9408,6 +10025,7 @@ export declare function Inconsolata<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -9421,6 +10039,7 @@ export declare function Inder<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Indie_Flower<
@@ -9433,6 +10052,7 @@ export declare function Indie_Flower<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ingrid_Darling<
@@ -9445,6 +10065,7 @@ export declare function Ingrid_Darling<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inika<
@@ -9457,6 +10078,7 @@ export declare function Inika<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inknut_Antiqua<
@@ -9477,6 +10099,7 @@ export declare function Inknut_Antiqua<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inria_Sans<
@@ -9489,6 +10112,7 @@ export declare function Inria_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inria_Serif<
@@ -9501,6 +10125,7 @@ export declare function Inria_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inspiration<
@@ -9513,6 +10138,7 @@ export declare function Inspiration<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Instrument_Sans<
@@ -9531,6 +10157,7 @@ export declare function Instrument_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -9544,6 +10171,7 @@ export declare function Instrument_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Inter<
@@ -9569,6 +10197,7 @@ export declare function Inter<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -9603,6 +10232,7 @@ export declare function Inter_Tight<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -9623,6 +10253,7 @@ export declare function Irish_Grover<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Island_Moments<
@@ -9635,6 +10266,7 @@ export declare function Island_Moments<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Istok_Web<
@@ -9647,6 +10279,7 @@ export declare function Istok_Web<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Italiana<
@@ -9659,6 +10292,7 @@ export declare function Italiana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Italianno<
@@ -9671,6 +10305,7 @@ export declare function Italianno<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Itim<
@@ -9683,6 +10318,7 @@ export declare function Itim<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacquard_12<
@@ -9695,6 +10331,7 @@ export declare function Jacquard_12<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacquard_12_Charted<
@@ -9707,6 +10344,7 @@ export declare function Jacquard_12_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacquard_24<
@@ -9719,6 +10357,7 @@ export declare function Jacquard_24<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacquard_24_Charted<
@@ -9731,6 +10370,7 @@ export declare function Jacquard_24_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacquarda_Bastarda_9<
@@ -9743,6 +10383,7 @@ export declare function Jacquarda_Bastarda_9<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacquarda_Bastarda_9_Charted<
@@ -9755,6 +10396,7 @@ export declare function Jacquarda_Bastarda_9_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacques_Francois<
@@ -9767,6 +10409,7 @@ export declare function Jacques_Francois<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jacques_Francois_Shadow<
@@ -9779,6 +10422,7 @@ export declare function Jacques_Francois_Shadow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jaini<
@@ -9791,6 +10435,7 @@ export declare function Jaini<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jaini_Purva<
@@ -9803,6 +10448,7 @@ export declare function Jaini_Purva<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jaldi<
@@ -9815,6 +10461,7 @@ export declare function Jaldi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jaro<
@@ -9827,6 +10474,7 @@ export declare function Jaro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -9840,6 +10488,7 @@ export declare function Jersey_10<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jersey_10_Charted<
@@ -9852,6 +10501,7 @@ export declare function Jersey_10_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jersey_15<
@@ -9864,6 +10514,7 @@ export declare function Jersey_15<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jersey_15_Charted<
@@ -9876,6 +10527,7 @@ export declare function Jersey_15_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jersey_20<
@@ -9888,6 +10540,7 @@ export declare function Jersey_20<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jersey_20_Charted<
@@ -9900,6 +10553,7 @@ export declare function Jersey_20_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jersey_25<
@@ -9912,6 +10566,7 @@ export declare function Jersey_25<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jersey_25_Charted<
@@ -9924,6 +10579,7 @@ export declare function Jersey_25_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function JetBrains_Mono<
@@ -9946,6 +10602,7 @@ export declare function JetBrains_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -9960,6 +10617,7 @@ export declare function Jim_Nightshade<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Joan<
@@ -9972,6 +10630,7 @@ export declare function Joan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jockey_One<
@@ -9984,6 +10643,7 @@ export declare function Jockey_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jolly_Lodger<
@@ -9996,6 +10656,7 @@ export declare function Jolly_Lodger<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jomhuria<
@@ -10008,6 +10669,7 @@ export declare function Jomhuria<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jomolhari<
@@ -10020,6 +10682,7 @@ export declare function Jomolhari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'tibetan'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Josefin_Sans<
@@ -10041,6 +10704,7 @@ export declare function Josefin_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Josefin_Slab<
@@ -10062,6 +10726,7 @@ export declare function Josefin_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jost<
@@ -10087,6 +10752,7 @@ export declare function Jost<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Joti_One<
@@ -10099,6 +10765,7 @@ export declare function Joti_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jua<
@@ -10111,6 +10778,7 @@ export declare function Jua<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Judson<
@@ -10123,6 +10791,7 @@ export declare function Judson<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Julee<
@@ -10135,6 +10804,7 @@ export declare function Julee<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Julius_Sans_One<
@@ -10147,6 +10817,7 @@ export declare function Julius_Sans_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Junge<
@@ -10159,6 +10830,7 @@ export declare function Junge<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Jura<
@@ -10178,6 +10850,7 @@ export declare function Jura<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -10199,6 +10872,7 @@ export declare function Just_Another_Hand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Just_Me_Again_Down_Here<
@@ -10211,6 +10885,7 @@ export declare function Just_Me_Again_Down_Here<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function K2D<
@@ -10232,6 +10907,7 @@ export declare function K2D<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kablammo<
@@ -10244,6 +10920,7 @@ export declare function Kablammo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -10259,6 +10936,7 @@ export declare function Kadwa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kaisei_Decol<
@@ -10271,6 +10949,7 @@ export declare function Kaisei_Decol<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kaisei_HarunoUmi<
@@ -10283,6 +10962,7 @@ export declare function Kaisei_HarunoUmi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kaisei_Opti<
@@ -10295,6 +10975,7 @@ export declare function Kaisei_Opti<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kaisei_Tokumin<
@@ -10307,6 +10988,7 @@ export declare function Kaisei_Tokumin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kalam<
@@ -10319,6 +11001,7 @@ export declare function Kalam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kalnia<
@@ -10340,6 +11023,7 @@ export declare function Kalnia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -10362,6 +11046,7 @@ export declare function Kalnia_Glaze<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -10381,6 +11066,7 @@ export declare function Kameron<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kanit<
@@ -10405,6 +11091,7 @@ export declare function Kanit<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kantumruy_Pro<
@@ -10426,6 +11113,7 @@ export declare function Kantumruy_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Karantina<
@@ -10438,6 +11126,7 @@ export declare function Karantina<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Karla<
@@ -10459,6 +11148,7 @@ export declare function Karla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Karla_Tamil_Inclined<
@@ -10471,6 +11161,7 @@ export declare function Karla_Tamil_Inclined<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Karla_Tamil_Upright<
@@ -10483,6 +11174,7 @@ export declare function Karla_Tamil_Upright<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Karma<
@@ -10501,6 +11193,7 @@ export declare function Karma<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Katibeh<
@@ -10513,6 +11206,7 @@ export declare function Katibeh<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kaushan_Script<
@@ -10525,6 +11219,7 @@ export declare function Kaushan_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kavivanar<
@@ -10537,6 +11232,7 @@ export declare function Kavivanar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kavoon<
@@ -10549,6 +11245,7 @@ export declare function Kavoon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kay_Pho_Du<
@@ -10561,6 +11258,7 @@ export declare function Kay_Pho_Du<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kayah-li' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kdam_Thmor_Pro<
@@ -10573,6 +11271,7 @@ export declare function Kdam_Thmor_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Keania_One<
@@ -10585,6 +11284,7 @@ export declare function Keania_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kelly_Slab<
@@ -10597,6 +11297,7 @@ export declare function Kelly_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kenia<
@@ -10609,6 +11310,7 @@ export declare function Kenia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Khand<
@@ -10627,6 +11329,7 @@ export declare function Khand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Khmer<
@@ -10639,6 +11342,7 @@ export declare function Khmer<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Khula<
@@ -10657,6 +11361,7 @@ export declare function Khula<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kings<
@@ -10669,6 +11374,7 @@ export declare function Kings<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kirang_Haerang<
@@ -10681,6 +11387,7 @@ export declare function Kirang_Haerang<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kite_One<
@@ -10693,6 +11400,7 @@ export declare function Kite_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kiwi_Maru<
@@ -10705,6 +11413,7 @@ export declare function Kiwi_Maru<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Klee_One<
@@ -10717,6 +11426,7 @@ export declare function Klee_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Knewave<
@@ -10729,6 +11439,7 @@ export declare function Knewave<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function KoHo<
@@ -10748,6 +11459,7 @@ export declare function KoHo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kodchasan<
@@ -10767,6 +11479,7 @@ export declare function Kodchasan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kode_Mono<
@@ -10785,6 +11498,7 @@ export declare function Kode_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Koh_Santepheap<
@@ -10803,6 +11517,7 @@ export declare function Koh_Santepheap<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kolker_Brush<
@@ -10815,6 +11530,7 @@ export declare function Kolker_Brush<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Konkhmer_Sleokchher<
@@ -10827,6 +11543,7 @@ export declare function Konkhmer_Sleokchher<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kosugi<
@@ -10839,6 +11556,7 @@ export declare function Kosugi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kosugi_Maru<
@@ -10851,6 +11569,7 @@ export declare function Kosugi_Maru<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kotta_One<
@@ -10863,6 +11582,7 @@ export declare function Kotta_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Koulen<
@@ -10875,6 +11595,7 @@ export declare function Koulen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kranky<
@@ -10887,6 +11608,7 @@ export declare function Kranky<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kreon<
@@ -10906,6 +11628,7 @@ export declare function Kreon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kristi<
@@ -10918,6 +11641,7 @@ export declare function Kristi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Krona_One<
@@ -10930,6 +11654,7 @@ export declare function Krona_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Krub<
@@ -10949,6 +11674,7 @@ export declare function Krub<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kufam<
@@ -10969,6 +11695,7 @@ export declare function Kufam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kulim_Park<
@@ -10987,6 +11714,7 @@ export declare function Kulim_Park<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kumar_One<
@@ -10999,6 +11727,7 @@ export declare function Kumar_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kumar_One_Outline<
@@ -11011,6 +11740,7 @@ export declare function Kumar_One_Outline<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Kumbh_Sans<
@@ -11036,6 +11766,7 @@ export declare function Kumbh_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
   axes?: 'YOPQ'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -11049,6 +11780,7 @@ export declare function Kurale<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'devanagari' | 'latin' | 'latin-ext'
   >
@@ -11063,6 +11795,7 @@ export declare function LXGW_WenKai_Mono_TC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -11084,6 +11817,7 @@ export declare function LXGW_WenKai_TC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -11105,6 +11839,7 @@ export declare function La_Belle_Aurore<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Labrada<
@@ -11130,6 +11865,7 @@ export declare function Labrada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lacquer<
@@ -11142,6 +11878,7 @@ export declare function Lacquer<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Laila<
@@ -11160,6 +11897,7 @@ export declare function Laila<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lakki_Reddy<
@@ -11172,6 +11910,7 @@ export declare function Lakki_Reddy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lalezar<
@@ -11184,6 +11923,7 @@ export declare function Lalezar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lancelot<
@@ -11196,6 +11936,7 @@ export declare function Lancelot<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Langar<
@@ -11208,6 +11949,7 @@ export declare function Langar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lateef<
@@ -11228,6 +11970,7 @@ export declare function Lateef<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lato<
@@ -11246,6 +11989,7 @@ export declare function Lato<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lavishly_Yours<
@@ -11258,6 +12002,7 @@ export declare function Lavishly_Yours<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function League_Gothic<
@@ -11270,6 +12015,7 @@ export declare function League_Gothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -11283,6 +12029,7 @@ export declare function League_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function League_Spartan<
@@ -11308,6 +12055,7 @@ export declare function League_Spartan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Leckerli_One<
@@ -11320,6 +12068,7 @@ export declare function Leckerli_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ledger<
@@ -11332,6 +12081,7 @@ export declare function Ledger<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lekton<
@@ -11344,6 +12094,7 @@ export declare function Lekton<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lemon<
@@ -11356,6 +12107,7 @@ export declare function Lemon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lemonada<
@@ -11375,6 +12127,7 @@ export declare function Lemonada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend<
@@ -11400,6 +12153,7 @@ export declare function Lexend<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend_Deca<
@@ -11425,6 +12179,7 @@ export declare function Lexend_Deca<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend_Exa<
@@ -11450,6 +12205,7 @@ export declare function Lexend_Exa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend_Giga<
@@ -11475,6 +12231,7 @@ export declare function Lexend_Giga<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend_Mega<
@@ -11500,6 +12257,7 @@ export declare function Lexend_Mega<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend_Peta<
@@ -11525,6 +12283,7 @@ export declare function Lexend_Peta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend_Tera<
@@ -11550,6 +12309,7 @@ export declare function Lexend_Tera<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lexend_Zetta<
@@ -11575,6 +12335,7 @@ export declare function Lexend_Zetta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Barcode_128<
@@ -11587,6 +12348,7 @@ export declare function Libre_Barcode_128<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Barcode_128_Text<
@@ -11599,6 +12361,7 @@ export declare function Libre_Barcode_128_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Barcode_39<
@@ -11611,6 +12374,7 @@ export declare function Libre_Barcode_39<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Barcode_39_Extended<
@@ -11623,6 +12387,7 @@ export declare function Libre_Barcode_39_Extended<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Barcode_39_Extended_Text<
@@ -11635,6 +12400,7 @@ export declare function Libre_Barcode_39_Extended_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Barcode_39_Text<
@@ -11647,6 +12413,7 @@ export declare function Libre_Barcode_39_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Barcode_EAN13_Text<
@@ -11659,6 +12426,7 @@ export declare function Libre_Barcode_EAN13_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Baskerville<
@@ -11671,6 +12439,7 @@ export declare function Libre_Baskerville<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Bodoni<
@@ -11689,6 +12458,7 @@ export declare function Libre_Bodoni<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Caslon_Display<
@@ -11701,6 +12471,7 @@ export declare function Libre_Caslon_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Caslon_Text<
@@ -11713,6 +12484,7 @@ export declare function Libre_Caslon_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Libre_Franklin<
@@ -11738,6 +12510,7 @@ export declare function Libre_Franklin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -11752,6 +12525,7 @@ export declare function Licorice<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Life_Savers<
@@ -11764,6 +12538,7 @@ export declare function Life_Savers<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lilita_One<
@@ -11776,6 +12551,7 @@ export declare function Lilita_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lily_Script_One<
@@ -11788,6 +12564,7 @@ export declare function Lily_Script_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Limelight<
@@ -11800,6 +12577,7 @@ export declare function Limelight<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Linden_Hill<
@@ -11812,6 +12590,7 @@ export declare function Linden_Hill<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Linefont<
@@ -11847,6 +12626,7 @@ export declare function Linefont<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -11869,6 +12649,7 @@ export declare function Lisu_Bosa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'lisu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Literata<
@@ -11891,6 +12672,7 @@ export declare function Literata<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -11912,6 +12694,7 @@ export declare function Liu_Jian_Mao_Cao<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Livvic<
@@ -11933,6 +12716,7 @@ export declare function Livvic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lobster<
@@ -11945,6 +12729,7 @@ export declare function Lobster<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -11959,6 +12744,7 @@ export declare function Lobster_Two<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Londrina_Outline<
@@ -11971,6 +12757,7 @@ export declare function Londrina_Outline<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Londrina_Shadow<
@@ -11983,6 +12770,7 @@ export declare function Londrina_Shadow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Londrina_Sketch<
@@ -11995,6 +12783,7 @@ export declare function Londrina_Sketch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Londrina_Solid<
@@ -12007,6 +12796,7 @@ export declare function Londrina_Solid<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Long_Cang<
@@ -12019,6 +12809,7 @@ export declare function Long_Cang<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lora<
@@ -12037,6 +12828,7 @@ export declare function Lora<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -12057,6 +12849,7 @@ export declare function Love_Light<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Love_Ya_Like_A_Sister<
@@ -12069,6 +12862,7 @@ export declare function Love_Ya_Like_A_Sister<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Loved_by_the_King<
@@ -12081,6 +12875,7 @@ export declare function Loved_by_the_King<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lovers_Quarrel<
@@ -12093,6 +12888,7 @@ export declare function Lovers_Quarrel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Luckiest_Guy<
@@ -12105,6 +12901,7 @@ export declare function Luckiest_Guy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lugrasimo<
@@ -12117,6 +12914,7 @@ export declare function Lugrasimo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lumanosimo<
@@ -12129,6 +12927,7 @@ export declare function Lumanosimo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lunasima<
@@ -12141,6 +12940,7 @@ export declare function Lunasima<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -12162,6 +12962,7 @@ export declare function Lusitana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Lustria<
@@ -12174,6 +12975,7 @@ export declare function Lustria<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Luxurious_Roman<
@@ -12186,6 +12988,7 @@ export declare function Luxurious_Roman<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Luxurious_Script<
@@ -12198,6 +13001,7 @@ export declare function Luxurious_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function M_PLUS_1<
@@ -12223,6 +13027,7 @@ export declare function M_PLUS_1<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function M_PLUS_1_Code<
@@ -12244,6 +13049,7 @@ export declare function M_PLUS_1_Code<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function M_PLUS_1p<
@@ -12264,6 +13070,7 @@ export declare function M_PLUS_1p<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -12298,6 +13105,7 @@ export declare function M_PLUS_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function M_PLUS_Code_Latin<
@@ -12319,6 +13127,7 @@ export declare function M_PLUS_Code_Latin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -12340,6 +13149,7 @@ export declare function M_PLUS_Rounded_1c<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -12361,6 +13171,7 @@ export declare function Ma_Shan_Zheng<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Macondo<
@@ -12373,6 +13184,7 @@ export declare function Macondo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Macondo_Swash_Caps<
@@ -12385,6 +13197,7 @@ export declare function Macondo_Swash_Caps<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mada<
@@ -12407,6 +13220,7 @@ export declare function Mada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Madimi_One<
@@ -12419,6 +13233,7 @@ export declare function Madimi_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Magra<
@@ -12431,6 +13246,7 @@ export declare function Magra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Maiden_Orange<
@@ -12443,6 +13259,7 @@ export declare function Maiden_Orange<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Maitree<
@@ -12462,6 +13279,7 @@ export declare function Maitree<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Major_Mono_Display<
@@ -12474,6 +13292,7 @@ export declare function Major_Mono_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mako<
@@ -12486,6 +13305,7 @@ export declare function Mako<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mali<
@@ -12505,6 +13325,7 @@ export declare function Mali<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mallanna<
@@ -12517,6 +13338,7 @@ export declare function Mallanna<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Maname<
@@ -12529,6 +13351,7 @@ export declare function Maname<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sinhala' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mandali<
@@ -12541,6 +13364,7 @@ export declare function Mandali<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Manjari<
@@ -12553,6 +13377,7 @@ export declare function Manjari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'malayalam'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Manrope<
@@ -12574,6 +13399,7 @@ export declare function Manrope<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -12588,6 +13414,7 @@ export declare function Mansalva<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Manuale<
@@ -12608,6 +13435,7 @@ export declare function Manuale<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Marcellus<
@@ -12620,6 +13448,7 @@ export declare function Marcellus<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Marcellus_SC<
@@ -12632,6 +13461,7 @@ export declare function Marcellus_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Marck_Script<
@@ -12644,6 +13474,7 @@ export declare function Marck_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Margarine<
@@ -12656,6 +13487,7 @@ export declare function Margarine<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Marhey<
@@ -12675,6 +13507,7 @@ export declare function Marhey<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Markazi_Text<
@@ -12693,6 +13526,7 @@ export declare function Markazi_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Marko_One<
@@ -12705,6 +13539,7 @@ export declare function Marko_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Marmelad<
@@ -12717,6 +13552,7 @@ export declare function Marmelad<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -12739,6 +13575,7 @@ export declare function Martel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Martel_Sans<
@@ -12759,6 +13596,7 @@ export declare function Martel_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Martian_Mono<
@@ -12781,6 +13619,7 @@ export declare function Martian_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -12794,6 +13633,7 @@ export declare function Marvel<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mate<
@@ -12806,6 +13646,7 @@ export declare function Mate<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mate_SC<
@@ -12818,6 +13659,7 @@ export declare function Mate_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Matemasie<
@@ -12830,6 +13672,7 @@ export declare function Matemasie<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Maven_Pro<
@@ -12850,6 +13693,7 @@ export declare function Maven_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function McLaren<
@@ -12862,6 +13706,7 @@ export declare function McLaren<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mea_Culpa<
@@ -12874,6 +13719,7 @@ export declare function Mea_Culpa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Meddon<
@@ -12886,6 +13732,7 @@ export declare function Meddon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function MedievalSharp<
@@ -12898,6 +13745,7 @@ export declare function MedievalSharp<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Medula_One<
@@ -12910,6 +13758,7 @@ export declare function Medula_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Meera_Inimai<
@@ -12922,6 +13771,7 @@ export declare function Meera_Inimai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Megrim<
@@ -12934,6 +13784,7 @@ export declare function Megrim<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Meie_Script<
@@ -12946,6 +13797,7 @@ export declare function Meie_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Meow_Script<
@@ -12958,6 +13810,7 @@ export declare function Meow_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Merienda<
@@ -12979,6 +13832,7 @@ export declare function Merienda<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Merriweather<
@@ -12991,6 +13845,7 @@ export declare function Merriweather<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -13013,6 +13868,7 @@ export declare function Merriweather_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Metal<
@@ -13025,6 +13881,7 @@ export declare function Metal<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Metal_Mania<
@@ -13037,6 +13894,7 @@ export declare function Metal_Mania<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Metamorphous<
@@ -13049,6 +13907,7 @@ export declare function Metamorphous<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Metrophobic<
@@ -13061,6 +13920,7 @@ export declare function Metrophobic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Michroma<
@@ -13073,6 +13933,7 @@ export declare function Michroma<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Micro_5<
@@ -13085,6 +13946,7 @@ export declare function Micro_5<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Micro_5_Charted<
@@ -13097,6 +13959,7 @@ export declare function Micro_5_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Milonga<
@@ -13109,6 +13972,7 @@ export declare function Milonga<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Miltonian<
@@ -13121,6 +13985,7 @@ export declare function Miltonian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Miltonian_Tattoo<
@@ -13133,6 +13998,7 @@ export declare function Miltonian_Tattoo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mina<
@@ -13145,6 +14011,7 @@ export declare function Mina<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mingzat<
@@ -13157,6 +14024,7 @@ export declare function Mingzat<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'lepcha'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Miniver<
@@ -13169,6 +14037,7 @@ export declare function Miniver<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Miriam_Libre<
@@ -13187,6 +14056,7 @@ export declare function Miriam_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mirza<
@@ -13199,6 +14069,7 @@ export declare function Mirza<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Miss_Fajardose<
@@ -13211,6 +14082,7 @@ export declare function Miss_Fajardose<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mitr<
@@ -13230,6 +14102,7 @@ export declare function Mitr<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mochiy_Pop_One<
@@ -13242,6 +14115,7 @@ export declare function Mochiy_Pop_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mochiy_Pop_P_One<
@@ -13254,6 +14128,7 @@ export declare function Mochiy_Pop_P_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Modak<
@@ -13266,6 +14141,7 @@ export declare function Modak<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Modern_Antiqua<
@@ -13278,6 +14154,7 @@ export declare function Modern_Antiqua<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Moderustic<
@@ -13298,6 +14175,7 @@ export declare function Moderustic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mogra<
@@ -13310,6 +14188,7 @@ export declare function Mogra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mohave<
@@ -13329,6 +14208,7 @@ export declare function Mohave<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Moirai_One<
@@ -13341,6 +14221,7 @@ export declare function Moirai_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Molengo<
@@ -13353,6 +14234,7 @@ export declare function Molengo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Molle<
@@ -13365,6 +14247,7 @@ export declare function Molle<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mona_Sans<
@@ -13387,6 +14270,7 @@ export declare function Mona_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -13406,6 +14290,7 @@ export declare function Monda<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Monofett<
@@ -13418,6 +14303,7 @@ export declare function Monofett<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Monomaniac_One<
@@ -13430,6 +14316,7 @@ export declare function Monomaniac_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Monoton<
@@ -13442,6 +14329,7 @@ export declare function Monoton<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Monsieur_La_Doulaise<
@@ -13454,6 +14342,7 @@ export declare function Monsieur_La_Doulaise<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Montaga<
@@ -13466,6 +14355,7 @@ export declare function Montaga<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Montagu_Slab<
@@ -13487,6 +14377,7 @@ export declare function Montagu_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -13500,6 +14391,7 @@ export declare function MonteCarlo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Montez<
@@ -13512,6 +14404,7 @@ export declare function Montez<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Montserrat<
@@ -13537,6 +14430,7 @@ export declare function Montserrat<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -13563,6 +14457,7 @@ export declare function Montserrat_Alternates<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -13577,6 +14472,7 @@ export declare function Montserrat_Subrayada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Moo_Lah_Lah<
@@ -13589,6 +14485,7 @@ export declare function Moo_Lah_Lah<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mooli<
@@ -13601,6 +14498,7 @@ export declare function Mooli<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Moon_Dance<
@@ -13613,6 +14511,7 @@ export declare function Moon_Dance<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Moul<
@@ -13625,6 +14524,7 @@ export declare function Moul<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Moulpali<
@@ -13637,6 +14537,7 @@ export declare function Moulpali<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mountains_of_Christmas<
@@ -13649,6 +14550,7 @@ export declare function Mountains_of_Christmas<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mouse_Memoirs<
@@ -13661,6 +14563,7 @@ export declare function Mouse_Memoirs<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mr_Bedfort<
@@ -13673,6 +14576,7 @@ export declare function Mr_Bedfort<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mr_Dafoe<
@@ -13685,6 +14589,7 @@ export declare function Mr_Dafoe<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mr_De_Haviland<
@@ -13697,6 +14602,7 @@ export declare function Mr_De_Haviland<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mrs_Saint_Delafield<
@@ -13709,6 +14615,7 @@ export declare function Mrs_Saint_Delafield<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mrs_Sheppards<
@@ -13721,6 +14628,7 @@ export declare function Mrs_Sheppards<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ms_Madi<
@@ -13733,6 +14641,7 @@ export declare function Ms_Madi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mukta<
@@ -13753,6 +14662,7 @@ export declare function Mukta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mukta_Mahee<
@@ -13773,6 +14683,7 @@ export declare function Mukta_Mahee<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mukta_Malar<
@@ -13793,6 +14704,7 @@ export declare function Mukta_Malar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mukta_Vaani<
@@ -13813,6 +14725,7 @@ export declare function Mukta_Vaani<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mulish<
@@ -13838,6 +14751,7 @@ export declare function Mulish<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -13865,6 +14779,7 @@ export declare function Murecho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function MuseoModerno<
@@ -13890,6 +14805,7 @@ export declare function MuseoModerno<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function My_Soul<
@@ -13902,6 +14818,7 @@ export declare function My_Soul<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mynerve<
@@ -13914,6 +14831,7 @@ export declare function Mynerve<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Mystery_Quest<
@@ -13926,6 +14844,7 @@ export declare function Mystery_Quest<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function NTR<
@@ -13938,6 +14857,7 @@ export declare function NTR<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nabla<
@@ -13950,6 +14870,7 @@ export declare function Nabla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic-ext' | 'latin' | 'latin-ext' | 'math' | 'vietnamese'
   >
@@ -13971,6 +14892,7 @@ export declare function Namdhinggo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'limbu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nanum_Brush_Script<
@@ -13983,6 +14905,7 @@ export declare function Nanum_Brush_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nanum_Gothic<
@@ -13995,6 +14918,7 @@ export declare function Nanum_Gothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nanum_Gothic_Coding<
@@ -14007,6 +14931,7 @@ export declare function Nanum_Gothic_Coding<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nanum_Myeongjo<
@@ -14019,6 +14944,7 @@ export declare function Nanum_Myeongjo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nanum_Pen_Script<
@@ -14031,6 +14957,7 @@ export declare function Nanum_Pen_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Narnoor<
@@ -14049,6 +14976,7 @@ export declare function Narnoor<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gunjala-gondi' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Neonderthaw<
@@ -14061,6 +14989,7 @@ export declare function Neonderthaw<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nerko_One<
@@ -14073,6 +15002,7 @@ export declare function Nerko_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Neucha<
@@ -14085,6 +15015,7 @@ export declare function Neucha<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Neuton<
@@ -14103,6 +15034,7 @@ export declare function Neuton<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function New_Amsterdam<
@@ -14115,6 +15047,7 @@ export declare function New_Amsterdam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function New_Rocker<
@@ -14127,6 +15060,7 @@ export declare function New_Rocker<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function New_Tegomin<
@@ -14139,6 +15073,7 @@ export declare function New_Tegomin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function News_Cycle<
@@ -14151,6 +15086,7 @@ export declare function News_Cycle<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Newsreader<
@@ -14172,6 +15108,7 @@ export declare function Newsreader<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -14185,6 +15122,7 @@ export declare function Niconne<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Niramit<
@@ -14204,6 +15142,7 @@ export declare function Niramit<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nixie_One<
@@ -14216,6 +15155,7 @@ export declare function Nixie_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nobile<
@@ -14228,6 +15168,7 @@ export declare function Nobile<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nokora<
@@ -14246,6 +15187,7 @@ export declare function Nokora<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Norican<
@@ -14258,6 +15200,7 @@ export declare function Norican<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nosifer<
@@ -14270,6 +15213,7 @@ export declare function Nosifer<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Notable<
@@ -14282,6 +15226,7 @@ export declare function Notable<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nothing_You_Could_Do<
@@ -14294,6 +15239,7 @@ export declare function Nothing_You_Could_Do<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noticia_Text<
@@ -14306,6 +15252,7 @@ export declare function Noticia_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Color_Emoji<
@@ -14318,6 +15265,7 @@ export declare function Noto_Color_Emoji<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'emoji'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Emoji<
@@ -14337,6 +15285,7 @@ export declare function Noto_Emoji<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'emoji'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Kufi_Arabic<
@@ -14362,6 +15311,7 @@ export declare function Noto_Kufi_Arabic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Music<
@@ -14374,6 +15324,7 @@ export declare function Noto_Music<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'music'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Naskh_Arabic<
@@ -14392,6 +15343,7 @@ export declare function Noto_Naskh_Arabic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Nastaliq_Urdu<
@@ -14410,6 +15362,7 @@ export declare function Noto_Nastaliq_Urdu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Rashi_Hebrew<
@@ -14435,6 +15388,7 @@ export declare function Noto_Rashi_Hebrew<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek-ext' | 'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans<
@@ -14460,6 +15414,7 @@ export declare function Noto_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -14488,6 +15443,7 @@ export declare function Noto_Sans_Adlam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'adlam' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Adlam_Unjoined<
@@ -14506,6 +15462,7 @@ export declare function Noto_Sans_Adlam_Unjoined<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'adlam' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Anatolian_Hieroglyphs<
@@ -14518,6 +15475,7 @@ export declare function Noto_Sans_Anatolian_Hieroglyphs<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'anatolian-hieroglyphs' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Arabic<
@@ -14543,6 +15501,7 @@ export declare function Noto_Sans_Arabic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -14569,6 +15528,7 @@ export declare function Noto_Sans_Armenian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'armenian' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -14582,6 +15542,7 @@ export declare function Noto_Sans_Avestan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'avestan' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Balinese<
@@ -14600,6 +15561,7 @@ export declare function Noto_Sans_Balinese<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'balinese' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Bamum<
@@ -14618,6 +15580,7 @@ export declare function Noto_Sans_Bamum<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bamum' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Bassa_Vah<
@@ -14636,6 +15599,7 @@ export declare function Noto_Sans_Bassa_Vah<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bassa-vah' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Batak<
@@ -14648,6 +15612,7 @@ export declare function Noto_Sans_Batak<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'batak' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Bengali<
@@ -14673,6 +15638,7 @@ export declare function Noto_Sans_Bengali<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -14686,6 +15652,7 @@ export declare function Noto_Sans_Bhaiksuki<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bhaiksuki' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Brahmi<
@@ -14698,6 +15665,7 @@ export declare function Noto_Sans_Brahmi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'brahmi' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Buginese<
@@ -14710,6 +15678,7 @@ export declare function Noto_Sans_Buginese<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'buginese' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Buhid<
@@ -14722,6 +15691,7 @@ export declare function Noto_Sans_Buhid<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'buhid' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Canadian_Aboriginal<
@@ -14747,6 +15717,7 @@ export declare function Noto_Sans_Canadian_Aboriginal<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'canadian-aboriginal' | 'latin' | 'latin-ext' | 'math' | 'symbols'
   >
@@ -14761,6 +15732,7 @@ export declare function Noto_Sans_Carian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'carian' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Caucasian_Albanian<
@@ -14773,6 +15745,7 @@ export declare function Noto_Sans_Caucasian_Albanian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'caucasian-albanian' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Chakma<
@@ -14785,6 +15758,7 @@ export declare function Noto_Sans_Chakma<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'chakma' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Cham<
@@ -14810,6 +15784,7 @@ export declare function Noto_Sans_Cham<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cham' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Cherokee<
@@ -14835,6 +15810,7 @@ export declare function Noto_Sans_Cherokee<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cherokee' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Chorasmian<
@@ -14847,6 +15823,7 @@ export declare function Noto_Sans_Chorasmian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'chorasmian' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Coptic<
@@ -14859,6 +15836,7 @@ export declare function Noto_Sans_Coptic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'coptic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Cuneiform<
@@ -14871,6 +15849,7 @@ export declare function Noto_Sans_Cuneiform<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cuneiform' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Cypriot<
@@ -14883,6 +15862,7 @@ export declare function Noto_Sans_Cypriot<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cypriot' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Cypro_Minoan<
@@ -14895,6 +15875,7 @@ export declare function Noto_Sans_Cypro_Minoan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cypro-minoan' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Deseret<
@@ -14907,6 +15888,7 @@ export declare function Noto_Sans_Deseret<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'deseret' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Devanagari<
@@ -14932,6 +15914,7 @@ export declare function Noto_Sans_Devanagari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -14958,6 +15941,7 @@ export declare function Noto_Sans_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -14979,6 +15963,7 @@ export declare function Noto_Sans_Duployan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'duployan' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Egyptian_Hieroglyphs<
@@ -14991,6 +15976,7 @@ export declare function Noto_Sans_Egyptian_Hieroglyphs<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'egyptian-hieroglyphs' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Elbasan<
@@ -15003,6 +15989,7 @@ export declare function Noto_Sans_Elbasan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'elbasan' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Elymaic<
@@ -15015,6 +16002,7 @@ export declare function Noto_Sans_Elymaic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'elymaic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Ethiopic<
@@ -15040,6 +16028,7 @@ export declare function Noto_Sans_Ethiopic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'ethiopic' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15066,6 +16055,7 @@ export declare function Noto_Sans_Georgian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic-ext'
     | 'georgian'
@@ -15087,6 +16077,7 @@ export declare function Noto_Sans_Glagolitic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic-ext' | 'glagolitic' | 'latin' | 'latin-ext' | 'math' | 'symbols'
   >
@@ -15101,6 +16092,7 @@ export declare function Noto_Sans_Gothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gothic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Grantha<
@@ -15113,6 +16105,7 @@ export declare function Noto_Sans_Grantha<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'grantha' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Gujarati<
@@ -15138,6 +16131,7 @@ export declare function Noto_Sans_Gujarati<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15157,6 +16151,7 @@ export declare function Noto_Sans_Gunjala_Gondi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gunjala-gondi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Gurmukhi<
@@ -15182,6 +16177,7 @@ export declare function Noto_Sans_Gurmukhi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15208,6 +16204,7 @@ export declare function Noto_Sans_HK<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Hanifi_Rohingya<
@@ -15226,6 +16223,7 @@ export declare function Noto_Sans_Hanifi_Rohingya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hanifi-rohingya' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Hanunoo<
@@ -15238,6 +16236,7 @@ export declare function Noto_Sans_Hanunoo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hanunoo' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Hatran<
@@ -15250,6 +16249,7 @@ export declare function Noto_Sans_Hatran<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hatran' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Hebrew<
@@ -15275,6 +16275,7 @@ export declare function Noto_Sans_Hebrew<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic-ext' | 'greek-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -15290,6 +16291,7 @@ export declare function Noto_Sans_Imperial_Aramaic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'imperial-aramaic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Indic_Siyaq_Numbers<
@@ -15302,6 +16304,7 @@ export declare function Noto_Sans_Indic_Siyaq_Numbers<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'indic-siyaq-numbers' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Inscriptional_Pahlavi<
@@ -15314,6 +16317,7 @@ export declare function Noto_Sans_Inscriptional_Pahlavi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'inscriptional-pahlavi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Inscriptional_Parthian<
@@ -15326,6 +16330,7 @@ export declare function Noto_Sans_Inscriptional_Parthian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'inscriptional-parthian' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_JP<
@@ -15351,6 +16356,7 @@ export declare function Noto_Sans_JP<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Javanese<
@@ -15369,6 +16375,7 @@ export declare function Noto_Sans_Javanese<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'javanese' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_KR<
@@ -15394,6 +16401,7 @@ export declare function Noto_Sans_KR<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Kaithi<
@@ -15406,6 +16414,7 @@ export declare function Noto_Sans_Kaithi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kaithi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Kannada<
@@ -15431,6 +16440,7 @@ export declare function Noto_Sans_Kannada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15450,6 +16460,7 @@ export declare function Noto_Sans_Kawi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kawi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Kayah_Li<
@@ -15468,6 +16479,7 @@ export declare function Noto_Sans_Kayah_Li<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kayah-li' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Kharoshthi<
@@ -15480,6 +16492,7 @@ export declare function Noto_Sans_Kharoshthi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kharoshthi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Khmer<
@@ -15505,6 +16518,7 @@ export declare function Noto_Sans_Khmer<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15518,6 +16532,7 @@ export declare function Noto_Sans_Khojki<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khojki' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Khudawadi<
@@ -15530,6 +16545,7 @@ export declare function Noto_Sans_Khudawadi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khudawadi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Lao<
@@ -15555,6 +16571,7 @@ export declare function Noto_Sans_Lao<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'lao' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15581,6 +16598,7 @@ export declare function Noto_Sans_Lao_Looped<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'lao' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15594,6 +16612,7 @@ export declare function Noto_Sans_Lepcha<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'lepcha'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Limbu<
@@ -15606,6 +16625,7 @@ export declare function Noto_Sans_Limbu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'limbu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Linear_A<
@@ -15618,6 +16638,7 @@ export declare function Noto_Sans_Linear_A<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'linear-a'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Linear_B<
@@ -15630,6 +16651,7 @@ export declare function Noto_Sans_Linear_B<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'linear-b'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Lisu<
@@ -15648,6 +16670,7 @@ export declare function Noto_Sans_Lisu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'lisu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Lycian<
@@ -15660,6 +16683,7 @@ export declare function Noto_Sans_Lycian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'lycian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Lydian<
@@ -15672,6 +16696,7 @@ export declare function Noto_Sans_Lydian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'lydian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Mahajani<
@@ -15684,6 +16709,7 @@ export declare function Noto_Sans_Mahajani<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'mahajani'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Malayalam<
@@ -15709,6 +16735,7 @@ export declare function Noto_Sans_Malayalam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'malayalam'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -15722,6 +16749,7 @@ export declare function Noto_Sans_Mandaic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'mandaic'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Manichaean<
@@ -15734,6 +16762,7 @@ export declare function Noto_Sans_Manichaean<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'manichaean'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Marchen<
@@ -15746,6 +16775,7 @@ export declare function Noto_Sans_Marchen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'marchen'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Masaram_Gondi<
@@ -15758,6 +16788,7 @@ export declare function Noto_Sans_Masaram_Gondi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'masaram-gondi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Math<
@@ -15770,6 +16801,7 @@ export declare function Noto_Sans_Math<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'math'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Mayan_Numerals<
@@ -15782,6 +16814,7 @@ export declare function Noto_Sans_Mayan_Numerals<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'mayan-numerals'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Medefaidrin<
@@ -15800,6 +16833,7 @@ export declare function Noto_Sans_Medefaidrin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'medefaidrin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Meetei_Mayek<
@@ -15825,6 +16859,7 @@ export declare function Noto_Sans_Meetei_Mayek<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'meetei-mayek'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Mende_Kikakui<
@@ -15837,6 +16872,7 @@ export declare function Noto_Sans_Mende_Kikakui<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'mende-kikakui'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Meroitic<
@@ -15849,6 +16885,7 @@ export declare function Noto_Sans_Meroitic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'latin'
     | 'latin-ext'
@@ -15867,6 +16904,7 @@ export declare function Noto_Sans_Miao<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'miao'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Modi<
@@ -15879,6 +16917,7 @@ export declare function Noto_Sans_Modi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'modi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Mongolian<
@@ -15891,6 +16930,7 @@ export declare function Noto_Sans_Mongolian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'mongolian' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Mono<
@@ -15916,6 +16956,7 @@ export declare function Noto_Sans_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -15937,6 +16978,7 @@ export declare function Noto_Sans_Mro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'mro'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Multani<
@@ -15949,6 +16991,7 @@ export declare function Noto_Sans_Multani<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'multani'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Myanmar<
@@ -15973,6 +17016,7 @@ export declare function Noto_Sans_Myanmar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'myanmar'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_NKo<
@@ -15985,6 +17029,7 @@ export declare function Noto_Sans_NKo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'nko'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_NKo_Unjoined<
@@ -16003,6 +17048,7 @@ export declare function Noto_Sans_NKo_Unjoined<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'nko'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Nabataean<
@@ -16015,6 +17061,7 @@ export declare function Noto_Sans_Nabataean<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'nabataean'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Nag_Mundari<
@@ -16033,6 +17080,7 @@ export declare function Noto_Sans_Nag_Mundari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'nag-mundari'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Nandinagari<
@@ -16045,6 +17093,7 @@ export declare function Noto_Sans_Nandinagari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'nandinagari'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_New_Tai_Lue<
@@ -16063,6 +17112,7 @@ export declare function Noto_Sans_New_Tai_Lue<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'new-tai-lue'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Newa<
@@ -16075,6 +17125,7 @@ export declare function Noto_Sans_Newa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'newa'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Nushu<
@@ -16087,6 +17138,7 @@ export declare function Noto_Sans_Nushu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'nushu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Ogham<
@@ -16099,6 +17151,7 @@ export declare function Noto_Sans_Ogham<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'ogham'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Ol_Chiki<
@@ -16117,6 +17170,7 @@ export declare function Noto_Sans_Ol_Chiki<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'ol-chiki'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_Hungarian<
@@ -16129,6 +17183,7 @@ export declare function Noto_Sans_Old_Hungarian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-hungarian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_Italic<
@@ -16141,6 +17196,7 @@ export declare function Noto_Sans_Old_Italic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-italic'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_North_Arabian<
@@ -16153,6 +17209,7 @@ export declare function Noto_Sans_Old_North_Arabian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-north-arabian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_Permic<
@@ -16165,6 +17222,7 @@ export declare function Noto_Sans_Old_Permic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'old-permic'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_Persian<
@@ -16177,6 +17235,7 @@ export declare function Noto_Sans_Old_Persian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-persian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_Sogdian<
@@ -16189,6 +17248,7 @@ export declare function Noto_Sans_Old_Sogdian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-sogdian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_South_Arabian<
@@ -16201,6 +17261,7 @@ export declare function Noto_Sans_Old_South_Arabian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-south-arabian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Old_Turkic<
@@ -16213,6 +17274,7 @@ export declare function Noto_Sans_Old_Turkic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-turkic'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Oriya<
@@ -16238,6 +17300,7 @@ export declare function Noto_Sans_Oriya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'oriya'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -16251,6 +17314,7 @@ export declare function Noto_Sans_Osage<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'osage'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Osmanya<
@@ -16263,6 +17327,7 @@ export declare function Noto_Sans_Osmanya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'osmanya'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Pahawh_Hmong<
@@ -16275,6 +17340,7 @@ export declare function Noto_Sans_Pahawh_Hmong<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'pahawh-hmong'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Palmyrene<
@@ -16287,6 +17353,7 @@ export declare function Noto_Sans_Palmyrene<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'palmyrene'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Pau_Cin_Hau<
@@ -16299,6 +17366,7 @@ export declare function Noto_Sans_Pau_Cin_Hau<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'pau-cin-hau'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Phags_Pa<
@@ -16311,6 +17379,7 @@ export declare function Noto_Sans_Phags_Pa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'phags-pa'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Phoenician<
@@ -16323,6 +17392,7 @@ export declare function Noto_Sans_Phoenician<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'phoenician'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Psalter_Pahlavi<
@@ -16335,6 +17405,7 @@ export declare function Noto_Sans_Psalter_Pahlavi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'psalter-pahlavi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Rejang<
@@ -16347,6 +17418,7 @@ export declare function Noto_Sans_Rejang<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'rejang'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Runic<
@@ -16359,6 +17431,7 @@ export declare function Noto_Sans_Runic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'runic'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_SC<
@@ -16384,6 +17457,7 @@ export declare function Noto_Sans_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Samaritan<
@@ -16396,6 +17470,7 @@ export declare function Noto_Sans_Samaritan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'samaritan'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Saurashtra<
@@ -16408,6 +17483,7 @@ export declare function Noto_Sans_Saurashtra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'saurashtra'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Sharada<
@@ -16420,6 +17496,7 @@ export declare function Noto_Sans_Sharada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sharada'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Shavian<
@@ -16432,6 +17509,7 @@ export declare function Noto_Sans_Shavian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'shavian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Siddham<
@@ -16444,6 +17522,7 @@ export declare function Noto_Sans_Siddham<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'siddham'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_SignWriting<
@@ -16456,6 +17535,7 @@ export declare function Noto_Sans_SignWriting<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'signwriting'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Sinhala<
@@ -16481,6 +17561,7 @@ export declare function Noto_Sans_Sinhala<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sinhala'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -16494,6 +17575,7 @@ export declare function Noto_Sans_Sogdian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sogdian'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Sora_Sompeng<
@@ -16512,6 +17594,7 @@ export declare function Noto_Sans_Sora_Sompeng<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sora-sompeng'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Soyombo<
@@ -16524,6 +17607,7 @@ export declare function Noto_Sans_Soyombo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'soyombo'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Sundanese<
@@ -16542,6 +17626,7 @@ export declare function Noto_Sans_Sundanese<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sundanese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Syloti_Nagri<
@@ -16554,6 +17639,7 @@ export declare function Noto_Sans_Syloti_Nagri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'syloti-nagri'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Symbols<
@@ -16579,6 +17665,7 @@ export declare function Noto_Sans_Symbols<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Symbols_2<
@@ -16591,6 +17678,7 @@ export declare function Noto_Sans_Symbols_2<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'braille' | 'latin' | 'latin-ext' | 'math' | 'mayan-numerals' | 'symbols'
   >
@@ -16618,6 +17706,7 @@ export declare function Noto_Sans_Syriac<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'syriac'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Syriac_Eastern<
@@ -16643,6 +17732,7 @@ export declare function Noto_Sans_Syriac_Eastern<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'syriac'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_TC<
@@ -16668,6 +17758,7 @@ export declare function Noto_Sans_TC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tagalog<
@@ -16680,6 +17771,7 @@ export declare function Noto_Sans_Tagalog<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tagalog'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tagbanwa<
@@ -16692,6 +17784,7 @@ export declare function Noto_Sans_Tagbanwa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tagbanwa'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tai_Le<
@@ -16704,6 +17797,7 @@ export declare function Noto_Sans_Tai_Le<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tai-le'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tai_Tham<
@@ -16722,6 +17816,7 @@ export declare function Noto_Sans_Tai_Tham<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tai-tham'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tai_Viet<
@@ -16734,6 +17829,7 @@ export declare function Noto_Sans_Tai_Viet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tai-viet'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Takri<
@@ -16746,6 +17842,7 @@ export declare function Noto_Sans_Takri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'takri'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tamil<
@@ -16771,6 +17868,7 @@ export declare function Noto_Sans_Tamil<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -16784,6 +17882,7 @@ export declare function Noto_Sans_Tamil_Supplement<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil-supplement'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tangsa<
@@ -16802,6 +17901,7 @@ export declare function Noto_Sans_Tangsa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tangsa'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Telugu<
@@ -16827,6 +17927,7 @@ export declare function Noto_Sans_Telugu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -16853,6 +17954,7 @@ export declare function Noto_Sans_Thaana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thaana'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Thai<
@@ -16878,6 +17980,7 @@ export declare function Noto_Sans_Thai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -16903,6 +18006,7 @@ export declare function Noto_Sans_Thai_Looped<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tifinagh<
@@ -16915,6 +18019,7 @@ export declare function Noto_Sans_Tifinagh<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tifinagh'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Tirhuta<
@@ -16927,6 +18032,7 @@ export declare function Noto_Sans_Tirhuta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tirhuta'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Ugaritic<
@@ -16939,6 +18045,7 @@ export declare function Noto_Sans_Ugaritic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'ugaritic'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Vai<
@@ -16951,6 +18058,7 @@ export declare function Noto_Sans_Vai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vai'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Vithkuqi<
@@ -16969,6 +18077,7 @@ export declare function Noto_Sans_Vithkuqi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vithkuqi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Wancho<
@@ -16981,6 +18090,7 @@ export declare function Noto_Sans_Wancho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'wancho'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Warang_Citi<
@@ -16993,6 +18103,7 @@ export declare function Noto_Sans_Warang_Citi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'warang-citi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Yi<
@@ -17005,6 +18116,7 @@ export declare function Noto_Sans_Yi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'yi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Sans_Zanabazar_Square<
@@ -17017,6 +18129,7 @@ export declare function Noto_Sans_Zanabazar_Square<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'zanabazar-square'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif<
@@ -17042,6 +18155,7 @@ export declare function Noto_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -17063,6 +18177,7 @@ export declare function Noto_Serif_Ahom<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'ahom' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Armenian<
@@ -17088,6 +18203,7 @@ export declare function Noto_Serif_Armenian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'armenian' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17101,6 +18217,7 @@ export declare function Noto_Serif_Balinese<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'balinese' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Bengali<
@@ -17126,6 +18243,7 @@ export declare function Noto_Serif_Bengali<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17152,6 +18270,7 @@ export declare function Noto_Serif_Devanagari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17178,6 +18297,7 @@ export declare function Noto_Serif_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -17199,6 +18319,7 @@ export declare function Noto_Serif_Dogra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'dogra' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Ethiopic<
@@ -17224,6 +18345,7 @@ export declare function Noto_Serif_Ethiopic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'ethiopic' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17250,6 +18372,7 @@ export declare function Noto_Serif_Georgian<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'georgian' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17263,6 +18386,7 @@ export declare function Noto_Serif_Grantha<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'grantha' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Gujarati<
@@ -17288,6 +18412,7 @@ export declare function Noto_Serif_Gujarati<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Gurmukhi<
@@ -17313,6 +18438,7 @@ export declare function Noto_Serif_Gurmukhi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_HK<
@@ -17335,6 +18461,7 @@ export declare function Noto_Serif_HK<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Hebrew<
@@ -17360,6 +18487,7 @@ export declare function Noto_Serif_Hebrew<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17383,6 +18511,7 @@ export declare function Noto_Serif_JP<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_KR<
@@ -17405,6 +18534,7 @@ export declare function Noto_Serif_KR<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Kannada<
@@ -17430,6 +18560,7 @@ export declare function Noto_Serif_Kannada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Khitan_Small_Script<
@@ -17442,6 +18573,7 @@ export declare function Noto_Serif_Khitan_Small_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khitan-small-script' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Khmer<
@@ -17467,6 +18599,7 @@ export declare function Noto_Serif_Khmer<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17486,6 +18619,7 @@ export declare function Noto_Serif_Khojki<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khojki' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Lao<
@@ -17511,6 +18645,7 @@ export declare function Noto_Serif_Lao<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'lao' | 'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17524,6 +18659,7 @@ export declare function Noto_Serif_Makasar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'makasar'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Malayalam<
@@ -17549,6 +18685,7 @@ export declare function Noto_Serif_Malayalam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'malayalam'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Myanmar<
@@ -17573,6 +18710,7 @@ export declare function Noto_Serif_Myanmar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'myanmar'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_NP_Hmong<
@@ -17591,6 +18729,7 @@ export declare function Noto_Serif_NP_Hmong<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'nyiakeng-puachue-hmong'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Old_Uyghur<
@@ -17603,6 +18742,7 @@ export declare function Noto_Serif_Old_Uyghur<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'old-uyghur'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Oriya<
@@ -17621,6 +18761,7 @@ export declare function Noto_Serif_Oriya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'oriya'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Ottoman_Siyaq<
@@ -17633,6 +18774,7 @@ export declare function Noto_Serif_Ottoman_Siyaq<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'ottoman-siyaq-numbers'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_SC<
@@ -17655,6 +18797,7 @@ export declare function Noto_Serif_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Sinhala<
@@ -17680,6 +18823,7 @@ export declare function Noto_Serif_Sinhala<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sinhala'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17703,6 +18847,7 @@ export declare function Noto_Serif_TC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Tamil<
@@ -17728,6 +18873,7 @@ export declare function Noto_Serif_Tamil<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17741,6 +18887,7 @@ export declare function Noto_Serif_Tangut<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tangut'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Telugu<
@@ -17766,6 +18913,7 @@ export declare function Noto_Serif_Telugu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Thai<
@@ -17791,6 +18939,7 @@ export declare function Noto_Serif_Thai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -17817,6 +18966,7 @@ export declare function Noto_Serif_Tibetan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tibetan'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Toto<
@@ -17835,6 +18985,7 @@ export declare function Noto_Serif_Toto<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'toto'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Vithkuqi<
@@ -17853,6 +19004,7 @@ export declare function Noto_Serif_Vithkuqi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vithkuqi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Serif_Yezidi<
@@ -17871,6 +19023,7 @@ export declare function Noto_Serif_Yezidi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'yezidi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Traditional_Nushu<
@@ -17890,6 +19043,7 @@ export declare function Noto_Traditional_Nushu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'nushu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Noto_Znamenny_Musical_Notation<
@@ -17902,6 +19056,7 @@ export declare function Noto_Znamenny_Musical_Notation<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'znamenny'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Cut<
@@ -17914,6 +19069,7 @@ export declare function Nova_Cut<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Flat<
@@ -17926,6 +19082,7 @@ export declare function Nova_Flat<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Mono<
@@ -17938,6 +19095,7 @@ export declare function Nova_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Oval<
@@ -17950,6 +19108,7 @@ export declare function Nova_Oval<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Round<
@@ -17962,6 +19121,7 @@ export declare function Nova_Round<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Script<
@@ -17974,6 +19134,7 @@ export declare function Nova_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Slim<
@@ -17986,6 +19147,7 @@ export declare function Nova_Slim<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nova_Square<
@@ -17998,6 +19160,7 @@ export declare function Nova_Square<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Numans<
@@ -18010,6 +19173,7 @@ export declare function Numans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Nunito<
@@ -18035,6 +19199,7 @@ export declare function Nunito<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18062,6 +19227,7 @@ export declare function Nunito_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18077,6 +19243,7 @@ export declare function Nuosu_SIL<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'yi'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Odibee_Sans<
@@ -18089,6 +19256,7 @@ export declare function Odibee_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Odor_Mean_Chey<
@@ -18101,6 +19269,7 @@ export declare function Odor_Mean_Chey<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Offside<
@@ -18113,6 +19282,7 @@ export declare function Offside<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oi<
@@ -18125,6 +19295,7 @@ export declare function Oi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'arabic'
     | 'cyrillic'
@@ -18155,6 +19326,7 @@ export declare function Ojuju<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Old_Standard_TT<
@@ -18167,6 +19339,7 @@ export declare function Old_Standard_TT<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18181,6 +19354,7 @@ export declare function Oldenburg<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ole<
@@ -18193,6 +19367,7 @@ export declare function Ole<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oleo_Script<
@@ -18205,6 +19380,7 @@ export declare function Oleo_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oleo_Script_Swash_Caps<
@@ -18217,6 +19393,7 @@ export declare function Oleo_Script_Swash_Caps<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Onest<
@@ -18242,6 +19419,7 @@ export declare function Onest<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oooh_Baby<
@@ -18254,6 +19432,7 @@ export declare function Oooh_Baby<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Open_Sans<
@@ -18274,6 +19453,7 @@ export declare function Open_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -18298,6 +19478,7 @@ export declare function Oranienbaum<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Orbit<
@@ -18310,6 +19491,7 @@ export declare function Orbit<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Orbitron<
@@ -18330,6 +19512,7 @@ export declare function Orbitron<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oregano<
@@ -18342,6 +19525,7 @@ export declare function Oregano<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Orelega_One<
@@ -18354,6 +19538,7 @@ export declare function Orelega_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Orienta<
@@ -18366,6 +19551,7 @@ export declare function Orienta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Original_Surfer<
@@ -18378,6 +19564,7 @@ export declare function Original_Surfer<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oswald<
@@ -18398,6 +19585,7 @@ export declare function Oswald<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18425,6 +19613,7 @@ export declare function Outfit<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Over_the_Rainbow<
@@ -18437,6 +19626,7 @@ export declare function Over_the_Rainbow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Overlock<
@@ -18449,6 +19639,7 @@ export declare function Overlock<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Overlock_SC<
@@ -18461,6 +19652,7 @@ export declare function Overlock_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Overpass<
@@ -18486,6 +19678,7 @@ export declare function Overpass<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18507,6 +19700,7 @@ export declare function Overpass_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18521,6 +19715,7 @@ export declare function Ovo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oxanium<
@@ -18542,6 +19737,7 @@ export declare function Oxanium<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oxygen<
@@ -18554,6 +19750,7 @@ export declare function Oxygen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Oxygen_Mono<
@@ -18566,6 +19763,7 @@ export declare function Oxygen_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function PT_Mono<
@@ -18578,6 +19776,7 @@ export declare function PT_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function PT_Sans<
@@ -18590,6 +19789,7 @@ export declare function PT_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function PT_Sans_Caption<
@@ -18602,6 +19802,7 @@ export declare function PT_Sans_Caption<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function PT_Sans_Narrow<
@@ -18614,6 +19815,7 @@ export declare function PT_Sans_Narrow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function PT_Serif<
@@ -18626,6 +19828,7 @@ export declare function PT_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function PT_Serif_Caption<
@@ -18638,6 +19841,7 @@ export declare function PT_Serif_Caption<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pacifico<
@@ -18650,6 +19854,7 @@ export declare function Pacifico<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18664,6 +19869,7 @@ export declare function Padauk<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'myanmar'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Padyakke_Expanded_One<
@@ -18676,6 +19882,7 @@ export declare function Padyakke_Expanded_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Palanquin<
@@ -18696,6 +19903,7 @@ export declare function Palanquin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Palanquin_Dark<
@@ -18708,6 +19916,7 @@ export declare function Palanquin_Dark<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Palette_Mosaic<
@@ -18720,6 +19929,7 @@ export declare function Palette_Mosaic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pangolin<
@@ -18732,6 +19942,7 @@ export declare function Pangolin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -18746,6 +19957,7 @@ export declare function Paprika<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Parisienne<
@@ -18758,6 +19970,7 @@ export declare function Parisienne<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Parkinsans<
@@ -18778,6 +19991,7 @@ export declare function Parkinsans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Passero_One<
@@ -18790,6 +20004,7 @@ export declare function Passero_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Passion_One<
@@ -18802,6 +20017,7 @@ export declare function Passion_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Passions_Conflict<
@@ -18814,6 +20030,7 @@ export declare function Passions_Conflict<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pathway_Extreme<
@@ -18839,6 +20056,7 @@ export declare function Pathway_Extreme<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('opsz' | 'wdth')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -18852,6 +20070,7 @@ export declare function Pathway_Gothic_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Patrick_Hand<
@@ -18864,6 +20083,7 @@ export declare function Patrick_Hand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Patrick_Hand_SC<
@@ -18876,6 +20096,7 @@ export declare function Patrick_Hand_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pattaya<
@@ -18888,6 +20109,7 @@ export declare function Pattaya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Patua_One<
@@ -18900,6 +20122,7 @@ export declare function Patua_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pavanam<
@@ -18912,6 +20135,7 @@ export declare function Pavanam<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Paytone_One<
@@ -18924,6 +20148,7 @@ export declare function Paytone_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Peddana<
@@ -18936,6 +20161,7 @@ export declare function Peddana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Peralta<
@@ -18948,6 +20174,7 @@ export declare function Peralta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Permanent_Marker<
@@ -18960,6 +20187,7 @@ export declare function Permanent_Marker<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Petemoss<
@@ -18972,6 +20200,7 @@ export declare function Petemoss<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Petit_Formal_Script<
@@ -18984,6 +20213,7 @@ export declare function Petit_Formal_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Petrona<
@@ -19009,6 +20239,7 @@ export declare function Petrona<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Phetsarath<
@@ -19021,6 +20252,7 @@ export declare function Phetsarath<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'lao'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Philosopher<
@@ -19033,6 +20265,7 @@ export declare function Philosopher<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -19056,6 +20289,7 @@ export declare function Phudu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Piazzolla<
@@ -19081,6 +20315,7 @@ export declare function Piazzolla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -19102,6 +20337,7 @@ export declare function Piedra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pinyon_Script<
@@ -19114,6 +20350,7 @@ export declare function Pinyon_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pirata_One<
@@ -19126,6 +20363,7 @@ export declare function Pirata_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pixelify_Sans<
@@ -19144,6 +20382,7 @@ export declare function Pixelify_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Plaster<
@@ -19156,6 +20395,7 @@ export declare function Plaster<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Platypi<
@@ -19176,6 +20416,7 @@ export declare function Platypi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Play<
@@ -19188,6 +20429,7 @@ export declare function Play<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -19202,6 +20444,7 @@ export declare function Playball<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playfair<
@@ -19223,6 +20466,7 @@ export declare function Playfair<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -19246,6 +20490,7 @@ export declare function Playfair_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playfair_Display_SC<
@@ -19258,6 +20503,7 @@ export declare function Playfair_Display_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playpen_Sans<
@@ -19280,6 +20526,7 @@ export declare function Playpen_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'emoji' | 'latin' | 'latin-ext' | 'math' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_AR<
@@ -19298,6 +20545,7 @@ export declare function Playwrite_AR<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_AT<
   T extends CssVariable | undefined = undefined,
@@ -19315,6 +20563,7 @@ export declare function Playwrite_AT<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_AU_NSW<
   T extends CssVariable | undefined = undefined,
@@ -19332,6 +20581,7 @@ export declare function Playwrite_AU_NSW<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_AU_QLD<
   T extends CssVariable | undefined = undefined,
@@ -19349,6 +20599,7 @@ export declare function Playwrite_AU_QLD<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_AU_SA<
   T extends CssVariable | undefined = undefined,
@@ -19366,6 +20617,7 @@ export declare function Playwrite_AU_SA<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_AU_TAS<
   T extends CssVariable | undefined = undefined,
@@ -19383,6 +20635,7 @@ export declare function Playwrite_AU_TAS<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_AU_VIC<
   T extends CssVariable | undefined = undefined,
@@ -19400,6 +20653,7 @@ export declare function Playwrite_AU_VIC<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_BE_VLG<
   T extends CssVariable | undefined = undefined,
@@ -19417,6 +20671,7 @@ export declare function Playwrite_BE_VLG<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_BE_WAL<
   T extends CssVariable | undefined = undefined,
@@ -19434,6 +20689,7 @@ export declare function Playwrite_BE_WAL<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_BR<
   T extends CssVariable | undefined = undefined,
@@ -19451,6 +20707,7 @@ export declare function Playwrite_BR<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_CA<
   T extends CssVariable | undefined = undefined,
@@ -19468,6 +20725,7 @@ export declare function Playwrite_CA<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_CL<
   T extends CssVariable | undefined = undefined,
@@ -19485,6 +20743,7 @@ export declare function Playwrite_CL<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_CO<
   T extends CssVariable | undefined = undefined,
@@ -19502,6 +20761,7 @@ export declare function Playwrite_CO<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_CU<
   T extends CssVariable | undefined = undefined,
@@ -19519,6 +20779,7 @@ export declare function Playwrite_CU<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_CZ<
   T extends CssVariable | undefined = undefined,
@@ -19536,6 +20797,7 @@ export declare function Playwrite_CZ<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_DE_Grund<
   T extends CssVariable | undefined = undefined,
@@ -19553,6 +20815,7 @@ export declare function Playwrite_DE_Grund<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_DE_LA<
   T extends CssVariable | undefined = undefined,
@@ -19570,6 +20833,7 @@ export declare function Playwrite_DE_LA<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_DE_SAS<
   T extends CssVariable | undefined = undefined,
@@ -19587,6 +20851,7 @@ export declare function Playwrite_DE_SAS<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_DE_VA<
   T extends CssVariable | undefined = undefined,
@@ -19604,6 +20869,7 @@ export declare function Playwrite_DE_VA<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_DK_Loopet<
   T extends CssVariable | undefined = undefined,
@@ -19621,6 +20887,7 @@ export declare function Playwrite_DK_Loopet<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_DK_Uloopet<
   T extends CssVariable | undefined = undefined,
@@ -19638,6 +20905,7 @@ export declare function Playwrite_DK_Uloopet<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_ES<
   T extends CssVariable | undefined = undefined,
@@ -19655,6 +20923,7 @@ export declare function Playwrite_ES<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_ES_Deco<
   T extends CssVariable | undefined = undefined,
@@ -19672,6 +20941,7 @@ export declare function Playwrite_ES_Deco<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_FR_Moderne<
   T extends CssVariable | undefined = undefined,
@@ -19689,6 +20959,7 @@ export declare function Playwrite_FR_Moderne<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_FR_Trad<
   T extends CssVariable | undefined = undefined,
@@ -19706,6 +20977,7 @@ export declare function Playwrite_FR_Trad<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_GB_J<
   T extends CssVariable | undefined = undefined,
@@ -19723,6 +20995,7 @@ export declare function Playwrite_GB_J<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_GB_S<
   T extends CssVariable | undefined = undefined,
@@ -19740,6 +21013,7 @@ export declare function Playwrite_GB_S<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_HR<
   T extends CssVariable | undefined = undefined,
@@ -19757,6 +21031,7 @@ export declare function Playwrite_HR<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_HR_Lijeva<
   T extends CssVariable | undefined = undefined,
@@ -19774,6 +21049,7 @@ export declare function Playwrite_HR_Lijeva<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_HU<
   T extends CssVariable | undefined = undefined,
@@ -19791,6 +21067,7 @@ export declare function Playwrite_HU<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_ID<
   T extends CssVariable | undefined = undefined,
@@ -19808,6 +21085,7 @@ export declare function Playwrite_ID<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_IE<
   T extends CssVariable | undefined = undefined,
@@ -19825,6 +21103,7 @@ export declare function Playwrite_IE<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_IN<
   T extends CssVariable | undefined = undefined,
@@ -19842,6 +21121,7 @@ export declare function Playwrite_IN<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_IS<
   T extends CssVariable | undefined = undefined,
@@ -19859,6 +21139,7 @@ export declare function Playwrite_IS<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_IT_Moderna<
   T extends CssVariable | undefined = undefined,
@@ -19876,6 +21157,7 @@ export declare function Playwrite_IT_Moderna<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_IT_Trad<
   T extends CssVariable | undefined = undefined,
@@ -19893,6 +21175,7 @@ export declare function Playwrite_IT_Trad<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_MX<
   T extends CssVariable | undefined = undefined,
@@ -19910,6 +21193,7 @@ export declare function Playwrite_MX<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_NG_Modern<
   T extends CssVariable | undefined = undefined,
@@ -19927,6 +21211,7 @@ export declare function Playwrite_NG_Modern<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_NL<
   T extends CssVariable | undefined = undefined,
@@ -19944,6 +21229,7 @@ export declare function Playwrite_NL<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_NO<
   T extends CssVariable | undefined = undefined,
@@ -19961,6 +21247,7 @@ export declare function Playwrite_NO<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_NZ<
   T extends CssVariable | undefined = undefined,
@@ -19978,6 +21265,7 @@ export declare function Playwrite_NZ<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_PE<
   T extends CssVariable | undefined = undefined,
@@ -19995,6 +21283,7 @@ export declare function Playwrite_PE<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_PL<
   T extends CssVariable | undefined = undefined,
@@ -20012,6 +21301,7 @@ export declare function Playwrite_PL<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_PT<
   T extends CssVariable | undefined = undefined,
@@ -20029,6 +21319,7 @@ export declare function Playwrite_PT<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_RO<
   T extends CssVariable | undefined = undefined,
@@ -20046,6 +21337,7 @@ export declare function Playwrite_RO<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_SK<
   T extends CssVariable | undefined = undefined,
@@ -20063,6 +21355,7 @@ export declare function Playwrite_SK<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_TZ<
   T extends CssVariable | undefined = undefined,
@@ -20080,6 +21373,7 @@ export declare function Playwrite_TZ<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_US_Modern<
   T extends CssVariable | undefined = undefined,
@@ -20097,6 +21391,7 @@ export declare function Playwrite_US_Modern<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_US_Trad<
   T extends CssVariable | undefined = undefined,
@@ -20114,6 +21409,7 @@ export declare function Playwrite_US_Trad<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_VN<
   T extends CssVariable | undefined = undefined,
@@ -20131,6 +21427,7 @@ export declare function Playwrite_VN<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Playwrite_ZA<
   T extends CssVariable | undefined = undefined,
@@ -20148,6 +21445,7 @@ export declare function Playwrite_ZA<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Plus_Jakarta_Sans<
   T extends CssVariable | undefined = undefined,
@@ -20168,6 +21466,7 @@ export declare function Plus_Jakarta_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Podkova<
@@ -20187,6 +21486,7 @@ export declare function Podkova<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -20201,6 +21501,7 @@ export declare function Poetsen_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Poiret_One<
@@ -20213,6 +21514,7 @@ export declare function Poiret_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Poller_One<
@@ -20225,6 +21527,7 @@ export declare function Poller_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Poltawski_Nowy<
@@ -20243,6 +21546,7 @@ export declare function Poltawski_Nowy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Poly<
@@ -20255,6 +21559,7 @@ export declare function Poly<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pompiere<
@@ -20267,6 +21572,7 @@ export declare function Pompiere<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ponnala<
@@ -20279,6 +21585,7 @@ export declare function Ponnala<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pontano_Sans<
@@ -20298,6 +21605,7 @@ export declare function Pontano_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Poor_Story<
@@ -20310,6 +21618,7 @@ export declare function Poor_Story<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Poppins<
@@ -20334,6 +21643,7 @@ export declare function Poppins<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Port_Lligat_Sans<
@@ -20346,6 +21656,7 @@ export declare function Port_Lligat_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Port_Lligat_Slab<
@@ -20358,6 +21669,7 @@ export declare function Port_Lligat_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Potta_One<
@@ -20370,6 +21682,7 @@ export declare function Potta_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pragati_Narrow<
@@ -20382,6 +21695,7 @@ export declare function Pragati_Narrow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Praise<
@@ -20394,6 +21708,7 @@ export declare function Praise<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Prata<
@@ -20406,6 +21721,7 @@ export declare function Prata<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Preahvihear<
@@ -20418,6 +21734,7 @@ export declare function Preahvihear<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Press_Start_2P<
@@ -20430,6 +21747,7 @@ export declare function Press_Start_2P<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Pridi<
@@ -20449,6 +21767,7 @@ export declare function Pridi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Princess_Sofia<
@@ -20461,6 +21780,7 @@ export declare function Princess_Sofia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Prociono<
@@ -20473,6 +21793,7 @@ export declare function Prociono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Prompt<
@@ -20497,6 +21818,7 @@ export declare function Prompt<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Prosto_One<
@@ -20509,6 +21831,7 @@ export declare function Prosto_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Protest_Guerrilla<
@@ -20521,6 +21844,7 @@ export declare function Protest_Guerrilla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Protest_Revolution<
@@ -20533,6 +21857,7 @@ export declare function Protest_Revolution<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Protest_Riot<
@@ -20545,6 +21870,7 @@ export declare function Protest_Riot<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Protest_Strike<
@@ -20557,6 +21883,7 @@ export declare function Protest_Strike<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Proza_Libre<
@@ -20575,6 +21902,7 @@ export declare function Proza_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Public_Sans<
@@ -20600,6 +21928,7 @@ export declare function Public_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Puppies_Play<
@@ -20612,6 +21941,7 @@ export declare function Puppies_Play<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Puritan<
@@ -20624,6 +21954,7 @@ export declare function Puritan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Purple_Purse<
@@ -20636,6 +21967,7 @@ export declare function Purple_Purse<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Qahiri<
@@ -20648,6 +21980,7 @@ export declare function Qahiri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Quando<
@@ -20660,6 +21993,7 @@ export declare function Quando<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Quantico<
@@ -20672,6 +22006,7 @@ export declare function Quantico<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Quattrocento<
@@ -20684,6 +22019,7 @@ export declare function Quattrocento<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Quattrocento_Sans<
@@ -20696,6 +22032,7 @@ export declare function Quattrocento_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Questrial<
@@ -20708,6 +22045,7 @@ export declare function Questrial<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Quicksand<
@@ -20727,6 +22065,7 @@ export declare function Quicksand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Quintessential<
@@ -20739,6 +22078,7 @@ export declare function Quintessential<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Qwigley<
@@ -20751,6 +22091,7 @@ export declare function Qwigley<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Qwitcher_Grypen<
@@ -20763,6 +22104,7 @@ export declare function Qwitcher_Grypen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function REM<
@@ -20788,6 +22130,7 @@ export declare function REM<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Racing_Sans_One<
@@ -20800,6 +22143,7 @@ export declare function Racing_Sans_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Radio_Canada<
@@ -20819,6 +22163,7 @@ export declare function Radio_Canada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'canadian-aboriginal' | 'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -20838,6 +22183,7 @@ export declare function Radio_Canada_Big<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Radley<
@@ -20850,6 +22196,7 @@ export declare function Radley<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rajdhani<
@@ -20868,6 +22215,7 @@ export declare function Rajdhani<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rakkas<
@@ -20880,6 +22228,7 @@ export declare function Rakkas<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Raleway<
@@ -20905,6 +22254,7 @@ export declare function Raleway<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -20919,6 +22269,7 @@ export declare function Raleway_Dots<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ramabhadra<
@@ -20931,6 +22282,7 @@ export declare function Ramabhadra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ramaraja<
@@ -20943,6 +22295,7 @@ export declare function Ramaraja<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rambla<
@@ -20955,6 +22308,7 @@ export declare function Rambla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rammetto_One<
@@ -20967,6 +22321,7 @@ export declare function Rammetto_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rampart_One<
@@ -20979,6 +22334,7 @@ export declare function Rampart_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ranchers<
@@ -20991,6 +22347,7 @@ export declare function Ranchers<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rancho<
@@ -21003,6 +22360,7 @@ export declare function Rancho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ranga<
@@ -21015,6 +22373,7 @@ export declare function Ranga<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rasa<
@@ -21034,6 +22393,7 @@ export declare function Rasa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rationale<
@@ -21046,6 +22406,7 @@ export declare function Rationale<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ravi_Prakash<
@@ -21058,6 +22419,7 @@ export declare function Ravi_Prakash<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Readex_Pro<
@@ -21078,6 +22440,7 @@ export declare function Readex_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'HEXP'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -21101,6 +22464,7 @@ export declare function Recursive<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('CASL' | 'CRSV' | 'MONO' | 'slnt')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -21123,6 +22487,7 @@ export declare function Red_Hat_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Red_Hat_Mono<
@@ -21142,6 +22507,7 @@ export declare function Red_Hat_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Red_Hat_Text<
@@ -21161,6 +22527,7 @@ export declare function Red_Hat_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Red_Rose<
@@ -21180,6 +22547,7 @@ export declare function Red_Rose<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Redacted<
@@ -21192,6 +22560,7 @@ export declare function Redacted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Redacted_Script<
@@ -21204,6 +22573,7 @@ export declare function Redacted_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reddit_Mono<
@@ -21226,6 +22596,7 @@ export declare function Reddit_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reddit_Sans<
@@ -21248,6 +22619,7 @@ export declare function Reddit_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reddit_Sans_Condensed<
@@ -21270,6 +22642,7 @@ export declare function Reddit_Sans_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Redressed<
@@ -21282,6 +22655,7 @@ export declare function Redressed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reem_Kufi<
@@ -21300,6 +22674,7 @@ export declare function Reem_Kufi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reem_Kufi_Fun<
@@ -21318,6 +22693,7 @@ export declare function Reem_Kufi_Fun<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reem_Kufi_Ink<
@@ -21330,6 +22706,7 @@ export declare function Reem_Kufi_Ink<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reenie_Beanie<
@@ -21342,6 +22719,7 @@ export declare function Reenie_Beanie<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Reggae_One<
@@ -21354,6 +22732,7 @@ export declare function Reggae_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rethink_Sans<
@@ -21373,6 +22752,7 @@ export declare function Rethink_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Revalia<
@@ -21385,6 +22765,7 @@ export declare function Revalia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rhodium_Libre<
@@ -21397,6 +22778,7 @@ export declare function Rhodium_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ribeye<
@@ -21409,6 +22791,7 @@ export declare function Ribeye<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ribeye_Marrow<
@@ -21421,6 +22804,7 @@ export declare function Ribeye_Marrow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Righteous<
@@ -21433,6 +22817,7 @@ export declare function Righteous<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Risque<
@@ -21445,6 +22830,7 @@ export declare function Risque<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Road_Rage<
@@ -21457,6 +22843,7 @@ export declare function Road_Rage<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Roboto<
@@ -21476,6 +22863,7 @@ export declare function Roboto<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -21509,6 +22897,7 @@ export declare function Roboto_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -21552,6 +22941,7 @@ export declare function Roboto_Flex<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -21589,6 +22979,7 @@ export declare function Roboto_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -21616,6 +23007,7 @@ export declare function Roboto_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -21644,6 +23036,7 @@ export declare function Roboto_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -21664,6 +23057,7 @@ export declare function Rochester<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rock_3D<
@@ -21676,6 +23070,7 @@ export declare function Rock_3D<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rock_Salt<
@@ -21688,6 +23083,7 @@ export declare function Rock_Salt<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function RocknRoll_One<
@@ -21700,6 +23096,7 @@ export declare function RocknRoll_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rokkitt<
@@ -21725,6 +23122,7 @@ export declare function Rokkitt<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Romanesco<
@@ -21737,6 +23135,7 @@ export declare function Romanesco<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ropa_Sans<
@@ -21749,6 +23148,7 @@ export declare function Ropa_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rosario<
@@ -21768,6 +23168,7 @@ export declare function Rosario<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rosarivo<
@@ -21780,6 +23181,7 @@ export declare function Rosarivo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rouge_Script<
@@ -21792,6 +23194,7 @@ export declare function Rouge_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rowdies<
@@ -21804,6 +23207,7 @@ export declare function Rowdies<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rozha_One<
@@ -21816,6 +23220,7 @@ export declare function Rozha_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rubik<
@@ -21837,6 +23242,7 @@ export declare function Rubik<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'arabic' | 'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -21851,6 +23257,7 @@ export declare function Rubik_80s_Fade<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -21865,6 +23272,7 @@ export declare function Rubik_Beastly<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -21879,6 +23287,7 @@ export declare function Rubik_Broken_Fax<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -21899,6 +23308,7 @@ export declare function Rubik_Bubbles<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -21913,6 +23323,7 @@ export declare function Rubik_Burned<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -21927,6 +23338,7 @@ export declare function Rubik_Dirt<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -21941,6 +23353,7 @@ export declare function Rubik_Distressed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -21955,6 +23368,7 @@ export declare function Rubik_Doodle_Shadow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -21975,6 +23389,7 @@ export declare function Rubik_Doodle_Triangles<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -21995,6 +23410,7 @@ export declare function Rubik_Gemstones<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22009,6 +23425,7 @@ export declare function Rubik_Glitch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22023,6 +23440,7 @@ export declare function Rubik_Glitch_Pop<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -22043,6 +23461,7 @@ export declare function Rubik_Iso<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22057,6 +23476,7 @@ export declare function Rubik_Lines<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -22077,6 +23497,7 @@ export declare function Rubik_Maps<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -22097,6 +23518,7 @@ export declare function Rubik_Marker_Hatch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22111,6 +23533,7 @@ export declare function Rubik_Maze<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22125,6 +23548,7 @@ export declare function Rubik_Microbe<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22139,6 +23563,7 @@ export declare function Rubik_Mono_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rubik_Moonrocks<
@@ -22151,6 +23576,7 @@ export declare function Rubik_Moonrocks<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22165,6 +23591,7 @@ export declare function Rubik_Pixels<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22179,6 +23606,7 @@ export declare function Rubik_Puddles<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22193,6 +23621,7 @@ export declare function Rubik_Scribble<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -22213,6 +23642,7 @@ export declare function Rubik_Spray_Paint<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22227,6 +23657,7 @@ export declare function Rubik_Storm<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22241,6 +23672,7 @@ export declare function Rubik_Vinyl<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22255,6 +23687,7 @@ export declare function Rubik_Wet_Paint<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'hebrew' | 'latin' | 'latin-ext'
   >
@@ -22277,6 +23710,7 @@ export declare function Ruda<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rufina<
@@ -22289,6 +23723,7 @@ export declare function Rufina<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ruge_Boogie<
@@ -22301,6 +23736,7 @@ export declare function Ruge_Boogie<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ruluko<
@@ -22313,6 +23749,7 @@ export declare function Ruluko<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rum_Raisin<
@@ -22325,6 +23762,7 @@ export declare function Rum_Raisin<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ruslan_Display<
@@ -22337,6 +23775,7 @@ export declare function Ruslan_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Russo_One<
@@ -22349,6 +23788,7 @@ export declare function Russo_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ruthie<
@@ -22361,6 +23801,7 @@ export declare function Ruthie<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ruwudu<
@@ -22373,6 +23814,7 @@ export declare function Ruwudu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Rye<
@@ -22385,6 +23827,7 @@ export declare function Rye<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function STIX_Two_Text<
@@ -22403,6 +23846,7 @@ export declare function STIX_Two_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -22427,6 +23871,7 @@ export declare function SUSE<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sacramento<
@@ -22439,6 +23884,7 @@ export declare function Sacramento<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sahitya<
@@ -22451,6 +23897,7 @@ export declare function Sahitya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sail<
@@ -22463,6 +23910,7 @@ export declare function Sail<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Saira<
@@ -22488,6 +23936,7 @@ export declare function Saira<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -22513,6 +23962,7 @@ export declare function Saira_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Saira_Extra_Condensed<
@@ -22537,6 +23987,7 @@ export declare function Saira_Extra_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Saira_Semi_Condensed<
@@ -22561,6 +24012,7 @@ export declare function Saira_Semi_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Saira_Stencil_One<
@@ -22573,6 +24025,7 @@ export declare function Saira_Stencil_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Salsa<
@@ -22585,6 +24038,7 @@ export declare function Salsa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sanchez<
@@ -22597,6 +24051,7 @@ export declare function Sanchez<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sancreek<
@@ -22609,6 +24064,7 @@ export declare function Sancreek<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sankofa_Display<
@@ -22621,6 +24077,7 @@ export declare function Sankofa_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sansita<
@@ -22633,6 +24090,7 @@ export declare function Sansita<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sansita_Swashed<
@@ -22654,6 +24112,7 @@ export declare function Sansita_Swashed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sarabun<
@@ -22675,6 +24134,7 @@ export declare function Sarabun<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sarala<
@@ -22687,6 +24147,7 @@ export declare function Sarala<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sarina<
@@ -22699,6 +24160,7 @@ export declare function Sarina<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sarpanch<
@@ -22718,6 +24180,7 @@ export declare function Sarpanch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sassy_Frass<
@@ -22730,6 +24193,7 @@ export declare function Sassy_Frass<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Satisfy<
@@ -22742,6 +24206,7 @@ export declare function Satisfy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sawarabi_Gothic<
@@ -22754,6 +24219,7 @@ export declare function Sawarabi_Gothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sawarabi_Mincho<
@@ -22766,6 +24232,7 @@ export declare function Sawarabi_Mincho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Scada<
@@ -22778,6 +24245,7 @@ export declare function Scada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Scheherazade_New<
@@ -22790,6 +24258,7 @@ export declare function Scheherazade_New<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Schibsted_Grotesk<
@@ -22810,6 +24279,7 @@ export declare function Schibsted_Grotesk<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Schoolbell<
@@ -22822,6 +24292,7 @@ export declare function Schoolbell<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Scope_One<
@@ -22834,6 +24305,7 @@ export declare function Scope_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Seaweed_Script<
@@ -22846,6 +24318,7 @@ export declare function Seaweed_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Secular_One<
@@ -22858,6 +24331,7 @@ export declare function Secular_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sedan<
@@ -22870,6 +24344,7 @@ export declare function Sedan<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sedan_SC<
@@ -22882,6 +24357,7 @@ export declare function Sedan_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sedgwick_Ave<
@@ -22894,6 +24370,7 @@ export declare function Sedgwick_Ave<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sedgwick_Ave_Display<
@@ -22906,6 +24383,7 @@ export declare function Sedgwick_Ave_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sen<
@@ -22925,6 +24403,7 @@ export declare function Sen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Send_Flowers<
@@ -22937,6 +24416,7 @@ export declare function Send_Flowers<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sevillana<
@@ -22949,6 +24429,7 @@ export declare function Sevillana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Seymour_One<
@@ -22961,6 +24442,7 @@ export declare function Seymour_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shadows_Into_Light<
@@ -22973,6 +24455,7 @@ export declare function Shadows_Into_Light<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shadows_Into_Light_Two<
@@ -22985,6 +24468,7 @@ export declare function Shadows_Into_Light_Two<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shalimar<
@@ -22997,6 +24481,7 @@ export declare function Shalimar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shantell_Sans<
@@ -23017,6 +24502,7 @@ export declare function Shantell_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -23032,6 +24518,7 @@ export declare function Shanti<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Share<
@@ -23044,6 +24531,7 @@ export declare function Share<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Share_Tech<
@@ -23056,6 +24544,7 @@ export declare function Share_Tech<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Share_Tech_Mono<
@@ -23068,6 +24557,7 @@ export declare function Share_Tech_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shippori_Antique<
@@ -23080,6 +24570,7 @@ export declare function Shippori_Antique<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shippori_Antique_B1<
@@ -23092,6 +24583,7 @@ export declare function Shippori_Antique_B1<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shippori_Mincho<
@@ -23110,6 +24602,7 @@ export declare function Shippori_Mincho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shippori_Mincho_B1<
@@ -23128,6 +24621,7 @@ export declare function Shippori_Mincho_B1<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shizuru<
@@ -23140,6 +24634,7 @@ export declare function Shizuru<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shojumaru<
@@ -23152,6 +24647,7 @@ export declare function Shojumaru<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Short_Stack<
@@ -23164,6 +24660,7 @@ export declare function Short_Stack<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Shrikhand<
@@ -23176,6 +24673,7 @@ export declare function Shrikhand<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gujarati' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Siemreap<
@@ -23188,6 +24686,7 @@ export declare function Siemreap<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sigmar<
@@ -23200,6 +24699,7 @@ export declare function Sigmar<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sigmar_One<
@@ -23212,6 +24712,7 @@ export declare function Sigmar_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Signika<
@@ -23231,6 +24732,7 @@ export declare function Signika<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'GRAD'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -23251,6 +24753,7 @@ export declare function Signika_Negative<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Silkscreen<
@@ -23263,6 +24766,7 @@ export declare function Silkscreen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Simonetta<
@@ -23275,6 +24779,7 @@ export declare function Simonetta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Single_Day<
@@ -23287,6 +24792,7 @@ export declare function Single_Day<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sintony<
   T extends CssVariable | undefined = undefined,
@@ -23298,6 +24804,7 @@ export declare function Sintony<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sirin_Stencil<
@@ -23310,6 +24817,7 @@ export declare function Sirin_Stencil<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Six_Caps<
@@ -23322,6 +24830,7 @@ export declare function Six_Caps<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sixtyfour<
@@ -23334,6 +24843,7 @@ export declare function Sixtyfour<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
   axes?: ('BLED' | 'SCAN')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -23347,6 +24857,7 @@ export declare function Sixtyfour_Convergence<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols'>
   axes?: ('BLED' | 'SCAN' | 'XELA' | 'YELA')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -23360,6 +24871,7 @@ export declare function Skranji<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Slabo_13px<
@@ -23372,6 +24884,7 @@ export declare function Slabo_13px<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Slabo_27px<
@@ -23384,6 +24897,7 @@ export declare function Slabo_27px<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Slackey<
@@ -23396,6 +24910,7 @@ export declare function Slackey<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Slackside_One<
@@ -23408,6 +24923,7 @@ export declare function Slackside_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Smokum<
@@ -23420,6 +24936,7 @@ export declare function Smokum<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Smooch<
@@ -23432,6 +24949,7 @@ export declare function Smooch<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Smooch_Sans<
@@ -23457,6 +24975,7 @@ export declare function Smooch_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Smythe<
@@ -23469,6 +24988,7 @@ export declare function Smythe<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sniglet<
@@ -23481,6 +25001,7 @@ export declare function Sniglet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Snippet<
@@ -23493,6 +25014,7 @@ export declare function Snippet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Snowburst_One<
@@ -23505,6 +25027,7 @@ export declare function Snowburst_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sofadi_One<
@@ -23517,6 +25040,7 @@ export declare function Sofadi_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sofia<
@@ -23529,6 +25053,7 @@ export declare function Sofia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sofia_Sans<
@@ -23566,6 +25091,7 @@ export declare function Sofia_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sofia_Sans_Condensed<
@@ -23603,6 +25129,7 @@ export declare function Sofia_Sans_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sofia_Sans_Extra_Condensed<
@@ -23640,6 +25167,7 @@ export declare function Sofia_Sans_Extra_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sofia_Sans_Semi_Condensed<
@@ -23677,6 +25205,7 @@ export declare function Sofia_Sans_Semi_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Solitreo<
@@ -23689,6 +25218,7 @@ export declare function Solitreo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Solway<
@@ -23707,6 +25237,7 @@ export declare function Solway<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sometype_Mono<
@@ -23725,6 +25256,7 @@ export declare function Sometype_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Song_Myung<
@@ -23737,6 +25269,7 @@ export declare function Song_Myung<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sono<
@@ -23758,6 +25291,7 @@ export declare function Sono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'MONO'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -23771,6 +25305,7 @@ export declare function Sonsie_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sora<
@@ -23793,6 +25328,7 @@ export declare function Sora<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sorts_Mill_Goudy<
@@ -23805,6 +25341,7 @@ export declare function Sorts_Mill_Goudy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sour_Gummy<
@@ -23830,6 +25367,7 @@ export declare function Sour_Gummy<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -23853,6 +25391,7 @@ export declare function Source_Code_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -23883,6 +25422,7 @@ export declare function Source_Sans_3<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -23913,6 +25453,7 @@ export declare function Source_Serif_4<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -23935,6 +25476,7 @@ export declare function Space_Grotesk<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Space_Mono<
@@ -23947,6 +25489,7 @@ export declare function Space_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Special_Elite<
@@ -23959,6 +25502,7 @@ export declare function Special_Elite<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Spectral<
@@ -23979,6 +25523,7 @@ export declare function Spectral<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -24001,6 +25546,7 @@ export declare function Spectral_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -24015,6 +25561,7 @@ export declare function Spicy_Rice<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Spinnaker<
@@ -24027,6 +25574,7 @@ export declare function Spinnaker<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Spirax<
@@ -24039,6 +25587,7 @@ export declare function Spirax<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Splash<
@@ -24051,6 +25600,7 @@ export declare function Splash<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Spline_Sans<
@@ -24070,6 +25620,7 @@ export declare function Spline_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Spline_Sans_Mono<
@@ -24089,6 +25640,7 @@ export declare function Spline_Sans_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Squada_One<
@@ -24101,6 +25653,7 @@ export declare function Squada_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Square_Peg<
@@ -24113,6 +25666,7 @@ export declare function Square_Peg<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sree_Krushnadevaraya<
@@ -24125,6 +25679,7 @@ export declare function Sree_Krushnadevaraya<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sriracha<
@@ -24137,6 +25692,7 @@ export declare function Sriracha<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Srisakdi<
@@ -24149,6 +25705,7 @@ export declare function Srisakdi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Staatliches<
@@ -24161,6 +25718,7 @@ export declare function Staatliches<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stalemate<
@@ -24173,6 +25731,7 @@ export declare function Stalemate<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stalinist_One<
@@ -24185,6 +25744,7 @@ export declare function Stalinist_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stardos_Stencil<
@@ -24197,6 +25757,7 @@ export declare function Stardos_Stencil<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stick<
@@ -24209,6 +25770,7 @@ export declare function Stick<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stick_No_Bills<
@@ -24230,6 +25792,7 @@ export declare function Stick_No_Bills<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sinhala'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stint_Ultra_Condensed<
@@ -24242,6 +25805,7 @@ export declare function Stint_Ultra_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stint_Ultra_Expanded<
@@ -24254,6 +25818,7 @@ export declare function Stint_Ultra_Expanded<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stoke<
@@ -24266,6 +25831,7 @@ export declare function Stoke<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Strait<
@@ -24278,6 +25844,7 @@ export declare function Strait<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Style_Script<
@@ -24290,6 +25857,7 @@ export declare function Style_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Stylish<
@@ -24302,6 +25870,7 @@ export declare function Stylish<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sue_Ellen_Francisco<
@@ -24314,6 +25883,7 @@ export declare function Sue_Ellen_Francisco<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Suez_One<
@@ -24326,6 +25896,7 @@ export declare function Suez_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sulphur_Point<
@@ -24338,6 +25909,7 @@ export declare function Sulphur_Point<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sumana<
@@ -24350,6 +25922,7 @@ export declare function Sumana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sunflower<
@@ -24362,6 +25935,7 @@ export declare function Sunflower<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sunshiney<
@@ -24374,6 +25948,7 @@ export declare function Sunshiney<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Supermercado_One<
@@ -24386,6 +25961,7 @@ export declare function Supermercado_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Sura<
@@ -24398,6 +25974,7 @@ export declare function Sura<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Suranna<
@@ -24410,6 +25987,7 @@ export declare function Suranna<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Suravaram<
@@ -24422,6 +26000,7 @@ export declare function Suravaram<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Suwannaphum<
@@ -24440,6 +26019,7 @@ export declare function Suwannaphum<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Swanky_and_Moo_Moo<
@@ -24452,6 +26032,7 @@ export declare function Swanky_and_Moo_Moo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Syncopate<
@@ -24464,6 +26045,7 @@ export declare function Syncopate<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Syne<
@@ -24483,6 +26065,7 @@ export declare function Syne<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Syne_Mono<
@@ -24495,6 +26078,7 @@ export declare function Syne_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Syne_Tactile<
@@ -24507,6 +26091,7 @@ export declare function Syne_Tactile<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tac_One<
@@ -24519,6 +26104,7 @@ export declare function Tac_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'math' | 'symbols' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tai_Heritage_Pro<
@@ -24531,6 +26117,7 @@ export declare function Tai_Heritage_Pro<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tai-viet' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tajawal<
@@ -24551,6 +26138,7 @@ export declare function Tajawal<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tangerine<
@@ -24563,6 +26151,7 @@ export declare function Tangerine<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tapestry<
@@ -24575,6 +26164,7 @@ export declare function Tapestry<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Taprom<
@@ -24587,6 +26177,7 @@ export declare function Taprom<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'khmer' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tauri<
@@ -24599,6 +26190,7 @@ export declare function Tauri<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Taviraj<
@@ -24623,6 +26215,7 @@ export declare function Taviraj<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Teachers<
@@ -24642,6 +26235,7 @@ export declare function Teachers<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'greek-ext' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Teko<
@@ -24661,6 +26255,7 @@ export declare function Teko<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tektur<
@@ -24681,6 +26276,7 @@ export declare function Tektur<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -24696,6 +26292,7 @@ export declare function Telex<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tenali_Ramakrishna<
@@ -24708,6 +26305,7 @@ export declare function Tenali_Ramakrishna<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tenor_Sans<
@@ -24720,6 +26318,7 @@ export declare function Tenor_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Text_Me_One<
@@ -24732,6 +26331,7 @@ export declare function Text_Me_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Texturina<
@@ -24757,6 +26357,7 @@ export declare function Texturina<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'opsz'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -24770,6 +26371,7 @@ export declare function Thasadith<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function The_Girl_Next_Door<
@@ -24782,6 +26384,7 @@ export declare function The_Girl_Next_Door<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function The_Nautigal<
@@ -24794,6 +26397,7 @@ export declare function The_Nautigal<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tienne<
@@ -24806,6 +26410,7 @@ export declare function Tienne<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tillana<
@@ -24824,6 +26429,7 @@ export declare function Tillana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tilt_Neon<
@@ -24836,6 +26442,7 @@ export declare function Tilt_Neon<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('XROT' | 'YROT')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -24849,6 +26456,7 @@ export declare function Tilt_Prism<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('XROT' | 'YROT')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -24862,6 +26470,7 @@ export declare function Tilt_Warp<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('XROT' | 'YROT')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -24875,6 +26484,7 @@ export declare function Timmana<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tinos<
@@ -24887,6 +26497,7 @@ export declare function Tinos<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -24908,6 +26519,7 @@ export declare function Tiny5<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Bangla<
@@ -24920,6 +26532,7 @@ export declare function Tiro_Bangla<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'bengali' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Devanagari_Hindi<
@@ -24932,6 +26545,7 @@ export declare function Tiro_Devanagari_Hindi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Devanagari_Marathi<
@@ -24944,6 +26558,7 @@ export declare function Tiro_Devanagari_Marathi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Devanagari_Sanskrit<
@@ -24956,6 +26571,7 @@ export declare function Tiro_Devanagari_Sanskrit<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Gurmukhi<
@@ -24968,6 +26584,7 @@ export declare function Tiro_Gurmukhi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'gurmukhi' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Kannada<
@@ -24980,6 +26597,7 @@ export declare function Tiro_Kannada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'kannada' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Tamil<
@@ -24992,6 +26610,7 @@ export declare function Tiro_Tamil<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'tamil'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tiro_Telugu<
@@ -25004,6 +26623,7 @@ export declare function Tiro_Telugu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'telugu'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Titan_One<
@@ -25016,6 +26636,7 @@ export declare function Titan_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Titillium_Web<
@@ -25035,6 +26656,7 @@ export declare function Titillium_Web<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tomorrow<
@@ -25059,6 +26681,7 @@ export declare function Tomorrow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tourney<
@@ -25084,6 +26707,7 @@ export declare function Tourney<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -25097,6 +26721,7 @@ export declare function Trade_Winds<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Train_One<
@@ -25109,6 +26734,7 @@ export declare function Train_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Trirong<
@@ -25133,6 +26759,7 @@ export declare function Trirong<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'thai' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Trispace<
@@ -25155,6 +26782,7 @@ export declare function Trispace<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: 'wdth'[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -25168,6 +26796,7 @@ export declare function Trocchi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Trochut<
@@ -25180,6 +26809,7 @@ export declare function Trochut<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Truculenta<
@@ -25205,6 +26835,7 @@ export declare function Truculenta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
   axes?: ('opsz' | 'wdth')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -25218,6 +26849,7 @@ export declare function Trykker<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tsukimi_Rounded<
@@ -25236,6 +26868,7 @@ export declare function Tsukimi_Rounded<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Tulpen_One<
@@ -25248,6 +26881,7 @@ export declare function Tulpen_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Turret_Road<
@@ -25267,6 +26901,7 @@ export declare function Turret_Road<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Twinkle_Star<
@@ -25279,6 +26914,7 @@ export declare function Twinkle_Star<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ubuntu<
@@ -25291,6 +26927,7 @@ export declare function Ubuntu<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -25305,6 +26942,7 @@ export declare function Ubuntu_Condensed<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -25319,6 +26957,7 @@ export declare function Ubuntu_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -25343,6 +26982,7 @@ export declare function Ubuntu_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -25364,6 +27004,7 @@ export declare function Ubuntu_Sans_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'greek-ext' | 'latin' | 'latin-ext'
   >
@@ -25378,6 +27019,7 @@ export declare function Uchen<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'tibetan'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ultra<
@@ -25390,6 +27032,7 @@ export declare function Ultra<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Unbounded<
@@ -25412,6 +27055,7 @@ export declare function Unbounded<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -25426,6 +27070,7 @@ export declare function Uncial_Antiqua<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Underdog<
@@ -25438,6 +27083,7 @@ export declare function Underdog<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Unica_One<
@@ -25450,6 +27096,7 @@ export declare function Unica_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function UnifrakturCook<
@@ -25462,6 +27109,7 @@ export declare function UnifrakturCook<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function UnifrakturMaguntia<
@@ -25474,6 +27122,7 @@ export declare function UnifrakturMaguntia<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Unkempt<
@@ -25486,6 +27135,7 @@ export declare function Unkempt<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Unlock<
@@ -25498,6 +27148,7 @@ export declare function Unlock<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Unna<
@@ -25510,6 +27161,7 @@ export declare function Unna<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Updock<
@@ -25522,6 +27174,7 @@ export declare function Updock<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Urbanist<
@@ -25547,6 +27200,7 @@ export declare function Urbanist<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function VT323<
@@ -25559,6 +27213,7 @@ export declare function VT323<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vampiro_One<
@@ -25571,6 +27226,7 @@ export declare function Vampiro_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Varela<
@@ -25583,6 +27239,7 @@ export declare function Varela<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Varela_Round<
@@ -25595,6 +27252,7 @@ export declare function Varela_Round<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'hebrew' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Varta<
@@ -25614,6 +27272,7 @@ export declare function Varta<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vast_Shadow<
@@ -25626,6 +27285,7 @@ export declare function Vast_Shadow<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vazirmatn<
@@ -25651,6 +27311,7 @@ export declare function Vazirmatn<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vesper_Libre<
@@ -25663,6 +27324,7 @@ export declare function Vesper_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Viaoda_Libre<
@@ -25675,6 +27337,7 @@ export declare function Viaoda_Libre<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -25689,6 +27352,7 @@ export declare function Vibes<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vibur<
@@ -25701,6 +27365,7 @@ export declare function Vibur<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Victor_Mono<
@@ -25722,6 +27387,7 @@ export declare function Victor_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -25736,6 +27402,7 @@ export declare function Vidaloka<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Viga<
@@ -25748,6 +27415,7 @@ export declare function Viga<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vina_Sans<
@@ -25760,6 +27428,7 @@ export declare function Vina_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Voces<
@@ -25772,6 +27441,7 @@ export declare function Voces<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Volkhov<
@@ -25784,6 +27454,7 @@ export declare function Volkhov<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vollkorn<
@@ -25804,6 +27475,7 @@ export declare function Vollkorn<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'greek' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -25818,6 +27490,7 @@ export declare function Vollkorn_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -25832,6 +27505,7 @@ export declare function Voltaire<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Vujahday_Script<
@@ -25844,6 +27518,7 @@ export declare function Vujahday_Script<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Waiting_for_the_Sunrise<
@@ -25856,6 +27531,7 @@ export declare function Waiting_for_the_Sunrise<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Wallpoet<
@@ -25868,6 +27544,7 @@ export declare function Wallpoet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Walter_Turncoat<
@@ -25880,6 +27557,7 @@ export declare function Walter_Turncoat<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Warnes<
@@ -25892,6 +27570,7 @@ export declare function Warnes<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Water_Brush<
@@ -25904,6 +27583,7 @@ export declare function Water_Brush<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Waterfall<
@@ -25916,6 +27596,7 @@ export declare function Waterfall<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Wavefont<
@@ -25951,6 +27632,7 @@ export declare function Wavefont<
 
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
 
   axes?: ('ROND' | 'YELA')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -25964,6 +27646,7 @@ export declare function Wellfleet<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Wendy_One<
@@ -25976,6 +27659,7 @@ export declare function Wendy_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Whisper<
@@ -25988,6 +27672,7 @@ export declare function Whisper<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function WindSong<
@@ -26000,6 +27685,7 @@ export declare function WindSong<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Wire_One<
@@ -26012,6 +27698,7 @@ export declare function Wire_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Wittgenstein<
@@ -26032,6 +27719,7 @@ export declare function Wittgenstein<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Wix_Madefor_Display<
@@ -26051,6 +27739,7 @@ export declare function Wix_Madefor_Display<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -26072,6 +27761,7 @@ export declare function Wix_Madefor_Text<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -26099,6 +27789,7 @@ export declare function Work_Sans<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Workbench<
@@ -26111,6 +27802,7 @@ export declare function Workbench<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'math' | 'symbols'>
   axes?: ('BLED' | 'SCAN')[]
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -26124,6 +27816,7 @@ export declare function Xanh_Mono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yaldevi<
@@ -26144,6 +27837,7 @@ export declare function Yaldevi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'sinhala'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yanone_Kaffeesatz<
@@ -26164,6 +27858,7 @@ export declare function Yanone_Kaffeesatz<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -26191,6 +27886,7 @@ export declare function Yantramanav<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yarndings_12<
@@ -26203,6 +27899,7 @@ export declare function Yarndings_12<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yarndings_12_Charted<
@@ -26215,6 +27912,7 @@ export declare function Yarndings_12_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yarndings_20<
@@ -26227,6 +27925,7 @@ export declare function Yarndings_20<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yarndings_20_Charted<
@@ -26239,6 +27938,7 @@ export declare function Yarndings_20_Charted<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'math' | 'symbols'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yatra_One<
@@ -26251,6 +27951,7 @@ export declare function Yatra_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'devanagari' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yellowtail<
@@ -26263,6 +27964,7 @@ export declare function Yellowtail<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yeon_Sung<
@@ -26275,6 +27977,7 @@ export declare function Yeon_Sung<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yeseva_One<
@@ -26287,6 +27990,7 @@ export declare function Yeseva_One<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     'cyrillic' | 'cyrillic-ext' | 'latin' | 'latin-ext' | 'vietnamese'
   >
@@ -26301,6 +28005,7 @@ export declare function Yesteryear<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yomogi<
@@ -26313,6 +28018,7 @@ export declare function Yomogi<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Young_Serif<
@@ -26325,6 +28031,7 @@ export declare function Young_Serif<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yrsa<
@@ -26344,6 +28051,7 @@ export declare function Yrsa<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext' | 'vietnamese'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Ysabeau<
@@ -26381,6 +28089,7 @@ export declare function Ysabeau<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -26427,6 +28136,7 @@ export declare function Ysabeau_Infant<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -26473,6 +28183,7 @@ export declare function Ysabeau_Office<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -26519,6 +28230,7 @@ export declare function Ysabeau_SC<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<
     | 'cyrillic'
     | 'cyrillic-ext'
@@ -26540,6 +28252,7 @@ export declare function Yuji_Boku<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yuji_Hentaigana_Akari<
@@ -26552,6 +28265,7 @@ export declare function Yuji_Hentaigana_Akari<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yuji_Hentaigana_Akebono<
@@ -26564,6 +28278,7 @@ export declare function Yuji_Hentaigana_Akebono<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yuji_Mai<
@@ -26576,6 +28291,7 @@ export declare function Yuji_Mai<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yuji_Syuku<
@@ -26588,6 +28304,7 @@ export declare function Yuji_Syuku<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Yusei_Magic<
@@ -26600,6 +28317,7 @@ export declare function Yusei_Magic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function ZCOOL_KuaiLe<
@@ -26612,6 +28330,7 @@ export declare function ZCOOL_KuaiLe<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function ZCOOL_QingKe_HuangYou<
@@ -26624,6 +28343,7 @@ export declare function ZCOOL_QingKe_HuangYou<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function ZCOOL_XiaoWei<
@@ -26636,6 +28356,7 @@ export declare function ZCOOL_XiaoWei<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zain<
@@ -26655,6 +28376,7 @@ export declare function Zain<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'arabic' | 'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Antique<
@@ -26667,6 +28389,7 @@ export declare function Zen_Antique<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Antique_Soft<
@@ -26679,6 +28402,7 @@ export declare function Zen_Antique_Soft<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Dots<
@@ -26691,6 +28415,7 @@ export declare function Zen_Dots<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Kaku_Gothic_Antique<
@@ -26709,6 +28434,7 @@ export declare function Zen_Kaku_Gothic_Antique<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Kaku_Gothic_New<
@@ -26727,6 +28453,7 @@ export declare function Zen_Kaku_Gothic_New<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Kurenaido<
@@ -26739,6 +28466,7 @@ export declare function Zen_Kurenaido<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Loop<
@@ -26751,6 +28479,7 @@ export declare function Zen_Loop<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Maru_Gothic<
@@ -26769,6 +28498,7 @@ export declare function Zen_Maru_Gothic<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Old_Mincho<
@@ -26787,6 +28517,7 @@ export declare function Zen_Old_Mincho<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'cyrillic' | 'greek' | 'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zen_Tokyo_Zoo<
@@ -26799,6 +28530,7 @@ export declare function Zen_Tokyo_Zoo<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zeyada<
@@ -26811,6 +28543,7 @@ export declare function Zeyada<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zhi_Mang_Xing<
@@ -26823,6 +28556,7 @@ export declare function Zhi_Mang_Xing<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zilla_Slab<
@@ -26841,6 +28575,7 @@ export declare function Zilla_Slab<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
 export declare function Zilla_Slab_Highlight<
@@ -26853,5 +28588,6 @@ export declare function Zilla_Slab_Highlight<
   preload?: boolean
   fallback?: string[]
   adjustFontFallback?: boolean
+  fallbackMetrics?: FallbackMetrics
   subsets?: Array<'latin' | 'latin-ext'>
 }): T extends undefined ? NextFont : NextFontWithVariable
@@ -13,6 +13,11 @@ const DEFAULT_SERIF_FONT = {
   azAvgWidth: 854.3953488372093,
   unitsPerEm: 2048,
 }
+const DEFAULT_MONOSPACE_FONT = {
+  name: 'Courier New',
+  azAvgWidth: 1229,
+  unitsPerEm: 2048,
+}
 
 /**
  * Calculate the average character width of a font file.
@@ -75,7 +80,11 @@ export function getFallbackMetricsFromFontFile(
   category = 'serif'
 ): AdjustFontFallback {
   const fallbackFont =
-    category === 'serif' ? DEFAULT_SERIF_FONT : DEFAULT_SANS_SERIF_FONT
+    category === 'serif'
+      ? DEFAULT_SERIF_FONT
+      : category === 'monospace'
+        ? DEFAULT_MONOSPACE_FONT
+        : DEFAULT_SANS_SERIF_FONT
 
   const azAvgWidth = calcAverageWidth(font)
   const { ascent, descent, lineGap, unitsPerEm } = font
@@ -2,6 +2,7 @@
 import type {
   CssVariable,
   Display,
+  FallbackMetrics,
   NextFont,
   NextFontWithVariable,
 } from '../types'
@@ -13,11 +14,33 @@ type LocalFont<T extends CssVariable | undefined = undefined> = {
         path: string
         weight?: string
         style?: string
+        /**
+         * (`next --turbopack` only) The face to use from a TrueType
+         * Collection (`.ttc`). Defaults to the first one. The face is
+         * served as a font file of its own.
+         */
+        collectionIndex?: number
+        /**
+         * Overrides the top-level `display` for this file.
+         */
+        display?: Display
+        /**
+         * Overrides the top-level `preload` for this file, e.g. to only
+         * preload the weights used above the fold.
+         */
+        preload?: boolean
       }>
   display?: Display
   weight?: string
   style?: string
-  adjustFontFallback?: 'Arial' | 'Times New Roman' | false
+  adjustFontFallback?: 'Arial' | 'Times New Roman' | 'Courier New' | false
+  fallbackMetrics?: FallbackMetrics
+  /**
+   * (`next --turbopack` only) The face to use when `src` is a TrueType
+   * Collection (`.ttc`). Defaults to the first one. The face is served as a
+   * font file of its own.
+   */
+  collectionIndex?: number
   fallback?: string[]
   preload?: boolean
   variable?: T
@@ -35,13 +35,13 @@ const nextFontLocalFontLoader: FontLoader = async ({
   // Load all font files and emit them to the .next output directory
   // Also generate a @font-face CSS for each font file
   const fontFiles = await Promise.all(
-    src.map(async ({ path, style, weight, ext, format }) => {
+    src.map(async ({ path, style, weight, ext, format, ...overrides }) => {
       const resolved = await resolve(path)
       const fileBuffer = await promisify(loaderContext.fs.readFile)(resolved)
       const fontUrl = emitFontFile(
         fileBuffer,
         ext,
-        preload,
+        overrides.preload ?? preload,
         typeof adjustFontFallback === 'undefined' || !!adjustFontFallback
       )
 
@@ -61,7 +61,7 @@ const nextFontLocalFontLoader: FontLoader = async ({
           : []),
         ['font-family', variableName],
         ['src', `url(${fontUrl}) format('${format}')`],
-        ['font-display', display],
+        ['font-display', overrides.display ?? display],
         ...(weight ?? defaultWeight
           ? [['font-weight', weight ?? defaultWeight]]
           : []),
@@ -91,7 +91,11 @@ const nextFontLocalFontLoader: FontLoader = async ({
     if (fallbackFontFile.fontMetadata) {
       adjustFontFallbackMetrics = getFallbackMetricsFromFontFile(
         fallbackFontFile.fontMetadata,
-        adjustFontFallback === 'Times New Roman' ? 'serif' : 'sans-serif'
+        adjustFontFallback === 'Times New Roman'
+          ? 'serif'
+          : adjustFontFallback === 'Courier New'
+            ? 'monospace'
+            : 'sans-serif'
       )
     }
   }
@@ -35,6 +35,38 @@ describe('validateLocalFontFunctionCall', () => {
     `)
   })
 
+  test('Invalid display value for a font file', async () => {
+    expect(() =>
+      validateLocalFontFunctionCall('', {
+        src: [{ path: './font-file.woff2', display: 'invalid' }],
+      })
+    ).toThrowErrorMatchingInlineSnapshot(`
+      "Invalid display value \`invalid\` for \`./font-file.woff2\`.
+      Available display values: \`auto\`, \`block\`, \`swap\`, \`fallback\`, \`optional\`"
+    `)
+  })
+
+  test('Invalid adjustFontFallback value', async () => {
+    expect(() =>
+      validateLocalFontFunctionCall('', {
+        src: './font-file.woff2',
+        adjustFontFallback: 'Helvetica',
+      })
+    ).toThrowErrorMatchingInlineSnapshot(`
+      "Invalid adjustFontFallback value \`Helvetica\`.
+      Available adjustFontFallback values: \`Arial\`, \`Times New Roman\`, \`Courier New\`, \`false\`"
+    `)
+  })
+
+  test('Courier New adjustFontFallback', async () => {
+    expect(
+      validateLocalFontFunctionCall('', {
+        src: './font-file.woff2',
+        adjustFontFallback: 'Courier New',
+      }).adjustFontFallback
+    ).toBe('Courier New')
+  })
+
   test('Invalid declaration', async () => {
     expect(() =>
       validateLocalFontFunctionCall('', {
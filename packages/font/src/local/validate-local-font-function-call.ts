@@ -15,6 +15,8 @@ type FontOptions = {
     path: string
     weight?: string
     style?: string
+    display?: string
+    preload?: boolean
     ext: string
     format: string
   }>
@@ -24,10 +26,16 @@ type FontOptions = {
   fallback?: string[]
   preload: boolean
   variable?: string
-  adjustFontFallback?: string | false
+  adjustFontFallback?: 'Arial' | 'Times New Roman' | 'Courier New' | false
   declarations?: Array<{ prop: string; value: string }>
 }
 
+const allowedAdjustFontFallbackValues = [
+  'Arial',
+  'Times New Roman',
+  'Courier New',
+]
+
 /**
  * Validate the data recieved from next-swc next-transform-font on next/font/local calls
  */
@@ -62,6 +70,18 @@ export function validateLocalFontFunctionCall(
     nextFontError('Missing required `src` property')
   }
 
+  if (
+    adjustFontFallback !== undefined &&
+    typeof adjustFontFallback !== 'boolean' &&
+    !allowedAdjustFontFallbackValues.includes(adjustFontFallback)
+  ) {
+    nextFontError(
+      `Invalid adjustFontFallback value \`${adjustFontFallback}\`.\nAvailable adjustFontFallback values: ${formatAvailableValues(
+        allowedAdjustFontFallbackValues
+      )}, \`false\``
+    )
+  }
+
   if (!Array.isArray(src)) {
     src = [{ path: src, weight, style }]
   } else {
@@ -75,6 +95,16 @@ export function validateLocalFontFunctionCall(
     if (!ext) {
       nextFontError(`Unexpected file \`${fontFile.path}\``)
     }
+    if (
+      fontFile.display !== undefined &&
+      !allowedDisplayValues.includes(fontFile.display)
+    ) {
+      nextFontError(
+        `Invalid display value \`${fontFile.display}\` for \`${fontFile.path}\`.\nAvailable display values: ${formatAvailableValues(
+          allowedDisplayValues
+        )}`
+      )
+    }
 
     return {
       ...fontFile,
@@ -10,3 +10,21 @@ export type NextFont = {
 export type NextFontWithVariable = NextFont & {
   variable: string
 }
+
+/**
+ * (`next --turbopack` only) Metrics of the automatic fallback fonts, keyed by
+ * the name of the fallback font, e.g. `{ Arial: { sizeAdjust: 1.07 } }`. They
+ * take precedence over the computed metrics, and `false` leaves that fallback
+ * font unadjusted. Fonts without known metrics get an adjusted fallback when
+ * all four metrics are provided.
+ */
+export type FallbackMetrics = Record<
+  string,
+  | {
+      ascent?: number
+      descent?: number
+      lineGap?: number
+      sizeAdjust?: number
+    }
+  | false
+>
@@ -123,7 +123,25 @@ program
   .option('--no-lint', 'Disables linting.')
   .option('--no-mangling', 'Disables mangling.')
   .option('--profile', 'Enables production profiling for React.')
+  .option(
+    '--annotate-chunks',
+    'Disables minification and annotates each module in the emitted chunks with its path and package version. Only supported with Turbopack.'
+  )
+  .option(
+    '--report-suppressions',
+    'Reports the issues suppressed in turbopack-suppressions.json per owner, and the expired and unused suppressions. Only supported with Turbopack.'
+  )
+  .addOption(
+    new Option(
+      '--export-graph [mode]',
+      'Writes the module graph, the config and the issues of the build to `graph-export.json` in the dist dir to attach to bug reports. The paths of the project are anonymized unless the mode is "full", which includes the sources as well. Only supported with Turbopack.'
+    ).choices(['anonymized', 'full'])
+  )
   .option('--experimental-app-only', 'Builds only App Router routes.')
+  .option(
+    '--trace-resolution <glob>',
+    'Writes the steps of resolving the requests matching the glob to `trace-resolution.jsonl` in the dist dir. Only supported with Turbopack.'
+  )
   .addOption(new Option('--experimental-turbo').hideHelp())
   .addOption(
     new Option(
@@ -198,6 +216,15 @@ program
     '--experimental-upload-trace, <traceUrl>',
     'Reports a subset of the debugging trace to a remote HTTP URL. Includes sensitive data.'
   )
+  .option(
+    '--focus <globs...>',
+    'Only compiles routes matching the globs eagerly and rebuilds them on change with Turbopack. Other routes are built when requested. The focus is remembered for later runs.'
+  )
+  .option('--no-focus', 'Clears a previously remembered focus.')
+  .option(
+    '--trace-resolution <glob>',
+    'Writes the steps of resolving the requests matching the glob to `trace-resolution.jsonl` in the dist dir. Only supported with Turbopack.'
+  )
   .action(
     (directory: string, options: NextDevOptions, { _optionValueSources }) => {
       const portSource = _optionValueSources.port
@@ -421,4 +448,22 @@ internal
     )
   })
 
+internal
+  .command('inspect-graph')
+  .argument('<file>', 'Graph export written by `next build --export-graph`.')
+  .option(
+    '--why <module>',
+    'Prints the shortest chain of imports from an entry to the first module whose path includes the given string.'
+  )
+  .option(
+    '--largest <n>',
+    'Number of the largest modules to list.',
+    parseValidPositiveInteger
+  )
+  .action((file: string, options: { why?: string; largest?: number }) => {
+    return import('../cli/internal/inspect-graph.js').then((mod) =>
+      mod.inspectGraphCli(file, options)
+    )
+  })
+
 program.parse(process.argv)
@@ -0,0 +1,28 @@
+import { readFile, writeFile } from 'fs/promises'
+import path from 'path'
+import type { BrowserExtensionManifestTransform } from '../server/config-shared'
+
+/**
+ * The directory of the dist dir the browser extension configured through
+ * `experimental.turbo.browserExtension` is written to.
+ */
+export const BROWSER_EXTENSION_DIR = 'extension'
+
+/**
+ * Passes the `manifest.json` of the browser extension, with the generated
+ * file names filled in, to the `transformManifest` hook of the config and
+ * writes the result back.
+ */
+export async function transformBrowserExtensionManifest(
+  distDir: string,
+  transformManifest: BrowserExtensionManifestTransform
+): Promise<void> {
+  const manifestPath = path.join(
+    distDir,
+    BROWSER_EXTENSION_DIR,
+    'manifest.json'
+  )
+  const manifest = JSON.parse(await readFile(manifestPath, 'utf8'))
+  const transformed = (await transformManifest(manifest)) ?? manifest
+  await writeFile(manifestPath, JSON.stringify(transformed, null, 2))
+}
@@ -0,0 +1,62 @@
+import { existsSync } from 'fs'
+import { mkdir, mkdtemp, readFile, rm, writeFile } from 'fs/promises'
+import { tmpdir } from 'os'
+import path from 'path'
+import { cleanOutput, OUTPUT_ARTIFACTS_MANIFEST } from './clean-output'
+
+describe('cleanOutput()', () => {
+  let distDir: string
+
+  async function write(file: string) {
+    await mkdir(path.dirname(path.join(distDir, file)), { recursive: true })
+    await writeFile(path.join(distDir, file), '')
+  }
+
+  beforeEach(async () => {
+    distDir = await mkdtemp(path.join(tmpdir(), 'next-clean-output-'))
+  })
+
+  afterEach(async () => {
+    await rm(distDir, { recursive: true, force: true })
+  })
+
+  it('removes the assets of the previous build which are not written anymore', async () => {
+    await write('static/chunks/a.js')
+    await write('static/chunks/b.js')
+    await write('cache/data.json')
+    expect(
+      await cleanOutput(distDir, ['static/chunks/a.js', 'static/chunks/b.js'])
+    ).toEqual([])
+
+    expect(await cleanOutput(distDir, ['static/chunks/a.js'])).toEqual([
+      'static/chunks/b.js',
+    ])
+    expect(existsSync(path.join(distDir, 'static/chunks/a.js'))).toBe(true)
+    expect(existsSync(path.join(distDir, 'static/chunks/b.js'))).toBe(false)
+    expect(existsSync(path.join(distDir, 'cache/data.json'))).toBe(true)
+    expect(
+      JSON.parse(
+        await readFile(path.join(distDir, OUTPUT_ARTIFACTS_MANIFEST), 'utf8')
+      ).paths
+    ).toEqual(['static/chunks/a.js'])
+  })
+
+  it('keeps the assets matching the keep globs', async () => {
+    await write('static/chunks/a.js')
+    await write('static/media/logo.png')
+    await cleanOutput(distDir, ['static/chunks/a.js', 'static/media/logo.png'])
+
+    expect(await cleanOutput(distDir, [], ['static/media/**'])).toEqual([
+      'static/chunks/a.js',
+    ])
+    expect(existsSync(path.join(distDir, 'static/media/logo.png'))).toBe(true)
+  })
+
+  it('never removes files outside of the dist dir', async () => {
+    await writeFile(
+      path.join(distDir, OUTPUT_ARTIFACTS_MANIFEST),
+      JSON.stringify({ version: 1, paths: ['../outside.js'] })
+    )
+    expect(await cleanOutput(distDir, [])).toEqual([])
+  })
+})
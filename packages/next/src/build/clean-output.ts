@@ -0,0 +1,71 @@
+import { readFile, rm, writeFile } from 'fs/promises'
+import path from 'path'
+import picomatch from 'next/dist/compiled/picomatch'
+
+/**
+ * Lists the assets written by the last build, relative to the dist dir, so
+ * that the next build can remove the ones it doesn't write anymore.
+ */
+export const OUTPUT_ARTIFACTS_MANIFEST = 'output-artifacts-manifest.json'
+
+type OutputArtifactsManifest = {
+  version: 1
+  paths: string[]
+}
+
+async function readOutputArtifactsManifest(
+  manifestPath: string
+): Promise<string[]> {
+  try {
+    const manifest: OutputArtifactsManifest = JSON.parse(
+      await readFile(manifestPath, 'utf8')
+    )
+    if (manifest.version === 1 && Array.isArray(manifest.paths)) {
+      return manifest.paths
+    }
+  } catch {}
+  return []
+}
+
+/**
+ * Removes the assets written by the previous build which weren't written by
+ * this build, unless they match one of the `keep` globs, and records the
+ * assets of this build for the next one. Files which were never written by a
+ * build are left alone.
+ *
+ * Returns the removed paths, relative to the dist dir.
+ */
+export async function cleanOutput(
+  distDir: string,
+  outputPaths: string[],
+  keep: string[] = []
+): Promise<string[]> {
+  const manifestPath = path.join(distDir, OUTPUT_ARTIFACTS_MANIFEST)
+  const previousPaths = await readOutputArtifactsManifest(manifestPath)
+  const currentPaths = new Set(outputPaths)
+  const isKept =
+    keep.length > 0 ? picomatch(keep, { dot: true }) : () => false
+
+  const removed: string[] = []
+  for (const previousPath of previousPaths) {
+    if (currentPaths.has(previousPath) || isKept(previousPath)) {
+      continue
+    }
+    const filePath = path.join(distDir, previousPath)
+    // Never remove anything outside of the dist dir, the manifest might have
+    // been edited by hand.
+    if (path.relative(distDir, filePath).startsWith('..')) {
+      continue
+    }
+    await rm(filePath, { force: true })
+    removed.push(previousPath)
+  }
+
+  const manifest: OutputArtifactsManifest = {
+    version: 1,
+    paths: [...currentPaths].sort(),
+  }
+  await writeFile(manifestPath, JSON.stringify(manifest, null, 2))
+
+  return removed
+}
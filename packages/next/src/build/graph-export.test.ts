@@ -0,0 +1,68 @@
+import {
+  anonymizePath,
+  findImportChain,
+  type TurbopackGraph,
+} from './graph-export'
+
+function createGraph(paths: string[], edges: [number, number][]) {
+  return {
+    modules: paths.map((path) => ({
+      path,
+      layer: null,
+      size: null,
+      source: null,
+    })),
+    entries: [0],
+    edges,
+  } satisfies TurbopackGraph
+}
+
+describe('anonymizePath', () => {
+  it('hashes the segments of project files and keeps extensions', () => {
+    const anonymized = anonymizePath('[project]/app/dashboard/page.tsx')
+    expect(anonymized).toMatch(
+      /^\[project\]\/[0-9a-f]{8}\/[0-9a-f]{8}\/[0-9a-f]{8}\.tsx$/
+    )
+    expect(anonymized).not.toContain('dashboard')
+    expect(anonymizePath('[project]/app/dashboard/page.tsx')).toBe(anonymized)
+  })
+
+  it('keeps packages and internal modules', () => {
+    expect(anonymizePath('[project]/node_modules/react/index.js')).toBe(
+      '[project]/node_modules/react/index.js'
+    )
+    expect(
+      anonymizePath('[project]/packages/ui/node_modules/lodash/get.js')
+    ).toMatch(
+      /^\[project\]\/[0-9a-f]{8}\/[0-9a-f]{8}\/node_modules\/lodash\/get\.js$/
+    )
+    expect(anonymizePath('[next]/entry/app-entry.tsx')).toBe(
+      '[next]/entry/app-entry.tsx'
+    )
+  })
+})
+
+describe('findImportChain', () => {
+  const graph = createGraph(
+    [
+      '[project]/app/page.tsx',
+      '[project]/app/header.tsx',
+      '[project]/app/footer.tsx',
+      '[project]/node_modules/lodash/get.js',
+    ],
+    [
+      [0, 1],
+      [0, 2],
+      [1, 2],
+      [2, 3],
+    ]
+  )
+
+  it('returns the shortest chain from an entry', () => {
+    expect(findImportChain(graph, 'lodash')).toEqual([0, 2, 3])
+  })
+
+  it('returns undefined when no module matches', () => {
+    expect(findImportChain(graph, 'react')).toBeUndefined()
+  })
+})
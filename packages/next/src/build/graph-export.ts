@@ -0,0 +1,255 @@
+import { createHash } from 'crypto'
+import { readFile, writeFile } from 'fs/promises'
+import path from 'path'
+import type { Issue } from './swc/types'
+import { getIssueFingerprint } from './turbopack-suppressions'
+import { renderStyledStringToErrorAnsi } from '../server/dev/turbopack-utils'
+import stripAnsi from 'next/dist/compiled/strip-ansi'
+
+/**
+ * A reproduction of a build written to the dist dir by
+ * `next build --export-graph`, to attach to bug reports.
+ */
+export const GRAPH_EXPORT_FILE = 'graph-export.json'
+
+/**
+ * `anonymized` replaces the names of the files of the project with hashes and
+ * leaves out their sources, `full` keeps the paths and includes the sources.
+ */
+export type GraphExportMode = 'anonymized' | 'full'
+
+/** The module graph of the app, serialized by Turbopack. */
+export interface TurbopackGraph {
+  modules: {
+    /** Prefixed with the name of its file system, e.g. `[project]/app/page.tsx` */
+    path: string
+    layer: string | null
+    /** The size of the source in bytes. */
+    size: number | null
+    source: string | null
+  }[]
+  /** The indices of the modules the app starts from. */
+  entries: number[]
+  /** The importing and the imported module of each reference. */
+  edges: [number, number][]
+}
+
+export interface GraphExportIssue {
+  page: string
+  severity: string
+  stage: string
+  filePath: string
+  fingerprint: string
+  /** Left out when anonymized, as it can contain the names of files. */
+  title?: string
+}
+
+export interface GraphExportBundle {
+  version: 1
+  nextVersion: string
+  anonymized: boolean
+  config: unknown
+  graph: TurbopackGraph
+  issues: GraphExportIssue[]
+}
+
+const PROJECT_PREFIX = '[project]/'
+
+function hashSegment(segment: string): string {
+  const extension = path.extname(segment)
+  const hash = createHash('sha1')
+    .update(segment.slice(0, segment.length - extension.length))
+    .digest('hex')
+    .slice(0, 8)
+  return `${hash}${extension}`
+}
+
+/**
+ * Replaces the names of the directories and files of the project in a module
+ * path with hashes, keeping the extensions. Packages in `node_modules` and
+ * the internal modules of Next.js are public and kept as they are.
+ */
+export function anonymizePath(modulePath: string): string {
+  if (!modulePath.startsWith(PROJECT_PREFIX)) {
+    return modulePath
+  }
+  let inNodeModules = false
+  const segments = modulePath
+    .slice(PROJECT_PREFIX.length)
+    .split('/')
+    .map((segment) => {
+      if (segment === 'node_modules') {
+        inNodeModules = true
+      }
+      return inNodeModules || segment === '' ? segment : hashSegment(segment)
+    })
+  return PROJECT_PREFIX + segments.join('/')
+}
+
+/**
+ * The config without functions and the values of `env`. When anonymized,
+ * the project directory in strings is replaced as well.
+ */
+function sanitizeConfig(config: unknown, dir: string, anonymized: boolean) {
+  return JSON.parse(
+    JSON.stringify(config, function (key, value) {
+      if (typeof value === 'function') {
+        return undefined
+      }
+      if (key === 'env' && value && typeof value === 'object') {
+        return Object.fromEntries(
+          Object.keys(value).map((name) => [name, '<redacted>'])
+        )
+      }
+      if (anonymized && typeof value === 'string' && value.includes(dir)) {
+        return value.split(dir).join('<project>')
+      }
+      return value
+    })
+  )
+}
+
+export async function writeGraphExport({
+  distDir,
+  dir,
+  mode,
+  config,
+  graph,
+  issues,
+}: {
+  distDir: string
+  dir: string
+  mode: GraphExportMode
+  config: unknown
+  graph: string
+  issues: { page: string; issue: Issue }[]
+}): Promise<string> {
+  const anonymized = mode === 'anonymized'
+  const parsedGraph: TurbopackGraph = JSON.parse(graph)
+  if (anonymized) {
+    for (const mod of parsedGraph.modules) {
+      mod.path = anonymizePath(mod.path)
+      mod.source = null
+    }
+  }
+
+  const bundle: GraphExportBundle = {
+    version: 1,
+    nextVersion: process.env.__NEXT_VERSION as string,
+    anonymized,
+    config: sanitizeConfig(config, dir, anonymized),
+    graph: parsedGraph,
+    issues: issues.map(({ page, issue }) => ({
+      page: anonymized ? anonymizePath(`${PROJECT_PREFIX}${page}`) : page,
+      severity: issue.severity,
+      stage: issue.stage,
+      filePath: anonymized ? anonymizePath(issue.filePath) : issue.filePath,
+      fingerprint: getIssueFingerprint(issue),
+      title: anonymized
+        ? undefined
+        : stripAnsi(renderStyledStringToErrorAnsi(issue.title)),
+    })),
+  }
+
+  const filePath = path.join(distDir, GRAPH_EXPORT_FILE)
+  await writeFile(filePath, JSON.stringify(bundle))
+  return filePath
+}
+
+export async function loadGraphExport(
+  filePath: string
+): Promise<GraphExportBundle> {
+  const bundle: GraphExportBundle = JSON.parse(
+    await readFile(filePath, 'utf8')
+  )
+  if (bundle.version !== 1) {
+    throw new Error(
+      `Unsupported graph export version ${bundle.version} in ${filePath}`
+    )
+  }
+  return bundle
+}
+
+/**
+ * The shortest chain of imports from an entry of the app to the first module
+ * whose path includes `query`, or `undefined` when no module matches.
+ */
+export function findImportChain(
+  graph: TurbopackGraph,
+  query: string
+): number[] | undefined {
+  const imports = new Map<number, number[]>()
+  for (const [from, to] of graph.edges) {
+    let targets = imports.get(from)
+    if (!targets) {
+      targets = []
+      imports.set(from, targets)
+    }
+    targets.push(to)
+  }
+
+  const importers = new Map<number, number | undefined>()
+  const queue: number[] = []
+  for (const entry of graph.entries) {
+    importers.set(entry, undefined)
+    queue.push(entry)
+  }
+  for (let i = 0; i < queue.length; i++) {
+    const mod = queue[i]
+    if (graph.modules[mod].path.includes(query)) {
+      const chain = [mod]
+      let importer = importers.get(mod)
+      while (importer !== undefined) {
+        chain.unshift(importer)
+        importer = importers.get(importer)
+      }
+      return chain
+    }
+    for (const target of imports.get(mod) ?? []) {
+      if (!importers.has(target)) {
+        importers.set(target, mod)
+        queue.push(target)
+      }
+    }
+  }
+  return undefined
+}
+
+/** A summary of the graph and the issues of an export, one line each. */
+export function summarizeGraphExport(
+  bundle: GraphExportBundle,
+  largest = 10
+): string[] {
+  const { modules, edges, entries } = bundle.graph
+  const lines = [
+    `Next.js ${bundle.nextVersion}${bundle.anonymized ? ', anonymized' : ''}`,
+    `${modules.length} modules, ${edges.length} references, ${entries.length} entries`,
+  ]
+
+  const layers = new Map<string, number>()
+  for (const mod of modules) {
+    const layer = mod.layer ?? '(no layer)'
+    layers.set(layer, (layers.get(layer) ?? 0) + 1)
+  }
+  for (const [layer, count] of [...layers].sort((a, b) => b[1] - a[1])) {
+    lines.push(`  ${layer}: ${count} modules`)
+  }
+
+  if (largest > 0) {
+    lines.push(`Largest modules:`)
+    for (const mod of [...modules]
+      .filter(({ size }) => size !== null)
+      .sort((a, b) => b.size! - a.size!)
+      .slice(0, largest)) {
+      lines.push(`  ${mod.size} B ${mod.path}`)
+    }
+  }
+
+  lines.push(`${bundle.issues.length} issues`)
+  for (const issue of bundle.issues) {
+    lines.push(
+      `  ${issue.severity} ${issue.stage} ${issue.filePath} (${issue.fingerprint})${issue.title ? `: ${issue.title}` : ''}`
+    )
+  }
+  return lines
+}
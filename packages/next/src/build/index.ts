@@ -119,6 +119,16 @@ import { PAGE_TYPES } from '../lib/page-types'
 import { generateBuildId } from './generate-build-id'
 import { isWriteable } from './is-writeable'
 import * as Log from './output/log'
+import {
+  getIssueFingerprint,
+  loadIssueSuppressions,
+  SUPPRESSION_FILE_NAME,
+} from './turbopack-suppressions'
+import {
+  GRAPH_EXPORT_FILE,
+  type GraphExportMode,
+  writeGraphExport,
+} from './graph-export'
 import createSpinner from './spinner'
 import { trace, flushAllTraces, setGlobal, type Span } from '../trace'
 import {
@@ -150,6 +160,7 @@ import {
   teardownHeapProfiler,
   createDefineEnv,
 } from './swc'
+import type { Issue } from './swc/types'
 import { getNamedRouteRegex } from '../shared/lib/router/utils/route-regex'
 import { getFilesInDir } from '../lib/get-files-in-dir'
 import { eventSwcPlugins } from '../telemetry/events/swc-plugins'
@@ -170,6 +181,11 @@ import { isAppRouteRoute } from '../lib/is-app-route-route'
 import { createClientRouterFilter } from '../lib/create-client-router-filter'
 import { createValidFileMatcher } from '../server/lib/find-page-file'
 import { startTypeChecking } from './type-check'
+import { cleanOutput, OUTPUT_ARTIFACTS_MANIFEST } from './clean-output'
+import {
+  BROWSER_EXTENSION_DIR,
+  transformBrowserExtensionManifest,
+} from './browser-extension'
 import { generateInterceptionRoutesRewrites } from '../lib/generate-interception-routes-rewrites'
 
 import { buildDataRoute } from '../server/lib/router-utils/build-data-route'
@@ -776,7 +792,10 @@ export default async function build(
   appDirOnly = false,
   turboNextBuild = false,
   experimentalBuildMode: 'default' | 'compile' | 'generate',
-  traceUploadUrl: string | undefined
+  traceUploadUrl: string | undefined,
+  annotateChunks = false,
+  reportSuppressions = false,
+  exportGraph?: GraphExportMode
 ): Promise<void> {
   const isCompileMode = experimentalBuildMode === 'compile'
   const isGenerateMode = experimentalBuildMode === 'generate'
@@ -1378,6 +1397,7 @@ export default async function build(
             encryptionKey: NextBuildContext.encryptionKey!,
             previewProps: NextBuildContext.previewProps!,
             browserslistQuery: supportedBrowsers.join(', '),
+            annotateChunks,
           },
           {
             persistentCaching: isPersistentCachingEnabled(config),
@@ -1547,6 +1567,28 @@ export default async function build(
           entrypoints: currentEntrypoints,
         })
 
+        const sitemap = await project.writeSitemap()
+        const featureUsageReport = await project.writeFeatureUsageReport()
+        const envUsageReport = await project.writeEnvUsageReport()
+        const duplicateModulesReport =
+          await project.writeDuplicateModulesReport()
+        const themes = await project.writeThemes()
+        const browserExtension = await project.writeBrowserExtension()
+        const chunkHashManifest = await project.writeChunkHashManifest()
+        const chunkIntegrityManifest =
+          await project.writeChunkIntegrityManifest()
+        const moduleFederation = await project.writeModuleFederation()
+        const transformManifest =
+          config.experimental.turbo?.browserExtension?.transformManifest
+        if (transformManifest && browserExtension.paths.length > 0) {
+          await transformBrowserExtensionManifest(distDir, transformManifest)
+        }
+        const outputClean = config.experimental.turbo?.outputClean
+        const outputPaths = outputClean
+          ? await project.getOutputPaths()
+          : undefined
+
+        const suppressions = await loadIssueSuppressions(dir)
         const errors: {
           page: string
           message: string
@@ -1555,24 +1597,108 @@ export default async function build(
           page: string
           message: string
         }[] = []
+        const exportedIssues: { page: string; issue: Issue }[] = []
+        const collectIssue = (page: string, issue: Issue) => {
+          exportedIssues.push({ page, issue })
+          const status = suppressions?.check(issue)
+          if (status?.type === 'suppressed') {
+            return
+          }
+          const message = `${formatIssue(issue)}Fingerprint: ${getIssueFingerprint(issue)}\n`
+          if (status?.type === 'expired') {
+            const { owner, expires } = status.suppression
+            errors.push({
+              page,
+              message: `${message}The suppression of this issue owned by ${owner} expired on ${expires}\n`,
+            })
+          } else if (issue.severity !== 'warning') {
+            errors.push({ page, message })
+          } else if (isRelevantWarning(issue)) {
+            warnings.push({ page, message })
+          }
+        }
         for (const [page, entryIssues] of currentEntryIssues) {
           for (const issue of entryIssues.values()) {
-            if (issue.severity !== 'warning') {
-              errors.push({
-                page,
-                message: formatIssue(issue),
-              })
-            } else {
-              if (isRelevantWarning(issue)) {
-                warnings.push({
-                  page,
-                  message: formatIssue(issue),
-                })
-              }
-            }
+            collectIssue(page, issue)
           }
         }
 
+        for (const issue of sitemap.issues) {
+          collectIssue('sitemap.xml', issue)
+        }
+        // The sitemap is emitted with the static files. It's served from the
+        // root after the filesystem, so a file in `public` takes precedence.
+        for (const sitemapPath of sitemap.paths) {
+          const rewrite = buildCustomRoute('rewrite', {
+            source: `${config.basePath}/${path.posix.basename(sitemapPath)}`,
+            destination: `${config.basePath}/_next/${sitemapPath}`,
+            basePath: false,
+            internal: true,
+          })
+          if (Array.isArray(routesManifest.rewrites)) {
+            routesManifest.rewrites.push(rewrite)
+          } else {
+            routesManifest.rewrites.afterFiles.push(rewrite)
+          }
+        }
+
+        for (const issue of featureUsageReport.issues) {
+          collectIssue('feature-usage.json', issue)
+        }
+        for (const issue of envUsageReport.issues) {
+          collectIssue('env-usage.json', issue)
+        }
+        for (const issue of duplicateModulesReport.issues) {
+          collectIssue('duplicate-modules.json', issue)
+        }
+        for (const reportPath of duplicateModulesReport.paths) {
+          Log.info(
+            `Wrote the modules duplicated across chunk groups to ${path.relative(dir, path.join(distDir, reportPath))}`
+          )
+        }
+
+        for (const issue of themes.issues) {
+          collectIssue('theme-manifest.json', issue)
+        }
+
+        for (const issue of browserExtension.issues) {
+          collectIssue(`${BROWSER_EXTENSION_DIR}/manifest.json`, issue)
+        }
+
+        for (const issue of chunkHashManifest.issues) {
+          collectIssue('chunk-hash-manifest.json', issue)
+        }
+
+        for (const issue of chunkIntegrityManifest.issues) {
+          collectIssue('static/chunks/integrity-manifest.json', issue)
+        }
+
+        for (const issue of moduleFederation.issues) {
+          collectIssue('remoteEntry.js', issue)
+        }
+
+        for (const issue of outputPaths?.issues ?? []) {
+          collectIssue(OUTPUT_ARTIFACTS_MANIFEST, issue)
+        }
+
+        if (exportGraph) {
+          const graphExport = await project.exportGraph(exportGraph === 'full')
+          for (const issue of graphExport.issues) {
+            collectIssue(GRAPH_EXPORT_FILE, issue)
+          }
+          const graphExportPath = await writeGraphExport({
+            distDir,
+            dir,
+            mode: exportGraph,
+            config,
+            graph: graphExport.graph,
+            issues: exportedIssues,
+          })
+          Log.info(
+            `Exported the module graph of the build to ${path.relative(dir, graphExportPath)}`
+          )
+        }
+
         const shutdownPromise = project.shutdown()
 
         if (warnings.length > 0) {
@@ -1585,6 +1711,13 @@ export default async function build(
           )
         }
 
+        if (reportSuppressions) {
+          Log.info(
+            suppressions?.formatDebtReport() ??
+              `No ${SUPPRESSION_FILE_NAME} found, no issues are suppressed`
+          )
+        }
+
         if (errors.length > 0) {
           throw new Error(
             `Turbopack build failed with ${errors.length} errors:\n${errors
@@ -1595,6 +1728,28 @@ export default async function build(
           )
         }
 
+        const warningBudget = suppressions?.warningBudget
+        if (warningBudget !== undefined && warnings.length > warningBudget) {
+          throw new Error(
+            `Turbopack build failed: it collected ${warnings.length} warnings, exceeding the warning budget of ${warningBudget} set in ${SUPPRESSION_FILE_NAME}`
+          )
+        }
+
+        // Only clean up after a successful build, the assets of the previous
+        // build are still needed when this one fails.
+        if (outputClean && outputPaths) {
+          const removed = await cleanOutput(
+            distDir,
+            outputPaths.paths,
+            outputClean === true ? [] : outputClean.keep
+          )
+          if (removed.length > 0) {
+            Log.info(
+              `Removed ${removed.length} stale assets of the previous build`
+            )
+          }
+        }
+
         const time = process.hrtime(startTime)
         return {
           duration: time[0] + time[1] / 1e9,
@@ -5,6 +5,7 @@ import type { StaticPathsResult } from './types'
 import type { CacheHandler } from '../../server/lib/incremental-cache'
 
 import path from 'path'
+import { promises as fs } from 'fs'
 import { AfterRunner } from '../../server/after/run-with-after'
 import { createWorkStore } from '../../server/async-storage/work-store'
 import { FallbackMode } from '../../lib/fallback'
@@ -20,6 +21,50 @@ import { nodeFs } from '../../server/lib/node-fs-methods'
 import { normalizePathname, encodeParam } from './utils'
 import * as ciEnvironment from '../../server/ci-info'
 import escapePathDelimiters from '../../shared/lib/router/utils/escape-path-delimiters'
+import {
+  SERVER_DIRECTORY,
+  STATIC_PARAMS_MANIFEST,
+} from '../../shared/lib/constants'
+import isError from '../../lib/is-error'
+
+/**
+ * The results of `generateStaticParams` that Turbopack evaluated at compile
+ * time, by the path of the module defining it in the loader tree.
+ */
+export type StaticParamsManifest = { [filePath: string]: Params[] }
+
+/**
+ * Loads the params of the segments of an app page that were evaluated at
+ * compile time, if any.
+ *
+ * @param distDir - The dist directory.
+ * @param page - The original app path of the page, e.g. `/blog/[slug]/page`.
+ * @returns The manifest, or `undefined` when it wasn't emitted.
+ */
+export async function loadStaticParamsManifest(
+  distDir: string,
+  page: string
+): Promise<StaticParamsManifest | undefined> {
+  try {
+    return JSON.parse(
+      await fs.readFile(
+        path.join(
+          distDir,
+          SERVER_DIRECTORY,
+          'app',
+          page,
+          STATIC_PARAMS_MANIFEST
+        ),
+        'utf8'
+      )
+    )
+  } catch (err) {
+    if (isError(err) && err.code === 'ENOENT') {
+      return undefined
+    }
+    throw err
+  }
+}
 
 /**
  * Compares two parameters to see if they're equal.
@@ -270,6 +315,7 @@ export async function buildAppStaticPaths({
   isRoutePPREnabled = false,
   buildId,
   rootParamKeys,
+  staticParamsManifest,
 }: {
   dir: string
   page: string
@@ -290,6 +336,7 @@ export async function buildAppStaticPaths({
   isRoutePPREnabled: boolean
   buildId: string
   rootParamKeys: readonly string[]
+  staticParamsManifest?: StaticParamsManifest
 }): Promise<Partial<StaticPathsResult>> {
   if (
     segments.some((generate) => generate.config?.dynamicParams === true) &&
@@ -379,6 +426,13 @@ export async function buildAppStaticPaths({
         const params: Params[] = []
 
         if (current.generateStaticParams) {
+          // Params evaluated at compile time don't depend on the params of the
+          // parent segments, so the function doesn't have to be called.
+          const staticParams =
+            current.filePath !== undefined
+              ? staticParamsManifest?.[current.filePath]
+              : undefined
+
           // fetchCache can be used to inform the fetch() defaults used inside
           // of generateStaticParams. revalidate and dynamic options don't come into
           // play within generateStaticParams.
@@ -388,16 +442,20 @@ export async function buildAppStaticPaths({
 
           if (parentsParams.length > 0) {
             for (const parentParams of parentsParams) {
-              const result = await current.generateStaticParams({
-                params: parentParams,
-              })
+              const result =
+                staticParams ??
+                (await current.generateStaticParams({
+                  params: parentParams,
+                }))
 
               for (const item of result) {
                 params.push({ ...parentParams, ...item })
               }
             }
           } else {
-            const result = await current.generateStaticParams({ params: {} })
+            const result =
+              staticParams ??
+              (await current.generateStaticParams({ params: {} }))
 
             params.push(...result)
           }
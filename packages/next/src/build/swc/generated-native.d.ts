@@ -65,6 +65,18 @@ export interface NapiWrittenEndpoint {
 export declare function endpointWriteToDisk(endpoint: {
   __napiType: 'Endpoint'
 }): Promise<TurbopackResult>
+export interface NapiClientModuleSize {
+  path: string
+  package?: string
+  size: number
+}
+/**
+ * Returns the modules in the client chunks of the endpoint with their size,
+ * see [client_module_sizes].
+ */
+export declare function endpointClientModuleSizes(endpoint: {
+  __napiType: 'Endpoint'
+}): Promise<Array<NapiClientModuleSize>>
 export declare function endpointServerChangedSubscribe(
   endpoint: { __napiType: 'Endpoint' },
   issues: boolean,
@@ -128,6 +140,11 @@ export interface NapiProjectOptions {
   previewProps: NapiDraftModeOptions
   /** The browserslist query to use for targeting browsers. */
   browserslistQuery: string
+  /**
+   * Disable minification and annotate each module in the emitted chunks
+   * with its path and package version.
+   */
+  annotateChunks?: boolean
 }
 /** [NapiProjectOptions] with all fields optional. */
 export interface NapiPartialProjectOptions {
@@ -239,6 +256,178 @@ export declare function projectHmrIdentifiersSubscribe(
   project: { __napiType: 'Project' },
   func: (...args: any[]) => any
 ): { __napiType: 'RootTask' }
+export interface NapiWrittenSitemap {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the `sitemap.xml` and `robots.txt` configured through
+ * `experimental.sitemap` to the dist dir, returning their paths relative to
+ * it.
+ */
+export declare function projectWriteSitemap(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenFeatureUsageReport {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the `feature-usage.json` enabled through
+ * `experimental.turbo.featureUsageReport` to the dist dir, returning its path
+ * relative to it.
+ */
+export declare function projectWriteFeatureUsageReport(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenEnvUsageReport {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the `env-usage.json` enabled through
+ * `experimental.turbo.envUsageReport` to the dist dir, returning its path
+ * relative to it.
+ */
+export declare function projectWriteEnvUsageReport(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenDuplicateModulesReport {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the `duplicate-modules.json` of the build stats to the dist dir when
+ * `TURBOPACK_STATS` is set, returning its path relative to it.
+ */
+export declare function projectWriteDuplicateModulesReport(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenThemes {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the CSS chunks of the themes configured through
+ * `experimental.turbo.themes` and the `theme-manifest.json` listing them,
+ * returning the path of the manifest relative to the dist dir.
+ */
+export declare function projectWriteThemes(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenChunkHashManifest {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the `chunk-hash-manifest.json` enabled through
+ * `experimental.turbo.contentHashing` to the dist dir, returning its path
+ * relative to it.
+ */
+export declare function projectWriteChunkHashManifest(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenChunkIntegrityManifest {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the `static/chunks/integrity-manifest.json` enabled through
+ * `experimental.turbo.assetPrefixFallback` to the dist dir, returning its
+ * path relative to it.
+ */
+export declare function projectWriteChunkIntegrityManifest(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenModuleFederation {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the remote entry and the chunks of the container configured in
+ * `experimental.turbo.moduleFederation` to the dist dir, returning their
+ * paths relative to it.
+ */
+export declare function projectWriteModuleFederation(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiWrittenBrowserExtension {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Writes the browser extension configured through
+ * `experimental.turbo.browserExtension` to the `extension` directory of the
+ * dist dir, returning the paths of the written files relative to the dist dir.
+ */
+export declare function projectWriteBrowserExtension(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiOutputPaths {
+  /** Relative to the dist dir */
+  paths: Array<string>
+}
+/**
+ * Lists the paths of all assets written when building the app, so that the
+ * assets of a previous build which aren't written anymore can be removed.
+ */
+export declare function projectOutputPaths(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
+export interface NapiGraphExport {
+  /** The module graph as JSON. */
+  graph: string
+}
+/**
+ * Serializes the module graph of the whole app, to reproduce issues without
+ * the project. The sources of the modules are only included when
+ * `include_sources` is set.
+ */
+export declare function projectGraphExport(
+  project: { __napiType: 'Project' },
+  includeSources: boolean
+): Promise<TurbopackResult>
+export interface NapiImportCost {
+  /** The layer of the importing module, e.g. `app-client` */
+  layer?: string
+  /** The number of modules only the import adds to the app */
+  modules: number
+  /** The size of the generated code of these modules, in bytes */
+  size: number
+  gzipSize: number
+}
+/**
+ * Returns the code the import of `specifier` in `file` adds to the app, for
+ * each client layer `file` is part of. `file` is relative to the project
+ * directory.
+ */
+export declare function projectImportCost(
+  project: { __napiType: 'Project' },
+  file: string,
+  specifier: string
+): Promise<TurbopackResult>
+export interface NapiFontFallback {
+  /** The scoped family of the web font, e.g. `__Roboto_c123b8` */
+  fontFamily?: string
+  /** e.g. `__Roboto_Fallback_c123b8` */
+  scopedFontFamily: string
+  /** The locally installed font the fallback renders with, e.g. `Arial` */
+  localFontFamily: string
+  ascentOverride?: number
+  descentOverride?: number
+  lineGapOverride?: number
+  sizeAdjust?: number
+}
+export interface NapiFontFallbacks {
+  fallbacks: Array<NapiFontFallback>
+}
+/**
+ * Reports the fallback fonts generated by `next/font` across the app with
+ * their computed metric adjustments, e.g. to debug layout shift.
+ */
+export declare function projectFontFallbacks(project: {
+  __napiType: 'Project'
+}): Promise<TurbopackResult>
 export interface NapiUpdateMessage {
   updateType: string
   value?: NapiUpdateInfo
@@ -265,6 +454,60 @@ export declare function projectUpdateInfoSubscribe(
   aggregationMs: number,
   func: (...args: any[]) => any
 ): void
+/**
+ * Subscribes to the progress of writing the endpoints of the project, e.g.
+ * during `next build`.
+ *
+ * Emits a `progress` event whenever an endpoint starts or finishes writing,
+ * with the number of endpoints written and being written, a `diagnostics`
+ * event with the issues and diagnostics of every endpoint written with any,
+ * and a `completion` event when the project is shut down.
+ *
+ * The signature of the `func` is `(build_event: NapiBuildEvent) => void`.
+ */
+export declare function projectBuildEventsSubscribe(
+  project: { __napiType: 'Project' },
+  func: (...args: any[]) => any
+): void
+export interface NapiBuildEvent {
+  /** `"progress"`, `"diagnostics"` or `"completion"` */
+  eventType: string
+  /** The number of endpoints written, for progress and completion events */
+  written?: number
+  /** The number of endpoints being written, for progress events */
+  pending?: number
+  issues?: Array<NapiIssue>
+  diagnostics?: Array<NapiDiagnostic>
+  /**
+   * The time since the first endpoint started writing, for completion
+   * events
+   */
+  duration?: number
+}
+export interface NapiResolveInterceptorRequest {
+  /** The index of the interceptor in `experimental.turbo.resolveInterceptors` */
+  interceptor: number
+  request: string
+  /** The directory of the importing module */
+  context: string
+}
+export interface NapiResolveInterception {
+  /** The request to resolve instead, relative to the importing module */
+  request?: string
+  /** Resolve to an empty module */
+  empty?: boolean
+}
+/**
+ * Sets the callback calling the `resolve` functions of the
+ * `experimental.turbo.resolveInterceptors` configured in next.config.
+ *
+ * The signature of the `func` is
+ * `(request: NapiResolveInterceptorRequest) => Promise<NapiResolveInterception | undefined>`.
+ */
+export declare function projectSetResolveInterceptor(
+  project: { __napiType: 'Project' },
+  func: (...args: any[]) => any
+): void
 export interface StackFrame {
   isServer: boolean
   isInternal?: boolean
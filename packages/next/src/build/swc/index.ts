@@ -25,12 +25,19 @@ import { TurbopackInternalError } from '../../server/dev/turbopack-utils'
 import type {
   NapiPartialProjectOptions,
   NapiProjectOptions,
+  NapiResolveInterception,
+  NapiResolveInterceptorRequest,
 } from './generated-native'
 import type {
   Binding,
+  ClientModuleSize,
   DefineEnv,
   Endpoint,
+  FontFallbacks,
   HmrIdentifiers,
+  OutputPaths,
+  GraphExport,
+  ImportCost,
   Project,
   ProjectOptions,
   Route,
@@ -39,7 +46,17 @@ import type {
   TurbopackStackFrame,
   Update,
   UpdateMessage,
+  BuildEvent,
   WrittenEndpoint,
+  WrittenFeatureUsageReport,
+  WrittenEnvUsageReport,
+  WrittenDuplicateModulesReport,
+  WrittenSitemap,
+  WrittenBrowserExtension,
+  WrittenChunkHashManifest,
+  WrittenChunkIntegrityManifest,
+  WrittenModuleFederation,
+  WrittenThemes,
 } from './types'
 
 type RawBindings = typeof import('./generated-native')
@@ -571,6 +588,42 @@ function bindingToApi(
     }
   }
 
+  /**
+   * The `resolve` functions of `experimental.turbo.resolveInterceptors` can't
+   * be serialized with the rest of the config, so Turbopack calls them through
+   * a callback instead.
+   */
+  function setResolveInterceptor(
+    nativeProject: { __napiType: 'Project' },
+    nextConfig: NextConfigComplete
+  ) {
+    const interceptors = nextConfig.experimental.turbo?.resolveInterceptors
+    if (!interceptors?.some((interceptor) => interceptor.resolve)) {
+      return
+    }
+    binding.projectSetResolveInterceptor(
+      nativeProject,
+      async ({
+        interceptor,
+        request,
+        context,
+      }: NapiResolveInterceptorRequest): Promise<
+        NapiResolveInterception | undefined
+      > => {
+        const result = await interceptors[interceptor].resolve?.(request, {
+          context,
+        })
+        if (typeof result === 'string') {
+          return { request: result }
+        }
+        if (result === false) {
+          return { empty: true }
+        }
+        return undefined
+      }
+    )
+  }
+
   class ProjectImpl implements Project {
     private readonly _nativeProject: { __napiType: 'Project' }
 
@@ -585,6 +638,9 @@ function bindingToApi(
           await rustifyPartialProjectOptions(options)
         )
       )
+      if (options.nextConfig) {
+        setResolveInterceptor(this._nativeProject, options.nextConfig)
+      }
     }
 
     entrypointsSubscribe() {
@@ -778,6 +834,151 @@ function bindingToApi(
       )
     }
 
+    buildEventsSubscribe() {
+      return subscribe<BuildEvent>(true, async (callback) =>
+        binding.projectBuildEventsSubscribe(this._nativeProject, callback)
+      )
+    }
+
+    async writeSitemap(): Promise<TurbopackResult<WrittenSitemap>> {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteSitemap(this._nativeProject) as Promise<
+            TurbopackResult<WrittenSitemap>
+          >
+      )
+    }
+
+    async writeFeatureUsageReport(): Promise<
+      TurbopackResult<WrittenFeatureUsageReport>
+    > {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteFeatureUsageReport(
+            this._nativeProject
+          ) as Promise<TurbopackResult<WrittenFeatureUsageReport>>
+      )
+    }
+
+    async writeEnvUsageReport(): Promise<
+      TurbopackResult<WrittenEnvUsageReport>
+    > {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteEnvUsageReport(this._nativeProject) as Promise<
+            TurbopackResult<WrittenEnvUsageReport>
+          >
+      )
+    }
+
+    async writeDuplicateModulesReport(): Promise<
+      TurbopackResult<WrittenDuplicateModulesReport>
+    > {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteDuplicateModulesReport(
+            this._nativeProject
+          ) as Promise<TurbopackResult<WrittenDuplicateModulesReport>>
+      )
+    }
+
+    async writeThemes(): Promise<TurbopackResult<WrittenThemes>> {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteThemes(this._nativeProject) as Promise<
+            TurbopackResult<WrittenThemes>
+          >
+      )
+    }
+
+    async writeBrowserExtension(): Promise<
+      TurbopackResult<WrittenBrowserExtension>
+    > {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteBrowserExtension(this._nativeProject) as Promise<
+            TurbopackResult<WrittenBrowserExtension>
+          >
+      )
+    }
+
+    async writeChunkHashManifest(): Promise<
+      TurbopackResult<WrittenChunkHashManifest>
+    > {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteChunkHashManifest(this._nativeProject) as Promise<
+            TurbopackResult<WrittenChunkHashManifest>
+          >
+      )
+    }
+
+    async writeChunkIntegrityManifest(): Promise<
+      TurbopackResult<WrittenChunkIntegrityManifest>
+    > {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteChunkIntegrityManifest(
+            this._nativeProject
+          ) as Promise<TurbopackResult<WrittenChunkIntegrityManifest>>
+      )
+    }
+
+    async writeModuleFederation(): Promise<
+      TurbopackResult<WrittenModuleFederation>
+    > {
+      return await withErrorCause(
+        () =>
+          binding.projectWriteModuleFederation(this._nativeProject) as Promise<
+            TurbopackResult<WrittenModuleFederation>
+          >
+      )
+    }
+
+    async getOutputPaths(): Promise<TurbopackResult<OutputPaths>> {
+      return await withErrorCause(
+        () =>
+          binding.projectOutputPaths(this._nativeProject) as Promise<
+            TurbopackResult<OutputPaths>
+          >
+      )
+    }
+
+    async exportGraph(
+      includeSources: boolean
+    ): Promise<TurbopackResult<GraphExport>> {
+      return await withErrorCause(
+        () =>
+          binding.projectGraphExport(
+            this._nativeProject,
+            includeSources
+          ) as Promise<TurbopackResult<GraphExport>>
+      )
+    }
+
+    async getImportCost(
+      file: string,
+      specifier: string
+    ): Promise<TurbopackResult<ImportCost[]>> {
+      return await withErrorCause(
+        () =>
+          binding.projectImportCost(
+            this._nativeProject,
+            file,
+            specifier
+          ) as Promise<TurbopackResult<ImportCost[]>>
+      )
+    }
+
+    async getFontFallbacks(): Promise<TurbopackResult<FontFallbacks>> {
+      return await withErrorCause(
+        () =>
+          binding.projectFontFallbacks(this._nativeProject) as Promise<
+            TurbopackResult<FontFallbacks>
+          >
+      )
+    }
+
     shutdown(): Promise<void> {
       return binding.projectShutdown(this._nativeProject)
     }
@@ -803,6 +1004,12 @@ function bindingToApi(
       )
     }
 
+    async clientModuleSizes(): Promise<ClientModuleSize[]> {
+      return await withErrorCause(() =>
+        binding.endpointClientModuleSizes(this._nativeEndpoint)
+      )
+    }
+
     async clientChanged(): Promise<AsyncIterableIterator<TurbopackResult<{}>>> {
       const clientSubscription = subscribe<TurbopackResult>(
         false,
@@ -904,6 +1111,31 @@ function bindingToApi(
     nextConfigSerializable.exportPathMap = {}
     nextConfigSerializable.webpack = nextConfig.webpack && {}
 
+    // Regular expressions are passed as their source, only the `i` flag has an
+    // equivalent in Rust's regex syntax.
+    const turbo = nextConfigSerializable.experimental?.turbo
+    if (turbo?.chunkCacheGroups) {
+      nextConfigSerializable.experimental = {
+        ...nextConfigSerializable.experimental,
+        turbo: {
+          ...turbo,
+          chunkCacheGroups: Object.fromEntries(
+            Object.entries<any>(turbo.chunkCacheGroups).map(([name, group]) => [
+              name,
+              group.test instanceof RegExp
+                ? {
+                    ...group,
+                    test:
+                      (group.test.flags.includes('i') ? '(?i)' : '') +
+                      group.test.source,
+                  }
+                : group,
+            ])
+          ),
+        },
+      }
+    }
+
     if (nextConfigSerializable.experimental?.turbo?.rules) {
       ensureLoadersHaveSerializableOptions(
         nextConfigSerializable.experimental.turbo?.rules
@@ -986,12 +1218,12 @@ function bindingToApi(
     options: ProjectOptions,
     turboEngineOptions
   ) {
-    return new ProjectImpl(
-      await binding.projectNew(
-        await rustifyProjectOptions(options),
-        turboEngineOptions || {}
-      )
+    const nativeProject = await binding.projectNew(
+      await rustifyProjectOptions(options),
+      turboEngineOptions || {}
     )
+    setResolveInterceptor(nativeProject, options.nextConfig)
+    return new ProjectImpl(nativeProject)
   }
 }
 
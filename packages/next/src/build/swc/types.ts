@@ -191,6 +191,27 @@ export interface UpdateInfo {
   tasks: number
 }
 
+export type BuildEvent =
+  | {
+      eventType: 'progress'
+      /** The number of endpoints written */
+      written: number
+      /** The number of endpoints being written */
+      pending: number
+    }
+  | {
+      eventType: 'diagnostics'
+      issues: Issue[]
+      diagnostics: Diagnostics[]
+    }
+  | {
+      eventType: 'completion'
+      /** The number of endpoints written */
+      written: number
+      /** The time since the first endpoint started writing in milliseconds */
+      duration: number
+    }
+
 export interface Project {
   update(options: Partial<ProjectOptions>): Promise<void>
 
@@ -216,11 +237,198 @@ export interface Project {
     aggregationMs: number
   ): AsyncIterableIterator<TurbopackResult<UpdateMessage>>
 
+  /**
+   * Streams the progress of writing endpoints, the issues and diagnostics of
+   * written endpoints, and the completion of the build when the project is
+   * shut down.
+   */
+  buildEventsSubscribe(): AsyncIterableIterator<BuildEvent>
+
+  writeSitemap(): Promise<TurbopackResult<WrittenSitemap>>
+
+  writeFeatureUsageReport(): Promise<
+    TurbopackResult<WrittenFeatureUsageReport>
+  >
+
+  writeEnvUsageReport(): Promise<TurbopackResult<WrittenEnvUsageReport>>
+
+  writeDuplicateModulesReport(): Promise<
+    TurbopackResult<WrittenDuplicateModulesReport>
+  >
+
+  writeThemes(): Promise<TurbopackResult<WrittenThemes>>
+
+  writeBrowserExtension(): Promise<TurbopackResult<WrittenBrowserExtension>>
+
+  writeChunkHashManifest(): Promise<TurbopackResult<WrittenChunkHashManifest>>
+
+  writeChunkIntegrityManifest(): Promise<
+    TurbopackResult<WrittenChunkIntegrityManifest>
+  >
+
+  writeModuleFederation(): Promise<TurbopackResult<WrittenModuleFederation>>
+
+  /**
+   * Lists the paths of all assets written when building the app, relative to
+   * the dist dir.
+   */
+  getOutputPaths(): Promise<TurbopackResult<OutputPaths>>
+
+  /**
+   * Serializes the module graph of the whole app, e.g. to attach it to a bug
+   * report. The sources of the modules are only included when
+   * `includeSources` is set.
+   */
+  exportGraph(includeSources: boolean): Promise<TurbopackResult<GraphExport>>
+
+  /**
+   * Returns the code the import of `specifier` in `file` adds to the app, for
+   * each client layer `file` is part of, e.g. to show the cost of imports in
+   * an editor. `file` is relative to the project directory.
+   */
+  getImportCost(
+    file: string,
+    specifier: string
+  ): Promise<TurbopackResult<ImportCost[]>>
+
+  /**
+   * Reports the fallback fonts generated by `next/font` across the app with
+   * their computed metric adjustments, e.g. to debug layout shift.
+   */
+  getFontFallbacks(): Promise<TurbopackResult<FontFallbacks>>
+
   shutdown(): Promise<void>
 
   onExit(): Promise<void>
 }
 
+export interface WrittenSitemap {
+  /**
+   * The written `sitemap.xml` and `robots.txt`, relative to the dist dir.
+   * Empty when `experimental.sitemap` isn't configured.
+   */
+  paths: string[]
+}
+
+export interface WrittenFeatureUsageReport {
+  /**
+   * The written `feature-usage.json`, relative to the dist dir. Empty when
+   * `experimental.turbo.featureUsageReport` isn't enabled.
+   */
+  paths: string[]
+}
+
+export interface WrittenEnvUsageReport {
+  /**
+   * The written `env-usage.json`, relative to the dist dir. Empty when
+   * `experimental.turbo.envUsageReport` isn't enabled.
+   */
+  paths: string[]
+}
+
+export interface WrittenDuplicateModulesReport {
+  /**
+   * The written `duplicate-modules.json`, relative to the dist dir. Empty
+   * when `TURBOPACK_STATS` isn't set.
+   */
+  paths: string[]
+}
+
+export interface OutputPaths {
+  /** Relative to the dist dir */
+  paths: string[]
+}
+
+export interface GraphExport {
+  /**
+   * The module graph as JSON, see `TurbopackGraph` in
+   * `build/graph-export.ts`.
+   */
+  graph: string
+}
+
+export interface ImportCost {
+  /** The layer of the importing module, e.g. `app-client`. */
+  layer?: string
+  /**
+   * The number of modules which are only part of the app because of the
+   * import. Modules also imported elsewhere aren't counted.
+   */
+  modules: number
+  /** The size of the generated code of these modules, in bytes. */
+  size: number
+  /** The size of the code when compressed with gzip, in bytes. */
+  gzipSize: number
+}
+
+export interface WrittenBrowserExtension {
+  /**
+   * The files of the browser extension, relative to the dist dir. Empty when
+   * `experimental.turbo.browserExtension` isn't configured.
+   */
+  paths: string[]
+}
+
+export interface WrittenChunkHashManifest {
+  /**
+   * The written `chunk-hash-manifest.json`, relative to the dist dir. Empty
+   * when `experimental.turbo.contentHashing` isn't enabled.
+   */
+  paths: string[]
+}
+
+export interface WrittenChunkIntegrityManifest {
+  /**
+   * The written `static/chunks/integrity-manifest.json`, relative to the dist
+   * dir. Empty when `experimental.turbo.assetPrefixFallback` isn't enabled.
+   */
+  paths: string[]
+}
+
+export interface WrittenModuleFederation {
+  /**
+   * The written `static/remoteEntry.js` and the chunks it loads, relative to
+   * the dist dir. Empty when no modules are exposed through
+   * `experimental.turbo.moduleFederation`.
+   */
+  paths: string[]
+}
+
+export interface WrittenThemes {
+  /**
+   * The written `theme-manifest.json`, relative to the dist dir. Empty when
+   * `experimental.turbo.themes` isn't configured.
+   */
+  paths: string[]
+}
+
+export interface FontFallback {
+  /**
+   * The scoped family of the web font, e.g. `__Roboto_c123b8`.
+   */
+  fontFamily?: string
+  /**
+   * The scoped family of the fallback, e.g. `__Roboto_Fallback_c123b8`.
+   */
+  scopedFontFamily: string
+  /**
+   * The locally installed font the fallback renders with, e.g. `Arial`.
+   */
+  localFontFamily: string
+  /**
+   * The `ascent-override`, `descent-override`, `line-gap-override` and
+   * `size-adjust` percentages, unset when the fallback isn't adjusted.
+   */
+  ascentOverride?: number
+  descentOverride?: number
+  lineGapOverride?: number
+  sizeAdjust?: number
+}
+
+export interface FontFallbacks {
+  fallbacks: FontFallback[]
+}
+
 export type Route =
   | {
       type: 'conflict'
@@ -252,6 +460,12 @@ export interface Endpoint {
   /** Write files for the endpoint to disk. */
   writeToDisk(): Promise<TurbopackResult<WrittenEndpoint>>
 
+  /**
+   * The modules in the client chunks of the endpoint with their size. Used to
+   * tell what a rebuild added to the client.
+   */
+  clientModuleSizes(): Promise<ClientModuleSize[]>
+
   /**
    * Listen to client-side changes to the endpoint.
    * After clientChanged() has been awaited it will listen to changes.
@@ -285,6 +499,15 @@ interface EndpointConfig {
   preferredRegion?: string
 }
 
+export type ClientModuleSize = {
+  /** The path of the module, e.g. `[project]/node_modules/date-fns/index.js`. */
+  path: string
+  /** The name of the package containing the module, if any. */
+  package?: string
+  /** The estimated size of the generated code of the module, in bytes. */
+  size: number
+}
+
 export type ServerPath = {
   path: string
   contentHash: string
@@ -388,6 +611,12 @@ export interface ProjectOptions {
    * The browserslist query to use for targeting browsers.
    */
   browserslistQuery: string
+
+  /**
+   * Disable minification and annotate each module in the emitted chunks with
+   * its path and package version.
+   */
+  annotateChunks?: boolean
 }
 
 export interface DefineEnv {
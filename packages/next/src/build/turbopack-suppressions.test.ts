@@ -0,0 +1,87 @@
+import type { Issue } from './swc/types'
+import {
+  getIssueFingerprint,
+  IssueSuppressions,
+  parseSuppressionFile,
+} from './turbopack-suppressions'
+
+function createIssue(title: string): Issue {
+  return {
+    severity: 'warning',
+    stage: 'parse',
+    filePath: '[project]/app/page.tsx',
+    title: { type: 'text', value: title },
+    description: { type: 'text', value: '/home/user/app/page.tsx' },
+    documentationLink: '',
+    subIssues: [],
+  }
+}
+
+describe('IssueSuppressions', () => {
+  const now = new Date('2025-03-10T12:00:00Z')
+  const issue = createIssue('Unexpected import')
+
+  it('ignores the description in the fingerprint', () => {
+    expect(getIssueFingerprint(issue)).toBe(
+      getIssueFingerprint({
+        ...issue,
+        description: { type: 'text', value: 'C:\\app\\page.tsx' },
+      })
+    )
+    expect(getIssueFingerprint(issue)).not.toBe(
+      getIssueFingerprint(createIssue('Other'))
+    )
+  })
+
+  it('suppresses issues until the end of the expiry date', () => {
+    const suppression = {
+      fingerprint: getIssueFingerprint(issue),
+      owner: '@team-a',
+      expires: '2025-03-10',
+    }
+    const suppressions = new IssueSuppressions(
+      { suppressions: [suppression] },
+      now
+    )
+    expect(suppressions.check(issue)).toEqual({
+      type: 'suppressed',
+      suppression,
+    })
+    expect(suppressions.check(createIssue('Other'))).toEqual({ type: 'none' })
+
+    const expired = new IssueSuppressions(
+      { suppressions: [{ ...suppression, expires: '2025-03-09' }] },
+      now
+    )
+    expect(expired.check(issue).type).toBe('expired')
+  })
+
+  it('reports unused suppressions', () => {
+    const suppressions = new IssueSuppressions(
+      {
+        suppressions: [
+          {
+            fingerprint: getIssueFingerprint(issue),
+            owner: '@team-a',
+            expires: '2025-04-01',
+          },
+          { fingerprint: 'abc', owner: '@team-b', expires: '2025-04-01' },
+        ],
+      },
+      now
+    )
+    suppressions.check(issue)
+    const report = suppressions.formatDebtReport()
+    expect(report).toContain('Suppression debt: 1 suppressed issues')
+    expect(report).toContain('- @team-a: 1, next expiring 2025-04-01')
+    expect(report).toContain('abc (@team-b, expires 2025-04-01, 0 issues)')
+  })
+
+  it('rejects suppressions without an expiry date', () => {
+    expect(() =>
+      parseSuppressionFile(
+        JSON.stringify({ suppressions: [{ fingerprint: 'abc', owner: 'me' }] })
+      )
+    ).toThrow('every suppression needs')
+  })
+})
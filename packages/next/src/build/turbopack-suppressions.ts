@@ -0,0 +1,174 @@
+import { createHash } from 'crypto'
+import { readFile } from 'fs/promises'
+import path from 'path'
+import type { Issue } from './swc/types'
+
+/**
+ * A checked-in file listing accepted Turbopack issues, so a codebase with many
+ * existing warnings and errors can be migrated incrementally.
+ */
+export const SUPPRESSION_FILE_NAME = 'turbopack-suppressions.json'
+
+export interface IssueSuppression {
+  /** The fingerprint printed with the issue in the build output. */
+  fingerprint: string
+  /** Who is responsible for fixing the issue. */
+  owner: string
+  /** The last day (`YYYY-MM-DD`) the issue is suppressed. */
+  expires: string
+  reason?: string
+}
+
+export interface SuppressionFile {
+  /** The number of unsuppressed warnings a build may collect. */
+  warningBudget?: number
+  suppressions: IssueSuppression[]
+}
+
+export type SuppressionStatus =
+  | { type: 'none' }
+  | { type: 'suppressed'; suppression: IssueSuppression }
+  | { type: 'expired'; suppression: IssueSuppression }
+
+const DATE_REGEX = /^\d{4}-\d{2}-\d{2}$/
+
+/**
+ * A fingerprint identifying an issue across builds and machines. It only
+ * covers the project relative file path, stage and title, as descriptions
+ * often contain absolute paths.
+ */
+export function getIssueFingerprint(issue: Issue): string {
+  return createHash('sha1')
+    .update(JSON.stringify([issue.stage, issue.filePath, issue.title]), 'utf8')
+    .digest('hex')
+    .slice(0, 12)
+}
+
+export class IssueSuppressions {
+  readonly warningBudget: number | undefined
+  private readonly suppressions = new Map<string, IssueSuppression>()
+  private readonly matches = new Map<string, number>()
+  private readonly today: string
+
+  constructor(file: SuppressionFile, now = new Date()) {
+    this.warningBudget = file.warningBudget
+    this.today = now.toISOString().slice(0, 10)
+    for (const suppression of file.suppressions) {
+      this.suppressions.set(suppression.fingerprint, suppression)
+    }
+  }
+
+  check(issue: Issue): SuppressionStatus {
+    const fingerprint = getIssueFingerprint(issue)
+    const suppression = this.suppressions.get(fingerprint)
+    if (!suppression) {
+      return { type: 'none' }
+    }
+    this.matches.set(fingerprint, (this.matches.get(fingerprint) ?? 0) + 1)
+    return this.isExpired(suppression)
+      ? { type: 'expired', suppression }
+      : { type: 'suppressed', suppression }
+  }
+
+  private isExpired(suppression: IssueSuppression): boolean {
+    return suppression.expires < this.today
+  }
+
+  /**
+   * Summarizes the suppression debt: the suppressed issues per owner, the
+   * expired suppressions and the ones not matching any issue anymore.
+   */
+  formatDebtReport(): string {
+    const byOwner = new Map<string, IssueSuppression[]>()
+    const expired: IssueSuppression[] = []
+    const unused: IssueSuppression[] = []
+    for (const suppression of this.suppressions.values()) {
+      if (!this.matches.has(suppression.fingerprint)) {
+        unused.push(suppression)
+      } else if (this.isExpired(suppression)) {
+        expired.push(suppression)
+      } else {
+        const owned = byOwner.get(suppression.owner) ?? []
+        owned.push(suppression)
+        byOwner.set(suppression.owner, owned)
+      }
+    }
+
+    const formatSuppression = ({
+      fingerprint,
+      owner,
+      expires,
+    }: IssueSuppression) =>
+      `  ${fingerprint} (${owner}, expires ${expires}, ${
+        this.matches.get(fingerprint) ?? 0
+      } issues)`
+
+    let report = `Suppression debt: ${
+      this.suppressions.size - unused.length
+    } suppressed issues\n`
+    for (const [owner, owned] of [...byOwner].sort(([a], [b]) =>
+      a.localeCompare(b)
+    )) {
+      const nextExpiring = owned.map(({ expires }) => expires).sort()[0]
+      report += `- ${owner}: ${owned.length}, next expiring ${nextExpiring}\n`
+    }
+    if (expired.length > 0) {
+      report += `Expired suppressions:\n${expired
+        .map(formatSuppression)
+        .join('\n')}\n`
+    }
+    if (unused.length > 0) {
+      report += `Unused suppressions, these can be removed from ${SUPPRESSION_FILE_NAME}:\n${unused
+        .map(formatSuppression)
+        .join('\n')}\n`
+    }
+    return report
+  }
+}
+
+export function parseSuppressionFile(content: string): SuppressionFile {
+  const file = JSON.parse(content)
+  if (
+    file.warningBudget !== undefined &&
+    !(Number.isInteger(file.warningBudget) && file.warningBudget >= 0)
+  ) {
+    throw new Error(
+      `Invalid ${SUPPRESSION_FILE_NAME}: "warningBudget" must be a non-negative integer`
+    )
+  }
+  if (!Array.isArray(file.suppressions)) {
+    throw new Error(
+      `Invalid ${SUPPRESSION_FILE_NAME}: "suppressions" must be an array`
+    )
+  }
+  for (const suppression of file.suppressions) {
+    if (
+      typeof suppression?.fingerprint !== 'string' ||
+      typeof suppression.owner !== 'string' ||
+      typeof suppression.expires !== 'string' ||
+      !DATE_REGEX.test(suppression.expires)
+    ) {
+      throw new Error(
+        `Invalid ${SUPPRESSION_FILE_NAME}: every suppression needs a "fingerprint", an "owner" and an "expires" date (YYYY-MM-DD), got ${JSON.stringify(
+          suppression
+        )}`
+      )
+    }
+  }
+  return file
+}
+
+export async function loadIssueSuppressions(
+  dir: string
+): Promise<IssueSuppressions | undefined> {
+  let content
+  try {
+    content = await readFile(path.join(dir, SUPPRESSION_FILE_NAME), 'utf8')
+  } catch (err: any) {
+    if (err.code === 'ENOENT') {
+      return undefined
+    }
+    throw err
+  }
+  return new IssueSuppressions(parseSuppressionFile(content))
+}
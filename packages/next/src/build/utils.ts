@@ -78,7 +78,10 @@ import type { AppSegment } from './segment-config/app/app-segments'
 import { collectSegments } from './segment-config/app/app-segments'
 import { createIncrementalCache } from '../export/helpers/create-incremental-cache'
 import { collectRootParamKeys } from './segment-config/app/collect-root-param-keys'
-import { buildAppStaticPaths } from './static-paths/app'
+import {
+  buildAppStaticPaths,
+  loadStaticParamsManifest,
+} from './static-paths/app'
 import { buildPagesStaticPaths } from './static-paths/pages'
 import type { PrerenderedRoute } from './static-paths/types'
 
@@ -1141,6 +1144,9 @@ export async function isPageStatic({
               isRoutePPREnabled,
               buildId,
               rootParamKeys,
+              staticParamsManifest: originalAppPath
+                ? await loadStaticParamsManifest(distDir, originalAppPath)
+                : undefined,
             }))
         }
       } else {
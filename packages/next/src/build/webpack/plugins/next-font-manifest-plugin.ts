@@ -13,6 +13,25 @@ export type NextFontManifest = {
   }
   appUsingSizeAdjust: boolean
   pagesUsingSizeAdjust: boolean
+  /**
+   * The fonts generated by `next/font`, keyed by their font family. Only
+   * emitted by Turbopack.
+   */
+  fonts?: {
+    [fontFamily: string]: {
+      /** The font families rendered until the font is loaded, in order. */
+      fallbacks: string[]
+      /** Whether one of the fallbacks is adjusted with `size-adjust`. */
+      hasSizeAdjust: boolean
+    }
+  }
+  /**
+   * The preloads left out of a page because none of its modules uses their
+   * font, keyed by page. Only emitted by Turbopack in production.
+   */
+  prunedPreloads?: {
+    [page: string]: string[]
+  }
 }
 const PLUGIN_NAME = 'NextFontManifestPlugin'
 
@@ -0,0 +1,28 @@
+import {
+  findImportChain,
+  loadGraphExport,
+  summarizeGraphExport,
+} from '../../build/graph-export'
+
+export async function inspectGraphCli(
+  file: string,
+  options: { why?: string; largest?: number }
+) {
+  const bundle = await loadGraphExport(file)
+
+  if (options.why !== undefined) {
+    const chain = findImportChain(bundle.graph, options.why)
+    if (!chain) {
+      console.log(`No module matching "${options.why}" is imported`)
+      return
+    }
+    for (const [depth, index] of chain.entries()) {
+      console.log(`${'  '.repeat(depth)}${bundle.graph.modules[index].path}`)
+    }
+    return
+  }
+
+  for (const line of summarizeGraphExport(bundle, options.largest)) {
+    console.log(line)
+  }
+}
@@ -4,6 +4,7 @@ import '../server/lib/cpu-profile'
 import { existsSync } from 'fs'
 import { italic } from '../lib/picocolors'
 import build from '../build'
+import type { GraphExportMode } from '../build/graph-export'
 import { warn } from '../build/output/log'
 import { printAndExit } from '../server/lib/utils'
 import isError from '../lib/is-error'
@@ -21,6 +22,10 @@ export type NextBuildOptions = {
   experimentalTurbo?: boolean
   experimentalBuildMode: 'default' | 'compile' | 'generate'
   experimentalUploadTrace?: string
+  annotateChunks?: boolean
+  reportSuppressions?: boolean
+  exportGraph?: GraphExportMode | true
+  traceResolution?: string
 }
 
 const nextBuild = (options: NextBuildOptions, directory?: string) => {
@@ -37,6 +42,10 @@ const nextBuild = (options: NextBuildOptions, directory?: string) => {
     experimentalTurbo,
     experimentalBuildMode,
     experimentalUploadTrace,
+    annotateChunks,
+    reportSuppressions,
+    exportGraph,
+    traceResolution,
   } = options
 
   let traceUploadUrl: string | undefined
@@ -54,6 +63,12 @@ const nextBuild = (options: NextBuildOptions, directory?: string) => {
     )
   }
 
+  if (annotateChunks) {
+    warn(
+      'Minification is disabled to annotate the emitted chunks. Note: This should only be used for debugging purposes.'
+    )
+  }
+
   if (profile) {
     warn(
       `Profiling is enabled. ${italic('Note: This may affect performance.')}`
@@ -75,6 +90,10 @@ const nextBuild = (options: NextBuildOptions, directory?: string) => {
     process.env.TURBOPACK = '1'
   }
 
+  if (traceResolution) {
+    process.env.NEXT_TURBOPACK_TRACE_RESOLUTION = traceResolution
+  }
+
   return build(
     dir,
     profile,
@@ -84,7 +103,10 @@ const nextBuild = (options: NextBuildOptions, directory?: string) => {
     experimentalAppOnly,
     !!process.env.TURBOPACK,
     experimentalBuildMode,
-    traceUploadUrl
+    traceUploadUrl,
+    annotateChunks,
+    reportSuppressions,
+    exportGraph === true ? 'anonymized' : exportGraph
   )
     .catch((err) => {
       if (experimentalDebugMemoryUsage) {
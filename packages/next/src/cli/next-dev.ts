@@ -38,6 +38,7 @@ import { once } from 'node:events'
 import { clearTimeout } from 'timers'
 import { flushAllTraces, trace } from '../trace'
 import { traceId } from '../trace/shared'
+import { writeFocus } from '../server/dev/turbopack/focus'
 
 export type NextDevOptions = {
   disableSourceMaps: boolean
@@ -50,6 +51,8 @@ export type NextDevOptions = {
   experimentalHttpsCert?: string
   experimentalHttpsCa?: string
   experimentalUploadTrace?: string
+  focus?: string[] | false
+  traceResolution?: string
 }
 
 type PortSource = 'cli' | 'default' | 'env'
@@ -243,6 +246,17 @@ const nextDev = async (
   isTurboSession = !!process.env.TURBOPACK
 
   const distDir = path.join(dir, config.distDir ?? '.next')
+
+  if (options.focus !== undefined) {
+    if (!isTurboSession) {
+      Log.warn('`--focus` only has an effect when running with Turbopack.')
+    }
+    await writeFocus(distDir, options.focus)
+  }
+
+  if (options.traceResolution) {
+    process.env.NEXT_TURBOPACK_TRACE_RESOLUTION = options.traceResolution
+  }
   setGlobal('phase', PHASE_DEVELOPMENT_SERVER)
   setGlobal('distDir', distDir)
 
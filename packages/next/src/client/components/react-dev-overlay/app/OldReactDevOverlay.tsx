@@ -4,6 +4,7 @@ import { ShadowPortal } from '../internal/components/ShadowPortal'
 import { BuildError } from '../internal/container/BuildError'
 import { Errors } from '../internal/container/Errors'
 import { StaticIndicator } from '../internal/container/StaticIndicator'
+import { ExpensiveImportNotice } from '../internal/container/ExpensiveImportNotice'
 import { Base } from '../internal/styles/Base'
 import { ComponentStyles } from '../internal/styles/ComponentStyles'
 import { CssReset } from '../internal/styles/CssReset'
@@ -83,6 +84,13 @@ export default class ReactDevOverlay extends React.PureComponent<
               {hasStaticIndicator && (
                 <StaticIndicator dispatcher={dispatcher} />
               )}
+
+              {state.expensiveImport && !hasRuntimeErrors ? (
+                <ExpensiveImportNotice
+                  expensiveImport={state.expensiveImport}
+                  onDismiss={() => dispatcher?.onExpensiveImport(null)}
+                />
+              ) : null}
             </>
           )}
         </ShadowPortal>
@@ -16,6 +16,7 @@ import {
   ACTION_BUILD_OK,
   ACTION_DEBUG_INFO,
   ACTION_REFRESH,
+  ACTION_EXPENSIVE_IMPORT,
   ACTION_STATIC_INDICATOR,
   ACTION_UNHANDLED_ERROR,
   ACTION_UNHANDLED_REJECTION,
@@ -36,6 +37,7 @@ import { parseComponentStack } from '../internal/helpers/parse-component-stack'
 import type { VersionInfo } from '../../../../server/dev/parse-version-info'
 import { HMR_ACTIONS_SENT_TO_BROWSER } from '../../../../server/dev/hot-reloader-types'
 import type {
+  ExpensiveImport,
   HMR_ACTION_TYPES,
   TurbopackMsgToBrowser,
 } from '../../../../server/dev/hot-reloader-types'
@@ -55,6 +57,7 @@ export interface Dispatcher {
   onBeforeRefresh(): void
   onRefresh(): void
   onStaticIndicator(status: boolean): void
+  onExpensiveImport(expensiveImport: ExpensiveImport | null): void
 }
 
 let mostRecentCompilationHash: any = null
@@ -519,6 +522,10 @@ function processMessage(
       }
       return
     }
+    case HMR_ACTIONS_SENT_TO_BROWSER.EXPENSIVE_IMPORT: {
+      dispatcher.onExpensiveImport(obj.data)
+      return
+    }
     case HMR_ACTIONS_SENT_TO_BROWSER.DEV_PAGES_MANIFEST_UPDATE: {
       return
     }
@@ -559,6 +566,9 @@ export default function HotReload({
       onDebugInfo(debugInfo) {
         dispatch({ type: ACTION_DEBUG_INFO, debugInfo })
       },
+      onExpensiveImport(expensiveImport) {
+        dispatch({ type: ACTION_EXPENSIVE_IMPORT, expensiveImport })
+      },
     }
   }, [dispatch])
 
@@ -90,6 +90,39 @@ const styles = css`
     width: 16px;
     height: 16px;
   }
+
+  .nextjs-expensive-import-toast {
+    padding: 12px 16px;
+    border-radius: var(--size-gap-double);
+    font-size: 14px;
+    background: var(--color-background);
+    color: var(--color-font);
+  }
+
+  .nextjs-expensive-import-toast-header {
+    display: flex;
+    align-items: flex-start;
+    gap: var(--size-gap);
+  }
+
+  .nextjs-expensive-import-toast-header button {
+    color: var(--color-font);
+    opacity: 0.8;
+    background: none;
+    border: none;
+    padding: 0;
+    outline: 0;
+  }
+
+  .nextjs-expensive-import-toast-header button > svg {
+    width: 16px;
+    height: 16px;
+  }
+
+  .nextjs-expensive-import-toast-packages {
+    margin: var(--size-gap) 0 0;
+    padding-left: var(--size-gap-double);
+  }
 `
 
 export { styles }
@@ -0,0 +1,46 @@
+import * as React from 'react'
+import { Toast } from '../components/Toast'
+import { CloseIcon } from '../icons/CloseIcon'
+import type { ExpensiveImport } from '../../../../../server/dev/hot-reloader-types'
+
+function formatSize(bytes: number) {
+  return bytes < 1000 ? `${bytes} B` : `${(bytes / 1000).toFixed(1)} kB`
+}
+
+export function ExpensiveImportNotice({
+  expensiveImport,
+  onDismiss,
+}: {
+  expensiveImport: ExpensiveImport
+  onDismiss: () => void
+}) {
+  const { page, sizeDelta, newModules, packages } = expensiveImport
+  return (
+    <Toast role="status" className="nextjs-expensive-import-toast">
+      <div className="nextjs-expensive-import-toast-header">
+        <span>
+          Your last change added {formatSize(sizeDelta)} and {newModules}{' '}
+          {newModules === 1 ? 'module' : 'modules'} to the client code of{' '}
+          <code>{page}</code>
+        </span>
+        <button
+          onClick={onDismiss}
+          className="nextjs-toast-hide-button"
+          aria-label="Hide expensive import notice"
+        >
+          <CloseIcon />
+        </button>
+      </div>
+      {packages.length > 0 ? (
+        <ul className="nextjs-expensive-import-toast-packages">
+          {packages.map(({ name, size, modules }) => (
+            <li key={name}>
+              <code>{name}</code> {formatSize(size)}, {modules}{' '}
+              {modules === 1 ? 'module' : 'modules'}
+            </li>
+          ))}
+        </ul>
+      ) : null}
+    </Toast>
+  )
+}
@@ -3,11 +3,14 @@ import * as React from 'react'
 import { ShadowPortal } from '../internal/components/ShadowPortal'
 import { BuildError } from '../internal/container/BuildError'
 import { Errors } from '../internal/container/Errors'
+import { ExpensiveImportNotice } from '../internal/container/ExpensiveImportNotice'
 import { ErrorBoundary } from './ErrorBoundary'
 import { Base } from '../internal/styles/Base'
 import { ComponentStyles } from '../internal/styles/ComponentStyles'
 import { CssReset } from '../internal/styles/CssReset'
 import { usePagesReactDevOverlay } from './hooks'
+import * as Bus from './bus'
+import { ACTION_EXPENSIVE_IMPORT } from '../shared'
 
 export type ErrorType = 'runtime' | 'build'
 
@@ -58,6 +61,16 @@ export default function ReactDevOverlay({
               versionInfo={state.versionInfo}
               initialDisplayState={'fullscreen'}
             />
+          ) : state.expensiveImport ? (
+            <ExpensiveImportNotice
+              expensiveImport={state.expensiveImport}
+              onDismiss={() =>
+                Bus.emit({
+                  type: ACTION_EXPENSIVE_IMPORT,
+                  expensiveImport: null,
+                })
+              }
+            />
           ) : undefined}
         </ShadowPortal>
       ) : undefined}
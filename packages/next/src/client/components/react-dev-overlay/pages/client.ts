@@ -9,12 +9,14 @@ import {
   ACTION_BEFORE_REFRESH,
   ACTION_BUILD_ERROR,
   ACTION_BUILD_OK,
+  ACTION_EXPENSIVE_IMPORT,
   ACTION_REFRESH,
   ACTION_UNHANDLED_ERROR,
   ACTION_UNHANDLED_REJECTION,
   ACTION_VERSION_INFO,
 } from '../shared'
 import type { VersionInfo } from '../../../../server/dev/parse-version-info'
+import type { ExpensiveImport } from '../../../../server/dev/hot-reloader-types'
 import { attachHydrationErrorState } from '../internal/helpers/attach-hydration-error-state'
 
 let isRegistered = false
@@ -138,6 +140,10 @@ export function onVersionInfo(versionInfo: VersionInfo) {
   Bus.emit({ type: ACTION_VERSION_INFO, versionInfo })
 }
 
+export function onExpensiveImport(expensiveImport: ExpensiveImport | null) {
+  Bus.emit({ type: ACTION_EXPENSIVE_IMPORT, expensiveImport })
+}
+
 export { getErrorByType } from '../internal/helpers/get-error-by-type'
 export { getServerError } from '../internal/helpers/node-stack-frames'
 export { default as ReactDevOverlay } from './ReactDevOverlay'
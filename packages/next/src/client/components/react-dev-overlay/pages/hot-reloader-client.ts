@@ -35,6 +35,7 @@ import {
   onBeforeRefresh,
   onRefresh,
   onVersionInfo,
+  onExpensiveImport,
 } from './client'
 import stripAnsi from 'next/dist/compiled/strip-ansi'
 import { addMessageListener, sendMessage } from './websocket'
@@ -334,6 +335,10 @@ function processMessage(obj: HMR_ACTION_TYPES) {
       }
       return
     }
+    case HMR_ACTIONS_SENT_TO_BROWSER.EXPENSIVE_IMPORT: {
+      onExpensiveImport(obj.data)
+      return
+    }
     case HMR_ACTIONS_SENT_TO_BROWSER.TURBOPACK_CONNECTED: {
       for (const listener of turbopackMessageListeners) {
         listener({
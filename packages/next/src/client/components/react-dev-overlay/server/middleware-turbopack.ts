@@ -16,6 +16,7 @@ import { launchEditor } from '../internal/helpers/launchEditor'
 import type { StackFrame } from 'next/dist/compiled/stacktrace-parser'
 import { SourceMapConsumer } from 'next/dist/compiled/source-map08'
 import type { Project, TurbopackStackFrame } from '../../../../build/swc/types'
+import type { DevSnapshotHistory } from '../../../../server/dev/turbopack/snapshots'
 import { getSourceMapFromFile } from '../internal/helpers/get-source-map-from-file'
 import { findSourceMap, type SourceMapPayload } from 'node:module'
 import { pathToFileURL } from 'node:url'
@@ -396,3 +397,63 @@ export function getSourceMapMiddleware(project: Project) {
     noContent(res)
   }
 }
+
+/**
+ * Serves the fallback fonts generated by `next/font` with their computed
+ * metric adjustments at `/__nextjs_font-fallbacks`, to debug layout shift
+ * without reading the generated CSS.
+ */
+export function getFontFallbacksMiddleware(project: Project) {
+  return async function (
+    req: IncomingMessage,
+    res: ServerResponse,
+    next: () => void
+  ): Promise<void> {
+    const { pathname } = new URL(req.url!, 'http://n')
+
+    if (pathname !== '/__nextjs_font-fallbacks') {
+      return next()
+    }
+
+    try {
+      const { fallbacks } = await project.getFontFallbacks()
+      json(res, fallbacks)
+    } catch (error) {
+      console.error('Failed to get font fallbacks:', error)
+      internalServerError(res)
+    }
+  }
+}
+
+/**
+ * Serves the snapshots of the module graph and the issues taken after each
+ * compilation at `/__nextjs_snapshots`. `?ago=<n>` diffs the latest snapshot
+ * against the one taken `n` snapshots earlier, `?id=<id>` against the one with
+ * the given id.
+ */
+export function getSnapshotsMiddleware(history: DevSnapshotHistory) {
+  return async function (
+    req: IncomingMessage,
+    res: ServerResponse,
+    next: () => void
+  ): Promise<void> {
+    const { pathname, searchParams } = new URL(req.url!, 'http://n')
+
+    if (pathname !== '/__nextjs_snapshots') {
+      return next()
+    }
+
+    const id = searchParams.get('id')
+    const ago = searchParams.get('ago')
+    if (id === null && ago === null) {
+      return json(res, history.list())
+    }
+
+    const diff =
+      id !== null ? history.diff(Number(id)) : history.diffAgo(Number(ago))
+    if (!diff) {
+      return badRequest(res)
+    }
+    json(res, diff)
+  }
+}
@@ -2,6 +2,7 @@ import { useReducer } from 'react'
 
 import type { StackFrame } from 'next/dist/compiled/stacktrace-parser'
 import type { VersionInfo } from '../../../server/dev/parse-version-info'
+import type { ExpensiveImport } from '../../../server/dev/hot-reloader-types'
 import type { SupportedErrorEvent } from './internal/container/Errors'
 import type { ComponentStackFrame } from './internal/helpers/parse-component-stack'
 import type { DebugInfo } from './types'
@@ -22,6 +23,8 @@ export interface OverlayState {
   notFound: boolean
   staticIndicator: boolean
   debugInfo: DebugInfo | undefined
+  /** The last edit which added more client code than the threshold. */
+  expensiveImport: ExpensiveImport | null
 }
 
 export const ACTION_STATIC_INDICATOR = 'static-indicator'
@@ -33,6 +36,7 @@ export const ACTION_VERSION_INFO = 'version-info'
 export const ACTION_UNHANDLED_ERROR = 'unhandled-error'
 export const ACTION_UNHANDLED_REJECTION = 'unhandled-rejection'
 export const ACTION_DEBUG_INFO = 'debug-info'
+export const ACTION_EXPENSIVE_IMPORT = 'expensive-import'
 
 interface StaticIndicatorAction {
   type: typeof ACTION_STATIC_INDICATOR
@@ -71,6 +75,11 @@ export interface DebugInfoAction {
   debugInfo: any
 }
 
+interface ExpensiveImportAction {
+  type: typeof ACTION_EXPENSIVE_IMPORT
+  expensiveImport: ExpensiveImport | null
+}
+
 interface VersionInfoAction {
   type: typeof ACTION_VERSION_INFO
   versionInfo: VersionInfo
@@ -86,6 +95,7 @@ export type BusEvent =
   | VersionInfoAction
   | StaticIndicatorAction
   | DebugInfoAction
+  | ExpensiveImportAction
 
 function pushErrorFilterDuplicates(
   errors: SupportedErrorEvent[],
@@ -110,6 +120,7 @@ export const INITIAL_OVERLAY_STATE: OverlayState = {
   rootLayoutMissingTags: [],
   versionInfo: { installed: '0.0.0', staleness: 'unknown' },
   debugInfo: undefined,
+  expensiveImport: null,
 }
 
 export function useErrorOverlayReducer() {
@@ -182,6 +193,9 @@ export function useErrorOverlayReducer() {
       case ACTION_VERSION_INFO: {
         return { ..._state, versionInfo: action.versionInfo }
       }
+      case ACTION_EXPENSIVE_IMPORT: {
+        return { ..._state, expensiveImport: action.expensiveImport }
+      }
       default: {
         return _state
       }
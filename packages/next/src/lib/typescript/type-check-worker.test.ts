@@ -0,0 +1,39 @@
+import { parseTsgoOutput } from './type-check-worker'
+
+describe('parseTsgoOutput()', () => {
+  it('parses diagnostics with 0-indexed positions', () => {
+    expect(
+      parseTsgoOutput(
+        "app/page.tsx(3,7): error TS2322: Type 'number' is not assignable to type 'string'.\n",
+        '/project'
+      )
+    ).toEqual([
+      {
+        category: 'error',
+        code: 2322,
+        message: "Type 'number' is not assignable to type 'string'.",
+        filePath: '/project/app/page.tsx',
+        range: {
+          start: { line: 2, column: 6 },
+          end: { line: 2, column: 6 },
+        },
+      },
+    ])
+  })
+
+  it('appends indented lines to the message of the previous diagnostic', () => {
+    const diagnostics = parseTsgoOutput(
+      [
+        "lib/a.ts(1,1): error TS2345: Argument of type '{}' is not assignable to parameter of type 'Props'.",
+        "  Property 'id' is missing in type '{}'.",
+        '',
+        'Found 1 error in lib/a.ts:1',
+      ].join('\n'),
+      '/project'
+    )
+    expect(diagnostics).toHaveLength(1)
+    expect(diagnostics[0].message).toBe(
+      "Argument of type '{}' is not assignable to parameter of type 'Props'.\nProperty 'id' is missing in type '{}'."
+    )
+  })
+})
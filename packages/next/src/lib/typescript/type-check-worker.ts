@@ -0,0 +1,313 @@
+import { spawn } from 'child_process'
+import path from 'path'
+import { getTypeScriptConfiguration } from './getTypeScriptConfiguration'
+import { getRequiredConfiguration } from './writeConfigurationDefaults'
+
+/**
+ * A diagnostic reported by the type checker. Lines and columns are 0-indexed
+ * like the ranges of Turbopack issues.
+ */
+export interface TypeCheckDiagnostic {
+  category: 'error' | 'warning'
+  code: number
+  message: string
+  filePath?: string
+  range?: {
+    start: { line: number; column: number }
+    end: { line: number; column: number }
+  }
+}
+
+export interface TypeCheckResult {
+  diagnostics: TypeCheckDiagnostic[]
+  // The tsconfig files of the projects which were checked again, relative to
+  // `dir`. Projects without changed files keep their previous diagnostics.
+  checkedProjects: string[]
+}
+
+interface ProjectState {
+  program?: import('typescript').SemanticDiagnosticsBuilderProgram
+  fileNames: Set<string>
+  modifiedTimes: Map<string, number>
+  diagnostics: TypeCheckDiagnostic[]
+}
+
+// This module runs in a persistent worker, so the programs of the previous
+// check are reused and only the changed files are checked again.
+const projects = new Map<string, ProjectState>()
+
+function tryResolve(request: string, dir: string): string | undefined {
+  try {
+    return require.resolve(request, { paths: [dir] })
+  } catch {
+    return undefined
+  }
+}
+
+function getModifiedTime(
+  ts: typeof import('typescript'),
+  fileName: string
+): number {
+  return ts.sys.getModifiedTime?.(fileName)?.getTime() ?? 0
+}
+
+/**
+ * Collects the project at `tsconfigPath` and the projects it references,
+ * referenced projects first.
+ */
+async function collectProjects(
+  ts: typeof import('typescript'),
+  tsconfigPath: string,
+  seen = new Map<string, import('typescript').ParsedCommandLine>()
+): Promise<Map<string, import('typescript').ParsedCommandLine>> {
+  if (seen.has(tsconfigPath)) {
+    return seen
+  }
+  const parsed = await getTypeScriptConfiguration(ts, tsconfigPath)
+  for (const reference of parsed.projectReferences ?? []) {
+    await collectProjects(ts, ts.resolveProjectReferencePath(reference), seen)
+  }
+  seen.set(tsconfigPath, parsed)
+  return seen
+}
+
+function hasChanged(
+  ts: typeof import('typescript'),
+  state: ProjectState,
+  fileNames: string[]
+): boolean {
+  if (
+    fileNames.length !== state.fileNames.size ||
+    fileNames.some((fileName) => !state.fileNames.has(fileName))
+  ) {
+    return true
+  }
+  for (const [fileName, time] of state.modifiedTimes) {
+    if (getModifiedTime(ts, fileName) !== time) {
+      return true
+    }
+  }
+  return false
+}
+
+function toTypeCheckDiagnostic(
+  ts: typeof import('typescript'),
+  diagnostic: import('typescript').Diagnostic
+): TypeCheckDiagnostic {
+  const result: TypeCheckDiagnostic = {
+    category:
+      diagnostic.category === ts.DiagnosticCategory.Error ? 'error' : 'warning',
+    code: diagnostic.code,
+    message: ts.flattenDiagnosticMessageText(diagnostic.messageText, '\n'),
+  }
+  if (diagnostic.file) {
+    result.filePath = diagnostic.file.fileName
+    if (diagnostic.start !== undefined) {
+      const start = diagnostic.file.getLineAndCharacterOfPosition(
+        diagnostic.start
+      )
+      const end = diagnostic.file.getLineAndCharacterOfPosition(
+        diagnostic.start + (diagnostic.length ?? 0)
+      )
+      result.range = {
+        start: { line: start.line, column: start.character },
+        end: { line: end.line, column: end.character },
+      }
+    }
+  }
+  return result
+}
+
+function checkWithTypeScript(
+  ts: typeof import('typescript'),
+  parsed: import('typescript').ParsedCommandLine,
+  state: ProjectState
+): TypeCheckDiagnostic[] {
+  const options: import('typescript').CompilerOptions = {
+    ...getRequiredConfiguration(ts),
+    ...parsed.options,
+    declarationMap: false,
+    emitDeclarationOnly: false,
+    noEmit: true,
+    // Referenced projects are checked on their own, their sources are read
+    // directly instead of their declaration output.
+    composite: false,
+    incremental: false,
+  }
+  const program = ts.createSemanticDiagnosticsBuilderProgram(
+    parsed.fileNames,
+    options,
+    ts.createIncrementalCompilerHost(options),
+    state.program
+  )
+  state.program = program
+
+  state.modifiedTimes = new Map()
+  for (const sourceFile of program.getSourceFiles()) {
+    state.modifiedTimes.set(
+      sourceFile.fileName,
+      getModifiedTime(ts, sourceFile.fileName)
+    )
+  }
+
+  // Files of other projects are imported by their source, only report the
+  // diagnostics of the files which belong to this project.
+  return [
+    ...program.getConfigFileParsingDiagnostics(),
+    ...program.getOptionsDiagnostics(),
+    ...program.getGlobalDiagnostics(),
+    ...program.getSyntacticDiagnostics(),
+    ...program.getSemanticDiagnostics(),
+  ]
+    .filter(
+      (diagnostic) =>
+        diagnostic.category === ts.DiagnosticCategory.Error ||
+        diagnostic.category === ts.DiagnosticCategory.Warning
+    )
+    .filter(
+      (diagnostic) =>
+        !diagnostic.file || state.fileNames.has(diagnostic.file.fileName)
+    )
+    .map((diagnostic) => toTypeCheckDiagnostic(ts, diagnostic))
+}
+
+const TSGO_DIAGNOSTIC_REGEX =
+  /^(.+)\((\d+),(\d+)\): (error|warning) TS(\d+): (.*)$/
+
+/**
+ * Parses the diagnostics printed by `tsgo --pretty false`. Their messages
+ * continue on indented lines.
+ */
+export function parseTsgoOutput(
+  output: string,
+  projectDir: string
+): TypeCheckDiagnostic[] {
+  const diagnostics: TypeCheckDiagnostic[] = []
+  for (const line of output.split(/\r?\n/)) {
+    const match = TSGO_DIAGNOSTIC_REGEX.exec(line)
+    if (match) {
+      const [, file, lineNumber, column, category, code, message] = match
+      const start = {
+        line: Number(lineNumber) - 1,
+        column: Number(column) - 1,
+      }
+      diagnostics.push({
+        category: category as TypeCheckDiagnostic['category'],
+        code: Number(code),
+        message,
+        filePath: path.resolve(projectDir, file).replaceAll(path.sep, '/'),
+        range: { start, end: start },
+      })
+    } else if (/^\s+\S/.test(line) && diagnostics.length > 0) {
+      diagnostics[diagnostics.length - 1].message += '\n' + line.trim()
+    }
+  }
+  return diagnostics
+}
+
+async function checkWithTsgo(
+  ts: typeof import('typescript'),
+  tsgoBin: string,
+  tsconfigPath: string,
+  parsed: import('typescript').ParsedCommandLine,
+  state: ProjectState
+): Promise<TypeCheckDiagnostic[]> {
+  const projectDir = path.dirname(tsconfigPath)
+  const output = await new Promise<string>((resolve, reject) => {
+    const child = spawn(
+      process.execPath,
+      [tsgoBin, '--noEmit', '--pretty', 'false', '-p', tsconfigPath],
+      { cwd: projectDir }
+    )
+    let stdout = ''
+    child.stdout.on('data', (data) => (stdout += data))
+    child.on('error', reject)
+    child.on('close', () => resolve(stdout))
+  })
+
+  // tsgo doesn't list the files it read, the files of the project are used to
+  // detect changes instead.
+  state.modifiedTimes = new Map(
+    parsed.fileNames.map((fileName) => [
+      fileName,
+      getModifiedTime(ts, fileName),
+    ])
+  )
+
+  return parseTsgoOutput(output, projectDir)
+}
+
+/**
+ * Checks the project at `tsconfigPath` and its referenced projects. Only the
+ * projects with changed files are checked again, using `tsgo` from
+ * `@typescript/native-preview` when it's installed.
+ */
+export async function typeCheck({
+  dir,
+  tsconfigPath,
+}: {
+  dir: string
+  tsconfigPath: string
+}): Promise<TypeCheckResult> {
+  const typescriptPath = tryResolve('typescript', dir)
+  if (!typescriptPath) {
+    return { diagnostics: [], checkedProjects: [] }
+  }
+  const ts: typeof import('typescript') = require(typescriptPath)
+  const tsgoPackageJson = tryResolve(
+    '@typescript/native-preview/package.json',
+    dir
+  )
+  const tsgoBin = tsgoPackageJson
+    ? path.join(path.dirname(tsgoPackageJson), 'bin', 'tsgo.js')
+    : undefined
+
+  const diagnostics: TypeCheckDiagnostic[] = []
+  const checkedProjects: string[] = []
+  let parsedProjects
+  try {
+    parsedProjects = await collectProjects(
+      ts,
+      path.resolve(dir, tsconfigPath)
+    )
+  } catch (err) {
+    projects.clear()
+    return {
+      diagnostics: [
+        {
+          category: 'error',
+          code: 0,
+          message: err instanceof Error ? err.message : String(err),
+          filePath: path.resolve(dir, tsconfigPath),
+        },
+      ],
+      checkedProjects: [],
+    }
+  }
+
+  for (const configPath of projects.keys()) {
+    if (!parsedProjects.has(configPath)) {
+      projects.delete(configPath)
+    }
+  }
+
+  for (const [configPath, parsed] of parsedProjects) {
+    let state = projects.get(configPath)
+    if (!state || hasChanged(ts, state, parsed.fileNames)) {
+      state ??= {
+        fileNames: new Set(),
+        modifiedTimes: new Map(),
+        diagnostics: [],
+      }
+      state.fileNames = new Set(parsed.fileNames)
+      state.diagnostics = tsgoBin
+        ? await checkWithTsgo(ts, tsgoBin, configPath, parsed, state)
+        : checkWithTypeScript(ts, parsed, state)
+      projects.set(configPath, state)
+      checkedProjects.push(path.relative(dir, configPath))
+    }
+    diagnostics.push(...state.diagnostics)
+  }
+
+  return { diagnostics, checkedProjects }
+}
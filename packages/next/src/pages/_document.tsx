@@ -198,6 +198,7 @@ function getScripts(
       <script
         key={file}
         src={`${assetPrefix}/_next/${encodeURIPath(file)}${assetQueryString}`}
+        type={buildManifest.esmChunks ? 'module' : undefined}
         nonce={props.nonce}
         async={!isDevelopment && disableOptimizedLoading}
         defer={!disableOptimizedLoading}
@@ -523,6 +524,39 @@ export class Head extends React.Component<HeadProps> {
     )
   }
 
+  getResourceHintLinks(): JSX.Element[] {
+    const {
+      buildManifest,
+      assetPrefix,
+      assetQueryString,
+      crossOrigin,
+      __NEXT_DATA__,
+    } = this.context
+
+    return ['/_app', __NEXT_DATA__.page].flatMap((page) => {
+      const resourceHints = buildManifest.resourceHints?.[page]
+      if (!resourceHints) {
+        return []
+      }
+      const link = (rel: 'prefetch' | 'preload', file: string) => (
+        <link
+          key={file}
+          nonce={this.props.nonce}
+          rel={rel}
+          href={`${assetPrefix}/_next/${encodeURIPath(
+            file
+          )}${assetQueryString}`}
+          as="script"
+          crossOrigin={this.props.crossOrigin || crossOrigin}
+        />
+      )
+      return [
+        ...resourceHints.preload.map((file) => link('preload', file)),
+        ...resourceHints.prefetch.map((file) => link('prefetch', file)),
+      ]
+    })
+  }
+
   getPreloadMainLinks(files: DocumentFiles): JSX.Element[] | null {
     const { assetPrefix, assetQueryString, scriptLoader, crossOrigin } =
       this.context
@@ -878,6 +912,9 @@ export class Head extends React.Component<HeadProps> {
             {!disableRuntimeJS &&
               !disableJsPreload &&
               this.getPreloadMainLinks(files)}
+            {!disableRuntimeJS &&
+              !disableJsPreload &&
+              this.getResourceHintLinks()}
 
             {!disableOptimizedLoading &&
               !disableRuntimeJS &&
@@ -1638,7 +1638,7 @@ async function renderToStream(
         nonce: ctx.nonce,
       }))
 
-  const [preinitScripts, bootstrapScript] = getRequiredScripts(
+  const [preinitScripts, bootstrapOptions] = getRequiredScripts(
     renderOpts.buildManifest,
     // Why is assetPrefix optional on renderOpts?
     // @TODO make it default empty string on renderOpts and get rid of it from ctx
@@ -1863,9 +1863,9 @@ async function renderToStream(
         maxHeadersLength: renderOpts.reactMaxHeadersLength,
         // When debugging the static shell, client-side rendering should be
         // disabled to prevent blanking out the page.
-        bootstrapScripts: renderOpts.isDebugStaticShell
-          ? []
-          : [bootstrapScript],
+        ...(renderOpts.isDebugStaticShell
+          ? { bootstrapScripts: [] }
+          : bootstrapOptions),
         formState,
       }
     )
@@ -1956,7 +1956,7 @@ async function renderToStream(
       res.statusCode = 500
     }
 
-    const [errorPreinitScripts, errorBootstrapScript] = getRequiredScripts(
+    const [errorPreinitScripts, errorBootstrapOptions] = getRequiredScripts(
       renderOpts.buildManifest,
       ctx.assetPrefix,
       renderOpts.crossOrigin,
@@ -2007,7 +2007,7 @@ async function renderToStream(
           streamOptions: {
             nonce: ctx.nonce,
             // Include hydration scripts in the HTML
-            bootstrapScripts: [errorBootstrapScript],
+            ...errorBootstrapOptions,
             formState,
           },
         }
@@ -2468,7 +2468,7 @@ async function prerenderToStream(
         nonce: ctx.nonce,
       }))
 
-  const [preinitScripts, bootstrapScript] = getRequiredScripts(
+  const [preinitScripts, bootstrapOptions] = getRequiredScripts(
     renderOpts.buildManifest,
     // Why is assetPrefix optional on renderOpts?
     // @TODO make it default empty string on renderOpts and get rid of it from ctx
@@ -2715,9 +2715,9 @@ async function prerenderToStream(
                   },
                   // When debugging the static shell, client-side rendering should be
                   // disabled to prevent blanking out the page.
-                  bootstrapScripts: renderOpts.isDebugStaticShell
-                    ? []
-                    : [bootstrapScript],
+                  ...(renderOpts.isDebugStaticShell
+                    ? { bootstrapScripts: [] }
+                    : bootstrapOptions),
                 }
               ),
             () => {
@@ -2881,9 +2881,9 @@ async function prerenderToStream(
                 maxHeadersLength: renderOpts.reactMaxHeadersLength,
                 // When debugging the static shell, client-side rendering should be
                 // disabled to prevent blanking out the page.
-                bootstrapScripts: renderOpts.isDebugStaticShell
-                  ? []
-                  : [bootstrapScript],
+                ...(renderOpts.isDebugStaticShell
+                  ? { bootstrapScripts: [] }
+                  : bootstrapOptions),
               }
             ),
           () => {
@@ -3183,9 +3183,9 @@ async function prerenderToStream(
               },
               // When debugging the static shell, client-side rendering should be
               // disabled to prevent blanking out the page.
-              bootstrapScripts: renderOpts.isDebugStaticShell
-                ? []
-                : [bootstrapScript],
+              ...(renderOpts.isDebugStaticShell
+                ? { bootstrapScripts: [] }
+                : bootstrapOptions),
             }
           )
           pendingInitialClientResult.catch((err: unknown) => {
@@ -3338,9 +3338,9 @@ async function prerenderToStream(
                   },
                   // When debugging the static shell, client-side rendering should be
                   // disabled to prevent blanking out the page.
-                  bootstrapScripts: renderOpts.isDebugStaticShell
-                    ? []
-                    : [bootstrapScript],
+                  ...(renderOpts.isDebugStaticShell
+                    ? { bootstrapScripts: [] }
+                    : bootstrapOptions),
                 }
               ),
             () => {
@@ -3499,9 +3499,9 @@ async function prerenderToStream(
           maxHeadersLength: renderOpts.reactMaxHeadersLength,
           // When debugging the static shell, client-side rendering should be
           // disabled to prevent blanking out the page.
-          bootstrapScripts: renderOpts.isDebugStaticShell
-            ? []
-            : [bootstrapScript],
+          ...(renderOpts.isDebugStaticShell
+            ? { bootstrapScripts: [] }
+            : bootstrapOptions),
         }
       )
       const getServerInsertedHTML = makeGetServerInsertedHTML({
@@ -3702,9 +3702,9 @@ async function prerenderToStream(
           nonce: ctx.nonce,
           // When debugging the static shell, client-side rendering should be
           // disabled to prevent blanking out the page.
-          bootstrapScripts: renderOpts.isDebugStaticShell
-            ? []
-            : [bootstrapScript],
+          ...(renderOpts.isDebugStaticShell
+            ? { bootstrapScripts: [] }
+            : bootstrapOptions),
         }
       )
 
@@ -3804,7 +3804,7 @@ async function prerenderToStream(
       res.statusCode = 500
     }
 
-    const [errorPreinitScripts, errorBootstrapScript] = getRequiredScripts(
+    const [errorPreinitScripts, errorBootstrapOptions] = getRequiredScripts(
       renderOpts.buildManifest,
       ctx.assetPrefix,
       renderOpts.crossOrigin,
@@ -3855,7 +3855,7 @@ async function prerenderToStream(
         streamOptions: {
           nonce: ctx.nonce,
           // Include hydration scripts in the HTML
-          bootstrapScripts: [errorBootstrapScript],
+          ...errorBootstrapOptions,
           formState,
         },
       })
@@ -3,6 +3,19 @@ import type { BuildManifest } from '../get-page-files'
 
 import ReactDOM from 'react-dom'
 
+type BootstrapScript = {
+  src: string
+  integrity?: string
+  crossOrigin?: string | undefined
+}
+
+export type BootstrapOptions = {
+  bootstrapScripts: Array<BootstrapScript>
+  // the bootstrap scripts when the client chunks are ES modules
+  bootstrapModules?: Array<BootstrapScript>
+  bootstrapScriptContent?: string
+}
+
 export function getRequiredScripts(
   buildManifest: BuildManifest,
   assetPrefix: string,
@@ -11,22 +24,35 @@ export function getRequiredScripts(
   qs: string,
   nonce: string | undefined,
   pagePath: string
-): [
-  () => void,
-  { src: string; integrity?: string; crossOrigin?: string | undefined },
-] {
+): [() => void, BootstrapOptions] {
   let preinitScripts: () => void
   let preinitScriptCommands: string[] = []
-  const bootstrapScript: {
-    src: string
-    integrity?: string
-    crossOrigin?: string | undefined
-  } = {
+  const bootstrapScript: BootstrapScript = {
     src: '',
     crossOrigin,
   }
 
-  const files = (
+  // ES module chunks are loaded with `<script type="module">`
+  const esmChunks = buildManifest.esmChunks === true
+  const preinit = (src: string, integrity?: string) => {
+    if (esmChunks) {
+      ReactDOM.preinitModule(src, {
+        as: 'script',
+        integrity,
+        crossOrigin,
+        nonce,
+      })
+    } else {
+      ReactDOM.preinit(src, {
+        as: 'script',
+        integrity,
+        crossOrigin,
+        nonce,
+      })
+    }
+  }
+
+  let files = (
     buildManifest.rootMainFilesTree?.[pagePath] || buildManifest.rootMainFiles
   ).map(encodeURIPath)
   if (files.length === 0) {
@@ -34,11 +60,32 @@ export function getRequiredScripts(
       'Invariant: missing bootstrap script. This is a bug in Next.js'
     )
   }
+
+  // The runtime chunk is inlined into the HTML instead of being requested. It
+  // has to run after the other chunks are in the document, so all of them are
+  // preinitialized and the inlined content bootstraps the page.
+  const inlinedFiles = buildManifest.inlinedRootMainFiles?.[pagePath]
+  let bootstrapScriptContent: string | undefined
+  if (inlinedFiles) {
+    bootstrapScriptContent = ''
+    files = files.filter((file) => {
+      const content = inlinedFiles[decodeURI(file)]
+      if (content === undefined) {
+        return true
+      }
+      bootstrapScriptContent += content + '\n'
+      return false
+    })
+  }
+  const firstPreinitFile = bootstrapScriptContent === undefined ? 1 : 0
+
   if (SRIManifest) {
-    bootstrapScript.src = `${assetPrefix}/_next/` + files[0] + qs
-    bootstrapScript.integrity = SRIManifest[files[0]]
+    if (bootstrapScriptContent === undefined) {
+      bootstrapScript.src = `${assetPrefix}/_next/` + files[0] + qs
+      bootstrapScript.integrity = SRIManifest[files[0]]
+    }
 
-    for (let i = 1; i < files.length; i++) {
+    for (let i = firstPreinitFile; i < files.length; i++) {
       const src = `${assetPrefix}/_next/` + files[i] + qs
       const integrity = SRIManifest[files[i]]
       preinitScriptCommands.push(src, integrity)
@@ -46,32 +93,74 @@ export function getRequiredScripts(
     preinitScripts = () => {
       // preinitScriptCommands is a double indexed array of src/integrity pairs
       for (let i = 0; i < preinitScriptCommands.length; i += 2) {
-        ReactDOM.preinit(preinitScriptCommands[i], {
-          as: 'script',
-          integrity: preinitScriptCommands[i + 1],
-          crossOrigin,
-          nonce,
-        })
+        preinit(preinitScriptCommands[i], preinitScriptCommands[i + 1])
       }
     }
   } else {
-    bootstrapScript.src = `${assetPrefix}/_next/` + files[0] + qs
+    if (bootstrapScriptContent === undefined) {
+      bootstrapScript.src = `${assetPrefix}/_next/` + files[0] + qs
+    }
 
-    for (let i = 1; i < files.length; i++) {
+    for (let i = firstPreinitFile; i < files.length; i++) {
       const src = `${assetPrefix}/_next/` + files[i] + qs
       preinitScriptCommands.push(src)
     }
     preinitScripts = () => {
       // preinitScriptCommands is a singled indexed array of src values
       for (let i = 0; i < preinitScriptCommands.length; i++) {
-        ReactDOM.preinit(preinitScriptCommands[i], {
+        preinit(preinitScriptCommands[i])
+      }
+    }
+  }
+
+  // The chunks of dynamic imports with a prefetch or preload comment are
+  // fetched along with the page. There is no API to prefetch them, so they are
+  // preloaded with a low priority instead.
+  const resourceHints = buildManifest.resourceHints?.[pagePath]
+  if (resourceHints) {
+    const preinitPageScripts = preinitScripts
+    preinitScripts = () => {
+      preinitPageScripts()
+      if (esmChunks) {
+        // Module preloads don't support a fetch priority, so prefetched
+        // chunks are preloaded as well
+        for (const file of [
+          ...resourceHints.preload,
+          ...resourceHints.prefetch,
+        ]) {
+          ReactDOM.preloadModule(
+            `${assetPrefix}/_next/${encodeURIPath(file)}${qs}`,
+            { as: 'script', nonce, crossOrigin }
+          )
+        }
+        return
+      }
+      for (const file of resourceHints.preload) {
+        ReactDOM.preload(`${assetPrefix}/_next/${encodeURIPath(file)}${qs}`, {
           as: 'script',
           nonce,
           crossOrigin,
         })
       }
+      for (const file of resourceHints.prefetch) {
+        ReactDOM.preload(`${assetPrefix}/_next/${encodeURIPath(file)}${qs}`, {
+          as: 'script',
+          nonce,
+          crossOrigin,
+          fetchPriority: 'low',
+        })
+      }
     }
   }
 
-  return [preinitScripts, bootstrapScript]
+  if (bootstrapScriptContent !== undefined) {
+    return [preinitScripts, { bootstrapScripts: [], bootstrapScriptContent }]
+  }
+  if (esmChunks) {
+    return [
+      preinitScripts,
+      { bootstrapScripts: [], bootstrapModules: [bootstrapScript] },
+    ]
+  }
+  return [preinitScripts, { bootstrapScripts: [bootstrapScript] }]
 }
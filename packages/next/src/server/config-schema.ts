@@ -178,6 +178,7 @@ export const configSchema: zod.ZodType<NextConfig> = z.lazy(() =>
             z.boolean().optional(),
             z.object({
               properties: z.array(z.string()).optional(),
+              productionOnly: z.boolean().optional(),
             }),
           ])
           .optional(),
@@ -194,6 +195,7 @@ export const configSchema: zod.ZodType<NextConfig> = z.lazy(() =>
             z.boolean().optional(),
             z.object({
               exclude: z.array(z.string()).min(1).optional(),
+              productionOnly: z.boolean().optional(),
             }),
           ])
           .optional(),
@@ -294,6 +296,9 @@ export const configSchema: zod.ZodType<NextConfig> = z.lazy(() =>
         disablePostcssPresetEnv: z.boolean().optional(),
         dynamicIO: z.boolean().optional(),
         inlineCss: z.boolean().optional(),
+        inlineRuntimeChunk: z
+          .union([z.boolean(), z.array(z.string())])
+          .optional(),
         esmExternals: z.union([z.boolean(), z.literal('loose')]).optional(),
         serverActions: z
           .object({
@@ -336,12 +341,81 @@ export const configSchema: zod.ZodType<NextConfig> = z.lazy(() =>
         reactOwnerStack: z.boolean().optional(),
         prerenderEarlyExit: z.boolean().optional(),
         proxyTimeout: z.number().gte(0).optional(),
+        devMocks: z.record(z.string(), z.string()).optional(),
         scrollRestoration: z.boolean().optional(),
         sri: z
           .object({
             algorithm: z.enum(['sha256', 'sha384', 'sha512']).optional(),
           })
           .optional(),
+        fontProvider: z
+          .union([
+            z.object({
+              type: z.literal('css2').optional(),
+              stylesheetUrl: z.string().url(),
+              userAgent: z.string().optional(),
+            }),
+            z.object({
+              type: z.literal('fontsource'),
+              userAgent: z.string().optional(),
+            }),
+          ])
+          .optional(),
+        fontCaCertificates: z.string().optional(),
+        fontCache: z
+          .object({
+            directory: z.string(),
+            offline: z.boolean().optional(),
+          })
+          .optional(),
+        fontDownloads: z
+          .object({
+            concurrency: z.number().int().positive().optional(),
+            retries: z.number().int().nonnegative().optional(),
+            cacheDirectory: z.string().optional(),
+          })
+          .optional(),
+        fontSubsetting: z.boolean().optional(),
+        fontMetrics: z
+          .record(
+            z.string(),
+            z.object({
+              capsizeKey: z.string().optional(),
+              azAvgWidth: z.number().positive(),
+              unitsPerEm: z.number().int().positive(),
+            })
+          )
+          .optional(),
+        fontFamilyHashing: z
+          .union([z.literal('content'), z.object({ salt: z.string() })])
+          .optional(),
+        sitemap: z
+          .object({
+            baseUrl: z.string().url(),
+            entries: z
+              .array(
+                z.object({
+                  url: z.string(),
+                  lastModified: z.string().optional(),
+                  changeFrequency: z
+                    .enum([
+                      'always',
+                      'hourly',
+                      'daily',
+                      'weekly',
+                      'monthly',
+                      'yearly',
+                      'never',
+                    ])
+                    .optional(),
+                  priority: z.number().min(0).max(1).optional(),
+                })
+              )
+              .optional(),
+            exclude: z.array(z.string()).optional(),
+            robots: z.boolean().optional(),
+          })
+          .optional(),
         strictNextHead: z.boolean().optional(),
         swcPlugins: z
           // The specific swc plugin's option is unknown, use z.any() here
@@ -400,6 +474,132 @@ export const configSchema: zod.ZodType<NextConfig> = z.lazy(() =>
               )
               .optional(),
             resolveExtensions: z.array(z.string()).optional(),
+            resolveInterceptors: z
+              .array(
+                z.object({
+                  test: z.string(),
+                  replacement: z.string().optional(),
+                  resolve: z
+                    .function()
+                    .args(z.string(), z.object({ context: z.string() }))
+                    .returns(z.any())
+                    .optional(),
+                })
+              )
+              .optional(),
+            preserveSymlinks: z.boolean().optional(),
+            caseSensitivePaths: z.boolean().optional(),
+            restrictedImports: z
+              .array(
+                z.strictObject({
+                  from: z.string(),
+                  disallow: z.array(z.string()),
+                  message: z.string().optional(),
+                })
+              )
+              .optional(),
+            remoteImports: z
+              .strictObject({
+                lockfile: z.string().optional(),
+                cacheDirectory: z.string().optional(),
+                frozenLockfile: z.boolean().optional(),
+              })
+              .optional(),
+            pnpmInjectedFromSource: z.boolean().optional(),
+            packageStore: z
+              .strictObject({
+                directory: z.string(),
+                lockfile: z.string().optional(),
+              })
+              .optional(),
+            resolveModules: z.array(z.string()).optional(),
+            packageConditions: z
+              .record(
+                z.string(),
+                z.record(
+                  z.enum(['client', 'server', 'edge', 'react-server']),
+                  z.array(z.string())
+                )
+              )
+              .optional(),
+            conditionNames: z
+              .record(
+                z.enum(['client', 'server', 'edge', 'react-server']),
+                z.array(z.string())
+              )
+              .optional(),
+            assetPrefixFallback: z.boolean().optional(),
+            chunkCacheGroups: z
+              .record(
+                z.string(),
+                z.union([
+                  z.array(z.string()),
+                  z.strictObject({
+                    packages: z.array(z.string()).optional(),
+                    test: z
+                      .union([z.string(), z.instanceof(RegExp)])
+                      .optional(),
+                  }),
+                ])
+              )
+              .optional(),
+            moduleGroups: z
+              .record(
+                z.string(),
+                z.strictObject({
+                  packages: z.array(z.string()).optional(),
+                  modules: z.array(z.string()).optional(),
+                })
+              )
+              .optional(),
+            minChunkSize: z.number().int().nonnegative().optional(),
+            maxChunkSize: z.number().int().positive().optional(),
+            themes: z
+              .record(z.string(), z.record(z.string(), z.string()))
+              .optional(),
+            browserExtension: z
+              .strictObject({
+                manifest: z.string(),
+                transformManifest: z
+                  .function()
+                  .args(z.record(z.string(), z.any()))
+                  .returns(z.any())
+                  .optional(),
+              })
+              .optional(),
+            featureUsageReport: z.boolean().optional(),
+            envUsageReport: z.boolean().optional(),
+            workerTimeouts: z
+              .strictObject({
+                slowAfterMs: z.number().int().positive().optional(),
+                timeoutMs: z.number().int().positive().optional(),
+                operations: z
+                  .record(
+                    z.string(),
+                    z.strictObject({
+                      slowAfterMs: z.number().int().positive().optional(),
+                      timeoutMs: z.number().int().positive().optional(),
+                    })
+                  )
+                  .optional(),
+              })
+              .optional(),
+            inlineAssets: z
+              .strictObject({
+                limit: z.number().int().nonnegative().optional(),
+                extensions: z
+                  .record(z.string(), z.number().int().nonnegative())
+                  .optional(),
+              })
+              .optional(),
+            typeCheck: z.boolean().optional(),
+            devSnapshots: z.number().int().positive().optional(),
+            outputClean: z
+              .union([
+                z.boolean(),
+                z.strictObject({ keep: z.array(z.string()).optional() }),
+              ])
+              .optional(),
             treeShaking: z.boolean().optional(),
             persistentCaching: z
               .union([z.number(), z.literal(false)])
@@ -407,6 +607,58 @@ export const configSchema: zod.ZodType<NextConfig> = z.lazy(() =>
             memoryLimit: z.number().optional(),
             moduleIdStrategy: z.enum(['named', 'deterministic']).optional(),
             minify: z.boolean().optional(),
+            contentHashing: z.boolean().optional(),
+            runtimeChunk: z.boolean().optional(),
+            esmChunks: z.boolean().optional(),
+            cssChunkSplitting: z.boolean().optional(),
+            maxCssChunkSize: z.number().int().positive().optional(),
+            expensiveImportHints: z
+              .union([
+                z.literal(false),
+                z.strictObject({
+                  size: z.number().int().positive().optional(),
+                  modules: z.number().int().positive().optional(),
+                }),
+              ])
+              .optional(),
+            importCostServer: z
+              .number()
+              .int()
+              .nonnegative()
+              .max(65535)
+              .optional(),
+            moduleFederation: z
+              .strictObject({
+                name: z.string().optional(),
+                exposes: z.record(z.string(), z.string()).optional(),
+                remotes: z.record(z.string(), z.string()).optional(),
+                shared: z
+                  .record(
+                    z.string(),
+                    z.strictObject({
+                      singleton: z.boolean().optional(),
+                      requiredVersion: z.string().optional(),
+                      version: z.string().optional(),
+                    })
+                  )
+                  .optional(),
+              })
+              .optional(),
+            loaderPermissions: z
+              .strictObject({
+                trusted: z.array(z.string()).optional(),
+                allowRead: z.array(z.string()).optional(),
+                allowWrite: z.array(z.string()).optional(),
+              })
+              .optional(),
+            reactSingleton: z.enum(['warn', 'enforce']).optional(),
+            chunkingBudget: z
+              .strictObject({
+                maxInitialJsBytes: z.number().int().nonnegative(),
+                failOnExceed: z.boolean().optional(),
+              })
+              .optional(),
+            sideEffectFreePackages: z.array(z.string()).optional(),
           })
           .optional(),
         optimizePackageImports: z.array(z.string()).optional(),
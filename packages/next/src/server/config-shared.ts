@@ -113,6 +113,86 @@ export type TurboRuleConfigItem =
   | { [condition: string]: TurboRuleConfigItem }
   | false
 
+export type BrowserExtensionManifestTransform = (
+  manifest: Record<string, any>
+) => Record<string, any> | void | Promise<Record<string, any> | void>
+
+export type TurboBrowserExtension = {
+  /**
+   * The `manifest.json` template of the extension, relative to the project
+   * directory. The scripts it references, relative to the template, are
+   * compiled: `background.service_worker` is replaced with a service worker
+   * importing the chunks of the script, the scripts in the `js` of
+   * `content_scripts` with their chunks, and scripts used as popup, options,
+   * side panel, devtools or override pages with an HTML page loading their
+   * chunks. Chunks are always loaded from the extension itself and never use
+   * `eval`. They can't be loaded on demand, so the modules of dynamic
+   * `import()`s are bundled with the script importing them.
+   *
+   * @example './extension/manifest.json'
+   */
+  manifest: string
+  /**
+   * Called with the generated `manifest.json`, with the file names of the
+   * compiled scripts filled in. Returns the manifest to write, or modifies it
+   * in place.
+   */
+  transformManifest?: BrowserExtensionManifestTransform
+}
+
+export type TurboWorkerTimeout = {
+  /**
+   * Report the operation once it runs longer than this, in milliseconds.
+   */
+  slowAfterMs?: number
+  /**
+   * Abort the operation and stop its worker once it runs longer than this,
+   * in milliseconds.
+   */
+  timeoutMs?: number
+}
+
+export type TurboWorkerTimeouts = TurboWorkerTimeout & {
+  /**
+   * Thresholds of specific operations, keyed by the name of a loader or
+   * `postcss`. Missing thresholds are taken from the default ones.
+   *
+   * @example { 'sass-loader': { timeoutMs: 120000 } }
+   */
+  operations?: Record<string, TurboWorkerTimeout>
+}
+
+export type TurboResolveInterceptor = {
+  /**
+   * The requests to intercept, an exact request or a pattern with a `*`
+   * wildcard like the keys of `resolveAlias`, e.g. `lodash/*`.
+   */
+  test: string
+  /**
+   * The request to resolve instead, relative to the importing module. A `*`
+   * is replaced with the part of the request matched by the wildcard.
+   */
+  replacement?: string
+  /**
+   * Called for the intercepted requests when there's no `replacement`.
+   * Returns the request to resolve instead, relative to the importing module,
+   * `false` to resolve to an empty module, or `undefined` to resolve the
+   * request as usual. Results are cached, so it has to return the same result
+   * for the same request and context.
+   */
+  resolve?: (
+    request: string,
+    options: {
+      /** The directory of the importing module. */
+      context: string
+    }
+  ) =>
+    | string
+    | false
+    | undefined
+    | Promise<string | false | undefined>
+}
+
 export interface ExperimentalTurboOptions {
   /**
    * (`next --turbopack` only) A mapping of aliased imports to modules to load in their place.
@@ -131,6 +211,279 @@ export interface ExperimentalTurboOptions {
    */
   resolveExtensions?: string[]
 
+  /**
+   * (`next --turbopack` only) Requests to resolve differently, similar to
+   * webpack's `NormalModuleReplacementPlugin`. They take precedence over
+   * `resolveAlias` and the aliases set up by Next.js.
+   */
+  resolveInterceptors?: TurboResolveInterceptor[]
+
+  /**
+   * (`next --turbopack` only) Keep symlinked paths of resolved modules instead
+   * of following them to the real path, like webpack's
+   * `resolve.symlinks: false`.
+   */
+  preserveSymlinks?: boolean
+
+  /**
+   * (`next --turbopack` only) Report an error when the casing of a request
+   * doesn't match the casing of the resolved file on disk, like
+   * `case-sensitive-paths-webpack-plugin`. Such requests resolve on
+   * case-insensitive file systems, e.g. on macOS, but fail on Linux.
+   */
+  caseSensitivePaths?: boolean
+
+  /**
+   * (`next --turbopack` only) Imports to forbid in parts of the app, like the
+   * `no-restricted-imports` ESLint rule. Modules matching the `from` glob,
+   * relative to the project, must not import requests matching one of the
+   * `disallow` globs as written, e.g.
+   * `{ from: 'app/**', disallow: ['@/server/db/**'] }`. Violations are build
+   * errors pointing at the import.
+   */
+  restrictedImports?: {
+    from: string
+    disallow: string[]
+    /** Explains the rule in the error, e.g. which module to use instead. */
+    message?: string
+  }[]
+
+  /**
+   * (`next --turbopack` only) Allow code running in the edge runtime to
+   * import modules from `https://` URLs, like Deno. Modules are fetched once
+   * and cached, and their content is verified against the SHA-256 hashes in
+   * the lockfile, e.g. `{ "remote": { "https://esm.sh/preact": "<hash>" } }`.
+   * Modules missing from the lockfile are reported with their hash, a
+   * mismatching hash is a build error. With a frozen lockfile, modules missing
+   * from it are build errors too and aren't fetched.
+   */
+  remoteImports?: {
+    /** Relative to the project. Defaults to `remote-imports.lock`. */
+    lockfile?: string
+    /**
+     * Relative to the project. Defaults to `<distDir>/cache/remote-imports`.
+     */
+    cacheDirectory?: string
+    /** Defaults to `true` in `next build`. */
+    frozenLockfile?: boolean
+  }
+
+  /**
+   * (`next --turbopack` only) Read the files of pnpm's injected dependencies
+   * (`dependenciesMeta.*.injected`) from the workspace package they were
+   * copied from, while still resolving their dependencies from the copy in
+   * `node_modules/.pnpm`. Changes of the package then show up without running
+   * `pnpm install`.
+   *
+   * @default true
+   */
+  pnpmInjectedFromSource?: boolean
+
+  /**
+   * (`next --turbopack` only) Resolve the packages of `package-lock.json`
+   * from a global content-addressed store instead of `node_modules`, so CI
+   * doesn't need to materialize `node_modules`. Packages are looked up in
+   * `<directory>/<algorithm>/<digest>` by the integrity of their tarball, with
+   * the digest base64url encoded without padding, e.g. `sha512-3q2+7w==` in
+   * `<directory>/sha512/3q2-7w`. Packages missing from the lockfile are still
+   * resolved from `node_modules`.
+   */
+  packageStore?: {
+    /** Relative to the project, within the root of the workspace. */
+    directory: string
+    /** Relative to the project. Defaults to `package-lock.json`. */
+    lockfile?: string
+  }
+
+  /**
+   * (`next --turbopack` only) Additional directories to resolve packages in
+   * before `node_modules`, like webpack's `resolve.modules`. Names like `src`
+   * are looked up in the directory of the importing module and its parents,
+   * paths starting with `.` or `/` are relative to the project directory.
+   *
+   * @example ['src', './vendor']
+   */
+  resolveModules?: string[]
+
+  /**
+   * (`next --turbopack` only) Conditions to resolve the `exports` field of
+   * specific packages with, per graph. Replaces the default conditions, e.g.
+   * to use the `browser` build of a package on the server.
+   */
+  packageConditions?: Record<
+    string,
+    Partial<Record<'client' | 'server' | 'edge' | 'react-server', string[]>>
+  >
+
+  /**
+   * (`next --turbopack` only) Additional conditions to resolve the `exports`
+   * and `imports` fields of all packages with, per graph, e.g.
+   * `{ edge: ['worker'] }`. The `react-server` conditions are added to the
+   * `server` or `edge` ones for server components.
+   */
+  conditionNames?: Partial<
+    Record<'client' | 'server' | 'edge' | 'react-server', string[]>
+  >
+
+  /**
+   * (`next --turbopack` only) Retry loading chunks from the same origin when
+   * loading them from the `assetPrefix` fails, e.g. when the CDN is blocked or
+   * a chunk fails its integrity check. In production builds, retried chunks are
+   * verified with the hashes of `static/chunks/integrity-manifest.json`, which
+   * is emitted next to them.
+   */
+  assetPrefixFallback?: boolean
+
+  /**
+   * (`next --turbopack` only) Named groups of packages or modules which are
+   * placed in client chunks of their own, like webpack's
+   * `splitChunks.cacheGroups`, e.g.
+   * `{ 'react-vendor': ['react', 'react-dom'], 'ui-kit': ['@radix-ui/*'] }`
+   * or `{ 'vendor-viz': { packages: ['lodash'], test: /[\\/]d3-/ } }`.
+   * The chunks are named after the group and keep their name and content as
+   * long as the modules don't change, which keeps them cached across
+   * deploys.
+   */
+  chunkCacheGroups?: Record<
+    string,
+    | string[]
+    | {
+        packages?: string[]
+        /**
+         * Matched against the identifiers of modules, e.g.
+         * `[project]/node_modules/d3-array/src/index.js [client] (ecmascript)`.
+         * Only the `i` flag of a `RegExp` is supported.
+         */
+        test?: string | RegExp
+      }
+  >
+
+  /**
+   * (`next --turbopack` only) Named groups of packages or modules which are
+   * never split across client chunks, e.g. a WASM wrapper and its JavaScript
+   * glue code: `{ codec: { modules: ['./lib/codec/**'] } }`. The modules of a
+   * group are placed in a single chunk regardless of its size. The build fails
+   * if a module matches multiple groups, or a group and a `chunkCacheGroups`
+   * entry, or if a group mixes JavaScript and CSS.
+   */
+  moduleGroups?: Record<
+    string,
+    {
+      /**
+       * Package names, e.g. `react`, or all packages of a scope, e.g.
+       * `@radix-ui/*`.
+       */
+      packages?: string[]
+      /**
+       * Globs matched against the paths of modules, relative to the project
+       * directory.
+       */
+      modules?: string[]
+    }
+  >
+
+  /**
+   * (`next --turbopack` only) Client chunks smaller than this many bytes are
+   * merged with other chunks of the same chunk group, as long as the result
+   * doesn't exceed `maxChunkSize`. Fewer, larger chunks make better use of
+   * HTTP/2 prioritization in large apps.
+   *
+   * @default 100000
+   */
+  minChunkSize?: number
+
+  /**
+   * (`next --turbopack` only) Client chunks larger than this many bytes are
+   * split at module boundaries. A single module larger than this is placed in
+   * a chunk of its own. Chunks of `chunkCacheGroups` and CSS chunks, which
+   * must keep the order of their rules, are not split.
+   *
+   * @default 1000000
+   */
+  maxChunkSize?: number
+
+  /**
+   * (`next --turbopack` only) Theme variants built in the same compilation,
+   * as import aliases keyed by theme id, e.g.
+   * `{ acme: { '@/theme/tokens.css': './themes/acme/tokens.css' } }`. The
+   * module graph of the app is shared by all themes, while the client CSS of
+   * each route is built again for every theme, with its aliases applied to the
+   * imports of the stylesheets. The chunks are listed by theme and route in
+   * `server/theme-manifest.json`.
+   */
+  themes?: Record<string, Record<string, string>>
+
+  /**
+   * (`next --turbopack` only) Build a Manifest V3 browser extension in the
+   * same compilation as the app, written to the `extension` directory of the
+   * dist dir by `next build`.
+   */
+  browserExtension?: TurboBrowserExtension
+
+  /**
+   * (`next --turbopack` only) Write `feature-usage.json` to the dist dir when
+   * building. It lists the modules using dynamic imports, `eval`, Node.js
+   * builtins or the legacy React context API, and the packages only shipping
+   * CommonJS modules, with a summary of the Node.js builtins used by client
+   * code.
+   */
+  featureUsageReport?: boolean
+
+  /**
+   * (`next --turbopack` only) Write `env-usage.json` to the dist dir when
+   * building. It lists the `process.env` variables referenced by the modules
+   * of each route, middleware and instrumentation, whether they're inlined
+   * when building or read at runtime, and the modules accessing `process.env`
+   * dynamically, e.g. `process.env[name]`, which can read any variable.
+   */
+  envUsageReport?: boolean
+
+  /**
+   * (`next --turbopack` only) Thresholds after which the webpack loaders and
+   * PostCSS running in the Node.js workers are reported as slow, naming the
+   * loaders and the module, or aborted. Slow operations are reported after
+   * 30 seconds by default, operations are never aborted by default.
+   */
+  workerTimeouts?: TurboWorkerTimeouts
+
+  /**
+   * (`next --turbopack` only) Inline static files, like images and fonts,
+   * which are smaller than `limit` bytes as `data:` URLs into the JavaScript
+   * and CSS referencing them instead of emitting them. `extensions` overrides
+   * the limit per file extension, e.g. `{ svg: 4096, png: 0 }` inlines SVGs
+   * under 4kB and never inlines PNGs. Nothing is inlined by default.
+   */
+  inlineAssets?: {
+    limit?: number
+    extensions?: Record<string, number>
+  }
+
+  /**
+   * (`next --turbopack` only) Check the types of the app in a persistent
+   * worker while running `next dev` and display the type errors in the error
+   * overlay. Uses `tsgo` when `@typescript/native-preview` is installed. Only
+   * the referenced projects with changed files are checked again.
+   */
+  typeCheck?: boolean
+
+  /**
+   * (`next --turbopack` only) Keep this many snapshots of the module graph and
+   * the issues of the app while running `next dev`, one after each
+   * compilation which changes them. `/__nextjs_snapshots` lists them, and
+   * `/__nextjs_snapshots?ago=3` lists the modules added or removed and the
+   * issues introduced or resolved since three snapshots ago.
+   */
+  devSnapshots?: number
+
+  /**
+   * (`next --turbopack` only) Remove the assets written by the previous
+   * `next build` which aren't written by the current one from the dist dir.
+   * The assets of each build are listed in `output-artifacts-manifest.json`,
+   * other files in the dist dir are left alone. `keep` lists globs, relative
+   * to the dist dir, of assets which are never removed.
+   */
+  outputClean?: boolean | { keep?: string[] }
+
   /**
    * (`next --turbopack` only) A list of webpack loaders to apply when running with Turbopack.
    *
@@ -162,8 +515,9 @@ export interface ExperimentalTurboOptions {
 
   /**
    * The module ID strategy to use for Turbopack.
-   * If not set, the default is `'named'` for development and `'deterministic'`
-   * for production.
+   * If not set, the default is `'named'`. `'deterministic'` derives the module
+   * IDs from hashed module paths, so they stay the same across production
+   * builds.
    */
   moduleIdStrategy?: 'named' | 'deterministic'
 
@@ -177,6 +531,175 @@ export interface ExperimentalTurboOptions {
    * Enable minification. Defaults to true in build mode and false in dev mode.
    */
   minify?: boolean
+
+  /**
+   * (`next build --turbopack` only) Add a hash of their content to the file
+   * names of client chunks, so that they can be cached forever, e.g.
+   * `static/chunks/pages_index_2a0a5f._.4f1c2b9e.js`. The hash only depends on
+   * the content of the chunk, not on the machine or directory it was built
+   * in. `chunk-hash-manifest.json` in the dist dir maps the file names
+   * without hashes to the emitted ones.
+   */
+  contentHashing?: boolean
+
+  /**
+   * Emit the Turbopack runtime as a separate chunk shared by all pages,
+   * instead of including it in the entry chunks of every page, so that
+   * editing a page doesn't change the runtime cached by browsers.
+   */
+  runtimeChunk?: boolean
+
+  /**
+   * Emit client chunks as ES modules, which are loaded with
+   * `<script type="module">` and `import()` instead of the chunk loader of
+   * the Turbopack runtime. Requires browsers with native module support, and
+   * the modules of the chunks are evaluated in strict mode. HMR updates are
+   * applied the same way as with classic chunks.
+   */
+  esmChunks?: boolean
+
+  /**
+   * Split the CSS of each page and of each dynamically imported chunk group
+   * into multiple chunks, by package and size, instead of a single chunk. The
+   * chunks only contain consecutive stylesheets and are loaded in the order
+   * the CSS is imported, so the rules keep the order of a single chunk.
+   */
+  cssChunkSplitting?: boolean
+
+  /**
+   * Split the CSS chunk of each page and of each dynamically imported chunk
+   * group into consecutive chunks of at most this many bytes, when
+   * `cssChunkSplitting` isn't enabled. The chunks are loaded in order, so the
+   * rules keep their order. For browserslist targets including IE 9 and older,
+   * chunks are also split to stay below their limit of 4095 selectors.
+   */
+  maxCssChunkSize?: number
+
+  /**
+   * (`next dev --turbopack` only) Show a notice in the dev overlay when an edit
+   * adds more than `size` bytes or more than `modules` modules to the client
+   * code of a page, e.g. by importing a large dependency. The notice lists the
+   * packages which added the most code. Defaults to 250 kB and 100 modules,
+   * `false` disables it.
+   */
+  expensiveImportHints?:
+    | false
+    | {
+        size?: number
+        modules?: number
+      }
+
+  /**
+   * (`next dev --turbopack` only) Answer the import cost requests of editor
+   * plugins on this port of `127.0.0.1`, `0` picks a free port. The port is
+   * written to `import-cost-server.json` in the dist dir.
+   *
+   * The server speaks JSON-RPC framed with `Content-Length` headers, like the
+   * Language Server Protocol. The `importCost` method takes the `file`
+   * containing an import and the `specifier` of the import, and responds with
+   * the size and the gzip size of the code the import adds to each client
+   * layer of the app, as bundled from the live module graph. Modules also imported
+   * elsewhere aren't counted. An `importCost/didChange` notification is sent
+   * after each compilation.
+   */
+  importCostServer?: number
+
+  /**
+   * (`next build --turbopack` only) Share code with separately built apps at
+   * runtime through Module Federation.
+   *
+   * A remote exposes modules under a `name`, which are written to
+   * `static/remoteEntry.js` with the chunks they need. Remotes need an
+   * `assetPrefix` pointing to their origin.
+   *
+   * A host lists the remote entries of its `remotes` and imports their modules
+   * as `{name}/{module}`, e.g. `import('app2/Button')`. Modules of remotes are
+   * only loaded in the browser, so components using them need to be rendered
+   * with `ssr: false`.
+   *
+   * `shared` packages are used in a single version by all apps on a page when
+   * they are `singleton`s, like `react`, and in the highest version satisfying
+   * the `requiredVersion` of an app otherwise.
+   *
+   * Host and remotes share the Turbopack runtime of the page, so they need to
+   * be built with the same `experimental.turbo.root`, e.g. the root of a
+   * monorepo, to have unique module ids.
+   */
+  moduleFederation?: {
+    /** The name of this app as a remote. */
+    name?: string
+    /**
+     * Modules exposed to other apps, e.g.
+     * `{ './Button': './components/Button' }`.
+     */
+    exposes?: Record<string, string>
+    /** The URLs of the remote entries of other apps by their name. */
+    remotes?: Record<string, string>
+    /** Packages shared with other apps. */
+    shared?: Record<
+      string,
+      {
+        /** Use a single version of the package on a page. */
+        singleton?: boolean
+        /** The semver range of versions of the package this app works with. */
+        requiredVersion?: string
+        /**
+         * The version provided by this app, read from its `package.json` by
+         * default.
+         */
+        version?: string
+      }
+    >
+  }
+
+  /**
+   * Restrict the file system access of the webpack loaders of `rules` which
+   * aren't trusted, using the Node.js permission model (Node.js 22.13 or
+   * later; with older versions untrusted loaders are not run and an error is
+   * reported). They can only read the project and `allowRead`, only write
+   * `allowWrite`, and can't start child processes or workers or load native
+   * addons.
+   *
+   * This is not a sandbox for untrusted code: network access isn't
+   * restricted, and SWC plugins and PostCSS plugins aren't restricted at all.
+   */
+  loaderPermissions?: {
+    /** Loaders which run without restrictions, e.g. `['@svgr/webpack']`. */
+    trusted?: string[]
+    /** Paths, relative to the project, untrusted loaders can also read. */
+    allowRead?: string[]
+    /** Paths, relative to the project, untrusted loaders can write. */
+    allowWrite?: string[]
+  }
+
+  /**
+   * Check that `react`, `react-dom` and `scheduler` resolve to a single copy in
+   * each runtime, reporting the paths and versions of duplicate copies. With
+   * `enforce`, duplicates of the same version as the hoisted copy are aliased
+   * to it and the remaining ones fail the build.
+   */
+  reactSingleton?: 'warn' | 'enforce'
+
+  /**
+   * (`next build --turbopack` only) Limit the size of the JS chunks each route
+   * loads initially. A `chunking-budget.json` report listing the chunks and
+   * the largest modules of a route is written next to its other manifests,
+   * and a warning is shown for routes exceeding the budget.
+   */
+  chunkingBudget?: {
+    /** The maximum size of the JS chunks a route loads initially, in bytes. */
+    maxInitialJsBytes: number
+    /** Fail the build instead of warning when a route exceeds the budget. */
+    failOnExceed?: boolean
+  }
+
+  /**
+   * Packages whose modules are treated as side-effect-free regardless of the
+   * `sideEffects` field of their `package.json`, so that the modules of them
+   * which aren't used are removed by tree shaking, e.g.
+   * `['some-icon-library']`.
+   */
+  sideEffectFreePackages?: string[]
 }
 
 export interface WebpackConfigContext {
@@ -306,6 +829,17 @@ export interface ExperimentalConfig {
   cpus?: number
   memoryBasedWorkersCount?: boolean
   proxyTimeout?: number
+
+  /**
+   * Serves paths from mocks instead of the app during `next dev`, e.g. to
+   * develop the frontend without access to the backends API routes call.
+   * Keys are paths using the syntax of `rewrites`, e.g. `/api/users/:id`,
+   * and values are files relative to the project: `.js` and `.cjs` files
+   * export a `(req, res, params) => void` resolver, other files are served as
+   * static fixtures.
+   */
+  devMocks?: Record<string, string>
+
   isrFlushToDisk?: boolean
   workerThreads?: boolean
   // optimizeCss can be boolean or critters' option object
@@ -341,6 +875,162 @@ export interface ExperimentalConfig {
     algorithm?: SubresourceIntegrityAlgorithm
   }
 
+  /**
+   * (`next --turbopack` only) Serve `next/font/google` fonts from another
+   * provider instead: a Google Fonts-compatible one, e.g. Bunny Fonts or a
+   * self-hosted mirror, or Fontsource. Only fonts which are also on Google
+   * Fonts can be requested.
+   */
+  fontProvider?:
+    | {
+        type?: 'css2'
+        /**
+         * The css2 API endpoint stylesheets are requested from, e.g.
+         * `https://fonts.bunny.net/css2`.
+         */
+        stylesheetUrl: string
+        /**
+         * The user agent sent when fetching stylesheets and font files.
+         */
+        userAgent?: string
+      }
+    | {
+        /**
+         * Builds the stylesheets from the metadata of Fontsource's API and
+         * downloads the font files from its CDN. Variable fonts only support
+         * the `wght` axis.
+         */
+        type: 'fontsource'
+        /**
+         * The user agent sent when fetching metadata and font files.
+         */
+        userAgent?: string
+      }
+
+  /**
+   * (`next --turbopack` only) Path to a PEM bundle of additional CA
+   * certificates to trust when fetching fonts, e.g. for corporate proxies
+   * intercepting TLS. Proxies are configured through the `HTTPS_PROXY`,
+   * `HTTP_PROXY` and `NO_PROXY` environment variables.
+   */
+  fontCaCertificates?: string
+
+  /**
+   * (`next --turbopack` only) Cache the stylesheets and font files fetched for
+   * `next/font/google` in a project-local directory, so that they can be
+   * committed and used by builds without network access.
+   */
+  fontCache?: {
+    /**
+     * The cache directory, relative to the project.
+     */
+    directory: string
+    /**
+     * Resolve fonts only from the cache instead of populating it from the
+     * network. Builds fail listing the missing entries when the cache is
+     * incomplete.
+     */
+    offline?: boolean
+  }
+
+  /**
+   * (`next --turbopack` only) How `next/font/google` downloads font files.
+   */
+  fontDownloads?: {
+    /**
+     * The maximum number of font files downloaded at the same time. Defaults
+     * to `8`.
+     */
+    concurrency?: number
+    /**
+     * How often failed downloads are retried, with an exponential backoff.
+     * Defaults to `3`.
+     */
+    retries?: number
+    /**
+     * The directory, relative to the project, downloaded font files are
+     * cached in across builds. Defaults to `<distDir>/cache/fonts`.
+     */
+    cacheDirectory?: string
+  }
+
+  /**
+   * (`next --turbopack` only) Strip the glyphs outside of the declared
+   * `subsets` from self-hosted `next/font/google` fonts and drop the
+   * `@font-face` rules of other subsets. Fonts with ligatures, kerning or
+   * other layout features are served complete, as subsetting would drop
+   * them. Defaults to `true`.
+   */
+  fontSubsetting?: boolean
+
+  /**
+   * (`next --turbopack` only) Metrics of additional locally installed fonts,
+   * keyed by font name, so `next/font/local` can generate an adjusted
+   * fallback with them, e.g. `adjustFontFallback: 'Corporate Sans'`.
+   */
+  fontMetrics?: Record<
+    string,
+    {
+      /** The key of the font in the capsize metrics. Defaults to its name. */
+      capsizeKey?: string
+      /** The average advance width of `a-z` and spaces, in font units. */
+      azAvgWidth: number
+      unitsPerEm: number
+    }
+  >
+
+  /**
+   * (`next --turbopack` only) How the suffix scoping `next/font` requests in
+   * generated filenames and class names is derived. `'content'` hashes the
+   * font request and `{ salt }` mixes the salt into the hash. Defaults to
+   * `'content'`.
+   */
+  fontFamilyHashing?: 'content' | { salt: string }
+
+  /**
+   * (`next build --turbopack` only) Generate `sitemap.xml` and `robots.txt`
+   * from the static routes of the app and pages directories, served at
+   * `/sitemap.xml` and `/robots.txt` unless `public` has files with the same
+   * names. The `<lastmod>` of each route is the modification date of its page
+   * file.
+   */
+  sitemap?: {
+    /**
+     * The origin the site is served from, e.g. `https://example.com`.
+     */
+    baseUrl: string
+    /**
+     * Entries to add to the discovered routes, e.g. for dynamic routes.
+     * Entries for a discovered route replace it.
+     */
+    entries?: Array<{
+      /**
+       * An absolute url, or a path relative to `baseUrl`.
+       */
+      url: string
+      lastModified?: string
+      changeFrequency?:
+        | 'always'
+        | 'hourly'
+        | 'daily'
+        | 'weekly'
+        | 'monthly'
+        | 'yearly'
+        | 'never'
+      priority?: number
+    }>
+    /**
+     * Path prefixes to leave out of the sitemap, which are also disallowed in
+     * `robots.txt`.
+     */
+    exclude?: string[]
+    /**
+     * Whether to generate `robots.txt` referencing the sitemap.
+     * @default true
+     */
+    robots?: boolean
+  }
+
   webVitalsAttribution?: Array<(typeof WEB_VITALS)[number]>
 
   /**
@@ -557,6 +1247,13 @@ export interface ExperimentalConfig {
    */
   inlineCss?: boolean
 
+  /**
+   * (`next --turbopack` only) Inline the runtime chunk into the HTML instead of
+   * requesting it, for all app pages or the pages in the listed route groups,
+   * e.g. `['(marketing)']`. Supports app-router in production mode only.
+   */
+  inlineRuntimeChunk?: boolean | string[]
+
   // TODO: Remove this config when the API is stable.
   /**
    * This config allows you to enable the experimental navigation API `forbidden` and `unauthorized`.
@@ -906,7 +1603,13 @@ export interface NextConfig extends Record<string, any> {
     reactRemoveProperties?:
       | boolean
       | {
+          /**
+           * Regular expressions matching the JSX properties to remove.
+           * Defaults to `['^data-test']`.
+           */
           properties?: string[]
+          /** Keep the properties in development. */
+          productionOnly?: boolean
         }
     relay?: {
       src: string
@@ -917,7 +1620,10 @@ export interface NextConfig extends Record<string, any> {
     removeConsole?:
       | boolean
       | {
+          /** The `console` methods to keep, e.g. `['error']`. */
           exclude?: string[]
+          /** Keep `console` calls in development. */
+          productionOnly?: boolean
         }
     styledComponents?: boolean | StyledComponentsConfig
     emotion?: boolean | EmotionConfig
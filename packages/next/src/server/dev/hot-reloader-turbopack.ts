@@ -33,6 +33,8 @@ import { BLOCKED_PAGES } from '../../shared/lib/constants'
 import {
   getOverlayMiddleware,
   getSourceMapMiddleware,
+  getFontFallbacksMiddleware,
+  getSnapshotsMiddleware,
 } from '../../client/components/react-dev-overlay/server/middleware-turbopack'
 import { PageNotFoundError } from '../../shared/lib/utils'
 import { debounce } from '../utils'
@@ -48,6 +50,7 @@ import {
   type ChangeSubscriptions,
   type ClientState,
   type EntryIssuesMap,
+  type IssuesMap,
   formatIssue,
   getTurbopackJsConfig,
   handleEntrypoints,
@@ -61,6 +64,7 @@ import {
   type SendHmr,
   type StartBuilding,
   processTopLevelIssues,
+  processTypeCheckIssues,
   type TopLevelIssuesMap,
   isWellKnownError,
   printNonFatalIssue,
@@ -74,6 +78,17 @@ import {
 } from '../lib/router-utils/setup-dev-bundler'
 import { TurbopackManifestLoader } from './turbopack/manifest-loader'
 import type { Entrypoints } from './turbopack/types'
+import {
+  ExpensiveImportTracker,
+  getExpensiveImportThreshold,
+} from './turbopack/expensive-imports'
+import { createFocusMatcher, readFocus } from './turbopack/focus'
+import { createTypeChecker } from './turbopack/type-check'
+import { DevSnapshotHistory, takeDevSnapshot } from './turbopack/snapshots'
+import {
+  startImportCostServer,
+  type ImportCostServer,
+} from './turbopack/import-cost-server'
 import { findPagePathData } from './on-demand-entry-handler'
 import type { RouteDefinition } from '../route-definitions/route-definition'
 import {
@@ -189,6 +204,12 @@ export async function createHotReloaderTurbopack(
     distDir,
   })
 
+  // When a focus set is persisted, routes matching it are compiled eagerly and
+  // rebuilt on change. The other routes are only built when they're requested.
+  const focus = await readFocus(distDir)
+  const isFocused = focus ? createFocusMatcher(focus) : () => true
+  const unfocusedPagesNoticed = new Set<string>()
+
   // TODO: Implement
   let clientRouterFilters: any
   if (nextConfig.experimental.clientRouterFilter) {
@@ -263,6 +284,21 @@ export async function createHotReloaderTurbopack(
 
   const currentTopLevelIssues: TopLevelIssuesMap = new Map()
   const currentEntryIssues: EntryIssuesMap = new Map()
+  // Type errors are displayed like the errors of the compilation, but they
+  // don't delay HMR events.
+  const currentTypeCheckIssues: IssuesMap = new Map()
+  const typeChecker = nextConfig.experimental.turbo?.typeCheck
+    ? createTypeChecker({
+        dir,
+        tsconfigPath: nextConfig.typescript.tsconfigPath,
+      })
+    : undefined
+  const devSnapshots = nextConfig.experimental.turbo?.devSnapshots
+  const snapshotHistory = devSnapshots
+    ? new DevSnapshotHistory(devSnapshots)
+    : undefined
+  // Snapshots are taken one after the other, so that they are in order
+  let snapshotQueue = Promise.resolve()
 
   const manifestLoader = new TurbopackManifestLoader({
     buildId,
@@ -272,6 +308,10 @@ export async function createHotReloaderTurbopack(
 
   // Dev specific
   const changeSubscriptions: ChangeSubscriptions = new Map()
+  const expensiveImportThreshold = getExpensiveImportThreshold(nextConfig)
+  const expensiveImports = expensiveImportThreshold
+    ? new ExpensiveImportTracker(expensiveImportThreshold)
+    : undefined
   const serverPathState = new Map<string, string>()
   const readyIds: ReadyIds = new Set()
   let currentEntriesHandlingResolve: ((value?: unknown) => void) | undefined
@@ -436,6 +476,50 @@ export async function createHotReloaderTurbopack(
   }
   const sendEnqueuedMessagesDebounce = debounce(sendEnqueuedMessages, 2)
 
+  function addErrors(
+    errorsMap: Map<string, CompilationError>,
+    issues: Iterable<IssuesMap>
+  ) {
+    for (const issueMap of issues) {
+      for (const [key, issue] of issueMap) {
+        if (issue.severity === 'warning') continue
+        if (errorsMap.has(key)) continue
+
+        const message = formatIssue(issue)
+
+        errorsMap.set(key, {
+          message,
+          details: issue.detail
+            ? renderStyledStringToErrorAnsi(issue.detail)
+            : undefined,
+        })
+      }
+    }
+  }
+
+  function sendBuilt() {
+    const errors = new Map<string, CompilationError>()
+    addErrors(errors, currentEntryIssues.values())
+    addErrors(errors, [currentTypeCheckIssues])
+
+    for (const client of clients) {
+      const state = clientStates.get(client)
+      if (!state) {
+        continue
+      }
+
+      const clientErrors = new Map(errors)
+      addErrors(clientErrors, state.clientIssues.values())
+
+      sendToClient(client, {
+        action: HMR_ACTIONS_SENT_TO_BROWSER.BUILT,
+        hash: String(++hmrHash),
+        errors: [...clientErrors.values()],
+        warnings: [],
+      })
+    }
+  }
+
   const sendHmr: SendHmr = (id: string, payload: HMR_ACTION_TYPES) => {
     for (const client of clients) {
       clientStates.get(client)?.hmrPayloads.set(id, payload)
@@ -460,6 +544,8 @@ export async function createHotReloaderTurbopack(
     sendEnqueuedMessagesDebounce()
   }
 
+  async function skipChangeSubscription() {}
+
   async function subscribeToChanges(
     key: EntryKey,
     includeIssues: boolean,
@@ -475,12 +561,21 @@ export async function createHotReloaderTurbopack(
       return
     }
 
-    const { side } = splitEntryKey(key)
+    const { type, side, page } = splitEntryKey(key)
+    // Pages' client code changes are reported by the client subscription,
+    // app pages only have a server subscription, which includes the SSR of
+    // their client components
+    const clientModules =
+      (type === 'pages' && side === 'client') ||
+      (type === 'app' && side === 'server')
+        ? expensiveImports
+        : undefined
 
     const changedPromise = endpoint[`${side}Changed`](includeIssues)
     changeSubscriptions.set(key, changedPromise)
     try {
       const changed = await changedPromise
+      await clientModules?.update(key, page, endpoint)
 
       for await (const change of changed) {
         processIssues(currentEntryIssues, key, change, false, true)
@@ -488,6 +583,19 @@ export async function createHotReloaderTurbopack(
         if (payload) {
           sendHmr(key, payload)
         }
+        if (
+          clientModules &&
+          !change.issues.some((issue) => issue.severity === 'error')
+        ) {
+          const expensiveImport = await clientModules.update(
+            key,
+            page,
+            endpoint
+          )
+          if (expensiveImport) {
+            sendHmr(`${key}:expensive-import`, expensiveImport)
+          }
+        }
       }
     } catch (e) {
       changeSubscriptions.delete(key)
@@ -506,6 +614,7 @@ export async function createHotReloaderTurbopack(
       await subscription.return?.()
       changeSubscriptions.delete(key)
     }
+    expensiveImports?.delete(key)
     currentEntryIssues.delete(key)
   }
 
@@ -625,11 +734,31 @@ export async function createHotReloaderTurbopack(
     )
   )
 
+  const importCostPort = nextConfig.experimental.turbo?.importCostServer
+  let importCostServer: ImportCostServer | undefined
+  if (importCostPort !== undefined) {
+    try {
+      importCostServer = await startImportCostServer({
+        project,
+        dir,
+        distDir,
+        port: importCostPort,
+      })
+    } catch (err) {
+      Log.warn('Failed to start the import cost server')
+      console.error(err)
+    }
+  }
+
   const middlewares = [
     getOverlayMiddleware(project),
     getSourceMapMiddleware(project),
+    getFontFallbacksMiddleware(project),
     getNextErrorFeedbackMiddleware(opts.telemetry),
   ]
+  if (snapshotHistory) {
+    middlewares.push(getSnapshotsMiddleware(snapshotHistory))
+  }
 
   const versionInfoPromise = getVersionInfo(
     isTestMode || opts.telemetry.isEnabled
@@ -810,7 +939,10 @@ export async function createHotReloaderTurbopack(
 
         const errors: CompilationError[] = []
 
-        for (const entryIssues of currentEntryIssues.values()) {
+        for (const entryIssues of [
+          ...currentEntryIssues.values(),
+          currentTypeCheckIssues,
+        ]) {
           for (const issue of entryIssues.values()) {
             if (issue.severity !== 'warning') {
               errors.push({
@@ -1038,6 +1170,16 @@ export async function createHotReloaderTurbopack(
             throw new Error(`mis-matched route type: isApp && page for ${page}`)
           }
 
+          // Routes outside the focus aren't subscribed to changes, so editing
+          // them doesn't rebuild them until they're requested again
+          const focused = isFocused(page, isInsideAppDir)
+          if (!focused && !unfocusedPagesNoticed.has(page)) {
+            unfocusedPagesNoticed.add(page)
+            Log.info(
+              `Focus: ${pathname} is outside the focus, it's rebuilt on request instead of on change`
+            )
+          }
+
           const finishBuilding = startBuilding(pathname, requestUrl, false)
           try {
             await handleRouteType({
@@ -1054,7 +1196,9 @@ export async function createHotReloaderTurbopack(
               logErrors: true,
 
               hooks: {
-                subscribeToChanges,
+                subscribeToChanges: focused
+                  ? subscribeToChanges
+                  : skipChangeSubscription,
                 handleWrittenEndpoint: (id, result) => {
                   currentWrittenEntrypoints.set(id, result)
                   clearRequireCache(id, result)
@@ -1082,6 +1226,57 @@ export async function createHotReloaderTurbopack(
     entrypoints: currentEntrypoints,
   })
 
+  // Routes matching the focus are compiled eagerly in the background
+  if (focus) {
+    const focusedRoutes = [
+      ...[...currentEntrypoints.app].map(
+        ([page, route]) => [page, route, true] as const
+      ),
+      ...[...currentEntrypoints.page].map(
+        ([page, route]) => [page, route, false] as const
+      ),
+    ].filter(([page, , isApp]) => isFocused(page, isApp))
+
+    Log.info(
+      `Focus: compiling ${focusedRoutes.length} of ${
+        currentEntrypoints.app.size + currentEntrypoints.page.size
+      } routes eagerly (${focus.join(', ')})`
+    )
+
+    ;(async () => {
+      for (const [page, route, isApp] of focusedRoutes) {
+        await hotReloaderSpan
+          .traceChild('ensure-focused-page', { page })
+          .traceAsyncFn(() =>
+            handleRouteType({
+              dev,
+              page,
+              pathname: isApp ? normalizeAppPath(page) : page,
+              route,
+              currentEntryIssues,
+              entrypoints: currentEntrypoints,
+              manifestLoader,
+              readyIds,
+              devRewrites: opts.fsChecker.rewrites,
+              productionRewrites: undefined,
+              logErrors: true,
+
+              hooks: {
+                subscribeToChanges,
+                handleWrittenEndpoint: (id, result) => {
+                  currentWrittenEntrypoints.set(id, result)
+                  clearRequireCache(id, result)
+                  assetMapper.setPathsForKey(id, result.clientPaths)
+                },
+              },
+            })
+          )
+      }
+    })().catch((err) => {
+      Log.warn('Failed to compile focused routes', err)
+    })
+  }
+
   async function handleProjectUpdates() {
     for await (const updateMessage of project.updateInfoSubscribe(30)) {
       switch (updateMessage.updateType) {
@@ -1091,46 +1286,37 @@ export async function createHotReloaderTurbopack(
         }
         case 'end': {
           sendEnqueuedMessages()
-
-          function addErrors(
-            errorsMap: Map<string, CompilationError>,
-            issues: EntryIssuesMap
-          ) {
-            for (const issueMap of issues.values()) {
-              for (const [key, issue] of issueMap) {
-                if (issue.severity === 'warning') continue
-                if (errorsMap.has(key)) continue
-
-                const message = formatIssue(issue)
-
-                errorsMap.set(key, {
-                  message,
-                  details: issue.detail
-                    ? renderStyledStringToErrorAnsi(issue.detail)
-                    : undefined,
-                })
+          sendBuilt()
+          typeChecker?.check().then(
+            (issues) => {
+              if (processTypeCheckIssues(currentTypeCheckIssues, issues)) {
+                sendBuilt()
               }
+            },
+            (err) => {
+              Log.error('Type checking failed')
+              console.error(err)
             }
-          }
-
-          const errors = new Map<string, CompilationError>()
-          addErrors(errors, currentEntryIssues)
-
-          for (const client of clients) {
-            const state = clientStates.get(client)
-            if (!state) {
-              continue
-            }
-
-            const clientErrors = new Map(errors)
-            addErrors(clientErrors, state.clientIssues)
-
-            sendToClient(client, {
-              action: HMR_ACTIONS_SENT_TO_BROWSER.BUILT,
-              hash: String(++hmrHash),
-              errors: [...clientErrors.values()],
-              warnings: [],
-            })
+          )
+          importCostServer?.notifyChanged()
+          if (snapshotHistory) {
+            snapshotQueue = snapshotQueue
+              .then(() =>
+                takeDevSnapshot(
+                  project,
+                  snapshotHistory,
+                  [...currentEntryIssues.values()].flatMap((issues) => [
+                    ...issues.values(),
+                  ])
+                )
+              )
+              .then(
+                () => {},
+                (err) => {
+                  Log.warn('Failed to take a snapshot of the module graph')
+                  console.error(err)
+                }
+              )
           }
 
           if (hmrEventHappened) {
@@ -24,6 +24,7 @@ export const enum HMR_ACTIONS_SENT_TO_BROWSER {
   SERVER_ERROR = 'serverError',
   TURBOPACK_CONNECTED = 'turbopack-connected',
   APP_ISR_MANIFEST = 'appIsrManifest',
+  EXPENSIVE_IMPORT = 'expensiveImport',
 }
 
 interface ServerErrorAction {
@@ -115,6 +116,30 @@ export interface AppIsrManifestAction {
   data: Record<string, boolean>
 }
 
+export interface ExpensiveImportPackage {
+  /** The package name, or the path of a module outside of `node_modules`. */
+  name: string
+  /** The bytes the package added to the client code. */
+  size: number
+  /** The number of modules the package added to the client code. */
+  modules: number
+}
+
+export interface ExpensiveImport {
+  page: string
+  /** The bytes the rebuild added to the client code of the page. */
+  sizeDelta: number
+  /** The number of modules the rebuild added to the client code. */
+  newModules: number
+  /** The packages which added the most code, largest first. */
+  packages: ExpensiveImportPackage[]
+}
+
+export interface ExpensiveImportAction {
+  action: HMR_ACTIONS_SENT_TO_BROWSER.EXPENSIVE_IMPORT
+  data: ExpensiveImport
+}
+
 export type HMR_ACTION_TYPES =
   | TurbopackMessageAction
   | TurbopackConnectedAction
@@ -131,6 +156,7 @@ export type HMR_ACTION_TYPES =
   | DevPagesManifestUpdateAction
   | ServerErrorAction
   | AppIsrManifestAction
+  | ExpensiveImportAction
 
 export type TurbopackMsgToBrowser =
   | { type: HMR_ACTIONS_SENT_TO_BROWSER.TURBOPACK_MESSAGE; data: any }
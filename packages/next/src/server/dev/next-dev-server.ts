@@ -69,6 +69,7 @@ import { decorateServerError } from '../../shared/lib/error-source'
 import type { ServerOnInstrumentationRequestError } from '../app-render/types'
 import type { ServerComponentsHmrCache } from '../response-cache'
 import { logRequests } from './log-requests'
+import { measureServerTiming } from './server-timing'
 import { FallbackMode } from '../../lib/fallback'
 
 // Load ReactDevOverlay only when needed
@@ -836,7 +837,9 @@ export default class DevServer extends Server {
     definition: RouteDefinition | undefined
     url?: string
   }): Promise<void> {
-    await this.bundlerService.ensurePage(opts)
+    await measureServerTiming('compile', () =>
+      this.bundlerService.ensurePage(opts)
+    )
   }
 
   protected async findPageComponents({
@@ -0,0 +1,38 @@
+import { ServerTiming } from './server-timing'
+
+describe('ServerTiming', () => {
+  it('attributes time to the innermost phase', async () => {
+    let now = 0
+    const timing = new ServerTiming(() => now)
+
+    now = 5
+    await timing.measure('render', async () => {
+      now = 10
+      await timing.measure('compile', async () => {
+        now = 40
+      })
+      now = 60
+    })
+    now = 62
+
+    expect(timing.toHeader()).toBe(
+      'compile;desc="Compile";dur=30.0, render;desc="Render";dur=25.0, serve;desc="Serve";dur=7.0, total;dur=62.0'
+    )
+  })
+
+  it('includes running phases until the header is created', async () => {
+    let now = 0
+    const timing = new ServerTiming(() => now)
+
+    let header: string | undefined
+    await timing.measure('render', async () => {
+      now = 20
+      header = timing.toHeader()
+      now = 100
+    })
+
+    expect(header).toBe(
+      'render;desc="Render";dur=20.0, serve;desc="Serve";dur=0.0, total;dur=20.0'
+    )
+  })
+})
@@ -0,0 +1,109 @@
+import type { ServerResponse } from 'http'
+import { AsyncLocalStorage } from 'async_hooks'
+
+export type ServerTimingPhase = 'compile' | 'render' | 'serve'
+
+const PHASE_DESCRIPTIONS: Record<ServerTimingPhase, string> = {
+  compile: 'Compile',
+  render: 'Render',
+  serve: 'Serve',
+}
+
+/**
+ * Breaks down the time spent handling a request in the dev server into
+ * phases. Time is attributed to the innermost phase only, e.g. compiling a
+ * page while rendering it counts as compilation, not rendering. Everything
+ * outside of a phase, like routing and serving static files, counts as
+ * `serve`.
+ */
+export class ServerTiming {
+  private readonly durations = new Map<ServerTimingPhase, number>()
+  private readonly active: ServerTimingPhase[] = ['serve']
+  private readonly start: number
+  private lastSwitch: number
+
+  constructor(private readonly now: () => number = () => performance.now()) {
+    this.start = this.lastSwitch = now()
+  }
+
+  private switchPhase() {
+    const now = this.now()
+    const phase = this.active[this.active.length - 1]
+    this.durations.set(
+      phase,
+      (this.durations.get(phase) ?? 0) + (now - this.lastSwitch)
+    )
+    this.lastSwitch = now
+  }
+
+  async measure<T>(phase: ServerTimingPhase, fn: () => Promise<T>) {
+    this.switchPhase()
+    this.active.push(phase)
+    try {
+      return await fn()
+    } finally {
+      this.switchPhase()
+      // Concurrent phases of the same request can end in any order
+      this.active.splice(this.active.lastIndexOf(phase), 1)
+    }
+  }
+
+  /**
+   * The value of the `Server-Timing` header with the durations until now, in
+   * milliseconds.
+   */
+  toHeader(): string {
+    this.switchPhase()
+    const metrics: string[] = []
+    for (const [phase, description] of Object.entries(PHASE_DESCRIPTIONS)) {
+      const duration = this.durations.get(phase as ServerTimingPhase)
+      if (duration !== undefined) {
+        metrics.push(
+          `${phase};desc="${description}";dur=${duration.toFixed(1)}`
+        )
+      }
+    }
+    metrics.push(`total;dur=${(this.now() - this.start).toFixed(1)}`)
+    return metrics.join(', ')
+  }
+}
+
+const serverTimingStorage = new AsyncLocalStorage<ServerTiming>()
+
+/**
+ * Handles a request with `fn`, adding a `Server-Timing` header with the
+ * phases measured through `measureServerTiming` when the headers are sent.
+ * Phases which are still running, like streaming a page, are included with
+ * their duration until then.
+ */
+export function withServerTiming<T>(
+  res: ServerResponse,
+  fn: () => Promise<T>
+): Promise<T> {
+  const timing = new ServerTiming()
+  const writeHead = res.writeHead
+  // Node.js calls `writeHead` when the headers are sent implicitly too
+  res.writeHead = function (this: ServerResponse, ...args: any[]) {
+    if (!this.headersSent) {
+      const existing = this.getHeader('Server-Timing')
+      this.setHeader(
+        'Server-Timing',
+        existing ? `${existing}, ${timing.toHeader()}` : timing.toHeader()
+      )
+    }
+    return writeHead.apply(this, args as Parameters<typeof writeHead>)
+  } as typeof writeHead
+  return serverTimingStorage.run(timing, fn)
+}
+
+/**
+ * Measures `fn` as `phase` of the request handled by `withServerTiming`, if
+ * any.
+ */
+export function measureServerTiming<T>(
+  phase: ServerTimingPhase,
+  fn: () => Promise<T>
+): Promise<T> {
+  const timing = serverTimingStorage.getStore()
+  return timing ? timing.measure(phase, fn) : fn()
+}
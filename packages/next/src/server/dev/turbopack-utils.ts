@@ -243,6 +243,29 @@ export function processTopLevelIssues(
   }
 }
 
+/**
+ * Replaces the issues of the type checker. Returns whether they changed.
+ */
+export function processTypeCheckIssues(
+  currentTypeCheckIssues: IssuesMap,
+  issues: Issue[]
+): boolean {
+  const newIssues = new Map<IssueKey, Issue>()
+  for (const issue of issues) {
+    newIssues.set(getIssueKey(issue), issue)
+  }
+
+  const changed =
+    newIssues.size !== currentTypeCheckIssues.size ||
+    [...newIssues.keys()].some((key) => !currentTypeCheckIssues.has(key))
+
+  currentTypeCheckIssues.clear()
+  for (const [key, issue] of newIssues) {
+    currentTypeCheckIssues.set(key, issue)
+  }
+  return changed
+}
+
 export function processIssues(
   currentEntryIssues: EntryIssuesMap,
   key: EntryKey,
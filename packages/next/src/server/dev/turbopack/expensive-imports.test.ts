@@ -0,0 +1,62 @@
+import type { ClientModuleSize } from '../../../build/swc/types'
+import { diffClientModules } from './expensive-imports'
+
+const threshold = { size: 1000, modules: 10 }
+
+function createModule(
+  path: string,
+  size: number,
+  pkg?: string
+): ClientModuleSize {
+  return { path, size, package: pkg }
+}
+
+describe('diffClientModules', () => {
+  const page = createModule('[project]/app/page.tsx', 100)
+
+  it('ignores small changes', () => {
+    expect(
+      diffClientModules(
+        '/',
+        [page],
+        [page, createModule('[project]/lib/a.ts', 200)],
+        threshold
+      )
+    ).toBeUndefined()
+  })
+
+  it('reports the packages which added the most code', () => {
+    const action = diffClientModules(
+      '/',
+      [page],
+      [
+        page,
+        createModule('[project]/node_modules/lodash/a.js', 800, 'lodash'),
+        createModule('[project]/node_modules/lodash/b.js', 400, 'lodash'),
+        createModule('[project]/node_modules/ms/index.js', 50, 'ms'),
+        createModule('[project]/lib/a.ts', 100),
+      ],
+      threshold
+    )
+    expect(action?.data).toEqual({
+      page: '/',
+      sizeDelta: 1350,
+      newModules: 4,
+      packages: [
+        { name: 'lodash', size: 1200, modules: 2 },
+        { name: '[project]/lib/a.ts', size: 100, modules: 1 },
+        { name: 'ms', size: 50, modules: 1 },
+      ],
+    })
+  })
+
+  it('reports many new modules', () => {
+    const modules = Array.from({ length: 10 }, (_, i) =>
+      createModule(`[project]/lib/${i}.ts`, 1)
+    )
+    expect(
+      diffClientModules('/', [page], [page, ...modules], threshold)?.data
+        .newModules
+    ).toBe(10)
+  })
+})
@@ -0,0 +1,116 @@
+import type { ClientModuleSize, Endpoint } from '../../../build/swc/types'
+import type { NextConfigComplete } from '../../config-shared'
+import type {
+  ExpensiveImportAction,
+  ExpensiveImportPackage,
+} from '../hot-reloader-types'
+import { HMR_ACTIONS_SENT_TO_BROWSER } from '../hot-reloader-types'
+import type { EntryKey } from './entry-key'
+
+/** The number of packages listed in a notice. */
+const MAX_PACKAGES = 5
+
+export interface ExpensiveImportThreshold {
+  /** The bytes a rebuild has to add to the client code to be reported. */
+  size: number
+  /** The number of modules a rebuild has to add to be reported. */
+  modules: number
+}
+
+export function getExpensiveImportThreshold(
+  nextConfig: NextConfigComplete
+): ExpensiveImportThreshold | undefined {
+  const hints = nextConfig.experimental.turbo?.expensiveImportHints
+  if (hints === false) {
+    return undefined
+  }
+  return {
+    size: hints?.size ?? 250_000,
+    modules: hints?.modules ?? 100,
+  }
+}
+
+/**
+ * Compares the client modules of a page before and after a rebuild. Returns
+ * the notice to show when the rebuild added more code than the threshold.
+ */
+export function diffClientModules(
+  page: string,
+  previous: ClientModuleSize[],
+  current: ClientModuleSize[],
+  threshold: ExpensiveImportThreshold
+): ExpensiveImportAction | undefined {
+  const previousPaths = new Set(previous.map((module) => module.path))
+  const newModules = current.filter(
+    (module) => !previousPaths.has(module.path)
+  )
+  const sum = (modules: ClientModuleSize[]) =>
+    modules.reduce((size, module) => size + module.size, 0)
+  const sizeDelta = sum(current) - sum(previous)
+  if (sizeDelta < threshold.size && newModules.length < threshold.modules) {
+    return undefined
+  }
+
+  const packages = new Map<string, ExpensiveImportPackage>()
+  for (const module of newModules) {
+    const name = module.package ?? module.path
+    let pkg = packages.get(name)
+    if (!pkg) {
+      pkg = { name, size: 0, modules: 0 }
+      packages.set(name, pkg)
+    }
+    pkg.size += module.size
+    pkg.modules += 1
+  }
+
+  return {
+    action: HMR_ACTIONS_SENT_TO_BROWSER.EXPENSIVE_IMPORT,
+    data: {
+      page,
+      sizeDelta,
+      newModules: newModules.length,
+      packages: [...packages.values()]
+        .sort((a, b) => b.size - a.size)
+        .slice(0, MAX_PACKAGES),
+    },
+  }
+}
+
+/**
+ * Keeps the client modules of each subscribed entry, to tell how much code a
+ * rebuild added to it.
+ */
+export class ExpensiveImportTracker {
+  private snapshots = new Map<EntryKey, ClientModuleSize[]>()
+
+  constructor(private threshold: ExpensiveImportThreshold) {}
+
+  /**
+   * Records the client modules of the entry after a rebuild. The first call
+   * for an entry only records them.
+   */
+  async update(
+    key: EntryKey,
+    page: string,
+    endpoint: Endpoint
+  ): Promise<ExpensiveImportAction | undefined> {
+    let current
+    try {
+      current = await endpoint.clientModuleSizes()
+    } catch {
+      // The endpoint doesn't compile, compare the next successful rebuild
+      // with the last one instead
+      return undefined
+    }
+    const previous = this.snapshots.get(key)
+    this.snapshots.set(key, current)
+    if (!previous) {
+      return undefined
+    }
+    return diffClientModules(page, previous, current, this.threshold)
+  }
+
+  delete(key: EntryKey) {
+    this.snapshots.delete(key)
+  }
+}
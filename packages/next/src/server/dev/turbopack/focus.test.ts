@@ -0,0 +1,56 @@
+import { mkdir, mkdtemp, writeFile } from 'fs/promises'
+import { join } from 'path'
+import { tmpdir } from 'os'
+import { createFocusMatcher, readFocus, writeFocus } from './focus'
+
+describe('createFocusMatcher', () => {
+  it('matches the pathname of a route', () => {
+    const isFocused = createFocusMatcher(['/dashboard/**'])
+    expect(isFocused('/dashboard/settings/page', true)).toBe(true)
+    expect(isFocused('/dashboard/settings', false)).toBe(true)
+    expect(isFocused('/blog/page', true)).toBe(false)
+  })
+
+  it('ignores route groups and the page segment of app routes', () => {
+    const isFocused = createFocusMatcher(['/dashboard'])
+    expect(isFocused('/(admin)/dashboard/page', true)).toBe(true)
+    expect(isFocused('/dashboard/page', true)).toBe(true)
+  })
+
+  it('matches the directory of a route', () => {
+    const isFocused = createFocusMatcher([
+      'app/**/dashboard/**',
+      'pages/blog/**',
+    ])
+    expect(isFocused('/(admin)/dashboard/page', true)).toBe(true)
+    expect(isFocused('/blog/[slug]', false)).toBe(true)
+    expect(isFocused('/blog/[slug]', true)).toBe(false)
+  })
+
+  it('matches the root route', () => {
+    const isFocused = createFocusMatcher(['app'])
+    expect(isFocused('/page', true)).toBe(true)
+    expect(isFocused('/about/page', true)).toBe(false)
+  })
+})
+
+describe('readFocus', () => {
+  it('reads the globs written by writeFocus', async () => {
+    const distDir = await mkdtemp(join(tmpdir(), 'turbopack-focus'))
+    await writeFocus(distDir, ['/dashboard/**'])
+    expect(await readFocus(distDir)).toEqual(['/dashboard/**'])
+
+    await writeFocus(distDir, false)
+    expect(await readFocus(distDir)).toBeUndefined()
+  })
+
+  it('ignores an invalid focus file', async () => {
+    const distDir = await mkdtemp(join(tmpdir(), 'turbopack-focus'))
+    await mkdir(join(distDir, 'cache'))
+    await writeFile(
+      join(distDir, 'cache', 'turbopack-focus.json'),
+      JSON.stringify({ globs: [1] })
+    )
+    expect(await readFocus(distDir)).toBeUndefined()
+  })
+})
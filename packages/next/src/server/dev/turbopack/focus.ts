@@ -0,0 +1,64 @@
+import { mkdir, readFile, rm, writeFile } from 'fs/promises'
+import { dirname, join } from 'path'
+import picomatch from 'next/dist/compiled/picomatch'
+import { normalizeAppPath } from '../../../shared/lib/router/utils/app-paths'
+
+/**
+ * The focus set lives in the dist dir's cache folder so it is never committed
+ * and survives restarts of `next dev` for the developer who set it.
+ */
+function getFocusFilePath(distDir: string) {
+  return join(distDir, 'cache', 'turbopack-focus.json')
+}
+
+export async function readFocus(
+  distDir: string
+): Promise<string[] | undefined> {
+  try {
+    const content = JSON.parse(
+      await readFile(getFocusFilePath(distDir), 'utf8')
+    )
+    if (
+      Array.isArray(content.globs) &&
+      content.globs.length > 0 &&
+      content.globs.every((glob: unknown) => typeof glob === 'string')
+    ) {
+      return content.globs
+    }
+  } catch {}
+  return undefined
+}
+
+/**
+ * Persists the focus globs, or removes the focus set when `globs` is `false`.
+ */
+export async function writeFocus(distDir: string, globs: string[] | false) {
+  const focusFile = getFocusFilePath(distDir)
+  if (globs === false) {
+    await rm(focusFile, { force: true })
+    return
+  }
+  await mkdir(dirname(focusFile), { recursive: true })
+  await writeFile(focusFile, JSON.stringify({ globs }, null, 2))
+}
+
+/**
+ * Globs are matched against the route pathname (e.g. `/dashboard/**`) and
+ * against the directory the route lives in (e.g. `app/dashboard/**` or
+ * `pages/blog/**`).
+ */
+export function createFocusMatcher(
+  globs: string[]
+): (page: string, isApp: boolean) => boolean {
+  const isMatch = picomatch(globs, { dot: true })
+
+  return (page, isApp) => {
+    const pathname = isApp ? normalizeAppPath(page) : page
+    const candidates = [
+      pathname,
+      `${isApp ? 'app' : 'pages'}${page}`,
+      `${isApp ? 'app' : 'pages'}${pathname === '/' ? '' : pathname}`,
+    ]
+    return candidates.some((candidate) => isMatch(candidate))
+  }
+}
@@ -0,0 +1,84 @@
+import type { ImportCost } from '../../../build/swc/types'
+import {
+  encodeMessage,
+  handleMessage,
+  MessageReader,
+} from './import-cost-server'
+
+function createProject(costs: ImportCost[]) {
+  return {
+    getImportCost: jest.fn(async (_file: string, _specifier: string) => ({
+      result: costs,
+      issues: [],
+      diagnostics: [],
+    })),
+  }
+}
+
+describe('MessageReader', () => {
+  it('splits messages framed with Content-Length headers', () => {
+    const messages: string[] = []
+    const reader = new MessageReader((message) => messages.push(message))
+    const encoded = Buffer.concat([
+      encodeMessage({ id: 1 }),
+      encodeMessage({ id: 'ä' }),
+    ])
+    // Chunks can end in the middle of headers and of multi-byte characters
+    for (let i = 0; i < encoded.length; i += 7) {
+      reader.push(encoded.subarray(i, i + 7))
+    }
+    expect(messages).toEqual(['{"id":1}', '{"id":"ä"}'])
+  })
+
+  it('throws without a Content-Length header', () => {
+    const reader = new MessageReader(() => {})
+    expect(() => reader.push(Buffer.from('Foo: 1\r\n\r\n{}'))).toThrow()
+  })
+})
+
+describe('handleMessage', () => {
+  const cost = { layer: 'app-client', modules: 3, size: 1200, gzipSize: 400 }
+
+  it('responds with the cost of an import', async () => {
+    const project = createProject([cost])
+    const response = await handleMessage(
+      project,
+      '/app',
+      JSON.stringify({
+        jsonrpc: '2.0',
+        id: 1,
+        method: 'importCost',
+        params: { file: '/app/src/page.tsx', specifier: 'lodash' },
+      })
+    )
+    expect(response).toEqual({ jsonrpc: '2.0', id: 1, result: [cost] })
+    expect(project.getImportCost).toHaveBeenCalledWith('src/page.tsx', 'lodash')
+  })
+
+  it('reports invalid requests', async () => {
+    const project = createProject([])
+    const request = (message: object) =>
+      handleMessage(project, '/app', JSON.stringify(message))
+
+    expect(await handleMessage(project, '/app', '{')).toMatchObject({
+      id: null,
+      error: { code: -32700 },
+    })
+    expect(
+      await request({ jsonrpc: '2.0', id: 1, method: 'hover' })
+    ).toMatchObject({ id: 1, error: { code: -32601 } })
+    expect(
+      await request({
+        jsonrpc: '2.0',
+        id: 2,
+        method: 'importCost',
+        params: { file: 'page.tsx' },
+      })
+    ).toMatchObject({ id: 2, error: { code: -32602 } })
+    // Notifications aren't answered
+    expect(
+      await request({ jsonrpc: '2.0', method: 'importCost' })
+    ).toBeUndefined()
+    expect(project.getImportCost).not.toHaveBeenCalled()
+  })
+})
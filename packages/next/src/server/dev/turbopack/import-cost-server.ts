@@ -0,0 +1,222 @@
+import { createServer, type Socket } from 'net'
+import { writeFile } from 'fs/promises'
+import { isAbsolute, join, relative } from 'path'
+import type { Project } from '../../../build/swc/types'
+
+/**
+ * The file in the dist dir telling editors the port of the import cost
+ * server, e.g. `{ "port": 51234, "pid": 1234 }`.
+ */
+export const IMPORT_COST_SERVER_FILE = 'import-cost-server.json'
+
+/** Sent to the connected editors after each compilation. */
+export const IMPORT_COST_CHANGED_NOTIFICATION = 'importCost/didChange'
+
+// JSON-RPC error codes
+const PARSE_ERROR = -32700
+const INVALID_REQUEST = -32600
+const METHOD_NOT_FOUND = -32601
+const INVALID_PARAMS = -32602
+const INTERNAL_ERROR = -32603
+
+type JsonRpcId = number | string | null
+
+export type JsonRpcResponse =
+  | { jsonrpc: '2.0'; id: JsonRpcId; result: unknown }
+  | {
+      jsonrpc: '2.0'
+      id: JsonRpcId
+      error: { code: number; message: string }
+    }
+
+export type ImportCostServer = {
+  port: number
+  /** Tells the connected editors to request the import costs again. */
+  notifyChanged(): void
+  close(): void
+}
+
+/**
+ * Splits a stream into the JSON-RPC messages framed with `Content-Length`
+ * headers, like the Language Server Protocol does. Editor plugins can use
+ * the transports of `vscode-jsonrpc` to connect.
+ */
+export class MessageReader {
+  private buffer = Buffer.alloc(0)
+
+  constructor(private onMessage: (message: string) => void) {}
+
+  /** Throws when a message has no `Content-Length` header. */
+  push(chunk: Buffer) {
+    this.buffer = Buffer.concat([this.buffer, chunk])
+    while (true) {
+      const headersEnd = this.buffer.indexOf('\r\n\r\n')
+      if (headersEnd === -1) {
+        return
+      }
+      const headers = this.buffer.subarray(0, headersEnd).toString('ascii')
+      const contentLength = /^content-length: *(\d+) *$/im.exec(headers)
+      if (!contentLength) {
+        throw new Error('Missing Content-Length header')
+      }
+      const start = headersEnd + 4
+      const end = start + Number(contentLength[1])
+      if (this.buffer.length < end) {
+        return
+      }
+      const message = this.buffer.subarray(start, end).toString('utf8')
+      this.buffer = this.buffer.subarray(end)
+      this.onMessage(message)
+    }
+  }
+}
+
+export function encodeMessage(message: object): Buffer {
+  const content = Buffer.from(JSON.stringify(message), 'utf8')
+  return Buffer.concat([
+    Buffer.from(`Content-Length: ${content.length}\r\n\r\n`, 'ascii'),
+    content,
+  ])
+}
+
+function error(id: JsonRpcId, code: number, message: string): JsonRpcResponse {
+  return { jsonrpc: '2.0', id, error: { code, message } }
+}
+
+/**
+ * Handles a JSON-RPC message of an editor. Resolves to the response, or to
+ * `undefined` for notifications, which aren't answered.
+ *
+ * The `importCost` method takes the `file` containing an import, absolute or
+ * relative to the project directory, and the `specifier` of the import, and
+ * responds with the code it adds to each client layer of the app, see
+ * `Project.getImportCost`.
+ */
+export async function handleMessage(
+  project: Pick<Project, 'getImportCost'>,
+  dir: string,
+  message: string
+): Promise<JsonRpcResponse | undefined> {
+  let request
+  try {
+    request = JSON.parse(message)
+  } catch {
+    return error(null, PARSE_ERROR, 'Invalid JSON')
+  }
+  if (
+    typeof request !== 'object' ||
+    request === null ||
+    request.jsonrpc !== '2.0' ||
+    typeof request.method !== 'string'
+  ) {
+    return error(request?.id ?? null, INVALID_REQUEST, 'Invalid request')
+  }
+  if (request.id === undefined) {
+    return undefined
+  }
+  const { id, method, params } = request
+
+  if (method !== 'importCost') {
+    return error(id, METHOD_NOT_FOUND, `Unknown method ${method}`)
+  }
+  if (
+    typeof params?.file !== 'string' ||
+    typeof params?.specifier !== 'string'
+  ) {
+    return error(id, INVALID_PARAMS, 'Expected a file and a specifier')
+  }
+
+  const file = isAbsolute(params.file)
+    ? relative(dir, params.file)
+    : params.file
+  try {
+    const { result } = await project.getImportCost(
+      file.replaceAll('\\', '/'),
+      params.specifier
+    )
+    return { jsonrpc: '2.0', id, result }
+  } catch (err) {
+    return error(
+      id,
+      INTERNAL_ERROR,
+      err instanceof Error ? err.message : String(err)
+    )
+  }
+}
+
+/**
+ * Starts a server on `127.0.0.1` answering the import cost requests of
+ * editors from the live module graph. `port` 0 picks a free port. The port is
+ * written to `import-cost-server.json` in the dist dir.
+ */
+export async function startImportCostServer({
+  project,
+  dir,
+  distDir,
+  port,
+}: {
+  project: Project
+  dir: string
+  distDir: string
+  port: number
+}): Promise<ImportCostServer> {
+  const sockets = new Set<Socket>()
+
+  const server = createServer((socket) => {
+    sockets.add(socket)
+    socket.on('close', () => sockets.delete(socket))
+    // Editors reconnect when the dev server restarts
+    socket.on('error', () => socket.destroy())
+
+    const reader = new MessageReader((message) => {
+      handleMessage(project, dir, message).then((response) => {
+        if (response && !socket.destroyed) {
+          socket.write(encodeMessage(response))
+        }
+      })
+    })
+    socket.on('data', (chunk) => {
+      try {
+        reader.push(chunk)
+      } catch {
+        socket.destroy()
+      }
+    })
+  })
+  // Connected editors don't keep the dev server running
+  server.unref()
+
+  await new Promise<void>((resolve, reject) => {
+    server.once('error', reject)
+    server.listen(port, '127.0.0.1', () => {
+      server.off('error', reject)
+      resolve()
+    })
+  })
+  const address = server.address()
+  const listeningPort =
+    typeof address === 'object' && address ? address.port : port
+  await writeFile(
+    join(distDir, IMPORT_COST_SERVER_FILE),
+    JSON.stringify({ port: listeningPort, pid: process.pid })
+  )
+
+  return {
+    port: listeningPort,
+    notifyChanged() {
+      const notification = encodeMessage({
+        jsonrpc: '2.0',
+        method: IMPORT_COST_CHANGED_NOTIFICATION,
+      })
+      for (const socket of sockets) {
+        socket.write(notification)
+      }
+    },
+    close() {
+      for (const socket of sockets) {
+        socket.destroy()
+      }
+      server.close()
+    },
+  }
+}
@@ -385,6 +385,19 @@ export class TurbopackManifestLoader {
     for (const m of manifests) {
       Object.assign(manifest.pages, m.pages)
       if (m.rootMainFiles.length) manifest.rootMainFiles = m.rootMainFiles
+      if (m.inlinedRootMainFiles) {
+        manifest.inlinedRootMainFiles = {
+          ...manifest.inlinedRootMainFiles,
+          ...m.inlinedRootMainFiles,
+        }
+      }
+      if (m.resourceHints) {
+        manifest.resourceHints = {
+          ...manifest.resourceHints,
+          ...m.resourceHints,
+        }
+      }
+      if (m.esmChunks) manifest.esmChunks = true
       // polyfillFiles should always be the same, so we can overwrite instead of actually merging
       if (m.polyfillFiles.length) manifest.polyfillFiles = m.polyfillFiles
     }
@@ -532,6 +545,9 @@ export class TurbopackManifestLoader {
     for (const m of manifests) {
       Object.assign(manifest.app, m.app)
       Object.assign(manifest.pages, m.pages)
+      if (m.fonts) {
+        manifest.fonts = { ...manifest.fonts, ...m.fonts }
+      }
 
       manifest.appUsingSizeAdjust =
         manifest.appUsingSizeAdjust || m.appUsingSizeAdjust
@@ -0,0 +1,88 @@
+import type { Issue } from '../../../build/swc/types'
+import type { TurbopackGraph } from '../../../build/graph-export'
+import { DevSnapshotHistory } from './snapshots'
+
+function createGraph(paths: string[]) {
+  return {
+    modules: paths.map((path) => ({
+      path,
+      layer: null,
+      size: null,
+      source: null,
+    })),
+    entries: [0],
+    edges: [],
+  } satisfies TurbopackGraph
+}
+
+function createIssue(filePath: string, title: string): Issue {
+  return {
+    severity: 'error',
+    stage: 'parse',
+    filePath,
+    title: { type: 'text', value: title },
+    documentationLink: '',
+    subIssues: [],
+  }
+}
+
+describe('DevSnapshotHistory', () => {
+  it('skips snapshots without changes', () => {
+    const history = new DevSnapshotHistory(10)
+    const graph = createGraph(['[project]/app/page.tsx'])
+    expect(history.record(graph, [], 1)?.id).toBe(1)
+    expect(history.record(graph, [], 2)).toBeUndefined()
+    expect(history.list()).toEqual([{ id: 1, time: 1, modules: 1, issues: 0 }])
+  })
+
+  it('drops the oldest snapshots', () => {
+    const history = new DevSnapshotHistory(2)
+    history.record(createGraph(['[project]/a.ts']), [])
+    history.record(createGraph(['[project]/b.ts']), [])
+    history.record(createGraph(['[project]/c.ts']), [])
+    expect(history.list().map((snapshot) => snapshot.id)).toEqual([2, 3])
+    expect(history.diff(1)).toBeUndefined()
+  })
+
+  it('diffs modules and issues against an earlier snapshot', () => {
+    const history = new DevSnapshotHistory(10)
+    const fixed = createIssue('[project]/app/a.ts', 'Fixed')
+    const introduced = createIssue('[project]/app/b.ts', 'Introduced')
+    history.record(
+      createGraph(['[project]/app/page.tsx', '[project]/app/a.ts']),
+      [fixed]
+    )
+    history.record(createGraph(['[project]/app/page.tsx']), [fixed])
+    history.record(
+      createGraph(['[project]/app/page.tsx', '[project]/app/b.ts']),
+      [introduced]
+    )
+
+    expect(history.diffAgo(2)).toEqual({
+      from: 1,
+      to: 3,
+      modules: {
+        added: ['[project]/app/b.ts'],
+        removed: ['[project]/app/a.ts'],
+      },
+      issues: {
+        introduced: [
+          {
+            severity: 'error',
+            stage: 'parse',
+            filePath: '[project]/app/b.ts',
+            title: 'Introduced',
+          },
+        ],
+        resolved: [
+          {
+            severity: 'error',
+            stage: 'parse',
+            filePath: '[project]/app/a.ts',
+            title: 'Fixed',
+          },
+        ],
+      },
+    })
+  })
+})
@@ -0,0 +1,180 @@
+import type { Issue, Project } from '../../../build/swc/types'
+import type { TurbopackGraph } from '../../../build/graph-export'
+import { getIssueFingerprint } from '../../../build/turbopack-suppressions'
+import { renderStyledStringToErrorAnsi } from '../turbopack-utils'
+import stripAnsi from 'next/dist/compiled/strip-ansi'
+
+export interface SnapshotIssue {
+  severity: string
+  stage: string
+  filePath: string
+  title: string
+}
+
+export interface DevSnapshot {
+  /** Increases with each snapshot taken during the session, starting at 1. */
+  id: number
+  /** When the snapshot was taken, in milliseconds since the epoch. */
+  time: number
+  /** The paths of the modules of the app, e.g. `[project]/app/page.tsx` */
+  modules: Set<string>
+  /** The issues of the app, keyed by their fingerprint. */
+  issues: Map<string, SnapshotIssue>
+}
+
+export interface DevSnapshotSummary {
+  id: number
+  time: number
+  modules: number
+  issues: number
+}
+
+export interface DevSnapshotDiff {
+  /** The id of the earlier snapshot. */
+  from: number
+  /** The id of the latest snapshot. */
+  to: number
+  modules: {
+    added: string[]
+    removed: string[]
+  }
+  issues: {
+    introduced: SnapshotIssue[]
+    resolved: SnapshotIssue[]
+  }
+}
+
+/**
+ * A bounded history of the module graph and the issues of the app, taken
+ * after each compilation of `next dev`, to find out which edit broke the app
+ * after a burst of changes. Compilations which change neither the modules nor
+ * the issues don't take a snapshot.
+ */
+export class DevSnapshotHistory {
+  private readonly snapshots: DevSnapshot[] = []
+  private nextId = 1
+
+  constructor(private readonly capacity: number) {}
+
+  /**
+   * Records a snapshot, dropping the oldest one when the history is full.
+   * Returns `undefined` when nothing changed since the latest snapshot.
+   */
+  record(
+    graph: TurbopackGraph,
+    issues: Iterable<Issue>,
+    now = Date.now()
+  ): DevSnapshot | undefined {
+    const modules = new Set(graph.modules.map((module) => module.path))
+    const snapshotIssues = new Map<string, SnapshotIssue>()
+    for (const issue of issues) {
+      snapshotIssues.set(getIssueFingerprint(issue), {
+        severity: issue.severity,
+        stage: issue.stage,
+        filePath: issue.filePath,
+        title: stripAnsi(renderStyledStringToErrorAnsi(issue.title)),
+      })
+    }
+
+    const latest = this.latest()
+    if (
+      latest &&
+      haveSameKeys(latest.modules, modules) &&
+      haveSameKeys(latest.issues, snapshotIssues)
+    ) {
+      return undefined
+    }
+
+    const snapshot: DevSnapshot = {
+      id: this.nextId++,
+      time: now,
+      modules,
+      issues: snapshotIssues,
+    }
+    this.snapshots.push(snapshot)
+    if (this.snapshots.length > this.capacity) {
+      this.snapshots.shift()
+    }
+    return snapshot
+  }
+
+  latest(): DevSnapshot | undefined {
+    return this.snapshots[this.snapshots.length - 1]
+  }
+
+  list(): DevSnapshotSummary[] {
+    return this.snapshots.map((snapshot) => ({
+      id: snapshot.id,
+      time: snapshot.time,
+      modules: snapshot.modules.size,
+      issues: snapshot.issues.size,
+    }))
+  }
+
+  /**
+   * Diffs the latest snapshot against the one with the given id. Returns
+   * `undefined` when the snapshot was dropped from the history already.
+   */
+  diff(id: number): DevSnapshotDiff | undefined {
+    const from = this.snapshots.find((snapshot) => snapshot.id === id)
+    const to = this.latest()
+    if (!from || !to) {
+      return undefined
+    }
+    return {
+      from: from.id,
+      to: to.id,
+      modules: {
+        added: [...to.modules].filter((path) => !from.modules.has(path)),
+        removed: [...from.modules].filter((path) => !to.modules.has(path)),
+      },
+      issues: {
+        introduced: [...to.issues]
+          .filter(([fingerprint]) => !from.issues.has(fingerprint))
+          .map(([, issue]) => issue),
+        resolved: [...from.issues]
+          .filter(([fingerprint]) => !to.issues.has(fingerprint))
+          .map(([, issue]) => issue),
+      },
+    }
+  }
+
+  /**
+   * Diffs the latest snapshot against the one taken `count` snapshots
+   * earlier.
+   */
+  diffAgo(count: number): DevSnapshotDiff | undefined {
+    const to = this.latest()
+    return to ? this.diff(to.id - count) : undefined
+  }
+}
+
+function haveSameKeys<K>(
+  a: { size: number; has(key: K): boolean; keys(): Iterable<K> },
+  b: { size: number; has(key: K): boolean }
+): boolean {
+  if (a.size !== b.size) {
+    return false
+  }
+  for (const key of a.keys()) {
+    if (!b.has(key)) {
+      return false
+    }
+  }
+  return true
+}
+
+/**
+ * Takes a snapshot of the module graph of the whole app and the issues of
+ * its compilation, together with `issues` reported for the compiled
+ * entrypoints.
+ */
+export async function takeDevSnapshot(
+  project: Project,
+  history: DevSnapshotHistory,
+  issues: Iterable<Issue>
+): Promise<DevSnapshot | undefined> {
+  const result = await project.exportGraph(false)
+  const graph: TurbopackGraph = JSON.parse(result.graph)
+  return history.record(graph, [...issues, ...result.issues])
+}
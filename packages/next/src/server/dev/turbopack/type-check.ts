@@ -0,0 +1,119 @@
+import { readFile } from 'fs/promises'
+import { relative } from 'path'
+import type { Issue } from '../../../build/swc/types'
+import type {
+  TypeCheckDiagnostic,
+  typeCheck,
+} from '../../../lib/typescript/type-check-worker'
+import { Worker } from '../../../lib/worker'
+
+export type TypeChecker = {
+  /**
+   * Checks the types of the changed projects and resolves to the issues of
+   * all projects. Calls made while a check is running are coalesced into a
+   * single check which starts once the running one is done.
+   */
+  check(): Promise<Issue[]>
+  close(): void
+}
+
+/**
+ * Converts a diagnostic of the type checker into an issue, so that it is
+ * displayed like the issues reported by Turbopack.
+ */
+export async function typeCheckDiagnosticToIssue(
+  dir: string,
+  diagnostic: TypeCheckDiagnostic,
+  readSource: (filePath: string) => Promise<string | undefined>
+): Promise<Issue> {
+  const { filePath, range } = diagnostic
+  let source: Issue['source']
+  if (filePath && range) {
+    source = {
+      source: {
+        ident: filePath,
+        content: await readSource(filePath),
+      },
+      range,
+    }
+  }
+  return {
+    severity: diagnostic.category,
+    stage: 'type check',
+    filePath: filePath
+      ? `[project]/${relative(dir, filePath).replaceAll('\\', '/')}`
+      : '',
+    title: {
+      type: 'text',
+      value: `Type ${diagnostic.category}: ${diagnostic.message}`,
+    },
+    description: { type: 'text', value: `TS${diagnostic.code}` },
+    source,
+    documentationLink: '',
+    subIssues: [],
+  }
+}
+
+/**
+ * Runs the type checker in a persistent worker, so that each check only
+ * checks the projects whose files changed since the previous check.
+ */
+export function createTypeChecker({
+  dir,
+  tsconfigPath,
+}: {
+  dir: string
+  tsconfigPath: string
+}): TypeChecker {
+  const worker = new Worker(
+    require.resolve('../../../lib/typescript/type-check-worker'),
+    {
+      exposedMethods: ['typeCheck'],
+      numWorkers: 1,
+      maxRetries: 0,
+    }
+  ) as Worker & { typeCheck: typeof typeCheck }
+
+  async function run(): Promise<Issue[]> {
+    const { diagnostics } = await worker.typeCheck({ dir, tsconfigPath })
+    const sources = new Map<string, Promise<string | undefined>>()
+    const readSource = (filePath: string) => {
+      let content = sources.get(filePath)
+      if (!content) {
+        content = readFile(filePath, 'utf8').catch(() => undefined)
+        sources.set(filePath, content)
+      }
+      return content
+    }
+    return Promise.all(
+      diagnostics.map((diagnostic) =>
+        typeCheckDiagnosticToIssue(dir, diagnostic, readSource)
+      )
+    )
+  }
+
+  let running: Promise<Issue[]> | undefined
+  let queued: Promise<Issue[]> | undefined
+
+  const typeChecker: TypeChecker = {
+    check() {
+      if (!running) {
+        running = run().finally(() => {
+          running = undefined
+        })
+        return running
+      }
+      queued ??= running
+        .catch(() => {})
+        .then(() => {
+          queued = undefined
+          return typeChecker.check()
+        })
+      return queued
+    },
+    close() {
+      worker.close()
+    },
+  }
+  return typeChecker
+}
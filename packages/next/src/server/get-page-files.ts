@@ -11,6 +11,22 @@ export type BuildManifest = {
   // different root main files per entries/build (ideally temporary)
   // until we can stitch the runtime chunks together safely
   rootMainFilesTree: { [appRoute: string]: readonly string[] }
+  // the content of the root main files inlined into the HTML of an app page,
+  // keyed by the page and the file
+  inlinedRootMainFiles?: {
+    [appPage: string]: { [file: string]: string }
+  }
+  // the chunks of dynamic imports with a `webpackPrefetch` or `webpackPreload`
+  // comment, keyed by the page
+  resourceHints?: {
+    [page: string]: {
+      prefetch: readonly string[]
+      preload: readonly string[]
+    }
+  }
+  // whether the client chunks are ES modules, which are loaded with
+  // `<script type="module">`
+  esmChunks?: boolean
   pages: {
     '/_app': readonly string[]
     [page: string]: readonly string[]
@@ -19,6 +19,7 @@ import { setupFsCheck } from './router-utils/filesystem'
 import { proxyRequest } from './router-utils/proxy-request'
 import { isAbortError, pipeToNodeResponse } from '../pipe-readable'
 import { getResolveRoutes } from './router-utils/resolve-routes'
+import { createDevMockHandler } from './router-utils/dev-mocks'
 import { addRequestMeta, getRequestMeta } from '../request-meta'
 import { pathHasPrefix } from '../../shared/lib/router/utils/path-has-prefix'
 import { removePathPrefix } from '../../shared/lib/router/utils/remove-path-prefix'
@@ -49,6 +50,7 @@ import { normalizedAssetPrefix } from '../../shared/lib/normalized-asset-prefix'
 import { NEXT_PATCH_SYMBOL } from './patch-fetch'
 import type { ServerInitResult } from './render-server'
 import { filterInternalHeaders } from './server-ipc/utils'
+import { measureServerTiming, withServerTiming } from '../dev/server-timing'
 
 const debug = setupDebug('next:router-server:main')
 const isNextFont = (pathname: string | null) =>
@@ -165,6 +167,11 @@ export async function initialize(opts: {
   renderServer.instance =
     require('./render-server') as typeof import('./render-server')
 
+  const devMockHandler =
+    opts.dev && config.experimental.devMocks
+      ? createDevMockHandler(opts.dir, config.experimental.devMocks)
+      : undefined
+
   const requestHandlerImpl: WorkerRequestHandler = async (req, res) => {
     // internal headers should not be honored by the request handler
     if (!process.env.NEXT_PRIVATE_TEST_HEADERS) {
@@ -288,7 +295,9 @@ export async function initialize(opts: {
         const initResult =
           await renderServer?.instance?.initialize(renderServerOpts)
         try {
-          await initResult?.requestHandler(req, res)
+          await measureServerTiming('render', async () =>
+            initResult?.requestHandler(req, res)
+          )
         } catch (err) {
           if (err instanceof NoFallbackError) {
             // eslint-disable-next-line
@@ -335,6 +344,16 @@ export async function initialize(opts: {
         req.url = origUrl
       }
 
+      if (devMockHandler) {
+        let { pathname } = url.parse(req.url || '/')
+        if (config.basePath) {
+          pathname = removePathPrefix(pathname || '/', config.basePath)
+        }
+        if (await devMockHandler(req, res, pathname || '/')) {
+          return
+        }
+      }
+
       const {
         finished,
         parsedUrl,
@@ -605,6 +624,11 @@ export async function initialize(opts: {
   }
 
   let requestHandler: WorkerRequestHandler = requestHandlerImpl
+  if (opts.dev && process.env.TURBOPACK) {
+    // Break down the time spent on each request for the browser devtools
+    requestHandler = (req, res) =>
+      withServerTiming(res, () => requestHandlerImpl(req, res))
+  }
   if (config.experimental.testProxy) {
     // Intercept fetch and other testmode apis.
     const { wrapRequestHandlerWorker, interceptTestApis } =
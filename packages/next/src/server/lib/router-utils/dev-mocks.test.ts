@@ -0,0 +1,61 @@
+/* eslint-env jest */
+
+import fs from 'fs'
+import os from 'os'
+import path from 'path'
+import { createDevMockHandler } from './dev-mocks'
+
+function createResponse() {
+  const res = {
+    statusCode: 0,
+    headers: {} as Record<string, string>,
+    body: undefined as string | undefined,
+    setHeader(name: string, value: string) {
+      res.headers[name.toLowerCase()] = value
+    },
+    end(body?: Buffer | string) {
+      res.body = body?.toString()
+    },
+  }
+  return res
+}
+
+describe('createDevMockHandler', () => {
+  let dir: string
+
+  beforeAll(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'next-dev-mocks-'))
+    fs.writeFileSync(path.join(dir, 'users.json'), '[{"id":1}]')
+    fs.writeFileSync(
+      path.join(dir, 'user.js'),
+      'module.exports = (req, res, params) => { res.end(params.id) }'
+    )
+  })
+
+  afterAll(() => {
+    fs.rmSync(dir, { recursive: true, force: true })
+  })
+
+  it('should serve fixture files', async () => {
+    const handler = createDevMockHandler(dir, { '/api/users': 'users.json' })
+    const res = createResponse()
+    expect(await handler({} as any, res as any, '/api/users')).toBe(true)
+    expect(res.statusCode).toBe(200)
+    expect(res.headers['content-type']).toBe('application/json')
+    expect(res.body).toBe('[{"id":1}]')
+  })
+
+  it('should call resolvers with the matched params', async () => {
+    const handler = createDevMockHandler(dir, { '/api/users/:id': 'user.js' })
+    const res = createResponse()
+    expect(await handler({} as any, res as any, '/api/users/42')).toBe(true)
+    expect(res.body).toBe('42')
+  })
+
+  it('should not handle other paths', async () => {
+    const handler = createDevMockHandler(dir, { '/api/users': 'users.json' })
+    const res = createResponse()
+    expect(await handler({} as any, res as any, '/api/posts')).toBe(false)
+    expect(res.body).toBeUndefined()
+  })
+})
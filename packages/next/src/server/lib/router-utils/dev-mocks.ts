@@ -0,0 +1,79 @@
+import type { IncomingMessage, ServerResponse } from 'http'
+import fs from 'fs/promises'
+import path from 'path'
+import { getPathMatch } from '../../../shared/lib/router/utils/path-match'
+import { getContentType } from '../../serve-static'
+
+/**
+ * A mock resolver exported by a file configured in `experimental.devMocks`.
+ */
+export type DevMockResolver = (
+  req: IncomingMessage,
+  res: ServerResponse,
+  params: Record<string, string | string[]>
+) => void | Promise<void>
+
+/**
+ * Responds to a request with its configured mock, returning whether one
+ * matched the pathname.
+ */
+export type DevMockHandler = (
+  req: IncomingMessage,
+  res: ServerResponse,
+  pathname: string
+) => Promise<boolean>
+
+const RESOLVER_EXTENSIONS = ['.js', '.cjs']
+
+/**
+ * Serves the paths configured in `experimental.devMocks` from fixture files or
+ * mock resolvers instead of the app, e.g. for frontend development without
+ * access to the backends the API routes call. Paths use the same syntax as
+ * `rewrites`, e.g. `/api/users/:id`.
+ */
+export function createDevMockHandler(
+  dir: string,
+  mocks: Record<string, string>
+): DevMockHandler {
+  const matchers = Object.entries(mocks).map(([source, file]) => ({
+    match: getPathMatch(source),
+    file: path.resolve(dir, file),
+  }))
+
+  return async (req, res, pathname) => {
+    for (const { match, file } of matchers) {
+      const params = match(pathname)
+      if (!params) {
+        continue
+      }
+
+      if (RESOLVER_EXTENSIONS.includes(path.extname(file))) {
+        // Load the resolver again for every request so edits apply without a
+        // restart.
+        delete require.cache[require.resolve(file)]
+        const mod = require(file)
+        const resolver: DevMockResolver = mod.default ?? mod
+        if (typeof resolver !== 'function') {
+          throw new Error(
+            `The mock for \`${pathname}\` at ${file} must export a function as default export`
+          )
+        }
+        await resolver(req, res, params)
+        return true
+      }
+
+      const content = await fs.readFile(file)
+      res.statusCode = 200
+      res.setHeader(
+        'Content-Type',
+        getContentType(path.extname(file).slice(1)) ??
+          'application/octet-stream'
+      )
+      res.setHeader('Cache-Control', 'no-store, must-revalidate')
+      res.end(content)
+      return true
+    }
+
+    return false
+  }
+}
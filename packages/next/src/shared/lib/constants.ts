@@ -32,6 +32,7 @@ export const PAGES_MANIFEST = 'pages-manifest.json'
 export const WEBPACK_STATS = 'webpack-stats.json'
 export const APP_PATHS_MANIFEST = 'app-paths-manifest.json'
 export const APP_PATH_ROUTES_MANIFEST = 'app-path-routes-manifest.json'
+export const STATIC_PARAMS_MANIFEST = 'static-params-manifest.json'
 export const BUILD_MANIFEST = 'build-manifest.json'
 export const APP_BUILD_MANIFEST = 'app-build-manifest.json'
 export const FUNCTIONS_CONFIG_MANIFEST = 'functions-config-manifest.json'
@@ -121,6 +121,32 @@ export async function capsize_metrics() {
   await writeJson(outputPathDist, entireMetricsCollection, { spaces: 2 })
 }
 
+// Refreshes the metrics of the default fallback fonts baked into next-core
+// from the capsize metrics. `azAvgWidth` is kept, as it's measured with the
+// same sample of characters Turbopack uses for the main font.
+export async function capsize_fallback_metrics() {
+  const {
+    entireMetricsCollection,
+    // eslint-disable-next-line import/no-extraneous-dependencies
+  } = require('@capsizecss/metrics/entireMetricsCollection')
+  const metricsPath = join(
+    __dirname,
+    '../../crates/next-core/src/next_font/fallback-font-metrics.json'
+  )
+
+  const fallbackFonts = await fs.readFile(metricsPath, 'utf8').then(JSON.parse)
+  for (const [name, entry] of Object.entries(fallbackFonts)) {
+    const metrics = entireMetricsCollection[entry.capsizeKey]
+    if (!metrics) {
+      throw new Error(`Missing capsize metrics for fallback font ${name}`)
+    }
+    entry.category = metrics.category
+    entry.unitsPerEm = metrics.unitsPerEm
+  }
+
+  await writeJson(metricsPath, fallbackFonts, { spaces: 2 })
+}
+
 // eslint-disable-next-line camelcase
 externals['@babel/runtime'] = 'next/dist/compiled/@babel/runtime'
 export async function copy_babel_runtime(task, opts) {
@@ -12,7 +12,7 @@ const fetch = require('node-fetch')
   let fontFunctions = `/**
   * This is an autogenerated file by scripts/update-google-fonts.js
   */
-  import type { CssVariable, NextFont, NextFontWithVariable, Display } from '../types'
+  import type { CssVariable, NextFont, NextFontWithVariable, Display, FallbackMetrics } from '../types'
   `
   const fontData = {}
   const ignoredSubsets = [
@@ -81,6 +81,7 @@ const fetch = require('node-fetch')
     ${hasPreloadableSubsets ? 'preload?:boolean' : ''}
     fallback?: string[]
     adjustFontFallback?: boolean
+    fallbackMetrics?: FallbackMetrics
     ${hasPreloadableSubsets ? `subsets?: Array<${formatUnion(subsets)}>` : ''}
     ${
       optionalAxes
@@ -0,0 +1,10 @@
+export default function Root({ children }) {
+  return (
+    <html>
+      <head>
+        <title>Hello</title>
+      </head>
+      <body>{children}</body>
+    </html>
+  )
+}
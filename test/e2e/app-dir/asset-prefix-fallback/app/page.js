@@ -0,0 +1,18 @@
+'use client'
+
+import { useState } from 'react'
+
+export default function Page() {
+  const [text, setText] = useState(null)
+  return (
+    <>
+      <button
+        id="load"
+        onClick={() => import('../lazy').then((mod) => setText(mod.text))}
+      >
+        Load
+      </button>
+      {text && <p id="lazy">{text}</p>}
+    </>
+  )
+}
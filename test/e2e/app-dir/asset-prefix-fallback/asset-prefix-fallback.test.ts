@@ -0,0 +1,62 @@
+import { nextTestSetup } from 'e2e-utils'
+import type { Page, Route } from 'playwright'
+
+// The fallback is implemented by the Turbopack runtime
+;(process.env.TURBOPACK ? describe : describe.skip)(
+  'app-dir assetPrefix fallback',
+  () => {
+    const { next, isNextDev } = nextTestSetup({
+      files: __dirname,
+    })
+
+    async function loadLazyFromFallback() {
+      let blockAssetPrefix = false
+      const fallbackRequests: string[] = []
+      const browser = await next.browser('/', {
+        beforePageLoad(page: Page) {
+          page.route('**/_next/static/chunks/**', (route: Route) => {
+            const { pathname } = new URL(route.request().url())
+            if (!pathname.startsWith('/cdn/')) {
+              fallbackRequests.push(pathname)
+            } else if (blockAssetPrefix) {
+              return route.abort()
+            }
+            return route.continue()
+          })
+        },
+      })
+
+      // Chunks loaded from here on fail like an unreachable CDN
+      blockAssetPrefix = true
+      await browser.elementByCss('#load').click()
+
+      expect(await browser.waitForElementByCss('#lazy').text()).toBe(
+        'lazy loaded'
+      )
+      return { browser, fallbackRequests }
+    }
+
+    it('should load chunks from the same origin when the assetPrefix fails', async () => {
+      const { fallbackRequests } = await loadLazyFromFallback()
+      expect(fallbackRequests.length).toBeGreaterThan(0)
+    })
+
+    // The integrity manifest is only emitted by production builds
+    if (!isNextDev) {
+      it('should verify the retried chunks with the integrity manifest', async () => {
+        const { browser, fallbackRequests } = await loadLazyFromFallback()
+        expect(fallbackRequests).toContain(
+          '/_next/static/chunks/integrity-manifest.json'
+        )
+
+        const integrities: string[] = await browser.eval(
+          `Array.from(document.querySelectorAll('script[src^="/_next/static/chunks/"]'), (script) => script.integrity)`
+        )
+        expect(integrities.length).toBeGreaterThan(0)
+        for (const integrity of integrities) {
+          expect(integrity).toMatch(/^sha256-/)
+        }
+      })
+    }
+  }
+)
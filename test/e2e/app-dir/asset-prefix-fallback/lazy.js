@@ -0,0 +1 @@
+export const text = 'lazy loaded'
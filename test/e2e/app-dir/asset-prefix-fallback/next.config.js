@@ -0,0 +1,18 @@
+module.exports = {
+  assetPrefix: '/cdn',
+  experimental: {
+    turbo: {
+      assetPrefixFallback: true,
+    },
+  },
+  async rewrites() {
+    return {
+      beforeFiles: [
+        {
+          source: '/cdn/:path*',
+          destination: '/:path*',
+        },
+      ],
+    }
+  },
+}
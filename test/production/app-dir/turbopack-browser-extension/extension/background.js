@@ -0,0 +1,3 @@
+import('./lazy-background').then(({ message }) => {
+  self.lazyMessage = message
+})
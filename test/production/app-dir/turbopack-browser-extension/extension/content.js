@@ -0,0 +1,3 @@
+import('./lazy-content').then(({ message }) => {
+  document.body.dataset.lazyMessage = message
+})
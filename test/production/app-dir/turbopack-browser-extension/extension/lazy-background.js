@@ -0,0 +1 @@
+export const message = 'lazy background module'
@@ -0,0 +1,10 @@
+/** @type {import('next').NextConfig} */
+module.exports = {
+  experimental: {
+    turbo: {
+      browserExtension: {
+        manifest: './extension/manifest.json',
+      },
+    },
+  },
+}
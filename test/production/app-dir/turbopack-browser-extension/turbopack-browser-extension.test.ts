@@ -0,0 +1,48 @@
+import { nextTestSetup } from 'e2e-utils'
+
+;(process.env.TURBOPACK ? describe : describe.skip)(
+  'turbopack-browser-extension',
+  () => {
+    const { next } = nextTestSetup({
+      files: __dirname,
+      skipDeployment: true,
+    })
+
+    async function readExtensionFiles(files: string[]) {
+      const contents = await Promise.all(
+        files.map((file) =>
+          next.readFile(`.next/extension/${file.replace(/^\//, '')}`)
+        )
+      )
+      return contents.join('\n')
+    }
+
+    it('should bundle the dynamic imports of the service worker', async () => {
+      const manifest = await next.readJSON('.next/extension/manifest.json')
+      expect(manifest.background.service_worker).toBe('background.js')
+
+      const serviceWorker = await next.readFile(
+        '.next/extension/background.js'
+      )
+      const files = JSON.parse(
+        `[${serviceWorker.match(/^importScripts\((.*)\);$/m)![1]}]`
+      )
+      // The chunks can't be loaded on demand without a DOM, so the module
+      // imported dynamically is part of the imported chunks.
+      expect(await readExtensionFiles(files)).toContain(
+        'lazy background module'
+      )
+    })
+
+    it('should bundle the dynamic imports of content scripts', async () => {
+      const manifest = await next.readJSON('.next/extension/manifest.json')
+      const [contentScript] = manifest.content_scripts
+      expect(contentScript.js.length).toBeGreaterThan(0)
+      expect(contentScript.js).not.toContain('content.js')
+
+      expect(await readExtensionFiles(contentScript.js)).toContain(
+        'lazy content module'
+      )
+    })
+  }
+)
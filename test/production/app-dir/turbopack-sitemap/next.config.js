@@ -0,0 +1,9 @@
+/** @type {import('next').NextConfig} */
+module.exports = {
+  experimental: {
+    sitemap: {
+      baseUrl: 'https://example.com',
+      exclude: ['/private'],
+    },
+  },
+}
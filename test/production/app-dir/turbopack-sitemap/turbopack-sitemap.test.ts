@@ -0,0 +1,32 @@
+import { nextTestSetup } from 'e2e-utils'
+
+;(process.env.TURBOPACK ? describe : describe.skip)('turbopack-sitemap', () => {
+  const { next } = nextTestSetup({
+    files: __dirname,
+    skipDeployment: true,
+  })
+
+  it('should serve the sitemap of the static routes', async () => {
+    const res = await next.fetch('/sitemap.xml')
+    expect(res.status).toBe(200)
+
+    const sitemap = await res.text()
+    expect(sitemap).toContain('<loc>https://example.com/</loc>')
+    expect(sitemap).toContain('<loc>https://example.com/about</loc>')
+    expect(sitemap).not.toContain('/private')
+  })
+
+  it('should serve robots.txt pointing at the sitemap', async () => {
+    const res = await next.fetch('/robots.txt')
+    expect(res.status).toBe(200)
+
+    const robots = await res.text()
+    expect(robots).toContain('Disallow: /private')
+    expect(robots).toContain('Sitemap: https://example.com/sitemap.xml')
+
+    const sitemap = await next.fetch(
+      new URL(robots.match(/^Sitemap: (.+)$/m)![1]).pathname
+    )
+    expect(sitemap.status).toBe(200)
+  })
+})
@@ -0,0 +1,9 @@
+import './global.css'
+
+export default function Root({ children }) {
+  return (
+    <html>
+      <body>{children}</body>
+    </html>
+  )
+}
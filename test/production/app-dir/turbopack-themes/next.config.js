@@ -0,0 +1,15 @@
+/** @type {import('next').NextConfig} */
+module.exports = {
+  experimental: {
+    turbo: {
+      resolveAlias: {
+        '@/theme/tokens.css': './themes/default/tokens.css',
+      },
+      themes: {
+        acme: {
+          '@/theme/tokens.css': './themes/acme/tokens.css',
+        },
+      },
+    },
+  },
+}
@@ -0,0 +1,39 @@
+import { nextTestSetup } from 'e2e-utils'
+
+;(process.env.TURBOPACK ? describe : describe.skip)('turbopack-themes', () => {
+  const { next } = nextTestSetup({
+    files: __dirname,
+    skipDeployment: true,
+  })
+
+  async function readChunks(chunks: string[]) {
+    const contents = await Promise.all(
+      chunks.map((chunk) => next.readFile(`.next/${chunk}`))
+    )
+    return contents.join('\n')
+  }
+
+  it('should build the CSS of each route with the aliases of the theme', async () => {
+    const manifest = await next.readJSON('.next/server/theme-manifest.json')
+    const routes: Record<string, string[]> = manifest.themes.acme
+    const chunks = Object.values(routes).flat()
+    expect(chunks.length).toBeGreaterThan(0)
+
+    const css = await readChunks(chunks)
+    expect(css).toContain('#654321')
+    expect(css).not.toContain('#123456')
+  })
+
+  it('should keep the default theme in the CSS of the app', async () => {
+    const $ = await next.render$('/')
+    const stylesheets = $('link[rel="stylesheet"]')
+      .map((_, link) => $(link).attr('href'))
+      .get()
+    expect(stylesheets.length).toBeGreaterThan(0)
+
+    const css = await Promise.all(
+      stylesheets.map(async (href) => (await next.fetch(href)).text())
+    )
+    expect(css.join('\n')).toContain('#123456')
+  })
+})
@@ -1,6 +1,7 @@
 pub mod indexed;
 mod operation;
 mod persisted_storage_log;
+mod persistent_kv_log;
 mod storage;
 
 use std::{
@@ -30,13 +31,16 @@ use turbo_tasks::{
         TransientTaskType, TypedCellContent,
     },
     event::{Event, EventListener},
+    persistent_kv::PersistentKvStorage,
     registry,
     util::IdFactoryWithReuse,
     CellId, FunctionId, RawVc, ReadConsistency, SessionId, TaskId, TraitTypeId,
     TurboTasksBackendApi, ValueTypeId, TRANSIENT_TASK_BIT,
 };
 
-pub use self::{operation::AnyOperation, storage::TaskDataCategory};
+pub use self::{
+    operation::AnyOperation, persistent_kv_log::PersistentKvUpdate, storage::TaskDataCategory,
+};
 use crate::{
     backend::{
         operation::{
@@ -45,6 +49,7 @@ use crate::{
             ExecuteContext, ExecuteContextImpl, Operation, OutdatedEdge, TaskDirtyCause, TaskGuard,
         },
         persisted_storage_log::PersistedStorageLog,
+        persistent_kv_log::PersistentKvLog,
         storage::{get, get_many, get_mut, iter_many, remove, Storage},
     },
     backing_storage::BackingStorage,
@@ -152,6 +157,7 @@ struct TurboTasksBackendInner<B: BackingStorage> {
     persisted_storage_data_log: Option<PersistedStorageLog>,
     persisted_storage_meta_log: Option<PersistedStorageLog>,
     storage: Storage<TaskId, CachedDataItem>,
+    persistent_kv_log: PersistentKvLog,
 
     /// Number of executing operations + Highest bit is set when snapshot is
     /// requested. When that bit is set, operations should pause until the
@@ -211,6 +217,7 @@ impl<B: BackingStorage> TurboTasksBackendInner<B> {
             persisted_storage_data_log: need_log.then(|| PersistedStorageLog::new(shard_amount)),
             persisted_storage_meta_log: need_log.then(|| PersistedStorageLog::new(shard_amount)),
             storage: Storage::new(),
+            persistent_kv_log: PersistentKvLog::default(),
             in_progress_operations: AtomicUsize::new(0),
             snapshot_request: Mutex::new(SnapshotRequest::new()),
             operations_suspended: Condvar::new(),
@@ -704,6 +711,7 @@ impl<B: BackingStorage> TurboTasksBackendInner<B> {
             .as_ref()
             .map(|l| l.take(|i| i))
             .unwrap_or_default();
+        let persistent_kv_updates = self.persistent_kv_log.take();
         let mut snapshot_request = self.snapshot_request.lock();
         snapshot_request.snapshot_requested = false;
         self.in_progress_operations
@@ -734,15 +742,19 @@ impl<B: BackingStorage> TurboTasksBackendInner<B> {
         if !shards_empty(&persisted_task_cache_log)
             || !shards_empty(&persisted_storage_meta_log)
             || !shards_empty(&persisted_storage_data_log)
+            || !persistent_kv_updates.is_empty()
         {
             new_items = true;
-            if let Err(err) = self.backing_storage.save_snapshot(
+            let result = self.backing_storage.save_snapshot(
                 self.session_id,
                 suspended_operations,
                 persisted_task_cache_log,
                 persisted_storage_meta_log,
                 persisted_storage_data_log,
-            ) {
+                persistent_kv_updates,
+            );
+            self.persistent_kv_log.persisted();
+            if let Err(err) = result {
                 println!("Persisting failed: {:?}", err);
                 return None;
             }
@@ -2052,6 +2064,35 @@ impl<B: BackingStorage> Backend for TurboTasksBackend<B> {
     fn dispose_root_task(&self, task_id: TaskId, turbo_tasks: &dyn TurboTasksBackendApi<Self>) {
         self.0.dispose_root_task(task_id, turbo_tasks);
     }
+
+    fn persistent_kv(&self) -> Option<&dyn PersistentKvStorage> {
+        if !self.0.should_persist() {
+            return None;
+        }
+        Some(&*self.0)
+    }
+}
+
+impl<B: BackingStorage> PersistentKvStorage for TurboTasksBackendInner<B> {
+    fn get(&self, namespace: &str, version: u32, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        self.persistent_kv_log.get(namespace, version, key, |key| {
+            self.backing_storage.lookup_persistent_kv(key)
+        })
+    }
+
+    fn set(
+        &self,
+        namespace: &str,
+        version: u32,
+        key: &[u8],
+        value: Option<&[u8]>,
+        quota: u64,
+    ) -> Result<bool> {
+        self.persistent_kv_log
+            .set(namespace, version, key, value, quota, |key| {
+                self.backing_storage.lookup_persistent_kv(key)
+            })
+    }
 }
 
 // from https://github.com/tokio-rs/tokio/blob/29cd6ec1ec6f90a7ee1ad641c03e0e00badbcb0e/tokio/src/time/instant.rs#L57-L63
@@ -0,0 +1,242 @@
+use anyhow::{bail, Result};
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+
+/// An update of a persistent key-value entry, `None` removes the entry.
+pub type PersistentKvUpdate = (Vec<u8>, Option<Vec<u8>>);
+
+/// The writes to [`PersistentKv`][turbo_tasks::persistent_kv::PersistentKv] namespaces which are
+/// not persisted yet, and the used bytes of each namespace.
+///
+/// Entries are stored under `namespace\0key` with the version of the namespace they were written
+/// with as prefix. Each namespace has a metadata entry stored under `namespace`, with the current
+/// version and the bytes used by the entries of that version.
+#[derive(Default)]
+pub struct PersistentKvLog {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Updates since the last snapshot.
+    updates: FxHashMap<Vec<u8>, Option<Vec<u8>>>,
+    /// Updates taken by the snapshot which is currently persisted. They are still served to
+    /// readers until the snapshot has been written.
+    persisting: FxHashMap<Vec<u8>, Option<Vec<u8>>>,
+    /// The metadata of the namespaces which were written to.
+    namespaces: FxHashMap<String, NamespaceMeta>,
+}
+
+#[derive(Clone, Copy)]
+struct NamespaceMeta {
+    version: u32,
+    size: u64,
+}
+
+impl NamespaceMeta {
+    fn decode(value: &[u8]) -> Option<Self> {
+        let (version, size) = value.split_first_chunk::<4>()?;
+        Some(Self {
+            version: u32::from_le_bytes(*version),
+            size: u64::from_le_bytes(size.try_into().ok()?),
+        })
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut value = self.version.to_le_bytes().to_vec();
+        value.extend_from_slice(&self.size.to_le_bytes());
+        value
+    }
+}
+
+fn entry_key(namespace: &str, key: &[u8]) -> Vec<u8> {
+    let mut entry_key = Vec::with_capacity(namespace.len() + 1 + key.len());
+    entry_key.extend_from_slice(namespace.as_bytes());
+    entry_key.push(0);
+    entry_key.extend_from_slice(key);
+    entry_key
+}
+
+/// Strips the version from a stored entry, if it's the expected one.
+fn entry_value(value: Vec<u8>, version: u32) -> Option<Vec<u8>> {
+    let (entry_version, _) = value.split_first_chunk::<4>()?;
+    (u32::from_le_bytes(*entry_version) == version).then(|| value[4..].to_vec())
+}
+
+fn validate_namespace(namespace: &str) -> Result<()> {
+    if namespace.is_empty() || namespace.contains('\0') {
+        bail!("Invalid persistent key-value namespace {namespace:?}");
+    }
+    Ok(())
+}
+
+impl State {
+    /// The current value of an entry, from the updates or from the storage via `lookup`.
+    fn current(
+        &self,
+        key: &[u8],
+        lookup: impl FnOnce(&[u8]) -> Result<Option<Vec<u8>>>,
+    ) -> Result<Option<Vec<u8>>> {
+        match self.updates.get(key).or_else(|| self.persisting.get(key)) {
+            Some(value) => Ok(value.clone()),
+            None => lookup(key),
+        }
+    }
+}
+
+impl PersistentKvLog {
+    pub fn get(
+        &self,
+        namespace: &str,
+        version: u32,
+        key: &[u8],
+        lookup: impl FnOnce(&[u8]) -> Result<Option<Vec<u8>>>,
+    ) -> Result<Option<Vec<u8>>> {
+        validate_namespace(namespace)?;
+        let state = self.state.lock();
+        Ok(state
+            .current(&entry_key(namespace, key), lookup)?
+            .and_then(|value| entry_value(value, version)))
+    }
+
+    /// Records a write of an entry, unless it would make the namespace exceed `quota` bytes.
+    /// Writes which shrink the namespace are always accepted. Changing the version of a namespace
+    /// resets its used bytes, as the entries of the previous version are treated as missing.
+    pub fn set(
+        &self,
+        namespace: &str,
+        version: u32,
+        key: &[u8],
+        value: Option<&[u8]>,
+        quota: u64,
+        lookup: impl Fn(&[u8]) -> Result<Option<Vec<u8>>>,
+    ) -> Result<bool> {
+        validate_namespace(namespace)?;
+        let mut state = self.state.lock();
+        let meta = match state.namespaces.get(namespace) {
+            Some(meta) => Some(*meta),
+            None => state
+                .current(namespace.as_bytes(), &lookup)?
+                .and_then(|value| NamespaceMeta::decode(&value)),
+        };
+        let meta = meta
+            .filter(|meta| meta.version == version)
+            .unwrap_or(NamespaceMeta { version, size: 0 });
+
+        let entry_key = entry_key(namespace, key);
+        let old_value = state
+            .current(&entry_key, &lookup)?
+            .and_then(|value| entry_value(value, version));
+        if old_value.is_none() && value.is_none() {
+            return Ok(true);
+        }
+        let entry_size = |value: &[u8]| (key.len() + value.len()) as u64;
+        let old_size = old_value.as_deref().map_or(0, entry_size);
+        let new_size = value.map_or(0, entry_size);
+        let size = meta.size.saturating_sub(old_size) + new_size;
+        if size > quota && new_size > old_size {
+            return Ok(false);
+        }
+
+        let meta = NamespaceMeta { version, size };
+        state.namespaces.insert(namespace.to_string(), meta);
+        state
+            .updates
+            .insert(namespace.as_bytes().to_vec(), Some(meta.encode()));
+        state.updates.insert(
+            entry_key,
+            value.map(|value| {
+                let mut entry = version.to_le_bytes().to_vec();
+                entry.extend_from_slice(value);
+                entry
+            }),
+        );
+        Ok(true)
+    }
+
+    /// Takes the updates for a snapshot. They are kept readable until [Self::persisted] is called.
+    pub fn take(&self) -> Vec<PersistentKvUpdate> {
+        let mut state = self.state.lock();
+        let updates = std::mem::take(&mut state.updates);
+        let result = updates
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        state.persisting = updates;
+        result
+    }
+
+    /// Marks the updates of the last [Self::take] as written to the storage.
+    pub fn persisted(&self) {
+        self.state.lock().persisting.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::Result;
+
+    use super::PersistentKvLog;
+
+    fn empty(_key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+
+    #[test]
+    fn test_quota() -> Result<()> {
+        let log = PersistentKvLog::default();
+        assert!(log.set("ns", 1, b"a", Some(b"12345"), 10, empty)?);
+        assert!(!log.set("ns", 1, b"b", Some(b"12345"), 10, empty)?);
+        // Shrinking an entry frees its bytes
+        assert!(log.set("ns", 1, b"a", Some(b"1"), 10, empty)?);
+        assert!(log.set("ns", 1, b"b", Some(b"12345"), 10, empty)?);
+        assert_eq!(
+            log.get("ns", 1, b"b", empty)?.as_deref(),
+            Some(&b"12345"[..])
+        );
+        // Namespaces have separate quotas
+        assert!(log.set("other", 1, b"a", Some(b"12345"), 10, empty)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_version() -> Result<()> {
+        let log = PersistentKvLog::default();
+        assert!(log.set("ns", 1, b"a", Some(b"12345"), 10, empty)?);
+        assert_eq!(log.get("ns", 2, b"a", empty)?, None);
+        // The entries of the previous version don't count towards the quota
+        assert!(log.set("ns", 2, b"b", Some(b"12345"), 10, empty)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_persisted_entries() -> Result<()> {
+        let log = PersistentKvLog::default();
+        assert!(log.set("ns", 1, b"a", Some(b"12345"), 10, empty)?);
+        let storage: HashMap<_, _> = log
+            .take()
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, value?)))
+            .collect();
+        assert_eq!(
+            log.get("ns", 1, b"a", empty)?.as_deref(),
+            Some(&b"12345"[..])
+        );
+        log.persisted();
+        assert_eq!(log.get("ns", 1, b"a", empty)?, None);
+
+        // A new session reads the entries and the used bytes from the storage
+        let log = PersistentKvLog::default();
+        let lookup = |key: &[u8]| Ok(storage.get(key).cloned());
+        assert_eq!(
+            log.get("ns", 1, b"a", lookup)?.as_deref(),
+            Some(&b"12345"[..])
+        );
+        assert!(!log.set("ns", 1, b"b", Some(b"12345"), 10, lookup)?);
+        assert!(log.set("ns", 1, b"a", None, 10, lookup)?);
+        assert!(log.set("ns", 1, b"b", Some(b"12345"), 10, lookup)?);
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use anyhow::Result;
 use turbo_tasks::{backend::CachedTaskType, SessionId, TaskId};
 
 use crate::{
-    backend::{AnyOperation, TaskDataCategory},
+    backend::{AnyOperation, PersistentKvUpdate, TaskDataCategory},
     data::{CachedDataItem, CachedDataUpdate},
     utils::chunked_vec::ChunkedVec,
 };
@@ -24,6 +24,7 @@ pub trait BackingStorage: 'static + Send + Sync {
         task_cache_updates: Vec<ChunkedVec<(Arc<CachedTaskType>, TaskId)>>,
         meta_updates: Vec<ChunkedVec<CachedDataUpdate>>,
         data_updates: Vec<ChunkedVec<CachedDataUpdate>>,
+        persistent_kv_updates: Vec<PersistentKvUpdate>,
     ) -> Result<()>;
     fn start_read_transaction(&self) -> Option<Self::ReadTransaction<'_>>;
     /// # Safety
@@ -51,6 +52,8 @@ pub trait BackingStorage: 'static + Send + Sync {
         task_id: TaskId,
         category: TaskDataCategory,
     ) -> Vec<CachedDataItem>;
+    /// Reads an entry written by a [`PersistentKvUpdate`].
+    fn lookup_persistent_kv(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
 
     fn shutdown(&self) -> Result<()> {
         Ok(())
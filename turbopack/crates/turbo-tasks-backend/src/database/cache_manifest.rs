@@ -0,0 +1,279 @@
+//! `cache-manifest.json` describes the databases in the persistent cache directory, so that CI
+//! cache steps can store and restore them individually. Each database ("segment") is a directory
+//! named after the engine version that wrote it, see [super::db_versioning]. The manifest lists
+//! the files of each segment and the fingerprint of the inputs it was last written with, taken from
+//! `TURBO_ENGINE_CACHE_FINGERPRINT`, e.g. a hash of the lockfile computed by the CI step.
+//!
+//! A CI step can upload each segment under a key derived from its name and fingerprint, and restore
+//! all segments of the current engine version, even ones written with other inputs, since cached
+//! tasks are revalidated anyway. When a segment is restored only partially, e.g. because an
+//! artifact upload was interrupted, it's removed on startup instead of failing to open the
+//! database, while the other segments are kept. Since the manifest is restored from an artifact
+//! too, only names of single files and directories in it are used.
+
+use std::{
+    env,
+    fs::{self, read_dir, remove_dir_all},
+    path::{Component, Path},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+pub const CACHE_MANIFEST: &str = "cache-manifest.json";
+
+const CACHE_MANIFEST_VERSION: u32 = 1;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheManifest {
+    version: u32,
+    segments: Vec<CacheSegment>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheSegment {
+    /// The name of the directory of the segment.
+    name: String,
+    /// The fingerprint of the inputs the segment was last written with.
+    fingerprint: Option<String>,
+    /// The files of the segment, sorted by name.
+    files: Vec<CacheFile>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CacheFile {
+    name: String,
+    size: u64,
+}
+
+/// Reads the manifest in `base_path`. A missing or unreadable manifest, or one of another format
+/// version, is treated as empty, since it only describes the directory.
+fn read_manifest(base_path: &Path) -> CacheManifest {
+    fs::read(base_path.join(CACHE_MANIFEST))
+        .ok()
+        .and_then(|content| serde_json::from_slice::<CacheManifest>(&content).ok())
+        .filter(|manifest| manifest.version == CACHE_MANIFEST_VERSION)
+        .unwrap_or_default()
+}
+
+fn write_manifest(base_path: &Path, manifest: &CacheManifest) -> Result<()> {
+    // Written to a temporary file first, so that the manifest is never incomplete.
+    let temp_path = base_path.join(format!("{CACHE_MANIFEST}.tmp"));
+    fs::write(&temp_path, serde_json::to_vec_pretty(manifest)?)?;
+    fs::rename(&temp_path, base_path.join(CACHE_MANIFEST))?;
+    Ok(())
+}
+
+fn segment_files(path: &Path) -> Result<Vec<CacheFile>> {
+    let mut files = read_dir(path)?
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let metadata = entry.metadata().ok()?;
+            metadata.is_file().then(|| CacheFile {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                size: metadata.len(),
+            })
+        })
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(files)
+}
+
+/// Whether `name` from the manifest is the name of a single file or directory, so that joining it
+/// to a path can't escape that path, e.g. with `..` or an absolute path.
+fn is_plain_name(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(components.next(), Some(Component::Normal(_))) && components.next().is_none()
+}
+
+/// Whether the `segment` was last written with other inputs than `fingerprint`. Such a segment is
+/// used anyway, since cached tasks are revalidated, but fewer of them are reused.
+fn has_other_fingerprint(segment: &CacheSegment, fingerprint: Option<&str>) -> bool {
+    matches!(
+        (segment.fingerprint.as_deref(), fingerprint),
+        (Some(restored), Some(current)) if restored != current
+    )
+}
+
+/// Whether all files of the `segment` were restored with the size they were written with.
+fn is_complete(base_path: &Path, segment: &CacheSegment) -> bool {
+    let path = base_path.join(&segment.name);
+    segment.files.iter().all(|file| {
+        fs::metadata(path.join(&file.name)).is_ok_and(|metadata| metadata.len() == file.size)
+    })
+}
+
+/// Removes the segments in `base_path` which don't match the manifest, and the segments which
+/// aren't on disk from the manifest. The segment `current` is removed from the manifest too, since
+/// it's about to be written to; it's added back by [update_cache_manifest]. Segments with names
+/// which aren't plain file names are dropped from the manifest without touching the file system.
+pub fn validate_restored_segments(base_path: &Path, current: &Path) -> Result<()> {
+    let mut manifest = read_manifest(base_path);
+    if manifest.segments.is_empty() {
+        return Ok(());
+    }
+    let fingerprint = env::var("TURBO_ENGINE_CACHE_FINGERPRINT").ok();
+    manifest.segments.retain(|segment| {
+        if !is_plain_name(&segment.name)
+            || !segment.files.iter().all(|file| is_plain_name(&file.name))
+        {
+            return false;
+        }
+        let path = base_path.join(&segment.name);
+        if !path.is_dir() {
+            return false;
+        }
+        if !is_complete(base_path, segment) {
+            println!(
+                "WARNING: The persistent cache {} was restored incompletely and is removed.",
+                path.display()
+            );
+            let _ = remove_dir_all(&path);
+            return false;
+        }
+        if path != current {
+            return true;
+        }
+        if has_other_fingerprint(segment, fingerprint.as_deref()) {
+            println!(
+                "The persistent cache {} was written with other inputs ({}) and is revalidated.",
+                path.display(),
+                segment.fingerprint.as_deref().unwrap_or_default()
+            );
+        }
+        false
+    });
+    write_manifest(base_path, &manifest)
+}
+
+/// Adds the segment at `path` to the manifest in its parent directory, after it was written.
+pub fn update_cache_manifest(path: &Path) -> Result<()> {
+    let base_path = path
+        .parent()
+        .context("the persistent cache must be in a directory")?;
+    let name = path
+        .file_name()
+        .context("the persistent cache must be in a directory")?
+        .to_string_lossy()
+        .into_owned();
+    let mut manifest = read_manifest(base_path);
+    manifest.version = CACHE_MANIFEST_VERSION;
+    manifest.segments.retain(|segment| segment.name != name);
+    manifest.segments.push(CacheSegment {
+        name,
+        fingerprint: env::var("TURBO_ENGINE_CACHE_FINGERPRINT").ok(),
+        files: segment_files(path)?,
+    });
+    manifest.segments.sort_by(|a, b| a.name.cmp(&b.name));
+    write_manifest(base_path, &manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::{
+        has_other_fingerprint, is_plain_name, read_manifest, update_cache_manifest,
+        validate_restored_segments, write_manifest, CacheFile, CacheManifest, CacheSegment,
+        CACHE_MANIFEST_VERSION,
+    };
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("turbo-tasks-backend-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    #[test]
+    fn removes_incomplete_segments() {
+        let base_path = temp_dir("cache-manifest");
+        for name in ["v1", "v2", "v3"] {
+            let path = base_path.join(name);
+            fs::create_dir(&path).unwrap();
+            fs::write(path.join("CURRENT"), [0, 0, 0, 1]).unwrap();
+            fs::write(path.join("00000001.sst"), "data").unwrap();
+            update_cache_manifest(&path).unwrap();
+        }
+        // A partially restored segment
+        fs::remove_file(base_path.join("v2/00000001.sst")).unwrap();
+
+        validate_restored_segments(&base_path, &base_path.join("v3")).unwrap();
+
+        assert!(base_path.join("v1").exists());
+        assert!(!base_path.join("v2").exists());
+        assert!(base_path.join("v3").exists());
+        let names = read_manifest(&base_path)
+            .segments
+            .into_iter()
+            .map(|segment| segment.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["v1"]);
+
+        fs::remove_dir_all(&base_path).unwrap();
+    }
+
+    #[test]
+    fn ignores_segments_outside_of_the_cache() {
+        let base_path = temp_dir("cache-manifest-hostile");
+        let outside = temp_dir("cache-manifest-outside");
+        fs::write(outside.join("data"), "data").unwrap();
+
+        // Segments which look incomplete, so they would be removed if they were used
+        let segments = [
+            format!("../{}", outside.file_name().unwrap().to_string_lossy()),
+            outside.to_string_lossy().into_owned(),
+        ]
+        .map(|name| CacheSegment {
+            name,
+            fingerprint: None,
+            files: vec![CacheFile {
+                name: "missing".to_string(),
+                size: 1,
+            }],
+        });
+        let manifest = CacheManifest {
+            version: CACHE_MANIFEST_VERSION,
+            segments: segments.into(),
+        };
+        write_manifest(&base_path, &manifest).unwrap();
+
+        validate_restored_segments(&base_path, &base_path.join("v1")).unwrap();
+
+        assert!(outside.join("data").exists());
+        assert!(read_manifest(&base_path).segments.is_empty());
+
+        fs::remove_dir_all(&base_path).unwrap();
+        fs::remove_dir_all(&outside).unwrap();
+    }
+
+    #[test]
+    fn plain_names() {
+        assert!(is_plain_name("v1"));
+        assert!(is_plain_name("00000001.sst"));
+        assert!(!is_plain_name(""));
+        assert!(!is_plain_name("."));
+        assert!(!is_plain_name(".."));
+        assert!(!is_plain_name("../v1"));
+        assert!(!is_plain_name("v1/CURRENT"));
+        assert!(!is_plain_name("/tmp"));
+    }
+
+    #[test]
+    fn other_fingerprints() {
+        let segment = |fingerprint: Option<&str>| CacheSegment {
+            name: "v1".to_string(),
+            fingerprint: fingerprint.map(str::to_string),
+            files: vec![],
+        };
+        assert!(has_other_fingerprint(&segment(Some("a")), Some("b")));
+        assert!(!has_other_fingerprint(&segment(Some("a")), Some("a")));
+        // Without fingerprints there's nothing to compare
+        assert!(!has_other_fingerprint(&segment(None), Some("a")));
+        assert!(!has_other_fingerprint(&segment(Some("a")), None));
+    }
+}
@@ -7,6 +7,8 @@ use std::{
 
 use anyhow::Result;
 
+use crate::database::cache_manifest::validate_restored_segments;
+
 /// Specifies many databases that have a different version than the current one are retained.
 /// For example if MAX_OTHER_DB_VERSIONS is 2, there can be at most 3 databases in the directory,
 /// the current one and two older/newer ones.
@@ -14,7 +16,9 @@ const MAX_OTHER_DB_VERSIONS: usize = 2;
 
 pub fn handle_db_versioning(base_path: &Path, version_info: &str) -> Result<PathBuf> {
     if let Ok(version) = env::var("TURBO_ENGINE_VERSION") {
-        return Ok(base_path.join(version));
+        let path = base_path.join(version);
+        validate_restored_segments(base_path, &path)?;
+        return Ok(path);
     }
     // Database versioning. Pass `TURBO_ENGINE_IGNORE_DIRTY` at runtime to ignore a
     // dirty git repository. Pass `TURBO_ENGINE_DISABLE_VERSIONING` at runtime to disable
@@ -92,5 +96,7 @@ pub fn handle_db_versioning(base_path: &Path, version_info: &str) -> Result<Path
         path = base_path.join("temp");
     }
 
+    validate_restored_segments(base_path, &path)?;
+
     Ok(path)
 }
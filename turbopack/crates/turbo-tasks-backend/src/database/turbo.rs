@@ -10,6 +10,7 @@ use parking_lot::Mutex;
 use turbo_persistence::{ArcSlice, TurboPersistence};
 
 use crate::database::{
+    cache_manifest::update_cache_manifest,
     key_value_database::{KeySpace, KeyValueDatabase},
     write_batch::{BaseWriteBatch, ConcurrentWriteBatch, WriteBatch},
 };
@@ -18,6 +19,7 @@ const COMPACT_MAX_COVERAGE: f32 = 20.0;
 const COMPACT_MAX_MERGE_SEQUENCE: usize = 8;
 
 pub struct TurboKeyValueDatabase {
+    path: PathBuf,
     db: Arc<TurboPersistence>,
     compact_join_handle: Mutex<Option<JoinHandle<Result<()>>>>,
 }
@@ -26,6 +28,7 @@ impl TurboKeyValueDatabase {
     pub fn new(path: PathBuf) -> Result<Self> {
         let db = Arc::new(TurboPersistence::open(path.to_path_buf())?);
         let mut this = Self {
+            path,
             db: db.clone(),
             compact_join_handle: Mutex::new(None),
         };
@@ -99,7 +102,9 @@ impl KeyValueDatabase for TurboKeyValueDatabase {
             join_handle.join().unwrap()?;
         }
         // Shutdown the database
-        self.db.shutdown()
+        self.db.shutdown()?;
+        // Describe the written files for CI caches
+        update_cache_manifest(&self.path)
     }
 }
 
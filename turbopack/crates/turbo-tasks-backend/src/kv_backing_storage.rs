@@ -13,7 +13,7 @@ use tracing::Span;
 use turbo_tasks::{backend::CachedTaskType, turbo_tasks_scope, KeyValuePair, SessionId, TaskId};
 
 use crate::{
-    backend::{AnyOperation, TaskDataCategory},
+    backend::{AnyOperation, PersistentKvUpdate, TaskDataCategory},
     backing_storage::BackingStorage,
     data::{CachedDataItem, CachedDataItemKey, CachedDataItemValue, CachedDataUpdate},
     database::{
@@ -39,6 +39,14 @@ const META_KEY_OPERATIONS: u32 = 0;
 const META_KEY_NEXT_FREE_TASK_ID: u32 = 1;
 const META_KEY_SESSION_ID: u32 = 2;
 
+/// Prefix of the persistent key-value entries in the infra key space. Other infra keys are
+/// [IntKey]s, which are shorter.
+const PERSISTENT_KV_PREFIX: &[u8] = b"persistent-kv\0";
+
+fn persistent_kv_key(key: &[u8]) -> Vec<u8> {
+    [PERSISTENT_KV_PREFIX, key].concat()
+}
+
 struct IntKey([u8; 4]);
 
 impl IntKey {
@@ -136,6 +144,7 @@ impl<T: KeyValueDatabase + Send + Sync + 'static> BackingStorage
         task_cache_updates: Vec<ChunkedVec<(Arc<CachedTaskType>, TaskId)>>,
         meta_updates: Vec<ChunkedVec<CachedDataUpdate>>,
         data_updates: Vec<ChunkedVec<CachedDataUpdate>>,
+        persistent_kv_updates: Vec<PersistentKvUpdate>,
     ) -> Result<()> {
         let _span = tracing::trace_span!("save snapshot", session_id = ?session_id, operations = operations.len());
         let mut batch = self.database.write_batch()?;
@@ -233,6 +242,7 @@ impl<T: KeyValueDatabase + Send + Sync + 'static> BackingStorage
                         next_task_id,
                         session_id,
                         operations,
+                        persistent_kv_updates,
                     )?;
                     anyhow::Ok(())
                 })?;
@@ -303,6 +313,7 @@ impl<T: KeyValueDatabase + Send + Sync + 'static> BackingStorage
                         next_task_id,
                         session_id,
                         operations,
+                        persistent_kv_updates,
                     )?;
                     anyhow::Ok(())
                 })?;
@@ -436,6 +447,18 @@ impl<T: KeyValueDatabase + Send + Sync + 'static> BackingStorage
             .unwrap_or_default()
     }
 
+    fn lookup_persistent_kv(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        let tx = self.database.begin_read_transaction()?;
+        let Some(value) = self
+            .database
+            .get(&tx, KeySpace::Infra, &persistent_kv_key(key))?
+        else {
+            return Ok(None);
+        };
+        let value: &[u8] = value.borrow();
+        Ok(Some(value.to_vec()))
+    }
+
     fn shutdown(&self) -> Result<()> {
         self.database.shutdown()
     }
@@ -464,6 +487,7 @@ fn save_infra<'a, S, C>(
     next_task_id: u32,
     session_id: SessionId,
     operations: Vec<Arc<AnyOperation>>,
+    persistent_kv_updates: Vec<PersistentKvUpdate>,
 ) -> Result<(), anyhow::Error>
 where
     S: SerialWriteBatch<'a>,
@@ -502,6 +526,21 @@ where
             )
             .with_context(|| anyhow!("Unable to write operations"))?;
     }
+    if !persistent_kv_updates.is_empty() {
+        let _span = tracing::trace_span!(
+            "update persistent key-value entries",
+            entries = persistent_kv_updates.len()
+        )
+        .entered();
+        for (key, value) in persistent_kv_updates {
+            let key = Cow::Owned(persistent_kv_key(&key));
+            match value {
+                Some(value) => batch.put(KeySpace::Infra, key, value.into()),
+                None => batch.delete(KeySpace::Infra, key),
+            }
+            .with_context(|| anyhow!("Unable to write persistent key-value entry"))?;
+        }
+    }
     Ok(())
 }
 
@@ -2,11 +2,16 @@
 #![feature(arbitrary_self_types)]
 #![feature(arbitrary_self_types_pointers)]
 
-use anyhow::Result;
+use std::{net::IpAddr, time::Duration};
+
+use anyhow::{Context, Result};
 use turbo_rcstr::RcStr;
 use turbo_tasks::{mark_session_dependent, ResolvedVc, Vc};
-use turbo_tasks_fs::FileSystemPath;
-use turbopack_core::issue::{Issue, IssueSeverity, IssueStage, OptionStyledString, StyledString};
+use turbo_tasks_fs::{rope::Rope, FileSystemPath};
+use turbopack_core::{
+    issue::{Issue, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    remote_source::{RemoteFetchResult, RemoteFetcher},
+};
 
 pub fn register() {
     turbo_tasks::register();
@@ -48,6 +53,23 @@ pub enum ProxyConfig {
 #[turbo_tasks::value(transparent)]
 pub struct OptionProxyConfig(Option<ProxyConfig>);
 
+/// Proxy and TLS settings used by [fetch_with_config].
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Default)]
+pub struct FetchConfig {
+    pub proxies: Vec<ProxyConfig>,
+    /// Hosts that are connected to directly rather than through a proxy, in
+    /// the format of the `NO_PROXY` environment variable, e.g.
+    /// `localhost,.internal.example.com`.
+    pub no_proxy: Option<RcStr>,
+    /// PEM encoded certificates to trust in addition to the built-in roots,
+    /// e.g. those of a corporate proxy intercepting TLS.
+    pub ca_certificates: Option<RcStr>,
+    /// How often transient failures (connection errors, timeouts, `429` and
+    /// `5xx` responses) are retried, with an exponential backoff.
+    pub retries: u32,
+}
+
 #[turbo_tasks::function(network)]
 pub async fn fetch(
     url: Vc<RcStr>,
@@ -58,21 +80,93 @@ pub async fn fetch(
     let user_agent = &*user_agent.await?;
     let proxy_option = &*proxy_option.await?;
 
-    let client_builder = reqwest::Client::builder();
-    let client_builder = match proxy_option {
-        Some(ProxyConfig::Http(proxy)) => client_builder.proxy(reqwest::Proxy::http(proxy)?),
-        Some(ProxyConfig::Https(proxy)) => client_builder.proxy(reqwest::Proxy::https(proxy)?),
-        _ => client_builder,
-    };
+    fetch_with_client_config(
+        url,
+        user_agent,
+        proxy_option.iter().collect(),
+        None,
+        None,
+        0,
+    )
+    .await
+}
 
-    let client = client_builder.build()?;
+#[turbo_tasks::function(network)]
+pub async fn fetch_with_config(
+    url: Vc<RcStr>,
+    user_agent: Vc<Option<RcStr>>,
+    config: Vc<FetchConfig>,
+) -> Result<Vc<FetchResult>> {
+    let url = &*url.await?;
+    let user_agent = &*user_agent.await?;
+    let config = &*config.await?;
+
+    fetch_with_client_config(
+        url,
+        user_agent,
+        config.proxies.iter().collect(),
+        config.no_proxy.as_deref(),
+        config.ca_certificates.as_deref(),
+        config.retries,
+    )
+    .await
+}
 
-    let mut builder = client.get(url.as_str());
-    if let Some(user_agent) = user_agent {
-        builder = builder.header("User-Agent", user_agent.as_str());
+async fn fetch_with_client_config(
+    url: &RcStr,
+    user_agent: &Option<RcStr>,
+    proxies: Vec<&ProxyConfig>,
+    no_proxy: Option<&str>,
+    ca_certificates: Option<&str>,
+    retries: u32,
+) -> Result<Vc<FetchResult>> {
+    let mut client_builder = reqwest::Client::builder();
+    for proxy_config in &proxies {
+        let proxy = match proxy_config {
+            ProxyConfig::Http(proxy) => reqwest::Proxy::http(proxy),
+            ProxyConfig::Https(proxy) => reqwest::Proxy::https(proxy),
+        };
+        let proxy = match proxy {
+            Ok(proxy) => proxy,
+            Err(err) => {
+                return Ok(Vc::cell(Err(FetchError::from_reqwest_error(
+                    &err,
+                    url,
+                    Some(proxy_config),
+                )
+                .resolved_cell())))
+            }
+        };
+        client_builder =
+            client_builder.proxy(proxy.no_proxy(no_proxy.and_then(reqwest::NoProxy::from_string)));
+    }
+    if let Some(ca_certificates) = ca_certificates {
+        for certificate in split_pem_certificates(ca_certificates) {
+            client_builder = client_builder.add_root_certificate(
+                reqwest::Certificate::from_pem(certificate.as_bytes())
+                    .context("Failed to parse the provided CA certificates")?,
+            );
+        }
     }
 
-    let response = builder.send().await.and_then(|r| r.error_for_status());
+    let client = client_builder.build()?;
+
+    let mut attempt = 0;
+    let response = loop {
+        let mut builder = client.get(url.as_str());
+        if let Some(user_agent) = user_agent {
+            builder = builder.header("User-Agent", user_agent.as_str());
+        }
+
+        let response = builder.send().await.and_then(|r| r.error_for_status());
+        match &response {
+            Err(err) if attempt < retries && is_transient_error(err) => {
+                tokio::time::sleep(RETRY_BASE_DELAY * 2u32.pow(attempt)).await;
+                attempt += 1;
+            }
+            _ => break response,
+        }
+    };
     match response {
         Ok(response) => {
             let status = response.status().as_u16();
@@ -86,19 +180,137 @@ pub async fn fetch(
         }
         Err(err) => {
             mark_session_dependent();
-            Ok(Vc::cell(Err(
-                FetchError::from_reqwest_error(&err, url).resolved_cell()
-            )))
+            let proxy = proxies
+                .into_iter()
+                .find(|proxy| proxy.applies_to(url, no_proxy));
+            Ok(Vc::cell(Err(FetchError::from_reqwest_error(
+                &err, url, proxy,
+            )
+            .resolved_cell())))
+        }
+    }
+}
+
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+fn is_transient_error(error: &reqwest::Error) -> bool {
+    error.is_connect()
+        || error.is_timeout()
+        || error.status().is_some_and(|status| {
+            status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+        })
+}
+
+/// Splits a PEM bundle into its individual certificates, as
+/// [reqwest::Certificate::from_pem] only reads the first one.
+fn split_pem_certificates(bundle: &str) -> Vec<&str> {
+    const END_MARKER: &str = "-----END CERTIFICATE-----";
+
+    let mut certificates = vec![];
+    let mut rest = bundle;
+    while let Some(end) = rest.find(END_MARKER) {
+        let (certificate, remaining) = rest.split_at(end + END_MARKER.len());
+        certificates.push(certificate.trim());
+        rest = remaining;
+    }
+    certificates
+}
+
+impl ProxyConfig {
+    /// Whether requests to `url` are sent through this proxy, which they
+    /// aren't when its host is in the `no_proxy` list.
+    fn applies_to(&self, url: &str, no_proxy: Option<&str>) -> bool {
+        let scheme_matches = match self {
+            ProxyConfig::Http(_) => url.starts_with("http:"),
+            ProxyConfig::Https(_) => url.starts_with("https:"),
+        };
+        scheme_matches
+            && !no_proxy.is_some_and(|no_proxy| {
+                reqwest::Url::parse(url)
+                    .ok()
+                    .and_then(|url| url.host_str().map(|host| is_no_proxy_host(no_proxy, host)))
+                    .unwrap_or(false)
+            })
+    }
+
+    /// The url of the proxy without any credentials, safe to show to users.
+    fn redacted_url(&self) -> RcStr {
+        let (ProxyConfig::Http(proxy) | ProxyConfig::Https(proxy)) = self;
+        match reqwest::Url::parse(proxy) {
+            Ok(mut url) => {
+                // These only fail for urls that can't have credentials in the first place
+                let _ = url.set_username("");
+                let _ = url.set_password(None);
+                url.as_str().into()
+            }
+            Err(_) => "<invalid proxy url>".into(),
         }
     }
 }
 
+/// Whether `host` is in the `no_proxy` list, matching it like
+/// [reqwest::NoProxy] does: `*` matches every host, IP addresses and networks
+/// (e.g. `10.0.0.0/8`) match the addresses in them, and domains match
+/// themselves and their subdomains, with or without a leading `.`.
+fn is_no_proxy_host(no_proxy: &str, host: &str) -> bool {
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let ip = host.parse::<IpAddr>().ok();
+    no_proxy
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| {
+            if entry == "*" {
+                return true;
+            }
+            if let Some(ip) = ip {
+                return is_in_network(ip, entry);
+            }
+            let domain = entry.trim_start_matches('.');
+            host.eq_ignore_ascii_case(domain)
+                || host.len() > domain.len()
+                    && host.as_bytes()[host.len() - domain.len() - 1] == b'.'
+                    && host[host.len() - domain.len()..].eq_ignore_ascii_case(domain)
+        })
+}
+
+/// Whether `ip` is the address or in the network (e.g. `10.0.0.0/8`) `entry`.
+fn is_in_network(ip: IpAddr, entry: &str) -> bool {
+    let (network, prefix) = match entry.split_once('/') {
+        Some((network, prefix)) => match prefix.parse::<u32>() {
+            Ok(prefix) => (network, Some(prefix)),
+            Err(_) => return false,
+        },
+        None => (entry, None),
+    };
+    let Ok(network) = network.parse::<IpAddr>() else {
+        return false;
+    };
+    let (ip, network, bits) = match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            (u32::from(ip) as u128, u32::from(network) as u128, 32)
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => (u128::from(ip), u128::from(network), 128),
+        _ => return false,
+    };
+    let prefix = prefix.unwrap_or(bits);
+    if prefix > bits {
+        return false;
+    }
+    // Shifting by all the bits of a u128 overflows, but then any address matches
+    let shift = bits - prefix;
+    ip.checked_shr(shift).unwrap_or(0) == network.checked_shr(shift).unwrap_or(0)
+}
+
 #[derive(Debug)]
 #[turbo_tasks::value(shared)]
 pub enum FetchErrorKind {
     Connect,
     Timeout,
     Status(u16),
+    /// The request failed while connecting through the given proxy, or the
+    /// proxy configuration is invalid.
+    Proxy(RcStr),
     Other,
 }
 
@@ -110,8 +322,19 @@ pub struct FetchError {
 }
 
 impl FetchError {
-    fn from_reqwest_error(error: &reqwest::Error, url: &str) -> FetchError {
-        let kind = if error.is_connect() {
+    fn from_reqwest_error(
+        error: &reqwest::Error,
+        url: &str,
+        proxy: Option<&ProxyConfig>,
+    ) -> FetchError {
+        let is_proxy_error = error.is_builder()
+            || error.is_connect()
+            || error
+                .status()
+                .is_some_and(|status| status == reqwest::StatusCode::PROXY_AUTHENTICATION_REQUIRED);
+        let kind = if let Some(proxy) = proxy.filter(|_| is_proxy_error) {
+            FetchErrorKind::Proxy(proxy.redacted_url())
+        } else if error.is_connect() {
             FetchErrorKind::Connect
         } else if error.is_timeout() {
             FetchErrorKind::Timeout
@@ -200,6 +423,12 @@ impl Issue for FetchIssue {
                 FetchErrorKind::Timeout => {
                     format!("Connection timed out when requesting {}", url).into()
                 }
+                FetchErrorKind::Proxy(proxy) => format!(
+                    "There was an issue connecting through the proxy {} while requesting {}. \
+                     Check the HTTPS_PROXY, HTTP_PROXY and NO_PROXY environment variables.",
+                    proxy, url
+                )
+                .into(),
                 FetchErrorKind::Other => format!("There was an issue requesting {}", url).into(),
             })
             .resolved_cell(),
@@ -211,3 +440,53 @@ impl Issue for FetchIssue {
         Vc::cell(Some(self.detail))
     }
 }
+
+/// Fetches remote modules over HTTP.
+#[turbo_tasks::value(shared)]
+pub struct HttpRemoteFetcher {
+    pub user_agent: Option<RcStr>,
+    pub config: ResolvedVc<FetchConfig>,
+}
+
+#[turbo_tasks::value_impl]
+impl HttpRemoteFetcher {
+    #[turbo_tasks::function]
+    pub fn new(user_agent: Option<RcStr>, config: ResolvedVc<FetchConfig>) -> Vc<Self> {
+        Self::cell(HttpRemoteFetcher { user_agent, config })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl RemoteFetcher for HttpRemoteFetcher {
+    #[turbo_tasks::function]
+    async fn fetch(&self, url: RcStr) -> Result<Vc<RemoteFetchResult>> {
+        let result = fetch_with_config(
+            Vc::cell(url.clone()),
+            Vc::cell(self.user_agent.clone()),
+            *self.config,
+        )
+        .await?;
+        Ok(match &*result {
+            Ok(response) => {
+                let response = response.await?;
+                if response.status >= 400 {
+                    mark_session_dependent();
+                    RemoteFetchResult::Err(
+                        format!("Received response with status {}", response.status).into(),
+                    )
+                } else {
+                    let body = response.body.await?.0.clone();
+                    RemoteFetchResult::Ok(Rope::from(body).resolved_cell())
+                }
+            }
+            Err(error) => {
+                let error = error.await?;
+                RemoteFetchResult::Err(match &*error.detail.await? {
+                    StyledString::Text(detail) => detail.clone(),
+                    _ => format!("There was an issue requesting {}", error.url.await?).into(),
+                })
+            }
+        }
+        .cell())
+    }
+}
@@ -19,10 +19,12 @@ mod mutex_map;
 mod read_glob;
 mod retry;
 pub mod rope;
+mod settle;
 pub mod source_context;
 pub mod util;
 pub(crate) mod virtual_fs;
 mod watcher;
+mod zip_fs;
 
 use std::{
     borrow::Cow,
@@ -33,7 +35,7 @@ use std::{
     io::{self, BufRead, ErrorKind},
     mem::take,
     path::{Path, PathBuf, MAIN_SEPARATOR},
-    sync::Arc,
+    sync::{Arc, Mutex},
     time::Duration,
 };
 
@@ -53,7 +55,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::{
     fs,
-    io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
     sync::{RwLock, RwLockReadGuard},
 };
 use tracing::Instrument;
@@ -68,12 +70,14 @@ use turbo_tasks_hash::{
 use util::{extract_disk_access, join_path, normalize_path, sys_to_unix, unix_to_sys};
 pub use virtual_fs::VirtualFileSystem;
 use watcher::DiskWatcher;
+pub use zip_fs::ZipFileSystem;
 
 use self::{invalidation::Write, json::UnparseableJson, mutex_map::MutexMap};
 use crate::{
     attach::AttachedFileSystem,
     retry::{retry_blocking, retry_future},
     rope::{Rope, RopeReader},
+    settle::{read_once, read_settled},
 };
 
 /// A (somewhat arbitrary) filename limit that we should try to keep output file names below.
@@ -206,6 +210,11 @@ struct DiskFileSystemInner {
     #[turbo_tasks(debug_ignore, trace_ignore)]
     #[serde(skip)]
     invalidation_lock: RwLock<()>,
+    /// Files the watcher reported as changed since they were last read. Only
+    /// these can be caught in the middle of a save.
+    #[turbo_tasks(debug_ignore, trace_ignore)]
+    #[serde(skip)]
+    changed_paths: Mutex<HashSet<String>>,
 
     #[turbo_tasks(debug_ignore, trace_ignore)]
     watcher: DiskWatcher,
@@ -217,6 +226,15 @@ impl DiskFileSystemInner {
         simplified(Path::new(&*self.root))
     }
 
+    /// Returns whether the watcher reported the file at `path` as changed
+    /// since it was last read.
+    fn take_changed(&self, path: &Path) -> bool {
+        self.changed_paths
+            .lock()
+            .unwrap()
+            .remove(&path_to_key(path))
+    }
+
     /// registers the path as an invalidator for the current task,
     /// has to be called within a turbo-tasks function
     fn register_invalidator(&self, path: &Path) -> Result<()> {
@@ -354,6 +372,31 @@ pub struct DiskFileSystem {
 }
 
 impl DiskFileSystem {
+    /// Creates the file system from within the current task, which is then
+    /// expected to store it in a cell of its own. Unlike
+    /// [DiskFileSystem::new], the identity of the cell, and of every task
+    /// taking a path of the file system as an argument, depends on the task
+    /// creating it instead of the `root`, so those tasks stay cached when the
+    /// root moves. See [DiskFileSystem::new] for the arguments.
+    pub fn create(name: RcStr, root: RcStr, ignored_subpaths: Vec<RcStr>) -> Self {
+        mark_stateful();
+
+        DiskFileSystem {
+            inner: Arc::new(DiskFileSystemInner {
+                name,
+                root,
+                mutex_map: Default::default(),
+                invalidation_lock: Default::default(),
+                changed_paths: Default::default(),
+                invalidator_map: InvalidatorMap::new(),
+                dir_invalidator_map: InvalidatorMap::new(),
+                watcher: DiskWatcher::new(
+                    ignored_subpaths.into_iter().map(PathBuf::from).collect(),
+                ),
+            }),
+        }
+    }
+
     pub fn name(&self) -> &RcStr {
         &self.inner.name
     }
@@ -437,24 +480,8 @@ impl DiskFileSystem {
     ///   be a full path, since it is possible that root & project dir is different and requires to
     ///   ignore specific subpaths from each.
     #[turbo_tasks::function]
-    pub async fn new(name: RcStr, root: RcStr, ignored_subpaths: Vec<RcStr>) -> Result<Vc<Self>> {
-        mark_stateful();
-
-        let instance = DiskFileSystem {
-            inner: Arc::new(DiskFileSystemInner {
-                name,
-                root,
-                mutex_map: Default::default(),
-                invalidation_lock: Default::default(),
-                invalidator_map: InvalidatorMap::new(),
-                dir_invalidator_map: InvalidatorMap::new(),
-                watcher: DiskWatcher::new(
-                    ignored_subpaths.into_iter().map(PathBuf::from).collect(),
-                ),
-            }),
-        };
-
-        Ok(Self::cell(instance))
+    pub fn new(name: RcStr, root: RcStr, ignored_subpaths: Vec<RcStr>) -> Vc<Self> {
+        Self::cell(Self::create(name, root, ignored_subpaths))
     }
 
     #[turbo_tasks::function(fs)]
@@ -535,7 +562,8 @@ impl FileSystem for DiskFileSystem {
         self.inner.register_invalidator(&full_path)?;
 
         let _lock = self.inner.lock_path(&full_path).await;
-        let content = match retry_future(|| File::from_path(full_path.clone()))
+        let changed = self.inner.take_changed(&full_path);
+        let content = match File::from_path(full_path.clone(), changed)
             .instrument(tracing::info_span!(
                 "read file",
                 path = display(full_path.display())
@@ -1738,13 +1766,14 @@ pub struct File {
 }
 
 impl File {
-    /// Reads a [File] from the given path
-    async fn from_path(p: PathBuf) -> io::Result<Self> {
-        let mut file = fs::File::open(p).await?;
-        let metadata = file.metadata().await?;
-
-        let mut output = Vec::with_capacity(metadata.len() as usize);
-        file.read_to_end(&mut output).await?;
+    /// Reads a [File] from the given path. Files that just `changed` are read
+    /// until they're settled, see [read_settled].
+    async fn from_path(p: PathBuf, changed: bool) -> io::Result<Self> {
+        let (output, metadata) = if changed {
+            read_settled(&p).await?
+        } else {
+            read_once(&p).await?
+        };
 
         Ok(File {
             meta: metadata.into(),
@@ -0,0 +1,164 @@
+//! Editors save files in different ways. Some (e.g. VSCode) truncate the file
+//! and write the new content into it, others (e.g. vim) move the original away
+//! and write a new file in its place, which briefly leaves an empty or
+//! partially written file behind. Reads catching a file in such a state are
+//! retried for a short window, until the file is settled.
+//!
+//! Editors writing a temporary file and renaming it over the original (e.g.
+//! IntelliJ's "safe write") are atomic, and never observed mid-save.
+
+use std::{fs::Metadata, future::Future, io, path::Path, time::Duration};
+
+use jsonc_parser::{parse_to_serde_value, ParseOptions};
+use tokio::fs;
+
+use crate::retry::retry_future;
+
+const MAX_SETTLE_ATTEMPTS: usize = 4;
+const SETTLE_DELAY: Duration = Duration::from_millis(15);
+
+/// Reads the file at `path`, which the watcher just reported as changed,
+/// retrying reads that look like the file was caught in the middle of a save.
+/// Files that stay empty or unparseable across reads are returned as is.
+/// Other files are read with [read_once], as they can't be mid-save.
+pub(crate) async fn read_settled(path: &Path) -> io::Result<(Vec<u8>, Metadata)> {
+    settle(path, || tokio::time::sleep(SETTLE_DELAY)).await
+}
+
+/// Reads the file at `path` until it's settled, calling `wait` before each
+/// retry.
+async fn settle<F: Future<Output = ()>>(
+    path: &Path,
+    mut wait: impl FnMut() -> F,
+) -> io::Result<(Vec<u8>, Metadata)> {
+    let (mut content, mut metadata) = read_once(path).await?;
+    for _ in 1..MAX_SETTLE_ATTEMPTS {
+        if is_settled(path, &content, &metadata).await {
+            break;
+        }
+
+        wait().await;
+        let (new_content, new_metadata) = read_once(path).await?;
+        let unchanged = new_content == content;
+        (content, metadata) = (new_content, new_metadata);
+        if unchanged {
+            break;
+        }
+    }
+    Ok((content, metadata))
+}
+
+pub(crate) async fn read_once(path: &Path) -> io::Result<(Vec<u8>, Metadata)> {
+    retry_future(|| async move {
+        let metadata = fs::metadata(path).await?;
+        let content = fs::read(path).await?;
+        Ok((content, metadata))
+    })
+    .await
+}
+
+/// Whether `content` read from `path` is complete: the file didn't change size
+/// while it was read, isn't empty, and parses if it's a JSON file. Only files
+/// that just changed are checked, as JSON files are parsed again when used.
+async fn is_settled(path: &Path, content: &[u8], metadata: &Metadata) -> bool {
+    if content.is_empty() || metadata.len() != content.len() as u64 {
+        return false;
+    }
+    if let Ok(metadata_after) = fs::metadata(path).await {
+        if metadata_after.len() != content.len() as u64 {
+            return false;
+        }
+    }
+    if path.extension().is_some_and(|ext| ext == "json") {
+        return std::str::from_utf8(content)
+            .is_ok_and(|content| parse_to_serde_value(content, &ParseOptions::default()).is_ok());
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use tokio::fs;
+
+    use super::{read_settled, settle};
+
+    #[tokio::test]
+    async fn reads_file_truncated_then_written() {
+        // VSCode: truncate the file, then write the new content into it
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("page.js");
+        fs::write(&path, "old").await.unwrap();
+
+        fs::write(&path, "").await.unwrap();
+        // The editor finishes the save while the read waits for the file to settle
+        let (content, _) = settle(&path, || {
+            std::fs::write(&path, "export default 42").unwrap();
+            async {}
+        })
+        .await
+        .unwrap();
+        assert_eq!(content, b"export default 42");
+    }
+
+    #[tokio::test]
+    async fn reads_file_replaced_by_new_file() {
+        // vim: move the original to a backup, then write a new file in its place
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("package.json");
+        fs::write(&path, r#"{ "name": "old" }"#).await.unwrap();
+
+        fs::rename(&path, dir.path().join("package.json~"))
+            .await
+            .unwrap();
+        fs::write(&path, r#"{ "name": "#).await.unwrap();
+        let (content, _) = settle(&path, || {
+            std::fs::write(&path, r#"{ "name": "new" }"#).unwrap();
+            async {}
+        })
+        .await
+        .unwrap();
+        assert_eq!(content, br#"{ "name": "new" }"#);
+    }
+
+    #[tokio::test]
+    async fn reads_file_renamed_over_original() {
+        // IntelliJ: write a temporary file, then rename it over the original
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("page.js");
+        let tmp_path = dir.path().join("page.js___jb_tmp___");
+        fs::write(&path, "old").await.unwrap();
+
+        fs::write(&tmp_path, "new").await.unwrap();
+        fs::rename(&tmp_path, &path).await.unwrap();
+
+        let (content, _) = settle(&path, || -> std::future::Ready<()> {
+            panic!("the file is settled")
+        })
+        .await
+        .unwrap();
+        assert_eq!(content, b"new");
+    }
+
+    #[tokio::test]
+    async fn returns_files_that_stay_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("empty.css");
+        fs::write(&path, "").await.unwrap();
+
+        let waits = Cell::new(0);
+        let (content, _) = settle(&path, || {
+            waits.set(waits.get() + 1);
+            async {}
+        })
+        .await
+        .unwrap();
+        assert!(content.is_empty());
+        // The read isn't retried once the file stays the same
+        assert_eq!(waits.get(), 1);
+
+        let (content, _) = read_settled(&path).await.unwrap();
+        assert!(content.is_empty());
+    }
+}
@@ -69,7 +69,9 @@ impl DiskWatcher {
     pub(crate) fn restore_if_watching(&self, dir_path: &Path, root_path: &Path) -> Result<()> {
         if self.watching.contains(dir_path) {
             let mut watcher = self.watcher.lock().unwrap();
-            self.start_watching_dir(&mut watcher, dir_path, root_path)?;
+            if !self.start_watching_dir(&mut watcher, dir_path, root_path)? {
+                self.watching.remove(dir_path);
+            }
         }
         Ok(())
     }
@@ -80,42 +82,31 @@ impl DiskWatcher {
             return Ok(());
         }
         let mut watcher = self.watcher.lock().unwrap();
-        if self.watching.insert(dir_path.to_path_buf()) {
-            self.start_watching_dir(&mut watcher, dir_path, root_path)?;
+        if self.watching.insert(dir_path.to_path_buf())
+            && !self.start_watching_dir(&mut watcher, dir_path, root_path)?
+        {
+            // Only an ancestor is watched while the directory doesn't exist, e.g. a package that
+            // isn't installed yet. It's read again once the ancestor reports its creation, which
+            // has to start watching it, otherwise changes within it would be missed.
+            self.watching.remove(dir_path);
         }
         Ok(())
     }
 
+    /// Returns whether `dir_path` itself is watched, as opposed to one of its
+    /// ancestors. Directories are considered watched until the watcher is
+    /// started.
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     fn start_watching_dir(
         &self,
         watcher: &mut std::sync::MutexGuard<Option<DiskWatcherInternal>>,
         dir_path: &Path,
         root_path: &Path,
-    ) -> Result<()> {
-        use anyhow::Context;
-
-        if let Some(watcher) = watcher.as_mut() {
-            let mut path = dir_path;
-            while let Err(err) = watcher.watch(path, RecursiveMode::NonRecursive) {
-                if path == root_path {
-                    return Err(err).context(format!(
-                        "Unable to watch {} (tried up to {})",
-                        dir_path.display(),
-                        path.display()
-                    ));
-                }
-                let Some(parent_path) = path.parent() else {
-                    return Err(err).context(format!(
-                        "Unable to watch {} (tried up to {})",
-                        dir_path.display(),
-                        path.display()
-                    ));
-                };
-                path = parent_path;
-            }
+    ) -> Result<bool> {
+        match watcher.as_mut() {
+            Some(watcher) => watch_dir_or_ancestor(watcher, dir_path, root_path),
+            None => Ok(true),
         }
-        Ok(())
     }
 
     /// Create a watcher and start watching by creating `debounced` watcher
@@ -166,8 +157,16 @@ impl DiskWatcher {
         }
 
         #[cfg(not(any(target_os = "macos", target_os = "windows")))]
-        for dir_path in self.watching.iter() {
-            watcher.watch(&dir_path, RecursiveMode::NonRecursive)?;
+        {
+            let mut missing_dirs = Vec::new();
+            for dir_path in self.watching.iter() {
+                if !watch_dir_or_ancestor(&mut watcher, &dir_path, inner.root_path())? {
+                    missing_dirs.push(dir_path.clone());
+                }
+            }
+            for dir_path in missing_dirs {
+                self.watching.remove(&dir_path);
+            }
         }
 
         // We need to invalidate all reads that happened before watching
@@ -265,7 +264,9 @@ impl DiskWatcher {
                             .collect();
 
                         if paths.is_empty() {
-                            return;
+                            // Only ignored paths changed, keep processing the other events
+                            event = rx.try_recv();
+                            continue;
                         }
 
                         // [NOTE] there is attrs in the `Event` struct, which contains few
@@ -408,6 +409,15 @@ impl DiskWatcher {
             let _lock = inner.invalidation_lock.blocking_write();
             {
                 let mut invalidator_map = inner.invalidator_map.lock().unwrap();
+                // Files that are read again are settled first, as the event might have been
+                // reported in the middle of a save
+                inner.changed_paths.lock().unwrap().extend(
+                    batched_invalidate_path
+                        .iter()
+                        .chain(&batched_invalidate_path_and_children)
+                        .map(path_to_key)
+                        .filter(|key| invalidator_map.contains_key(key)),
+                );
                 invalidate_path(
                     &inner,
                     report_invalidation_reason,
@@ -440,6 +450,37 @@ impl DiskWatcher {
     }
 }
 
+/// Watches `dir_path`, or its closest ancestor when it doesn't exist. Returns
+/// whether `dir_path` itself is watched.
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn watch_dir_or_ancestor(
+    watcher: &mut DiskWatcherInternal,
+    dir_path: &Path,
+    root_path: &Path,
+) -> Result<bool> {
+    use anyhow::Context;
+
+    let mut path = dir_path;
+    while let Err(err) = watcher.watch(path, RecursiveMode::NonRecursive) {
+        if path == root_path {
+            return Err(err).context(format!(
+                "Unable to watch {} (tried up to {})",
+                dir_path.display(),
+                path.display()
+            ));
+        }
+        let Some(parent_path) = path.parent() else {
+            return Err(err).context(format!(
+                "Unable to watch {} (tried up to {})",
+                dir_path.display(),
+                path.display()
+            ));
+        };
+        path = parent_path;
+    }
+    Ok(path == dir_path)
+}
+
 #[instrument(parent = None, level = "info", name = "DiskFileSystem file change", skip_all, fields(name = display(path.display())))]
 fn invalidate(
     inner: &DiskFileSystemInner,
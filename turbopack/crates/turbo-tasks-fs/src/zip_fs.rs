@@ -0,0 +1,355 @@
+use std::{collections::BTreeMap, io::Read};
+
+use anyhow::{bail, Context, Result};
+use auto_hash_map::AutoMap;
+use flate2::read::DeflateDecoder;
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{trace::TraceRawVcs, Completion, NonLocalValue, ResolvedVc, ValueToString, Vc};
+
+use crate::{
+    DirectoryContent, DirectoryEntry, File, FileContent, FileMeta, FileSystem, FileSystemPath,
+    LinkContent,
+};
+
+/// A read-only [FileSystem] exposing the contents of a zip archive, e.g. a
+/// package in the Yarn cache.
+///
+/// Only stored and deflated entries are supported, which covers the archives
+/// written by Yarn.
+#[turbo_tasks::value]
+pub struct ZipFileSystem {
+    name: RcStr,
+    archive: ResolvedVc<FileSystemPath>,
+}
+
+#[turbo_tasks::value_impl]
+impl ZipFileSystem {
+    /// Creates a [Vc<ZipFileSystem>] for the given archive. File systems
+    /// created with the same `name` and `archive` are equivalent, while
+    /// different names allow to expose the same archive under multiple
+    /// identities.
+    #[turbo_tasks::function]
+    pub fn new(name: RcStr, archive: ResolvedVc<FileSystemPath>) -> Vc<Self> {
+        ZipFileSystem { name, archive }.cell()
+    }
+
+    #[turbo_tasks::function]
+    pub fn archive(&self) -> Vc<FileSystemPath> {
+        *self.archive
+    }
+
+    #[turbo_tasks::function]
+    async fn entries(&self) -> Result<Vc<ZipEntries>> {
+        let FileContent::Content(file) = &*self.archive.read().await? else {
+            return Ok(Vc::cell(BTreeMap::new()));
+        };
+        let entries = read_entries(&file.content().to_bytes()?)
+            .with_context(|| format!("failed to read the zip archive {}", self.name))?;
+        Ok(Vc::cell(entries))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl FileSystem for ZipFileSystem {
+    #[turbo_tasks::function]
+    async fn read(self: Vc<Self>, fs_path: Vc<FileSystemPath>) -> Result<Vc<FileContent>> {
+        let path = &fs_path.await?.path;
+        let Some(ZipEntry::File(entry)) = self.entries().await?.get(path).copied() else {
+            return Ok(FileContent::NotFound.cell());
+        };
+        let FileContent::Content(file) = &*self.await?.archive.read().await? else {
+            return Ok(FileContent::NotFound.cell());
+        };
+        let content = read_entry(&file.content().to_bytes()?, &entry)
+            .with_context(|| format!("failed to read {path} from a zip archive"))?;
+        Ok(FileContent::Content(File::from(content)).cell())
+    }
+
+    #[turbo_tasks::function]
+    fn read_link(&self, _fs_path: Vc<FileSystemPath>) -> Vc<LinkContent> {
+        LinkContent::NotFound.into()
+    }
+
+    #[turbo_tasks::function]
+    async fn read_dir(self: Vc<Self>, fs_path: Vc<FileSystemPath>) -> Result<Vc<DirectoryContent>> {
+        let path = &fs_path.await?.path;
+        let entries = self.entries().await?;
+        if !path.is_empty() && entries.get(path) != Some(&ZipEntry::Directory) {
+            return Ok(DirectoryContent::not_found());
+        }
+
+        let prefix = if path.is_empty() {
+            String::new()
+        } else {
+            format!("{path}/")
+        };
+        let mut children = AutoMap::new();
+        for (entry_path, entry) in entries.iter() {
+            let Some(name) = entry_path.strip_prefix(&prefix) else {
+                continue;
+            };
+            if name.contains('/') {
+                continue;
+            }
+            let child = fs_path.join(name.into()).to_resolved().await?;
+            children.insert(
+                name.into(),
+                match entry {
+                    ZipEntry::Directory => DirectoryEntry::Directory(child),
+                    ZipEntry::File(_) => DirectoryEntry::File(child),
+                },
+            );
+        }
+        Ok(DirectoryContent::new(children))
+    }
+
+    #[turbo_tasks::function]
+    fn track(&self, _fs_path: Vc<FileSystemPath>) -> Vc<Completion> {
+        self.archive.track()
+    }
+
+    #[turbo_tasks::function]
+    fn write(&self, _fs_path: Vc<FileSystemPath>, _content: Vc<FileContent>) -> Result<Vc<()>> {
+        bail!("Writing is not possible on a zip file system")
+    }
+
+    #[turbo_tasks::function]
+    fn write_link(&self, _fs_path: Vc<FileSystemPath>, _target: Vc<LinkContent>) -> Result<Vc<()>> {
+        bail!("Writing is not possible on a zip file system")
+    }
+
+    #[turbo_tasks::function]
+    fn metadata(&self, _fs_path: Vc<FileSystemPath>) -> Vc<FileMeta> {
+        FileMeta::default().cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for ZipFileSystem {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> Vc<RcStr> {
+        Vc::cell(self.name.clone())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+struct ZipFileEntry {
+    method: u16,
+    compressed_size: u32,
+    uncompressed_size: u32,
+    header_offset: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+enum ZipEntry {
+    Directory,
+    File(ZipFileEntry),
+}
+
+/// The entries of an archive, keyed by their path without a trailing slash.
+#[turbo_tasks::value(transparent)]
+struct ZipEntries(BTreeMap<RcStr, ZipEntry>);
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+const END_OF_CENTRAL_DIRECTORY_SIZE: usize = 22;
+
+fn u16_at(bytes: &[u8], offset: usize) -> Result<u16> {
+    let bytes = bytes
+        .get(offset..offset + 2)
+        .context("unexpected end of archive")?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> Result<u32> {
+    let bytes = bytes
+        .get(offset..offset + 4)
+        .context("unexpected end of archive")?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Reads the central directory of an archive.
+fn read_entries(bytes: &[u8]) -> Result<BTreeMap<RcStr, ZipEntry>> {
+    // The end of central directory record is followed by a comment of up to
+    // 64KiB
+    let end = (0..=bytes.len().saturating_sub(END_OF_CENTRAL_DIRECTORY_SIZE))
+        .rev()
+        .take(u16::MAX as usize + 1)
+        .find(|&offset| u32_at(bytes, offset).ok() == Some(END_OF_CENTRAL_DIRECTORY_SIGNATURE))
+        .context("not a zip archive")?;
+    let count = u16_at(bytes, end + 10)?;
+    let central_directory = u32_at(bytes, end + 16)?;
+    if count == u16::MAX || central_directory == u32::MAX {
+        bail!("zip64 archives are not supported");
+    }
+
+    let mut entries = BTreeMap::new();
+    let mut offset = central_directory as usize;
+    for _ in 0..count {
+        if u32_at(bytes, offset)? != CENTRAL_DIRECTORY_SIGNATURE {
+            bail!("invalid central directory entry at {offset}");
+        }
+        let name_length = u16_at(bytes, offset + 28)? as usize;
+        let extra_length = u16_at(bytes, offset + 30)? as usize;
+        let comment_length = u16_at(bytes, offset + 32)? as usize;
+        let name = bytes
+            .get(offset + 46..offset + 46 + name_length)
+            .context("unexpected end of archive")?;
+        let name = std::str::from_utf8(name).context("invalid file name")?;
+        let entry = if name.ends_with('/') {
+            ZipEntry::Directory
+        } else {
+            ZipEntry::File(ZipFileEntry {
+                method: u16_at(bytes, offset + 10)?,
+                compressed_size: u32_at(bytes, offset + 20)?,
+                uncompressed_size: u32_at(bytes, offset + 24)?,
+                header_offset: u32_at(bytes, offset + 42)?,
+            })
+        };
+        offset += 46 + name_length + extra_length + comment_length;
+
+        let path = name.trim_end_matches('/');
+        if path.is_empty() {
+            continue;
+        }
+        // Archives don't need to list their directories
+        for (index, _) in path.match_indices('/') {
+            entries
+                .entry(path[..index].into())
+                .or_insert(ZipEntry::Directory);
+        }
+        entries.insert(path.into(), entry);
+    }
+    Ok(entries)
+}
+
+fn read_entry(bytes: &[u8], entry: &ZipFileEntry) -> Result<Vec<u8>> {
+    let offset = entry.header_offset as usize;
+    if u32_at(bytes, offset)? != LOCAL_HEADER_SIGNATURE {
+        bail!("invalid local file header at {offset}");
+    }
+    let start =
+        offset + 30 + u16_at(bytes, offset + 26)? as usize + u16_at(bytes, offset + 28)? as usize;
+    let data = bytes
+        .get(start..start + entry.compressed_size as usize)
+        .context("unexpected end of archive")?;
+    match entry.method {
+        0 => Ok(data.to_vec()),
+        8 => {
+            let mut content = Vec::with_capacity(entry.uncompressed_size as usize);
+            DeflateDecoder::new(data).read_to_end(&mut content)?;
+            Ok(content)
+        }
+        method => bail!("unsupported compression method {method}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::DeflateEncoder, Compression};
+
+    use super::*;
+
+    /// Writes an archive with the given entries, deflating the ones marked as
+    /// compressed. Checksums are left empty, as they aren't verified.
+    fn write_archive(files: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        let mut archive = Vec::new();
+        let mut central_directory = Vec::new();
+        for &(name, content, compressed) in files {
+            let (method, data) = if compressed {
+                let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(content).unwrap();
+                (8u16, encoder.finish().unwrap())
+            } else {
+                (0u16, content.to_vec())
+            };
+
+            let header_offset = archive.len() as u32;
+            archive.extend(LOCAL_HEADER_SIGNATURE.to_le_bytes());
+            archive.extend([0; 4]);
+            archive.extend(method.to_le_bytes());
+            archive.extend([0; 8]);
+            archive.extend((data.len() as u32).to_le_bytes());
+            archive.extend((content.len() as u32).to_le_bytes());
+            archive.extend((name.len() as u16).to_le_bytes());
+            archive.extend([0; 2]);
+            archive.extend(name.as_bytes());
+            archive.extend(&data);
+
+            central_directory.extend(CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+            central_directory.extend([0; 6]);
+            central_directory.extend(method.to_le_bytes());
+            central_directory.extend([0; 8]);
+            central_directory.extend((data.len() as u32).to_le_bytes());
+            central_directory.extend((content.len() as u32).to_le_bytes());
+            central_directory.extend((name.len() as u16).to_le_bytes());
+            central_directory.extend([0; 12]);
+            central_directory.extend(header_offset.to_le_bytes());
+            central_directory.extend(name.as_bytes());
+        }
+
+        let central_directory_offset = archive.len() as u32;
+        archive.extend(&central_directory);
+        archive.extend(END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        archive.extend([0; 6]);
+        archive.extend((files.len() as u16).to_le_bytes());
+        archive.extend((central_directory.len() as u32).to_le_bytes());
+        archive.extend(central_directory_offset.to_le_bytes());
+        archive.extend([0; 2]);
+        archive
+    }
+
+    #[test]
+    fn reads_stored_and_deflated_entries() {
+        let archive = write_archive(&[
+            ("node_modules/react/", b"", false),
+            (
+                "node_modules/react/package.json",
+                b"{\"name\":\"react\"}",
+                false,
+            ),
+            (
+                "node_modules/react/cjs/react.development.js",
+                b"module.exports = 'react'",
+                true,
+            ),
+        ]);
+        let entries = read_entries(&archive).unwrap();
+
+        assert_eq!(
+            entries.keys().map(|key| key.as_str()).collect::<Vec<_>>(),
+            vec![
+                "node_modules",
+                "node_modules/react",
+                "node_modules/react/cjs",
+                "node_modules/react/cjs/react.development.js",
+                "node_modules/react/package.json",
+            ]
+        );
+        let Some(ZipEntry::File(entry)) = entries.get("node_modules/react/package.json") else {
+            panic!("expected a file");
+        };
+        assert_eq!(
+            read_entry(&archive, entry).unwrap(),
+            b"{\"name\":\"react\"}"
+        );
+        let Some(ZipEntry::File(entry)) =
+            entries.get("node_modules/react/cjs/react.development.js")
+        else {
+            panic!("expected a file");
+        };
+        assert_eq!(
+            read_entry(&archive, entry).unwrap(),
+            b"module.exports = 'react'"
+        );
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(read_entries(b"module.exports = {}").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+#![feature(arbitrary_self_types)]
+#![feature(arbitrary_self_types_pointers)]
+#![allow(clippy::needless_return)] // tokio macro-generated code doesn't respect this
+
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use anyhow::{bail, Result};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{run_once, State, ValueToString, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileContent, FileSystem, FileSystemPath};
+use turbo_tasks_testing::{register, Registration};
+
+static REGISTRATION: Registration = register!(turbo_tasks_fs::register);
+
+static LINE_COUNT_EXECUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[tokio::test]
+async fn moved_root_hits_persistent_cache() {
+    REGISTRATION.ensure_registered();
+
+    let dir = tempfile::tempdir().unwrap();
+    let before = dir.path().join("before");
+    let after = dir.path().join("after");
+    std::fs::create_dir_all(before.join("src")).unwrap();
+    std::fs::write(before.join("src/lines.txt"), "a\nb\nc\n").unwrap();
+
+    let tt = REGISTRATION.create_turbo_tasks("moved_root_hits_persistent_cache", true);
+    let lines = run_once(tt.clone(), count_lines_in(before.clone()))
+        .await
+        .unwrap();
+    assert_eq!(lines, 3);
+    assert_eq!(LINE_COUNT_EXECUTIONS.load(Ordering::SeqCst), 1);
+    tt.stop_and_wait().await;
+
+    std::fs::rename(&before, &after).unwrap();
+
+    let tt = REGISTRATION.create_turbo_tasks("moved_root_hits_persistent_cache", false);
+    let lines = run_once(tt.clone(), count_lines_in(after)).await.unwrap();
+    assert_eq!(lines, 3);
+    // The file is read again from its new location, but the task taking its
+    // path is restored from the persistent cache.
+    assert_eq!(LINE_COUNT_EXECUTIONS.load(Ordering::SeqCst), 1);
+    tt.stop_and_wait().await;
+}
+
+async fn count_lines_in(root: PathBuf) -> Result<usize> {
+    let workspace = Workspace::new();
+    workspace
+        .await?
+        .root
+        .set(Some(root.to_str().unwrap().into()));
+    let path = workspace.fs().root().join("src/lines.txt".into());
+    Ok(*line_count(path).strongly_consistent().await?)
+}
+
+/// Holds the root of a file system which can change between sessions, like
+/// the project of a `ProjectContainer` in next-api.
+#[turbo_tasks::value]
+struct Workspace {
+    root: State<Option<RcStr>>,
+}
+
+#[turbo_tasks::value_impl]
+impl Workspace {
+    #[turbo_tasks::function]
+    fn new() -> Vc<Self> {
+        Workspace {
+            root: State::new(None),
+        }
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn fs(&self) -> Result<Vc<DiskFileSystem>> {
+        let Some(root) = self.root.get().clone() else {
+            bail!("the workspace root isn't set");
+        };
+        Ok(DiskFileSystem::cell(DiskFileSystem::create(
+            "workspace".into(),
+            root,
+            vec![],
+        )))
+    }
+}
+
+#[turbo_tasks::function]
+async fn line_count(path: Vc<FileSystemPath>) -> Result<Vc<usize>> {
+    LINE_COUNT_EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+    let FileContent::Content(file) = &*path.read().await? else {
+        bail!("{} doesn't exist", path.to_string().await?);
+    };
+    Ok(Vc::cell(file.content().to_str()?.lines().count()))
+}
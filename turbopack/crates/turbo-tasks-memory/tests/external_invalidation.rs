@@ -0,0 +1 @@
+../../turbo-tasks-testing/tests/external_invalidation.rs
\ No newline at end of file
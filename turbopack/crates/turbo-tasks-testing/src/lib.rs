@@ -14,9 +14,11 @@ use std::{
 
 use anyhow::{anyhow, Result};
 use futures::FutureExt;
+use turbo_rcstr::RcStr;
 use turbo_tasks::{
     backend::{CellContent, TaskCollectiblesMap, TypedCellContent},
     event::{Event, EventListener},
+    persistent_kv::PersistentKvStorage,
     registry,
     test_helpers::with_turbo_tasks_for_testing,
     util::{SharedError, StaticOrArc},
@@ -317,6 +319,14 @@ impl TurboTasksApi for VcStorage {
         // no-op
     }
 
+    fn mark_own_task_as_externally_dependent(&self, _task: TaskId, _key: RcStr) {
+        // no-op
+    }
+
+    fn invalidate_external_dependency(&self, _key: &str) -> usize {
+        unimplemented!()
+    }
+
     fn detached_for_testing(
         &self,
         _f: std::pin::Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>,
@@ -327,6 +337,10 @@ impl TurboTasksApi for VcStorage {
     fn stop_and_wait(&self) -> std::pin::Pin<Box<dyn Future<Output = ()> + Send + 'static>> {
         Box::pin(async {})
     }
+
+    fn persistent_kv(&self) -> Option<&dyn PersistentKvStorage> {
+        None
+    }
 }
 
 impl VcStorage {
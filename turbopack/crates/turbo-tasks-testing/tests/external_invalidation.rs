@@ -0,0 +1,73 @@
+#![feature(arbitrary_self_types)]
+#![feature(arbitrary_self_types_pointers)]
+#![allow(clippy::needless_return)] // tokio macro-generated code doesn't respect this
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use anyhow::Result;
+use turbo_tasks::{invalidate_external_dependency, mark_external_dependency, State, Vc};
+use turbo_tasks_testing::{register, run, Registration};
+
+static REGISTRATION: Registration = register!();
+
+static EXTERNAL_VALUE: AtomicU32 = AtomicU32::new(1);
+
+#[tokio::test]
+async fn external_invalidation() {
+    run(&REGISTRATION, || async {
+        EXTERNAL_VALUE.store(1, Ordering::SeqCst);
+        let output = read_external("cms:posts".into());
+        assert_eq!(*output.strongly_consistent().await?, 1);
+
+        EXTERNAL_VALUE.store(2, Ordering::SeqCst);
+        assert_eq!(invalidate_external_dependency("cms:other"), 0);
+        assert_eq!(*output.strongly_consistent().await?, 1);
+
+        assert_eq!(invalidate_external_dependency("cms:posts"), 1);
+        assert_eq!(*output.strongly_consistent().await?, 2);
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn external_invalidation_drops_dependencies_on_reexecution() {
+    run(&REGISTRATION, || async {
+        let input = ChangingKey {
+            key: State::new("cms:drafts".into()),
+        }
+        .cell();
+        let output = read_external_by_key(input);
+        output.strongly_consistent().await?;
+
+        // The task no longer depends on the key it read before
+        input.await?.key.set("cms:posts".into());
+        output.strongly_consistent().await?;
+        assert_eq!(invalidate_external_dependency("cms:drafts"), 0);
+        assert_eq!(invalidate_external_dependency("cms:posts"), 1);
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[turbo_tasks::function]
+fn read_external(key: String) -> Result<Vc<u32>> {
+    mark_external_dependency(&key);
+    Ok(Vc::cell(EXTERNAL_VALUE.load(Ordering::SeqCst)))
+}
+
+#[turbo_tasks::value]
+struct ChangingKey {
+    key: State<String>,
+}
+
+#[turbo_tasks::function]
+async fn read_external_by_key(input: Vc<ChangingKey>) -> Result<Vc<u32>> {
+    let key = (*input.await?.key.get()).clone();
+    mark_external_dependency(&key);
+    Ok(Vc::cell(EXTERNAL_VALUE.load(Ordering::SeqCst)))
+}
@@ -18,6 +18,7 @@ use crate::{
     event::EventListener,
     magic_any::MagicAny,
     manager::{ReadConsistency, TurboTasksBackendApi},
+    persistent_kv::PersistentKvStorage,
     raw_vc::CellId,
     registry,
     task::shared_reference::TypedSharedReference,
@@ -732,6 +733,12 @@ pub trait Backend: Sync + Send {
     ) -> TaskId;
 
     fn dispose_root_task(&self, task: TaskId, turbo_tasks: &dyn TurboTasksBackendApi<Self>);
+
+    /// The storage of [`PersistentKv`][crate::persistent_kv::PersistentKv] namespaces. Only
+    /// backends which persist their cache provide one.
+    fn persistent_kv(&self) -> Option<&dyn PersistentKvStorage> {
+        None
+    }
 }
 
 impl CachedTaskType {
@@ -0,0 +1,315 @@
+//! Invalidation of tasks depending on data turbo-tasks can't observe itself,
+//! e.g. content of a CMS, codegen outputs written outside of the watched
+//! directories or remote schemas.
+//!
+//! A task declares such a dependency with [`mark_external_dependency`] under a
+//! key of its choosing. Pinging that key, either with
+//! [`invalidate_external_dependency`] or through one of the listeners, e.g.
+//! from a CMS webhook, invalidates the task.
+//!
+//! The dependencies are tracked per [`TurboTasks`][crate::TurboTasks]
+//! instance, and the ones of a task are dropped whenever it executes again.
+
+use std::{
+    fmt::{Display, Formatter},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{bail, Result};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    net::TcpListener,
+};
+use turbo_rcstr::RcStr;
+
+use crate::{
+    manager::{current_task, with_turbo_tasks},
+    mark_session_dependent,
+    util::StaticOrArc,
+    FxIndexSet, InvalidationReason, InvalidationReasonKind, TaskId, TurboTasksApi,
+};
+
+/// The external data sources the tasks of a turbo-tasks instance depend on.
+#[derive(Default)]
+pub(crate) struct ExternalDependencies {
+    inner: Mutex<ExternalDependenciesInner>,
+}
+
+#[derive(Default)]
+struct ExternalDependenciesInner {
+    tasks_by_key: FxHashMap<RcStr, FxHashSet<TaskId>>,
+    keys_by_task: FxHashMap<TaskId, FxHashSet<RcStr>>,
+}
+
+impl ExternalDependencies {
+    pub(crate) fn add(&self, task: TaskId, key: RcStr) {
+        let mut inner = self.inner.lock().unwrap();
+        inner
+            .tasks_by_key
+            .entry(key.clone())
+            .or_default()
+            .insert(task);
+        inner.keys_by_task.entry(task).or_default().insert(key);
+    }
+
+    /// Forgets the dependencies of `task`. Called when it starts executing,
+    /// the execution declares the ones it still has.
+    pub(crate) fn remove_task(&self, task: TaskId) {
+        let mut inner = self.inner.lock().unwrap();
+        let Some(keys) = inner.keys_by_task.remove(&task) else {
+            return;
+        };
+        for key in keys {
+            if let Some(tasks) = inner.tasks_by_key.get_mut(&key) {
+                tasks.remove(&task);
+                if tasks.is_empty() {
+                    inner.tasks_by_key.remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Removes and returns the tasks depending on `key`.
+    pub(crate) fn take_dependents(&self, key: &str) -> FxHashSet<TaskId> {
+        let mut inner = self.inner.lock().unwrap();
+        let Some(tasks) = inner.tasks_by_key.remove(key) else {
+            return FxHashSet::default();
+        };
+        for task in &tasks {
+            if let Some(keys) = inner.keys_by_task.get_mut(task) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    inner.keys_by_task.remove(task);
+                }
+            }
+        }
+        tasks
+    }
+}
+
+/// Makes the current task depend on the external data source `key`, so it's
+/// invalidated when `key` is pinged.
+///
+/// The task is also marked as session dependent, as changes happening while no
+/// process is listening can't be observed.
+pub fn mark_external_dependency(key: &str) {
+    mark_session_dependent();
+    with_turbo_tasks(|tt| {
+        tt.mark_own_task_as_externally_dependent(
+            current_task("turbo_tasks::mark_external_dependency()"),
+            key.into(),
+        )
+    });
+}
+
+/// Invalidates all tasks of the current turbo-tasks instance depending on the
+/// external data source `key`. Returns the number of invalidated tasks.
+pub fn invalidate_external_dependency(key: &str) -> usize {
+    with_turbo_tasks(|tt| tt.invalidate_external_dependency(key))
+}
+
+/// Listens on a unix socket at `path` for keys to invalidate in
+/// `turbo_tasks`, one per line. Each line is answered with the number of
+/// invalidated tasks.
+#[cfg(unix)]
+pub async fn listen_for_external_invalidations_on_socket(
+    turbo_tasks: Arc<dyn TurboTasksApi>,
+    path: impl AsRef<std::path::Path>,
+) -> Result<()> {
+    let path = path.as_ref();
+    // A socket left behind by a previous process would make binding fail
+    let _ = std::fs::remove_file(path);
+    let listener = tokio::net::UnixListener::bind(path)?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(handle_socket_connection(turbo_tasks.clone(), stream));
+    }
+}
+
+#[cfg(unix)]
+async fn handle_socket_connection(
+    turbo_tasks: Arc<dyn TurboTasksApi>,
+    stream: impl AsyncRead + AsyncWrite + Unpin,
+) -> Result<()> {
+    let mut stream = BufReader::new(stream);
+    let mut line = String::new();
+    while stream.read_line(&mut line).await? != 0 {
+        let key = line.trim();
+        if !key.is_empty() {
+            let count = turbo_tasks.invalidate_external_dependency(key);
+            stream
+                .get_mut()
+                .write_all(format!("{count}\n").as_bytes())
+                .await?;
+        }
+        line.clear();
+    }
+    Ok(())
+}
+
+/// Listens for HTTP requests on `addr`. `POST /invalidate/<key>` invalidates
+/// the tasks of `turbo_tasks` depending on `key` and responds with their
+/// number.
+///
+/// Requests aren't authenticated, so `addr` must be a loopback address. A
+/// webhook of a remote service has to be forwarded by a proxy which verifies
+/// it.
+pub async fn listen_for_external_invalidations_on_http(
+    turbo_tasks: Arc<dyn TurboTasksApi>,
+    addr: SocketAddr,
+) -> Result<()> {
+    if !addr.ip().is_loopback() {
+        bail!(
+            "External invalidations can only be received on a loopback address, but {addr} was \
+             given"
+        );
+    }
+    let listener = TcpListener::bind(addr).await?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(handle_http_connection(turbo_tasks.clone(), stream));
+    }
+}
+
+async fn handle_http_connection(
+    turbo_tasks: Arc<dyn TurboTasksApi>,
+    stream: impl AsyncRead + AsyncWrite + Unpin,
+) -> Result<()> {
+    let mut stream = BufReader::new(stream);
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).await?;
+    // Skip the headers, the request is fully described by its request line
+    let mut header = String::new();
+    while stream.read_line(&mut header).await? > 2 {
+        header.clear();
+    }
+
+    let (status, body) = match parse_invalidation_request(&request_line) {
+        Ok(key) => (
+            "200 OK",
+            format!("{}\n", turbo_tasks.invalidate_external_dependency(key)),
+        ),
+        Err(status) => (status, String::new()),
+    };
+    stream
+        .get_mut()
+        .write_all(
+            format!(
+                "HTTP/1.1 {status}\r\nContent-Type: text/plain\r\nContent-Length: \
+                 {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .as_bytes(),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Extracts the key from a `POST /invalidate/<key> HTTP/1.1` request line, or
+/// returns the status to respond with.
+fn parse_invalidation_request(request_line: &str) -> Result<&str, &'static str> {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err("400 Bad Request");
+    };
+    let Some(key) = target
+        .strip_prefix("/invalidate/")
+        .filter(|key| !key.is_empty())
+    else {
+        return Err("404 Not Found");
+    };
+    if method != "POST" {
+        return Err("405 Method Not Allowed");
+    }
+    Ok(key)
+}
+
+/// Invalidation was caused by a ping of an external data source.
+#[derive(PartialEq, Eq, Hash)]
+pub struct ExternalChange {
+    pub key: RcStr,
+}
+
+impl InvalidationReason for ExternalChange {
+    fn kind(&self) -> Option<StaticOrArc<dyn InvalidationReasonKind>> {
+        Some(StaticOrArc::Static(&EXTERNAL_CHANGE_KIND))
+    }
+}
+
+impl Display for ExternalChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "external data source {} changed", self.key)
+    }
+}
+
+/// Invalidation kind for [ExternalChange]
+#[derive(PartialEq, Eq, Hash)]
+struct ExternalChangeKind;
+
+static EXTERNAL_CHANGE_KIND: ExternalChangeKind = ExternalChangeKind;
+
+impl InvalidationReasonKind for ExternalChangeKind {
+    fn fmt(
+        &self,
+        reasons: &FxIndexSet<StaticOrArc<dyn InvalidationReason>>,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "{} external data sources changed ({}, ...)",
+            reasons.len(),
+            reasons[0]
+                .as_any()
+                .downcast_ref::<ExternalChange>()
+                .unwrap()
+                .key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashSet;
+
+    use super::{parse_invalidation_request, ExternalDependencies};
+    use crate::TaskId;
+
+    #[test]
+    fn test_external_dependencies() {
+        let dependencies = ExternalDependencies::default();
+        let (first, second) = (TaskId::from(1), TaskId::from(2));
+        dependencies.add(first, "cms:posts".into());
+        dependencies.add(first, "cms:authors".into());
+        dependencies.add(second, "cms:posts".into());
+
+        assert_eq!(
+            dependencies.take_dependents("cms:posts"),
+            FxHashSet::from_iter([first, second])
+        );
+        assert!(dependencies.take_dependents("cms:posts").is_empty());
+
+        // Executing again drops the remaining dependencies of the task
+        dependencies.remove_task(first);
+        assert!(dependencies.take_dependents("cms:authors").is_empty());
+        assert!(dependencies.inner.lock().unwrap().keys_by_task.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalidation_request() {
+        assert_eq!(
+            parse_invalidation_request("POST /invalidate/cms:posts HTTP/1.1\r\n"),
+            Ok("cms:posts")
+        );
+        assert_eq!(
+            parse_invalidation_request("GET /invalidate/cms:posts HTTP/1.1\r\n"),
+            Err("405 Method Not Allowed")
+        );
+        assert_eq!(
+            parse_invalidation_request("POST /invalidate/ HTTP/1.1\r\n"),
+            Err("404 Not Found")
+        );
+        assert_eq!(parse_invalidation_request("\r\n"), Err("400 Bad Request"));
+    }
+}
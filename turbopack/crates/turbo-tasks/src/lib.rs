@@ -49,6 +49,7 @@ mod display;
 pub mod duration_span;
 mod effect;
 pub mod event;
+mod external_invalidation;
 pub mod graph;
 mod id;
 mod id_factory;
@@ -65,6 +66,7 @@ mod no_move_vec;
 mod once_map;
 mod output;
 pub mod persisted_graph;
+pub mod persistent_kv;
 pub mod primitives;
 mod raw_vc;
 mod read_ref;
@@ -92,6 +94,12 @@ pub use collectibles::CollectiblesSource;
 pub use completion::{Completion, Completions};
 pub use display::ValueToString;
 pub use effect::{apply_effects, effect, get_effects, Effects};
+#[cfg(unix)]
+pub use external_invalidation::listen_for_external_invalidations_on_socket;
+pub use external_invalidation::{
+    invalidate_external_dependency, listen_for_external_invalidations_on_http,
+    mark_external_dependency, ExternalChange,
+};
 pub use id::{
     ExecutionId, FunctionId, LocalTaskId, SessionId, TaskId, TraitTypeId, ValueTypeId,
     TRANSIENT_TASK_BIT,
@@ -21,7 +21,8 @@ use rustc_hash::FxHasher;
 use serde::{Deserialize, Serialize};
 use tokio::{runtime::Handle, select, task_local};
 use tokio_util::task::TaskTracker;
-use tracing::{info_span, instrument, trace_span, Instrument, Level, Span};
+use tracing::{info_span, instrument, trace, trace_span, Instrument, Level, Span};
+use turbo_rcstr::RcStr;
 use turbo_tasks_malloc::TurboMalloc;
 
 use crate::{
@@ -31,12 +32,14 @@ use crate::{
     },
     capture_future::{self, CaptureFuture},
     event::{Event, EventListener},
+    external_invalidation::{ExternalChange, ExternalDependencies},
     id::{
         BackendJobId, ExecutionId, FunctionId, LocalCellId, LocalTaskId, TraitTypeId,
         TRANSIENT_TASK_BIT,
     },
     id_factory::{IdFactory, IdFactoryWithReuse},
     magic_any::MagicAny,
+    persistent_kv::PersistentKvStorage,
     raw_vc::{CellId, RawVc},
     registry::{self, get_function},
     serialization_invalidation::SerializationInvalidator,
@@ -184,6 +187,12 @@ pub trait TurboTasksApi: TurboTasksCallApi + Sync + Send {
     fn update_own_task_cell(&self, task: TaskId, index: CellId, content: CellContent);
     fn mark_own_task_as_finished(&self, task: TaskId);
     fn mark_own_task_as_session_dependent(&self, task: TaskId);
+    /// Makes `task` depend on the external data source `key` until it
+    /// executes again, see [`crate::mark_external_dependency`].
+    fn mark_own_task_as_externally_dependent(&self, task: TaskId, key: RcStr);
+    /// Invalidates the tasks depending on the external data source `key`,
+    /// returning their number.
+    fn invalidate_external_dependency(&self, key: &str) -> usize;
 
     fn connect_task(&self, task: TaskId);
 
@@ -197,6 +206,10 @@ pub trait TurboTasksApi: TurboTasksCallApi + Sync + Send {
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>;
 
     fn stop_and_wait(&self) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+
+    /// The storage of [`PersistentKv`][crate::persistent_kv::PersistentKv] namespaces, if the
+    /// backend persists its cache.
+    fn persistent_kv(&self) -> Option<&dyn PersistentKvStorage>;
 }
 
 /// A wrapper around a value that is unused.
@@ -360,6 +373,10 @@ pub enum ReadConsistency {
     Strong,
 }
 
+/// The target of the trace events reporting the number of tasks waiting for a
+/// thread to execute on, recorded in a `queue_depth` field.
+const QUEUE_DEPTH_TARGET: &str = "turbo_tasks::queue_depth";
+
 pub struct TurboTasks<B: Backend + 'static> {
     this: Weak<Self>,
     backend: B,
@@ -371,6 +388,8 @@ pub struct TurboTasks<B: Backend + 'static> {
     currently_scheduled_foreground_jobs: AtomicUsize,
     currently_scheduled_background_jobs: AtomicUsize,
     scheduled_tasks: AtomicUsize,
+    /// Tasks that have been scheduled but haven't started executing yet.
+    queued_tasks: AtomicUsize,
     start: Mutex<Option<Instant>>,
     aggregated_update: Mutex<(Option<(Duration, usize)>, InvalidationReasonSet)>,
     event: Event,
@@ -378,6 +397,7 @@ pub struct TurboTasks<B: Backend + 'static> {
     event_foreground: Event,
     event_background: Event,
     program_start: Instant,
+    external_dependencies: ExternalDependencies,
 }
 
 /// Information about a "global" task. A global task can contain multiple "local" tasks (see
@@ -485,6 +505,7 @@ impl<B: Backend + 'static> TurboTasks<B> {
             currently_scheduled_background_jobs: AtomicUsize::new(0),
             currently_scheduled_foreground_jobs: AtomicUsize::new(0),
             scheduled_tasks: AtomicUsize::new(0),
+            queued_tasks: AtomicUsize::new(0),
             start: Default::default(),
             aggregated_update: Default::default(),
             event: Event::new(|| "TurboTasks::event".to_string()),
@@ -492,6 +513,7 @@ impl<B: Backend + 'static> TurboTasks<B> {
             event_foreground: Event::new(|| "TurboTasks::event_foreground".to_string()),
             event_background: Event::new(|| "TurboTasks::event_background".to_string()),
             program_start: Instant::now(),
+            external_dependencies: Default::default(),
         });
         this.backend.startup(&*this);
         this
@@ -755,12 +777,16 @@ impl<B: Backend + 'static> TurboTasks<B> {
     pub(crate) fn schedule(&self, task_id: TaskId) {
         self.begin_primary_job();
         self.scheduled_tasks.fetch_add(1, Ordering::AcqRel);
+        let queue_depth = self.queued_tasks.fetch_add(1, Ordering::AcqRel) + 1;
+        trace!(target: QUEUE_DEPTH_TARGET, queue_depth);
 
         #[cfg(feature = "tokio_tracing")]
         let description = self.backend.get_task_description(task_id);
 
         let this = self.pin();
         let future = async move {
+            let queue_depth = this.queued_tasks.fetch_sub(1, Ordering::AcqRel) - 1;
+            trace!(target: QUEUE_DEPTH_TARGET, queue_depth);
             let mut schedule_again = true;
             while schedule_again {
                 let backend_state = this.backend.new_task_state(task_id);
@@ -784,6 +810,7 @@ impl<B: Backend + 'static> TurboTasks<B> {
                     else {
                         return false;
                     };
+                    this.external_dependencies.remove_task(task_id);
 
                     async {
                         let (result, duration, memory_usage) =
@@ -1412,6 +1439,19 @@ impl<B: Backend + 'static> TurboTasksApi for TurboTasks<B> {
         self.backend.mark_own_task_as_session_dependent(task, self);
     }
 
+    fn mark_own_task_as_externally_dependent(&self, task: TaskId, key: RcStr) {
+        self.external_dependencies.add(task, key);
+    }
+
+    fn invalidate_external_dependency(&self, key: &str) -> usize {
+        let tasks = self.external_dependencies.take_dependents(key);
+        let reason: Arc<dyn InvalidationReason> = Arc::new(ExternalChange { key: key.into() });
+        for &task in &tasks {
+            self.invalidate_with_reason(task, reason.clone().into());
+        }
+        tasks.len()
+    }
+
     /// Creates a future that inherits the current task id and task state. The current global task
     /// will wait for this future to be dropped before exiting.
     fn detached_for_testing(
@@ -1441,6 +1481,10 @@ impl<B: Backend + 'static> TurboTasksApi for TurboTasks<B> {
             this.stop_and_wait().await;
         })
     }
+
+    fn persistent_kv(&self) -> Option<&dyn PersistentKvStorage> {
+        self.backend.persistent_kv()
+    }
 }
 
 impl<B: Backend + 'static> TurboTasksBackendApi<B> for TurboTasks<B> {
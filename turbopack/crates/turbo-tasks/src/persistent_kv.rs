@@ -0,0 +1,130 @@
+//! A typed key-value store for integrations, backed by the persistent cache of the backend.
+//!
+//! Integrations like the font cache or the license collector keep data across sessions which isn't
+//! the output of a task, e.g. downloaded files. Instead of writing their own files next to the
+//! cache, they declare a [`PersistentKv`] namespace:
+//!
+//! ```ignore
+//! static LICENSES: PersistentKv<License> = PersistentKv::new("licenses", 1);
+//!
+//! LICENSES.set(&package_key, &license)?;
+//! let license = LICENSES.get(&package_key)?;
+//! ```
+//!
+//! Each namespace has a version, entries written with another version are treated as missing, so
+//! changing the type of the values only needs a version bump. Each namespace has a quota of bytes
+//! too, writes which would exceed it are skipped.
+//!
+//! The store is only available when the backend persists its cache. Otherwise reads return `None`
+//! and writes are skipped, so integrations have to handle missing entries anyway.
+//!
+//! Reads aren't tracked as dependencies of the current task. Since entries are only written by
+//! integrations, which use them as a cache of their own inputs, this is fine as long as the same
+//! key always maps to an equivalent value.
+
+use std::marker::PhantomData;
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::manager::with_turbo_tasks;
+
+/// The quota of a namespace, unless configured with [`PersistentKv::with_quota`].
+pub const DEFAULT_QUOTA: u64 = 64 * 1024 * 1024;
+
+/// The untyped storage of the namespaces, implemented by persisting backends.
+pub trait PersistentKvStorage: Send + Sync {
+    /// Reads the value of `key` in `namespace`, if it was written with `version`.
+    fn get(&self, namespace: &str, version: u32, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    /// Writes the value of `key` in `namespace`, or removes it when `value` is `None`. Returns
+    /// `false` without writing when the values of the namespace would exceed `quota` bytes.
+    fn set(
+        &self,
+        namespace: &str,
+        version: u32,
+        key: &[u8],
+        value: Option<&[u8]>,
+        quota: u64,
+    ) -> Result<bool>;
+}
+
+/// A namespace of the persistent key-value store, with values of type `T`. See the [module
+/// documentation][self].
+pub struct PersistentKv<T> {
+    namespace: &'static str,
+    version: u32,
+    quota: u64,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> PersistentKv<T> {
+    /// Creates a namespace. The `version` has to be changed when the serialized form of `T`
+    /// changes.
+    pub const fn new(namespace: &'static str, version: u32) -> Self {
+        Self {
+            namespace,
+            version,
+            quota: DEFAULT_QUOTA,
+            _value: PhantomData,
+        }
+    }
+
+    /// Limits the size of all values in the namespace to `quota` bytes.
+    pub const fn with_quota(self, quota: u64) -> Self {
+        Self { quota, ..self }
+    }
+
+    pub fn namespace(&self) -> &'static str {
+        self.namespace
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> PersistentKv<T> {
+    /// Reads the value of `key`. Values which can't be deserialized, e.g. because they were written
+    /// by an integration which forgot to bump the version, are treated as missing.
+    pub fn get(&self, key: &str) -> Result<Option<T>> {
+        let Some(value) = with_turbo_tasks(|tt| {
+            tt.persistent_kv()
+                .map(|kv| kv.get(self.namespace, self.version, key.as_bytes()))
+                .transpose()
+        })?
+        .flatten() else {
+            return Ok(None);
+        };
+        Ok(serde_json::from_slice(&value).ok())
+    }
+
+    /// Writes the value of `key`. Returns whether it was stored, i.e. whether the store is
+    /// available and the value fits into the quota of the namespace.
+    pub fn set(&self, key: &str, value: &T) -> Result<bool> {
+        let value = serde_json::to_vec(value).with_context(|| {
+            format!(
+                "Unable to serialize the value of {key} in the persistent namespace {}",
+                self.namespace
+            )
+        })?;
+        self.set_raw(key, Some(&value))
+    }
+
+    /// Removes the value of `key`.
+    pub fn remove(&self, key: &str) -> Result<()> {
+        self.set_raw(key, None)?;
+        Ok(())
+    }
+
+    fn set_raw(&self, key: &str, value: Option<&[u8]>) -> Result<bool> {
+        with_turbo_tasks(|tt| {
+            let Some(kv) = tt.persistent_kv() else {
+                return Ok(false);
+            };
+            kv.set(
+                self.namespace,
+                self.version,
+                key.as_bytes(),
+                value,
+                self.quota,
+            )
+        })
+    }
+}
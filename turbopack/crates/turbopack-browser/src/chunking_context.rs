@@ -7,10 +7,12 @@ use turbopack_core::{
     chunk::{
         availability_info::AvailabilityInfo,
         chunk_group::{make_chunk_group, MakeChunkGroupResult},
+        chunking::{ChunkCacheGroups, ChunkModuleGroups, ChunkSizeLimits},
         module_id_strategies::{DevModuleIdStrategy, ModuleIdStrategy},
         Chunk, ChunkGroupResult, ChunkItem, ChunkableModule, ChunkingContext,
-        EntryChunkGroupResult, EvaluatableAssets, MinifyType, ModuleId,
+        EntryChunkGroupResult, EvaluatableAssets, MinifyType, ModuleId, ResourceHint,
     },
+    code_builder::Code,
     environment::Environment,
     ident::AssetIdent,
     module::Module,
@@ -25,7 +27,7 @@ use turbopack_ecmascript_runtime::RuntimeType;
 
 use crate::ecmascript::{
     chunk::EcmascriptDevChunk,
-    evaluate::chunk::EcmascriptDevEvaluateChunk,
+    evaluate::{chunk::EcmascriptDevEvaluateChunk, runtime_chunk::EcmascriptBrowserRuntimeChunk},
     list::asset::{EcmascriptDevChunkList, EcmascriptDevChunkListSource},
 };
 
@@ -64,6 +66,14 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn chunk_fallback_base_path(
+        mut self,
+        chunk_fallback_base_path: ResolvedVc<Option<RcStr>>,
+    ) -> Self {
+        self.chunking_context.chunk_fallback_base_path = chunk_fallback_base_path;
+        self
+    }
+
     pub fn reference_chunk_source_maps(mut self, source_maps: bool) -> Self {
         self.chunking_context.reference_chunk_source_maps = source_maps;
         self
@@ -79,6 +89,16 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn chunk_loading_global(mut self, chunk_loading_global: RcStr) -> Self {
+        self.chunking_context.chunk_loading_global = Some(chunk_loading_global);
+        self
+    }
+
+    pub fn esm_chunks(mut self, esm_chunks: bool) -> Self {
+        self.chunking_context.esm_chunks = esm_chunks;
+        self
+    }
+
     pub fn manifest_chunks(mut self, manifest_chunks: bool) -> Self {
         self.chunking_context.manifest_chunks = manifest_chunks;
         self
@@ -89,6 +109,16 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn content_hashing(mut self, content_hashing: bool) -> Self {
+        self.chunking_context.content_hashing = content_hashing;
+        self
+    }
+
+    pub fn runtime_chunk(mut self, runtime_chunk: bool) -> Self {
+        self.chunking_context.runtime_chunk = runtime_chunk;
+        self
+    }
+
     pub fn module_id_strategy(
         mut self,
         module_id_strategy: ResolvedVc<Box<dyn ModuleIdStrategy>>,
@@ -97,6 +127,34 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn chunk_cache_groups(mut self, chunk_cache_groups: ResolvedVc<ChunkCacheGroups>) -> Self {
+        self.chunking_context.chunk_cache_groups = chunk_cache_groups;
+        self
+    }
+
+    pub fn chunk_module_groups(
+        mut self,
+        chunk_module_groups: ResolvedVc<ChunkModuleGroups>,
+    ) -> Self {
+        self.chunking_context.chunk_module_groups = chunk_module_groups;
+        self
+    }
+
+    pub fn chunk_size_limits(mut self, chunk_size_limits: ResolvedVc<ChunkSizeLimits>) -> Self {
+        self.chunking_context.chunk_size_limits = chunk_size_limits;
+        self
+    }
+
+    pub fn split_ordered_chunks(mut self, split_ordered_chunks: bool) -> Self {
+        self.chunking_context.split_ordered_chunks = split_ordered_chunks;
+        self
+    }
+
+    pub fn max_ordered_chunk_size(mut self, max_ordered_chunk_size: usize) -> Self {
+        self.chunking_context.max_ordered_chunk_size = max_ordered_chunk_size;
+        self
+    }
+
     pub fn build(self) -> Vc<BrowserChunkingContext> {
         BrowserChunkingContext::new(Value::new(self.chunking_context))
     }
@@ -133,6 +191,10 @@ pub struct BrowserChunkingContext {
     /// Base path that will be prepended to all chunk URLs when loading them.
     /// This path will not appear in chunk paths or chunk data.
     chunk_base_path: ResolvedVc<Option<RcStr>>,
+    /// Base path that chunks are retried from when loading them from
+    /// `chunk_base_path` fails, e.g. when a CDN is unreachable or a chunk
+    /// fails its integrity check.
+    chunk_fallback_base_path: ResolvedVc<Option<RcStr>>,
     /// URL prefix that will be prepended to all static asset URLs when loading
     /// them.
     asset_base_path: ResolvedVc<Option<RcStr>>,
@@ -146,10 +208,34 @@ pub struct BrowserChunkingContext {
     runtime_type: RuntimeType,
     /// Whether to minify resulting chunks
     minify_type: MinifyType,
+    /// Whether chunk file names contain a hash of their content
+    content_hashing: bool,
+    /// Whether the runtime is emitted as a chunk of its own instead of being
+    /// part of the evaluate chunks
+    runtime_chunk: bool,
+    /// Whether chunks are ES modules, which are loaded with `import()`
+    /// instead of `<script>` elements
+    esm_chunks: bool,
+    /// The global chunks are registered in, `TURBOPACK` by default, which must
+    /// be a valid identifier. Builds whose chunks are loaded on the same page
+    /// need globals of their own, so that each is registered with its own
+    /// runtime, which has its own registries of chunks and module ids.
+    chunk_loading_global: Option<RcStr>,
     /// Whether to use manifest chunks for lazy compilation
     manifest_chunks: bool,
     /// The module id strategy to use
     module_id_strategy: ResolvedVc<Box<dyn ModuleIdStrategy>>,
+    /// The groups of packages which are placed in named chunks of their own
+    chunk_cache_groups: ResolvedVc<ChunkCacheGroups>,
+    /// Groups of packages or modules which are never split across chunks
+    chunk_module_groups: ResolvedVc<ChunkModuleGroups>,
+    /// The sizes chunks are split and merged towards
+    chunk_size_limits: ResolvedVc<ChunkSizeLimits>,
+    /// Whether CSS of a chunk group is split into multiple chunks, in order
+    split_ordered_chunks: bool,
+    /// The size the CSS chunk of a chunk group is split at, when it isn't
+    /// split otherwise
+    max_ordered_chunk_size: usize,
 }
 
 impl BrowserChunkingContext {
@@ -176,14 +262,24 @@ impl BrowserChunkingContext {
                 reference_css_chunk_source_maps: true,
                 asset_root_path,
                 chunk_base_path: ResolvedVc::cell(None),
+                chunk_fallback_base_path: ResolvedVc::cell(None),
                 asset_base_path: ResolvedVc::cell(None),
                 enable_hot_module_replacement: false,
                 enable_tracing: false,
                 environment,
                 runtime_type,
                 minify_type: MinifyType::NoMinify,
+                content_hashing: false,
+                runtime_chunk: false,
+                esm_chunks: false,
+                chunk_loading_global: None,
                 manifest_chunks: false,
                 module_id_strategy: ResolvedVc::upcast(DevModuleIdStrategy::new_resolved()),
+                chunk_cache_groups: ResolvedVc::cell(Vec::new()),
+                chunk_module_groups: ResolvedVc::cell(Vec::new()),
+                chunk_size_limits: ChunkSizeLimits::default().resolved_cell(),
+                split_ordered_chunks: false,
+                max_ordered_chunk_size: usize::MAX,
             },
         }
     }
@@ -198,15 +294,40 @@ impl BrowserChunkingContext {
         self.runtime_type
     }
 
+    /// Returns the path chunks are placed at.
+    pub fn chunk_root_path(&self) -> Vc<FileSystemPath> {
+        *self.chunk_root_path
+    }
+
     /// Returns the asset base path.
     pub fn chunk_base_path(&self) -> Vc<Option<RcStr>> {
         *self.chunk_base_path
     }
 
+    /// Returns the chunk fallback base path.
+    pub fn chunk_fallback_base_path(&self) -> Vc<Option<RcStr>> {
+        *self.chunk_fallback_base_path
+    }
+
     /// Returns the minify type.
     pub fn minify_type(&self) -> MinifyType {
         self.minify_type
     }
+
+    /// Returns whether the runtime is emitted as a chunk of its own.
+    pub fn runtime_chunk(&self) -> bool {
+        self.runtime_chunk
+    }
+
+    /// Returns whether chunks are emitted as ES modules.
+    pub fn esm_chunks(&self) -> bool {
+        self.esm_chunks
+    }
+
+    /// Returns the global chunks are registered in.
+    pub fn chunk_loading_global(&self) -> &str {
+        self.chunk_loading_global.as_deref().unwrap_or("TURBOPACK")
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -216,6 +337,103 @@ impl BrowserChunkingContext {
         this.into_value().cell()
     }
 
+    #[turbo_tasks::function]
+    async fn chunk_group_with_name(
+        self: Vc<Self>,
+        ident: Vc<AssetIdent>,
+        module: ResolvedVc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> Result<Vc<ChunkGroupResult>> {
+        let span = tracing::info_span!("chunking", ident = ident.to_string().await?.to_string());
+        async move {
+            let this = self.await?;
+            let input_availability_info = availability_info.into_value();
+            let MakeChunkGroupResult {
+                chunks,
+                availability_info,
+            } = make_chunk_group(
+                Vc::upcast(self),
+                [ResolvedVc::upcast(module)],
+                input_availability_info,
+                chunk_name.clone(),
+            )
+            .await?;
+
+            let mut assets = chunks
+                .iter()
+                .map(|chunk| self.generate_chunk(**chunk).to_resolved())
+                .try_join()
+                .await?;
+
+            if this.enable_hot_module_replacement {
+                let mut ident = ident;
+                // The chunks of a named chunk group differ from the unnamed one
+                if let Some(chunk_name) = &chunk_name {
+                    ident = ident.with_modifier(Vc::cell(chunk_name.clone()));
+                }
+                match input_availability_info {
+                    AvailabilityInfo::Root => {}
+                    AvailabilityInfo::Untracked => {
+                        ident = ident.with_modifier(Vc::cell("untracked".into()));
+                    }
+                    AvailabilityInfo::Complete {
+                        available_chunk_items,
+                    } => {
+                        ident = ident.with_modifier(Vc::cell(
+                            available_chunk_items.hash().await?.to_string().into(),
+                        ));
+                    }
+                }
+                assets.push(
+                    self.generate_chunk_list_register_chunk(
+                        ident,
+                        EvaluatableAssets::empty(),
+                        Vc::cell(assets.clone()),
+                        Value::new(EcmascriptDevChunkListSource::Dynamic),
+                    )
+                    .to_resolved()
+                    .await?,
+                );
+            }
+
+            Ok(ChunkGroupResult {
+                assets: ResolvedVc::cell(assets),
+                availability_info,
+            }
+            .cell())
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// The code of the runtime, which is part of the evaluate chunks, or of
+    /// the runtime chunk when `runtime_chunk` is enabled.
+    #[turbo_tasks::function]
+    pub(crate) async fn runtime_code(self: Vc<Self>) -> Result<Vc<Code>> {
+        let this = self.await?;
+        Ok(match this.runtime_type {
+            RuntimeType::Development | RuntimeType::Production => {
+                turbopack_ecmascript_runtime::get_browser_runtime_code(
+                    *this.environment,
+                    *this.chunk_base_path,
+                    *this.chunk_fallback_base_path,
+                    Value::new(this.runtime_type),
+                    *this.output_root_to_root_path,
+                    this.esm_chunks,
+                    this.chunk_loading_global().into(),
+                )
+            }
+            #[cfg(feature = "test")]
+            RuntimeType::Dummy => turbopack_ecmascript_runtime::get_dummy_runtime_code().cell(),
+        })
+    }
+
+    #[turbo_tasks::function]
+    fn generate_runtime_chunk(self: Vc<Self>) -> Vc<Box<dyn OutputAsset>> {
+        Vc::upcast(EcmascriptBrowserRuntimeChunk::new(self))
+    }
+
     #[turbo_tasks::function]
     fn generate_evaluate_chunk(
         self: Vc<Self>,
@@ -373,6 +591,11 @@ impl ChunkingContext for BrowserChunkingContext {
         Ok(self.asset_root_path.join(asset_path.into()))
     }
 
+    #[turbo_tasks::function]
+    fn content_hashing(&self) -> Vc<bool> {
+        Vc::cell(self.content_hashing)
+    }
+
     #[turbo_tasks::function]
     fn is_hot_module_replacement_enabled(&self) -> Vc<bool> {
         Vc::cell(self.enable_hot_module_replacement)
@@ -389,67 +612,49 @@ impl ChunkingContext for BrowserChunkingContext {
     }
 
     #[turbo_tasks::function]
-    async fn chunk_group(
+    fn chunk_cache_groups(&self) -> Vc<ChunkCacheGroups> {
+        *self.chunk_cache_groups
+    }
+
+    #[turbo_tasks::function]
+    fn chunk_module_groups(&self) -> Vc<ChunkModuleGroups> {
+        *self.chunk_module_groups
+    }
+
+    #[turbo_tasks::function]
+    fn chunk_size_limits(&self) -> Vc<ChunkSizeLimits> {
+        *self.chunk_size_limits
+    }
+
+    #[turbo_tasks::function]
+    fn split_ordered_chunks(&self) -> Vc<bool> {
+        Vc::cell(self.split_ordered_chunks)
+    }
+
+    #[turbo_tasks::function]
+    fn max_ordered_chunk_size(&self) -> Vc<usize> {
+        Vc::cell(self.max_ordered_chunk_size)
+    }
+
+    #[turbo_tasks::function]
+    fn chunk_group(
         self: Vc<Self>,
         ident: Vc<AssetIdent>,
-        module: ResolvedVc<Box<dyn ChunkableModule>>,
+        module: Vc<Box<dyn ChunkableModule>>,
         availability_info: Value<AvailabilityInfo>,
-    ) -> Result<Vc<ChunkGroupResult>> {
-        let span = tracing::info_span!("chunking", ident = ident.to_string().await?.to_string());
-        async move {
-            let this = self.await?;
-            let input_availability_info = availability_info.into_value();
-            let MakeChunkGroupResult {
-                chunks,
-                availability_info,
-            } = make_chunk_group(
-                Vc::upcast(self),
-                [ResolvedVc::upcast(module)],
-                input_availability_info,
-            )
-            .await?;
-
-            let mut assets = chunks
-                .iter()
-                .map(|chunk| self.generate_chunk(**chunk).to_resolved())
-                .try_join()
-                .await?;
-
-            if this.enable_hot_module_replacement {
-                let mut ident = ident;
-                match input_availability_info {
-                    AvailabilityInfo::Root => {}
-                    AvailabilityInfo::Untracked => {
-                        ident = ident.with_modifier(Vc::cell("untracked".into()));
-                    }
-                    AvailabilityInfo::Complete {
-                        available_chunk_items,
-                    } => {
-                        ident = ident.with_modifier(Vc::cell(
-                            available_chunk_items.hash().await?.to_string().into(),
-                        ));
-                    }
-                }
-                assets.push(
-                    self.generate_chunk_list_register_chunk(
-                        ident,
-                        EvaluatableAssets::empty(),
-                        Vc::cell(assets.clone()),
-                        Value::new(EcmascriptDevChunkListSource::Dynamic),
-                    )
-                    .to_resolved()
-                    .await?,
-                );
-            }
+    ) -> Vc<ChunkGroupResult> {
+        self.chunk_group_with_name(ident, module, None, availability_info)
+    }
 
-            Ok(ChunkGroupResult {
-                assets: ResolvedVc::cell(assets),
-                availability_info,
-            }
-            .cell())
-        }
-        .instrument(span)
-        .await
+    #[turbo_tasks::function]
+    fn named_chunk_group(
+        self: Vc<Self>,
+        ident: Vc<AssetIdent>,
+        module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: RcStr,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> Vc<ChunkGroupResult> {
+        self.chunk_group_with_name(ident, module, Some(chunk_name), availability_info)
     }
 
     #[turbo_tasks::function]
@@ -476,7 +681,7 @@ impl ChunkingContext for BrowserChunkingContext {
             let MakeChunkGroupResult {
                 chunks,
                 availability_info,
-            } = make_chunk_group(Vc::upcast(self), entries, availability_info).await?;
+            } = make_chunk_group(Vc::upcast(self), entries, availability_info, None).await?;
 
             let mut assets: Vec<ResolvedVc<Box<dyn OutputAsset>>> = chunks
                 .iter()
@@ -505,6 +710,13 @@ impl ChunkingContext for BrowserChunkingContext {
                     .await?,
             );
 
+            // The runtime chunk is shared by all chunk groups and registers
+            // the chunks that were loaded before it, so it's not one of the
+            // other chunks the evaluate chunk waits for.
+            if this.runtime_chunk {
+                assets.insert(0, self.generate_runtime_chunk().to_resolved().await?);
+            }
+
             Ok(ChunkGroupResult {
                 assets: ResolvedVc::cell(assets),
                 availability_info,
@@ -536,17 +748,25 @@ impl ChunkingContext for BrowserChunkingContext {
     async fn async_loader_chunk_item(
         self: Vc<Self>,
         module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
+        resource_hint: Option<ResourceHint>,
         availability_info: Value<AvailabilityInfo>,
     ) -> Result<Vc<Box<dyn ChunkItem>>> {
         Ok(if self.await?.manifest_chunks {
             let manifest_asset =
-                ManifestAsyncModule::new(module, Vc::upcast(self), availability_info);
+                ManifestAsyncModule::new(module, Vc::upcast(self), chunk_name, availability_info);
             Vc::upcast(ManifestLoaderChunkItem::new(
                 manifest_asset,
                 Vc::upcast(self),
             ))
         } else {
-            let module = AsyncLoaderModule::new(module, Vc::upcast(self), availability_info);
+            let module = AsyncLoaderModule::new(
+                module,
+                Vc::upcast(self),
+                chunk_name,
+                resource_hint,
+                availability_info,
+            );
             Vc::upcast(module.as_chunk_item(Vc::upcast(self)))
         })
     }
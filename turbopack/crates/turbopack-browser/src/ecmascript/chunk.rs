@@ -3,7 +3,10 @@ use turbo_rcstr::RcStr;
 use turbo_tasks::{FxIndexSet, ResolvedVc, ValueToString, Vc};
 use turbopack_core::{
     asset::{Asset, AssetContent},
-    chunk::{Chunk, ChunkingContext, OutputChunk, OutputChunkRuntimeInfo},
+    chunk::{
+        content_hash::content_hashed_path, Chunk, ChunkingContext, OutputChunk,
+        OutputChunkRuntimeInfo,
+    },
     ident::AssetIdent,
     introspect::{Introspectable, IntrospectableChildren},
     output::{OutputAsset, OutputAssets},
@@ -83,9 +86,17 @@ impl EcmascriptDevChunk {
 #[turbo_tasks::value_impl]
 impl OutputAsset for EcmascriptDevChunk {
     #[turbo_tasks::function]
-    fn ident(&self) -> Vc<AssetIdent> {
-        let ident = self.chunk.ident().with_modifier(modifier());
-        AssetIdent::from_path(self.chunking_context.chunk_path(ident, ".js".into()))
+    async fn ident(self: Vc<Self>) -> Result<Vc<AssetIdent>> {
+        let this = self.await?;
+        let ident = this.chunk.ident().with_modifier(modifier());
+        let path = this.chunking_context.chunk_path(ident, ".js".into());
+        Ok(AssetIdent::from_path(
+            if *this.chunking_context.content_hashing().await? {
+                content_hashed_path(path, self.own_content().content_hash())
+            } else {
+                path
+            },
+        ))
     }
 
     #[turbo_tasks::function]
@@ -5,6 +5,7 @@ use indoc::writedoc;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{ResolvedVc, Vc};
 use turbo_tasks_fs::File;
+use turbo_tasks_hash::Xxh3Hash64Hasher;
 use turbopack_core::{
     asset::AssetContent,
     chunk::{ChunkingContext, MinifyType, ModuleId},
@@ -51,6 +52,19 @@ impl EcmascriptDevChunkContent {
     pub fn entries(&self) -> Vc<EcmascriptDevChunkContentEntries> {
         *self.entries
     }
+
+    /// A hash of the code of the chunk items. Unlike the code of the chunk, it
+    /// doesn't depend on the path of the chunk, which is derived from it when
+    /// content hashing is enabled.
+    #[turbo_tasks::function]
+    pub(crate) async fn content_hash(&self) -> Result<Vc<u64>> {
+        let mut hasher = Xxh3Hash64Hasher::new();
+        for (id, entry) in self.entries.await?.iter() {
+            hasher.write_ref(&**id);
+            hasher.write_value(*entry.hash.await?);
+        }
+        Ok(Vc::cell(hasher.finish()))
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -88,15 +102,21 @@ impl EcmascriptDevChunkContent {
         // When the runtime executes (see the `evaluate` module), it will pick up and
         // register all pending chunks, and replace the list of pending chunks
         // with itself so later chunks can register directly with it.
+        let chunking_context = this.chunking_context.await?;
         writedoc!(
             code,
             r#"
-                (globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([{chunk_path}, {{
+                (globalThis.{global} = globalThis.{global} || []).push([{chunk_path}, {{
             "#,
+            global = chunking_context.chunk_loading_global(),
             chunk_path = StringifyJs(chunk_server_path)
         )?;
 
+        let minify_type = chunking_context.minify_type();
         for (id, entry) in this.entries.await?.iter() {
+            if matches!(minify_type, MinifyType::NoMinify) {
+                write!(code, "\n{}", &*entry.provenance.await?)?;
+            }
             write!(code, "\n{}: ", StringifyJs(&id))?;
             code.push_code(&*entry.code.await?);
             write!(code, ",")?;
@@ -116,10 +136,7 @@ impl EcmascriptDevChunkContent {
         }
 
         let code = code.build().cell();
-        if matches!(
-            this.chunking_context.await?.minify_type(),
-            MinifyType::Minify
-        ) {
+        if matches!(minify_type, MinifyType::Minify) {
             return Ok(minify(chunk_path_vc, code));
         }
 
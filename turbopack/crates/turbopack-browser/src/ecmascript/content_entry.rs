@@ -2,6 +2,7 @@ use std::io::Write as _;
 
 use anyhow::Result;
 use tracing::{info_span, Instrument};
+use turbo_rcstr::RcStr;
 use turbo_tasks::{FxIndexMap, ReadRef, ResolvedVc, TryJoinIterExt, ValueToString, Vc};
 use turbopack_core::{
     chunk::{AsyncModuleInfo, ChunkItem, ChunkItemExt, ModuleId},
@@ -10,7 +11,7 @@ use turbopack_core::{
     issue::{code_gen::CodeGenerationIssue, IssueExt, IssueSeverity, StyledString},
 };
 use turbopack_ecmascript::chunk::{
-    EcmascriptChunkContent, EcmascriptChunkItem, EcmascriptChunkItemExt,
+    chunk_item_provenance, EcmascriptChunkContent, EcmascriptChunkItem, EcmascriptChunkItemExt,
 };
 
 /// A chunk item's content entry.
@@ -24,6 +25,8 @@ use turbopack_ecmascript::chunk::{
 pub struct EcmascriptDevChunkContentEntry {
     pub code: ResolvedVc<Code>,
     pub hash: ResolvedVc<u64>,
+    /// Written in front of the code in non-minified chunks.
+    pub provenance: ResolvedVc<RcStr>,
 }
 
 impl EcmascriptDevChunkContentEntry {
@@ -35,6 +38,9 @@ impl EcmascriptDevChunkContentEntry {
         Ok(EcmascriptDevChunkContentEntry {
             code,
             hash: code.source_code_hash().to_resolved().await?,
+            provenance: chunk_item_provenance(Vc::upcast(chunk_item))
+                .to_resolved()
+                .await?,
         })
     }
 }
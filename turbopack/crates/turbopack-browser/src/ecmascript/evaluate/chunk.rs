@@ -6,11 +6,12 @@ use serde::Serialize;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{ReadRef, ResolvedVc, TryJoinIterExt, Value, ValueToString, Vc};
 use turbo_tasks_fs::File;
+use turbo_tasks_hash::Xxh3Hash64Hasher;
 use turbopack_core::{
     asset::{Asset, AssetContent},
     chunk::{
-        ChunkData, ChunkItemExt, ChunkableModule, ChunkingContext, ChunksData, EvaluatableAssets,
-        MinifyType, ModuleId,
+        content_hash::content_hashed_path, ChunkData, ChunkItemExt, ChunkableModule,
+        ChunkingContext, ChunksData, EvaluatableAssets, MinifyType, ModuleId,
     },
     code_builder::{Code, CodeBuilder},
     ident::AssetIdent,
@@ -23,12 +24,12 @@ use turbopack_ecmascript::{
     minify::minify,
     utils::StringifyJs,
 };
-use turbopack_ecmascript_runtime::RuntimeType;
 
 use crate::BrowserChunkingContext;
 
 /// An Ecmascript chunk that:
-/// * Contains the Turbopack dev runtime code; and
+/// * Contains the Turbopack dev runtime code, unless it's emitted as a
+///   runtime chunk; and
 /// * Evaluates a list of runtime entries.
 #[turbo_tasks::value(shared)]
 pub(crate) struct EcmascriptDevEvaluateChunk {
@@ -62,14 +63,37 @@ impl EcmascriptDevEvaluateChunk {
         ChunkData::from_assets(self.chunking_context.output_root(), *self.other_chunks)
     }
 
+    /// A hash of the runtime code, unless it's in the runtime chunk, the paths
+    /// of the other chunks and the ids of the entries. Unlike the code of the
+    /// chunk, it doesn't depend on the path of the chunk, which is derived
+    /// from it when content hashing is enabled.
+    #[turbo_tasks::function]
+    async fn content_hash(self: Vc<Self>) -> Result<Vc<u64>> {
+        let this = self.await?;
+        let mut hasher = Xxh3Hash64Hasher::new();
+        if !this.chunking_context.await?.runtime_chunk() {
+            hasher.write_value(
+                *this
+                    .chunking_context
+                    .runtime_code()
+                    .source_code_hash()
+                    .await?,
+            );
+        }
+        for &chunk_data in self.chunks_data().await?.iter() {
+            hasher.write_ref(&chunk_data.await?.path);
+        }
+        for entry in this.evaluatable_assets.await?.iter() {
+            hasher.write_ref(&*entry.ident().to_string().await?);
+        }
+        Ok(Vc::cell(hasher.finish()))
+    }
+
     #[turbo_tasks::function]
     async fn code(self: Vc<Self>) -> Result<Vc<Code>> {
         let this = self.await?;
-        let chunking_context = this.chunking_context.await?;
-        let environment = this.chunking_context.environment();
 
         let output_root = this.chunking_context.output_root().await?;
-        let output_root_to_root_path = this.chunking_context.output_root_to_root_path();
         let chunk_path_vc = self.ident().path();
         let chunk_path = chunk_path_vc.await?;
         let chunk_public_path = if let Some(path) = output_root.get_path_to(&chunk_path) {
@@ -127,10 +151,11 @@ impl EcmascriptDevEvaluateChunk {
         // We still use the `TURBOPACK` global variable to store the chunk here,
         // as there may be another runtime already loaded in the page.
         // This is the case in integration tests.
+        let chunking_context = this.chunking_context.await?;
         writedoc!(
             code,
             r#"
-                (globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+                (globalThis.{global} = globalThis.{global} || []).push([
                     {},
                     {{}},
                     {}
@@ -138,32 +163,11 @@ impl EcmascriptDevEvaluateChunk {
             "#,
             StringifyJs(&chunk_public_path),
             StringifyJs(&params),
+            global = chunking_context.chunk_loading_global(),
         )?;
 
-        match chunking_context.runtime_type() {
-            RuntimeType::Development => {
-                let runtime_code = turbopack_ecmascript_runtime::get_browser_runtime_code(
-                    environment,
-                    chunking_context.chunk_base_path(),
-                    Value::new(chunking_context.runtime_type()),
-                    output_root_to_root_path,
-                );
-                code.push_code(&*runtime_code.await?);
-            }
-            RuntimeType::Production => {
-                let runtime_code = turbopack_ecmascript_runtime::get_browser_runtime_code(
-                    environment,
-                    chunking_context.chunk_base_path(),
-                    Value::new(chunking_context.runtime_type()),
-                    output_root_to_root_path,
-                );
-                code.push_code(&*runtime_code.await?);
-            }
-            #[cfg(feature = "test")]
-            RuntimeType::Dummy => {
-                let runtime_code = turbopack_ecmascript_runtime::get_dummy_runtime_code();
-                code.push_code(&runtime_code);
-            }
+        if !chunking_context.runtime_chunk() {
+            code.push_code(&*this.chunking_context.runtime_code().await?);
         }
 
         if code.has_source_map() {
@@ -178,10 +182,7 @@ impl EcmascriptDevEvaluateChunk {
         }
 
         let code = code.build().cell();
-        if matches!(
-            this.chunking_context.await?.minify_type(),
-            MinifyType::Minify
-        ) {
+        if matches!(chunking_context.minify_type(), MinifyType::Minify) {
             return Ok(minify(chunk_path_vc, code));
         }
 
@@ -205,12 +206,13 @@ fn modifier() -> Vc<RcStr> {
 #[turbo_tasks::value_impl]
 impl OutputAsset for EcmascriptDevEvaluateChunk {
     #[turbo_tasks::function]
-    async fn ident(&self) -> Result<Vc<AssetIdent>> {
-        let mut ident = self.ident.await?.clone_value();
+    async fn ident(self: Vc<Self>) -> Result<Vc<AssetIdent>> {
+        let this = self.await?;
+        let mut ident = this.ident.await?.clone_value();
 
         ident.add_modifier(modifier().to_resolved().await?);
 
-        let evaluatable_assets = self.evaluatable_assets.await?;
+        let evaluatable_assets = this.evaluatable_assets.await?;
         ident.modifiers.extend(
             evaluatable_assets
                 .iter()
@@ -220,7 +222,7 @@ impl OutputAsset for EcmascriptDevEvaluateChunk {
         );
 
         ident.modifiers.extend(
-            self.other_chunks
+            this.other_chunks
                 .await?
                 .iter()
                 .map(|chunk| chunk.ident().to_string().to_resolved())
@@ -229,8 +231,13 @@ impl OutputAsset for EcmascriptDevEvaluateChunk {
         );
 
         let ident = AssetIdent::new(Value::new(ident));
+        let path = this.chunking_context.chunk_path(ident, ".js".into());
         Ok(AssetIdent::from_path(
-            self.chunking_context.chunk_path(ident, ".js".into()),
+            if *this.chunking_context.content_hashing().await? {
+                content_hashed_path(path, self.content_hash())
+            } else {
+                path
+            },
         ))
     }
 
@@ -0,0 +1,140 @@
+use std::io::Write;
+
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, ValueToString, Vc};
+use turbo_tasks_fs::File;
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    chunk::{content_hash::content_hashed_path, MinifyType},
+    code_builder::{Code, CodeBuilder},
+    ident::AssetIdent,
+    output::{OutputAsset, OutputAssets},
+    source_map::{GenerateSourceMap, OptionSourceMap, SourceMapAsset},
+};
+use turbopack_ecmascript::minify::minify;
+
+use crate::BrowserChunkingContext;
+
+/// An Ecmascript chunk that only contains the Turbopack runtime code. It's
+/// emitted when the runtime isn't part of the evaluate chunks, so that it's
+/// shared by all chunk groups and changes to them don't change it.
+#[turbo_tasks::value(shared)]
+pub(crate) struct EcmascriptBrowserRuntimeChunk {
+    chunking_context: ResolvedVc<BrowserChunkingContext>,
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptBrowserRuntimeChunk {
+    /// Creates a new [`Vc<EcmascriptBrowserRuntimeChunk>`].
+    #[turbo_tasks::function]
+    pub fn new(chunking_context: ResolvedVc<BrowserChunkingContext>) -> Vc<Self> {
+        EcmascriptBrowserRuntimeChunk { chunking_context }.cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn code(self: Vc<Self>) -> Result<Vc<Code>> {
+        let this = self.await?;
+        let chunk_path_vc = self.ident().path();
+        let chunk_path = chunk_path_vc.await?;
+
+        let mut code = CodeBuilder::default();
+
+        // The runtime only installs itself when the `TURBOPACK` global is an
+        // array, which it isn't when no chunk was loaded before it.
+        writeln!(
+            code,
+            "globalThis.{global} = globalThis.{global} || [];",
+            global = this.chunking_context.await?.chunk_loading_global()
+        )?;
+
+        code.push_code(&*this.chunking_context.runtime_code().await?);
+
+        if code.has_source_map() {
+            let filename = chunk_path.file_name();
+            write!(
+                code,
+                // findSourceMapURL assumes this co-located sourceMappingURL,
+                // and needs to be adjusted in case this is ever changed.
+                "\n\n//# sourceMappingURL={}.map",
+                urlencoding::encode(filename)
+            )?;
+        }
+
+        let code = code.build().cell();
+        if matches!(
+            this.chunking_context.await?.minify_type(),
+            MinifyType::Minify
+        ) {
+            return Ok(minify(chunk_path_vc, code));
+        }
+
+        Ok(code)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for EcmascriptBrowserRuntimeChunk {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> Vc<RcStr> {
+        Vc::cell("Ecmascript Browser Runtime Chunk".into())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for EcmascriptBrowserRuntimeChunk {
+    #[turbo_tasks::function]
+    async fn ident(&self) -> Result<Vc<AssetIdent>> {
+        let chunking_context = self.chunking_context.await?;
+        let path = chunking_context
+            .chunk_root_path()
+            .join("turbopack-runtime.js".into());
+        Ok(AssetIdent::from_path(
+            if *self.chunking_context.content_hashing().await? {
+                content_hashed_path(
+                    path,
+                    self.chunking_context.runtime_code().source_code_hash(),
+                )
+            } else {
+                path
+            },
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn references(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
+        let this = self.await?;
+        let mut references = Vec::new();
+
+        if *this
+            .chunking_context
+            .reference_chunk_source_maps(Vc::upcast(self))
+            .await?
+        {
+            references.push(ResolvedVc::upcast(
+                SourceMapAsset::new(Vc::upcast(self)).to_resolved().await?,
+            ));
+        }
+
+        Ok(Vc::cell(references))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for EcmascriptBrowserRuntimeChunk {
+    #[turbo_tasks::function]
+    async fn content(self: Vc<Self>) -> Result<Vc<AssetContent>> {
+        let code = self.code().await?;
+        Ok(AssetContent::file(
+            File::from(code.source_code().clone()).into(),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl GenerateSourceMap for EcmascriptBrowserRuntimeChunk {
+    #[turbo_tasks::function]
+    fn generate_source_map(self: Vc<Self>) -> Vc<OptionSourceMap> {
+        self.code().generate_source_map()
+    }
+}
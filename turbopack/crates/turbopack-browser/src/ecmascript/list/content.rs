@@ -3,6 +3,7 @@ use std::io::Write;
 use anyhow::{Context, Result};
 use indoc::writedoc;
 use serde::Serialize;
+use turbo_rcstr::RcStr;
 use turbo_tasks::{FxIndexMap, IntoTraitRef, ResolvedVc, TryJoinIterExt, Vc};
 use turbo_tasks_fs::File;
 use turbopack_core::{
@@ -26,6 +27,7 @@ use super::{
 #[turbo_tasks::value]
 pub(super) struct EcmascriptDevChunkListContent {
     chunk_list_path: String,
+    chunk_loading_global: RcStr,
     pub(super) chunks_contents: FxIndexMap<String, ResolvedVc<Box<dyn VersionedContent>>>,
     source: EcmascriptDevChunkListSource,
 }
@@ -42,6 +44,11 @@ impl EcmascriptDevChunkListContent {
                 .get_path_to(&*chunk_list.ident().path().await?)
                 .context("chunk list path not in output root")?
                 .to_string(),
+            chunk_loading_global: chunk_list_ref
+                .chunking_context
+                .await?
+                .chunk_loading_global()
+                .into(),
             chunks_contents: chunk_list_ref
                 .chunks
                 .await?
@@ -126,14 +133,15 @@ impl EcmascriptDevChunkListContent {
         writedoc!(
             code,
             r#"
-                (globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+                (globalThis.{global} = globalThis.{global} || []).push([
                     {},
                     {{}},
                 ]);
-                (globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({:#});
+                (globalThis.{global}_CHUNK_LISTS = globalThis.{global}_CHUNK_LISTS || []).push({:#});
             "#,
             StringifyJs(&this.chunk_list_path),
             StringifyJs(&params),
+            global = this.chunk_loading_global,
         )?;
 
         Ok(Code::cell(code.build()))
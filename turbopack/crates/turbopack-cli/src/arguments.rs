@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use clap::{Args, Parser};
+use clap::{Args, Parser, ValueEnum};
 use turbopack_cli_utils::issue::IssueSeverityCliOption;
 
 #[derive(Debug, Parser)]
@@ -107,4 +107,33 @@ pub struct BuildArguments {
     /// Don't minify build output.
     #[clap(long)]
     pub no_minify: bool,
+
+    /// Bundle each entry into a single file targeting Node.js, which
+    /// contains the runtime and all chunks of the entry, e.g. to build a
+    /// package, a serverless function or a script.
+    #[clap(long)]
+    pub single_file: bool,
+
+    /// The module format of single-file bundles.
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = BundleFormatCliOption::Cjs,
+        requires = "single_file"
+    )]
+    pub format: BundleFormatCliOption,
+
+    /// A package which is loaded from `node_modules` at runtime instead of
+    /// being included in single-file bundles, including its subpaths. Can be
+    /// repeated.
+    #[clap(long = "external", value_name = "PACKAGE", requires = "single_file")]
+    pub externals: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BundleFormatCliOption {
+    /// CommonJS, written to `<entry>.js`.
+    Cjs,
+    /// ECMAScript modules, written to `<entry>.mjs`.
+    Esm,
 }
@@ -20,7 +20,7 @@ use turbopack_core::{
         availability_info::AvailabilityInfo, ChunkableModule, ChunkingContext, ChunkingContextExt,
         EvaluatableAsset, EvaluatableAssets, MinifyType,
     },
-    environment::{BrowserEnvironment, Environment, ExecutionEnvironment},
+    environment::{BrowserEnvironment, Environment, ExecutionEnvironment, NodeJsEnvironment},
     issue::{handle_issues, IssueReporter, IssueSeverity},
     module::Module,
     output::{OutputAsset, OutputAssets},
@@ -29,16 +29,20 @@ use turbopack_core::{
     resolve::{
         origin::{PlainResolveOrigin, ResolveOriginExt},
         parse::Request,
+        ExternalType,
     },
 };
 use turbopack_ecmascript_runtime::RuntimeType;
 use turbopack_env::dotenv::load_env;
 use turbopack_node::execution_context::ExecutionContext;
-use turbopack_nodejs::NodeJsChunkingContext;
+use turbopack_nodejs::{BundleFormat, NodeJsChunkingContext};
 
 use crate::{
-    arguments::BuildArguments,
-    contexts::{get_client_asset_context, get_client_compile_time_info, NodeEnv},
+    arguments::{BuildArguments, BundleFormatCliOption},
+    contexts::{
+        get_client_asset_context, get_client_compile_time_info, get_node_asset_context,
+        get_node_compile_time_info, NodeEnv,
+    },
     util::{
         normalize_dirs, normalize_entries, output_fs, project_fs, EntryRequest, EntryRequests,
         NormalizedDirs,
@@ -60,6 +64,8 @@ pub struct TurbopackBuildBuilder {
     show_all: bool,
     log_detail: bool,
     minify_type: MinifyType,
+    single_file: Option<BundleFormat>,
+    externals: Vec<RcStr>,
 }
 
 impl TurbopackBuildBuilder {
@@ -78,6 +84,8 @@ impl TurbopackBuildBuilder {
             show_all: false,
             log_detail: false,
             minify_type: MinifyType::Minify,
+            single_file: None,
+            externals: vec![],
         }
     }
 
@@ -111,6 +119,20 @@ impl TurbopackBuildBuilder {
         self
     }
 
+    /// Bundles each entry into a single file of the given format targeting
+    /// Node.js.
+    pub fn single_file(mut self, format: BundleFormat) -> Self {
+        self.single_file = Some(format);
+        self
+    }
+
+    /// Loads `package` from `node_modules` at runtime instead of including it
+    /// in single-file bundles.
+    pub fn external(mut self, package: RcStr) -> Self {
+        self.externals.push(package);
+        self
+    }
+
     pub async fn build(self) -> Result<()> {
         let task = self.turbo_tasks.spawn_once_task::<(), _>(async move {
             let build_result_op = build_internal(
@@ -126,6 +148,8 @@ impl TurbopackBuildBuilder {
                 .resolved_cell(),
                 self.browserslist_query,
                 self.minify_type,
+                self.single_file,
+                self.externals,
             );
 
             // Await the result to propagate any errors.
@@ -169,16 +193,22 @@ async fn build_internal(
     entry_requests: ResolvedVc<EntryRequests>,
     browserslist_query: RcStr,
     minify_type: MinifyType,
+    single_file: Option<BundleFormat>,
+    externals: Vec<RcStr>,
 ) -> Result<Vc<()>> {
-    let env = Environment::new(Value::new(ExecutionEnvironment::Browser(
-        BrowserEnvironment {
-            dom: true,
-            web_worker: false,
-            service_worker: false,
-            browserslist_query: browserslist_query.clone(),
-        }
-        .resolved_cell(),
-    )))
+    let env = Environment::new(Value::new(if single_file.is_some() {
+        ExecutionEnvironment::NodeJsLambda(NodeJsEnvironment::default().resolved_cell())
+    } else {
+        ExecutionEnvironment::Browser(
+            BrowserEnvironment {
+                dom: true,
+                web_worker: false,
+                service_worker: false,
+                browserslist_query: browserslist_query.clone(),
+            }
+            .resolved_cell(),
+        )
+    }))
     .to_resolved()
     .await?;
     let output_fs = output_fs(project_dir.clone());
@@ -219,15 +249,28 @@ async fn build_internal(
         .build(),
     );
 
-    let compile_time_info = get_client_compile_time_info(browserslist_query, node_env);
     let execution_context =
         ExecutionContext::new(*root_path, chunking_context, load_env(*root_path));
-    let asset_context = get_client_asset_context(
-        *project_path,
-        execution_context,
-        compile_time_info,
-        node_env,
-    );
+    let asset_context = if let Some(format) = single_file {
+        get_node_asset_context(
+            *project_path,
+            execution_context,
+            get_node_compile_time_info(node_env),
+            node_env,
+            externals,
+            match format {
+                BundleFormat::CommonJs => ExternalType::CommonJs,
+                BundleFormat::Esm => ExternalType::EcmaScriptModule,
+            },
+        )
+    } else {
+        get_client_asset_context(
+            *project_path,
+            execution_context,
+            get_client_compile_time_info(browserslist_query, node_env),
+            node_env,
+        )
+    };
 
     let entry_requests = (*entry_requests
         .await?
@@ -275,6 +318,45 @@ async fn build_internal(
         .try_join()
         .await?;
 
+    if let Some(format) = single_file {
+        let chunking_context =
+            Vc::try_resolve_downcast_type::<NodeJsChunkingContext>(chunking_context)
+                .await?
+                .unwrap();
+        let extension = match format {
+            BundleFormat::CommonJs => "js",
+            BundleFormat::Esm => "mjs",
+        };
+        let bundles = entries
+            .iter()
+            .map(|&entry_module| async move {
+                chunking_context
+                    .single_file_bundle(
+                        build_output_root
+                            .join(
+                                entry_module
+                                    .ident()
+                                    .path()
+                                    .file_stem()
+                                    .await?
+                                    .as_deref()
+                                    .unwrap()
+                                    .into(),
+                            )
+                            .with_extension(extension.into()),
+                        *entry_module,
+                        EvaluatableAssets::empty(),
+                        format,
+                    )
+                    .to_resolved()
+                    .await
+            })
+            .try_join()
+            .await?;
+        emit_assets(vec![Vc::cell(bundles)]).await?;
+        return Ok(Default::default());
+    }
+
     let entry_chunk_groups = entries
         .into_iter()
         .map(|entry_module| async move {
@@ -323,8 +405,15 @@ async fn build_internal(
         .try_join()
         .await?;
 
+    emit_assets(entry_chunk_groups).await?;
+
+    Ok(Default::default())
+}
+
+/// Writes the given chunk groups and all assets referenced by them.
+async fn emit_assets(chunk_groups: Vec<Vc<OutputAssets>>) -> Result<()> {
     let mut chunks: HashSet<ResolvedVc<Box<dyn OutputAsset>>> = HashSet::new();
-    for chunk_group in entry_chunk_groups {
+    for chunk_group in chunk_groups {
         chunks.extend(&*all_assets_from_entries(chunk_group).await?);
     }
 
@@ -334,7 +423,7 @@ async fn build_internal(
         .try_join()
         .await?;
 
-    Ok(Default::default())
+    Ok(())
 }
 
 pub async fn build(args: &BuildArguments) -> Result<()> {
@@ -363,6 +452,16 @@ pub async fn build(args: &BuildArguments) -> Result<()> {
         })
         .show_all(args.common.show_all);
 
+    if args.single_file {
+        builder = builder.single_file(match args.format {
+            BundleFormatCliOption::Cjs => BundleFormat::CommonJs,
+            BundleFormatCliOption::Esm => BundleFormat::Esm,
+        });
+        for package in &args.externals {
+            builder = builder.external(package.as_str().into());
+        }
+    }
+
     for entry in normalize_entries(&args.common.entries) {
         builder = builder.entry_request(EntryRequest::Relative(entry));
     }
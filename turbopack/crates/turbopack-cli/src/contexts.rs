@@ -18,8 +18,11 @@ use turbopack_core::{
     compile_time_info::{CompileTimeDefines, CompileTimeInfo},
     condition::ContextCondition,
     context::AssetContext,
-    environment::{BrowserEnvironment, Environment, ExecutionEnvironment},
-    resolve::options::{ImportMap, ImportMapping},
+    environment::{BrowserEnvironment, Environment, ExecutionEnvironment, NodeJsEnvironment},
+    resolve::{
+        options::{ImportMap, ImportMapping},
+        ExternalTraced, ExternalType,
+    },
 };
 use turbopack_ecmascript_plugins::transform::{
     emotion::{EmotionTransformConfig, EmotionTransformer},
@@ -86,6 +89,7 @@ pub async fn get_client_resolve_options_context(
     let next_client_import_map = get_client_import_map(project_path).to_resolved().await?;
     let module_options_context = ResolveOptionsContext {
         enable_node_modules: Some(project_path.root().to_resolved().await?),
+        enable_yarn_pnp: Some(project_path.to_resolved().await?),
         custom_conditions: vec![node_env.await?.to_string().into(), "browser".into()],
         import_map: Some(next_client_import_map),
         browser: true,
@@ -104,6 +108,46 @@ pub async fn get_client_resolve_options_context(
     .cell())
 }
 
+/// The resolve options of single-file bundles, which run in Node.js.
+/// `externals` are packages (and their subpaths) which are loaded at runtime
+/// instead of being bundled.
+#[turbo_tasks::function]
+pub async fn get_node_resolve_options_context(
+    project_path: Vc<FileSystemPath>,
+    node_env: Vc<NodeEnv>,
+    externals: Vec<RcStr>,
+    external_type: ExternalType,
+) -> Result<Vc<ResolveOptionsContext>> {
+    let mut import_map = ImportMap::empty();
+    let external =
+        ImportMapping::External(None, external_type, ExternalTraced::Untraced).resolved_cell();
+    for package in externals {
+        import_map.insert_exact_alias(package.clone(), external);
+        import_map.insert_wildcard_alias(format!("{package}/"), external);
+    }
+
+    let module_options_context = ResolveOptionsContext {
+        enable_node_modules: Some(project_path.root().to_resolved().await?),
+        enable_yarn_pnp: Some(project_path.to_resolved().await?),
+        enable_node_native_modules: true,
+        enable_node_externals: true,
+        custom_conditions: vec![node_env.await?.to_string().into(), "node".into()],
+        import_map: Some(import_map.resolved_cell()),
+        module: true,
+        ..Default::default()
+    };
+    Ok(ResolveOptionsContext {
+        enable_typescript: true,
+        enable_react: true,
+        rules: vec![(
+            foreign_code_context_condition().await?,
+            module_options_context.clone().resolved_cell(),
+        )],
+        ..module_options_context
+    }
+    .cell())
+}
+
 #[turbo_tasks::function]
 async fn get_client_module_options_context(
     project_path: Vc<FileSystemPath>,
@@ -208,6 +252,33 @@ pub fn get_client_asset_context(
     asset_context
 }
 
+#[turbo_tasks::function]
+pub fn get_node_asset_context(
+    project_path: Vc<FileSystemPath>,
+    execution_context: Vc<ExecutionContext>,
+    compile_time_info: Vc<CompileTimeInfo>,
+    node_env: Vc<NodeEnv>,
+    externals: Vec<RcStr>,
+    external_type: ExternalType,
+) -> Vc<Box<dyn AssetContext>> {
+    let resolve_options_context =
+        get_node_resolve_options_context(project_path, node_env, externals, external_type);
+    let module_options_context = get_client_module_options_context(
+        project_path,
+        execution_context,
+        compile_time_info.environment(),
+        node_env,
+    );
+
+    Vc::upcast(ModuleAssetContext::new(
+        Default::default(),
+        compile_time_info,
+        module_options_context,
+        resolve_options_context,
+        Vc::cell("node".into()),
+    ))
+}
+
 fn client_defines(node_env: &NodeEnv) -> Vc<CompileTimeDefines> {
     compile_time_defines!(
         process.turbopack = true,
@@ -239,3 +310,17 @@ pub async fn get_client_compile_time_info(
     .cell()
     .await
 }
+
+#[turbo_tasks::function]
+pub async fn get_node_compile_time_info(node_env: Vc<NodeEnv>) -> Result<Vc<CompileTimeInfo>> {
+    CompileTimeInfo::builder(
+        Environment::new(Value::new(ExecutionEnvironment::NodeJsLambda(
+            NodeJsEnvironment::default().resolved_cell(),
+        )))
+        .to_resolved()
+        .await?,
+    )
+    .defines(client_defines(&*node_env.await?).to_resolved().await?)
+    .cell()
+    .await
+}
@@ -0,0 +1,140 @@
+use std::{fs, path::Path, process::Command, sync::Once};
+
+use anyhow::Result;
+use clap::Parser;
+use turbopack_cli::{arguments::Arguments, build::build};
+
+static REGISTER: Once = Once::new();
+
+/// Writes a project whose entry loads a module through a dynamic import and
+/// the `external-pkg` package from `node_modules`.
+fn write_project(dir: &Path) -> Result<()> {
+    fs::write(
+        dir.join("index.js"),
+        r#"export const name = "single-file";
+
+export async function loadLazy() {
+  const { lazy } = await import("./lazy.js");
+  return lazy;
+}
+
+export async function externalGreeting() {
+  const { greeting } = await import("external-pkg");
+  return greeting;
+}
+"#,
+    )?;
+    fs::write(
+        dir.join("lazy.js"),
+        "export const lazy = \"lazy module\";\n",
+    )?;
+
+    let package_dir = dir.join("node_modules/external-pkg");
+    fs::create_dir_all(&package_dir)?;
+    fs::write(
+        package_dir.join("package.json"),
+        r#"{ "name": "external-pkg", "main": "index.js" }"#,
+    )?;
+    fs::write(
+        package_dir.join("index.js"),
+        "exports.greeting = \"greeting from node_modules\";\n",
+    )?;
+    Ok(())
+}
+
+/// Builds `index.js` of a project into a single file with the given `format`,
+/// and returns the files emitted into `dist`.
+async fn build_single_file(dir: &Path, format: &str) -> Result<Vec<String>> {
+    REGISTER.call_once(turbopack_cli::register);
+
+    let Arguments::Build(args) = Arguments::parse_from([
+        "turbopack-cli",
+        "build",
+        "--dir",
+        dir.to_str().unwrap(),
+        "--single-file",
+        "--format",
+        format,
+        "--external",
+        "external-pkg",
+        "--no-minify",
+        "./index.js",
+    ]) else {
+        unreachable!()
+    };
+    build(&args).await?;
+
+    let mut files = fs::read_dir(dir.join("dist"))?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<_>>>()?;
+    files.retain(|file| !file.ends_with(".map"));
+    files.sort();
+    Ok(files)
+}
+
+fn run_node(dir: &Path, script: &str) -> Result<String> {
+    let output = Command::new("node").arg(script).current_dir(dir).output()?;
+    assert!(
+        output.status.success(),
+        "node {script} failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bundles_commonjs_into_single_file() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_project(dir.path())?;
+
+    let files = build_single_file(dir.path(), "cjs").await?;
+    assert_eq!(files, ["index.js"]);
+
+    // The dynamically imported module is part of the bundle, the external
+    // package isn't
+    let bundle = fs::read_to_string(dir.path().join("dist/index.js"))?;
+    assert!(bundle.contains("lazy module"));
+    assert!(!bundle.contains("greeting from node_modules"));
+
+    fs::write(
+        dir.path().join("run.cjs"),
+        r#"const bundle = require("./dist/index.js");
+(async () => {
+  console.log(bundle.name, await bundle.loadLazy(), await bundle.externalGreeting());
+})();
+"#,
+    )?;
+    assert_eq!(
+        run_node(dir.path(), "run.cjs")?,
+        "single-file lazy module greeting from node_modules\n"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bundles_esm_into_single_file() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    write_project(dir.path())?;
+
+    let files = build_single_file(dir.path(), "esm").await?;
+    assert_eq!(files, ["index.mjs"]);
+
+    let bundle = fs::read_to_string(dir.path().join("dist/index.mjs"))?;
+    assert!(bundle.contains("lazy module"));
+    assert!(!bundle.contains("greeting from node_modules"));
+
+    fs::write(
+        dir.path().join("run.mjs"),
+        r#"import { name, loadLazy, externalGreeting } from "./dist/index.mjs";
+
+console.log(name, await loadLazy(), await externalGreeting());
+"#,
+    )?;
+    assert_eq!(
+        run_node(dir.path(), "run.mjs")?,
+        "single-file lazy module greeting from node_modules\n"
+    );
+
+    Ok(())
+}
@@ -75,13 +75,16 @@ impl AvailableChunkItems {
         } else {
             hasher.write_value(0u64);
         }
-        let item_idents = self
+        let mut item_idents = self
             .chunk_items
             .await?
             .iter()
             .map(|(&chunk_item, _)| chunk_item.asset_ident().to_string())
             .try_join()
             .await?;
+        // The hash names chunk lists, so it must not depend on the order in which the chunk
+        // items were discovered.
+        item_idents.sort();
         for ident in item_idents {
             hasher.write_value(ident);
         }
@@ -2,6 +2,7 @@ use std::collections::HashSet;
 
 use anyhow::Result;
 use auto_hash_map::AutoSet;
+use turbo_rcstr::RcStr;
 use turbo_tasks::{
     FxIndexMap, FxIndexSet, ResolvedVc, TryFlatJoinIterExt, TryJoinIterExt, Value, Vc,
 };
@@ -20,11 +21,13 @@ pub struct MakeChunkGroupResult {
     pub availability_info: AvailabilityInfo,
 }
 
-/// Creates a chunk group from a set of entries.
+/// Creates a chunk group from a set of entries. Its chunks are named after
+/// `chunk_name`, if any.
 pub async fn make_chunk_group(
     chunking_context: Vc<Box<dyn ChunkingContext>>,
     chunk_group_entries: impl IntoIterator<Item = ResolvedVc<Box<dyn Module>>>,
     availability_info: AvailabilityInfo,
+    chunk_name: Option<RcStr>,
 ) -> Result<MakeChunkGroupResult> {
     let ChunkContentResult {
         chunk_items,
@@ -129,8 +132,13 @@ pub async fn make_chunk_group(
     // Insert async chunk loaders for every referenced async module
     let async_loaders = async_modules
         .into_iter()
-        .map(|module| {
-            chunking_context.async_loader_chunk_item(*module, Value::new(availability_info))
+        .map(|(module, (chunk_name, resource_hint))| {
+            chunking_context.async_loader_chunk_item(
+                *module,
+                chunk_name,
+                resource_hint,
+                Value::new(availability_info),
+            )
         })
         .collect::<Vec<_>>();
     let has_async_loaders = !async_loaders.is_empty();
@@ -178,6 +186,7 @@ pub async fn make_chunk_group(
         chunking_context,
         Vc::cell(chunk_items.into_iter().collect()),
         "".into(),
+        chunk_name,
         Vc::cell(referenced_output_assets),
     )
     .await?
@@ -191,6 +200,7 @@ pub async fn make_chunk_group(
             chunking_context,
             Vc::cell(async_loader_chunk_items.into_iter().collect()),
             "async-loader-".into(),
+            None,
             async_loader_external_module_references,
         )
         .await?;
@@ -3,12 +3,17 @@ use std::{
     mem::{replace, take},
 };
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use tracing::Level;
 use turbo_rcstr::RcStr;
-use turbo_tasks::{FxIndexMap, ReadRef, ResolvedVc, TryJoinIterExt, ValueToString, Vc};
+use turbo_tasks::{
+    trace::TraceRawVcs, FxIndexMap, NonLocalValue, ReadRef, ResolvedVc, TryJoinIterExt,
+    ValueToString, Vc,
+};
+use turbo_tasks_fs::{glob::Glob, FileSystemPath};
 
 use super::{
     AsyncModuleInfo, Chunk, ChunkItem, ChunkItemsWithAsyncModuleInfo, ChunkType, ChunkingContext,
@@ -16,10 +21,112 @@ use super::{
 };
 use crate::output::OutputAssets;
 
+/// A named group of packages or modules which are placed in a chunk of their
+/// own, like webpack's `splitChunks.cacheGroups`. The chunk is named after the
+/// group, so its name and content stay the same as long as the modules in it
+/// don't change.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+pub struct ChunkCacheGroup {
+    pub name: RcStr,
+    /// Package names, e.g. `react`, or all packages of a scope, e.g.
+    /// `@radix-ui/*`.
+    pub packages: Vec<RcStr>,
+    /// A regular expression matched against the identifiers of modules, e.g.
+    /// `[project]/node_modules/d3/src/index.js [client] (ecmascript)`.
+    pub test: Option<RcStr>,
+}
+
+impl ChunkCacheGroup {
+    fn matches_package(&self, package_name: &str) -> bool {
+        matches_package(&self.packages, package_name)
+    }
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct ChunkCacheGroups(Vec<ChunkCacheGroup>);
+
+#[turbo_tasks::value_impl]
+impl ChunkCacheGroups {
+    #[turbo_tasks::function]
+    pub fn empty() -> Vc<Self> {
+        Vc::cell(Vec::new())
+    }
+}
+
+/// A group of packages or modules which must never be split across chunks,
+/// e.g. a WASM wrapper and its JavaScript glue code. All modules of the group
+/// that end up in the same chunking are placed in a single chunk, regardless
+/// of its size.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+pub struct ChunkModuleGroup {
+    pub name: RcStr,
+    /// Package names, e.g. `react`, or all packages of a scope, e.g.
+    /// `@radix-ui/*`.
+    pub packages: Vec<RcStr>,
+    /// Globs matched against the paths of modules, relative to the root of
+    /// their filesystem.
+    pub modules: Vec<RcStr>,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct ChunkModuleGroups(Vec<ChunkModuleGroup>);
+
+#[turbo_tasks::value_impl]
+impl ChunkModuleGroups {
+    #[turbo_tasks::function]
+    pub fn empty() -> Vc<Self> {
+        Vc::cell(Vec::new())
+    }
+}
+
+/// Whether `package_name` matches one of the package name `patterns`.
+fn matches_package(patterns: &[RcStr], package_name: &str) -> bool {
+    !package_name.is_empty()
+        && patterns.iter().any(|pattern| {
+            if let Some(scope) = pattern.strip_suffix("/*") {
+                package_name
+                    .strip_prefix(scope)
+                    .is_some_and(|rest| rest.starts_with('/'))
+            } else {
+                package_name == pattern.as_str()
+            }
+        })
+}
+
+/// The sizes chunks are split and merged towards. Chunks larger than `max`
+/// are split at module boundaries, smaller than `min` are merged with others,
+/// as long as the result doesn't exceed `max`. Chunks of cache groups are left
+/// as they are, as are chunks of types that must keep the order of their items,
+/// unless [ChunkingContext::split_ordered_chunks] is enabled.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Copy, Debug)]
+pub struct ChunkSizeLimits {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Default for ChunkSizeLimits {
+    fn default() -> Self {
+        ChunkSizeLimits {
+            min: SMALL_CHUNK,
+            max: LARGE_CHUNK,
+        }
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkSizeLimits {
+    #[turbo_tasks::function]
+    pub fn default_limits() -> Vc<Self> {
+        ChunkSizeLimits::default().cell()
+    }
+}
+
 #[turbo_tasks::value]
 struct ChunkItemInfo {
     ty: ResolvedVc<Box<dyn ChunkType>>,
     name: ResolvedVc<RcStr>,
+    path: ResolvedVc<FileSystemPath>,
     size: usize,
 }
 
@@ -29,13 +136,14 @@ async fn chunk_item_info(
     chunk_item: Vc<Box<dyn ChunkItem>>,
     async_info: Option<Vc<AsyncModuleInfo>>,
 ) -> Result<Vc<ChunkItemInfo>> {
-    let asset_ident = chunk_item.asset_ident().to_string();
+    let asset_ident = chunk_item.asset_ident();
     let ty = chunk_item.ty().to_resolved().await?;
     let chunk_item_size = ty.chunk_item_size(chunking_context, chunk_item, async_info);
     Ok(ChunkItemInfo {
         ty,
         size: *chunk_item_size.await?,
-        name: asset_ident.to_resolved().await?,
+        name: asset_ident.to_string().to_resolved().await?,
+        path: asset_ident.path().to_resolved().await?,
     }
     .cell())
 }
@@ -47,6 +155,7 @@ pub async fn make_chunks(
     chunking_context: Vc<Box<dyn ChunkingContext>>,
     chunk_items: Vc<ChunkItemsWithAsyncModuleInfo>,
     key_prefix: RcStr,
+    chunk_name: Option<RcStr>,
     mut referenced_output_assets: Vc<OutputAssets>,
 ) -> Result<Vc<Chunks>> {
     let chunk_items = chunk_items
@@ -58,11 +167,26 @@ pub async fn make_chunks(
         })
         .try_join()
         .await?;
+
+    let cache_groups = chunking_context.chunk_cache_groups().await?;
+    let module_groups = chunking_context.chunk_module_groups().await?;
+    let limits = *chunking_context.chunk_size_limits().await?;
+    let split_ordered_chunks = *chunking_context.split_ordered_chunks().await?;
+    let max_ordered_chunk_size = *chunking_context.max_ordered_chunk_size().await?;
+
+    let module_group_indices =
+        module_group_indices(&chunk_items, &module_groups, &cache_groups).await?;
+
     let mut map = FxIndexMap::<_, Vec<_>>::default();
-    for (chunk_item, async_info, chunk_item_info) in chunk_items {
-        map.entry(chunk_item_info.ty)
-            .or_default()
-            .push((chunk_item, async_info, chunk_item_info));
+    for ((chunk_item, async_info, chunk_item_info), module_group) in
+        chunk_items.into_iter().zip(module_group_indices)
+    {
+        map.entry(chunk_item_info.ty).or_default().push((
+            chunk_item,
+            async_info,
+            chunk_item_info,
+            module_group,
+        ));
     }
 
     let mut chunks = Vec::new();
@@ -71,39 +195,65 @@ pub async fn make_chunks(
 
         let chunk_items = chunk_items
             .into_iter()
-            .map(|(chunk_item, async_info, chunk_item_info)| async move {
-                Ok((
-                    chunk_item,
-                    async_info,
-                    chunk_item_info.size,
-                    chunk_item_info.name.await?,
-                ))
-            })
+            .map(
+                |(chunk_item, async_info, chunk_item_info, module_group)| async move {
+                    Ok((
+                        (
+                            chunk_item,
+                            async_info,
+                            chunk_item_info.size,
+                            chunk_item_info.name.await?,
+                        ),
+                        module_group,
+                    ))
+                },
+            )
             .try_join()
             .await?;
 
         let mut split_context = SplitContext {
             ty,
             chunking_context,
+            limits,
+            chunk_name: chunk_name.clone(),
             chunks: &mut chunks,
+            pending: Vec::new(),
             referenced_output_assets: &mut referenced_output_assets,
             empty_referenced_output_assets: OutputAssets::empty().resolve().await?,
         };
 
         if !*ty.must_keep_item_order().await? {
+            let chunk_items =
+                module_groups_split(chunk_items, module_groups.len(), &mut split_context);
+            let chunk_items =
+                cache_groups_split(chunk_items, &cache_groups, &mut split_context).await?;
             app_vendors_split(
                 chunk_items,
                 format!("{key_prefix}{ty_name}"),
                 &mut split_context,
             )
             .await?;
+            let pending = take(&mut split_context.pending);
+            for chunk_items in merge_small_groups(pending, limits, |(_, _, size, _)| *size) {
+                push_chunk(chunk_items, None, &mut split_context);
+            }
         } else {
-            make_chunk(
-                chunk_items,
-                &mut format!("{key_prefix}{ty_name}"),
-                &mut split_context,
-            )
-            .await?;
+            let chunk_items = chunk_items.into_iter().map(|(item, _)| item).collect();
+            let groups = if split_ordered_chunks {
+                ordered_split(
+                    chunk_items,
+                    limits,
+                    |(_, _, _, asset_ident)| package_name(asset_ident).to_string(),
+                    |(_, _, size, _)| *size,
+                )
+            } else {
+                // All items end up in a single chunk, unless it's oversized.
+                split_oversized(chunk_items, max_ordered_chunk_size, |(_, _, size, _)| *size)
+            };
+            let groups = split_exceeding_rule_count(groups, &split_context).await?;
+            for chunk_items in groups {
+                push_chunk(chunk_items, None, &mut split_context);
+            }
         }
     }
 
@@ -127,7 +277,13 @@ type ChunkItemWithInfo = (
 struct SplitContext<'a> {
     ty: ResolvedVc<Box<dyn ChunkType>>,
     chunking_context: Vc<Box<dyn ChunkingContext>>,
+    limits: ChunkSizeLimits,
+    /// The name of the chunks which aren't named after a cache group.
+    chunk_name: Option<RcStr>,
     chunks: &'a mut Vec<Vc<Box<dyn Chunk>>>,
+    /// The chunk items of the chunks created by splitting, which are merged
+    /// when they are too small once all of them are known.
+    pending: Vec<Vec<ChunkItemWithInfo>>,
     referenced_output_assets: &'a mut Vc<OutputAssets>,
     empty_referenced_output_assets: Vc<OutputAssets>,
 }
@@ -142,26 +298,151 @@ async fn handle_split_group(
     split_context: &mut SplitContext<'_>,
     remaining: Option<&mut Vec<ChunkItemWithInfo>>,
 ) -> Result<bool> {
-    Ok(match (chunk_size(chunk_items), remaining) {
-        (ChunkSize::Large, _) => false,
-        (ChunkSize::Perfect, _) | (ChunkSize::Small, None) => {
-            make_chunk(take(chunk_items), key, split_context).await?;
-            true
-        }
-        (ChunkSize::Small, Some(remaining)) => {
-            remaining.extend(take(chunk_items));
-            true
-        }
-    })
+    Ok(
+        match (chunk_size(chunk_items, split_context.limits), remaining) {
+            (ChunkSize::Large, _) => false,
+            (ChunkSize::Perfect, _) | (ChunkSize::Small, None) => {
+                make_chunk(take(chunk_items), key, split_context).await?;
+                true
+            }
+            (ChunkSize::Small, Some(remaining)) => {
+                remaining.extend(take(chunk_items));
+                true
+            }
+        },
+    )
 }
 
-/// Creates a chunk with the given `chunk_items. `key` should be unique.
+/// Creates a chunk with the given `chunk_items`, or multiple ones if they
+/// exceed the maximum chunk size. `key` should be unique.
 #[tracing::instrument(level = Level::TRACE, skip_all, fields(key = display(key)))]
 async fn make_chunk(
     chunk_items: Vec<ChunkItemWithInfo>,
     key: &mut String,
     split_context: &mut SplitContext<'_>,
 ) -> Result<()> {
+    let max = split_context.limits.max;
+    split_context
+        .pending
+        .extend(split_oversized(chunk_items, max, |(_, _, size, _)| *size));
+    Ok(())
+}
+
+/// Splits `items` into consecutive groups of at most `max` in size. Items
+/// larger than `max` are placed in a group of their own.
+fn split_oversized<T>(items: Vec<T>, max: usize, size: impl Fn(&T) -> usize) -> Vec<Vec<T>> {
+    let mut groups = Vec::new();
+    let mut current = Vec::new();
+    let mut current_size = 0;
+    for item in items {
+        let item_size = size(&item);
+        if !current.is_empty() && current_size + item_size > max {
+            groups.push(take(&mut current));
+            current_size = 0;
+        }
+        current_size += item_size;
+        current.push(item);
+    }
+    if !current.is_empty() {
+        groups.push(current);
+    }
+    groups
+}
+
+/// Merges consecutive groups while one of them is smaller than `limits.min`
+/// and the result doesn't exceed `limits.max`.
+fn merge_small_groups<T>(
+    groups: Vec<Vec<T>>,
+    limits: ChunkSizeLimits,
+    size: impl Fn(&T) -> usize,
+) -> Vec<Vec<T>> {
+    let mut merged: Vec<(Vec<T>, usize)> = Vec::new();
+    for group in groups {
+        let group_size = group.iter().map(&size).sum::<usize>();
+        match merged.last_mut() {
+            Some((last, last_size))
+                if (*last_size < limits.min || group_size < limits.min)
+                    && *last_size + group_size <= limits.max =>
+            {
+                last.extend(group);
+                *last_size += group_size;
+            }
+            _ => merged.push((group, group_size)),
+        }
+    }
+    merged.into_iter().map(|(group, _)| group).collect()
+}
+
+/// Splits `items` whose order must be kept into consecutive groups, which are
+/// loaded in the order they are returned. Items are split where the `key` of
+/// consecutive items, e.g. their package, changes, and where a group would
+/// exceed `limits.max`. Small groups are merged again with their neighbors.
+///
+/// Items are never moved, so two chunk groups importing the same items in a
+/// different order get different chunks, instead of a chunk which has the
+/// order of one of them (the order conflicts of `mini-css-extract-plugin`).
+/// And as the groups only depend on the items before and in them, chunk
+/// groups starting with the same items share the chunks of them.
+fn ordered_split<T, K: PartialEq>(
+    items: Vec<T>,
+    limits: ChunkSizeLimits,
+    key: impl Fn(&T) -> K,
+    size: impl Fn(&T) -> usize,
+) -> Vec<Vec<T>> {
+    let mut runs: Vec<Vec<T>> = Vec::new();
+    for item in items {
+        match runs.last_mut() {
+            Some(run) if key(&run[0]) == key(&item) => run.push(item),
+            _ => runs.push(vec![item]),
+        }
+    }
+    let groups = runs
+        .into_iter()
+        .flat_map(|run| split_oversized(run, limits.max, &size))
+        .collect();
+    merge_small_groups(groups, limits, size)
+}
+
+/// Splits the ordered `groups` further into consecutive groups, so that none
+/// of them exceeds the [ChunkType::max_chunk_rule_count] of the chunk type.
+async fn split_exceeding_rule_count(
+    groups: Vec<Vec<ChunkItemWithInfo>>,
+    split_context: &SplitContext<'_>,
+) -> Result<Vec<Vec<ChunkItemWithInfo>>> {
+    let SplitContext {
+        ty,
+        chunking_context,
+        ..
+    } = *split_context;
+    let max_rule_count = *ty.max_chunk_rule_count(chunking_context).await?;
+    if max_rule_count == usize::MAX {
+        return Ok(groups);
+    }
+
+    let mut split_groups = Vec::new();
+    for group in groups {
+        let items = group
+            .into_iter()
+            .map(|item| async move {
+                let rule_count = *ty.chunk_item_rule_count(chunking_context, item.0).await?;
+                Ok((item, rule_count))
+            })
+            .try_join()
+            .await?;
+        split_groups.extend(
+            split_oversized(items, max_rule_count, |(_, rule_count)| *rule_count)
+                .into_iter()
+                .map(|group| group.into_iter().map(|(item, _)| item).collect()),
+        );
+    }
+    Ok(split_groups)
+}
+
+fn push_chunk(
+    chunk_items: Vec<ChunkItemWithInfo>,
+    name: Option<RcStr>,
+    split_context: &mut SplitContext<'_>,
+) {
     split_context.chunks.push(
         split_context.ty.chunk(
             split_context.chunking_context,
@@ -173,9 +454,177 @@ async fn make_chunk(
                 split_context.referenced_output_assets,
                 split_context.empty_referenced_output_assets,
             ),
+            name.or_else(|| split_context.chunk_name.clone()),
         ),
     );
-    Ok(())
+}
+
+fn cache_group_tests(cache_groups: &[ChunkCacheGroup]) -> Result<Vec<Option<Regex>>> {
+    cache_groups
+        .iter()
+        .map(|group| {
+            group
+                .test
+                .as_deref()
+                .map(|test| {
+                    Regex::new(test).with_context(|| {
+                        format!("invalid test of the chunk cache group {}", group.name)
+                    })
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+fn cache_group_matches(group: &ChunkCacheGroup, test: &Option<Regex>, asset_ident: &str) -> bool {
+    group.matches_package(package_name(asset_ident))
+        || test.as_ref().is_some_and(|test| test.is_match(asset_ident))
+}
+
+/// Finds the module group of each chunk item. Fails when a chunk item matches
+/// multiple module groups, or a module group and a cache group, or when the
+/// chunk items of a module group have different chunk types, since such a
+/// group can't be kept in a single chunk.
+async fn module_group_indices(
+    chunk_items: &[(
+        Vc<Box<dyn ChunkItem>>,
+        Option<Vc<AsyncModuleInfo>>,
+        ReadRef<ChunkItemInfo>,
+    )],
+    module_groups: &[ChunkModuleGroup],
+    cache_groups: &[ChunkCacheGroup],
+) -> Result<Vec<Option<usize>>> {
+    if module_groups.is_empty() {
+        return Ok(vec![None; chunk_items.len()]);
+    }
+    let globs = module_groups
+        .iter()
+        .map(|group| {
+            group
+                .modules
+                .iter()
+                .map(|glob| {
+                    Glob::parse(glob).with_context(|| {
+                        format!(
+                            "invalid module glob of the chunk module group {}",
+                            group.name
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let cache_group_tests = cache_group_tests(cache_groups)?;
+    let mut group_types = vec![None; module_groups.len()];
+    let mut indices = Vec::with_capacity(chunk_items.len());
+    for (_, _, chunk_item_info) in chunk_items {
+        let asset_ident = chunk_item_info.name.await?;
+        let path = chunk_item_info.path.await?;
+        let package_name = package_name(&asset_ident);
+        let mut matching = module_groups
+            .iter()
+            .zip(&globs)
+            .enumerate()
+            .filter(|(_, (group, globs))| {
+                matches_package(&group.packages, package_name)
+                    || globs.iter().any(|glob| glob.execute(&path.path))
+            })
+            .map(|(index, _)| index);
+        let Some(index) = matching.next() else {
+            indices.push(None);
+            continue;
+        };
+        let group = &module_groups[index];
+        if let Some(other) = matching.next() {
+            bail!(
+                "{asset_ident} matches the chunk module groups {} and {}, but can only be placed \
+                 in one of them",
+                group.name,
+                module_groups[other].name
+            );
+        }
+        if let Some((cache_group, _)) = cache_groups
+            .iter()
+            .zip(&cache_group_tests)
+            .find(|(cache_group, test)| cache_group_matches(cache_group, test, &asset_ident))
+        {
+            bail!(
+                "{asset_ident} matches the chunk module group {} and the chunk cache group {}, \
+                 but can only be placed in one of them",
+                group.name,
+                cache_group.name
+            );
+        }
+        match group_types[index] {
+            None => group_types[index] = Some(chunk_item_info.ty),
+            Some(ty) if ty != chunk_item_info.ty => bail!(
+                "the chunk module group {} contains modules of different chunk types ({} and {}), \
+                 which can't be placed in a single chunk",
+                group.name,
+                ty.to_string().await?,
+                chunk_item_info.ty.to_string().await?
+            ),
+            Some(_) => {}
+        }
+        indices.push(Some(index));
+    }
+    Ok(indices)
+}
+
+/// Creates a chunk for each module group with its chunk items, regardless of
+/// the size. Returns the remaining chunk items.
+fn module_groups_split(
+    chunk_items: Vec<(ChunkItemWithInfo, Option<usize>)>,
+    module_groups: usize,
+    split_context: &mut SplitContext<'_>,
+) -> Vec<ChunkItemWithInfo> {
+    let mut groups: Vec<Vec<_>> = (0..module_groups).map(|_| Vec::new()).collect();
+    let mut remaining = Vec::new();
+    for (item, module_group) in chunk_items {
+        match module_group {
+            Some(index) => groups[index].push(item),
+            None => remaining.push(item),
+        }
+    }
+    for chunk_items in groups {
+        if !chunk_items.is_empty() {
+            push_chunk(chunk_items, None, split_context);
+        }
+    }
+    remaining
+}
+
+/// Creates a named chunk for each cache group with the chunk items of its
+/// packages, regardless of the size. Returns the remaining chunk items.
+#[tracing::instrument(level = Level::TRACE, skip_all)]
+async fn cache_groups_split(
+    chunk_items: Vec<ChunkItemWithInfo>,
+    cache_groups: &[ChunkCacheGroup],
+    split_context: &mut SplitContext<'_>,
+) -> Result<Vec<ChunkItemWithInfo>> {
+    if cache_groups.is_empty() {
+        return Ok(chunk_items);
+    }
+    let tests = cache_group_tests(cache_groups)?;
+    let mut groups: Vec<Vec<_>> = cache_groups.iter().map(|_| Vec::new()).collect();
+    let mut remaining = Vec::new();
+    for item in chunk_items {
+        let (_, _, _, asset_ident) = &item;
+        match cache_groups
+            .iter()
+            .zip(&tests)
+            .position(|(group, test)| cache_group_matches(group, test, asset_ident))
+        {
+            Some(index) => groups[index].push(item),
+            None => remaining.push(item),
+        }
+    }
+    for (group, chunk_items) in cache_groups.iter().zip(groups) {
+        if !chunk_items.is_empty() {
+            push_chunk(chunk_items, Some(group.name.clone()), split_context);
+        }
+    }
+    Ok(remaining)
 }
 
 /// Split chunk items into app code and vendor code. Continues splitting with
@@ -361,16 +810,111 @@ enum ChunkSize {
 
 /// Determines the total size of the passed chunk items. Returns too small, too
 /// large or perfect fit.
-fn chunk_size(chunk_items: &[ChunkItemWithInfo]) -> ChunkSize {
+fn chunk_size(chunk_items: &[ChunkItemWithInfo], limits: ChunkSizeLimits) -> ChunkSize {
     let mut total_size = 0;
     for (_, _, size, _) in chunk_items {
         total_size += size;
     }
-    if total_size >= LARGE_CHUNK {
+    if total_size > limits.max {
         ChunkSize::Large
-    } else if total_size > SMALL_CHUNK {
+    } else if total_size >= limits.min {
         ChunkSize::Perfect
     } else {
         ChunkSize::Small
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_group_matches() {
+        let group = ChunkCacheGroup {
+            name: "react-vendor".into(),
+            packages: vec!["react".into(), "@radix-ui/*".into()],
+            test: None,
+        };
+        assert!(group.matches_package("react"));
+        assert!(group.matches_package("@radix-ui/react-dialog"));
+        assert!(!group.matches_package("react-dom"));
+        assert!(!group.matches_package("@radix-ui-extra/react"));
+        assert!(!group.matches_package(""));
+    }
+
+    #[test]
+    fn test_split_oversized() {
+        let groups = split_oversized(vec![40, 30, 50, 200, 10], 100, |size| *size);
+        assert_eq!(groups, vec![vec![40, 30], vec![50], vec![200], vec![10]]);
+    }
+
+    #[test]
+    fn test_merge_small_groups() {
+        let limits = ChunkSizeLimits { min: 50, max: 100 };
+        let groups = merge_small_groups(
+            vec![
+                vec![10],
+                vec![20, 5],
+                vec![80],
+                vec![60],
+                vec![30],
+                vec![90],
+            ],
+            limits,
+            |size| *size,
+        );
+        assert_eq!(
+            groups,
+            vec![vec![10, 20, 5], vec![80], vec![60, 30], vec![90]]
+        );
+    }
+
+    fn split_stylesheets(items: &[(&'static str, usize)]) -> Vec<Vec<&'static str>> {
+        let limits = ChunkSizeLimits { min: 50, max: 100 };
+        ordered_split(
+            items.to_vec(),
+            limits,
+            |(name, _)| name.as_bytes()[0],
+            |(_, size)| *size,
+        )
+        .into_iter()
+        .map(|group| group.into_iter().map(|(name, _)| name).collect())
+        .collect()
+    }
+
+    #[test]
+    fn test_ordered_split_keeps_order() {
+        // `a` and `b` are interleaved, grouping them by package would reorder
+        // `b1` before `a2`.
+        let groups = split_stylesheets(&[("a1", 60), ("b1", 60), ("a2", 60)]);
+        assert_eq!(groups, vec![vec!["a1"], vec!["b1"], vec!["a2"]]);
+    }
+
+    #[test]
+    fn test_ordered_split_conflicting_order() {
+        // Two chunk groups importing the same stylesheets in a different order
+        // don't share a chunk with the order of either of them.
+        let first = split_stylesheets(&[("a1", 60), ("b1", 60)]);
+        let second = split_stylesheets(&[("b1", 60), ("a1", 60)]);
+        assert_eq!(first, vec![vec!["a1"], vec!["b1"]]);
+        assert_eq!(second, vec![vec!["b1"], vec!["a1"]]);
+    }
+
+    #[test]
+    fn test_ordered_split_shared_prefix() {
+        // The chunks of stylesheets both chunk groups start with are the same.
+        let first = split_stylesheets(&[("a1", 30), ("a2", 30), ("b1", 60)]);
+        let second = split_stylesheets(&[("a1", 30), ("a2", 30), ("c1", 70)]);
+        assert_eq!(first[0], vec!["a1", "a2"]);
+        assert_eq!(first[0], second[0]);
+    }
+
+    #[test]
+    fn test_ordered_split_merges_and_splits() {
+        let groups =
+            split_stylesheets(&[("a1", 10), ("b1", 20), ("c1", 60), ("c2", 60), ("d1", 10)]);
+        // `c1` and `c2` exceed the maximum together, the small groups around
+        // them are merged into them.
+        assert_eq!(groups, vec![vec!["a1", "b1", "c1"], vec!["c2", "d1"]]);
+    }
+}
@@ -7,7 +7,10 @@ use turbo_tasks_hash::DeterministicHash;
 
 use super::{availability_info::AvailabilityInfo, ChunkableModule, EvaluatableAssets};
 use crate::{
-    chunk::{ChunkItem, ModuleId},
+    chunk::{
+        chunking::{ChunkCacheGroups, ChunkModuleGroups, ChunkSizeLimits},
+        ChunkItem, ModuleId, ResourceHint,
+    },
     environment::Environment,
     ident::AssetIdent,
     module::Module,
@@ -103,6 +106,12 @@ pub trait ChunkingContext {
         original_asset_ident: Vc<AssetIdent>,
     ) -> Vc<FileSystemPath>;
 
+    /// Whether the file names of chunks contain a hash of their content, see
+    /// [crate::chunk::content_hash].
+    fn content_hashing(self: Vc<Self>) -> Vc<bool> {
+        Vc::cell(false)
+    }
+
     fn is_hot_module_replacement_enabled(self: Vc<Self>) -> Vc<bool> {
         Vc::cell(false)
     }
@@ -111,9 +120,42 @@ pub trait ChunkingContext {
         Vc::cell(false)
     }
 
+    /// The groups of packages which are placed in named chunks of their own.
+    fn chunk_cache_groups(self: Vc<Self>) -> Vc<ChunkCacheGroups> {
+        ChunkCacheGroups::empty()
+    }
+
+    /// The groups of packages or modules which are never split across chunks.
+    fn chunk_module_groups(self: Vc<Self>) -> Vc<ChunkModuleGroups> {
+        ChunkModuleGroups::empty()
+    }
+
+    /// The sizes chunks are split and merged towards.
+    fn chunk_size_limits(self: Vc<Self>) -> Vc<ChunkSizeLimits> {
+        ChunkSizeLimits::default_limits()
+    }
+
+    /// Whether the items of chunk types which must keep their order, like CSS,
+    /// are split into multiple chunks of a chunk group instead of a single one.
+    fn split_ordered_chunks(self: Vc<Self>) -> Vc<bool> {
+        Vc::cell(false)
+    }
+
+    /// The size the single chunk of the items of chunk types which must keep
+    /// their order is split at into consecutive chunks, when
+    /// [ChunkingContext::split_ordered_chunks] isn't enabled.
+    fn max_ordered_chunk_size(self: Vc<Self>) -> Vc<usize> {
+        Vc::cell(usize::MAX)
+    }
+
+    /// The chunk item loading the chunk group of `module`, whose chunks are
+    /// named after `chunk_name`, if any, and hinted according to
+    /// `resource_hint`.
     fn async_loader_chunk_item(
         &self,
         module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
+        resource_hint: Option<ResourceHint>,
         availability_info: Value<AvailabilityInfo>,
     ) -> Vc<Box<dyn ChunkItem>>;
     fn async_loader_chunk_item_id(&self, module: Vc<Box<dyn ChunkableModule>>) -> Vc<ModuleId>;
@@ -125,6 +167,19 @@ pub trait ChunkingContext {
         availability_info: Value<AvailabilityInfo>,
     ) -> Vc<ChunkGroupResult>;
 
+    /// Like [ChunkingContext::chunk_group], but the chunks are named after
+    /// `chunk_name` instead of the modules in them, e.g. for a
+    /// `turbopackChunkName` comment.
+    fn named_chunk_group(
+        self: Vc<Self>,
+        ident: Vc<AssetIdent>,
+        module: Vc<Box<dyn ChunkableModule>>,
+        _chunk_name: RcStr,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> Vc<ChunkGroupResult> {
+        self.chunk_group(ident, module, availability_info)
+    }
+
     fn evaluated_chunk_group(
         self: Vc<Self>,
         ident: Vc<AssetIdent>,
@@ -213,6 +268,7 @@ pub trait ChunkingContextExt {
     fn chunk_group_assets(
         self: Vc<Self>,
         module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
         availability_info: Value<AvailabilityInfo>,
     ) -> Vc<OutputAssets>
     where
@@ -302,9 +358,10 @@ impl<T: ChunkingContext + Send + Upcast<Box<dyn ChunkingContext>>> ChunkingConte
     fn chunk_group_assets(
         self: Vc<Self>,
         module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
         availability_info: Value<AvailabilityInfo>,
     ) -> Vc<OutputAssets> {
-        chunk_group_assets(Vc::upcast(self), module, availability_info)
+        chunk_group_assets(Vc::upcast(self), module, chunk_name, availability_info)
     }
 }
 
@@ -354,10 +411,17 @@ async fn entry_chunk_group_asset(
 async fn chunk_group_assets(
     chunking_context: Vc<Box<dyn ChunkingContext>>,
     module: Vc<Box<dyn ChunkableModule>>,
+    chunk_name: Option<RcStr>,
     availability_info: Value<AvailabilityInfo>,
 ) -> Result<Vc<OutputAssets>> {
-    Ok(*chunking_context
-        .chunk_group(module.ident(), module, availability_info)
-        .await?
-        .assets)
+    let chunk_group = match chunk_name {
+        Some(chunk_name) => chunking_context.named_chunk_group(
+            module.ident(),
+            module,
+            chunk_name,
+            availability_info,
+        ),
+        None => chunking_context.chunk_group(module.ident(), module, availability_info),
+    };
+    Ok(*chunk_group.await?.assets)
 }
@@ -0,0 +1,64 @@
+//! Content hashes in the file names of chunks, which allow serving them as
+//! immutable assets. The hash is appended to the logical file name of the
+//! chunk, e.g. `pages_index_2a0a5f._.js` is emitted as
+//! `pages_index_2a0a5f._.4f1c2b9e.js`, so the logical name can be recovered
+//! from the file name.
+
+use anyhow::Result;
+use turbo_tasks::Vc;
+use turbo_tasks_fs::FileSystemPath;
+use turbo_tasks_hash::encode_hex;
+
+/// The number of hex digits of the content hash in file names.
+const CONTENT_HASH_LENGTH: usize = 8;
+
+/// The path of the chunk at the logical `path` with `content_hash` in its file
+/// name.
+#[turbo_tasks::function]
+pub async fn content_hashed_path(
+    path: Vc<FileSystemPath>,
+    content_hash: Vc<u64>,
+) -> Result<Vc<FileSystemPath>> {
+    let file_name = content_hashed_file_name(path.await?.file_name(), *content_hash.await?);
+    Ok(path.parent().join(file_name.into()))
+}
+
+fn content_hashed_file_name(file_name: &str, content_hash: u64) -> String {
+    let hash = &encode_hex(content_hash)[..CONTENT_HASH_LENGTH];
+    match file_name.rfind('.') {
+        Some(index) => format!("{}.{hash}{}", &file_name[..index], &file_name[index..]),
+        None => format!("{file_name}.{hash}"),
+    }
+}
+
+/// The logical file name of a chunk emitted with a content hash in its
+/// `file_name`, or `None` if the file name doesn't contain one.
+pub fn logical_file_name(file_name: &str) -> Option<String> {
+    let (stem, extension) = match file_name.rfind('.') {
+        Some(index) => file_name.split_at(index),
+        None => return None,
+    };
+    let (name, hash) = stem.rsplit_once('.')?;
+    (!name.is_empty()
+        && hash.len() == CONTENT_HASH_LENGTH
+        && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')))
+    .then(|| format!("{name}{extension}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content_hashed_file_name, logical_file_name};
+
+    #[test]
+    fn content_hashed_file_names() {
+        let file_name = content_hashed_file_name("pages_index_2a0a5f._.js", 0x4f1c_2b9e_0000_0001);
+        assert_eq!(file_name, "pages_index_2a0a5f._.4f1c2b9e.js");
+        assert_eq!(
+            logical_file_name(&file_name).as_deref(),
+            Some("pages_index_2a0a5f._.js")
+        );
+        assert_eq!(logical_file_name("pages_index_2a0a5f._.js"), None);
+        assert_eq!(logical_file_name("styles.4F1C2B9E.css"), None);
+        assert_eq!(logical_file_name(".4f1c2b9e.css"), None);
+    }
+}
@@ -4,6 +4,7 @@ pub mod chunk_group;
 pub mod chunking;
 pub(crate) mod chunking_context;
 pub(crate) mod containment_tree;
+pub mod content_hash;
 pub(crate) mod data;
 pub(crate) mod evaluate;
 pub mod module_id_strategies;
@@ -176,8 +177,13 @@ pub enum ChunkingType {
     /// becomes an async module when the referenced module is async.
     ParallelInheritAsync,
     /// An async loader is placed into the referencing chunk and loads the
-    /// separate chunk group in which the module is placed.
-    Async,
+    /// separate chunk group in which the module is placed. Its chunks are
+    /// named after `chunk_name`, if any, and are hinted to the browser
+    /// according to `resource_hint`.
+    Async {
+        chunk_name: Option<RcStr>,
+        resource_hint: Option<ResourceHint>,
+    },
     /// Create a new chunk group in a separate context, merging references with the same tag into a
     /// single chunk group. It does not inherit the available modules from the parent.
     // TODO implement
@@ -196,6 +202,31 @@ pub enum ChunkingType {
 #[turbo_tasks::value(transparent)]
 pub struct ChunkingTypeOption(Option<ChunkingType>);
 
+/// How the browser is hinted to load the chunks of an async chunk group before
+/// they are imported. A preload takes precedence over a prefetch.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Hash,
+    TraceRawVcs,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    ValueDebugFormat,
+    NonLocalValue,
+    TaskInput,
+)]
+pub enum ResourceHint {
+    /// The chunks are fetched with a low priority when the browser is idle.
+    Prefetch,
+    /// The chunks are fetched in parallel with the referencing chunk group.
+    Preload,
+}
+
 /// A [ModuleReference] implementing this trait and returning Some(_) for
 /// [ChunkableModuleReference::chunking_type] are considered as potentially
 /// chunkable references. When all [Module]s of such a reference implement
@@ -213,7 +244,10 @@ type AsyncInfo = FxIndexMap<Vc<Box<dyn ChunkItem>>, Vec<Vc<Box<dyn ChunkItem>>>>
 
 pub struct ChunkContentResult {
     pub chunk_items: FxIndexSet<Vc<Box<dyn ChunkItem>>>,
-    pub async_modules: FxIndexSet<ResolvedVc<Box<dyn ChunkableModule>>>,
+    /// The modules loaded async by the chunk group, with the name and the
+    /// resource hint of their chunks.
+    pub async_modules:
+        FxIndexMap<ResolvedVc<Box<dyn ChunkableModule>>, (Option<RcStr>, Option<ResourceHint>)>,
     pub traced_modules: FxIndexSet<ResolvedVc<Box<dyn Module>>>,
     pub external_output_assets: Vc<OutputAssets>,
     pub external_module_references: FxIndexSet<Vc<Box<dyn ModuleReference>>>,
@@ -265,6 +299,8 @@ enum ChunkContentGraphNode {
     // Async module that is referenced from the chunk group
     AsyncModule {
         module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
+        resource_hint: Option<ResourceHint>,
     },
     // Module that is referenced as traced and will be turned into a separate RebasedAsset
     TracedModule {
@@ -479,7 +515,10 @@ async fn graph_node_to_referenced_nodes(
                                 None,
                             ))
                         }
-                        ChunkingType::Async => {
+                        ChunkingType::Async {
+                            chunk_name,
+                            resource_hint,
+                        } => {
                             let chunk_loading =
                                 chunking_context.environment().chunk_loading().await?;
                             if matches!(*chunk_loading, ChunkLoading::Edge) {
@@ -503,6 +542,8 @@ async fn graph_node_to_referenced_nodes(
                                         key: None,
                                         node: ChunkContentGraphNode::AsyncModule {
                                             module: *chunkable_module,
+                                            chunk_name: chunk_name.clone(),
+                                            resource_hint: *resource_hint,
                                         },
                                     }),
                                     None,
@@ -673,7 +714,8 @@ async fn chunk_content_internal_parallel(
     let graph_nodes: Vec<_> = traversal_result?.into_reverse_topological().collect();
 
     let mut chunk_items = FxIndexSet::default();
-    let mut async_modules = FxIndexSet::default();
+    let mut async_modules: FxIndexMap<_, (Option<RcStr>, Option<ResourceHint>)> =
+        FxIndexMap::default();
     let mut external_module_references = FxIndexSet::default();
     let mut external_output_assets: FxIndexSet<ResolvedVc<Box<dyn OutputAsset>>> =
         FxIndexSet::default();
@@ -692,9 +734,19 @@ async fn chunk_content_internal_parallel(
             ChunkContentGraphNode::ChunkItem { item, .. } => {
                 chunk_items.insert(*item.to_resolved().await?);
             }
-            ChunkContentGraphNode::AsyncModule { module } => {
+            ChunkContentGraphNode::AsyncModule {
+                module,
+                chunk_name,
+                resource_hint,
+            } => {
                 let module = module.to_resolved().await?;
-                async_modules.insert(module);
+                // The first import naming the chunks of a module wins, and the
+                // strongest hint of all imports is used
+                let (name, hint) = async_modules.entry(module).or_default();
+                if name.is_none() {
+                    *name = chunk_name;
+                }
+                *hint = (*hint).max(resource_hint);
             }
             ChunkContentGraphNode::ExternalModuleReference(reference) => {
                 let reference = reference.resolve().await?;
@@ -777,12 +829,14 @@ pub trait ChunkType: ValueToString {
     /// Whether the source (reference) order of items needs to be retained during chunking.
     fn must_keep_item_order(self: Vc<Self>) -> Vc<bool>;
 
-    /// Create a new chunk for the given chunk items
+    /// Create a new chunk for the given chunk items. A `name` is used for the
+    /// chunk's output path instead of the common path of the chunk items.
     fn chunk(
         &self,
         chunking_context: Vc<Box<dyn ChunkingContext>>,
         chunk_items: Vec<ChunkItemWithAsyncModuleInfo>,
         referenced_output_assets: Vc<OutputAssets>,
+        name: Option<RcStr>,
     ) -> Vc<Box<dyn Chunk>>;
 
     fn chunk_item_size(
@@ -791,6 +845,26 @@ pub trait ChunkType: ValueToString {
         chunk_item: Vc<Box<dyn ChunkItem>>,
         async_module_info: Option<Vc<AsyncModuleInfo>>,
     ) -> Vc<usize>;
+
+    /// The number of rules of `chunk_item` counted against
+    /// [ChunkType::max_chunk_rule_count], e.g. the selectors of a stylesheet.
+    fn chunk_item_rule_count(
+        self: Vc<Self>,
+        _chunking_context: Vc<Box<dyn ChunkingContext>>,
+        _chunk_item: Vc<Box<dyn ChunkItem>>,
+    ) -> Vc<usize> {
+        Vc::cell(0)
+    }
+
+    /// The number of rules a chunk can contain before it has to be split, e.g.
+    /// legacy browsers ignore the selectors of a stylesheet after the 4095th
+    /// one. Only applies to chunk types which must keep their item order.
+    fn max_chunk_rule_count(
+        self: Vc<Self>,
+        _chunking_context: Vc<Box<dyn ChunkingContext>>,
+    ) -> Vc<usize> {
+        Vc::cell(usize::MAX)
+    }
 }
 
 pub fn round_chunk_item_size(size: usize) -> usize {
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use turbo_rcstr::RcStr;
-use turbo_tasks::{FxIndexMap, ResolvedVc, Vc};
+use turbo_tasks::{FxIndexMap, ResolvedVc, TaskInput, TryJoinIterExt, Vc};
 use turbo_tasks_fs::FileSystemPath;
 
 use crate::environment::Environment;
@@ -139,7 +139,7 @@ impl From<serde_json::Value> for CompileTimeDefineValue {
 }
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, Hash, TaskInput)]
 pub enum DefineableNameSegment {
     Name(RcStr),
     TypeOf,
@@ -189,14 +189,42 @@ impl CompileTimeDefines {
         Vc::cell(FxIndexMap::default())
     }
 
+    /// The defines with a cell for each value, so that reading a value only
+    /// depends on that define. The cells are keyed by name instead of by their
+    /// position, so that adding, removing or reordering defines doesn't
+    /// invalidate the modules reading the other ones. Changing the set of
+    /// names still invalidates the modules which look up defines.
     #[turbo_tasks::function]
-    pub fn individual(&self) -> Vc<CompileTimeDefinesIndividual> {
-        Vc::cell(
-            self.0
-                .iter()
-                .map(|(key, value)| (key.clone(), value.clone().resolved_cell()))
+    pub async fn individual(self: Vc<Self>) -> Result<Vc<CompileTimeDefinesIndividual>> {
+        Ok(Vc::cell(
+            self.await?
+                .keys()
+                .map(|name| async move {
+                    Ok((
+                        name.clone(),
+                        self.value_of(name.clone()).to_resolved().await?,
+                    ))
+                })
+                .try_join()
+                .await?
+                .into_iter()
                 .collect(),
-        )
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn value_of(
+        self: Vc<Self>,
+        name: Vec<DefineableNameSegment>,
+    ) -> Result<Vc<CompileTimeDefineValue>> {
+        // A removed define is only read by tasks which are about to be
+        // recomputed without it
+        Ok(self
+            .await?
+            .get(&name)
+            .cloned()
+            .unwrap_or_else(|| CompileTimeDefineValue::JSON("undefined".into()))
+            .cell())
     }
 }
 
@@ -254,14 +282,41 @@ impl FreeVarReferences {
         Vc::cell(FxIndexMap::default())
     }
 
+    /// Like [CompileTimeDefines::individual], the references with a cell for
+    /// each one, keyed by name.
     #[turbo_tasks::function]
-    pub fn individual(&self) -> Vc<FreeVarReferencesIndividual> {
-        Vc::cell(
-            self.0
-                .iter()
-                .map(|(key, value)| (key.clone(), value.clone().resolved_cell()))
+    pub async fn individual(self: Vc<Self>) -> Result<Vc<FreeVarReferencesIndividual>> {
+        Ok(Vc::cell(
+            self.await?
+                .keys()
+                .map(|name| async move {
+                    Ok((
+                        name.clone(),
+                        self.reference_of(name.clone()).to_resolved().await?,
+                    ))
+                })
+                .try_join()
+                .await?
+                .into_iter()
                 .collect(),
-        )
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn reference_of(
+        self: Vc<Self>,
+        name: Vec<DefineableNameSegment>,
+    ) -> Result<Vc<FreeVarReference>> {
+        // A removed reference is only read by tasks which are about to be
+        // recomputed without it
+        Ok(self
+            .await?
+            .get(&name)
+            .cloned()
+            .unwrap_or_else(|| {
+                FreeVarReference::Value(CompileTimeDefineValue::JSON("undefined".into()))
+            })
+            .cell())
     }
 }
 
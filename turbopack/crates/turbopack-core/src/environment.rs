@@ -93,6 +93,28 @@ impl Environment {
         })
     }
 
+    /// Whether the environment includes browsers which ignore the selectors of
+    /// a stylesheet after the 4095th one, i.e. Internet Explorer 9 and older.
+    #[turbo_tasks::function]
+    pub async fn has_legacy_selector_limit(&self) -> Result<Vc<bool>> {
+        Ok(match self.execution {
+            ExecutionEnvironment::Browser(browser_env) => {
+                let browsers = browserslist::resolve(
+                    browser_env.await?.browserslist_query.split(','),
+                    &browserslist::Opts::default(),
+                )?;
+                Vc::cell(browsers.iter().any(|browser| {
+                    browser.name() == "ie"
+                        && browser
+                            .version()
+                            .parse::<f32>()
+                            .is_ok_and(|version| version < 10.0)
+                }))
+            }
+            _ => Vc::cell(false),
+        })
+    }
+
     #[turbo_tasks::function]
     pub fn node_externals(&self) -> Vc<bool> {
         match self.execution {
@@ -85,7 +85,7 @@ pub async fn children_from_module_references(
                 Some(ChunkingType::ParallelInheritAsync) => {
                     key = parallel_inherit_async_reference_ty()
                 }
-                Some(ChunkingType::Async) => key = async_reference_ty(),
+                Some(ChunkingType::Async { .. }) => key = async_reference_ty(),
                 Some(ChunkingType::Isolated { .. }) => key = isolated_reference_ty(),
                 Some(ChunkingType::Passthrough) => key = passthrough_reference_ty(),
                 Some(ChunkingType::Traced) => key = traced_reference_ty(),
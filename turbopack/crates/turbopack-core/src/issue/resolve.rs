@@ -137,6 +137,142 @@ impl Issue for ResolvingIssue {
     // TODO add source link
 }
 
+/// A request which resolved to a path whose casing differs from the path on
+/// disk, which only works on case-insensitive file systems.
+#[turbo_tasks::value(shared)]
+pub struct CaseMismatchIssue {
+    pub file_path: ResolvedVc<FileSystemPath>,
+    pub request: ResolvedVc<Request>,
+    /// The resolved path, with the casing of the request.
+    pub path: ResolvedVc<FileSystemPath>,
+    /// The path with the casing on disk, relative to the root of its file
+    /// system.
+    pub actual_path: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for CaseMismatchIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        IssueSeverity::Error.cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn title(&self) -> Result<Vc<StyledString>> {
+        Ok(StyledString::Line(vec![
+            StyledString::Text("The casing of ".into()),
+            StyledString::Code(self.request.to_string().await?.clone_value()),
+            StyledString::Text(" doesn't match the file on disk".into()),
+        ])
+        .cell())
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.file_path
+    }
+
+    #[turbo_tasks::function]
+    async fn description(&self) -> Result<Vc<OptionStyledString>> {
+        Ok(Vc::cell(Some(
+            StyledString::Stack(vec![
+                StyledString::Line(vec![
+                    StyledString::Text("The request resolves to ".into()),
+                    StyledString::Code(self.path.await?.path.clone()),
+                    StyledString::Text(", but the file on disk is ".into()),
+                    StyledString::Code(self.actual_path.clone()),
+                    StyledString::Text(".".into()),
+                ]),
+                StyledString::Text(
+                    "This only works on case-insensitive file systems and fails on case-sensitive \
+                     ones, e.g. on Linux. Change the request to use the casing on disk."
+                        .into(),
+                ),
+            ])
+            .resolved_cell(),
+        )))
+    }
+}
+
+/// An import forbidden by a
+/// [RestrictedImport][crate::resolve::options::RestrictedImport] rule.
+#[turbo_tasks::value(shared)]
+pub struct RestrictedImportIssue {
+    pub file_path: ResolvedVc<FileSystemPath>,
+    pub request: RcStr,
+    /// The `from` glob of the rule.
+    pub from: RcStr,
+    /// The `disallow` glob of the rule that matched the request.
+    pub disallow: RcStr,
+    pub message: Option<RcStr>,
+    pub source: Option<ResolvedVc<IssueSource>>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for RestrictedImportIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        IssueSeverity::Error.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Text("Restricted import of ".into()),
+            StyledString::Code(self.request.clone()),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.file_path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        let rule = StyledString::Line(vec![
+            StyledString::Text("Modules matching ".into()),
+            StyledString::Code(self.from.clone()),
+            StyledString::Text(" must not import ".into()),
+            StyledString::Code(self.disallow.clone()),
+            StyledString::Text(".".into()),
+        ]);
+        Vc::cell(Some(
+            match &self.message {
+                Some(message) => {
+                    StyledString::Stack(vec![rule, StyledString::Text(message.clone())])
+                }
+                None => rule,
+            }
+            .resolved_cell(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn source(&self) -> Result<Vc<OptionIssueSource>> {
+        Ok(Vc::cell(match self.source {
+            Some(source) => Some(
+                source
+                    .resolve_source_map(*self.file_path)
+                    .to_resolved()
+                    .await?,
+            ),
+            None => None,
+        }))
+    }
+}
+
 async fn lookup_import_map(
     import_map: Vc<ImportMap>,
     file_path: Vc<FileSystemPath>,
@@ -200,6 +200,9 @@ pub enum WorkerReferenceSubType {
     WebWorker,
     SharedWorker,
     ServiceWorker,
+    /// A module added to an audio, paint, layout or animation worklet with
+    /// `addModule()`.
+    Worklet,
     Custom(u8),
     Undefined,
 }
@@ -0,0 +1,372 @@
+//! Modules imported from `https://` URLs, like in Deno. They are fetched once,
+//! verified against the content hashes of a lockfile and cached on disk, so
+//! that builds don't depend on the network and a remote module can't change
+//! without anyone noticing.
+
+use anyhow::Result;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, ResolvedVc, Vc};
+use turbo_tasks_fs::{rope::Rope, util::normalize_path, File, FileContent, FileSystemPath};
+
+use crate::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    resolve::{parse::Request, pattern::Pattern},
+    source::Source,
+};
+
+/// Fetches remote modules. This crate doesn't do any networking itself, so
+/// the fetching is implemented by the embedder.
+#[turbo_tasks::value_trait]
+pub trait RemoteFetcher {
+    /// Fetches the body of `url`.
+    fn fetch(self: Vc<Self>, url: RcStr) -> Vc<RemoteFetchResult>;
+}
+
+#[turbo_tasks::value(shared)]
+pub enum RemoteFetchResult {
+    Ok(ResolvedVc<Rope>),
+    /// Explains why the module couldn't be fetched.
+    Err(RcStr),
+}
+
+/// Enables importing remote modules.
+#[turbo_tasks::value(shared)]
+pub struct RemoteImports {
+    /// The directory fetched modules are cached in. Remote modules are
+    /// identified by paths inside of it that mirror their URL, e.g.
+    /// `https/esm.sh/react.js`, so that their relative imports resolve
+    /// relative to the URL.
+    pub cache_dir: ResolvedVc<FileSystemPath>,
+    /// A JSON file with the SHA-256 hashes of the content of the remote
+    /// modules, like `{ "remote": { "https://esm.sh/react": "<hex>" } }`.
+    pub lockfile: ResolvedVc<FileSystemPath>,
+    /// Whether modules missing from the lockfile are errors instead of
+    /// warnings. They're then neither fetched nor read from the cache.
+    pub frozen_lockfile: bool,
+    pub fetcher: ResolvedVc<Box<dyn RemoteFetcher>>,
+}
+
+#[derive(Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    remote: FxIndexMap<RcStr, RcStr>,
+}
+
+#[turbo_tasks::value(transparent)]
+struct LockedHashes(FxIndexMap<RcStr, RcStr>);
+
+#[turbo_tasks::function]
+async fn locked_hashes(lockfile: Vc<FileSystemPath>) -> Result<Vc<LockedHashes>> {
+    let FileContent::Content(file) = &*lockfile.read().await? else {
+        return Ok(Vc::cell(FxIndexMap::default()));
+    };
+    let lockfile: Lockfile = serde_json::from_str(&file.content().to_str()?)?;
+    Ok(Vc::cell(lockfile.remote))
+}
+
+fn sha256_hex(content: &Rope) -> Result<String> {
+    let hash = Sha256::digest(content.to_bytes()?);
+    Ok(hash.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Extensions of remote modules that are kept as they are. Other modules,
+/// like `https://esm.sh/react`, are assumed to be JavaScript.
+const KNOWN_EXTENSIONS: &[&str] = &[
+    "js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx", "json", "css", "wasm",
+];
+
+/// The path identifying a remote module inside of the cache dir, and the query
+/// of its URL.
+fn remote_module_path(url: &str) -> Option<(String, RcStr)> {
+    let (scheme, rest) = url.split_once("://")?;
+    let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, format!("?{query}")),
+        None => (rest, String::new()),
+    };
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    if host.is_empty() {
+        return None;
+    }
+    let mut path = normalize_path(path)?;
+    if path.is_empty() || rest.ends_with('/') {
+        path = if path.is_empty() {
+            "index".into()
+        } else {
+            format!("{path}/index")
+        };
+    }
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    let has_known_extension = file_name
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| KNOWN_EXTENSIONS.contains(&extension));
+    if !has_known_extension {
+        path.push_str(".js");
+    }
+    Some((format!("{scheme}/{host}/{path}"), query.into()))
+}
+
+/// The URL of a request to a remote module, or of a relative request within a
+/// remote module, which is resolved relative to the URL of the module.
+pub(crate) async fn remote_request_url(
+    remote_imports: Vc<RemoteImports>,
+    lookup_path: Vc<FileSystemPath>,
+    request: &Request,
+) -> Result<Option<RcStr>> {
+    let (path, query, server_relative) = match request {
+        Request::Uri {
+            protocol,
+            remainder,
+            ..
+        } => {
+            // Modules fetched over plain `http:` could be tampered with on the way,
+            // and they would be trusted when they are missing from the lockfile
+            return Ok(
+                (protocol.as_str() == "https:").then(|| format!("{protocol}{remainder}").into())
+            );
+        }
+        Request::Relative {
+            path: Pattern::Constant(path),
+            query,
+            ..
+        } => (path, query, false),
+        Request::ServerRelative {
+            path: Pattern::Constant(path),
+            query,
+            ..
+        } => (path, query, true),
+        _ => return Ok(None),
+    };
+    let cache_dir = remote_imports.await?.cache_dir.await?;
+    let lookup_path = lookup_path.await?;
+    let Some(dir) = cache_dir.get_path_to(&lookup_path) else {
+        return Ok(None);
+    };
+    let mut segments = dir.splitn(3, '/');
+    let (Some(scheme @ "https"), Some(host)) = (segments.next(), segments.next()) else {
+        return Ok(None);
+    };
+    let base = if server_relative {
+        ""
+    } else {
+        segments.next().unwrap_or_default()
+    };
+    let Some(path) = normalize_path(&format!("{base}/{path}")) else {
+        return Ok(None);
+    };
+    Ok(Some(
+        format!(
+            "{scheme}://{host}/{}{}",
+            path.trim_start_matches('/'),
+            query.await?
+        )
+        .into(),
+    ))
+}
+
+/// A module fetched from a URL.
+#[turbo_tasks::value]
+pub struct RemoteSource {
+    url: RcStr,
+    remote_imports: ResolvedVc<RemoteImports>,
+}
+
+#[turbo_tasks::value_impl]
+impl RemoteSource {
+    #[turbo_tasks::function]
+    pub fn new(url: RcStr, remote_imports: ResolvedVc<RemoteImports>) -> Vc<Self> {
+        Self::cell(RemoteSource {
+            url,
+            remote_imports,
+        })
+    }
+
+    #[turbo_tasks::function]
+    async fn path(&self) -> Result<Vc<FileSystemPath>> {
+        let cache_dir = self.remote_imports.await?.cache_dir;
+        Ok(match remote_module_path(&self.url) {
+            Some((path, _)) => cache_dir.join(path.into()),
+            None => cache_dir.join("invalid-url.js".into()),
+        })
+    }
+
+    /// The content of the module, read from the cache unless it's missing or
+    /// doesn't match the lockfile.
+    #[turbo_tasks::function]
+    async fn verified_content(self: Vc<Self>) -> Result<Vc<FileContent>> {
+        let this = self.await?;
+        let remote_imports = this.remote_imports.await?;
+        let locked_hashes = locked_hashes(*remote_imports.lockfile).await?;
+        let locked_hash = locked_hashes.get(&this.url);
+
+        let path = self.path().to_resolved().await?;
+        let issue = |severity: IssueSeverity, message: String| {
+            RemoteImportIssue {
+                file_path: path,
+                url: this.url.clone(),
+                severity: severity.resolved_cell(),
+                message: message.into(),
+            }
+            .resolved_cell()
+            .emit()
+        };
+        if locked_hash.is_none() && remote_imports.frozen_lockfile {
+            issue(
+                IssueSeverity::Error,
+                "The module isn't listed in the lockfile, which is frozen. Add the SHA-256 hash \
+                 of its content to the \"remote\" entries of the lockfile."
+                    .into(),
+            );
+            return Ok(FileContent::NotFound.cell());
+        }
+
+        let url_hash = sha256_hex(&Rope::from(this.url.as_bytes().to_vec()))?;
+        let cache_path = remote_imports
+            .cache_dir
+            .join(format!("content/{url_hash}").into());
+        if let FileContent::Content(file) = &*cache_path.read().await? {
+            let matches_lockfile = match locked_hash {
+                Some(hash) => *hash == sha256_hex(file.content())?,
+                None => true,
+            };
+            if matches_lockfile {
+                return Ok(cache_path.read());
+            }
+        }
+
+        let content = match &*remote_imports.fetcher.fetch(this.url.clone()).await? {
+            RemoteFetchResult::Ok(content) => content.await?,
+            RemoteFetchResult::Err(message) => {
+                issue(IssueSeverity::Error, message.to_string());
+                return Ok(FileContent::NotFound.cell());
+            }
+        };
+        let hash = sha256_hex(&content)?;
+        match locked_hash {
+            Some(locked_hash) if *locked_hash != hash => {
+                issue(
+                    IssueSeverity::Error,
+                    format!(
+                        "The content doesn't match the hash in the lockfile. Expected \
+                         {locked_hash}, but got {hash}. Update the lockfile if the change is \
+                         expected."
+                    ),
+                );
+                return Ok(FileContent::NotFound.cell());
+            }
+            Some(_) => {}
+            None => issue(
+                IssueSeverity::Warning,
+                format!(
+                    "The module isn't listed in the lockfile. Add \"{}\": \"{hash}\" to the \
+                     \"remote\" entries to verify its content in future builds.",
+                    this.url
+                ),
+            ),
+        }
+        let content = FileContent::Content(File::from(content.clone_value())).cell();
+        cache_path.write(content).await?;
+        Ok(content)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Source for RemoteSource {
+    #[turbo_tasks::function]
+    fn ident(self: Vc<Self>) -> Result<Vc<AssetIdent>> {
+        Ok(AssetIdent::from_path(self.path()).with_query(self.query()))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl RemoteSource {
+    #[turbo_tasks::function]
+    fn query(&self) -> Vc<RcStr> {
+        Vc::cell(
+            remote_module_path(&self.url)
+                .map(|(_, query)| query)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for RemoteSource {
+    #[turbo_tasks::function]
+    async fn content(self: Vc<Self>) -> Result<Vc<AssetContent>> {
+        Ok(AssetContent::File(self.verified_content().to_resolved().await?).cell())
+    }
+}
+
+#[turbo_tasks::value(shared)]
+pub struct RemoteImportIssue {
+    pub file_path: ResolvedVc<FileSystemPath>,
+    pub url: RcStr,
+    pub severity: ResolvedVc<IssueSeverity>,
+    pub message: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for RemoteImportIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        *self.severity
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Text("Remote import of ".into()),
+            StyledString::Code(self.url.clone()),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Load.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.file_path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(self.message.clone()).resolved_cell(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::remote_module_path;
+
+    #[test]
+    fn module_paths() {
+        let path = |url| remote_module_path(url).map(|(path, query)| (path, query.to_string()));
+        assert_eq!(
+            path("https://esm.sh/react@18.2.0"),
+            Some(("https/esm.sh/react@18.2.0.js".into(), "".into()))
+        );
+        assert_eq!(
+            path("https://esm.sh/v135/react/index.mjs?target=es2022#x"),
+            Some((
+                "https/esm.sh/v135/react/index.mjs".into(),
+                "?target=es2022".into()
+            ))
+        );
+        assert_eq!(
+            path("https://deno.land/"),
+            Some(("https/deno.land/index.js".into(), "".into()))
+        );
+        assert_eq!(path("https://example.com/../../etc/passwd"), None);
+        assert_eq!(path("esm.sh/react"), None);
+    }
+}
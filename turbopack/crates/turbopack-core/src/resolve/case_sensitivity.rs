@@ -0,0 +1,81 @@
+//! Verifies that the casing of resolved paths matches the entries on disk. On
+//! case-insensitive file systems, e.g. the defaults of macOS and Windows, a
+//! request like `./Button` resolves to `button.tsx`, but it fails on
+//! case-sensitive ones.
+
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{DirectoryContent, FileSystemPath};
+
+use super::{parse::Request, ResolveResult, ResolveResultItem};
+use crate::{
+    issue::{resolve::CaseMismatchIssue, IssueExt},
+    source::Source,
+};
+
+/// Emits an issue for every source of `result` whose path differs in casing
+/// from the path on disk.
+pub(super) async fn check_resolve_result_casing(
+    lookup_path: Vc<FileSystemPath>,
+    request: Vc<Request>,
+    result: Vc<ResolveResult>,
+) -> Result<()> {
+    for item in result.await?.primary.values() {
+        let ResolveResultItem::Source(source) = item else {
+            continue;
+        };
+        let path = source.ident().path();
+        if let Some(actual_path) = &*actual_path_casing(path).await? {
+            CaseMismatchIssue {
+                file_path: lookup_path.to_resolved().await?,
+                request: request.to_resolved().await?,
+                path: path.to_resolved().await?,
+                actual_path: actual_path.clone(),
+            }
+            .resolved_cell()
+            .emit();
+        }
+    }
+    Ok(())
+}
+
+/// The path of `path` with the casing of the entries on disk, relative to the
+/// root of its file system. `None` when the casing matches or the path doesn't
+/// exist.
+#[turbo_tasks::function]
+async fn actual_path_casing(path: ResolvedVc<FileSystemPath>) -> Result<Vc<Option<RcStr>>> {
+    let path_value = path.await?;
+    if path_value.is_root() {
+        return Ok(Vc::cell(None));
+    }
+    let parent = path.parent();
+    let name = path_value.file_name();
+    let DirectoryContent::Entries(entries) = &*parent.read_dir().await? else {
+        return Ok(Vc::cell(None));
+    };
+    let actual_name = if entries.get(name).is_some() {
+        name
+    } else if let Some((actual_name, _)) = entries
+        .iter()
+        .find(|(entry, _)| entry.to_lowercase() == name.to_lowercase())
+    {
+        actual_name.as_str()
+    } else {
+        return Ok(Vc::cell(None));
+    };
+
+    let parent_actual_path = actual_path_casing(parent).await?;
+    if parent_actual_path.is_none() && actual_name == name {
+        return Ok(Vc::cell(None));
+    }
+    let parent_path = match &*parent_actual_path {
+        Some(parent_path) => parent_path.clone(),
+        None => parent.await?.path.clone(),
+    };
+    Ok(Vc::cell(Some(if parent_path.is_empty() {
+        actual_name.into()
+    } else {
+        format!("{parent_path}/{actual_name}").into()
+    })))
+}
@@ -19,6 +19,7 @@ use turbo_tasks_fs::{
 };
 
 use self::{
+    case_sensitivity::check_resolve_result_casing,
     options::{
         resolve_modules_options, ConditionValue, ImportMapResult, ResolveInPackage,
         ResolveIntoPackage, ResolveModules, ResolveModulesOptions, ResolveOptions,
@@ -26,8 +27,14 @@ use self::{
     origin::{ResolveOrigin, ResolveOriginExt},
     parse::Request,
     pattern::Pattern,
-    plugin::BeforeResolvePlugin,
-    remap::{ExportsField, ImportsField},
+    plugin::{BeforeResolvePlugin, PackageLookupResult},
+    pnpm::{keep_symlinked_path, pnpm_file_source},
+    remap::{has_invalid_segments, ExportsField, ImportsField},
+    restricted_imports::check_restricted_imports,
+    trace::{
+        describe_resolve_result, is_traced, trace_resolve_step, ResolveTraceCandidate,
+        ResolveTraceStep, ResolveTraceTarget,
+    },
 };
 use crate::{
     context::AssetContext,
@@ -40,6 +47,7 @@ use crate::{
     package_json::{read_package_json, PackageJsonIssue},
     raw_module::RawModule,
     reference_type::ReferenceType,
+    remote_source::{remote_request_url, RemoteSource},
     resolve::{
         node::{node_cjs_resolve_options, node_esm_resolve_options},
         pattern::{read_matches, PatternMatch},
@@ -49,13 +57,17 @@ use crate::{
 };
 
 mod alias_map;
+mod case_sensitivity;
 pub mod node;
 pub mod options;
 pub mod origin;
 pub mod parse;
 pub mod pattern;
 pub mod plugin;
+mod pnpm;
 pub(crate) mod remap;
+mod restricted_imports;
+pub mod trace;
 
 pub use alias_map::{
     AliasMap, AliasMapIntoIter, AliasMapLookupIterator, AliasMatch, AliasPattern, AliasTemplate,
@@ -1051,24 +1063,37 @@ impl ResolveResultOption {
 
 async fn exists(
     fs_path: Vc<FileSystemPath>,
+    preserve_symlinks: bool,
     refs: &mut Vec<ResolvedVc<Box<dyn Source>>>,
 ) -> Result<Option<ResolvedVc<FileSystemPath>>> {
-    type_exists(fs_path, FileSystemEntryType::File, refs).await
+    type_exists(fs_path, FileSystemEntryType::File, preserve_symlinks, refs).await
 }
 
 async fn dir_exists(
     fs_path: Vc<FileSystemPath>,
+    preserve_symlinks: bool,
     refs: &mut Vec<ResolvedVc<Box<dyn Source>>>,
 ) -> Result<Option<ResolvedVc<FileSystemPath>>> {
-    type_exists(fs_path, FileSystemEntryType::Directory, refs).await
+    type_exists(
+        fs_path,
+        FileSystemEntryType::Directory,
+        preserve_symlinks,
+        refs,
+    )
+    .await
 }
 
+/// Checks the type of the real path of `fs_path`, returning the real path, or
+/// `fs_path` itself when `preserve_symlinks` is set. The symlinks on the way
+/// are added to `refs` either way.
 async fn type_exists(
     fs_path: Vc<FileSystemPath>,
     ty: FileSystemEntryType,
+    preserve_symlinks: bool,
     refs: &mut Vec<ResolvedVc<Box<dyn Source>>>,
 ) -> Result<Option<ResolvedVc<FileSystemPath>>> {
-    let result = fs_path.resolve().await?.realpath_with_links().await?;
+    let fs_path = fs_path.to_resolved().await?;
+    let result = fs_path.realpath_with_links().await?;
     refs.extend(
         result
             .symlinks
@@ -1083,7 +1108,11 @@ async fn type_exists(
     );
     let path = result.path;
     Ok(if *path.get_type().await? == ty {
-        Some(path)
+        Some(if preserve_symlinks && keep_symlinked_path(*path).await? {
+            fs_path
+        } else {
+            path
+        })
     } else {
         None
     })
@@ -1091,9 +1120,11 @@ async fn type_exists(
 
 async fn any_exists(
     fs_path: Vc<FileSystemPath>,
+    preserve_symlinks: bool,
     refs: &mut Vec<ResolvedVc<Box<dyn Source>>>,
 ) -> Result<Option<(FileSystemEntryType, Vc<FileSystemPath>)>> {
-    let result = fs_path.resolve().await?.realpath_with_links().await?;
+    let fs_path = fs_path.resolve().await?;
+    let result = fs_path.realpath_with_links().await?;
     refs.extend(
         result
             .symlinks
@@ -1115,7 +1146,14 @@ async fn any_exists(
         ) {
             None
         } else {
-            Some((ty, *path))
+            Some((
+                ty,
+                if preserve_symlinks && keep_symlinked_path(*path).await? {
+                    fs_path
+                } else {
+                    *path
+                },
+            ))
         },
     )
 }
@@ -1160,6 +1198,8 @@ enum ImportsFieldResult {
     Some(
         #[turbo_tasks(debug_ignore, trace_ignore)] ImportsField,
         ResolvedVc<FileSystemPath>,
+        /// The name of the package.
+        Option<RcStr>,
     ),
     None,
 }
@@ -1182,8 +1222,12 @@ async fn imports_field(lookup_path: Vc<FileSystemPath>) -> Result<Vc<ImportsFiel
     let Some(imports) = package_json.get("imports") else {
         return Ok(ImportsFieldResult::None.cell());
     };
+    let name = package_json
+        .get("name")
+        .and_then(|name| name.as_str())
+        .map(RcStr::from);
     match imports.try_into() {
-        Ok(imports) => Ok(ImportsFieldResult::Some(imports, *package_json_path).cell()),
+        Ok(imports) => Ok(ImportsFieldResult::Some(imports, *package_json_path, name).cell()),
         Err(err) => {
             PackageJsonIssue {
                 path: *package_json_path,
@@ -1313,9 +1357,12 @@ async fn find_package(
 ) -> Result<Vc<FindPackageResult>> {
     let mut packages = vec![];
     let mut affecting_sources = vec![];
+    let mut candidates = is_traced(&package_name).then(Vec::new);
     let options = options.await?;
+    let mut handled_by_plugin = false;
     for resolve_modules in &options.modules {
         match resolve_modules {
+            ResolveModules::Nested(..) if handled_by_plugin => {}
             ResolveModules::Nested(root_vc, names) => {
                 let mut lookup_path = lookup_path;
                 let mut lookup_path_value = lookup_path.await?;
@@ -1324,14 +1371,25 @@ async fn find_package(
                 while lookup_path_value.is_inside_ref(root) {
                     for name in names.iter() {
                         let fs_path = lookup_path.join(name.clone());
-                        if let Some(fs_path) = dir_exists(fs_path, &mut affecting_sources).await? {
+                        let mut found = false;
+                        if let Some(fs_path) =
+                            dir_exists(fs_path, options.preserve_symlinks, &mut affecting_sources)
+                                .await?
+                        {
                             let fs_path = fs_path.join(package_name.clone());
-                            if let Some(fs_path) =
-                                dir_exists(fs_path, &mut affecting_sources).await?
+                            if let Some(fs_path) = dir_exists(
+                                fs_path,
+                                options.preserve_symlinks,
+                                &mut affecting_sources,
+                            )
+                            .await?
                             {
                                 packages.push(FindPackageItem::PackageDirectory(fs_path));
+                                found = true;
                             }
                         }
+                        trace_candidate(&mut candidates, fs_path.join(package_name.clone()), found)
+                            .await?;
                     }
                     lookup_path = lookup_path.parent().resolve().await?;
                     let new_context_value = lookup_path.await?;
@@ -1347,9 +1405,14 @@ async fn find_package(
             } => {
                 let excluded_extensions = excluded_extensions.await?;
                 let package_dir = dir.join(package_name.clone());
-                if let Some((ty, package_dir)) =
-                    any_exists(package_dir, &mut affecting_sources).await?
-                {
+                let found = any_exists(
+                    package_dir,
+                    options.preserve_symlinks,
+                    &mut affecting_sources,
+                )
+                .await?;
+                trace_candidate(&mut candidates, package_dir, found.is_some()).await?;
+                if let Some((ty, package_dir)) = found {
                     match ty {
                         FileSystemEntryType::Directory => {
                             packages.push(FindPackageItem::PackageDirectory(
@@ -1369,20 +1432,72 @@ async fn find_package(
                         continue;
                     }
                     let package_file = package_dir.append(extension.clone());
-                    if let Some(package_file) = exists(package_file, &mut affecting_sources).await?
-                    {
+                    let found = exists(
+                        package_file,
+                        options.preserve_symlinks,
+                        &mut affecting_sources,
+                    )
+                    .await?;
+                    trace_candidate(&mut candidates, package_file, found.is_some()).await?;
+                    if let Some(package_file) = found {
                         packages.push(FindPackageItem::PackageFile(package_file));
                     }
                 }
             }
+            ResolveModules::Plugin(plugin) => {
+                match &*plugin
+                    .find_package(lookup_path, package_name.clone())
+                    .await?
+                {
+                    PackageLookupResult::Unhandled => {}
+                    PackageLookupResult::Found {
+                        package_dir,
+                        affecting_sources: plugin_affecting_sources,
+                    } => {
+                        handled_by_plugin = true;
+                        trace_candidate(&mut candidates, **package_dir, true).await?;
+                        packages.push(FindPackageItem::PackageDirectory(*package_dir));
+                        affecting_sources.extend(plugin_affecting_sources.iter().copied());
+                    }
+                    PackageLookupResult::NotFound {
+                        affecting_sources: plugin_affecting_sources,
+                    } => {
+                        handled_by_plugin = true;
+                        affecting_sources.extend(plugin_affecting_sources.iter().copied());
+                    }
+                }
+            }
         }
     }
+    if let Some(candidates) = candidates {
+        trace_resolve_step(ResolveTraceStep::FindPackage {
+            request: package_name.to_string(),
+            lookup_path: lookup_path.to_string().await?.to_string(),
+            candidates,
+        });
+    }
     Ok(FindPackageResult::cell(FindPackageResult {
         packages,
         affecting_sources,
     }))
 }
 
+/// Adds a location a package was looked up in to the resolution trace, if the
+/// package is traced.
+async fn trace_candidate(
+    candidates: &mut Option<Vec<ResolveTraceCandidate>>,
+    path: Vc<FileSystemPath>,
+    found: bool,
+) -> Result<()> {
+    if let Some(candidates) = candidates {
+        candidates.push(ResolveTraceCandidate {
+            path: path.to_string().await?.to_string(),
+            found,
+        });
+    }
+    Ok(())
+}
+
 fn merge_results(results: Vec<Vc<ResolveResult>>) -> Vc<ResolveResult> {
     match results.len() {
         0 => ResolveResult::unresolvable().into(),
@@ -1527,6 +1642,20 @@ pub async fn resolve_inline(
         let result =
             handle_after_resolve_plugins(lookup_path, reference_type, request, options, raw_result)
                 .await?;
+
+        if options.await?.case_sensitive_paths {
+            check_resolve_result_casing(lookup_path, request, result).await?;
+        }
+
+        let request_str = request.to_string().await?;
+        if is_traced(&request_str) {
+            trace_resolve_step(ResolveTraceStep::Result {
+                request: request_str.to_string(),
+                lookup_path: lookup_path.to_string().await?.to_string(),
+                results: describe_resolve_result(result).await?,
+            });
+        }
+
         Ok(result)
     }
     .instrument(span)
@@ -1715,6 +1844,14 @@ async fn resolve_internal_inline(
                 let result = import_map.await?.lookup(lookup_path, *request).await?;
                 if !matches!(result, ImportMapResult::NoEntry) {
                     has_alias = true;
+                    let request_str = request.to_string().await?;
+                    if is_traced(&request_str) {
+                        trace_resolve_step(ResolveTraceStep::Alias {
+                            request: request_str.to_string(),
+                            lookup_path: lookup_path.to_string().await?.to_string(),
+                            alias: describe_import_map_result(&result).await?,
+                        });
+                    }
                     let resolved_result = resolve_import_map_result(
                         &result,
                         lookup_path,
@@ -1739,6 +1876,17 @@ async fn resolve_internal_inline(
             }
         }
 
+        if let Some(remote_imports) = options_value.remote_imports {
+            if let Some(url) =
+                remote_request_url(*remote_imports, lookup_path, &request_value).await?
+            {
+                return Ok(ResolveResult::source(ResolvedVc::upcast(
+                    RemoteSource::new(url, remote_imports).to_resolved().await?,
+                ))
+                .cell());
+            }
+        }
+
         let result = match &*request_value {
             Request::Dynamic => ResolveResult::unresolvable().into(),
             Request::Alternatives { requests } => {
@@ -1903,27 +2051,8 @@ async fn resolve_internal_inline(
             }
             Request::Empty => ResolveResult::unresolvable().into(),
             Request::PackageInternal { path } => {
-                let options_value = options.await?;
-                let (conditions, unspecified_conditions) = options_value
-                    .in_package
-                    .iter()
-                    .find_map(|item| match item {
-                        ResolveInPackage::ImportsField {
-                            conditions,
-                            unspecified_conditions,
-                        } => Some((Cow::Borrowed(conditions), *unspecified_conditions)),
-                        _ => None,
-                    })
-                    .unwrap_or_else(|| (Default::default(), ConditionValue::Unset));
-                resolve_package_internal_with_imports_field(
-                    lookup_path,
-                    request,
-                    options,
-                    path,
-                    &conditions,
-                    &unspecified_conditions,
-                )
-                .await?
+                resolve_package_internal_with_imports_field(lookup_path, request, options, path)
+                    .await?
             }
             Request::Uri {
                 protocol,
@@ -2307,14 +2436,10 @@ async fn apply_in_package(
             continue;
         };
 
-        let value = if let Some(value) = field_value.get(&*request) {
-            value
-        } else if let Some(request) = request.strip_prefix("./") {
-            let Some(value) = field_value.get(request) else {
-                continue;
-            };
-            value
-        } else {
+        let Some((key, value)) = alias_field_keys(&request, options_value)
+            .into_iter()
+            .find_map(|key| field_value.get(&*key).map(|value| (key, value)))
+        else {
             continue;
         };
 
@@ -2333,7 +2458,7 @@ async fn apply_in_package(
         }
 
         if let Some(value) = value.as_str() {
-            if value == &*request {
+            if value == &*request || value == &*key {
                 // This would be a cycle, so we ignore it
                 return Ok(None);
             }
@@ -2371,6 +2496,43 @@ async fn apply_in_package(
     Ok(None)
 }
 
+/// Returns the keys under which a request may appear in an alias field (e.g.
+/// the object form of `browser`), in order of precedence.
+///
+/// Besides the request itself and its form without the `./` prefix, relative
+/// requests also match keys with one of the configured extensions or default
+/// files appended. Alias fields usually refer to fully specified files, and the
+/// aliased file doesn't need to exist on disk (e.g. `"./lib/node.js": false`).
+fn alias_field_keys(request: &RcStr, options_value: &ResolveOptions) -> Vec<RcStr> {
+    let mut keys = vec![request.clone()];
+    let Some(unprefixed) = request.strip_prefix("./") else {
+        return keys;
+    };
+    keys.push(unprefixed.into());
+
+    if options_value.fully_specified {
+        return keys;
+    }
+
+    let base = request.trim_end_matches('/');
+    for extension in options_value.extensions.iter() {
+        keys.push(format!("{base}{extension}").into());
+    }
+    for default_file in options_value.default_files.iter() {
+        for extension in options_value.extensions.iter() {
+            keys.push(format!("{base}/{default_file}{extension}").into());
+        }
+    }
+    let unprefixed_keys = keys
+        .iter()
+        .skip(2)
+        .filter_map(|key| key.strip_prefix("./").map(RcStr::from))
+        .collect::<Vec<_>>();
+    keys.extend(unprefixed_keys);
+
+    keys
+}
+
 #[turbo_tasks::value]
 enum FindSelfReferencePackageResult {
     Found {
@@ -2432,6 +2594,15 @@ async fn resolve_module_request(
         return Ok(result);
     }
 
+    let options = if options_value.package_conditions.contains_key(module) {
+        options
+            .with_package_conditions(module.into())
+            .resolve()
+            .await?
+    } else {
+        options
+    };
+
     // Self references, if the nearest package.json has the name of the requested
     // module. This should match only using the exports field and no other
     // fields/fallbacks.
@@ -2610,6 +2781,33 @@ async fn resolve_into_package(
     Ok(merge_results(results))
 }
 
+/// Describes what an import map entry maps a request to, for the resolution
+/// trace.
+async fn describe_import_map_result(result: &ImportMapResult) -> Result<String> {
+    Ok(match result {
+        ImportMapResult::Result(result) => describe_resolve_result(**result).await?.join(", "),
+        ImportMapResult::Alias(request, _) => request.to_string().await?.to_string(),
+        ImportMapResult::External(name, ..) | ImportMapResult::AliasExternal { name, .. } => {
+            format!("external {name}")
+        }
+        ImportMapResult::Alternatives(list) => {
+            let mut alternatives = vec![];
+            for result in list {
+                alternatives.push(Box::pin(describe_import_map_result(result)).await?);
+            }
+            alternatives.join(" | ")
+        }
+        ImportMapResult::Fallbacks(list) => {
+            let mut fallbacks = vec![];
+            for result in list {
+                fallbacks.push(Box::pin(describe_import_map_result(result)).await?);
+            }
+            fallbacks.join(" || ")
+        }
+        ImportMapResult::NoEntry => String::new(),
+    })
+}
+
 #[tracing::instrument(level = Level::TRACE, skip_all)]
 async fn resolve_import_map_result(
     result: &ImportMapResult,
@@ -2708,6 +2906,28 @@ async fn resolve_import_map_result(
 
             Some(merge_results(results.into_iter().flatten().collect()))
         }
+        ImportMapResult::Fallbacks(list) => {
+            let mut last_result = None;
+            for result in list {
+                let Some(result) = Box::pin(resolve_import_map_result(
+                    result,
+                    lookup_path,
+                    original_lookup_path,
+                    original_request,
+                    options,
+                    query,
+                ))
+                .await?
+                else {
+                    continue;
+                };
+                if !*result.is_unresolvable().await? {
+                    return Ok(Some(result));
+                }
+                last_result = Some(result);
+            }
+            last_result
+        }
         ImportMapResult::NoEntry => None,
     })
 }
@@ -2724,6 +2944,11 @@ async fn resolved(
     fragment: Vc<RcStr>,
 ) -> Result<Vc<ResolveResult>> {
     let RealPathResult { path, symlinks } = &*fs_path.realpath_with_links().await?;
+    let path = &if options_value.preserve_symlinks && keep_symlinked_path(**path).await? {
+        fs_path.to_resolved().await?
+    } else {
+        *path
+    };
 
     let path_ref = &*path.await?;
     // Check alias field for path aliases first
@@ -2762,11 +2987,10 @@ async fn resolved(
 
     Ok(ResolveResult::source_with_affecting_sources(
         request_key,
-        ResolvedVc::upcast(
-            FileSource::new_with_query(**path, query)
-                .to_resolved()
-                .await?,
-        ),
+        pnpm_file_source(**path, query, options_value.pnpm_injected_from_source)
+            .await?
+            .to_resolved()
+            .await?,
         symlinks
             .iter()
             .map(|symlink| async move {
@@ -2792,12 +3016,16 @@ async fn handle_exports_imports_field(
 ) -> Result<Vc<ResolveResult>> {
     let mut results = Vec::new();
     let mut conditions_state = HashMap::new();
+    let is_imports = path.starts_with('#');
 
     let query_str = query.await?;
     let req = Pattern::Constant(format!("{}{}", path, query_str).into());
 
     let values = exports_imports_field
         .lookup(&req)
+        // Only the most specific key of an "imports" field applies, Node.js doesn't fall back to
+        // less specific keys when none of its conditions match.
+        .take(if is_imports { 1 } else { usize::MAX })
         .map(AliasMatch::try_into_self)
         .collect::<Result<Vec<_>>>()?;
 
@@ -2812,25 +3040,64 @@ async fn handle_exports_imports_field(
         }
     }
 
+    let package_path_value = package_path.await?;
+    let request = exports_imports_field_request(&package_path_value.path, path);
+    if is_traced(&request) {
+        trace_resolve_step(ResolveTraceStep::ExportsImportsField {
+            request,
+            package_json: package_json_path.to_string().await?.to_string(),
+            targets: results
+                .iter()
+                .map(|(target, conditions)| ResolveTraceTarget {
+                    target: target.to_string(),
+                    conditions: conditions
+                        .iter()
+                        .map(|&(condition, set)| {
+                            if set {
+                                condition.to_string()
+                            } else {
+                                format!("!{condition}")
+                            }
+                        })
+                        .collect(),
+                })
+                .collect(),
+        });
+    }
+
     let mut resolved_results = Vec::new();
     for (result_path, conditions) in results {
-        if let Some(result_path) = result_path.with_normalized_path() {
-            let request = Request::parse(Value::new(Pattern::Concatenation(vec![
+        let request = if is_imports && !result_path.constant_prefix().starts_with("./") {
+            // Targets of the "imports" field that aren't relative are package requests, e.g.
+            // `"#dep": "dep/sub"`.
+            Request::parse(Value::new(result_path.clone()))
+        } else {
+            // The part of the request matched by a `*` must not leave the package either.
+            if is_imports
+                && matches!(result_path, Pattern::Constant(result_path)
+                    if result_path.strip_prefix("./").is_some_and(has_invalid_segments))
+            {
+                continue;
+            }
+            let Some(result_path) = result_path.with_normalized_path() else {
+                continue;
+            };
+            Request::parse(Value::new(Pattern::Concatenation(vec![
                 Pattern::Constant("./".into()),
                 result_path,
             ])))
-            .to_resolved()
-            .await?;
+        }
+        .to_resolved()
+        .await?;
 
-            let resolve_result =
-                Box::pin(resolve_internal_inline(package_path, *request, options)).await?;
-            if conditions.is_empty() {
-                resolved_results.push(resolve_result.with_request(path.into()));
-            } else {
-                let mut resolve_result = resolve_result.await?.with_request_ref(path.into());
-                resolve_result.add_conditions(conditions);
-                resolved_results.push(resolve_result.cell());
-            }
+        let resolve_result =
+            Box::pin(resolve_internal_inline(package_path, *request, options)).await?;
+        if conditions.is_empty() {
+            resolved_results.push(resolve_result.with_request(path.into()));
+        } else {
+            let mut resolve_result = resolve_result.await?.with_request_ref(path.into());
+            resolve_result.add_conditions(conditions);
+            resolved_results.push(resolve_result.cell());
         }
     }
 
@@ -2843,17 +3110,32 @@ async fn handle_exports_imports_field(
     ))
 }
 
+/// The request resolved through the `exports` or `imports` field of the
+/// package in `package_path`, e.g. `lodash/fp` for the subpath `./fp`, for the
+/// resolution trace.
+fn exports_imports_field_request(package_path: &str, path: &str) -> String {
+    if path.starts_with('#') {
+        return path.to_string();
+    }
+    let mut segments = package_path.rsplit('/');
+    let name = segments.next().unwrap_or_default();
+    let package_name = match segments.next() {
+        Some(scope) if scope.starts_with('@') => format!("{scope}/{name}"),
+        _ => name.to_string(),
+    };
+    format!("{package_name}{}", path.trim_start_matches('.'))
+}
+
 /// Resolves a `#dep` import using the containing package.json's `imports`
 /// field. The dep may be a constant string or a pattern, and the values can be
 /// static strings or conditions like `import` or `require` to handle ESM/CJS
-/// with differently compiled files.
+/// with differently compiled files. Packages with `package_conditions` are
+/// resolved with those, like their exports field.
 async fn resolve_package_internal_with_imports_field(
     file_path: Vc<FileSystemPath>,
     request: Vc<Request>,
     resolve_options: Vc<ResolveOptions>,
     pattern: &Pattern,
-    conditions: &BTreeMap<RcStr, ConditionValue>,
-    unspecified_conditions: &ConditionValue,
 ) -> Result<Vc<ResolveResult>> {
     let Pattern::Constant(specifier) = pattern else {
         bail!("PackageInternal requests can only be Constant strings");
@@ -2875,19 +3157,41 @@ async fn resolve_package_internal_with_imports_field(
     }
 
     let imports_result = imports_field(file_path).await?;
-    let (imports, package_json_path) = match &*imports_result {
-        ImportsFieldResult::Some(i, p) => (i, *p),
+    let (imports, package_json_path, name) = match &*imports_result {
+        ImportsFieldResult::Some(i, p, n) => (i, *p, n),
         ImportsFieldResult::None => return Ok(ResolveResult::unresolvable().cell()),
     };
 
+    let resolve_options = match name {
+        Some(name) if resolve_options.await?.package_conditions.contains_key(name) => {
+            resolve_options
+                .with_package_conditions(name.clone())
+                .resolve()
+                .await?
+        }
+        _ => resolve_options,
+    };
+    let options_value = resolve_options.await?;
+    let (conditions, unspecified_conditions) = options_value
+        .in_package
+        .iter()
+        .find_map(|item| match item {
+            ResolveInPackage::ImportsField {
+                conditions,
+                unspecified_conditions,
+            } => Some((Cow::Borrowed(conditions), *unspecified_conditions)),
+            _ => None,
+        })
+        .unwrap_or_else(|| (Default::default(), ConditionValue::Unset));
+
     handle_exports_imports_field(
         package_json_path.parent(),
         *package_json_path,
         resolve_options,
         imports,
         specifier,
-        conditions,
-        unspecified_conditions,
+        &conditions,
+        &unspecified_conditions,
         Vc::<RcStr>::default(),
     )
     .await
@@ -2905,6 +3209,7 @@ pub async fn handle_resolve_error(
     async fn is_unresolvable(result: Vc<ModuleResolveResult>) -> Result<bool> {
         Ok(*result.resolve().await?.is_unresolvable().await?)
     }
+    check_restricted_imports(origin_path, request, resolve_options, source).await?;
     Ok(match is_unresolvable(result).await {
         Ok(unresolvable) => {
             if unresolvable {
@@ -2949,6 +3254,7 @@ pub async fn handle_resolve_source_error(
     async fn is_unresolvable(result: Vc<ResolveResult>) -> Result<bool> {
         Ok(*result.resolve().await?.is_unresolvable().await?)
     }
+    check_restricted_imports(origin_path, request, resolve_options, source).await?;
     Ok(match is_unresolvable(result).await {
         Ok(unresolvable) => {
             if unresolvable {
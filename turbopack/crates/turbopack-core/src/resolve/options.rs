@@ -12,10 +12,13 @@ use turbo_tasks_fs::{glob::Glob, FileSystemPath};
 use super::{
     alias_map::{AliasMap, AliasTemplate},
     pattern::Pattern,
-    plugin::BeforeResolvePlugin,
+    plugin::{BeforeResolvePlugin, PackageLookupPlugin},
     AliasPattern, ExternalType, ResolveResult, ResolveResultItem,
 };
-use crate::resolve::{parse::Request, plugin::AfterResolvePlugin, ExternalTraced};
+use crate::{
+    remote_source::RemoteImports,
+    resolve::{parse::Request, plugin::AfterResolvePlugin, ExternalTraced},
+};
 
 #[turbo_tasks::value(shared)]
 #[derive(Hash, Debug)]
@@ -25,6 +28,30 @@ pub struct LockedVersions {}
 #[derive(Debug)]
 pub struct ExcludedExtensions(pub FxIndexSet<RcStr>);
 
+/// Forbids the modules matching `from` to import requests matching one of
+/// the `disallow` globs. `from` is relative to the root of the file system of
+/// the importing module, `disallow` matches the request as written, e.g.
+/// `lodash/**` or `../server/**`.
+#[derive(
+    TraceRawVcs,
+    Hash,
+    PartialEq,
+    Eq,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    ValueDebugFormat,
+    NonLocalValue,
+)]
+pub struct RestrictedImport {
+    pub from: RcStr,
+    pub disallow: Vec<RcStr>,
+    /// Explains why the import is restricted, e.g. which module to use
+    /// instead.
+    pub message: Option<RcStr>,
+}
+
 /// A location where to resolve modules.
 #[derive(
     TraceRawVcs,
@@ -47,6 +74,10 @@ pub enum ResolveModules {
         dir: ResolvedVc<FileSystemPath>,
         excluded_extensions: ResolvedVc<ExcludedExtensions>,
     },
+    /// let the plugin find the package directory, e.g. from the Yarn
+    /// Plug'n'Play manifest. When the plugin handles a lookup, the
+    /// following `Nested` locations are skipped.
+    Plugin(ResolvedVc<Box<dyn PackageLookupPlugin>>),
 }
 
 #[derive(
@@ -126,6 +157,10 @@ pub enum ImportMapping {
     Ignore,
     Empty,
     Alternatives(Vec<ResolvedVc<ImportMapping>>),
+    /// Mappings which are tried in order, the first one which resolves is
+    /// used. Useful for the fallback arrays of the tsconfig.json
+    /// `compilerOptions.paths` option.
+    Fallbacks(Vec<ResolvedVc<ImportMapping>>),
     Dynamic(ResolvedVc<Box<dyn ImportMappingReplacement>>),
 }
 
@@ -146,6 +181,7 @@ pub enum ReplacedImportMapping {
     Ignore,
     Empty,
     Alternatives(Vec<ResolvedVc<ReplacedImportMapping>>),
+    Fallbacks(Vec<ResolvedVc<ReplacedImportMapping>>),
     Dynamic(ResolvedVc<Box<dyn ImportMappingReplacement>>),
 }
 
@@ -166,6 +202,23 @@ impl ImportMapping {
             )
         }
     }
+
+    /// Like [`ImportMapping::primary_alternatives`], but only the first of the
+    /// `list` which resolves is used.
+    pub fn primary_fallbacks(
+        list: Vec<RcStr>,
+        lookup_path: Option<ResolvedVc<FileSystemPath>>,
+    ) -> ImportMapping {
+        if list.len() <= 1 {
+            Self::primary_alternatives(list, lookup_path)
+        } else {
+            ImportMapping::Fallbacks(
+                list.into_iter()
+                    .map(|s| ImportMapping::PrimaryAlternative(s, lookup_path).resolved_cell())
+                    .collect(),
+            )
+        }
+    }
 }
 
 impl AliasTemplate for ResolvedVc<ImportMapping> {
@@ -215,6 +268,13 @@ impl AliasTemplate for Vc<ImportMapping> {
                         .try_join()
                         .await?,
                 ),
+                ImportMapping::Fallbacks(fallbacks) => ReplacedImportMapping::Fallbacks(
+                    fallbacks
+                        .iter()
+                        .map(|mapping| mapping.convert())
+                        .try_join()
+                        .await?,
+                ),
                 ImportMapping::Dynamic(replacement) => ReplacedImportMapping::Dynamic(*replacement),
             }
             .resolved_cell())
@@ -275,6 +335,13 @@ impl AliasTemplate for Vc<ImportMapping> {
                         .try_join()
                         .await?,
                 ),
+                ImportMapping::Fallbacks(fallbacks) => ReplacedImportMapping::Fallbacks(
+                    fallbacks
+                        .iter()
+                        .map(|mapping| mapping.replace(&capture))
+                        .try_join()
+                        .await?,
+                ),
                 ImportMapping::Dynamic(replacement) => {
                     (*replacement.replace(capture.clone().cell()).await?).clone()
                 }
@@ -396,6 +463,7 @@ pub enum ImportMapResult {
     },
     Alias(ResolvedVc<Request>, Option<ResolvedVc<FileSystemPath>>),
     Alternatives(Vec<ImportMapResult>),
+    Fallbacks(Vec<ImportMapResult>),
     NoEntry,
 }
 
@@ -452,6 +520,12 @@ async fn import_mapping_to_result(
                 .try_join()
                 .await?,
         ),
+        ReplacedImportMapping::Fallbacks(list) => ImportMapResult::Fallbacks(
+            list.iter()
+                .map(|mapping| Box::pin(import_mapping_to_result(**mapping, lookup_path, request)))
+                .try_join()
+                .await?,
+        ),
         ReplacedImportMapping::Dynamic(replacement) => replacement
             .result(lookup_path, request)
             .await?
@@ -492,6 +566,18 @@ impl ValueToString for ImportMapResult {
                     .collect::<Vec<_>>();
                 Ok(Vc::cell(strings.join(" | ").into()))
             }
+            ImportMapResult::Fallbacks(fallbacks) => {
+                let strings = fallbacks
+                    .iter()
+                    .map(|fallback| fallback.clone().cell().to_string())
+                    .try_join()
+                    .await?;
+                let strings = strings
+                    .iter()
+                    .map(|string| string.as_str())
+                    .collect::<Vec<_>>();
+                Ok(Vc::cell(strings.join(" || ").into()))
+            }
             ImportMapResult::NoEntry => Ok(Vc::cell("No import map entry".into())),
         }
     }
@@ -603,6 +689,27 @@ pub struct ResolveOptions {
     pub enable_typescript_with_output_extension: bool,
     /// Warn instead of error for resolve errors
     pub loose_errors: bool,
+    /// Conditions that replace the ones used for the exports and imports fields
+    /// when resolving into specific packages, keyed by package name. This
+    /// allows using e.g. the browser build of a package with a broken exports
+    /// field on the server.
+    pub package_conditions: BTreeMap<RcStr, ResolutionConditions>,
+    /// Keep the symlinked path of resolved files instead of resolving it to
+    /// the real path, so that e.g. a linked local package keeps the path
+    /// inside of `node_modules`. Like webpack's `resolve.symlinks: false`.
+    pub preserve_symlinks: bool,
+    /// Emit an issue when the casing of a resolved path differs from the
+    /// entries on disk, which only resolves on case-insensitive file systems.
+    pub case_sensitive_paths: bool,
+    /// Imports that are forbidden from some modules, reported as errors.
+    pub restricted_imports: Vec<RestrictedImport>,
+    /// Resolve `https://` requests, and relative requests within remote
+    /// modules, to modules fetched from the URL.
+    pub remote_imports: Option<ResolvedVc<RemoteImports>>,
+    /// Read the files of pnpm's injected dependencies from the workspace
+    /// package they were copied from, so that changes of the package show up
+    /// without running `pnpm install`.
+    pub pnpm_injected_from_source: bool,
 
     pub placeholder_for_future_extensions: (),
 }
@@ -658,6 +765,39 @@ impl ResolveOptions {
         Ok(resolve_options.into())
     }
 
+    /// Returns a new [Vc<ResolveOptions>] using the conditions configured for
+    /// `package` in `package_conditions` for the exports and imports fields.
+    #[turbo_tasks::function]
+    pub async fn with_package_conditions(self: Vc<Self>, package: RcStr) -> Result<Vc<Self>> {
+        let resolve_options = self.await?;
+        let Some(package_conditions) = resolve_options.package_conditions.get(&package) else {
+            return Ok(self);
+        };
+        let package_conditions = package_conditions.clone();
+        let mut resolve_options = resolve_options.clone_value();
+        for item in resolve_options.into_package.iter_mut() {
+            if let ResolveIntoPackage::ExportsField {
+                conditions,
+                unspecified_conditions,
+            } = item
+            {
+                *conditions = package_conditions.clone();
+                *unspecified_conditions = ConditionValue::Unset;
+            }
+        }
+        for item in resolve_options.in_package.iter_mut() {
+            if let ResolveInPackage::ImportsField {
+                conditions,
+                unspecified_conditions,
+            } = item
+            {
+                *conditions = package_conditions.clone();
+                *unspecified_conditions = ConditionValue::Unset;
+            }
+        }
+        Ok(resolve_options.cell())
+    }
+
     /// Overrides the fully_specified flag for resolving
     #[turbo_tasks::function]
     pub async fn with_fully_specified(self: Vc<Self>, fully_specified: bool) -> Result<Vc<Self>> {
@@ -676,6 +816,7 @@ impl ResolveOptions {
 pub struct ResolveModulesOptions {
     pub modules: Vec<ResolveModules>,
     pub extensions: Vec<RcStr>,
+    pub preserve_symlinks: bool,
 }
 
 #[turbo_tasks::function]
@@ -686,6 +827,7 @@ pub async fn resolve_modules_options(
     Ok(ResolveModulesOptions {
         modules: options.modules.clone(),
         extensions: options.extensions.clone(),
+        preserve_symlinks: options.preserve_symlinks,
     }
     .into())
 }
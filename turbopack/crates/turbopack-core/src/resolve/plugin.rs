@@ -6,6 +6,7 @@ use turbo_tasks_fs::{glob::Glob, FileSystemPath};
 use crate::{
     reference_type::ReferenceType,
     resolve::{parse::Request, ResolveResultOption},
+    source::Source,
 };
 
 /// A condition which determines if the hooks of a resolve plugin gets called.
@@ -105,3 +106,33 @@ pub trait AfterResolvePlugin {
         request: Vc<Request>,
     ) -> Vc<ResolveResultOption>;
 }
+
+/// The result of looking up a package with a [PackageLookupPlugin].
+#[turbo_tasks::value(shared)]
+#[derive(Debug)]
+pub enum PackageLookupResult {
+    /// The plugin doesn't handle lookups from this location, so the package
+    /// is looked up in the other configured locations.
+    Unhandled,
+    /// The package is located in this directory.
+    Found {
+        package_dir: ResolvedVc<FileSystemPath>,
+        affecting_sources: Vec<ResolvedVc<Box<dyn Source>>>,
+    },
+    /// The package is not available from this location. The plugin is
+    /// expected to report the reason as an issue.
+    NotFound {
+        affecting_sources: Vec<ResolvedVc<Box<dyn Source>>>,
+    },
+}
+
+/// A plugin which finds the directories of packages, replacing the lookup in
+/// `node_modules` directories.
+#[turbo_tasks::value_trait]
+pub trait PackageLookupPlugin {
+    fn find_package(
+        self: Vc<Self>,
+        lookup_path: Vc<FileSystemPath>,
+        package_name: RcStr,
+    ) -> Vc<PackageLookupResult>;
+}
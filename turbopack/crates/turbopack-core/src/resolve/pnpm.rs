@@ -0,0 +1,225 @@
+//! Support for the layout of pnpm's virtual store, where every package is
+//! installed once per set of peer dependencies at
+//! `node_modules/.pnpm/<name>@<version>_<peers>/node_modules/<name>`, and
+//! `node_modules` of projects only contain symlinks into it.
+
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{util::join_path, FileSystemEntryType, FileSystemPath};
+
+use crate::{
+    asset::{Asset, AssetContent},
+    file_source::FileSource,
+    ident::AssetIdent,
+    source::Source,
+};
+
+const VIRTUAL_STORE: &str = "node_modules/.pnpm/";
+
+/// A path inside of a package in the virtual store.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) struct VirtualStorePath<'a> {
+    /// The directory containing the `node_modules` of the virtual store,
+    /// usually the root of the workspace.
+    pub root: &'a str,
+    /// The directory name of the package in the virtual store, which
+    /// identifies its name, version and the versions of its peer
+    /// dependencies, e.g. `react-dom@18.2.0_react@18.2.0`.
+    pub entry: &'a str,
+    /// The path inside of the package.
+    pub inner: &'a str,
+}
+
+/// Splits a path into the parts of the virtual store, if it's inside of a
+/// package installed there.
+pub(super) fn virtual_store_path(path: &str) -> Option<VirtualStorePath<'_>> {
+    let start = path
+        .rmatch_indices(VIRTUAL_STORE)
+        .map(|(index, _)| index)
+        .find(|&index| index == 0 || path[..index].ends_with('/'))?;
+    let root = path[..start].trim_end_matches('/');
+    let (entry, rest) = path[start + VIRTUAL_STORE.len()..].split_once('/')?;
+    let rest = rest.strip_prefix("node_modules/")?;
+    let name_len = if rest.starts_with('@') {
+        let (scope, rest) = rest.split_once('/')?;
+        scope.len() + 1 + rest.find('/').unwrap_or(rest.len())
+    } else {
+        rest.find('/').unwrap_or(rest.len())
+    };
+    Some(VirtualStorePath {
+        root,
+        entry,
+        inner: rest[name_len..].trim_start_matches('/'),
+    })
+}
+
+/// Whether a symlinked path should be kept when resolving with
+/// `preserve_symlinks`. Packages in the virtual store are identified by their
+/// real path instead, so that a package installed with the same peer
+/// dependencies is a single module no matter which project's `node_modules`
+/// it's reached through.
+pub(super) async fn keep_symlinked_path(real_path: Vc<FileSystemPath>) -> Result<bool> {
+    Ok(virtual_store_path(&real_path.await?.path).is_none())
+}
+
+/// The workspace package, relative to the root of the workspace, that an
+/// injected dependency (`dependenciesMeta.*.injected`) was copied from. Their
+/// entries look like `file+packages+ui_react@18.2.0`, or
+/// `ui@file+packages+ui(react@18.2.0)` since pnpm 9.
+///
+/// Directories containing `_` or `+` in their name can't be told apart from
+/// peer dependencies and nested directories, so they aren't supported.
+pub(super) fn injected_source_dir(entry: &str) -> Option<String> {
+    let spec = match entry.find("@file+") {
+        Some(index) if index > 0 => &entry[index + 1..],
+        _ => entry,
+    };
+    let spec = spec.strip_prefix("file+")?;
+    let dir = spec
+        .find(['(', '_'])
+        .map_or(spec, |peers_start| &spec[..peers_start]);
+    (!dir.is_empty()).then(|| dir.replace('+', "/"))
+}
+
+/// The path a file of a package in the virtual store should be read from.
+/// Injected dependencies are hard-linked copies of a workspace package made
+/// by `pnpm install`, so edits of the package only show up in them after the
+/// next install, and files written by editors that replace them don't show
+/// up at all. Reading them from the package instead keeps them up to date,
+/// while they are still resolved at the location of the copy, where their
+/// peer dependencies are installed.
+pub(super) fn injected_original_path(path: &str) -> Option<String> {
+    let store_path = virtual_store_path(path)?;
+    let dir = injected_source_dir(store_path.entry)?;
+    join_path(store_path.root, &format!("{dir}/{}", store_path.inner))
+}
+
+/// A file of an injected dependency, identified by its path inside of the
+/// virtual store, but read from the workspace package it was copied from.
+#[turbo_tasks::value]
+pub struct PnpmInjectedSource {
+    source: ResolvedVc<FileSource>,
+    original: ResolvedVc<FileSystemPath>,
+}
+
+#[turbo_tasks::value_impl]
+impl PnpmInjectedSource {
+    #[turbo_tasks::function]
+    pub fn new(source: ResolvedVc<FileSource>, original: ResolvedVc<FileSystemPath>) -> Vc<Self> {
+        Self::cell(PnpmInjectedSource { source, original })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Source for PnpmInjectedSource {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        Vc::upcast::<Box<dyn Source>>(*self.source).ident()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for PnpmInjectedSource {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        // Files that were removed from the package, or that aren't part of it,
+        // like generated files, are read from the copy.
+        Ok(
+            if matches!(*self.original.get_type().await?, FileSystemEntryType::File) {
+                AssetContent::File(self.original.read().to_resolved().await?).cell()
+            } else {
+                Vc::upcast::<Box<dyn Asset>>(*self.source).content()
+            },
+        )
+    }
+}
+
+/// The source of the resolved file at `path`, which is read from the
+/// workspace package if the file belongs to an injected dependency.
+pub(super) async fn pnpm_file_source(
+    path: Vc<FileSystemPath>,
+    query: Vc<RcStr>,
+    injected_from_source: bool,
+) -> Result<Vc<Box<dyn Source>>> {
+    let source = FileSource::new_with_query(path, query);
+    if injected_from_source {
+        if let Some(original) = injected_original_path(&path.await?.path) {
+            return Ok(Vc::upcast(PnpmInjectedSource::new(
+                source,
+                path.root().join(original.into()),
+            )));
+        }
+    }
+    Ok(Vc::upcast(source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        injected_original_path, injected_source_dir, virtual_store_path, VirtualStorePath,
+    };
+
+    #[test]
+    fn virtual_store_paths() {
+        assert_eq!(
+            virtual_store_path(
+                "apps/web/node_modules/.pnpm/react-dom@18.2.0_react@18.2.0/node_modules/react-dom/\
+                 cjs/index.js"
+            ),
+            Some(VirtualStorePath {
+                root: "apps/web",
+                entry: "react-dom@18.2.0_react@18.2.0",
+                inner: "cjs/index.js",
+            })
+        );
+        assert_eq!(
+            virtual_store_path(
+                "node_modules/.pnpm/@scope+pkg@1.0.0(react@18.2.0)/node_modules/@scope/pkg"
+            ),
+            Some(VirtualStorePath {
+                root: "",
+                entry: "@scope+pkg@1.0.0(react@18.2.0)",
+                inner: "",
+            })
+        );
+        assert_eq!(virtual_store_path("node_modules/react/index.js"), None);
+        assert_eq!(virtual_store_path("my_node_modules/.pnpm/a@1.0.0"), None);
+    }
+
+    #[test]
+    fn injected_source_dirs() {
+        assert_eq!(
+            injected_source_dir("file+packages+ui_react@18.2.0"),
+            Some("packages/ui".into())
+        );
+        assert_eq!(
+            injected_source_dir("ui@file+packages+ui(react@18.2.0)(react-dom@18.2.0)"),
+            Some("packages/ui".into())
+        );
+        assert_eq!(injected_source_dir("file+ui"), Some("ui".into()));
+        assert_eq!(injected_source_dir("react@18.2.0"), None);
+        assert_eq!(injected_source_dir("file+"), None);
+    }
+
+    #[test]
+    fn injected_original_paths() {
+        assert_eq!(
+            injected_original_path(
+                "node_modules/.pnpm/file+packages+ui_react@18.2.0/node_modules/@acme/ui/src/\
+                 button.tsx"
+            ),
+            Some("packages/ui/src/button.tsx".into())
+        );
+        assert_eq!(
+            injected_original_path(
+                "node_modules/.pnpm/file+..+..+outside/node_modules/outside/index.js"
+            ),
+            None
+        );
+        assert_eq!(
+            injected_original_path("node_modules/.pnpm/react@18.2.0/node_modules/react/index.js"),
+            None
+        );
+    }
+}
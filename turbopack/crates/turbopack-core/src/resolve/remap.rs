@@ -204,7 +204,15 @@ impl SubpathValue {
     fn try_new(value: &Value, ty: ExportImport) -> Result<Self> {
         match value {
             Value::Null => Ok(SubpathValue::Excluded),
-            Value::String(s) => Ok(SubpathValue::Result(s.as_str().into())),
+            Value::String(s) => {
+                if matches!(ty, ExportImport::Import) && !is_valid_import_target(s) {
+                    bail!(
+                        "invalid imports field target \"{s}\": targets must either start with \
+                         \"./\" and stay within the package, or be a package request"
+                    );
+                }
+                Ok(SubpathValue::Result(s.as_str().into()))
+            }
             Value::Number(_) => bail!("numeric values are invalid in {ty}s field entries"),
             Value::Bool(_) => bail!("boolean values are invalid in {ty}s field entries"),
             Value::Object(object) => Ok(SubpathValue::Conditional(
@@ -223,16 +231,90 @@ impl SubpathValue {
                     })
                     .collect::<Result<Vec<_>>>()?,
             )),
-            Value::Array(array) => Ok(SubpathValue::Alternatives(
-                array
-                    .iter()
-                    .map(|value| SubpathValue::try_new(value, ty))
-                    .collect::<Result<Vec<_>>>()?,
-            )),
+            Value::Array(array) => {
+                let mut alternatives = Vec::with_capacity(array.len());
+                let mut last_error = None;
+                for value in array {
+                    match SubpathValue::try_new(value, ty) {
+                        Ok(value) => alternatives.push(value),
+                        // Node.js falls back to the next alternative of an imports field entry
+                        // when a target is invalid, and only fails when none is valid.
+                        Err(err) if matches!(ty, ExportImport::Import) => last_error = Some(err),
+                        Err(err) => return Err(err),
+                    }
+                }
+                if let (true, Some(err)) = (alternatives.is_empty(), last_error) {
+                    return Err(err);
+                }
+                Ok(SubpathValue::Alternatives(alternatives))
+            }
         }
     }
 }
 
+/// Returns true if `target` is allowed as the target of an "imports" field
+/// entry. Targets are either relative to the package, without leaving it, or
+/// package requests like `"#dep": "dep/sub"`.
+///
+/// See `PACKAGE_TARGET_RESOLVE` in https://nodejs.org/api/esm.html#resolution-algorithm-specification
+fn is_valid_import_target(target: &str) -> bool {
+    match target.strip_prefix("./") {
+        Some(path) => !has_invalid_segments(path),
+        None => !target.starts_with("../") && !target.starts_with('/') && !is_url(target),
+    }
+}
+
+/// Returns true if any segment of `path` is empty, `.`, `..` or
+/// `node_modules`, case insensitive and including percent encoded variants,
+/// which Node.js rejects in the targets of "imports" field entries and in the
+/// part of a request matched by a `*`.
+pub(super) fn has_invalid_segments(path: &str) -> bool {
+    path.split(['/', '\\']).any(|segment| {
+        matches!(
+            &*percent_decode(segment).to_ascii_lowercase(),
+            "" | "." | ".." | "node_modules"
+        )
+    })
+}
+
+/// Decodes the percent encoded ASCII characters of `segment`, leaving invalid
+/// escapes as they are.
+fn percent_decode(segment: &str) -> String {
+    let mut decoded = String::with_capacity(segment.len());
+    let mut rest = segment;
+    while let Some(index) = rest.find('%') {
+        decoded.push_str(&rest[..index]);
+        rest = &rest[index..];
+        match rest
+            .get(1..3)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            .filter(u8::is_ascii)
+        {
+            Some(byte) => {
+                decoded.push(byte as char);
+                rest = &rest[3..];
+            }
+            None => {
+                decoded.push('%');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Returns true if `target` starts with a URL scheme, e.g. `node:` or
+/// `https:`.
+fn is_url(target: &str) -> bool {
+    let Some((scheme, _)) = target.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
 impl ReplacedSubpathValue {
     /// Walks the [ReplacedSubpathValue] and adds results to the `target`
     /// vector. It uses the `conditions` to skip or enter conditional
@@ -554,3 +636,52 @@ impl<'a> IntoIterator for &'a ResolveAliasMap {
         (&self.0).into_iter()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{has_invalid_segments, is_valid_import_target, ImportsField, SubpathValue};
+
+    #[test]
+    fn test_import_targets() {
+        assert!(is_valid_import_target("./sub/*.js"));
+        assert!(is_valid_import_target("dep"));
+        assert!(is_valid_import_target("@scope/dep/sub/*"));
+        assert!(!is_valid_import_target("../outside.js"));
+        assert!(!is_valid_import_target("/absolute.js"));
+        assert!(!is_valid_import_target("some:url"));
+        assert!(!is_valid_import_target("./sub/../outside.js"));
+        assert!(!is_valid_import_target("./node_modules/dep/index.js"));
+        assert!(!is_valid_import_target("./%2E%2e/outside.js"));
+        assert!(!is_valid_import_target("./sub//index.js"));
+
+        assert!(!has_invalid_segments("sub/file.js"));
+        assert!(has_invalid_segments("sub/Node_Modules/file.js"));
+        assert!(has_invalid_segments("sub\\..\\file.js"));
+        assert!(!has_invalid_segments("100%/file.js"));
+    }
+
+    #[test]
+    fn test_imports_field_array_fallbacks() {
+        let imports = ImportsField::try_from(&json!({
+            "#fallback": ["some:url", "../outside.js", { "import": "./import.js" }, "./index.js"],
+        }))
+        .unwrap();
+        let (_, value) = (&*imports).into_iter().next().unwrap();
+        assert_eq!(
+            *value,
+            SubpathValue::Alternatives(vec![
+                SubpathValue::Conditional(vec![(
+                    "import".into(),
+                    SubpathValue::Result("./import.js".into())
+                )]),
+                SubpathValue::Result("./index.js".into()),
+            ])
+        );
+
+        assert!(ImportsField::try_from(&json!({ "#invalid": ["../outside.js"] })).is_err());
+        assert!(ImportsField::try_from(&json!({ "#invalid": "./node_modules/dep" })).is_err());
+        assert!(ImportsField::try_from(&json!({ "#empty": [] })).is_ok());
+    }
+}
@@ -0,0 +1,58 @@
+//! Enforces boundaries between parts of an app, like the
+//! `no-restricted-imports` ESLint rule, by reporting requests that
+//! [RestrictedImport] rules forbid for the importing module.
+
+use anyhow::Result;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{glob::Glob, FileSystemPath};
+
+use super::{
+    options::{ResolveOptions, RestrictedImport},
+    parse::Request,
+};
+use crate::issue::{resolve::RestrictedImportIssue, IssueExt, IssueSource};
+
+/// Emits an issue when a rule of `options` forbids the module at
+/// `origin_path` to import `request`. Only requests without dynamic parts are
+/// checked.
+pub(super) async fn check_restricted_imports(
+    origin_path: Vc<FileSystemPath>,
+    request: Vc<Request>,
+    options: Vc<ResolveOptions>,
+    source: Option<ResolvedVc<IssueSource>>,
+) -> Result<()> {
+    let options = options.await?;
+    if options.restricted_imports.is_empty() {
+        return Ok(());
+    }
+    let Some(request_str) = request.await?.request() else {
+        return Ok(());
+    };
+    let origin = origin_path.await?;
+    for RestrictedImport {
+        from,
+        disallow,
+        message,
+    } in options.restricted_imports.iter()
+    {
+        if !Glob::parse(from)?.execute(&origin.path) {
+            continue;
+        }
+        for pattern in disallow {
+            if Glob::parse(pattern)?.execute(&request_str) {
+                RestrictedImportIssue {
+                    file_path: origin_path.to_resolved().await?,
+                    request: request_str.clone(),
+                    from: from.clone(),
+                    disallow: pattern.clone(),
+                    message: message.clone(),
+                    source,
+                }
+                .resolved_cell()
+                .emit();
+                return Ok(());
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Records the steps taken to resolve the requests matching a glob: the
+//! aliases applied, the locations a package was looked up in, the conditions
+//! of the `exports` and `imports` fields which matched and the final results.
+//!
+//! Each step is written as a JSON object on its own line. Resolving is cached
+//! like any other task, so a step is only recorded when it's computed, not when
+//! a cached result is reused.
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::{Mutex, OnceLock},
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use turbo_tasks::{ValueToString, Vc};
+use turbo_tasks_fs::glob::Glob;
+
+use super::{ResolveResult, ResolveResultItem};
+use crate::source::Source;
+
+struct ResolveTrace {
+    glob: Glob,
+    output: Mutex<BufWriter<File>>,
+}
+
+static RESOLVE_TRACE: OnceLock<ResolveTrace> = OnceLock::new();
+
+/// Writes a trace of the resolution of the requests matching `glob` to
+/// `output`, one JSON object per step. The trace can only be enabled once per
+/// process, later calls are ignored.
+pub fn enable_resolve_trace(glob: &str, output: &Path) -> Result<()> {
+    if RESOLVE_TRACE.get().is_some() {
+        return Ok(());
+    }
+    let glob = Glob::parse(glob)
+        .with_context(|| format!("Invalid glob for the resolution trace: {glob}"))?;
+    let file = File::create(output).with_context(|| {
+        format!(
+            "Unable to create the resolution trace file {}",
+            output.display()
+        )
+    })?;
+    let _ = RESOLVE_TRACE.set(ResolveTrace {
+        glob,
+        output: Mutex::new(BufWriter::new(file)),
+    });
+    Ok(())
+}
+
+/// Whether the steps of resolving `request` are recorded. Cheap enough to be
+/// checked before collecting the data of a step.
+pub(super) fn is_traced(request: &str) -> bool {
+    RESOLVE_TRACE
+        .get()
+        .is_some_and(|trace| trace.glob.execute(request))
+}
+
+#[derive(Serialize)]
+pub(super) struct ResolveTraceCandidate {
+    pub path: String,
+    pub found: bool,
+}
+
+#[derive(Serialize)]
+pub(super) struct ResolveTraceTarget {
+    pub target: String,
+    /// The conditions the target is guarded by, `!` marks the conditions
+    /// which have to be unset.
+    pub conditions: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "step", rename_all = "camelCase")]
+pub(super) enum ResolveTraceStep {
+    /// An alias of the import map matched the request.
+    #[serde(rename_all = "camelCase")]
+    Alias {
+        request: String,
+        lookup_path: String,
+        alias: String,
+    },
+    /// The locations the package of a request was looked up in, in order.
+    #[serde(rename_all = "camelCase")]
+    FindPackage {
+        request: String,
+        lookup_path: String,
+        candidates: Vec<ResolveTraceCandidate>,
+    },
+    /// The targets of the `exports` or `imports` field of a package matching
+    /// the subpath of the request, in order of precedence.
+    #[serde(rename_all = "camelCase")]
+    ExportsImportsField {
+        request: String,
+        package_json: String,
+        targets: Vec<ResolveTraceTarget>,
+    },
+    /// The results of resolving the request, after the resolve plugins were
+    /// applied.
+    #[serde(rename_all = "camelCase")]
+    Result {
+        request: String,
+        lookup_path: String,
+        results: Vec<String>,
+    },
+}
+
+/// Records a step. Failing to write the trace doesn't fail resolving.
+pub(super) fn trace_resolve_step(step: ResolveTraceStep) {
+    let Some(trace) = RESOLVE_TRACE.get() else {
+        return;
+    };
+    let Ok(line) = serde_json::to_string(&step) else {
+        return;
+    };
+    let mut output = trace.output.lock().unwrap();
+    let _ = writeln!(output, "{line}").and_then(|_| output.flush());
+}
+
+/// Describes the items of a resolve result for the trace.
+pub(super) async fn describe_resolve_result(result: Vc<ResolveResult>) -> Result<Vec<String>> {
+    let mut items = vec![];
+    for item in result.await?.primary.values() {
+        items.push(match item {
+            ResolveResultItem::Source(source) => {
+                source.ident().path().to_string().await?.to_string()
+            }
+            ResolveResultItem::External { name, .. } => format!("external {name}"),
+            ResolveResultItem::Ignore => "ignored".to_string(),
+            ResolveResultItem::Error(error) => format!("error {}", error.await?),
+            ResolveResultItem::Empty => "empty".to_string(),
+            ResolveResultItem::Custom(_) => "custom".to_string(),
+        });
+    }
+    Ok(items)
+}
@@ -0,0 +1,106 @@
+#![feature(arbitrary_self_types)]
+#![feature(arbitrary_self_types_pointers)]
+#![allow(clippy::needless_return)] // tokio macro-generated code doesn't respect this
+
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{State, Vc};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::compile_time_info::{
+    CompileTimeDefineValue, CompileTimeDefines, DefineableNameSegment,
+};
+
+static REGISTRATION: Registration = register!(turbopack_core::register);
+
+/// The names of the defines read by the executions of `read_define`.
+static EXECUTIONS: Mutex<Vec<RcStr>> = Mutex::new(Vec::new());
+
+fn take_executions() -> Vec<RcStr> {
+    let mut executions = std::mem::take(&mut *EXECUTIONS.lock().unwrap());
+    executions.sort();
+    executions
+}
+
+#[tokio::test]
+async fn changed_define_only_reruns_its_readers() {
+    run(&REGISTRATION, || async {
+        let env = Env {
+            vars: State::new(vec![
+                ("API_URL".into(), "https://a.example".into()),
+                ("FLAG".into(), "1".into()),
+            ]),
+        }
+        .cell();
+        let defines = env_defines(env);
+        let api_url = read_define(defines, "API_URL".into());
+        let flag = read_define(defines, "FLAG".into());
+        api_url.strongly_consistent().await?;
+        flag.strongly_consistent().await?;
+        assert_eq!(take_executions(), ["API_URL", "FLAG"]);
+
+        // Changing a define only re-runs the modules reading it
+        env.await?.vars.set(vec![
+            ("API_URL".into(), "https://b.example".into()),
+            ("FLAG".into(), "1".into()),
+        ]);
+        assert_eq!(
+            *api_url.strongly_consistent().await?,
+            CompileTimeDefineValue::from("https://b.example")
+        );
+        flag.strongly_consistent().await?;
+        assert_eq!(take_executions(), ["API_URL"]);
+
+        // Touching `.env` without changing its values re-runs nothing
+        env.await?.vars.set_unconditionally(vec![
+            ("API_URL".into(), "https://b.example".into()),
+            ("FLAG".into(), "1".into()),
+        ]);
+        api_url.strongly_consistent().await?;
+        flag.strongly_consistent().await?;
+        assert_eq!(take_executions(), Vec::<RcStr>::new());
+
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+/// The variables of a `.env` file.
+#[turbo_tasks::value]
+struct Env {
+    vars: State<Vec<(RcStr, RcStr)>>,
+}
+
+fn define_name(name: &RcStr) -> Vec<DefineableNameSegment> {
+    vec!["process".into(), "env".into(), name.clone().into()]
+}
+
+/// The `process.env.*` defines of the variables of `env`.
+#[turbo_tasks::function]
+async fn env_defines(env: Vc<Env>) -> Result<Vc<CompileTimeDefines>> {
+    Ok(Vc::cell(
+        env.await?
+            .vars
+            .get()
+            .iter()
+            .map(|(name, value)| (define_name(name), value.clone().into()))
+            .collect(),
+    ))
+}
+
+/// Reads a single define, like a module referencing it.
+#[turbo_tasks::function]
+async fn read_define(
+    defines: Vc<CompileTimeDefines>,
+    name: RcStr,
+) -> Result<Vc<CompileTimeDefineValue>> {
+    EXECUTIONS.lock().unwrap().push(name.clone());
+    let individual = defines.individual().await?;
+    let value = individual
+        .get(&define_name(&name))
+        .context("The define is missing")?
+        .await?;
+    Ok((*value).clone().cell())
+}
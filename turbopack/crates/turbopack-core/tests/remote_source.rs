@@ -0,0 +1,150 @@
+#![feature(arbitrary_self_types)]
+#![feature(arbitrary_self_types_pointers)]
+#![allow(clippy::needless_return)] // tokio macro-generated code doesn't respect this
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{rope::Rope, DiskFileSystem, FileContent, FileSystem};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    issue::{Issue, IssueDescriptionExt, IssueSeverity},
+    remote_source::{RemoteFetchResult, RemoteFetcher, RemoteImports, RemoteSource},
+};
+
+static REGISTRATION: Registration = register!(turbopack_core::register);
+
+const URL: &str = "https://esm.sh/preact";
+const BODY: &str = "export const h = () => {};";
+
+#[tokio::test]
+async fn locked_module_is_returned() {
+    run(&REGISTRATION, || async {
+        let dir = tempfile::tempdir()?;
+        write_lockfile(dir.path(), &sha256_hex(BODY))?;
+
+        let (content, severities) = content_of(dir.path(), false).await?;
+        assert_eq!(content.as_deref(), Some(BODY));
+        assert_eq!(severities, vec![]);
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn hash_mismatch_is_an_error() {
+    run(&REGISTRATION, || async {
+        let dir = tempfile::tempdir()?;
+        write_lockfile(dir.path(), &sha256_hex("export const h = null;"))?;
+
+        let (content, severities) = content_of(dir.path(), false).await?;
+        assert_eq!(content, None);
+        assert_eq!(severities, vec![IssueSeverity::Error]);
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn unlocked_module_is_a_warning() {
+    run(&REGISTRATION, || async {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(
+            dir.path().join("remote-imports.lock"),
+            r#"{ "remote": {} }"#,
+        )?;
+
+        let (content, severities) = content_of(dir.path(), false).await?;
+        assert_eq!(content.as_deref(), Some(BODY));
+        assert_eq!(severities, vec![IssueSeverity::Warning]);
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn unlocked_module_with_frozen_lockfile_is_an_error() {
+    run(&REGISTRATION, || async {
+        let dir = tempfile::tempdir()?;
+        std::fs::write(
+            dir.path().join("remote-imports.lock"),
+            r#"{ "remote": {} }"#,
+        )?;
+
+        let (content, severities) = content_of(dir.path(), true).await?;
+        assert_eq!(content, None);
+        assert_eq!(severities, vec![IssueSeverity::Error]);
+        // The module is neither fetched nor cached.
+        assert!(!dir.path().join("cache").exists());
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+fn sha256_hex(content: &str) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn write_lockfile(dir: &std::path::Path, hash: &str) -> Result<()> {
+    std::fs::write(
+        dir.join("remote-imports.lock"),
+        format!(r#"{{ "remote": {{ "{URL}": "{hash}" }} }}"#),
+    )?;
+    Ok(())
+}
+
+/// Reads the module at [`URL`] through remote imports rooted at `dir`,
+/// returning its content, if any, and the severities of the reported issues.
+async fn content_of(
+    dir: &std::path::Path,
+    frozen_lockfile: bool,
+) -> Result<(Option<String>, Vec<IssueSeverity>)> {
+    let root = DiskFileSystem::new("test".into(), dir.to_str().unwrap().into(), vec![]).root();
+    let remote_imports = RemoteImports {
+        cache_dir: root.join("cache".into()).to_resolved().await?,
+        lockfile: root
+            .join("remote-imports.lock".into())
+            .to_resolved()
+            .await?,
+        frozen_lockfile,
+        fetcher: ResolvedVc::upcast(FixedFetcher { body: BODY.into() }.resolved_cell()),
+    }
+    .resolved_cell();
+    let asset_content = RemoteSource::new(URL.into(), remote_imports).content();
+
+    let mut severities = vec![];
+    for issue in asset_content.peek_issues_with_path().await?.iter() {
+        severities.push(*issue.severity().await?);
+    }
+    let AssetContent::File(file) = &*asset_content.await? else {
+        panic!("remote modules are files");
+    };
+    let content = match &*file.await? {
+        FileContent::Content(file) => Some(file.content().to_str()?.into_owned()),
+        FileContent::NotFound => None,
+    };
+    Ok((content, severities))
+}
+
+/// Serves the same body for every URL.
+#[turbo_tasks::value]
+struct FixedFetcher {
+    body: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl RemoteFetcher for FixedFetcher {
+    #[turbo_tasks::function]
+    fn fetch(&self, _url: RcStr) -> Vc<RemoteFetchResult> {
+        RemoteFetchResult::Ok(Rope::from(self.body.to_string()).resolved_cell()).cell()
+    }
+}
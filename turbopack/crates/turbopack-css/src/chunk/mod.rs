@@ -1,17 +1,25 @@
 pub(crate) mod single_item_chunk;
 pub mod source_map;
+mod update;
+mod version;
 
 use std::fmt::Write;
 
 use anyhow::{bail, Result};
+use lightningcss::{
+    stylesheet::{ParserOptions, StyleSheet},
+    visit_types,
+    visitor::Visit,
+};
 use turbo_rcstr::RcStr;
 use turbo_tasks::{FxIndexSet, ResolvedVc, TryJoinIterExt, Value, ValueDefault, ValueToString, Vc};
 use turbo_tasks_fs::{rope::Rope, File, FileSystem};
 use turbopack_core::{
     asset::{Asset, AssetContent},
     chunk::{
-        round_chunk_item_size, AsyncModuleInfo, Chunk, ChunkItem, ChunkItemWithAsyncModuleInfo,
-        ChunkType, ChunkableModule, ChunkingContext, ModuleId, OutputChunk, OutputChunkRuntimeInfo,
+        content_hash::content_hashed_path, round_chunk_item_size, AsyncModuleInfo, Chunk,
+        ChunkItem, ChunkItemWithAsyncModuleInfo, ChunkType, ChunkableModule, ChunkingContext,
+        ModuleId, OutputChunk, OutputChunkRuntimeInfo,
     },
     code_builder::{fileify_source_map, Code, CodeBuilder},
     ident::AssetIdent,
@@ -25,9 +33,13 @@ use turbopack_core::{
     reference_type::ImportContext,
     server_fs::ServerFileSystem,
     source_map::{GenerateSourceMap, OptionSourceMap},
+    version::VersionedContent,
 };
 
-use self::{single_item_chunk::chunk::SingleItemCssChunk, source_map::CssChunkSourceMapAsset};
+use self::{
+    single_item_chunk::chunk::SingleItemCssChunk, source_map::CssChunkSourceMapAsset,
+    update::CssChunkVersionedContent,
+};
 use crate::{process::ParseCssResultSourceMap, util::stringify_js, ImportAssetReference};
 
 #[turbo_tasks::value]
@@ -55,8 +67,11 @@ impl CssChunk {
         *self.content
     }
 
+    /// The code of the chunk without the reference to its source map. Unlike
+    /// the code of the chunk, it doesn't depend on the path of the chunk, which
+    /// is derived from it when content hashing is enabled.
     #[turbo_tasks::function]
-    async fn code(self: Vc<Self>) -> Result<Vc<Code>> {
+    async fn body_code(self: Vc<Self>) -> Result<Vc<Code>> {
         use std::io::Write;
 
         let this = self.await?;
@@ -106,22 +121,36 @@ impl CssChunk {
         let built = &body.build();
         code.push_code(built);
 
-        if *this
+        Ok(code.build().cell())
+    }
+
+    #[turbo_tasks::function]
+    async fn code(self: Vc<Self>) -> Result<Vc<Code>> {
+        use std::io::Write;
+
+        let this = self.await?;
+        let body_code = self.body_code();
+        let body = body_code.await?;
+
+        if !*this
             .chunking_context
             .reference_chunk_source_maps(Vc::upcast(self))
             .await?
-            && code.has_source_map()
+            || !body.has_source_map()
         {
-            let chunk_path = self.path().await?;
-            writeln!(
-                code,
-                "/*# sourceMappingURL={}.map*/",
-                urlencoding::encode(chunk_path.file_name())
-            )?;
+            return Ok(body_code);
         }
 
-        let c = code.build().cell();
-        Ok(c)
+        let mut code = CodeBuilder::default();
+        code.push_code(&body);
+        let chunk_path = self.path().await?;
+        writeln!(
+            code,
+            "/*# sourceMappingURL={}.map*/",
+            urlencoding::encode(chunk_path.file_name())
+        )?;
+
+        Ok(code.build().cell())
     }
 
     #[turbo_tasks::function]
@@ -253,8 +282,9 @@ fn chunk_item_key() -> Vc<RcStr> {
 #[turbo_tasks::value_impl]
 impl OutputAsset for CssChunk {
     #[turbo_tasks::function]
-    async fn ident(&self) -> Result<Vc<AssetIdent>> {
-        let CssChunkContent { chunk_items, .. } = &*self.content.await?;
+    async fn ident(self: Vc<Self>) -> Result<Vc<AssetIdent>> {
+        let this = self.await?;
+        let CssChunkContent { chunk_items, .. } = &*this.content.await?;
         let mut common_path = if let Some(chunk_item) = chunk_items.first() {
             let path = chunk_item.asset_ident().path().to_resolved().await?;
             Some((path, path.await?))
@@ -304,10 +334,16 @@ impl OutputAsset for CssChunk {
             layer: None,
         };
 
-        Ok(AssetIdent::from_path(self.chunking_context.chunk_path(
-            AssetIdent::new(Value::new(ident)),
-            ".css".into(),
-        )))
+        let path = this
+            .chunking_context
+            .chunk_path(AssetIdent::new(Value::new(ident)), ".css".into());
+        Ok(AssetIdent::from_path(
+            if *this.chunking_context.content_hashing().await? {
+                content_hashed_path(path, self.body_code().source_code_hash())
+            } else {
+                path
+            },
+        ))
     }
 
     #[turbo_tasks::function]
@@ -347,6 +383,11 @@ impl Asset for CssChunk {
     fn content(self: Vc<Self>) -> Vc<AssetContent> {
         self.content()
     }
+
+    #[turbo_tasks::function]
+    fn versioned_content(self: Vc<Self>) -> Vc<Box<dyn VersionedContent>> {
+        Vc::upcast(CssChunkVersionedContent::new(self.code()))
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -497,6 +538,8 @@ impl ChunkType for CssChunkType {
         chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
         chunk_items: Vec<ChunkItemWithAsyncModuleInfo>,
         referenced_output_assets: ResolvedVc<OutputAssets>,
+        // CSS chunks keep the item order, so they are never placed in named chunks
+        _name: Option<RcStr>,
     ) -> Result<Vc<Box<dyn Chunk>>> {
         let content = CssChunkContent {
             chunk_items: chunk_items
@@ -534,6 +577,82 @@ impl ChunkType for CssChunkType {
             round_chunk_item_size(content.inner_code.len())
         })))
     }
+
+    #[turbo_tasks::function]
+    async fn chunk_item_rule_count(
+        self: Vc<Self>,
+        _chunking_context: Vc<Box<dyn ChunkingContext>>,
+        chunk_item: Vc<Box<dyn ChunkItem>>,
+    ) -> Result<Vc<usize>> {
+        let Some(chunk_item) =
+            Vc::try_resolve_downcast::<Box<dyn CssChunkItem>>(chunk_item).await?
+        else {
+            bail!("Chunk item is not an css chunk item but reporting chunk type css");
+        };
+        let content = chunk_item.content().await?;
+        Ok(Vc::cell(count_selectors(&content.inner_code.to_str()?)))
+    }
+
+    #[turbo_tasks::function]
+    async fn max_chunk_rule_count(
+        self: Vc<Self>,
+        chunking_context: Vc<Box<dyn ChunkingContext>>,
+    ) -> Result<Vc<usize>> {
+        Ok(Vc::cell(
+            if *chunking_context
+                .environment()
+                .has_legacy_selector_limit()
+                .await?
+            {
+                LEGACY_MAX_SELECTORS
+            } else {
+                usize::MAX
+            },
+        ))
+    }
+}
+
+/// Internet Explorer 9 and older ignore the selectors of a stylesheet after
+/// this many.
+const LEGACY_MAX_SELECTORS: usize = 4095;
+
+/// Counts the selectors of a stylesheet, including the ones in at-rules, like
+/// legacy browsers count them against their limit.
+fn count_selectors(code: &str) -> usize {
+    struct SelectorCounter {
+        count: usize,
+    }
+
+    impl lightningcss::visitor::Visitor<'_> for SelectorCounter {
+        type Error = ();
+
+        fn visit_types(&self) -> lightningcss::visitor::VisitTypes {
+            visit_types!(SELECTORS)
+        }
+
+        fn visit_selector(
+            &mut self,
+            _selector: &mut lightningcss::selector::Selector<'_>,
+        ) -> Result<(), Self::Error> {
+            self.count += 1;
+            Ok(())
+        }
+    }
+
+    let Ok(mut stylesheet) = StyleSheet::parse(
+        code,
+        ParserOptions {
+            error_recovery: true,
+            ..Default::default()
+        },
+    ) else {
+        return 0;
+    };
+    let mut counter = SelectorCounter { count: 0 };
+    match stylesheet.visit(&mut counter) {
+        Ok(()) => counter.count,
+        Err(()) => 0,
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -543,3 +662,16 @@ impl ValueDefault for CssChunkType {
         Self::default().cell()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::count_selectors;
+
+    #[test]
+    fn test_count_selectors() {
+        assert_eq!(
+            count_selectors(".a, .b { color: red } @media (min-width: 1px) { .c { color: red } }"),
+            3
+        );
+    }
+}
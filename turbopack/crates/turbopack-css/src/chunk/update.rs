@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde::Serialize;
+use turbo_tasks::{IntoTraitRef, ReadRef, Vc};
+use turbo_tasks_fs::File;
+use turbo_tasks_hash::hash_xxh3_hash64;
+use turbopack_core::{
+    asset::AssetContent,
+    code_builder::Code,
+    version::{PartialUpdate, TotalUpdate, Update, Version, VersionedContent},
+};
+
+use super::version::{split_top_level_rules, CssChunkVersion};
+
+/// The content of a [super::CssChunk] alongside its [CssChunkVersion].
+#[turbo_tasks::value(serialization = "none")]
+pub(super) struct CssChunkVersionedContent {
+    // Like `VersionedAssetContent`, this holds a snapshot of the code so that
+    // the content and the version can't get out of sync.
+    code: ReadRef<Code>,
+}
+
+#[turbo_tasks::value_impl]
+impl CssChunkVersionedContent {
+    #[turbo_tasks::function]
+    pub async fn new(code: Vc<Code>) -> Result<Vc<Self>> {
+        Ok(CssChunkVersionedContent { code: code.await? }.cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl VersionedContent for CssChunkVersionedContent {
+    #[turbo_tasks::function]
+    fn content(&self) -> Vc<AssetContent> {
+        AssetContent::file(File::from(self.code.source_code().clone()).into())
+    }
+
+    #[turbo_tasks::function]
+    async fn version(&self) -> Result<Vc<Box<dyn Version>>> {
+        let code = self.code.source_code().to_str()?;
+        let rules = split_top_level_rules(&code);
+        Ok(Vc::upcast(CssChunkVersion::new(&code, &rules).cell()))
+    }
+
+    #[turbo_tasks::function]
+    async fn update(self: Vc<Self>, from: Vc<Box<dyn Version>>) -> Result<Vc<Update>> {
+        let to = self.version().into_trait_ref().await?;
+        let Some(from) = Vc::try_resolve_downcast_type::<CssChunkVersion>(from).await? else {
+            // It's likely `from` is `NotFoundVersion`.
+            return Ok(Update::Total(TotalUpdate { to }).cell());
+        };
+        let from = from.await?;
+
+        let this = self.await?;
+        let code = this.code.source_code().to_str()?;
+        if from.hash == hash_xxh3_hash64(&*code) {
+            return Ok(Update::None.cell());
+        }
+
+        let rules = split_top_level_rules(&code);
+        let Some(insertions) = compute_insertions(&from.rule_hashes, &rules) else {
+            return Ok(Update::Total(TotalUpdate { to }).cell());
+        };
+        // Only comments or whitespace changed, which still affects source maps
+        if insertions.is_empty() {
+            return Ok(Update::Total(TotalUpdate { to }).cell());
+        }
+
+        let update = CssInsertRulesUpdate {
+            previous_rule_count: from.rule_hashes.len(),
+            insertions,
+        };
+        Ok(Update::Partial(PartialUpdate {
+            to,
+            instruction: Arc::new(serde_json::to_value(&update)?),
+        })
+        .cell())
+    }
+}
+
+/// An update of a CSS chunk which only inserts top-level rules, applied by the
+/// runtime with `CSSStyleSheet.insertRule` instead of reloading the stylesheet.
+#[derive(Serialize)]
+#[serde(tag = "type")]
+#[serde(rename_all = "camelCase")]
+struct CssInsertRulesUpdate<'a> {
+    /// The number of rules of the stylesheet being updated, used by the runtime
+    /// to detect whether the indices below still apply.
+    previous_rule_count: usize,
+    /// Sorted by index, which is the position of the rule in the new version.
+    insertions: Vec<CssRuleInsertion<'a>>,
+}
+
+#[derive(Serialize)]
+struct CssRuleInsertion<'a> {
+    index: usize,
+    rule: &'a str,
+}
+
+/// Returns the rules to insert to go from the previous rules to the new ones,
+/// or `None` if any previous rule was changed, removed or reordered.
+fn compute_insertions<'a>(
+    previous_hashes: &[u64],
+    rules: &[&'a str],
+) -> Option<Vec<CssRuleInsertion<'a>>> {
+    let mut previous = previous_hashes.iter().peekable();
+    let mut insertions = vec![];
+    for (index, rule) in rules.iter().enumerate() {
+        if previous.peek() == Some(&&hash_xxh3_hash64(*rule)) {
+            previous.next();
+        } else {
+            insertions.push(CssRuleInsertion { index, rule });
+        }
+    }
+    previous.next().is_none().then_some(insertions)
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_tasks_hash::hash_xxh3_hash64;
+
+    use super::compute_insertions;
+
+    fn hashes(rules: &[&str]) -> Vec<u64> {
+        rules.iter().map(|rule| hash_xxh3_hash64(*rule)).collect()
+    }
+
+    #[test]
+    fn inserts_new_rules() {
+        let previous = hashes(&[".a{}", ".c{}"]);
+        let insertions = compute_insertions(&previous, &[".a{}", ".b{}", ".c{}", ".d{}"]).unwrap();
+        assert_eq!(
+            insertions
+                .iter()
+                .map(|insertion| (insertion.index, insertion.rule))
+                .collect::<Vec<_>>(),
+            vec![(1, ".b{}"), (3, ".d{}")]
+        );
+    }
+
+    #[test]
+    fn rejects_changed_rules() {
+        let previous = hashes(&[".a{}", ".c{}"]);
+        assert!(compute_insertions(&previous, &[".a{}", ".c{color:red}"]).is_none());
+        assert!(compute_insertions(&previous, &[".c{}", ".a{}"]).is_none());
+        assert!(compute_insertions(&previous, &[".a{}"]).is_none());
+    }
+}
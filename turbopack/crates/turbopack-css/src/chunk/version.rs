@@ -0,0 +1,119 @@
+use turbo_rcstr::RcStr;
+use turbo_tasks::Vc;
+use turbo_tasks_hash::{encode_hex, hash_xxh3_hash64};
+use turbopack_core::version::Version;
+
+/// The version of a [super::CssChunk]. Besides the hash of the whole chunk, it
+/// remembers the hashes of the top-level rules, so that a later version which
+/// only inserts rules (e.g. Tailwind generating a utility for a class that was
+/// just added to a component) can be applied to the stylesheet in place.
+#[turbo_tasks::value(serialization = "none")]
+pub(super) struct CssChunkVersion {
+    pub(super) hash: u64,
+    pub(super) rule_hashes: Vec<u64>,
+}
+
+impl CssChunkVersion {
+    pub(super) fn new(code: &str, rules: &[&str]) -> Self {
+        CssChunkVersion {
+            hash: hash_xxh3_hash64(code),
+            rule_hashes: rules.iter().map(|rule| hash_xxh3_hash64(*rule)).collect(),
+        }
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Version for CssChunkVersion {
+    #[turbo_tasks::function]
+    fn id(&self) -> Vc<RcStr> {
+        Vc::cell(encode_hex(self.hash).into())
+    }
+}
+
+/// Splits a stylesheet into its top-level rules, in the same way the CSSOM
+/// exposes them through `CSSStyleSheet.cssRules`. Comments between rules are
+/// dropped, while `@charset` is skipped as it doesn't appear in the CSSOM.
+pub(super) fn split_top_level_rules(code: &str) -> Vec<&str> {
+    let bytes = code.as_bytes();
+    let mut rules = vec![];
+    let mut depth = 0usize;
+    let mut start = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = code[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+                continue;
+            }
+            quote @ (b'"' | b'\'') => {
+                start.get_or_insert(i);
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'{' => {
+                start.get_or_insert(i);
+                depth += 1;
+            }
+            b'}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    if let Some(start) = start.take() {
+                        rules.push(&code[start..=i]);
+                    }
+                }
+            }
+            b';' if depth == 0 => {
+                if let Some(start) = start.take() {
+                    rules.push(&code[start..=i]);
+                }
+            }
+            byte if byte.is_ascii_whitespace() => {}
+            _ => {
+                start.get_or_insert(i);
+            }
+        }
+        i += 1;
+    }
+    if let Some(start) = start {
+        rules.push(code[start..].trim_end());
+    }
+
+    rules.retain(|rule| !rule.starts_with("@charset"));
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_top_level_rules;
+
+    #[test]
+    fn splits_top_level_rules() {
+        let code = r#"@charset "utf-8";
+/* [project]/app/globals.css */
+@import url("a.css");
+.a { color: red; }
+@media (min-width: 640px) {
+  .sm\:flex { display: flex; }
+}
+.b::before { content: "}"; /* } */ }
+/*# sourceMappingURL=chunk.css.map*/
+"#;
+
+        assert_eq!(
+            split_top_level_rules(code),
+            vec![
+                r#"@import url("a.css");"#,
+                ".a { color: red; }",
+                "@media (min-width: 640px) {\n  .sm\\:flex { display: flex; }\n}",
+                r#".b::before { content: "}"; /* } */ }"#,
+            ]
+        );
+    }
+}
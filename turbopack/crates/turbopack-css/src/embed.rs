@@ -1,7 +1,11 @@
+use turbo_rcstr::RcStr;
 use turbo_tasks::Vc;
 use turbopack_core::{chunk::ChunkItem, output::OutputAsset};
 
 #[turbo_tasks::value_trait]
 pub trait CssEmbed: ChunkItem {
     fn embedded_asset(self: Vc<Self>) -> Vc<Box<dyn OutputAsset>>;
+    /// A `data:` URL replacing the reference to the embedded asset, which
+    /// isn't emitted then.
+    fn inlined_url(self: Vc<Self>) -> Vc<Option<RcStr>>;
 }
@@ -26,6 +26,8 @@ use crate::{embed::CssEmbed, StyleSheetLike};
 #[turbo_tasks::value(into = "new")]
 pub enum ReferencedAsset {
     Some(ResolvedVc<Box<dyn OutputAsset>>),
+    /// The asset is inlined as this `data:` URL.
+    Inline(RcStr),
     None,
 }
 
@@ -65,6 +67,9 @@ impl UrlAssetReference {
                 if let Some(embeddable) =
                     Vc::try_resolve_downcast::<Box<dyn CssEmbed>>(chunk_item).await?
                 {
+                    if let Some(url) = &*embeddable.inlined_url().await? {
+                        return Ok(ReferencedAsset::Inline(url.clone()).into());
+                    }
                     return Ok(ReferencedAsset::Some(
                         embeddable.embedded_asset().to_resolved().await?,
                     )
@@ -128,7 +133,11 @@ pub async fn resolve_url_reference(
     );
     let context_path = chunk_path.parent().await?;
 
-    if let ReferencedAsset::Some(asset) = &*url.get_referenced_asset(chunking_context).await? {
+    let referenced_asset = url.get_referenced_asset(chunking_context).await?;
+    if let ReferencedAsset::Inline(url) = &*referenced_asset {
+        return Ok(Vc::cell(Some(url.clone())));
+    }
+    if let ReferencedAsset::Some(asset) = &*referenced_asset {
         // TODO(WEB-662) This is not the correct way to get the path of the asset.
         // `asset` is on module-level, but we need the output-level asset instead.
         let path = asset.ident().path().await?;
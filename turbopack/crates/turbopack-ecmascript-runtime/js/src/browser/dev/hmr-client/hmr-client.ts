@@ -233,17 +233,38 @@ function mergeChunkUpdates(
     return undefined;
   }
 
-  if (updateA.type === "partial") {
-    invariant(updateA.instruction, "Partial updates are unsupported");
+  if (updateA.type === "partial" && updateB.type === "partial") {
+    return {
+      type: "partial",
+      instruction: mergeCssInsertRulesUpdates(
+        updateA.instruction,
+        updateB.instruction
+      ),
+    };
   }
 
-  if (updateB.type === "partial") {
-    invariant(updateB.instruction, "Partial updates are unsupported");
+  // A partial update followed or preceded by any other change can only be
+  // applied by reloading the whole chunk.
+  if (updateA.type === "partial" || updateB.type === "partial") {
+    return { type: "total" };
   }
 
   return undefined;
 }
 
+function mergeCssInsertRulesUpdates(
+  updateA: CssInsertRulesUpdate,
+  updateB: CssInsertRulesUpdate
+): CssInsertRulesUpdate {
+  // The indices of the second update refer to the stylesheet after the first
+  // one was applied, so applying both in sequence is equivalent.
+  return {
+    type: "CssInsertRulesUpdate",
+    previousRuleCount: updateA.previousRuleCount,
+    insertions: [...updateA.insertions, ...updateB.insertions],
+  };
+}
+
 function mergeChunkListEcmascriptMergedUpdates(
   mergedA: EcmascriptMergedUpdate,
   mergedB: EcmascriptMergedUpdate
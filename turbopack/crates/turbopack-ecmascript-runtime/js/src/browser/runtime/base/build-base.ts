@@ -124,7 +124,7 @@ function instantiateModule(id: ModuleId, source: SourceInfo): Module {
         P: resolveAbsolutePath,
         U: relativeURL,
         R: createResolvePathFromModule(r),
-        b: getWorkerBlobURL,
+        b: getWorkerURL,
         __dirname: typeof module.id === "string" ? module.id.replace(/(^|\/)\/+$/, "") : module.id
       })
     );
@@ -24,6 +24,9 @@ type RefreshRuntimeGlobals =
 // prefixed to chunk urls in the worker.
 // declare var TURBOPACK_WORKER_LOCATION: string;
 // declare var CHUNK_BASE_PATH: string;
+// The global chunk lists are registered in, `TURBOPACK_CHUNK_LISTS` unless the
+// chunks use a chunk loading global other than `TURBOPACK`.
+declare var CHUNK_LISTS_GLOBAL: "TURBOPACK_CHUNK_LISTS";
 declare var $RefreshHelpers$: RefreshRuntimeGlobals["$RefreshHelpers$"];
 declare var $RefreshReg$: RefreshRuntimeGlobals["$RefreshReg$"];
 declare var $RefreshSig$: RefreshRuntimeGlobals["$RefreshSig$"];
@@ -52,6 +55,10 @@ type ModuleFactory = (
 
 interface DevRuntimeBackend {
   reloadChunk?: (chunkPath: ChunkPath) => Promise<void>;
+  insertCssRules?: (
+    chunkPath: ChunkPath,
+    update: CssInsertRulesUpdate
+  ) => Promise<void>;
   unloadChunk?: (chunkPath: ChunkPath) => void;
   restart: () => void;
 }
@@ -226,7 +233,7 @@ function instantiateModule(id: ModuleId, source: SourceInfo): Module {
           U: relativeURL,
           k: refresh,
           R: createResolvePathFromModule(r),
-          b: getWorkerBlobURL,
+          b: getWorkerURL,
           z: requireStub,
           __dirname: typeof module.id === "string" ? module.id.replace(/(^|\/)\/+$/, "") : module.id
         })
@@ -608,11 +615,20 @@ function applyChunkListUpdate(update: ChunkListUpdate) {
           DEV_BACKEND.unloadChunk?.(chunkPath);
           break;
         case "partial":
-          invariant(
-            chunkUpdate.instruction,
-            (instruction) =>
-              `Unknown partial instruction: ${JSON.stringify(instruction)}.`
-          );
+          switch (chunkUpdate.instruction.type) {
+            case "CssInsertRulesUpdate":
+              DEV_BACKEND.insertCssRules?.(
+                chunkPath,
+                chunkUpdate.instruction
+              );
+              break;
+            default:
+              invariant(
+                chunkUpdate.instruction,
+                (instruction) =>
+                  `Unknown partial instruction: ${JSON.stringify(instruction)}.`
+              );
+          }
           break;
         default:
           invariant(
@@ -1102,14 +1118,14 @@ function registerChunkList(
 
 globalThis.TURBOPACK_CHUNK_UPDATE_LISTENERS ??= [];
 
-const chunkListsToRegister = globalThis.TURBOPACK_CHUNK_LISTS;
+const chunkListsToRegister = globalThis[CHUNK_LISTS_GLOBAL];
 if (Array.isArray(chunkListsToRegister)) {
   for (const chunkList of chunkListsToRegister) {
     registerChunkList(globalThis.TURBOPACK_CHUNK_UPDATE_LISTENERS, chunkList);
   }
 }
 
-globalThis.TURBOPACK_CHUNK_LISTS = {
+globalThis[CHUNK_LISTS_GLOBAL] = {
   push: (chunkList) => {
     registerChunkList(globalThis.TURBOPACK_CHUNK_UPDATE_LISTENERS!, chunkList);
   },
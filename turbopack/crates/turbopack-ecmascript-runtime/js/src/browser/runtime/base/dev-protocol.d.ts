@@ -51,9 +51,22 @@ type ChunkUpdate =
     }
   | { type: "deleted" }
   | { type: "total" }
-  // We currently don't have any chunks that can be updated partially that can't
-  // be merged either. So these updates would go into `MergedChunkUpdate` instead.
-  | { type: "partial"; instruction: never };
+  // Ecmascript chunks can be merged, so their partial updates go into
+  // `MergedChunkUpdate` instead.
+  | { type: "partial"; instruction: PartialChunkInstruction };
+
+type PartialChunkInstruction =
+  | CssInsertRulesUpdate
+  | {
+      type: never;
+    };
+
+type CssInsertRulesUpdate = {
+  type: "CssInsertRulesUpdate";
+  previousRuleCount: number;
+  // Sorted by index, which is the position of the rule in the new version.
+  insertions: { index: number; rule: string }[];
+};
 
 type MergedChunkUpdate =
   | EcmascriptMergedUpdate
@@ -13,6 +13,8 @@
 
 declare var TURBOPACK_WORKER_LOCATION: string;
 declare var CHUNK_BASE_PATH: string;
+declare var CHUNK_FALLBACK_BASE_PATH: string | null;
+declare var CHUNK_FORMAT_ESM: boolean;
 declare function instantiateModule(id: ModuleId, source: SourceInfo): Module;
 
 type RuntimeParams = {
@@ -70,6 +72,10 @@ interface RuntimeBackend {
 
 interface DevRuntimeBackend {
   reloadChunk?: (chunkPath: ChunkPath) => Promise<void>;
+  insertCssRules?: (
+    chunkPath: ChunkPath,
+    update: CssInsertRulesUpdate
+  ) => Promise<void>;
   unloadChunk?: (chunkPath: ChunkPath) => void;
   restart: () => void;
 }
@@ -238,10 +244,35 @@ function resolveAbsolutePath(modulePath?: string): string {
   return `/ROOT/${modulePath ?? ""}`;
 }
 
-function getWorkerBlobURL(chunks: ChunkPath[]): string {
-  let bootstrap = `self.TURBOPACK_WORKER_LOCATION = ${JSON.stringify(location.origin)};importScripts(${chunks.map(c => (`self.TURBOPACK_WORKER_LOCATION + ${JSON.stringify(getChunkRelativeUrl(c))}`)).join(", ")});`;
-  let blob = new Blob([bootstrap], { type: "text/javascript" });
-  return URL.createObjectURL(blob);
+/**
+ * Returns the URL to construct a worker or to add a worklet module with, which
+ * loads `chunks`.
+ *
+ * Workers are loaded from blob or data URLs, which chunk URLs can't be resolved
+ * against, so the chunks are loaded from absolute URLs and the worker resolves
+ * the chunks it loads later against `TURBOPACK_WORKER_LOCATION`. Shared workers
+ * are loaded from a data URL, so that all pages get the same URL and share the
+ * worker. Worklets only support module scripts, so they import the chunks.
+ * ES module chunks can't be loaded with `importScripts`, so workers import
+ * them one after another as well.
+ */
+function getWorkerURL(chunks: ChunkPath[], type: WorkerType): string {
+  const urls = chunks.map(
+    (c) => new URL(getChunkRelativeUrl(c), location.origin).href
+  );
+  if (type === "worklet") {
+    const bootstrap = urls.map((url) => `import ${JSON.stringify(url)};`).join("");
+    return URL.createObjectURL(new Blob([bootstrap], { type: "text/javascript" }));
+  }
+
+  const loadChunks = CHUNK_FORMAT_ESM
+    ? `(async () => {for (const url of ${JSON.stringify(urls)}) await import(url);})();`
+    : `importScripts(${urls.map((url) => JSON.stringify(url)).join(", ")});`;
+  const bootstrap = `self.TURBOPACK_WORKER_LOCATION = ${JSON.stringify(location.origin)};${loadChunks}`;
+  if (type === "shared-worker") {
+    return `data:text/javascript,${encodeURIComponent(bootstrap)}`;
+  }
+  return URL.createObjectURL(new Blob([bootstrap], { type: "text/javascript" }));
 }
 
 /**
@@ -293,10 +324,31 @@ function instantiateRuntimeModule(
  * Returns the URL relative to the origin where a chunk can be fetched from.
  */
 function getChunkRelativeUrl(chunkPath: ChunkPath): string {
-  return `${CHUNK_BASE_PATH}${chunkPath
+  return `${CHUNK_BASE_PATH}${encodeChunkPath(chunkPath)}`;
+}
+
+/**
+ * Returns the URL a chunk can be fetched from when fetching it from
+ * `getChunkRelativeUrl` failed, e.g. because the CDN serving chunks is
+ * unreachable or the chunk failed its integrity check.
+ *
+ * Returns `null` when no fallback is configured.
+ */
+function getChunkFallbackUrl(chunkPath: ChunkPath): string | null {
+  if (
+    CHUNK_FALLBACK_BASE_PATH == null ||
+    CHUNK_FALLBACK_BASE_PATH === CHUNK_BASE_PATH
+  ) {
+    return null;
+  }
+  return `${CHUNK_FALLBACK_BASE_PATH}${encodeChunkPath(chunkPath)}`;
+}
+
+function encodeChunkPath(chunkPath: ChunkPath): string {
+  return chunkPath
     .split("/")
     .map((p) => encodeURIComponent(p))
-    .join("/")}`;
+    .join("/");
 }
 
 /**
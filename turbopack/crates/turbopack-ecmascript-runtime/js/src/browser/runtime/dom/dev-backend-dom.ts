@@ -41,6 +41,11 @@ let DEV_BACKEND: DevRuntimeBackend;
         for (const script of Array.from(scripts)) {
           script.remove();
         }
+        if (CHUNK_FORMAT_ESM) {
+          // The module stays in the module map of the browser, so loading the
+          // chunk again has to import it from another URL.
+          importedChunks.add(chunkPath);
+        }
       } else {
         throw new Error(`can't infer type of chunk from path ${chunkPath}`);
       }
@@ -105,6 +110,37 @@ let DEV_BACKEND: DevRuntimeBackend;
       });
     },
 
+    async insertCssRules(chunkPath, update) {
+      const chunkUrl = getChunkRelativeUrl(chunkPath);
+      const decodedChunkUrl = decodeURI(chunkUrl);
+
+      const links = document.querySelectorAll<HTMLLinkElement>(
+        `link[rel=stylesheet][href="${chunkUrl}"],link[rel=stylesheet][href^="${chunkUrl}?"],link[rel=stylesheet][href="${decodedChunkUrl}"],link[rel=stylesheet][href^="${decodedChunkUrl}?"]`
+      );
+
+      try {
+        for (const link of Array.from(links)) {
+          const sheet = link.sheet;
+          // Rules dropped by the browser while parsing would shift the indices
+          // of the insertions, so only patch stylesheets matching the previous
+          // version.
+          if (
+            sheet == null ||
+            sheet.cssRules.length !== update.previousRuleCount
+          ) {
+            throw new Error(`Stylesheet of chunk ${chunkPath} is out of sync`);
+          }
+          for (const { index, rule } of update.insertions) {
+            sheet.insertRule(rule, index);
+          }
+        }
+      } catch {
+        // This also covers cross-origin stylesheets, whose rules can't be
+        // accessed.
+        await DEV_BACKEND.reloadChunk!(chunkPath);
+      }
+    },
+
     restart: () => self.location.reload(),
   };
 
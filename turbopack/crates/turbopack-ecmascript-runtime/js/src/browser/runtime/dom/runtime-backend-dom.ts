@@ -23,8 +23,21 @@ function augmentContext(context: unknown): unknown {
   return context;
 }
 
-function fetchWebAssembly(wasmChunkPath: ChunkPath) {
-  return fetch(getChunkRelativeUrl(wasmChunkPath));
+async function fetchWebAssembly(wasmChunkPath: ChunkPath) {
+  const fallbackUrl = getChunkFallbackUrl(wasmChunkPath);
+  if (fallbackUrl == null) {
+    return fetch(getChunkRelativeUrl(wasmChunkPath));
+  }
+
+  try {
+    const response = await fetch(getChunkRelativeUrl(wasmChunkPath));
+    if (response.ok) {
+      return response;
+    }
+  } catch {
+    // Retried from the fallback location below.
+  }
+  return fetch(fallbackUrl);
 }
 
 async function loadWebAssembly(
@@ -53,6 +66,13 @@ async function loadWebAssemblyModule(
  */
 const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
 
+/**
+ * The chunks which were imported as ES modules. Browsers only evaluate a module
+ * URL once, so a chunk which is loaded again after HMR unloaded it is imported
+ * with a query string.
+ */
+const importedChunks: Set<ChunkPath> = new Set();
+
 (() => {
   BACKEND = {
     async registerChunk(chunkPath, params) {
@@ -111,6 +131,133 @@ const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
     return resolver;
   }
 
+  let chunkIntegrity: Promise<Record<ChunkPath, string>> | undefined;
+
+  /**
+   * Fetches `static/chunks/integrity-manifest.json` from
+   * `CHUNK_FALLBACK_BASE_PATH` once. It maps the path of each chunk to the
+   * integrity it was built with, so chunks retried from the same origin are
+   * verified even when the failed element had no integrity attribute.
+   */
+  function loadChunkIntegrity(): Promise<Record<ChunkPath, string>> {
+    if (chunkIntegrity == null) {
+      const manifestUrl = getChunkFallbackUrl(
+        "static/chunks/integrity-manifest.json"
+      );
+      chunkIntegrity =
+        manifestUrl == null
+          ? Promise.resolve({})
+          : fetch(manifestUrl)
+              .then((response) => (response.ok ? response.json() : {}))
+              .catch(() => ({}));
+    }
+    return chunkIntegrity;
+  }
+
+  /**
+   * Retries loading a script chunk from `CHUNK_FALLBACK_BASE_PATH` after
+   * loading it from `CHUNK_BASE_PATH` failed. The fallback is verified with
+   * the integrity of the chunk in the integrity manifest, or with the one of
+   * the failed script when the manifest doesn't list the chunk.
+   */
+  async function loadFallbackScript(
+    chunkPath: ChunkPath,
+    resolver: ChunkResolver,
+    failedIntegrity: string
+  ) {
+    const fallbackUrl = getChunkFallbackUrl(chunkPath);
+    if (fallbackUrl == null) {
+      resolver.reject();
+      return;
+    }
+    if (CHUNK_FORMAT_ESM) {
+      // The integrity of imported modules can only be checked through an
+      // import map.
+      importedChunks.add(chunkPath);
+      import(fallbackUrl).catch((error) => resolver.reject(error));
+      return;
+    }
+
+    const integrity =
+      (await loadChunkIntegrity())[chunkPath] ?? failedIntegrity;
+    if (document.querySelector(`script[src="${fallbackUrl}"]`) != null) {
+      // Another failed script for this chunk already triggered the fallback.
+      return;
+    }
+
+    const script = document.createElement("script");
+    script.src = fallbackUrl;
+    if (integrity) {
+      script.integrity = integrity;
+    }
+    script.onerror = () => {
+      resolver.reject();
+    };
+    document.body.appendChild(script);
+  }
+
+  /**
+   * Imports an ES module chunk, retrying from `CHUNK_FALLBACK_BASE_PATH` when
+   * importing it from `CHUNK_BASE_PATH` fails. The chunk resolves its resolver
+   * in `registerChunk` when it's evaluated.
+   */
+  async function importChunk(chunkPath: ChunkPath, resolver: ChunkResolver) {
+    const query = importedChunks.has(chunkPath) ? `?ts=${Date.now()}` : "";
+    importedChunks.add(chunkPath);
+
+    const resolveUrl = (url: string) =>
+      typeof importScripts === "function"
+        ? new URL(url, TURBOPACK_WORKER_LOCATION).href
+        : url;
+    try {
+      await import(resolveUrl(getChunkRelativeUrl(chunkPath) + query));
+    } catch (error) {
+      const fallbackUrl = getChunkFallbackUrl(chunkPath);
+      if (fallbackUrl == null) {
+        resolver.reject(error as Error);
+        return;
+      }
+      try {
+        await import(resolveUrl(fallbackUrl + query));
+      } catch (error) {
+        resolver.reject(error as Error);
+      }
+    }
+  }
+
+  /**
+   * Retries loading a CSS chunk from `CHUNK_FALLBACK_BASE_PATH` after loading
+   * it from `CHUNK_BASE_PATH` failed, verified like `loadFallbackScript`.
+   */
+  async function loadFallbackStylesheet(
+    chunkPath: ChunkPath,
+    resolver: ChunkResolver,
+    failedIntegrity: string
+  ) {
+    const fallbackUrl = getChunkFallbackUrl(chunkPath);
+    if (fallbackUrl == null) {
+      resolver.reject();
+      return;
+    }
+
+    const integrity =
+      (await loadChunkIntegrity())[chunkPath] ?? failedIntegrity;
+
+    const link = document.createElement("link");
+    link.rel = "stylesheet";
+    link.href = fallbackUrl;
+    if (integrity) {
+      link.integrity = integrity;
+    }
+    link.onerror = () => {
+      resolver.reject();
+    };
+    link.onload = () => {
+      resolver.resolve();
+    };
+    document.body.appendChild(link);
+  }
+
   /**
    * Loads the given chunk, and returns a promise that resolves once the chunk
    * has been loaded.
@@ -141,12 +288,35 @@ const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
     const chunkUrl = getChunkRelativeUrl(chunkPath);
     const decodedChunkUrl = decodeURI(chunkUrl);
 
+    if (
+      CHUNK_FORMAT_ESM &&
+      chunkPath.endsWith(".js") &&
+      (typeof importScripts === "function" ||
+        document.querySelector(
+          `script[src="${chunkUrl}"],script[src^="${chunkUrl}?"],script[src="${decodedChunkUrl}"],script[src^="${decodedChunkUrl}?"]`
+        ) == null)
+    ) {
+      // ES module chunks are imported instead of appending a
+      // `<script type="module">` element, which works in workers as well.
+      // Chunks rendered by the server are handled like classic scripts below.
+      importChunk(chunkPath, resolver);
+      return resolver.promise;
+    }
+
     if (typeof importScripts === "function") {
       // We're in a web worker
       if (chunkPath.endsWith(".css")) {
         // ignore
       } else if (chunkPath.endsWith(".js")) {
-        importScripts(TURBOPACK_WORKER_LOCATION + chunkUrl);
+        try {
+          importScripts(new URL(chunkUrl, TURBOPACK_WORKER_LOCATION).href);
+        } catch (error) {
+          const fallbackUrl = getChunkFallbackUrl(chunkPath);
+          if (fallbackUrl == null) {
+            throw error;
+          }
+          importScripts(new URL(fallbackUrl, TURBOPACK_WORKER_LOCATION).href);
+        }
       } else {
         throw new Error(`can't infer type of chunk from path ${chunkPath} in worker`);
       }
@@ -164,7 +334,7 @@ const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
           link.rel = "stylesheet";
           link.href = chunkUrl;
           link.onerror = () => {
-            resolver.reject();
+            loadFallbackStylesheet(chunkPath, resolver, link.integrity);
           };
           link.onload = () => {
             // CSS chunks do not register themselves, and as such must be marked as
@@ -181,8 +351,14 @@ const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
           // There is this edge where the script already failed loading, but we
           // can't detect that. The Promise will never resolve in this case.
           for (const script of Array.from(previousScripts)) {
+            // Scripts rendered by the server can carry an integrity attribute,
+            // in which case a rejected integrity check also ends up here.
             script.addEventListener("error", () => {
-              resolver.reject();
+              loadFallbackScript(
+                chunkPath,
+                resolver,
+                (script as HTMLScriptElement).integrity
+              );
             });
           }
         } else {
@@ -192,7 +368,7 @@ const chunkResolvers: Map<ChunkPath, ChunkResolver> = new Map();
           // which happens in `registerChunk`. Hence the absence of `resolve()` in
           // this branch.
           script.onerror = () => {
-            resolver.reject();
+            loadFallbackScript(chunkPath, resolver, script.integrity);
           };
           document.body.appendChild(script);
         }
@@ -61,6 +61,7 @@ const fs = require("fs/promises") as typeof import('fs/promises');
 
 const moduleFactories: ModuleFactories = Object.create(null);
 const moduleCache: ModuleCache<ModuleWithDirection> = Object.create(null);
+const registeredChunks: Set<ChunkPath> = new Set();
 
 /**
  * Returns an absolute path to the given module's id.
@@ -93,6 +94,19 @@ function loadChunk(chunkData: ChunkData, source?: SourceInfo): void {
   }
 }
 
+/**
+ * Registers the module factories of a chunk which is inlined in the same file
+ * as the runtime (single-file bundles), so that loading it is a no-op.
+ */
+function registerChunk(chunkPath: ChunkPath, chunkModules: ModuleFactories): void {
+  registeredChunks.add(chunkPath);
+  for (const [moduleId, moduleFactory] of Object.entries(chunkModules)) {
+    if (!moduleFactories[moduleId]) {
+      moduleFactories[moduleId] = moduleFactory;
+    }
+  }
+}
+
 function loadChunkPath(chunkPath: ChunkPath, source?: SourceInfo): void {
   if (!chunkPath.endsWith(".js")) {
     // We only support loading JS chunks in Node.js.
@@ -100,6 +114,10 @@ function loadChunkPath(chunkPath: ChunkPath, source?: SourceInfo): void {
     return;
   }
 
+  if (registeredChunks.has(chunkPath)) {
+    return;
+  }
+
   try {
     const resolved = path.resolve(RUNTIME_ROOT, chunkPath);
     const chunkModules: ModuleFactories = require(resolved);
@@ -133,6 +151,10 @@ async function loadChunkAsync(
     return;
   }
 
+  if (registeredChunks.has(chunkPath)) {
+    return;
+  }
+
   const resolved = path.resolve(RUNTIME_ROOT, chunkPath);
 
   try {
@@ -185,8 +207,8 @@ function loadWebAssemblyModule(chunkPath: ChunkPath) {
   return compileWebAssemblyFromPath(resolved);
 }
 
-function getWorkerBlobURL(_chunks: ChunkPath[]): string {
-  throw new Error("Worker blobs are not implemented yet for Node.js");
+function getWorkerURL(_chunks: ChunkPath[], _type: WorkerType): string {
+  throw new Error("Workers are not implemented yet for Node.js");
 }
 
 function instantiateModule(id: ModuleId, source: SourceInfo): ModuleWithDirection {
@@ -262,7 +284,7 @@ function instantiateModule(id: ModuleId, source: SourceInfo): ModuleWithDirectio
       P: resolveAbsolutePath,
       U: relativeURL,
       R: createResolvePathFromModule(r),
-      b: getWorkerBlobURL,
+      b: getWorkerURL,
       z: requireStub,
       __dirname: typeof module.id === "string" ? module.id.replace(/(^|\/)\/+$/, "") : module.id
     });
@@ -340,4 +362,5 @@ function getOrInstantiateRuntimeModule(
 module.exports = {
   getOrInstantiateRuntimeModule,
   loadChunk,
+  registerChunk,
 };
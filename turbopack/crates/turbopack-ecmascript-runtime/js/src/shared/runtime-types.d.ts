@@ -60,7 +60,8 @@ type AsyncModule = (
 ) => void;
 
 type ResolveAbsolutePath = (modulePath?: string) => string;
-type GetWorkerBlobURL = (chunks: ChunkPath[]) => string;
+type WorkerType = "worker" | "shared-worker" | "worklet";
+type GetWorkerURL = (chunks: ChunkPath[], type: WorkerType) => string;
 
 interface Module {
   exports: Function | Exports | Promise<Exports> | AsyncModulePromise;
@@ -100,7 +101,7 @@ interface TurbopackBaseContext<M> {
   g: typeof globalThis;
   P: ResolveAbsolutePath;
   U: RelativeURL;
-  b: GetWorkerBlobURL,
+  b: GetWorkerURL,
   z: CommonJsRequire
   __dirname: string;
 }
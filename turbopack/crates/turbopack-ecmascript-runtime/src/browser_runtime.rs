@@ -13,13 +13,19 @@ use turbopack_ecmascript::utils::StringifyJs;
 
 use crate::{asset_context::get_runtime_asset_context, embed_js::embed_static_code, RuntimeType};
 
-/// Returns the code for the ECMAScript runtime.
+/// Returns the code for the ECMAScript runtime. With `esm_chunks`, chunks are
+/// loaded with `import()` instead of `<script>` elements. The runtime picks up
+/// the chunks registered in the `chunk_loading_global`, and the chunk lists
+/// registered in `{chunk_loading_global}_CHUNK_LISTS`.
 #[turbo_tasks::function]
 pub async fn get_browser_runtime_code(
     environment: Vc<Environment>,
     chunk_base_path: Vc<Option<RcStr>>,
+    chunk_fallback_base_path: Vc<Option<RcStr>>,
     runtime_type: Value<RuntimeType>,
     output_root_to_root_path: Vc<RcStr>,
+    esm_chunks: bool,
+    chunk_loading_global: RcStr,
 ) -> Result<Vc<Code>> {
     let asset_context = get_runtime_asset_context(environment).await?;
 
@@ -76,22 +82,30 @@ pub async fn get_browser_runtime_code(
     let relative_root_path = output_root_to_root_path.await?;
     let chunk_base_path = &*chunk_base_path.await?;
     let chunk_base_path = chunk_base_path.as_ref().map_or_else(|| "", |f| f.as_str());
+    let chunk_fallback_base_path = &*chunk_fallback_base_path.await?;
 
     writedoc!(
         code,
         r#"
             (() => {{
-            if (!Array.isArray(globalThis.TURBOPACK)) {{
+            if (!Array.isArray(globalThis.{global})) {{
                 return;
             }}
 
             const CHUNK_BASE_PATH = {};
+            const CHUNK_FALLBACK_BASE_PATH = {};
             const RELATIVE_ROOT_PATH = {};
             const RUNTIME_PUBLIC_PATH = {};
+            const CHUNK_FORMAT_ESM = {};
+            const CHUNK_LISTS_GLOBAL = {};
         "#,
         StringifyJs(chunk_base_path),
+        StringifyJs(chunk_fallback_base_path),
         StringifyJs(relative_root_path.as_str()),
         StringifyJs(chunk_base_path),
+        esm_chunks,
+        StringifyJs(&format!("{chunk_loading_global}_CHUNK_LISTS")),
+        global = chunk_loading_global,
     )?;
 
     code.push_code(&*shared_runtime_utils_code.await?);
@@ -126,11 +140,12 @@ pub async fn get_browser_runtime_code(
     writedoc!(
         code,
         r#"
-            const chunksToRegister = globalThis.TURBOPACK;
-            globalThis.TURBOPACK = {{ push: registerChunk }};
+            const chunksToRegister = globalThis.{global};
+            globalThis.{global} = {{ push: registerChunk }};
             chunksToRegister.forEach(registerChunk);
             }})();
-        "#
+        "#,
+        global = chunk_loading_global,
     )?;
 
     Ok(Code::cell(code.build()))
@@ -237,6 +237,42 @@ impl Effect {
             Effect::Unreachable { .. } => {}
         }
     }
+
+    /// Collects the conditions of this effect and of the effects nested in it.
+    pub fn collect_conditions_mut<'a>(&'a mut self, conditions: &mut Vec<&'a mut JsValue>) {
+        let blocks: Vec<&'a mut EffectsBlock> = match self {
+            Effect::Conditional {
+                condition, kind, ..
+            } => {
+                conditions.push(condition);
+                match &mut **kind {
+                    ConditionalKind::If { then: block }
+                    | ConditionalKind::Else { r#else: block }
+                    | ConditionalKind::And { expr: block }
+                    | ConditionalKind::Or { expr: block }
+                    | ConditionalKind::NullishCoalescing { expr: block } => vec![block],
+                    ConditionalKind::IfElse { then, r#else }
+                    | ConditionalKind::Ternary { then, r#else } => vec![then, r#else],
+                    ConditionalKind::IfElseMultiple { then, r#else } => {
+                        then.iter_mut().chain(r#else.iter_mut()).collect()
+                    }
+                }
+            }
+            Effect::Call { args, .. } | Effect::MemberCall { args, .. } => args
+                .iter_mut()
+                .filter_map(|arg| match arg {
+                    EffectArg::Closure(_, block) => Some(block),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+        for block in blocks {
+            for effect in &mut block.effects {
+                effect.collect_conditions_mut(conditions);
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -318,6 +354,11 @@ impl EvalContext {
         self.imports.is_esm(specified_type)
     }
 
+    /// The mark of identifiers referencing globals.
+    pub fn unresolved_mark(&self) -> Mark {
+        self.unresolved_mark
+    }
+
     fn eval_prop_name(&self, prop: &PropName) -> JsValue {
         match prop {
             PropName::Ident(ident) => ident.sym.clone().into(),
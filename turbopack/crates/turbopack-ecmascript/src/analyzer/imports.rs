@@ -14,7 +14,7 @@ use swc_core::{
 };
 use turbo_rcstr::RcStr;
 use turbo_tasks::{FxIndexMap, FxIndexSet, Vc};
-use turbopack_core::{issue::IssueSource, source::Source};
+use turbopack_core::{chunk::ResourceHint, issue::IssueSource, source::Source};
 
 use super::{top_level_await::has_top_level_await, JsValue, ModuleValue};
 use crate::{
@@ -195,11 +195,38 @@ pub struct ImportAttributes {
     /// const b = import(/* turbopackIgnore: true */ "b");
     /// ```
     pub ignore: bool,
+    /// The name of the chunks created for a dynamic import, used in their file
+    /// names instead of the path of the imported module.
+    ///
+    /// This is set by using either a `webpackChunkName` or `turbopackChunkName`
+    /// comment.
+    ///
+    /// Example:
+    /// ```js
+    /// const settings = import(/* turbopackChunkName: "settings" */ "./settings");
+    /// ```
+    pub chunk_name: Option<RcStr>,
+    /// Should the browser load the chunks of a dynamic import before it's
+    /// evaluated? The chunks are listed in the client manifests, so that they
+    /// are prefetched or preloaded along with the page.
+    ///
+    /// This is set by using either a `webpackPrefetch`/`webpackPreload` or
+    /// `turbopackPrefetch`/`turbopackPreload` comment.
+    ///
+    /// Example:
+    /// ```js
+    /// const modal = import(/* turbopackPrefetch: true */ "./modal");
+    /// ```
+    pub resource_hint: Option<ResourceHint>,
 }
 
 impl ImportAttributes {
     pub const fn empty() -> Self {
-        ImportAttributes { ignore: false }
+        ImportAttributes {
+            ignore: false,
+            chunk_name: None,
+            resource_hint: None,
+        }
     }
 
     pub fn empty_ref() -> &'static Self {
@@ -541,11 +568,14 @@ impl Visit for Analyzer<'_> {
         }
     }
 
-    /// check if import or require contains an ignore comment
+    /// check if import or require contains an ignore comment, or import a chunk
+    /// name or resource hint comment
     ///
     /// We are checking for the following cases:
     /// - import(/* webpackIgnore: true */ "a")
     /// - require(/* webpackIgnore: true */ "a")
+    /// - import(/* webpackChunkName: "a" */ "a")
+    /// - import(/* webpackPrefetch: true */ "a")
     ///
     /// We can do this by checking if any of the comment spans are between the
     /// callee and the first argument.
@@ -565,14 +595,25 @@ impl Visit for Analyzer<'_> {
 
             // we are interested here in the last comment with a valid directive
             let ignore_directive = parse_ignore_directive(comments, n.args.first());
+            let (chunk_name, resource_hint) = match &n.callee {
+                Callee::Import(_) => (
+                    parse_chunk_name_directive(comments, n.args.first()),
+                    parse_resource_hint_directive(comments, n.args.first()),
+                ),
+                _ => (None, None),
+            };
 
-            if let Some((callee_span, ignore_directive)) = callee_span.zip(ignore_directive) {
-                self.data.attributes.insert(
-                    callee_span.lo,
-                    ImportAttributes {
-                        ignore: ignore_directive,
-                    },
-                );
+            if let Some(callee_span) = callee_span {
+                if ignore_directive.is_some() || chunk_name.is_some() || resource_hint.is_some() {
+                    self.data.attributes.insert(
+                        callee_span.lo,
+                        ImportAttributes {
+                            ignore: ignore_directive.unwrap_or_default(),
+                            chunk_name,
+                            resource_hint,
+                        },
+                    );
+                }
             };
         }
 
@@ -583,7 +624,9 @@ impl Visit for Analyzer<'_> {
         // we could actually unwrap thanks to the optimisation above but it can't hurt to be safe...
         if let Some(comments) = self.comments {
             let callee_span = match &n.callee {
-                box Expr::Ident(Ident { sym, .. }) if sym == "Worker" => Some(n.span),
+                box Expr::Ident(Ident { sym, .. }) if sym == "Worker" || sym == "SharedWorker" => {
+                    Some(n.span)
+                }
                 _ => None,
             };
 
@@ -594,6 +637,8 @@ impl Visit for Analyzer<'_> {
                     callee_span.lo,
                     ImportAttributes {
                         ignore: ignore_directive,
+                        chunk_name: None,
+                        resource_hint: None,
                     },
                 );
             };
@@ -623,6 +668,69 @@ fn parse_ignore_directive(comments: &dyn Comments, value: Option<&ExprOrSpread>)
         .next()
 }
 
+fn parse_resource_hint_directive(
+    comments: &dyn Comments,
+    value: Option<&ExprOrSpread>,
+) -> Option<ResourceHint> {
+    // a preload takes precedence when both are requested
+    value
+        .map(|arg| arg.span_lo())
+        .and_then(|comment_pos| comments.get_leading(comment_pos))
+        .iter()
+        .flatten()
+        .filter_map(|comment| {
+            let (directive, value) = comment.text.trim().split_once(':')?;
+            match (directive.trim(), value.trim()) {
+                ("webpackPrefetch" | "turbopackPrefetch", "true") => Some(ResourceHint::Prefetch),
+                ("webpackPreload" | "turbopackPreload", "true") => Some(ResourceHint::Preload),
+                _ => None,
+            }
+        })
+        .max()
+}
+
+fn parse_chunk_name_directive(
+    comments: &dyn Comments,
+    value: Option<&ExprOrSpread>,
+) -> Option<RcStr> {
+    // we are interested here in the last comment with a valid directive
+    value
+        .map(|arg| arg.span_lo())
+        .and_then(|comment_pos| comments.get_leading(comment_pos))
+        .iter()
+        .flatten()
+        .rev()
+        .filter_map(|comment| {
+            let (directive, value) = comment.text.trim().split_once(':')?;
+            if !matches!(directive.trim(), "webpackChunkName" | "turbopackChunkName") {
+                return None;
+            }
+            let value = value.trim();
+            let name = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .or_else(|| {
+                    value
+                        .strip_prefix('\'')
+                        .and_then(|value| value.strip_suffix('\''))
+                })?;
+            // The name ends up in file names, so only keep characters which are
+            // safe in them
+            let name = name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect::<String>();
+            (!name.is_empty()).then(|| name.into())
+        })
+        .next()
+}
+
 pub(crate) fn orig_name(n: &ModuleExportName) -> JsWord {
     match n {
         ModuleExportName::Ident(v) => v.sym.clone(),
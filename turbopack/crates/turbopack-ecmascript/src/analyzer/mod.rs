@@ -1795,6 +1795,14 @@ impl JsValue {
                       "Worker".to_string(),
                       "The standard Worker constructor: https://developer.mozilla.org/en-US/docs/Web/API/Worker/Worker"
                     ),
+                    WellKnownFunctionKind::SharedWorkerConstructor => (
+                      "SharedWorker".to_string(),
+                      "The standard SharedWorker constructor: https://developer.mozilla.org/en-US/docs/Web/API/SharedWorker/SharedWorker"
+                    ),
+                    WellKnownFunctionKind::WorkletAddModule => (
+                      "Worklet.addModule".to_string(),
+                      "The standard Worklet addModule method: https://developer.mozilla.org/en-US/docs/Web/API/Worklet/addModule"
+                    ),
                     WellKnownFunctionKind::URLConstructor => (
                       "URL".to_string(),
                       "The standard URL constructor: https://developer.mozilla.org/en-US/docs/Web/API/URL/URL"
@@ -3811,6 +3819,9 @@ pub enum WellKnownFunctionKind {
     NodeResolveFrom,
     NodeProtobufLoad,
     WorkerConstructor,
+    SharedWorkerConstructor,
+    /// `addModule()` of an audio, paint, layout or animation worklet.
+    WorkletAddModule,
     URLConstructor,
 }
 
@@ -3948,6 +3959,12 @@ pub mod test_utils {
                     true,
                     "ignored Worker constructor",
                 ),
+                "SharedWorker" => JsValue::unknown_if(
+                    ignore,
+                    JsValue::WellKnownFunction(WellKnownFunctionKind::SharedWorkerConstructor),
+                    true,
+                    "ignored SharedWorker constructor",
+                ),
                 "define" => JsValue::WellKnownFunction(WellKnownFunctionKind::Define),
                 "URL" => JsValue::WellKnownFunction(WellKnownFunctionKind::URLConstructor),
                 "process" => JsValue::WellKnownObject(WellKnownObjectKind::NodeProcess),
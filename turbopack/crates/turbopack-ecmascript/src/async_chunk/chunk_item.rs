@@ -29,8 +29,9 @@ pub struct AsyncLoaderChunkItem {
 
 #[turbo_tasks::value_impl]
 impl AsyncLoaderChunkItem {
+    /// The chunks of the chunk group loaded by this chunk item.
     #[turbo_tasks::function]
-    pub(super) async fn chunks(&self) -> Result<Vc<OutputAssets>> {
+    pub async fn chunks(&self) -> Result<Vc<OutputAssets>> {
         let module = self.module.await?;
         if let Some(chunk_items) = module.availability_info.available_chunk_items() {
             if chunk_items
@@ -49,6 +50,7 @@ impl AsyncLoaderChunkItem {
         }
         Ok(self.chunking_context.chunk_group_assets(
             *ResolvedVc::upcast(module.inner),
+            module.chunk_name.clone(),
             Value::new(module.availability_info),
         ))
     }
@@ -3,7 +3,7 @@ use turbo_rcstr::RcStr;
 use turbo_tasks::{ResolvedVc, Value, Vc};
 use turbopack_core::{
     asset::{Asset, AssetContent},
-    chunk::{availability_info::AvailabilityInfo, ChunkableModule, ChunkingContext},
+    chunk::{availability_info::AvailabilityInfo, ChunkableModule, ChunkingContext, ResourceHint},
     ident::AssetIdent,
     module::Module,
     reference::{ModuleReferences, SingleModuleReference},
@@ -22,6 +22,10 @@ fn modifier() -> Vc<RcStr> {
 pub struct AsyncLoaderModule {
     pub inner: ResolvedVc<Box<dyn ChunkableModule>>,
     pub chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
+    /// The name of the chunks of `inner`, if any.
+    pub chunk_name: Option<RcStr>,
+    /// How the browser is hinted to load the chunks of `inner`, if at all.
+    pub resource_hint: Option<ResourceHint>,
     pub availability_info: AvailabilityInfo,
 }
 
@@ -31,11 +35,15 @@ impl AsyncLoaderModule {
     pub fn new(
         module: ResolvedVc<Box<dyn ChunkableModule>>,
         chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
+        chunk_name: Option<RcStr>,
+        resource_hint: Option<ResourceHint>,
         availability_info: Value<AvailabilityInfo>,
     ) -> Vc<Self> {
         Self::cell(AsyncLoaderModule {
             inner: module,
             chunking_context,
+            chunk_name,
+            resource_hint,
             availability_info: availability_info.into_value(),
         })
     }
@@ -36,6 +36,7 @@ impl ChunkType for EcmascriptChunkType {
         chunking_context: Vc<Box<dyn ChunkingContext>>,
         chunk_items: Vec<ChunkItemWithAsyncModuleInfo>,
         referenced_output_assets: Vc<OutputAssets>,
+        name: Option<RcStr>,
     ) -> Result<Vc<Box<dyn Chunk>>> {
         let Some(chunking_context) =
             Vc::try_resolve_downcast::<Box<dyn ChunkingContext>>(chunking_context).await?
@@ -60,6 +61,7 @@ impl ChunkType for EcmascriptChunkType {
                 .try_join()
                 .await?,
             referenced_output_assets: referenced_output_assets.await?.clone_value(),
+            name,
         }
         .cell();
         Ok(Vc::upcast(EcmascriptChunk::new(chunking_context, content)))
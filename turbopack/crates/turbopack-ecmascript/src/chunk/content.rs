@@ -1,3 +1,4 @@
+use turbo_rcstr::RcStr;
 use turbo_tasks::{ResolvedVc, Vc};
 use turbopack_core::{chunk::AsyncModuleInfo, output::OutputAsset};
 
@@ -12,4 +13,7 @@ type EcmascriptChunkItemWithAsyncInfo = (
 pub struct EcmascriptChunkContent {
     pub chunk_items: Vec<EcmascriptChunkItemWithAsyncInfo>,
     pub referenced_output_assets: Vec<ResolvedVc<Box<dyn OutputAsset>>>,
+    /// The name of the chunk, used for its output path instead of the common
+    /// path of the chunk items.
+    pub name: Option<RcStr>,
 }
@@ -3,6 +3,7 @@ pub(crate) mod content;
 pub(crate) mod data;
 pub(crate) mod item;
 pub(crate) mod placeable;
+pub(crate) mod provenance;
 
 use std::fmt::Write;
 
@@ -32,6 +33,7 @@ pub use self::{
         EcmascriptChunkItemOptions,
     },
     placeable::{EcmascriptChunkPlaceable, EcmascriptExports},
+    provenance::chunk_item_provenance,
 };
 
 #[turbo_tasks::value]
@@ -75,8 +77,12 @@ fn availability_root_key() -> Vc<RcStr> {
 impl Chunk for EcmascriptChunk {
     #[turbo_tasks::function]
     async fn ident(&self) -> Result<Vc<AssetIdent>> {
-        let EcmascriptChunkContent { chunk_items, .. } = &*self.content.await?;
-        let mut common_path = if let Some((chunk_item, _)) = chunk_items.first() {
+        let EcmascriptChunkContent {
+            chunk_items, name, ..
+        } = &*self.content.await?;
+        let mut common_path = if name.is_some() {
+            None
+        } else if let Some((chunk_item, _)) = chunk_items.first() {
             let path = chunk_item.asset_ident().path().to_resolved().await?;
             Some((path, path.await?))
         } else {
@@ -112,7 +118,13 @@ impl Chunk for EcmascriptChunk {
             .await?;
 
         let ident = AssetIdent {
-            path: if let Some((common_path, _)) = common_path {
+            path: if let Some(name) = name {
+                self.chunking_context
+                    .root_path()
+                    .join(name.clone())
+                    .to_resolved()
+                    .await?
+            } else if let Some((common_path, _)) = common_path {
                 common_path
             } else {
                 ServerFileSystem::new().root().to_resolved().await?
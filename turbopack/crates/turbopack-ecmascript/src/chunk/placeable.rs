@@ -33,7 +33,29 @@ pub trait EcmascriptChunkPlaceable: ChunkableModule + Module + Asset {
 enum SideEffectsValue {
     None,
     Constant(bool),
-    Glob(ResolvedVc<Glob>),
+    /// Modules matching `side_effects` and not matching `no_side_effects` have side effects.
+    /// `side_effects` is `None` when all patterns are negated, then all other modules have side
+    /// effects.
+    Glob {
+        side_effects: Option<ResolvedVc<Glob>>,
+        no_side_effects: Option<ResolvedVc<Glob>>,
+    },
+}
+
+/// Converts a pattern of the `sideEffects` field into a glob relative to the package root, and
+/// whether it's negated. Like webpack, patterns without a `/` match files in any directory.
+fn side_effects_glob(pattern: &str) -> (bool, String) {
+    let (negated, pattern) = match pattern.strip_prefix('!') {
+        Some(pattern) => (true, pattern),
+        None => (false, pattern),
+    };
+    let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+    let glob = if pattern.contains('/') {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    };
+    (negated, glob)
 }
 
 #[turbo_tasks::function]
@@ -49,11 +71,8 @@ async fn side_effects_from_package_json(
                     .iter()
                     .filter_map(|side_effect| {
                         if let Some(side_effect) = side_effect.as_str() {
-                            if side_effect.contains('/') {
-                                Some(Glob::new(side_effect.into()))
-                            } else {
-                                Some(Glob::new(format!("**/{side_effect}").into()))
-                            }
+                            let (negated, glob) = side_effects_glob(side_effect);
+                            Some((negated, Glob::new(glob.into())))
                         } else {
                             SideEffectsInPackageJsonIssue {
                                 path: package_json,
@@ -74,9 +93,9 @@ async fn side_effects_from_package_json(
                             None
                         }
                     })
-                    .map(|glob| async move {
-                        match glob.resolve().await {
-                            Ok(glob) => Ok(Some(glob)),
+                    .map(|(negated, glob)| async move {
+                        match glob.to_resolved().await {
+                            Ok(glob) => Ok(Some((negated, glob))),
                             Err(err) => {
                                 SideEffectsInPackageJsonIssue {
                                     path: package_json,
@@ -99,9 +118,20 @@ async fn side_effects_from_package_json(
                     })
                     .try_flat_join()
                     .await?;
-                return Ok(
-                    SideEffectsValue::Glob(Glob::alternatives(globs).to_resolved().await?).cell(),
-                );
+                let (no_side_effects, side_effects): (Vec<_>, Vec<_>) =
+                    globs.into_iter().partition(|(negated, _)| *negated);
+                let alternatives = |globs: Vec<(bool, ResolvedVc<Glob>)>| async move {
+                    if globs.is_empty() {
+                        return Ok(None);
+                    }
+                    let globs = globs.into_iter().map(|(_, glob)| *glob).collect();
+                    anyhow::Ok(Some(Glob::alternatives(globs).to_resolved().await?))
+                };
+                return Ok(SideEffectsValue::Glob {
+                    side_effects: alternatives(side_effects).await?,
+                    no_side_effects: alternatives(no_side_effects).await?,
+                }
+                .cell());
             } else {
                 SideEffectsInPackageJsonIssue {
                     path: package_json,
@@ -173,13 +203,22 @@ pub async fn is_marked_as_side_effect_free(
         match *side_effects_from_package_json(*package_json).await? {
             SideEffectsValue::None => {}
             SideEffectsValue::Constant(side_effects) => return Ok(Vc::cell(!side_effects)),
-            SideEffectsValue::Glob(glob) => {
-                if let Some(rel_path) = package_json
-                    .parent()
-                    .await?
-                    .get_relative_path_to(&*path.await?)
-                {
-                    return Ok(Vc::cell(!glob.await?.execute(&rel_path)));
+            SideEffectsValue::Glob {
+                side_effects,
+                no_side_effects,
+            } => {
+                let package_dir = package_json.parent().await?;
+                let path = path.await?;
+                if let Some(rel_path) = package_dir.get_path_to(&path) {
+                    let has_side_effects = match side_effects {
+                        Some(glob) => glob.await?.execute(rel_path),
+                        None => true,
+                    };
+                    let excluded = match no_side_effects {
+                        Some(glob) => glob.await?.execute(rel_path),
+                        None => false,
+                    };
+                    return Ok(Vc::cell(!has_side_effects || excluded));
                 }
             }
         }
@@ -218,3 +257,25 @@ impl EcmascriptExports {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::side_effects_glob;
+
+    #[test]
+    fn test_side_effects_glob() {
+        assert_eq!(side_effects_glob("*.css"), (false, "**/*.css".to_string()));
+        assert_eq!(
+            side_effects_glob("./src/polyfill.js"),
+            (false, "src/polyfill.js".to_string())
+        );
+        assert_eq!(
+            side_effects_glob("!./dist/icons/**"),
+            (true, "dist/icons/**".to_string())
+        );
+        assert_eq!(
+            side_effects_glob("!index.js"),
+            (true, "**/index.js".to_string())
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::Result;
+use serde::Serialize;
+use turbo_rcstr::RcStr;
+use turbo_tasks::Vc;
+use turbopack_core::{chunk::ChunkItem, package_json::read_package_json};
+
+#[derive(Serialize)]
+struct ModuleProvenance<'a> {
+    path: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'a str>,
+}
+
+/// A structured comment describing where the module of a chunk item comes
+/// from, written in front of it in non-minified chunks, e.g.
+/// `/* turbopack-module: {"path":"node_modules/react/index.js","package":"react",
+/// "version":"19.0.0"} */`.
+#[turbo_tasks::function]
+pub async fn chunk_item_provenance(chunk_item: Vc<Box<dyn ChunkItem>>) -> Result<Vc<RcStr>> {
+    let path_vc = chunk_item.asset_ident().path();
+    let path = path_vc.await?;
+
+    let mut package = None;
+    let mut version = None;
+    if let Some((package_root, package_name)) = package_of_path(&path.path) {
+        let package_json = read_package_json(
+            path_vc
+                .root()
+                .join(format!("{package_root}/package.json").into())
+                .to_resolved()
+                .await?,
+        )
+        .await?;
+        package = Some(package_name);
+        version = package_json
+            .as_ref()
+            .and_then(|json| json.get("version"))
+            .and_then(|version| version.as_str())
+            .map(RcStr::from);
+    }
+
+    let provenance = ModuleProvenance {
+        path: &path.path,
+        package,
+        version: version.as_deref(),
+    };
+    // `*/` would end the comment early, it can't appear in the JSON otherwise.
+    let json = serde_json::to_string(&provenance)?.replace("*/", "*\\/");
+    Ok(Vc::cell(format!("/* turbopack-module: {} */", json).into()))
+}
+
+/// Returns the directory and name of the package in `node_modules` containing
+/// `path`, e.g. `node_modules/@swc/helpers` and `@swc/helpers` for
+/// `node_modules/@swc/helpers/esm/_interop_require_default.js`.
+fn package_of_path(path: &str) -> Option<(&str, &str)> {
+    let start = path
+        .rfind("/node_modules/")
+        .map(|index| index + "/node_modules/".len())
+        .or_else(|| {
+            path.starts_with("node_modules/")
+                .then_some("node_modules/".len())
+        })?;
+    let rest = &path[start..];
+    let segments = if rest.starts_with('@') { 2 } else { 1 };
+    let name_len = rest
+        .match_indices('/')
+        .nth(segments - 1)
+        .map_or(rest.len(), |(index, _)| index);
+    let name = &rest[..name_len];
+    Some((&path[..start + name_len], name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::package_of_path;
+
+    #[test]
+    fn test_package_of_path() {
+        assert_eq!(
+            package_of_path("node_modules/react/index.js"),
+            Some(("node_modules/react", "react"))
+        );
+        assert_eq!(
+            package_of_path("apps/web/node_modules/@swc/helpers/esm/index.js"),
+            Some(("apps/web/node_modules/@swc/helpers", "@swc/helpers"))
+        );
+        assert_eq!(
+            package_of_path("node_modules/.pnpm/a@1.0.0/node_modules/a/index.js"),
+            Some(("node_modules/.pnpm/a@1.0.0/node_modules/a", "a"))
+        );
+        assert_eq!(package_of_path("app/page.tsx"), None);
+    }
+}
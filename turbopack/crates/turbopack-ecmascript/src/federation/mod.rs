@@ -0,0 +1,133 @@
+//! Module Federation lets separately built applications share code at
+//! runtime. A build can expose modules as a remote container, consume the
+//! modules exposed by other builds (remotes), and negotiate which version of
+//! a shared module, like `react`, is used by all of them.
+//!
+//! Remotes and shared modules are resolved by the
+//! [resolve::ModuleFederationResolvePlugin], while the runtime, which keeps
+//! track of containers and shared modules, is inlined into the generated
+//! modules and installed once per page.
+//!
+//! Module ids are only unique within a build, so builds loaded on the same
+//! page must not share a chunk registry. Each build registers its chunks in a
+//! chunk loading global of its own (see
+//! `BrowserChunkingContextBuilder::chunk_loading_global`), so they're picked
+//! up by its own runtime, with its own module factories and module cache. The
+//! remote entry of a build therefore includes its runtime. Builds only
+//! exchange modules through the federation runtime in
+//! `globalThis.__TURBOPACK_FEDERATION__`, which passes functions returning
+//! the exports of modules, never module ids.
+
+pub mod remote_module;
+pub mod resolve;
+
+use std::fmt::Write;
+
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{FxIndexMap, ResolvedVc};
+
+use crate::utils::StringifyJs;
+
+/// The directory, relative to the root, that contains the virtual modules
+/// generated for module federation.
+pub const FEDERATION_DIR: &str = "__federation__";
+
+/// The share scope used for all shared modules.
+pub const DEFAULT_SHARE_SCOPE: &str = "default";
+
+const RUNTIME_CODE: &str = include_str!("runtime.js");
+
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug)]
+pub struct SharedModule {
+    /// The version of the module provided by this build.
+    pub version: RcStr,
+    /// The semver range of versions this build works with. Any version is
+    /// accepted when not set.
+    pub required_version: Option<RcStr>,
+    /// Whether only a single version of the module can be used on a page.
+    pub singleton: bool,
+}
+
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug, Default)]
+pub struct ModuleFederationOptions {
+    /// The name of the container exposing [Self::exposes].
+    pub name: Option<RcStr>,
+    /// Modules exposed to other builds, by the request they are imported
+    /// with (e.g. `./Button`), to their path relative to the project.
+    pub exposes: FxIndexMap<RcStr, RcStr>,
+    /// The URLs of the remote entries of other builds, by the name of their
+    /// container. Modules of remotes are imported as `{name}/{module}`.
+    pub remotes: FxIndexMap<RcStr, RcStr>,
+    /// Modules shared with other builds, by their request.
+    pub shared: FxIndexMap<RcStr, SharedModule>,
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionModuleFederationOptions(Option<ResolvedVc<ModuleFederationOptions>>);
+
+/// Returns code which assigns the federation runtime to `federation`,
+/// installing it first if no other module did.
+pub fn runtime_code() -> String {
+    format!(
+        "const federation = globalThis.__TURBOPACK_FEDERATION__ || \
+         (globalThis.__TURBOPACK_FEDERATION__ = {});\n",
+        RUNTIME_CODE.trim_end()
+    )
+}
+
+/// Returns the code of a CommonJS module that exports the version of the
+/// shared module `name` selected by the runtime, falling back to the version
+/// of this build.
+pub fn consume_shared_code(name: &str, shared: &SharedModule) -> Result<String> {
+    let mut code = runtime_code();
+    writeln!(
+        code,
+        "module.exports = federation.loadShared({}, {}, {{ version: {}, requiredVersion: {}, \
+         singleton: {} }}, () => require({}));",
+        StringifyJs(DEFAULT_SHARE_SCOPE),
+        StringifyJs(name),
+        StringifyJs(&shared.version),
+        StringifyJs(&shared.required_version),
+        shared.singleton,
+        StringifyJs(name)
+    )?;
+    Ok(code)
+}
+
+/// Returns the code of the container entry of the build named `name`, which
+/// registers the `exposes` modules with the runtime. `exposes` maps the
+/// requests of exposed modules to the requests importing them from the
+/// container entry.
+///
+/// Exposed modules are imported statically, so that they are part of the
+/// chunks of the container entry. Chunks loaded on demand would be loaded
+/// relative to the page of the host, unless the asset prefix of the build is
+/// an absolute URL.
+pub fn container_code(name: &str, exposes: &FxIndexMap<RcStr, RcStr>) -> Result<String> {
+    let mut code = String::new();
+    for (i, request) in exposes.values().enumerate() {
+        writeln!(
+            code,
+            "import * as __exposed_{i}__ from {};",
+            StringifyJs(request)
+        )?;
+    }
+    code.push_str(&runtime_code());
+    writeln!(
+        code,
+        "federation.registerContainer({}, {{",
+        StringifyJs(name)
+    )?;
+    for (i, module) in exposes.keys().enumerate() {
+        writeln!(
+            code,
+            "  {}: () => Promise.resolve(__exposed_{i}__),",
+            StringifyJs(module)
+        )?;
+    }
+    writeln!(code, "}});")?;
+    Ok(code)
+}
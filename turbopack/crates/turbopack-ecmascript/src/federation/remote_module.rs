@@ -0,0 +1,212 @@
+use std::io::Write;
+
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{glob::Glob, rope::RopeBuilder, FileContent, FileSystem, VirtualFileSystem};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    chunk::{AsyncModuleInfo, ChunkItem, ChunkType, ChunkableModule, ChunkingContext},
+    ident::AssetIdent,
+    module::Module,
+};
+
+use super::runtime_code;
+use crate::{
+    chunk::{
+        EcmascriptChunkItem, EcmascriptChunkItemContent, EcmascriptChunkPlaceable,
+        EcmascriptChunkType, EcmascriptExports,
+    },
+    references::async_module::{AsyncModule, OptionAsyncModule},
+    utils::StringifyJs,
+    EcmascriptModuleContent, EcmascriptOptions,
+};
+
+#[turbo_tasks::function]
+fn layer() -> Vc<RcStr> {
+    Vc::cell("federation".into())
+}
+
+// Without this wrapper, VirtualFileSystem::new_with_name always returns a new filesystem
+#[turbo_tasks::function]
+fn remotes_fs() -> Vc<VirtualFileSystem> {
+    VirtualFileSystem::new_with_name("federation-remotes".into())
+}
+
+/// A module exposed by a remote container, which is loaded at runtime from
+/// the remote entry of the remote.
+#[turbo_tasks::value]
+pub struct RemoteModule {
+    /// The name of the remote container.
+    pub remote: RcStr,
+    /// The URL of the remote entry of the remote.
+    pub entry: RcStr,
+    /// The request of the module exposed by the remote, e.g. `./Button`.
+    pub module: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl RemoteModule {
+    #[turbo_tasks::function]
+    pub fn new(remote: RcStr, entry: RcStr, module: RcStr) -> Vc<Self> {
+        Self::cell(RemoteModule {
+            remote,
+            entry,
+            module,
+        })
+    }
+
+    #[turbo_tasks::function]
+    pub fn content(&self) -> Result<Vc<EcmascriptModuleContent>> {
+        let mut code = RopeBuilder::from(runtime_code().into_bytes());
+
+        writeln!(
+            code,
+            "const mod = await federation.loadRemote({}, {}, {});",
+            StringifyJs(&self.remote),
+            StringifyJs(&self.entry),
+            StringifyJs(&self.module)
+        )?;
+        writeln!(code)?;
+        writeln!(code, "__turbopack_export_namespace__(mod);")?;
+
+        Ok(EcmascriptModuleContent {
+            inner_code: code.build(),
+            source_map: None,
+            is_esm: true,
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Module for RemoteModule {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        AssetIdent::from_path(
+            remotes_fs()
+                .root()
+                .join(format!("{}/{}", self.remote, self.module.trim_start_matches("./")).into()),
+        )
+        .with_layer(layer())
+        .with_modifier(Vc::cell(self.entry.clone()))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for RemoteModule {
+    #[turbo_tasks::function]
+    fn content(self: Vc<Self>) -> Vc<AssetContent> {
+        // should be `NotFound` as this function gets called to detect source changes
+        AssetContent::file(FileContent::NotFound.cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableModule for RemoteModule {
+    #[turbo_tasks::function]
+    fn as_chunk_item(
+        self: ResolvedVc<Self>,
+        chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
+    ) -> Vc<Box<dyn ChunkItem>> {
+        Vc::upcast(
+            RemoteModuleChunkItem {
+                module: self,
+                chunking_context,
+            }
+            .cell(),
+        )
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkPlaceable for RemoteModule {
+    #[turbo_tasks::function]
+    fn get_exports(&self) -> Vc<EcmascriptExports> {
+        EcmascriptExports::DynamicNamespace.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn get_async_module(&self) -> Vc<OptionAsyncModule> {
+        Vc::cell(Some(
+            AsyncModule {
+                has_top_level_await: true,
+                import_externals: false,
+            }
+            .resolved_cell(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    fn is_marked_as_side_effect_free(
+        self: Vc<Self>,
+        _side_effect_free_packages: Vc<Glob>,
+    ) -> Vc<bool> {
+        Vc::cell(false)
+    }
+}
+
+#[turbo_tasks::value]
+pub struct RemoteModuleChunkItem {
+    module: ResolvedVc<RemoteModule>,
+    chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkItem for RemoteModuleChunkItem {
+    #[turbo_tasks::function]
+    fn asset_ident(&self) -> Vc<AssetIdent> {
+        self.module.ident()
+    }
+
+    #[turbo_tasks::function]
+    fn ty(self: Vc<Self>) -> Vc<Box<dyn ChunkType>> {
+        Vc::upcast(Vc::<EcmascriptChunkType>::default())
+    }
+
+    #[turbo_tasks::function]
+    fn module(&self) -> Vc<Box<dyn Module>> {
+        Vc::upcast(*self.module)
+    }
+
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> Vc<Box<dyn ChunkingContext>> {
+        *self.chunking_context
+    }
+
+    #[turbo_tasks::function]
+    fn is_self_async(&self) -> Vc<bool> {
+        Vc::cell(true)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkItem for RemoteModuleChunkItem {
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> Vc<Box<dyn ChunkingContext>> {
+        *self.chunking_context
+    }
+
+    #[turbo_tasks::function]
+    fn content(self: Vc<Self>) -> Vc<EcmascriptChunkItemContent> {
+        panic!("content() should not be called");
+    }
+
+    #[turbo_tasks::function]
+    fn content_with_async_module_info(
+        &self,
+        async_module_info: Option<Vc<AsyncModuleInfo>>,
+    ) -> Vc<EcmascriptChunkItemContent> {
+        let async_module_options = self
+            .module
+            .get_async_module()
+            .module_options(async_module_info);
+
+        EcmascriptChunkItemContent::new(
+            self.module.content(),
+            *self.chunking_context,
+            EcmascriptOptions::default().cell(),
+            async_module_options,
+        )
+    }
+}
@@ -0,0 +1,133 @@
+use anyhow::Result;
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Value, Vc};
+use turbo_tasks_fs::{FileContent, FileSystemPath};
+use turbopack_core::{
+    asset::AssetContent,
+    reference_type::ReferenceType,
+    resolve::{
+        parse::Request,
+        pattern::Pattern,
+        plugin::{BeforeResolvePlugin, BeforeResolvePluginCondition},
+        ResolveResult, ResolveResultOption,
+    },
+    virtual_source::VirtualSource,
+};
+
+use super::{consume_shared_code, ModuleFederationOptions, FEDERATION_DIR};
+
+/// The extension of the sources describing modules of remotes. They are
+/// turned into [super::remote_module::RemoteModule]s by a module rule.
+pub const REMOTE_MODULE_EXTENSION: &str = ".federation-remote";
+
+/// Resolves imports of the modules of remotes, and of shared modules, to
+/// virtual sources loading them through the federation runtime.
+#[turbo_tasks::value]
+pub struct ModuleFederationResolvePlugin {
+    root: ResolvedVc<FileSystemPath>,
+    options: ResolvedVc<ModuleFederationOptions>,
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleFederationResolvePlugin {
+    #[turbo_tasks::function]
+    pub fn new(
+        root: ResolvedVc<FileSystemPath>,
+        options: ResolvedVc<ModuleFederationOptions>,
+    ) -> Vc<Self> {
+        ModuleFederationResolvePlugin { root, options }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl BeforeResolvePlugin for ModuleFederationResolvePlugin {
+    #[turbo_tasks::function]
+    async fn before_resolve_condition(&self) -> Result<Vc<BeforeResolvePluginCondition>> {
+        let options = self.options.await?;
+        let mut modules: Vec<RcStr> = options.remotes.keys().cloned().collect();
+        for request in options.shared.keys() {
+            let module = module_of_request(request);
+            if !modules.iter().any(|m| m == module) {
+                modules.push(module.into());
+            }
+        }
+        Ok(BeforeResolvePluginCondition::from_modules(Vc::cell(
+            modules,
+        )))
+    }
+
+    #[turbo_tasks::function]
+    async fn before_resolve(
+        &self,
+        lookup_path: Vc<FileSystemPath>,
+        _reference_type: Value<ReferenceType>,
+        request: Vc<Request>,
+    ) -> Result<Vc<ResolveResultOption>> {
+        let federation_dir = self.root.join(FEDERATION_DIR.into());
+        // The generated modules import the modules they share themselves.
+        if *lookup_path.is_inside_or_equal(federation_dir).await? {
+            return Ok(ResolveResultOption::none());
+        }
+
+        let request = &*request.await?;
+        let Request::Module {
+            module,
+            path: Pattern::Constant(path),
+            ..
+        } = request
+        else {
+            return Ok(ResolveResultOption::none());
+        };
+        let options = self.options.await?;
+
+        if let Some(entry) = options.remotes.get(module) {
+            if path.is_empty() {
+                return Ok(ResolveResultOption::none());
+            }
+            let content = serde_json::json!({
+                "remote": module,
+                "entry": entry,
+                "module": format!(".{path}"),
+            });
+            let source = VirtualSource::new(
+                federation_dir
+                    .join(format!("remotes/{module}{path}{REMOTE_MODULE_EXTENSION}").into()),
+                AssetContent::file(FileContent::Content(content.to_string().into()).cell()),
+            )
+            .to_resolved()
+            .await?;
+            return Ok(ResolveResultOption::some(
+                ResolveResult::source(ResolvedVc::upcast(source)).resolved_cell(),
+            ));
+        }
+
+        let name: RcStr = format!("{module}{path}").into();
+        if let Some(shared) = options.shared.get(&name) {
+            let source = VirtualSource::new(
+                federation_dir.join(format!("shared/{name}.js").into()),
+                AssetContent::file(
+                    FileContent::Content(consume_shared_code(&name, shared)?.into()).cell(),
+                ),
+            )
+            .to_resolved()
+            .await?;
+            return Ok(ResolveResultOption::some(
+                ResolveResult::source(ResolvedVc::upcast(source)).resolved_cell(),
+            ));
+        }
+
+        Ok(ResolveResultOption::none())
+    }
+}
+
+/// Returns the package name of a module request, e.g. `@scope/a` for
+/// `@scope/a/b`.
+fn module_of_request(request: &str) -> &str {
+    let mut separators = request.match_indices('/').map(|(i, _)| i);
+    let end = if request.starts_with('@') {
+        separators.nth(1)
+    } else {
+        separators.next()
+    };
+    end.map_or(request, |end| &request[..end])
+}
@@ -0,0 +1,147 @@
+(() => {
+  // Shared modules by share scope, name and version. Each version has a
+  // `get` function returning its exports, and `loaded` is set once it was
+  // used by any container.
+  const shareScopes = Object.create(null);
+  // Containers registered by the remote entries loaded on the page.
+  const containers = Object.create(null);
+  const containerWaiters = Object.create(null);
+  // Promises of the containers of remotes, by remote name.
+  const remotes = Object.create(null);
+
+  function parseVersion(version) {
+    return version
+      .replace(/^[^\d]*/, "")
+      .split(/[.+-]/)
+      .slice(0, 3)
+      .map((part) => parseInt(part, 10) || 0);
+  }
+
+  function compareVersions(a, b) {
+    const pa = parseVersion(a);
+    const pb = parseVersion(b);
+    for (let i = 0; i < 3; i++) {
+      if (pa[i] !== pb[i]) return (pa[i] || 0) - (pb[i] || 0);
+    }
+    return 0;
+  }
+
+  // Supports exact versions, `*`, and `^`, `~` and `>=` ranges.
+  function satisfies(version, range) {
+    if (!range || range === "*") return true;
+    const comparison = compareVersions(version, range);
+    const v = parseVersion(version);
+    const r = parseVersion(range);
+    if (range.startsWith(">=")) return comparison >= 0;
+    if (range.startsWith("^")) {
+      const fixed = r[0] !== 0 ? 1 : r[1] !== 0 ? 2 : 3;
+      return comparison >= 0 && r.slice(0, fixed).every((p, i) => p === v[i]);
+    }
+    if (range.startsWith("~")) {
+      return comparison >= 0 && v[0] === r[0] && v[1] === r[1];
+    }
+    return comparison === 0;
+  }
+
+  function getVersions(scope, name) {
+    const shareScope = (shareScopes[scope] = shareScopes[scope] || {});
+    return (shareScope[name] = shareScope[name] || {});
+  }
+
+  function registerShared(scope, name, version, get) {
+    const versions = getVersions(scope, name);
+    if (!versions[version]) versions[version] = { get, loaded: false };
+  }
+
+  // Returns the exports of the shared module `name`, registering `fallback`,
+  // the module of the calling build, as one of its versions. Singletons use
+  // the version which was used first, other modules the highest version
+  // satisfying `requiredVersion`.
+  function loadShared(scope, name, options, fallback) {
+    registerShared(scope, name, options.version, fallback);
+    const versions = getVersions(scope, name);
+    let selected;
+    if (options.singleton) {
+      selected =
+        Object.keys(versions).find((version) => versions[version].loaded) ||
+        Object.keys(versions).sort(compareVersions).pop();
+      if (!satisfies(selected, options.requiredVersion)) {
+        console.warn(
+          `Shared singleton ${name} is used in version ${selected}, which doesn't satisfy the required version ${options.requiredVersion}`
+        );
+      }
+    } else {
+      selected =
+        Object.keys(versions)
+          .filter((version) => satisfies(version, options.requiredVersion))
+          .sort(compareVersions)
+          .pop() || options.version;
+    }
+    const entry = versions[selected];
+    entry.loaded = true;
+    return entry.get();
+  }
+
+  // Registers the modules exposed by the remote entry of this build.
+  function registerContainer(name, exposes) {
+    const container = {
+      get(module) {
+        const load = exposes[module];
+        if (!load) {
+          return Promise.reject(
+            new Error(`Module ${module} is not exposed by the remote ${name}`)
+          );
+        }
+        return load().then((exports) => () => exports);
+      },
+    };
+    containers[name] = container;
+    (containerWaiters[name] || []).forEach((resolve) => resolve(container));
+    delete containerWaiters[name];
+  }
+
+  function waitForContainer(name) {
+    if (containers[name]) return Promise.resolve(containers[name]);
+    return new Promise((resolve) => {
+      (containerWaiters[name] = containerWaiters[name] || []).push(resolve);
+    });
+  }
+
+  function loadScript(url) {
+    return new Promise((resolve, reject) => {
+      if (typeof document === "undefined") {
+        if (typeof importScripts === "function") {
+          importScripts(url);
+          resolve();
+        } else {
+          reject(
+            new Error(`Remote entries can only be loaded in the browser: ${url}`)
+          );
+        }
+        return;
+      }
+      const script = document.createElement("script");
+      script.src = url;
+      script.async = true;
+      script.onload = () => resolve();
+      script.onerror = () =>
+        reject(new Error(`Failed to load the remote entry ${url}`));
+      document.head.appendChild(script);
+    });
+  }
+
+  // Returns the exports of the `module` exposed by the remote `name`, loading
+  // its remote entry from `url` first if necessary.
+  function loadRemote(name, url, module) {
+    if (!remotes[name]) {
+      remotes[name] = containers[name]
+        ? Promise.resolve(containers[name])
+        : loadScript(url).then(() => waitForContainer(name));
+    }
+    return remotes[name]
+      .then((container) => container.get(module))
+      .then((factory) => factory());
+  }
+
+  return { registerShared, loadShared, registerContainer, loadRemote };
+})()
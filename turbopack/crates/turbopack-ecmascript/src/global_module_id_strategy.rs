@@ -180,6 +180,10 @@ pub async fn merge_preprocessed_module_ids(
         }
     }
 
+    // The order of the endpoints and of the modules within them isn't stable across builds, so
+    // sort the idents to make the assignment of ids on collisions independent of it.
+    merged_module_ids.sort_keys();
+
     // 5% fill rate, as done in Webpack
     // https://github.com/webpack/webpack/blob/27cf3e59f5f289dfc4d76b7a1df2edbc4e651589/lib/ids/IdHelpers.js#L366-L405
     let optimal_range = merged_module_ids.len() * 20;
@@ -196,7 +200,7 @@ pub async fn merge_preprocessed_module_ids(
         let mut i = 1;
         while used_ids.contains(&trimmed_hash) {
             // If the id is already used, seek to find another available id.
-            trimmed_hash = hash_xxh3_hash64(full_hash + i) % digit_mask;
+            trimmed_hash = hash_xxh3_hash64(full_hash.wrapping_add(i)) % digit_mask;
             i += 1;
         }
         used_ids.insert(trimmed_hash);
@@ -35,6 +35,8 @@ fn modifier() -> Vc<RcStr> {
 pub struct ManifestAsyncModule {
     pub inner: ResolvedVc<Box<dyn ChunkableModule>>,
     pub chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
+    /// The name of the chunks of `inner`, if any.
+    pub chunk_name: Option<RcStr>,
     pub availability_info: AvailabilityInfo,
 }
 
@@ -44,11 +46,13 @@ impl ManifestAsyncModule {
     pub async fn new(
         module: ResolvedVc<Box<dyn ChunkableModule>>,
         chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
+        chunk_name: Option<RcStr>,
         availability_info: Value<AvailabilityInfo>,
     ) -> Vc<Self> {
         Self::cell(ManifestAsyncModule {
             inner: module,
             chunking_context,
+            chunk_name,
             availability_info: availability_info.into_value(),
         })
     }
@@ -57,6 +61,7 @@ impl ManifestAsyncModule {
     pub(super) fn chunks(&self) -> Vc<OutputAssets> {
         self.chunking_context.chunk_group_assets(
             *ResolvedVc::upcast(self.inner),
+            self.chunk_name.clone(),
             Value::new(self.availability_info),
         )
     }
@@ -78,9 +83,11 @@ impl ManifestAsyncModule {
                 return Ok(Vc::cell(vec![]));
             }
         }
-        Ok(this
-            .chunking_context
-            .chunk_group_assets(Vc::upcast(self), Value::new(this.availability_info)))
+        Ok(this.chunking_context.chunk_group_assets(
+            Vc::upcast(self),
+            None,
+            Value::new(this.availability_info),
+        ))
     }
 
     #[turbo_tasks::function]
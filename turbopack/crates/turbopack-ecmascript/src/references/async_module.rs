@@ -138,7 +138,9 @@ impl AsyncModule {
                         }
                     }
                     ReferencedAsset::External(..) => None,
-                    ReferencedAsset::None | ReferencedAsset::Unresolvable => None,
+                    ReferencedAsset::Ignored
+                    | ReferencedAsset::None
+                    | ReferencedAsset::Unresolvable => None,
                 })
             })
             .try_flat_join()
@@ -43,6 +43,9 @@ use crate::{
 pub enum ReferencedAsset {
     Some(ResolvedVc<Box<dyn EcmascriptChunkPlaceable>>),
     External(RcStr, ExternalType),
+    /// The request is ignored, e.g. a file mapped to `false` by the `browser`
+    /// field. It's imported like an empty CommonJS module.
+    Ignored,
     None,
     Unresolvable,
 }
@@ -54,6 +57,7 @@ impl ReferencedAsset {
             ReferencedAsset::External(request, ty) => Some(magic_identifier::mangle(&format!(
                 "{ty} external {request}"
             ))),
+            ReferencedAsset::Ignored => Some(magic_identifier::mangle("ignored module")),
             ReferencedAsset::None | ReferencedAsset::Unresolvable => None,
         })
     }
@@ -93,7 +97,9 @@ impl ReferencedAsset {
                         return Ok(ReferencedAsset::Some(placeable).cell());
                     }
                 }
-                // TODO ignore should probably be handled differently
+                ModuleResolveResultItem::Ignore => {
+                    return Ok(ReferencedAsset::Ignored.cell());
+                }
                 _ => {}
             }
         }
@@ -368,6 +374,17 @@ impl CodeGenerateable for EsmAssetReference {
                             request
                         )
                     }
+                    ReferencedAsset::Ignored => Some((
+                        ident.clone().into(),
+                        var_decl_with_span(
+                            quote!(
+                                "var $name = { default: {} };" as Stmt,
+                                name =
+                                    Ident::new(ident.clone().into(), DUMMY_SP, Default::default()),
+                            ),
+                            span,
+                        ),
+                    )),
                     ReferencedAsset::None => None,
                 }
             } else {
@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use swc_core::{
+    common::GLOBALS,
+    ecma::ast::{
+        Decl, ExportSpecifier, Id, Module, ModuleDecl, ModuleExportName, ModuleItem, Pat, Program,
+        Stmt, VarDecl, VarDeclKind,
+    },
+};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, TryJoinIterExt, Value, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbopack_core::{
+    module::Module,
+    reference_type::EcmaScriptModulesReferenceSubType,
+    resolve::{origin::ResolveOrigin, parse::Request},
+};
+use turbopack_resolve::ecmascript::esm_resolve;
+
+use crate::{
+    analyzer::{
+        graph::create_graph,
+        imports::{ImportAttributes, ImportMap, Reexport},
+        linker::link,
+        ConstantNumber, ConstantValue, JsValue, ModuleValue,
+    },
+    parse::ParseResult,
+    references::{compile_time_info_for_module_type, early_value_visitor, value_visitor},
+    tree_shake::TURBOPACK_PART_IMPORT_SOURCE,
+    EcmascriptModuleAsset, EcmascriptParsable,
+};
+
+/// The number of modules a constant is followed through, e.g. along a chain of
+/// re-exports.
+pub(crate) const MAX_CONSTANT_EXPORT_DEPTH: u32 = 8;
+
+/// The compile-time constant value of an export, e.g. of
+/// `export const FLAG = process.env.FLAG === "1"`.
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Clone, Hash)]
+pub enum ConstantExport {
+    Undefined,
+    Null,
+    Bool(bool),
+    Str(RcStr),
+    /// The bits of the number, as `f64` isn't `Eq`.
+    Num(u64),
+}
+
+impl ConstantExport {
+    fn from_js_value(value: &JsValue) -> Option<Self> {
+        let JsValue::Constant(value) = value else {
+            return None;
+        };
+        Some(match value {
+            ConstantValue::Undefined => ConstantExport::Undefined,
+            ConstantValue::Null => ConstantExport::Null,
+            ConstantValue::True => ConstantExport::Bool(true),
+            ConstantValue::False => ConstantExport::Bool(false),
+            ConstantValue::Str(s) => ConstantExport::Str(s.as_str().into()),
+            ConstantValue::Num(ConstantNumber(n)) => ConstantExport::Num(n.to_bits()),
+            ConstantValue::BigInt(_) | ConstantValue::Regex(..) => return None,
+        })
+    }
+}
+
+impl From<&ConstantExport> for JsValue {
+    fn from(value: &ConstantExport) -> Self {
+        JsValue::Constant(match value {
+            ConstantExport::Undefined => ConstantValue::Undefined,
+            ConstantExport::Null => ConstantValue::Null,
+            ConstantExport::Bool(b) => (*b).into(),
+            ConstantExport::Str(s) => s.as_str().into(),
+            ConstantExport::Num(bits) => ConstantValue::Num(ConstantNumber(f64::from_bits(*bits))),
+        })
+    }
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionConstantExport(Option<ConstantExport>);
+
+/// Returns the compile-time constant value of the export `export` of `module`,
+/// as read by the module at `importer`. Only `const` bindings are considered,
+/// as their value can't change after the module has been evaluated. The value
+/// is followed through named re-exports and imported bindings up to `depth`
+/// modules, which also ends cycles of re-exports.
+///
+/// A module importing the importer is evaluated after it when the importer is
+/// evaluated first, so the importer would read the binding before it's
+/// initialized. Its exports aren't constant to the importer. Only direct
+/// imports are checked, not longer cycles.
+#[turbo_tasks::function]
+pub async fn constant_export(
+    module: ResolvedVc<EcmascriptModuleAsset>,
+    export: RcStr,
+    importer: ResolvedVc<FileSystemPath>,
+    depth: u32,
+) -> Result<Vc<OptionConstantExport>> {
+    if depth == 0 {
+        return Ok(Vc::cell(None));
+    }
+    let parsed = module.failsafe_parse().await?;
+    let ParseResult::Ok {
+        program,
+        globals,
+        eval_context,
+        ..
+    } = &*parsed
+    else {
+        return Ok(Vc::cell(None));
+    };
+    let Program::Module(m) = program else {
+        return Ok(Vc::cell(None));
+    };
+    let origin = ResolvedVc::upcast::<Box<dyn ResolveOrigin>>(module);
+    let imports = &eval_context.imports;
+
+    // `export { imported as export } from "..."`
+    for (i, reexport) in imports.reexports() {
+        let Reexport::Named { imported, exported } = reexport else {
+            continue;
+        };
+        if exported.as_str() != export.as_str() {
+            continue;
+        }
+        let Some(reference) = imports.references().nth(i) else {
+            return Ok(Vc::cell(None));
+        };
+        return Ok(
+            match resolve_esm_module(*origin, &reference.module_path).await? {
+                Some(module) => {
+                    constant_export(*module, imported.as_str().into(), *importer, depth - 1)
+                }
+                None => Vc::cell(None),
+            },
+        );
+    }
+
+    let Some(local) = exported_const(m, &export, imports) else {
+        return Ok(Vc::cell(None));
+    };
+    if imports_path(*origin, imports, *importer).await? {
+        return Ok(Vc::cell(None));
+    }
+    let mut var_graph = GLOBALS.set(globals, || create_graph(program, eval_context));
+    let mut value = imports
+        .get_import(&local)
+        .unwrap_or(JsValue::Variable(local));
+    inline_imported_constants(&mut [&mut value], &mut var_graph.values, *origin, depth - 1).await?;
+
+    let compile_time_info =
+        compile_time_info_for_module_type(*module.await?.compile_time_info, true);
+    let value = link(
+        &var_graph,
+        value,
+        &early_value_visitor,
+        &|value| {
+            value_visitor(
+                *origin,
+                value,
+                compile_time_info,
+                &var_graph,
+                ImportAttributes::empty_ref(),
+            )
+        },
+        HashMap::new(),
+    )
+    .await?;
+
+    Ok(Vc::cell(ConstantExport::from_js_value(&value)))
+}
+
+/// Returns the local binding exported as `export`, if it's a top-level `const`
+/// or an imported binding, which is immutable as well.
+fn exported_const(m: &Module, export: &str, imports: &ImportMap) -> Option<Id> {
+    let const_binding = |var: &VarDecl, name: &str| {
+        if var.kind != VarDeclKind::Const {
+            return None;
+        }
+        var.decls.iter().find_map(|decl| match &decl.name {
+            Pat::Ident(ident) if ident.id.sym.as_str() == name => Some(ident.id.to_id()),
+            _ => None,
+        })
+    };
+
+    // `export const export = ...`
+    let local = m.body.iter().find_map(|item| match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(decl)) => match &decl.decl {
+            Decl::Var(var) => const_binding(var, export),
+            _ => None,
+        },
+        _ => None,
+    });
+    if local.is_some() {
+        return local;
+    }
+
+    // `export { local as export }`
+    let local = m.body.iter().find_map(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named)) = item else {
+            return None;
+        };
+        if named.src.is_some() {
+            return None;
+        }
+        named.specifiers.iter().find_map(|specifier| {
+            let ExportSpecifier::Named(specifier) = specifier else {
+                return None;
+            };
+            let ModuleExportName::Ident(orig) = &specifier.orig else {
+                return None;
+            };
+            let exported = match &specifier.exported {
+                Some(ModuleExportName::Ident(exported)) => exported.sym.as_str(),
+                Some(ModuleExportName::Str(exported)) => exported.value.as_str(),
+                None => orig.sym.as_str(),
+            };
+            (exported == export).then(|| orig.to_id())
+        })
+    })?;
+    if imports.get_import(&local).is_some() {
+        return Some(local);
+    }
+    m.body.iter().find_map(|item| match item {
+        ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+            const_binding(var, &local.0).filter(|id| *id == local)
+        }
+        _ => None,
+    })
+}
+
+/// Whether any of the imports of `origin` resolves to the module at `path`.
+async fn imports_path(
+    origin: Vc<Box<dyn ResolveOrigin>>,
+    imports: &ImportMap,
+    path: Vc<FileSystemPath>,
+) -> Result<bool> {
+    let path = path.await?;
+    for reference in imports.references() {
+        if reference.module_path.as_str() == TURBOPACK_PART_IMPORT_SOURCE {
+            continue;
+        }
+        let result = esm_resolve(
+            origin,
+            Request::parse(Value::new(
+                RcStr::from(reference.module_path.as_str()).into(),
+            )),
+            Value::new(EcmaScriptModulesReferenceSubType::Import),
+            true,
+            None,
+        );
+        for module in result.primary_modules().await?.iter() {
+            if *module.ident().path().await? == *path {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+async fn resolve_esm_module(
+    origin: Vc<Box<dyn ResolveOrigin>>,
+    module_path: &str,
+) -> Result<Option<ResolvedVc<EcmascriptModuleAsset>>> {
+    if module_path == TURBOPACK_PART_IMPORT_SOURCE {
+        return Ok(None);
+    }
+    let result = esm_resolve(
+        origin,
+        Request::parse(Value::new(RcStr::from(module_path).into())),
+        Value::new(EcmaScriptModulesReferenceSubType::Import),
+        true,
+        None,
+    );
+    Ok(match &**result.primary_modules().await? {
+        [module] => ResolvedVc::try_downcast_type::<EcmascriptModuleAsset>(*module).await?,
+        _ => None,
+    })
+}
+
+/// Replaces the imported bindings in `values` and in the variables they read
+/// with the constant values of the exports, where they are known. Linking then
+/// evaluates conditions on them, e.g. `if (FLAG)` with a `FLAG` imported from a
+/// module which derives it from `process.env`.
+pub(crate) async fn inline_imported_constants(
+    values: &mut [&mut JsValue],
+    variables: &mut HashMap<Id, JsValue>,
+    origin: Vc<Box<dyn ResolveOrigin>>,
+    depth: u32,
+) -> Result<()> {
+    fn collect(value: &JsValue, ids: &mut Vec<Id>, imports: &mut HashSet<(RcStr, RcStr)>) {
+        match value {
+            JsValue::Variable(id) => ids.push(id.clone()),
+            JsValue::Member(_, box JsValue::Module(module), box JsValue::Constant(export)) => {
+                if let (Some(export), None) = (export.as_str(), module.annotations.module_type()) {
+                    imports.insert((module.module.as_str().into(), export.into()));
+                }
+            }
+            _ => value.for_each_children(&mut |child| collect(child, ids, imports)),
+        }
+    }
+
+    let mut imports = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut queue = vec![];
+    for value in values.iter() {
+        collect(value, &mut queue, &mut imports);
+    }
+    while let Some(id) = queue.pop() {
+        if !visited.insert(id.clone()) {
+            continue;
+        }
+        if let Some(value) = variables.get(&id) {
+            collect(value, &mut queue, &mut imports);
+        }
+    }
+    if imports.is_empty() {
+        return Ok(());
+    }
+
+    let constants: HashMap<(RcStr, RcStr), JsValue> = imports
+        .into_iter()
+        .map(|(module_path, export)| async move {
+            let Some(module) = resolve_esm_module(origin, &module_path).await? else {
+                return Ok(None);
+            };
+            let constant =
+                constant_export(*module, export.clone(), origin.origin_path(), depth).await?;
+            Ok((*constant)
+                .as_ref()
+                .map(|constant| ((module_path, export), JsValue::from(constant))))
+        })
+        .try_join()
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+    if constants.is_empty() {
+        return Ok(());
+    }
+
+    let mut replace = |value: &mut JsValue| {
+        if let JsValue::Member(
+            _,
+            box JsValue::Module(ModuleValue { module, .. }),
+            box JsValue::Constant(export),
+        ) = value
+        {
+            let key = export
+                .as_str()
+                .map(|export| (RcStr::from(module.as_str()), RcStr::from(export)));
+            if let Some(constant) = key.and_then(|key| constants.get(&key)) {
+                *value = constant.clone();
+                return true;
+            }
+        }
+        false
+    };
+    for value in values.iter_mut() {
+        value.visit_mut(&mut replace);
+    }
+    for id in visited {
+        if let Some(value) = variables.get_mut(&id) {
+            value.visit_mut(&mut replace);
+        }
+    }
+    Ok(())
+}
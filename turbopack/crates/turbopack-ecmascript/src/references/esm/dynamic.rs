@@ -7,7 +7,9 @@ use swc_core::{
 use turbo_rcstr::RcStr;
 use turbo_tasks::{ResolvedVc, Value, ValueToString, Vc};
 use turbopack_core::{
-    chunk::{ChunkableModuleReference, ChunkingContext, ChunkingType, ChunkingTypeOption},
+    chunk::{
+        ChunkableModuleReference, ChunkingContext, ChunkingType, ChunkingTypeOption, ResourceHint,
+    },
     environment::ChunkLoading,
     issue::IssueSource,
     reference::ModuleReference,
@@ -38,6 +40,12 @@ pub struct EsmAsyncAssetReference {
     pub issue_source: ResolvedVc<IssueSource>,
     pub in_try: bool,
     pub import_externals: bool,
+    /// The name of the chunks of the imported module, from a
+    /// `turbopackChunkName` comment.
+    pub chunk_name: Option<RcStr>,
+    /// The resource hint for the chunks of the imported module, from a
+    /// `turbopackPrefetch` or `turbopackPreload` comment.
+    pub resource_hint: Option<ResourceHint>,
 }
 
 impl EsmAsyncAssetReference {
@@ -61,6 +69,8 @@ impl EsmAsyncAssetReference {
         annotations: Value<ImportAnnotations>,
         in_try: bool,
         import_externals: bool,
+        chunk_name: Option<RcStr>,
+        resource_hint: Option<ResourceHint>,
     ) -> Vc<Self> {
         Self::cell(EsmAsyncAssetReference {
             origin,
@@ -70,6 +80,8 @@ impl EsmAsyncAssetReference {
             annotations: annotations.into_value(),
             in_try,
             import_externals,
+            chunk_name,
+            resource_hint,
         })
     }
 }
@@ -102,7 +114,10 @@ impl ValueToString for EsmAsyncAssetReference {
 impl ChunkableModuleReference for EsmAsyncAssetReference {
     #[turbo_tasks::function]
     fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
-        Vc::cell(Some(ChunkingType::Async))
+        Vc::cell(Some(ChunkingType::Async {
+            chunk_name: self.chunk_name.clone(),
+            resource_hint: self.resource_hint,
+        }))
     }
 }
 
@@ -1,5 +1,6 @@
 pub(crate) mod base;
 pub(crate) mod binding;
+pub(crate) mod constant_export;
 pub(crate) mod dynamic;
 pub(crate) mod export;
 pub(crate) mod meta;
@@ -32,6 +32,7 @@ use num_traits::Zero;
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use regex::Regex;
+use rustc_hash::FxHashSet;
 use sourcemap::decode_data_url;
 use swc_core::{
     atoms::JsWord,
@@ -63,7 +64,7 @@ use turbopack_core::{
     issue::{analyze::AnalyzeIssue, IssueExt, IssueSeverity, IssueSource, StyledString},
     module::Module,
     reference::{ModuleReference, ModuleReferences, SourceMapReference},
-    reference_type::{CommonJsReferenceSubType, ReferenceType},
+    reference_type::{CommonJsReferenceSubType, ReferenceType, WorkerReferenceSubType},
     resolve::{
         find_context_file,
         origin::{PlainResolveOrigin, ResolveOrigin, ResolveOriginExt},
@@ -80,7 +81,7 @@ use turbopack_resolve::{
 };
 use turbopack_swc_utils::emitter::IssueEmitter;
 use unreachable::Unreachable;
-use worker::WorkerAssetReference;
+use worker::{worker_call_name, WorkerAssetReference, WORKLET_PROPERTIES};
 
 use self::{
     amd::{
@@ -120,7 +121,7 @@ use crate::{
     analyzer::{
         builtin::early_replace_builtin,
         graph::{ConditionalKind, EffectArg, EvalContext, VarGraph},
-        imports::{ImportAnnotations, ImportAttributes, ImportedSymbol, Reexport},
+        imports::{ImportAnnotations, ImportAttributes, ImportMap, ImportedSymbol, Reexport},
         parse_require_context,
         top_level_await::has_top_level_await,
         ConstantNumber, ConstantString, JsValueUrlKind, RequireContextValue,
@@ -133,7 +134,11 @@ use crate::{
         async_module::{AsyncModule, OptionAsyncModule},
         cjs::{CjsRequireAssetReference, CjsRequireCacheAccess, CjsRequireResolveAssetReference},
         dynamic_expression::DynamicExpression,
-        esm::{module_id::EsmModuleIdAssetReference, EsmBinding, UrlRewriteBehavior},
+        esm::{
+            constant_export::{inline_imported_constants, MAX_CONSTANT_EXPORT_DEPTH},
+            module_id::EsmModuleIdAssetReference,
+            EsmBinding, UrlRewriteBehavior,
+        },
         ident::IdentReplacement,
         node::PackageJsonReference,
         require_context::{RequireContextAssetReference, RequireContextMap},
@@ -353,6 +358,11 @@ struct AnalysisState<'a> {
     import_externals: bool,
     ignore_dynamic_requests: bool,
     url_rewrite_behavior: Option<UrlRewriteBehavior>,
+    /// The spans of the `new URL()` expressions passed to workers and
+    /// worklets, see [ModuleReferencesVisitor::worker_urls].
+    worker_urls: &'a FxHashSet<Span>,
+    /// The magic comments of the imports, see [ImportAttributes].
+    import_map: &'a ImportMap,
 }
 
 impl AnalysisState<'_> {
@@ -650,6 +660,7 @@ pub(crate) async fn analyse_ecmascript_module_internal(
         analysis.add_import_reference(reference);
     }
 
+    let mut worker_urls = FxHashSet::default();
     let (webpack_runtime, webpack_entry, webpack_chunks, esm_exports, esm_star_exports) =
         set_handler_and_globals(&handler, globals, || {
             // TODO migrate to effects
@@ -686,6 +697,7 @@ pub(crate) async fn analyse_ecmascript_module_internal(
             }
 
             program.visit_with_ast_path(&mut visitor, &mut Default::default());
+            worker_urls = visitor.worker_urls;
 
             (
                 visitor.webpack_runtime,
@@ -861,6 +873,23 @@ pub(crate) async fn analyse_ecmascript_module_internal(
 
     analysis.set_exports(exports);
 
+    if options.tree_shaking_mode.is_some() {
+        // Conditions on constants imported from other modules, e.g. feature
+        // flags derived from `process.env`, are evaluated like local ones, so
+        // that the inactive branches are eliminated.
+        let mut conditions = Vec::new();
+        for effect in var_graph.effects.iter_mut() {
+            effect.collect_conditions_mut(&mut conditions);
+        }
+        inline_imported_constants(
+            &mut conditions,
+            &mut var_graph.values,
+            *origin,
+            MAX_CONSTANT_EXPORT_DEPTH,
+        )
+        .await?;
+    }
+
     let effects = take(&mut var_graph.effects);
 
     let mut analysis_state = AnalysisState {
@@ -875,6 +904,8 @@ pub(crate) async fn analyse_ecmascript_module_internal(
         import_externals: options.import_externals,
         ignore_dynamic_requests: options.ignore_dynamic_requests,
         url_rewrite_behavior: options.url_rewrite_behavior,
+        worker_urls: &worker_urls,
+        import_map: &eval_context.imports,
     };
 
     enum Action {
@@ -1108,6 +1139,13 @@ pub(crate) async fn analyse_ecmascript_module_internal(
                         continue;
                     }
                 }
+                let is_worklet_add_module = !new
+                    && prop.as_str() == Some("addModule")
+                    && matches!(
+                        &obj,
+                        JsValue::Member(_, _, box JsValue::Constant(JsConstantValue::Str(worklet)))
+                            if WORKLET_PROPERTIES.contains(&worklet.as_str())
+                    );
                 let mut obj = analysis_state
                     .link_value(obj, ImportAttributes::empty_ref())
                     .await?;
@@ -1150,12 +1188,16 @@ pub(crate) async fn analyse_ecmascript_module_internal(
                     }
                 }
 
-                let func = analysis_state
-                    .link_value(
-                        JsValue::member(Box::new(obj.clone()), Box::new(prop)),
-                        ImportAttributes::empty_ref(),
-                    )
-                    .await?;
+                let func = if is_worklet_add_module {
+                    JsValue::WellKnownFunction(WellKnownFunctionKind::WorkletAddModule)
+                } else {
+                    analysis_state
+                        .link_value(
+                            JsValue::member(Box::new(obj.clone()), Box::new(prop)),
+                            ImportAttributes::empty_ref(),
+                        )
+                        .await?
+                };
 
                 handle_call(
                     &ast_path,
@@ -1315,6 +1357,68 @@ async fn compile_time_info_for_module_type(
     .cell())
 }
 
+/// Adds a [WorkerAssetReference] for a `new Worker()`, `new SharedWorker()`
+/// or worklet `addModule()` call. Its first argument must be a `new URL()`
+/// relative to `import.meta.url`.
+async fn handle_worker(
+    ast_path: &[AstParentKind],
+    span: Span,
+    args: Vec<JsValue>,
+    worker_type: WorkerReferenceSubType,
+    state: &AnalysisState<'_>,
+    analysis: &mut AnalyzeEcmascriptModuleResultBuilder,
+    in_try: bool,
+) -> Result<()> {
+    let &AnalysisState {
+        handler,
+        origin,
+        source,
+        compile_time_info,
+        ignore_dynamic_requests,
+        ..
+    } = state;
+    let name = worker_call_name(&worker_type);
+
+    if let Some(url @ JsValue::Url(_, JsValueUrlKind::Relative)) = args.first() {
+        let pat = js_value_to_pattern(url);
+        if !pat.has_constant_parts() {
+            let (args, hints) = JsValue::explain_args(&args, 10, 2);
+            handler.span_warn_with_code(
+                span,
+                &format!("{name}({args}) is very dynamic{hints}",),
+                DiagnosticId::Lint(errors::failed_to_analyse::ecmascript::NEW_WORKER.to_string()),
+            );
+            if ignore_dynamic_requests {
+                return Ok(());
+            }
+        }
+
+        if *compile_time_info.environment().rendering().await? == Rendering::Client {
+            analysis.add_reference(
+                WorkerAssetReference::new(
+                    *origin,
+                    Request::parse(Value::new(pat)),
+                    Value::new(worker_type),
+                    Vc::cell(ast_path.to_vec()),
+                    issue_source(*source, span),
+                    in_try,
+                )
+                .to_resolved()
+                .await?,
+            );
+        }
+
+        return Ok(());
+    }
+    let (args, hints) = JsValue::explain_args(&args, 10, 2);
+    handler.span_warn_with_code(
+        span,
+        &format!("{name}({args}) is not statically analyse-able{hints}",),
+        DiagnosticId::Error(errors::failed_to_analyse::ecmascript::DYNAMIC_IMPORT.to_string()),
+    );
+    Ok(())
+}
+
 async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
     ast_path: &[AstParentKind],
     span: Span,
@@ -1364,6 +1468,10 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
     if new {
         match func {
             JsValue::WellKnownFunction(WellKnownFunctionKind::URLConstructor) => {
+                // The URL passed to a worker is referenced by the worker instead
+                if state.worker_urls.contains(&span) {
+                    return Ok(());
+                }
                 let args = linked_args(args).await?;
                 if let [url, JsValue::Member(
                     _,
@@ -1408,47 +1516,29 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
             }
             JsValue::WellKnownFunction(WellKnownFunctionKind::WorkerConstructor) => {
                 let args = linked_args(args).await?;
-                if let Some(url @ JsValue::Url(_, JsValueUrlKind::Relative)) = args.first() {
-                    let pat = js_value_to_pattern(url);
-                    if !pat.has_constant_parts() {
-                        let (args, hints) = explain_args(&args);
-                        handler.span_warn_with_code(
-                            span,
-                            &format!("new Worker({args}) is very dynamic{hints}",),
-                            DiagnosticId::Lint(
-                                errors::failed_to_analyse::ecmascript::NEW_WORKER.to_string(),
-                            ),
-                        );
-                        if ignore_dynamic_requests {
-                            return Ok(());
-                        }
-                    }
-
-                    if *compile_time_info.environment().rendering().await? == Rendering::Client {
-                        analysis.add_reference(
-                            WorkerAssetReference::new(
-                                *origin,
-                                Request::parse(Value::new(pat)),
-                                Vc::cell(ast_path.to_vec()),
-                                issue_source(*source, span),
-                                in_try,
-                            )
-                            .to_resolved()
-                            .await?,
-                        );
-                    }
-
-                    return Ok(());
-                }
-                let (args, hints) = explain_args(&args);
-                handler.span_warn_with_code(
+                return handle_worker(
+                    ast_path,
                     span,
-                    &format!("new Worker({args}) is not statically analyse-able{hints}",),
-                    DiagnosticId::Error(
-                        errors::failed_to_analyse::ecmascript::DYNAMIC_IMPORT.to_string(),
-                    ),
-                );
-                return Ok(());
+                    args,
+                    WorkerReferenceSubType::WebWorker,
+                    state,
+                    analysis,
+                    in_try,
+                )
+                .await;
+            }
+            JsValue::WellKnownFunction(WellKnownFunctionKind::SharedWorkerConstructor) => {
+                let args = linked_args(args).await?;
+                return handle_worker(
+                    ast_path,
+                    span,
+                    args,
+                    WorkerReferenceSubType::SharedWorker,
+                    state,
+                    analysis,
+                    in_try,
+                )
+                .await;
             }
             _ => {}
         }
@@ -1483,6 +1573,19 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
                 .await?;
             }
         }
+        JsValue::WellKnownFunction(WellKnownFunctionKind::WorkletAddModule) => {
+            let args = linked_args(args).await?;
+            handle_worker(
+                ast_path,
+                span,
+                args,
+                WorkerReferenceSubType::Worklet,
+                state,
+                analysis,
+                in_try,
+            )
+            .await?;
+        }
         JsValue::WellKnownFunction(WellKnownFunctionKind::Import) => {
             let args = linked_args(args).await?;
             if args.len() == 1 || args.len() == 2 {
@@ -1525,6 +1628,7 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
                         return Ok(());
                     }
                 }
+                let attributes = state.import_map.get_attributes(span);
                 analysis.add_reference(
                     EsmAsyncAssetReference::new(
                         *origin,
@@ -1534,6 +1638,8 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
                         Value::new(import_annotations),
                         in_try,
                         state.import_externals,
+                        attributes.chunk_name.clone(),
+                        attributes.resource_hint,
                     )
                     .to_resolved()
                     .await?,
@@ -2701,6 +2807,12 @@ async fn value_visitor_inner(
                 true,
                 "ignored Worker constructor",
             ),
+            "SharedWorker" => JsValue::unknown_if(
+                ignore,
+                JsValue::WellKnownFunction(WellKnownFunctionKind::SharedWorkerConstructor),
+                true,
+                "ignored SharedWorker constructor",
+            ),
             "define" => JsValue::WellKnownFunction(WellKnownFunctionKind::Define),
             "URL" => JsValue::WellKnownFunction(WellKnownFunctionKind::URLConstructor),
             "process" => JsValue::WellKnownObject(WellKnownObjectKind::NodeProcess),
@@ -2887,6 +2999,11 @@ struct ModuleReferencesVisitor<'a> {
     webpack_runtime: Option<(RcStr, Span)>,
     webpack_entry: bool,
     webpack_chunks: Vec<Lit>,
+    /// The spans of the `new URL(..., import.meta.url)` expressions passed to
+    /// `new Worker()`, `new SharedWorker()` or a worklet's `addModule()`. They
+    /// are referenced by a [WorkerAssetReference] and must not be emitted as
+    /// static assets.
+    worker_urls: FxHashSet<Span>,
 }
 
 impl<'a> ModuleReferencesVisitor<'a> {
@@ -2905,6 +3022,52 @@ impl<'a> ModuleReferencesVisitor<'a> {
             webpack_runtime: None,
             webpack_entry: false,
             webpack_chunks: Vec::new(),
+            worker_urls: FxHashSet::default(),
+        }
+    }
+}
+
+impl ModuleReferencesVisitor<'_> {
+    fn is_unresolved(&self, ident: &Ident) -> bool {
+        ident.ctxt.outer() == self.eval_context.unresolved_mark()
+    }
+
+    /// Records `arg` in [ModuleReferencesVisitor::worker_urls] when it's a
+    /// `new URL("...", import.meta.url)` expression.
+    fn add_worker_url(&mut self, arg: Option<&ExprOrSpread>) {
+        let Some(ExprOrSpread {
+            spread: None,
+            expr: box Expr::New(url),
+        }) = arg
+        else {
+            return;
+        };
+        let (box Expr::Ident(callee), Some(args)) = (&url.callee, &url.args) else {
+            return;
+        };
+        if callee.sym != "URL" || !self.is_unresolved(callee) {
+            return;
+        }
+        if let [ExprOrSpread {
+            spread: None,
+            expr: box Expr::Lit(Lit::Str(_)),
+        }, ExprOrSpread {
+            spread: None,
+            expr:
+                box Expr::Member(MemberExpr {
+                    obj:
+                        box Expr::MetaProp(MetaPropExpr {
+                            kind: MetaPropKind::ImportMeta,
+                            ..
+                        }),
+                    prop: MemberProp::Ident(prop),
+                    ..
+                }),
+        }] = &args[..]
+        {
+            if prop.sym == "url" {
+                self.worker_urls.insert(url.span);
+            }
         }
     }
 }
@@ -3174,11 +3337,39 @@ impl VisitAstPath for ModuleReferencesVisitor<'_> {
         decl.visit_children_with_ast_path(self, ast_path);
     }
 
+    fn visit_new_expr<'ast: 'r, 'r>(
+        &mut self,
+        new: &'ast NewExpr,
+        ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
+    ) {
+        if let box Expr::Ident(ident) = &new.callee {
+            if (ident.sym == "Worker" || ident.sym == "SharedWorker") && self.is_unresolved(ident) {
+                self.add_worker_url(new.args.iter().flatten().next());
+            }
+        }
+        new.visit_children_with_ast_path(self, ast_path);
+    }
+
     fn visit_call_expr<'ast: 'r, 'r>(
         &mut self,
         call: &'ast CallExpr,
         ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
     ) {
+        // `audioContext.audioWorklet.addModule(new URL(...))`
+        if let Callee::Expr(box Expr::Member(MemberExpr {
+            obj:
+                box Expr::Member(MemberExpr {
+                    prop: MemberProp::Ident(worklet),
+                    ..
+                }),
+            prop: MemberProp::Ident(method),
+            ..
+        })) = &call.callee
+        {
+            if method.sym == "addModule" && WORKLET_PROPERTIES.contains(&&*worklet.sym) {
+                self.add_worker_url(call.args.first());
+            }
+        }
         if let Callee::Expr(expr) = &call.callee {
             if let StaticExpr::FreeVar(var) = self.old_analyser.evaluate_expr(expr) {
                 match &var[..] {
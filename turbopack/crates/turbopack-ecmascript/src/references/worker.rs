@@ -1,7 +1,7 @@
 use anyhow::{bail, Result};
 use swc_core::{
     common::util::take::Take,
-    ecma::ast::{Expr, ExprOrSpread, Lit, NewExpr},
+    ecma::ast::{CallExpr, Expr, ExprOrSpread, Lit, NewExpr},
     quote_expr,
 };
 use turbo_rcstr::RcStr;
@@ -22,11 +22,30 @@ use crate::{
     worker_chunk::module::WorkerLoaderModule,
 };
 
+/// The properties of the worklets modules can be added to with `addModule()`,
+/// e.g. `audioContext.audioWorklet`.
+pub(crate) const WORKLET_PROPERTIES: &[&str] = &[
+    "audioWorklet",
+    "paintWorklet",
+    "layoutWorklet",
+    "animationWorklet",
+];
+
+/// The expression creating a worker of `worker_type`, used in messages.
+pub(crate) fn worker_call_name(worker_type: &WorkerReferenceSubType) -> &'static str {
+    match worker_type {
+        WorkerReferenceSubType::SharedWorker => "new SharedWorker",
+        WorkerReferenceSubType::Worklet => "addModule",
+        _ => "new Worker",
+    }
+}
+
 #[turbo_tasks::value]
 #[derive(Hash, Debug)]
 pub struct WorkerAssetReference {
     pub origin: ResolvedVc<Box<dyn ResolveOrigin>>,
     pub request: ResolvedVc<Request>,
+    pub worker_type: WorkerReferenceSubType,
     pub path: ResolvedVc<AstPath>,
     pub issue_source: ResolvedVc<IssueSource>,
     pub in_try: bool,
@@ -38,6 +57,7 @@ impl WorkerAssetReference {
     pub fn new(
         origin: ResolvedVc<Box<dyn ResolveOrigin>>,
         request: ResolvedVc<Request>,
+        worker_type: Value<WorkerReferenceSubType>,
         path: ResolvedVc<AstPath>,
         issue_source: ResolvedVc<IssueSource>,
         in_try: bool,
@@ -45,6 +65,7 @@ impl WorkerAssetReference {
         Self::cell(WorkerAssetReference {
             origin,
             request,
+            worker_type: worker_type.into_value(),
             path,
             issue_source,
             in_try,
@@ -59,8 +80,7 @@ impl WorkerAssetReference {
         let module = url_resolve(
             *self.origin,
             *self.request,
-            // TODO support more worker types
-            Value::new(ReferenceType::Worker(WorkerReferenceSubType::WebWorker)),
+            Value::new(ReferenceType::Worker(self.worker_type.clone())),
             Some(*self.issue_source),
             self.in_try,
         );
@@ -82,7 +102,10 @@ impl WorkerAssetReference {
             return Ok(None);
         };
 
-        Ok(Some(WorkerLoaderModule::new(*chunkable)))
+        Ok(Some(WorkerLoaderModule::new(
+            *chunkable,
+            Value::new(self.worker_type.clone()),
+        )))
     }
 }
 
@@ -106,7 +129,12 @@ impl ValueToString for WorkerAssetReference {
     #[turbo_tasks::function]
     async fn to_string(&self) -> Result<Vc<RcStr>> {
         Ok(Vc::cell(
-            format!("new Worker {}", self.request.to_string().await?,).into(),
+            format!(
+                "{} {}",
+                worker_call_name(&self.worker_type),
+                self.request.to_string().await?,
+            )
+            .into(),
         ))
     }
 }
@@ -131,41 +159,51 @@ impl CodeGenerateable for WorkerAssetReference {
 
         let path = &self.path.await?;
 
-        let visitor = create_visitor!(path, visit_mut_expr(expr: &mut Expr) {
-            let message = if let Expr::New(NewExpr { args, ..}) = expr {
-                if let Some(args) = args {
-                    match args.first_mut() {
-                        Some(ExprOrSpread { spread: None, expr }) => {
-                            let item_id = Expr::Lit(Lit::Str(item_id.to_string().into()));
-                            *expr = quote_expr!(
-                                "__turbopack_require__($item_id)",
-                                item_id: Expr = item_id
-                            );
-
-                            if let Some(opts) = args.get_mut(1) {
-                                if opts.spread.is_none(){
-                                    *opts.expr = *quote_expr!(
-                                        "{...$opts, type: undefined}",
-                                        opts: Expr = (*opts.expr).take()
-                                    );
-                                }
+        let name = worker_call_name(&self.worker_type);
+        let is_worker = !matches!(self.worker_type, WorkerReferenceSubType::Worklet);
+        let is_shared = matches!(self.worker_type, WorkerReferenceSubType::SharedWorker);
 
+        let visitor = create_visitor!(path, visit_mut_expr(expr: &mut Expr) {
+            let args = match expr {
+                Expr::New(NewExpr { args, .. }) => args.as_mut(),
+                Expr::Call(CallExpr { args, .. }) => Some(args),
+                _ => None,
+            };
+            let message = if let Some(args) = args {
+                match args.first_mut() {
+                    Some(ExprOrSpread { spread: None, expr }) => {
+                        let item_id = Expr::Lit(Lit::Str(item_id.to_string().into()));
+                        *expr = quote_expr!(
+                            "__turbopack_require__($item_id)",
+                            item_id: Expr = item_id
+                        );
+
+                        // The chunks are loaded with `importScripts()`, which
+                        // isn't available in module workers. The options of a
+                        // shared worker can also be its name.
+                        if let Some(opts) = args.get_mut(1) {
+                            if is_worker
+                                && opts.spread.is_none()
+                                && !(is_shared && matches!(*opts.expr, Expr::Lit(Lit::Str(_))))
+                            {
+                                *opts.expr = *quote_expr!(
+                                    "{...$opts, type: undefined}",
+                                    opts: Expr = (*opts.expr).take()
+                                );
                             }
-                            return;
-                        }
-                        // These are SWC bugs: https://github.com/swc-project/swc/issues/5394
-                        Some(ExprOrSpread { spread: Some(_), expr: _ }) => {
-                            "spread operator is illegal in new Worker() expressions."
-                        }
-                        _ => {
-                            "new Worker() expressions require at least 1 argument"
                         }
+                        return;
+                    }
+                    // These are SWC bugs: https://github.com/swc-project/swc/issues/5394
+                    Some(ExprOrSpread { spread: Some(_), expr: _ }) => {
+                        format!("spread operator is illegal in {name}() expressions.")
+                    }
+                    _ => {
+                        format!("{name}() expressions require at least 1 argument")
                     }
-                } else {
-                    "new Worker() expressions require at least 1 argument"
                 }
             } else {
-                "visitor must be executed on a NewExpr"
+                format!("{name}() expressions require at least 1 argument")
             };
             *expr = *quote_expr!(
                 "(() => { throw new Error($message); })()",
@@ -28,7 +28,8 @@ use turbo_tasks::FxIndexSet;
 
 use super::{
     util::{
-        collect_top_level_decls, ids_captured_by, ids_used_by, ids_used_by_ignoring_nested, Vars,
+        collect_top_level_decls, ids_captured_by, ids_used_by, ids_used_by_ignoring_nested,
+        may_have_side_effects, Vars,
     },
     Key, TURBOPACK_PART_IMPORT_SOURCE,
 };
@@ -1029,13 +1030,17 @@ impl DepGraph {
 
                             used_ids.write.insert(default_var.to_id());
 
+                            let has_explicit_pure =
+                                comments.has_flag(export.expr.span().lo, "PURE");
+
                             let data = ItemData {
                                 read_vars: used_ids.read,
                                 eventual_read_vars: captured_ids.read,
                                 write_vars: used_ids.write,
                                 eventual_write_vars: captured_ids.write,
                                 var_decls: [default_var.to_id()].into_iter().collect(),
-                                side_effects: true,
+                                pure: has_explicit_pure,
+                                side_effects: !has_explicit_pure,
                                 content: ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(
                                     VarDecl {
                                         span: DUMMY_SP,
@@ -1243,15 +1248,25 @@ impl DepGraph {
                         vars.read.retain(|id| !decl_ids.contains(id));
                         eventual_vars.read.retain(|id| !decl_ids.contains(id));
 
+                        // Initializers like `{ a: /*#__PURE__*/ createIcon() }` are
+                        // side-effect-free as well
                         let side_effects = !has_explicit_pure
-                            && (vars.found_unresolved
-                                || decl.init.as_deref().is_some_and(|e| {
-                                    e.may_have_side_effects(&ExprCtx {
+                            && decl.init.as_deref().is_some_and(|e| {
+                                may_have_side_effects(e, comments, &|e| {
+                                    ids_used_by_ignoring_nested(
+                                        e,
                                         unresolved_ctxt,
-                                        is_unresolved_ref_safe: false,
-                                        in_strict: false,
-                                    })
-                                }));
+                                        top_level_ctxt,
+                                        &top_level_vars,
+                                    )
+                                    .found_unresolved
+                                        || e.may_have_side_effects(&ExprCtx {
+                                            unresolved_ctxt,
+                                            is_unresolved_ref_safe: false,
+                                            in_strict: false,
+                                        })
+                                })
+                            });
 
                         let var_decl = Box::new(VarDecl {
                             decls: vec![decl.clone()],
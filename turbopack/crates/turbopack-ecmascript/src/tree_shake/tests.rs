@@ -3,12 +3,16 @@ use std::{fmt::Write, hash::Hash, path::PathBuf, sync::Arc};
 use anyhow::Error;
 use serde::Deserialize;
 use swc_core::{
-    common::{comments::SingleThreadedComments, util::take::Take, Mark, SourceMap, SyntaxContext},
+    common::{
+        comments::SingleThreadedComments, util::take::Take, FileName, Mark, SourceMap,
+        SyntaxContext,
+    },
     ecma::{
-        ast::{EsVersion, Id, Module},
+        ast::{EsVersion, Expr, Id, Module},
         atoms::JsWord,
         codegen::text_writer::JsWriter,
-        parser::{parse_file_as_module, EsSyntax},
+        parser::{parse_file_as_expr, parse_file_as_module, EsSyntax},
+        utils::{ExprCtx, ExprExt},
         visit::VisitMutWith,
     },
     testing::{self, fixture, NormalizedOutput},
@@ -20,6 +24,7 @@ use super::{
         DepGraph, Dependency, InternedGraph, ItemId, ItemIdGroupKind, Mode, SplitModuleResult,
     },
     merge::Merger,
+    util::may_have_side_effects,
     Analyzer, Key,
 };
 
@@ -28,6 +33,52 @@ fn test_fixture(input: PathBuf) {
     run(input);
 }
 
+#[test]
+fn test_pure_annotations() {
+    testing::run_test(false, |cm, _handler| {
+        for (code, side_effects) in [
+            ("/*#__PURE__*/ createIcon('a')", false),
+            ("/*#__PURE__*/ createIcon(sideEffect())", true),
+            (
+                "({ a: /*#__PURE__*/ createIcon('a'), b: [/*#__PURE__*/ new Icon()] })",
+                false,
+            ),
+            ("({ a: createIcon('a') })", true),
+            ("({ [key]: /*#__PURE__*/ createIcon('a') })", true),
+            (
+                "/*#__PURE__*/ class extends Base { static a = sideEffect() }",
+                false,
+            ),
+            ("class extends Base { static a = sideEffect() }", true),
+        ] {
+            let fm = cm.new_source_file(FileName::Anon.into(), code.into());
+            let comments = SingleThreadedComments::default();
+            let expr = parse_file_as_expr(
+                &fm,
+                Default::default(),
+                EsVersion::latest(),
+                Some(&comments),
+                &mut vec![],
+            )
+            .unwrap();
+            let leaf = |expr: &Expr| {
+                expr.may_have_side_effects(&ExprCtx {
+                    unresolved_ctxt: SyntaxContext::empty(),
+                    is_unresolved_ref_safe: false,
+                    in_strict: false,
+                })
+            };
+            assert_eq!(
+                may_have_side_effects(&expr, &comments, &leaf),
+                side_effects,
+                "{code}"
+            );
+        }
+        Ok(())
+    })
+    .unwrap();
+}
+
 #[derive(Deserialize)]
 struct TestConfig {
     /// Enabled exports. This is `Vec<Vec<String>>` because we test multiple
@@ -1,6 +1,6 @@
 use rustc_hash::FxHashSet;
 use swc_core::{
-    common::SyntaxContext,
+    common::{comments::Comments, SyntaxContext},
     ecma::{
         ast::{
             ArrowExpr, AssignPatProp, AssignTarget, ClassDecl, ClassExpr, Constructor, DefaultDecl,
@@ -286,6 +286,54 @@ where
     v.vars
 }
 
+/// Returns whether evaluating `expr` may have side effects, honoring the
+/// `/*#__PURE__*/` annotations of calls, `new` expressions and class
+/// expressions, also when they are nested in object or array literals. The
+/// arguments of annotated calls are still evaluated, so they are checked as
+/// well. Other expressions are checked with `leaf`.
+pub(crate) fn may_have_side_effects(
+    expr: &Expr,
+    comments: &dyn Comments,
+    leaf: &dyn Fn(&Expr) -> bool,
+) -> bool {
+    let check = |expr: &Expr| may_have_side_effects(expr, comments, leaf);
+    let check_prop_name = |name: &PropName| match name {
+        PropName::Computed(computed) => check(&computed.expr),
+        _ => false,
+    };
+    let check_args = |args: &[ExprOrSpread]| {
+        args.iter()
+            .any(|arg| arg.spread.is_some() || check(&arg.expr))
+    };
+
+    match expr {
+        Expr::Call(call) if comments.has_flag(call.span.lo, "PURE") => check_args(&call.args),
+        Expr::New(new) if comments.has_flag(new.span.lo, "PURE") => {
+            new.args.as_deref().is_some_and(check_args)
+        }
+        Expr::Class(class) if comments.has_flag(class.class.span.lo, "PURE") => false,
+        Expr::Object(object) => object.props.iter().any(|prop| match prop {
+            PropOrSpread::Spread(_) => true,
+            PropOrSpread::Prop(prop) => match &**prop {
+                Prop::Shorthand(ident) => leaf(&Expr::Ident(ident.clone())),
+                Prop::KeyValue(prop) => check_prop_name(&prop.key) || check(&prop.value),
+                Prop::Getter(prop) => check_prop_name(&prop.key),
+                Prop::Setter(prop) => check_prop_name(&prop.key),
+                Prop::Method(prop) => check_prop_name(&prop.key),
+                Prop::Assign(_) => true,
+            },
+        }),
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .flatten()
+            .any(|elem| elem.spread.is_some() || check(&elem.expr)),
+        Expr::Paren(paren) => check(&paren.expr),
+        Expr::Seq(seq) => seq.exprs.iter().any(|expr| check(expr)),
+        _ => leaf(expr),
+    }
+}
+
 pub struct TopLevelBindingCollector {
     bindings: FxHashSet<Id>,
     is_pat_decl: bool,
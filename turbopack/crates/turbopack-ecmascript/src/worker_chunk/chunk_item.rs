@@ -10,6 +10,7 @@ use turbopack_core::{
     ident::AssetIdent,
     module::Module,
     output::OutputAssets,
+    reference_type::WorkerReferenceSubType,
 };
 
 use super::module::WorkerLoaderModule;
@@ -84,11 +85,17 @@ impl EcmascriptChunkItem for WorkerLoaderChunkItem {
             .map(|chunk_data| EcmascriptChunkData::new(chunk_data))
             .collect();
 
+        let worker_type = match self.await?.module.await?.worker_type {
+            WorkerReferenceSubType::SharedWorker => "shared-worker",
+            WorkerReferenceSubType::Worklet => "worklet",
+            _ => "worker",
+        };
         let code = formatdoc! {
             r#"
-                __turbopack_export_value__(__turbopack_worker_blob_url__({chunks:#}));
+                __turbopack_export_value__(__turbopack_worker_blob_url__({chunks:#}, {worker_type}));
             "#,
             chunks = StringifyJs(&chunks_data),
+            worker_type = StringifyJs(worker_type),
         };
 
         Ok(EcmascriptChunkItemContent {
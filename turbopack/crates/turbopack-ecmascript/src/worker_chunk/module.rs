@@ -1,6 +1,6 @@
 use anyhow::Result;
 use turbo_rcstr::RcStr;
-use turbo_tasks::{ResolvedVc, ValueToString, Vc};
+use turbo_tasks::{ResolvedVc, Value, ValueToString, Vc};
 use turbopack_core::{
     asset::{Asset, AssetContent},
     chunk::{
@@ -10,33 +10,52 @@ use turbopack_core::{
     ident::AssetIdent,
     module::Module,
     reference::{ModuleReference, ModuleReferences},
+    reference_type::WorkerReferenceSubType,
     resolve::ModuleResolveResult,
 };
 
 use super::chunk_item::WorkerLoaderChunkItem;
 
 #[turbo_tasks::function]
-fn modifier() -> Vc<RcStr> {
-    Vc::cell("worker loader".into())
+fn modifier(worker_type: Value<WorkerReferenceSubType>) -> Vc<RcStr> {
+    Vc::cell(
+        match worker_type.into_value() {
+            WorkerReferenceSubType::SharedWorker => "shared worker loader",
+            WorkerReferenceSubType::Worklet => "worklet loader",
+            _ => "worker loader",
+        }
+        .into(),
+    )
 }
 
 /// The WorkerLoaderModule is a module that creates a separate root chunk group for the given module
-/// and exports a URL to pass to the worker constructor.
+/// and exports a URL to pass to the worker constructor, or to the `addModule()` method of a
+/// worklet.
 #[turbo_tasks::value]
 pub struct WorkerLoaderModule {
     pub inner: ResolvedVc<Box<dyn ChunkableModule>>,
+    pub worker_type: WorkerReferenceSubType,
 }
 
 #[turbo_tasks::value_impl]
 impl WorkerLoaderModule {
     #[turbo_tasks::function]
-    pub fn new(module: ResolvedVc<Box<dyn ChunkableModule>>) -> Vc<Self> {
-        Self::cell(WorkerLoaderModule { inner: module })
+    pub fn new(
+        module: ResolvedVc<Box<dyn ChunkableModule>>,
+        worker_type: Value<WorkerReferenceSubType>,
+    ) -> Vc<Self> {
+        Self::cell(WorkerLoaderModule {
+            inner: module,
+            worker_type: worker_type.into_value(),
+        })
     }
 
     #[turbo_tasks::function]
-    pub fn asset_ident_for(module: Vc<Box<dyn ChunkableModule>>) -> Vc<AssetIdent> {
-        module.ident().with_modifier(modifier())
+    pub fn asset_ident_for(
+        module: Vc<Box<dyn ChunkableModule>>,
+        worker_type: Value<WorkerReferenceSubType>,
+    ) -> Vc<AssetIdent> {
+        module.ident().with_modifier(modifier(worker_type))
     }
 }
 
@@ -44,7 +63,7 @@ impl WorkerLoaderModule {
 impl Module for WorkerLoaderModule {
     #[turbo_tasks::function]
     fn ident(&self) -> Vc<AssetIdent> {
-        Self::asset_ident_for(*self.inner)
+        Self::asset_ident_for(*self.inner, Value::new(self.worker_type.clone()))
     }
 
     #[turbo_tasks::function]
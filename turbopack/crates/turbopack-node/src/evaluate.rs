@@ -1,6 +1,12 @@
-use std::{borrow::Cow, ops::ControlFlow, thread::available_parallelism, time::Duration};
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::ControlFlow,
+    thread::available_parallelism,
+    time::{Duration, Instant},
+};
 
-use anyhow::{anyhow, bail, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use async_stream::try_stream as generator;
 use async_trait::async_trait;
 use futures::{
@@ -11,9 +17,12 @@ use futures_retry::{FutureRetry, RetryPolicy};
 use parking_lot::Mutex;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use tokio::select;
+use turbo_rcstr::RcStr;
 use turbo_tasks::{
-    apply_effects, duration_span, fxindexmap, mark_finished, prevent_gc, util::SharedError,
-    Completion, FxIndexMap, RawVc, ResolvedVc, TaskInput, TryJoinIterExt, Value, Vc,
+    apply_effects, duration_span, fxindexmap, mark_finished, prevent_gc, trace::TraceRawVcs,
+    util::SharedError, Completion, FxIndexMap, NonLocalValue, RawVc, ResolvedVc, TaskInput,
+    TryJoinIterExt, Value, Vc,
 };
 use turbo_tasks_bytes::{Bytes, Stream};
 use turbo_tasks_env::{EnvMap, ProcessEnv};
@@ -26,7 +35,7 @@ use turbopack_core::{
     error::PrettyPrintError,
     file_source::FileSource,
     ident::AssetIdent,
-    issue::{Issue, IssueExt, IssueStage, OptionStyledString, StyledString},
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
     module::Module,
     output::{OutputAsset, OutputAssets},
     reference_type::{InnerAssets, ReferenceType},
@@ -36,6 +45,9 @@ use turbopack_core::{
 use crate::{
     embed_js::embed_file_path,
     emit, emit_package_json, internal_assets_for_source_mapping,
+    loader_permissions::{
+        node_version, LoaderPermissions, LoaderPermissionsUnsupportedIssue, NodePermissions,
+    },
     pool::{FormattingMode, NodeJsOperation, NodeJsPool},
     source_map::StructuredError,
     AssetsForSourceMapping,
@@ -198,7 +210,8 @@ pub enum EnvVarTracking {
 
 #[turbo_tasks::function]
 /// Pass the file you cared as `runtime_entries` to invalidate and reload the
-/// evaluated result automatically.
+/// evaluated result automatically. The processes of the pool are restricted to
+/// `permissions` when they are set.
 pub async fn get_evaluate_pool(
     module_asset: Vc<Box<dyn Module>>,
     cwd: Vc<FileSystemPath>,
@@ -209,6 +222,7 @@ pub async fn get_evaluate_pool(
     additional_invalidation: Vc<Completion>,
     debug: bool,
     env_var_tracking: EnvVarTracking,
+    permissions: Option<Vc<LoaderPermissions>>,
 ) -> Result<Vc<NodeJsPool>> {
     let EmittedEvaluatePoolAssets {
         bootstrap,
@@ -245,7 +259,7 @@ pub async fn get_evaluate_pool(
             env.read_all().untracked().await?
         }
     };
-    let pool = NodeJsPool::new(
+    let mut pool = NodeJsPool::new(
         cwd,
         entrypoint,
         env.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
@@ -255,6 +269,23 @@ pub async fn get_evaluate_pool(
         available_parallelism().map_or(1, |v| v.get()),
         debug,
     );
+    if let Some(permissions) = permissions {
+        let node_version = node_version().await?;
+        if !node_version.supports_permission_model() {
+            LoaderPermissionsUnsupportedIssue {
+                project_dir: pool.project_dir,
+                node_version: node_version.to_string().into(),
+            }
+            .resolved_cell()
+            .emit();
+            bail!("Node.js {node_version} doesn't support restricting the permissions of loaders");
+        }
+        // The process needs to read its entrypoint and the chunks it loads,
+        // besides the project.
+        pool = pool.with_permissions(
+            NodePermissions::new(permissions, [*pool.project_dir, *output_root]).await?,
+        );
+    }
     additional_invalidation.await?;
     Ok(pool.cell())
 }
@@ -296,6 +327,116 @@ impl futures_retry::ErrorHandler<anyhow::Error> for PoolErrorHandler {
     }
 }
 
+/// Total number of attempts of an evaluation whose worker crashes.
+const MAX_CRASH_ATTEMPTS: usize = 3;
+
+/// What to do after the `attempt`th attempt of an evaluation crashed its
+/// worker.
+#[derive(Debug, PartialEq, Eq)]
+enum CrashAction {
+    /// Retry the evaluation on a freshly booted up worker.
+    Retry,
+    /// Give up on the evaluation, and report that it crashed its worker.
+    GiveUp,
+}
+
+impl CrashAction {
+    fn after_attempt(attempt: usize) -> Self {
+        if attempt < MAX_CRASH_ATTEMPTS {
+            CrashAction::Retry
+        } else {
+            CrashAction::GiveUp
+        }
+    }
+}
+
+/// Whether `error` of an evaluation was caused by its worker crashing, and the
+/// evaluation can be retried, which it can't once it streamed values.
+fn is_retryable_crash(error: &anyhow::Error, yielded: bool) -> bool {
+    !yielded && error.downcast_ref::<WorkerCrashed>().is_some()
+}
+
+/// The context of errors caused by the worker running an evaluation exiting or
+/// no longer responding, as opposed to errors of the evaluation itself.
+#[derive(Debug)]
+struct WorkerCrashed;
+
+impl Display for WorkerCrashed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the Node.js worker exited or stopped responding")
+    }
+}
+
+/// The thresholds after which an evaluation is reported as slow or aborted.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    TraceRawVcs,
+    NonLocalValue,
+    TaskInput,
+)]
+pub struct EvaluateTimeout {
+    /// Report the evaluation as slow once it takes longer than this.
+    pub slow_after_ms: Option<u64>,
+    /// Abort the evaluation and kill its worker once it takes longer than this.
+    pub timeout_ms: Option<u64>,
+}
+
+impl EvaluateTimeout {
+    /// The more lenient of both thresholds, `None` being no limit.
+    fn most_lenient(self, other: Self) -> Self {
+        fn max(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+            Some(a?.max(b?))
+        }
+        EvaluateTimeout {
+            slow_after_ms: max(self.slow_after_ms, other.slow_after_ms),
+            timeout_ms: max(self.timeout_ms, other.timeout_ms),
+        }
+    }
+}
+
+/// The thresholds of the operations dispatched to the Node.js workers.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug, Default)]
+pub struct EvaluateTimeouts {
+    /// The thresholds of operations without an override.
+    pub default: EvaluateTimeout,
+    /// Overrides by the name of the operation, e.g. a webpack loader or `postcss`.
+    pub operations: FxIndexMap<RcStr, EvaluateTimeout>,
+}
+
+#[turbo_tasks::value_impl]
+impl EvaluateTimeouts {
+    /// No thresholds, evaluations can take as long as they need.
+    #[turbo_tasks::function]
+    pub fn none() -> Vc<Self> {
+        EvaluateTimeouts::default().cell()
+    }
+}
+
+impl EvaluateTimeouts {
+    /// The thresholds of an operation made of the steps `names`, e.g. a chain of loaders. When
+    /// several steps have overrides, the most lenient thresholds apply.
+    pub fn for_operation<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> EvaluateTimeout {
+        names
+            .into_iter()
+            .filter_map(|name| {
+                // Loaders can be referenced with options in a query string
+                let name = name.split_once('?').map_or(name, |(name, _)| name);
+                self.operations.get(name).copied()
+            })
+            .reduce(EvaluateTimeout::most_lenient)
+            .unwrap_or(self.default)
+    }
+}
+
 #[async_trait]
 pub trait EvaluateContext {
     type InfoMessage: DeserializeOwned;
@@ -324,6 +465,23 @@ pub trait EvaluateContext {
         pool: &NodeJsPool,
     ) -> Result<Self::ResponseMessage>;
     async fn finish(&self, _state: Self::State, _pool: &NodeJsPool) -> Result<()>;
+    /// The thresholds after which the evaluation is reported through
+    /// [EvaluateContext::emit_long_task]. No thresholds by default.
+    fn timeout(&self) -> EvaluateTimeout {
+        EvaluateTimeout::default()
+    }
+    /// Reports that the evaluation is still running after `elapsed`, or that it
+    /// was aborted when `timed_out` is set.
+    async fn emit_long_task(&self, _elapsed: Duration, _timed_out: bool) -> Result<()> {
+        Ok(())
+    }
+    /// Reports that the workers running the evaluation crashed `attempts` times
+    /// in a row, the last time with `error`. Fails the evaluation with `error`
+    /// by default; contexts which emit an issue instead let the evaluation end
+    /// without a value.
+    async fn emit_worker_crash(&self, error: anyhow::Error, _attempts: usize) -> Result<()> {
+        Err(error)
+    }
 }
 
 pub fn custom_evaluate(evaluate_context: impl EvaluateContext) -> Vc<JavaScriptEvaluation> {
@@ -429,51 +587,99 @@ pub async fn compute(
         // worker. So we retry picking workers from the pools until we succeed
         // sending the job.
 
-        let (mut operation, _) = FutureRetry::new(
-            || async {
-                let mut operation = pool.operation().await?;
-                operation
-                    .send(EvalJavaScriptOutgoingMessage::Evaluate {
-                        args: args.iter().map(|v| &**v).collect(),
-                    })
-                    .await?;
-                Ok(operation)
-            },
-            PoolErrorHandler,
-        )
-        .await
-        .map_err(|(e, _)| e)?;
-
-        // The evaluation sent an initial intermediate value without completing. We'll
-        // need to spawn a new thread to continually pull data out of the process,
-        // and ferry that along.
+        // A worker crashing while evaluating, e.g. because a loader runs out of
+        // memory, can be caused by the state of that worker. So the evaluation is
+        // retried on a fresh worker, unless it already streamed values.
+        let mut attempt = 1;
+        let mut yielded = false;
         loop {
-            let output = pull_operation(&mut operation, &pool, &evaluate_context, &mut state).await?;
+            let (mut operation, _) = FutureRetry::new(
+                || async {
+                    let mut operation = if attempt > 1 {
+                        pool.fresh_operation().await?
+                    } else {
+                        pool.operation().await?
+                    };
+                    operation
+                        .send(EvalJavaScriptOutgoingMessage::Evaluate {
+                            args: args.iter().map(|v| &**v).collect(),
+                        })
+                        .await?;
+                    Ok(operation)
+                },
+                PoolErrorHandler,
+            )
+            .await
+            .map_err(|(e, _)| e)?;
+
+            let started = Instant::now();
+            let mut slow_reported = false;
+            let mut crash = None;
+
+            // The evaluation sent an initial intermediate value without completing. We'll
+            // need to spawn a new thread to continually pull data out of the process,
+            // and ferry that along.
+            loop {
+                let output = match pull_operation(
+                    &mut operation,
+                    &pool,
+                    &evaluate_context,
+                    &mut state,
+                    started,
+                    &mut slow_reported,
+                )
+                .await
+                {
+                    Ok(output) => output,
+                    Err(e) if is_retryable_crash(&e, yielded) => {
+                        crash = Some(e);
+                        break;
+                    }
+                    Err(e) => Err(e)?,
+                };
 
-            match output {
-                LoopResult::Continue(data) => {
-                    yield data.into();
+                match output {
+                    LoopResult::Continue(data) => {
+                        yielded = true;
+                        yield data.into();
+                    }
+                    LoopResult::Break(Ok(Some(data))) => {
+                        yield data.into();
+                        break;
+                    }
+                    LoopResult::Break(Err(e)) => {
+                        let error = print_error(e, &pool, &evaluate_context).await?;
+                        Err(anyhow!("Node.js evaluation failed: {}", error))?;
+                        break;
+                    }
+                    LoopResult::Break(Ok(None)) => {
+                        break;
+                    }
                 }
-                LoopResult::Break(Ok(Some(data))) => {
-                    yield data.into();
-                    break;
+            }
+
+            let Some(error) = crash else {
+                evaluate_context.finish(state, &pool).await?;
+
+                if kill {
+                    operation.wait_or_kill().await?;
                 }
-                LoopResult::Break(Err(e)) => {
-                    let error = print_error(e, &pool, &evaluate_context).await?;
-                    Err(anyhow!("Node.js evaluation failed: {}", error))?;
-                    break;
+                break;
+            };
+
+            // The crashed worker isn't returned to the pool.
+            drop(operation);
+            match CrashAction::after_attempt(attempt) {
+                CrashAction::Retry => {
+                    attempt += 1;
+                    state = Default::default();
                 }
-                LoopResult::Break(Ok(None)) => {
+                CrashAction::GiveUp => {
+                    evaluate_context.emit_worker_crash(error, attempt).await?;
                     break;
                 }
             }
         }
-
-        evaluate_context.finish(state, &pool).await?;
-
-        if kill {
-            operation.wait_or_kill().await?;
-        }
     };
 
     let mut sender = (sender.get)();
@@ -497,11 +703,13 @@ async fn pull_operation<T: EvaluateContext>(
     pool: &NodeJsPool,
     evaluate_context: &T,
     state: &mut T::State,
+    started: Instant,
+    slow_reported: &mut bool,
 ) -> Result<LoopResult> {
     let guard = duration_span!("Node.js evaluation");
 
     let output = loop {
-        match operation.recv().await? {
+        match recv_with_timeout(operation, evaluate_context, started, slow_reported).await? {
             EvalJavaScriptIncomingMessage::Error(error) => {
                 evaluate_context.emit_error(error, pool).await?;
                 // Do not reuse the process in case of error
@@ -527,7 +735,8 @@ async fn pull_operation<T: EvaluateContext>(
                                 error: None,
                                 data: Some(serde_json::to_value(response)?),
                             })
-                            .await?;
+                            .await
+                            .context(WorkerCrashed)?;
                     }
                     Err(e) => {
                         operation
@@ -536,7 +745,8 @@ async fn pull_operation<T: EvaluateContext>(
                                 error: Some(PrettyPrintError(&e).to_string()),
                                 data: None,
                             })
-                            .await?;
+                            .await
+                            .context(WorkerCrashed)?;
                     }
                 }
             }
@@ -547,6 +757,60 @@ async fn pull_operation<T: EvaluateContext>(
     Ok(output)
 }
 
+/// Receives the next message of the evaluation started at `started`. Reports the
+/// evaluation once it exceeds the thresholds of [EvaluateContext::timeout] and
+/// aborts it when it times out. The worker isn't reused after a timeout.
+async fn recv_with_timeout<T: EvaluateContext>(
+    operation: &mut NodeJsOperation,
+    evaluate_context: &T,
+    started: Instant,
+    slow_reported: &mut bool,
+) -> Result<EvalJavaScriptIncomingMessage> {
+    let EvaluateTimeout {
+        slow_after_ms,
+        timeout_ms,
+    } = evaluate_context.timeout();
+    let deadline = |ms: Option<u64>| ms.map(|ms| started + Duration::from_millis(ms));
+    let mut slow_deadline = deadline(slow_after_ms).filter(|_| !*slow_reported);
+    let timeout_deadline = deadline(timeout_ms);
+    if slow_deadline.is_none() && timeout_deadline.is_none() {
+        return operation.recv().await.context(WorkerCrashed);
+    }
+
+    async fn sleep_until(deadline: Option<Instant>) {
+        match deadline {
+            Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+            None => std::future::pending().await,
+        }
+    }
+
+    {
+        // The message is only partially read when the future is dropped, so it
+        // has to be kept when reporting a slow evaluation.
+        let recv = operation.recv();
+        pin_mut!(recv);
+        loop {
+            select! {
+                message = &mut recv => return message.context(WorkerCrashed),
+                _ = sleep_until(slow_deadline) => {
+                    *slow_reported = true;
+                    slow_deadline = None;
+                    evaluate_context.emit_long_task(started.elapsed(), false).await?;
+                }
+                _ = sleep_until(timeout_deadline) => break,
+            }
+        }
+    }
+
+    operation.disallow_reuse();
+    let elapsed = started.elapsed();
+    evaluate_context.emit_long_task(elapsed, true).await?;
+    bail!(
+        "Node.js evaluation timed out after {}ms",
+        elapsed.as_millis()
+    )
+}
+
 #[turbo_tasks::function]
 async fn basic_compute(
     evaluate_context: BasicEvaluateContext,
@@ -591,6 +855,7 @@ impl EvaluateContext for BasicEvaluateContext {
             *self.additional_invalidation,
             self.debug,
             EnvVarTracking::WholeEnvTracked,
+            None,
         )
     }
 
@@ -701,3 +966,144 @@ impl Issue for EvaluationIssue {
         )))
     }
 }
+
+/// An evaluation which took longer than the thresholds configured for it.
+#[turbo_tasks::value(shared)]
+pub struct EvaluationTimeoutIssue {
+    pub context_ident: ResolvedVc<AssetIdent>,
+    /// The name of the operation, e.g. the loaders applied.
+    pub operation: RcStr,
+    pub elapsed_ms: u64,
+    /// Whether the evaluation was aborted, otherwise it's still running.
+    pub timed_out: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for EvaluationTimeoutIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        if self.timed_out {
+            IssueSeverity::Error.cell()
+        } else {
+            IssueSeverity::Warning.cell()
+        }
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Code(self.operation.clone()),
+            StyledString::Text(if self.timed_out {
+                " timed out".into()
+            } else {
+                " is taking long".into()
+            }),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Transform.into()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.context_ident.path()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        let text = if self.timed_out {
+            format!(
+                "The operation was aborted after {}ms and its Node.js worker was stopped.",
+                self.elapsed_ms
+            )
+        } else {
+            format!(
+                "The operation is still running after {}ms. It might be hanging, e.g. waiting for \
+                 a promise which never settles.",
+                self.elapsed_ms
+            )
+        };
+        Vc::cell(Some(StyledString::Text(text.into()).resolved_cell()))
+    }
+}
+
+/// An evaluation which was given up on because its workers crashed repeatedly.
+#[turbo_tasks::value(shared)]
+pub struct EvaluationCrashIssue {
+    pub context_ident: ResolvedVc<AssetIdent>,
+    /// The name of the operation, e.g. the loaders applied.
+    pub operation: RcStr,
+    pub attempts: usize,
+    pub error: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for EvaluationCrashIssue {
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Line(vec![
+            StyledString::Code(self.operation.clone()),
+            StyledString::Text(" crashed its Node.js worker".into()),
+        ])
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Transform.into()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.context_ident.path()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(
+                format!(
+                    "The operation crashed its Node.js worker {} times in a row, even when \
+                     retried on newly started workers, so the module was skipped. This is usually \
+                     caused by the operation running out of memory or by a native module it \
+                     uses.\n\n{}",
+                    self.attempts, self.error
+                )
+                .into(),
+            )
+            .resolved_cell(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn test_retryable_crash() {
+        let crash = anyhow!("failed to receive message").context(WorkerCrashed);
+        assert!(is_retryable_crash(&crash, false));
+        // Values which were already streamed can't be taken back
+        assert!(!is_retryable_crash(&crash, true));
+        // Errors of the evaluation itself are reported instead
+        let error = anyhow!("Node.js evaluation timed out after 1000ms");
+        assert!(!is_retryable_crash(&error, false));
+    }
+
+    #[test]
+    fn test_crash_action() {
+        let actions: Vec<_> = (1..=MAX_CRASH_ATTEMPTS)
+            .map(CrashAction::after_attempt)
+            .collect();
+        assert_eq!(
+            actions,
+            [CrashAction::Retry, CrashAction::Retry, CrashAction::GiveUp]
+        );
+    }
+}
@@ -1,29 +1,75 @@
+use anyhow::Result;
 use turbo_tasks::{ResolvedVc, Vc};
 use turbo_tasks_env::ProcessEnv;
 use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::chunk::ChunkingContext;
 
+use crate::{
+    evaluate::EvaluateTimeouts,
+    loader_permissions::{LoaderPermissions, OptionLoaderPermissions},
+};
+
 #[turbo_tasks::value]
 pub struct ExecutionContext {
     pub project_path: ResolvedVc<FileSystemPath>,
     pub chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
     pub env: ResolvedVc<Box<dyn ProcessEnv>>,
+    pub timeouts: ResolvedVc<EvaluateTimeouts>,
+    pub loader_permissions: Option<ResolvedVc<LoaderPermissions>>,
 }
 
 #[turbo_tasks::value_impl]
 impl ExecutionContext {
     #[turbo_tasks::function]
-    pub fn new(
+    pub async fn new(
         project_path: ResolvedVc<FileSystemPath>,
         chunking_context: ResolvedVc<Box<dyn ChunkingContext>>,
         env: ResolvedVc<Box<dyn ProcessEnv>>,
-    ) -> Vc<Self> {
-        ExecutionContext {
+    ) -> Result<Vc<Self>> {
+        Ok(ExecutionContext {
             project_path,
             chunking_context,
             env,
+            timeouts: EvaluateTimeouts::none().to_resolved().await?,
+            loader_permissions: None,
+        }
+        .cell())
+    }
+
+    /// Reports and aborts the operations of this context which take longer than
+    /// `timeouts`.
+    #[turbo_tasks::function]
+    pub async fn with_timeouts(
+        self: Vc<Self>,
+        timeouts: ResolvedVc<EvaluateTimeouts>,
+    ) -> Result<Vc<Self>> {
+        let this = self.await?;
+        Ok(ExecutionContext {
+            project_path: this.project_path,
+            chunking_context: this.chunking_context,
+            env: this.env,
+            timeouts,
+            loader_permissions: this.loader_permissions,
+        }
+        .cell())
+    }
+
+    /// Restricts the permissions of the processes running webpack loaders which
+    /// aren't trusted by `loader_permissions`.
+    #[turbo_tasks::function]
+    pub async fn with_loader_permissions(
+        self: Vc<Self>,
+        loader_permissions: Vc<OptionLoaderPermissions>,
+    ) -> Result<Vc<Self>> {
+        let this = self.await?;
+        Ok(ExecutionContext {
+            project_path: this.project_path,
+            chunking_context: this.chunking_context,
+            env: this.env,
+            timeouts: this.timeouts,
+            loader_permissions: *loader_permissions.await?,
         }
-        .cell()
+        .cell())
     }
 
     #[turbo_tasks::function]
@@ -40,4 +86,9 @@ impl ExecutionContext {
     pub fn env(&self) -> Vc<Box<dyn ProcessEnv>> {
         *self.env
     }
+
+    #[turbo_tasks::function]
+    pub fn timeouts(&self) -> Vc<EvaluateTimeouts> {
+        *self.timeouts
+    }
 }
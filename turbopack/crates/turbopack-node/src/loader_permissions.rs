@@ -0,0 +1,281 @@
+use std::{fmt, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use tokio::{process::Command, sync::OnceCell};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{ResolvedVc, Vc};
+use turbo_tasks_fs::{to_sys_path, FileSystemPath};
+use turbopack_core::issue::{Issue, IssueStage, OptionStyledString, StyledString};
+
+/// Limits the file system access of webpack loaders which aren't trusted,
+/// using the Node.js permission model: they can only read the project and
+/// `allow_read`, only write `allow_write`, and can't spawn processes, workers
+/// or load native addons. This requires Node.js 22.13 or later, see
+/// [NodeVersion::supports_permission_model].
+///
+/// This is not a sandbox. The permission model doesn't cover the network, and
+/// Node.js doesn't treat it as a security boundary against malicious code.
+/// SWC plugins and PostCSS plugins aren't restricted.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug, Default)]
+pub struct LoaderPermissions {
+    /// Loaders which run without restrictions, by their request, e.g.
+    /// `@svgr/webpack`. Packages include the modules in them.
+    pub trusted: Vec<RcStr>,
+    /// Paths untrusted loaders can read in addition to the project.
+    pub allow_read: Vec<ResolvedVc<FileSystemPath>>,
+    /// Paths untrusted loaders can write.
+    pub allow_write: Vec<ResolvedVc<FileSystemPath>>,
+}
+
+impl LoaderPermissions {
+    pub fn is_trusted(&self, loader: &str) -> bool {
+        self.trusted.iter().any(|trusted| {
+            loader
+                .strip_prefix(trusted.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    }
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct OptionLoaderPermissions(Option<ResolvedVc<LoaderPermissions>>);
+
+#[turbo_tasks::value_impl]
+impl OptionLoaderPermissions {
+    #[turbo_tasks::function]
+    pub fn none() -> Vc<Self> {
+        Vc::cell(None)
+    }
+}
+
+/// The permissions of a Node.js process running untrusted loaders.
+#[derive(Clone, Debug)]
+pub(crate) struct NodePermissions {
+    read: Vec<PathBuf>,
+    write: Vec<PathBuf>,
+}
+
+impl NodePermissions {
+    /// The permissions granted by `permissions`, in addition to reading
+    /// `readable`, which the process needs to start.
+    pub(crate) async fn new(
+        permissions: Vc<LoaderPermissions>,
+        readable: impl IntoIterator<Item = Vc<FileSystemPath>>,
+    ) -> Result<Self> {
+        let permissions = permissions.await?;
+        let mut read = vec![];
+        for path in readable
+            .into_iter()
+            .chain(permissions.allow_read.iter().map(|path| **path))
+        {
+            read.push(sys_path(path).await?);
+        }
+        let mut write = vec![];
+        for path in permissions.allow_write.iter() {
+            write.push(sys_path(**path).await?);
+        }
+        Ok(NodePermissions { read, write })
+    }
+
+    /// Adds the permission flags to the `node` command. They need to be added
+    /// before the entrypoint, and are only supported by the Node.js versions
+    /// [NodeVersion::supports_permission_model] accepts.
+    pub(crate) fn apply_args(&self, cmd: &mut Command) {
+        // `--allow-child-process`, `--allow-worker` and `--allow-addons` are
+        // left out, so none of them are allowed.
+        cmd.arg("--permission");
+        for path in &self.read {
+            cmd.arg(format!("--allow-fs-read={}", path.display()));
+        }
+        for path in &self.write {
+            cmd.arg(format!("--allow-fs-write={}", path.display()));
+        }
+    }
+}
+
+async fn sys_path(path: Vc<FileSystemPath>) -> Result<PathBuf> {
+    let Some(sys_path) = to_sys_path(path).await? else {
+        bail!(
+            "Loader permissions can only be granted for paths on disk, but got {}",
+            path.await?
+        );
+    };
+    Ok(sys_path)
+}
+
+/// A version of Node.js, e.g. `22.13.0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct NodeVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+impl NodeVersion {
+    /// Parses the output of `node --version`, e.g. `v22.13.0`.
+    fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().strip_prefix('v')?.splitn(3, '.');
+        let mut next = || parts.next()?.parse().ok();
+        Some(NodeVersion {
+            major: next()?,
+            minor: next()?,
+            patch: next()?,
+        })
+    }
+
+    /// Whether the permission model is available as `--permission`, which
+    /// it is since Node.js 22.13 and 23.5. Node.js 18 doesn't have it, and
+    /// Node.js 20 only has an experimental version behind other flags.
+    pub(crate) fn supports_permission_model(self) -> bool {
+        match self.major {
+            0..=21 => false,
+            22 => self.minor >= 13,
+            23 => self.minor >= 5,
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for NodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+static NODE_VERSION: OnceCell<NodeVersion> = OnceCell::const_new();
+
+/// The version of the `node` binary the pool starts processes with. It's only
+/// read once per process.
+pub(crate) async fn node_version() -> Result<NodeVersion> {
+    NODE_VERSION
+        .get_or_try_init(|| async {
+            let output = Command::new("node")
+                .arg("--version")
+                .output()
+                .await
+                .context("running `node --version` failed")?;
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            NodeVersion::parse(&stdout)
+                .with_context(|| format!("unexpected output of `node --version`: {stdout}"))
+        })
+        .await
+        .copied()
+}
+
+/// Untrusted loaders weren't run, because the Node.js version doesn't
+/// support the permission model.
+#[turbo_tasks::value(shared)]
+pub struct LoaderPermissionsUnsupportedIssue {
+    pub project_dir: ResolvedVc<FileSystemPath>,
+    pub node_version: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for LoaderPermissionsUnsupportedIssue {
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(
+            format!(
+                "Node.js {} can't restrict the permissions of webpack loaders",
+                self.node_version
+            )
+            .into(),
+        )
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Transform.into()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.project_dir
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(
+                "`experimental.turbo.loaderPermissions` relies on the Node.js permission model, \
+                 which is available since Node.js 22.13. Loaders which aren't listed in `trusted` \
+                 are not run. Upgrade Node.js or trust the loaders."
+                    .into(),
+            )
+            .resolved_cell(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::process::Command;
+
+    use super::{LoaderPermissions, NodePermissions, NodeVersion};
+
+    #[test]
+    fn parses_node_versions() {
+        assert_eq!(
+            NodeVersion::parse("v22.13.1\n"),
+            Some(NodeVersion {
+                major: 22,
+                minor: 13,
+                patch: 1
+            })
+        );
+        assert_eq!(NodeVersion::parse("22.13.1"), None);
+        assert_eq!(NodeVersion::parse("v22"), None);
+    }
+
+    #[test]
+    fn permission_model_versions() {
+        let supported = |version: &str| {
+            NodeVersion::parse(version)
+                .unwrap()
+                .supports_permission_model()
+        };
+        assert!(!supported("v18.20.4"));
+        assert!(!supported("v20.18.0"));
+        assert!(!supported("v22.12.0"));
+        assert!(supported("v22.13.0"));
+        assert!(!supported("v23.4.0"));
+        assert!(supported("v23.5.0"));
+        assert!(supported("v24.0.0"));
+    }
+
+    #[test]
+    fn trusted_loaders() {
+        let permissions = LoaderPermissions {
+            trusted: vec!["@svgr/webpack".into(), "raw-loader".into()],
+            ..Default::default()
+        };
+        assert!(permissions.is_trusted("@svgr/webpack"));
+        assert!(permissions.is_trusted("@svgr/webpack/dist/index.js"));
+        assert!(permissions.is_trusted("raw-loader"));
+        assert!(!permissions.is_trusted("raw-loader-extra"));
+        assert!(!permissions.is_trusted("@svgr/webpack-extra"));
+        assert!(!permissions.is_trusted("babel-loader"));
+    }
+
+    #[test]
+    fn permission_args() {
+        let permissions = NodePermissions {
+            read: vec!["/project".into(), "/cache".into()],
+            write: vec!["/project/out".into()],
+        };
+        let mut cmd = Command::new("node");
+        permissions.apply_args(&mut cmd);
+        let cmd = cmd.as_std();
+        assert_eq!(
+            cmd.get_args().collect::<Vec<_>>(),
+            [
+                "--permission",
+                "--allow-fs-read=/project",
+                "--allow-fs-read=/cache",
+                "--allow-fs-write=/project/out",
+            ]
+        );
+    }
+}
@@ -32,7 +32,9 @@ use turbo_tasks::{duration_span, FxIndexSet, ResolvedVc, Vc};
 use turbo_tasks_fs::{json::parse_json_with_source_context, FileSystemPath};
 use turbopack_ecmascript::magic_identifier::unmangle_identifiers;
 
-use crate::{source_map::apply_source_mapping, AssetsForSourceMapping};
+use crate::{
+    loader_permissions::NodePermissions, source_map::apply_source_mapping, AssetsForSourceMapping,
+};
 
 #[derive(Clone, Copy)]
 pub enum FormattingMode {
@@ -317,6 +319,7 @@ impl NodeJsPoolProcess {
         shared_stdout: SharedOutputSet,
         shared_stderr: SharedOutputSet,
         debug: bool,
+        permissions: Option<&NodePermissions>,
     ) -> Result<Self> {
         let guard = Box::new(duration_span!("Node.js process startup"));
         let listener = TcpListener::bind("127.0.0.1:0")
@@ -328,6 +331,9 @@ impl NodeJsPoolProcess {
         if debug {
             cmd.arg("--inspect-brk");
         }
+        if let Some(permissions) = permissions {
+            permissions.apply_args(&mut cmd);
+        }
         cmd.arg(entrypoint);
         cmd.arg(port.to_string());
         cmd.env_clear();
@@ -709,6 +715,9 @@ pub struct NodeJsPool {
     #[turbo_tasks(trace_ignore, debug_ignore)]
     shared_stderr: SharedOutputSet,
     debug: bool,
+    /// The permissions of the processes when they run untrusted code.
+    #[turbo_tasks(trace_ignore, debug_ignore)]
+    permissions: Option<NodePermissions>,
     #[turbo_tasks(trace_ignore, debug_ignore)]
     stats: Arc<Mutex<NodeJsPoolStats>>,
 }
@@ -740,11 +749,22 @@ impl NodeJsPool {
             shared_stdout: Arc::new(Mutex::new(FxIndexSet::default())),
             shared_stderr: Arc::new(Mutex::new(FxIndexSet::default())),
             debug,
+            permissions: None,
             stats: Default::default(),
         }
     }
 
-    async fn acquire_process(&self) -> Result<(NodeJsPoolProcess, AcquiredPermits)> {
+    /// Restricts the processes of the pool to `permissions`.
+    pub(super) fn with_permissions(self, permissions: NodePermissions) -> Self {
+        Self {
+            permissions: Some(permissions),
+            ..self
+        }
+    }
+
+    /// Acquires an idle process, or boots up a new one. With `fresh`, a new
+    /// process is always booted up.
+    async fn acquire_process(&self, fresh: bool) -> Result<(NodeJsPoolProcess, AcquiredPermits)> {
         {
             self.stats.lock().add_queued_task();
         }
@@ -758,33 +778,42 @@ impl NodeJsPool {
             permit
         };
 
-        select! {
-            idle_process_permit = self.idle_process_semaphore.clone().acquire_owned() => {
-                let idle_process_permit = idle_process_permit.context("acquiring idle process permit")?;
-                let process = {
-                    let mut processes = self.processes.lock();
-                    processes.pop().unwrap()
-                };
-                idle_process_permit.forget();
-                Ok((process, AcquiredPermits::Idle { concurrency_permit }))
-            },
-            bootup_permit = bootup => {
-                let bootup_permit = bootup_permit.context("acquiring bootup permit")?;
-                {
-                    self.stats.lock().add_booting_worker();
-                }
-                let (process, bootup_time) = self.create_process().await?;
-                // Update the worker count
-                {
-                    let mut stats = self.stats.lock();
-                    stats.add_bootup_time(bootup_time);
-                    stats.finished_booting_worker();
-                }
-                // Increase the allowed booting up processes
-                self.bootup_semaphore.add_permits(1);
-                Ok((process, AcquiredPermits::Fresh { concurrency_permit, bootup_permit }))
+        let bootup_permit = if fresh {
+            bootup.await
+        } else {
+            select! {
+                idle_process_permit = self.idle_process_semaphore.clone().acquire_owned() => {
+                    let idle_process_permit = idle_process_permit.context("acquiring idle process permit")?;
+                    let process = {
+                        let mut processes = self.processes.lock();
+                        processes.pop().unwrap()
+                    };
+                    idle_process_permit.forget();
+                    return Ok((process, AcquiredPermits::Idle { concurrency_permit }));
+                },
+                bootup_permit = bootup => bootup_permit,
             }
+        };
+        let bootup_permit = bootup_permit.context("acquiring bootup permit")?;
+        {
+            self.stats.lock().add_booting_worker();
+        }
+        let (process, bootup_time) = self.create_process().await?;
+        // Update the worker count
+        {
+            let mut stats = self.stats.lock();
+            stats.add_bootup_time(bootup_time);
+            stats.finished_booting_worker();
         }
+        // Increase the allowed booting up processes
+        self.bootup_semaphore.add_permits(1);
+        Ok((
+            process,
+            AcquiredPermits::Fresh {
+                concurrency_permit,
+                bootup_permit,
+            },
+        ))
     }
 
     async fn create_process(&self) -> Result<(NodeJsPoolProcess, Duration), anyhow::Error> {
@@ -799,6 +828,7 @@ impl NodeJsPool {
             self.shared_stdout.clone(),
             self.shared_stderr.clone(),
             self.debug,
+            self.permissions.as_ref(),
         )
         .await
         .context("creating new process")?;
@@ -806,8 +836,19 @@ impl NodeJsPool {
     }
 
     pub async fn operation(&self) -> Result<NodeJsOperation> {
+        self.new_operation(false).await
+    }
+
+    /// Like [Self::operation], but runs the operation on a newly booted up
+    /// process instead of an idle one, e.g. to retry an operation which crashed
+    /// its process, which may have been caused by the state of that process.
+    pub async fn fresh_operation(&self) -> Result<NodeJsOperation> {
+        self.new_operation(true).await
+    }
+
+    async fn new_operation(&self, fresh: bool) -> Result<NodeJsOperation> {
         // Acquire a running process (handles concurrency limits, boots up the process)
-        let (process, permits) = self.acquire_process().await?;
+        let (process, permits) = self.acquire_process(fresh).await?;
 
         Ok(NodeJsOperation {
             process: Some(process),
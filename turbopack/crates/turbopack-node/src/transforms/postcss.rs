@@ -470,6 +470,8 @@ impl PostCssTransformedAsset {
             project_path,
             chunking_context,
             env,
+            timeouts,
+            ..
         } = &*self.execution_context.await?;
 
         // For this postcss transform, there is no gaurantee that looking up for the
@@ -543,6 +545,9 @@ impl PostCssTransformedAsset {
                 ResolvedVc::cell(css_path.into()),
             ],
             additional_invalidation: config_changed,
+            operation: "postcss".into(),
+            timeout: timeouts.await?.for_operation(["postcss"]),
+            permissions: None,
         })
         .await?;
 
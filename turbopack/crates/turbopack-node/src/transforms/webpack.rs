@@ -1,4 +1,4 @@
-use std::mem::take;
+use std::{mem::take, time::Duration};
 
 use anyhow::{bail, Context, Result};
 use async_trait::async_trait;
@@ -21,6 +21,7 @@ use turbopack_core::{
     asset::{Asset, AssetContent},
     chunk::ChunkingContext,
     context::{AssetContext, ProcessResult},
+    error::PrettyPrintError,
     file_source::FileSource,
     ident::AssetIdent,
     issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
@@ -48,9 +49,11 @@ use crate::{
     embed_js::embed_file_path,
     evaluate::{
         compute, custom_evaluate, get_evaluate_pool, EnvVarTracking, EvaluateContext,
-        EvaluationIssue, JavaScriptEvaluation, JavaScriptStreamSender,
+        EvaluateTimeout, EvaluationCrashIssue, EvaluationIssue, EvaluationTimeoutIssue,
+        JavaScriptEvaluation, JavaScriptStreamSender,
     },
     execution_context::ExecutionContext,
+    loader_permissions::LoaderPermissions,
     pool::{FormattingMode, NodeJsPool},
     source_map::{StackFrame, StructuredError},
     AssetsForSourceMapping,
@@ -202,6 +205,8 @@ impl WebpackLoadersProcessedAsset {
             project_path,
             chunking_context,
             env,
+            timeouts,
+            loader_permissions,
         } = *transform.execution_context.await?;
         let source_content = this.source.content();
         let AssetContent::File(file) = *source_content.await? else {
@@ -235,6 +240,21 @@ impl WebpackLoadersProcessedAsset {
             ));
         };
         let loaders = transform.loaders.await?;
+        let timeout = timeouts
+            .await?
+            .for_operation(loaders.iter().map(|loader| &*loader.loader));
+        // The loaders of a rule run in the same process, so they are all
+        // restricted when one of them isn't trusted.
+        let permissions = match loader_permissions {
+            Some(permissions) => {
+                let permissions_value = permissions.await?;
+                loaders
+                    .iter()
+                    .any(|loader| !permissions_value.is_trusted(&loader.loader))
+                    .then_some(permissions)
+            }
+            None => None,
+        };
         let config_value = evaluate_webpack_loader(WebpackLoaderContext {
             module_asset: webpack_loaders_executor,
             cwd: project_path,
@@ -251,6 +271,14 @@ impl WebpackLoadersProcessedAsset {
                 ResolvedVc::cell(json!(*loaders)),
             ],
             additional_invalidation: Completion::immutable().to_resolved().await?,
+            operation: loaders
+                .iter()
+                .map(|loader| &*loader.loader)
+                .collect::<Vec<_>>()
+                .join(", ")
+                .into(),
+            timeout,
+            permissions,
         })
         .await?;
 
@@ -401,6 +429,11 @@ pub struct WebpackLoaderContext {
     pub resolve_options_context: Option<ResolvedVc<ResolveOptionsContext>>,
     pub args: Vec<ResolvedVc<JsonValue>>,
     pub additional_invalidation: ResolvedVc<Completion>,
+    /// The name the evaluation is reported as when it exceeds `timeout`.
+    pub operation: RcStr,
+    pub timeout: EvaluateTimeout,
+    /// Restricts the processes running the evaluation when set.
+    pub permissions: Option<ResolvedVc<LoaderPermissions>>,
 }
 
 #[async_trait]
@@ -428,6 +461,7 @@ impl EvaluateContext for WebpackLoaderContext {
             // vars only. So the runtime code tracks which env vars are read and send a dependency
             // message for them.
             EnvVarTracking::Untracked,
+            self.permissions.map(|permissions| *permissions),
         )
     }
 
@@ -592,6 +626,47 @@ impl EvaluateContext for WebpackLoaderContext {
         }
         Ok(())
     }
+
+    fn timeout(&self) -> EvaluateTimeout {
+        self.timeout
+    }
+
+    async fn emit_long_task(&self, elapsed: Duration, timed_out: bool) -> Result<()> {
+        if !timed_out {
+            // Issues are only reported once the evaluation finished, log a
+            // warning right away so a hanging loader can be told apart from a
+            // frozen server.
+            tracing::warn!(
+                operation = %self.operation,
+                elapsed_ms = elapsed.as_millis() as u64,
+                path = %self.context_ident_for_issue.path().to_string().await?,
+                "loader evaluation is still running"
+            );
+        }
+        EvaluationTimeoutIssue {
+            context_ident: self.context_ident_for_issue,
+            operation: self.operation.clone(),
+            elapsed_ms: elapsed.as_millis() as u64,
+            timed_out,
+        }
+        .resolved_cell()
+        .emit();
+        Ok(())
+    }
+
+    async fn emit_worker_crash(&self, error: anyhow::Error, attempts: usize) -> Result<()> {
+        // Skip the module instead of failing the whole build, the issue points
+        // at the module and the loaders crashing on it.
+        EvaluationCrashIssue {
+            context_ident: self.context_ident_for_issue,
+            operation: self.operation.clone(),
+            attempts,
+            error: PrettyPrintError(&error).to_string().into(),
+        }
+        .resolved_cell()
+        .emit();
+        Ok(())
+    }
 }
 
 #[turbo_tasks::function]
@@ -11,7 +11,7 @@ use turbopack_core::{
         chunk_group::{make_chunk_group, MakeChunkGroupResult},
         module_id_strategies::{DevModuleIdStrategy, ModuleIdStrategy},
         Chunk, ChunkGroupResult, ChunkItem, ChunkableModule, ChunkingContext,
-        EntryChunkGroupResult, EvaluatableAssets, MinifyType, ModuleId,
+        EntryChunkGroupResult, EvaluatableAssets, MinifyType, ModuleId, ResourceHint,
     },
     environment::Environment,
     ident::AssetIdent,
@@ -26,7 +26,9 @@ use turbopack_ecmascript::{
 use turbopack_ecmascript_runtime::RuntimeType;
 
 use crate::ecmascript::node::{
-    chunk::EcmascriptBuildNodeChunk, entry::chunk::EcmascriptBuildNodeEntryChunk,
+    bundle::{BundleFormat, EcmascriptBuildNodeBundle},
+    chunk::EcmascriptBuildNodeChunk,
+    entry::chunk::EcmascriptBuildNodeEntryChunk,
 };
 
 /// A builder for [`Vc<NodeJsChunkingContext>`].
@@ -168,11 +170,96 @@ impl NodeJsChunkingContext {
         this.into_value().cell()
     }
 
+    #[turbo_tasks::function]
+    async fn chunk_group_with_name(
+        self: Vc<Self>,
+        _ident: Vc<AssetIdent>,
+        module: ResolvedVc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> Result<Vc<ChunkGroupResult>> {
+        let span = tracing::info_span!(
+            "chunking",
+            module = module.ident().to_string().await?.to_string()
+        );
+        async move {
+            let MakeChunkGroupResult {
+                chunks,
+                availability_info,
+            } = make_chunk_group(
+                Vc::upcast(self),
+                [ResolvedVc::upcast(module)],
+                availability_info.into_value(),
+                chunk_name,
+            )
+            .await?;
+
+            let assets = chunks
+                .iter()
+                .map(|chunk| self.generate_chunk(**chunk).to_resolved())
+                .try_join()
+                .await?;
+
+            Ok(ChunkGroupResult {
+                assets: ResolvedVc::cell(assets),
+                availability_info,
+            }
+            .cell())
+        }
+        .instrument(span)
+        .await
+    }
+
     #[turbo_tasks::function]
     pub fn asset_prefix(&self) -> Vc<Option<RcStr>> {
         *self.asset_prefix
     }
 
+    /// Generates a single output file that, like [`ChunkingContext::entry_chunk_group`],
+    /// evaluates the given assets and exports the given module, but which contains the runtime
+    /// and all JS chunks of the module, including the ones loaded by dynamic imports. This is
+    /// used to build libraries, serverless functions and scripts.
+    #[turbo_tasks::function]
+    pub async fn single_file_bundle(
+        self: Vc<Self>,
+        path: Vc<FileSystemPath>,
+        module: ResolvedVc<Box<dyn Module>>,
+        evaluatable_assets: Vc<EvaluatableAssets>,
+        format: BundleFormat,
+    ) -> Result<Vc<Box<dyn OutputAsset>>> {
+        let MakeChunkGroupResult { chunks, .. } = make_chunk_group(
+            Vc::upcast(self),
+            once(module).chain(
+                evaluatable_assets
+                    .await?
+                    .iter()
+                    .map(|&asset| ResolvedVc::upcast(asset)),
+            ),
+            AvailabilityInfo::Root,
+            None,
+        )
+        .await?;
+
+        let chunks = chunks
+            .iter()
+            .map(|chunk| self.generate_chunk(**chunk).to_resolved())
+            .try_join()
+            .await?;
+
+        let Some(module) = ResolvedVc::try_downcast(module).await? else {
+            bail!("module must be placeable in an ecmascript chunk");
+        };
+
+        Ok(Vc::upcast(EcmascriptBuildNodeBundle::new(
+            path,
+            self,
+            Vc::cell(chunks),
+            evaluatable_assets,
+            *module,
+            format,
+        )))
+    }
+
     #[turbo_tasks::function]
     async fn generate_chunk(
         self: Vc<Self>,
@@ -292,41 +379,24 @@ impl ChunkingContext for NodeJsChunkingContext {
     }
 
     #[turbo_tasks::function]
-    async fn chunk_group(
+    fn chunk_group(
         self: Vc<Self>,
-        _ident: Vc<AssetIdent>,
-        module: ResolvedVc<Box<dyn ChunkableModule>>,
+        ident: Vc<AssetIdent>,
+        module: Vc<Box<dyn ChunkableModule>>,
         availability_info: Value<AvailabilityInfo>,
-    ) -> Result<Vc<ChunkGroupResult>> {
-        let span = tracing::info_span!(
-            "chunking",
-            module = module.ident().to_string().await?.to_string()
-        );
-        async move {
-            let MakeChunkGroupResult {
-                chunks,
-                availability_info,
-            } = make_chunk_group(
-                Vc::upcast(self),
-                [ResolvedVc::upcast(module)],
-                availability_info.into_value(),
-            )
-            .await?;
-
-            let assets = chunks
-                .iter()
-                .map(|chunk| self.generate_chunk(**chunk).to_resolved())
-                .try_join()
-                .await?;
+    ) -> Vc<ChunkGroupResult> {
+        self.chunk_group_with_name(ident, module, None, availability_info)
+    }
 
-            Ok(ChunkGroupResult {
-                assets: ResolvedVc::cell(assets),
-                availability_info,
-            }
-            .cell())
-        }
-        .instrument(span)
-        .await
+    #[turbo_tasks::function]
+    fn named_chunk_group(
+        self: Vc<Self>,
+        ident: Vc<AssetIdent>,
+        module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: RcStr,
+        availability_info: Value<AvailabilityInfo>,
+    ) -> Vc<ChunkGroupResult> {
+        self.chunk_group_with_name(ident, module, Some(chunk_name), availability_info)
     }
 
     /// Generates an output chunk that:
@@ -355,6 +425,7 @@ impl ChunkingContext for NodeJsChunkingContext {
                     .map(|&asset| ResolvedVc::upcast(asset)),
             ),
             availability_info,
+            None,
         )
         .await?;
 
@@ -415,17 +486,25 @@ impl ChunkingContext for NodeJsChunkingContext {
     async fn async_loader_chunk_item(
         self: Vc<Self>,
         module: Vc<Box<dyn ChunkableModule>>,
+        chunk_name: Option<RcStr>,
+        resource_hint: Option<ResourceHint>,
         availability_info: Value<AvailabilityInfo>,
     ) -> Result<Vc<Box<dyn ChunkItem>>> {
         Ok(if self.await?.manifest_chunks {
             let manifest_asset =
-                ManifestAsyncModule::new(module, Vc::upcast(self), availability_info);
+                ManifestAsyncModule::new(module, Vc::upcast(self), chunk_name, availability_info);
             Vc::upcast(ManifestLoaderChunkItem::new(
                 manifest_asset,
                 Vc::upcast(self),
             ))
         } else {
-            let module = AsyncLoaderModule::new(module, Vc::upcast(self), availability_info);
+            let module = AsyncLoaderModule::new(
+                module,
+                Vc::upcast(self),
+                chunk_name,
+                resource_hint,
+                availability_info,
+            );
             Vc::upcast(module.as_chunk_item(Vc::upcast(self)))
         })
     }
@@ -0,0 +1,358 @@
+use std::io::Write;
+
+use anyhow::{bail, Result};
+use indoc::writedoc;
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, TaskInput, ValueToString, Vc};
+use turbo_tasks_fs::{File, FileSystemPath};
+use turbo_tasks_hash::DeterministicHash;
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    chunk::{ChunkItemExt, ChunkableModule, ChunkingContext, EvaluatableAssets},
+    code_builder::{Code, CodeBuilder},
+    ident::AssetIdent,
+    output::{OutputAsset, OutputAssets},
+    reference::all_assets_from_entries,
+    source_map::{GenerateSourceMap, OptionSourceMap, SourceMapAsset},
+};
+use turbopack_ecmascript::{
+    chunk::{EcmascriptChunkPlaceable, EcmascriptExports},
+    utils::StringifyJs,
+};
+
+use super::chunk::EcmascriptBuildNodeChunk;
+use crate::NodeJsChunkingContext;
+
+/// The module format of a single-file bundle.
+#[derive(
+    Debug,
+    Default,
+    TaskInput,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    TraceRawVcs,
+    DeterministicHash,
+    NonLocalValue,
+)]
+pub enum BundleFormat {
+    /// The exports of the entry are assigned to `module.exports`.
+    #[default]
+    CommonJs,
+    /// The exports of the entry are exported as named ECMAScript exports.
+    Esm,
+}
+
+/// A single file containing the Node.js runtime and all JS chunks loaded by
+/// an entry, including the ones loaded by dynamic imports. Other output
+/// assets, like WebAssembly or static files, are still emitted next to it.
+#[turbo_tasks::value(shared)]
+pub(crate) struct EcmascriptBuildNodeBundle {
+    path: ResolvedVc<FileSystemPath>,
+    chunking_context: ResolvedVc<NodeJsChunkingContext>,
+    chunks: ResolvedVc<OutputAssets>,
+    evaluatable_assets: ResolvedVc<EvaluatableAssets>,
+    exported_module: ResolvedVc<Box<dyn EcmascriptChunkPlaceable>>,
+    format: BundleFormat,
+}
+
+#[turbo_tasks::value]
+struct BundledAssets {
+    /// JS chunks which are inlined in the bundle.
+    inlined: Vec<ResolvedVc<EcmascriptBuildNodeChunk>>,
+    /// Assets which are referenced by the inlined chunks, and emitted as
+    /// separate files.
+    external: Vec<ResolvedVc<Box<dyn OutputAsset>>>,
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptBuildNodeBundle {
+    /// Creates a new [`Vc<EcmascriptBuildNodeBundle>`].
+    #[turbo_tasks::function]
+    pub fn new(
+        path: ResolvedVc<FileSystemPath>,
+        chunking_context: ResolvedVc<NodeJsChunkingContext>,
+        chunks: ResolvedVc<OutputAssets>,
+        evaluatable_assets: ResolvedVc<EvaluatableAssets>,
+        exported_module: ResolvedVc<Box<dyn EcmascriptChunkPlaceable>>,
+        format: BundleFormat,
+    ) -> Vc<Self> {
+        EcmascriptBuildNodeBundle {
+            path,
+            chunking_context,
+            chunks,
+            evaluatable_assets,
+            exported_module,
+            format,
+        }
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn bundled_assets(&self) -> Result<Vc<BundledAssets>> {
+        let mut inlined = vec![];
+        let mut external = vec![];
+        for &asset in &*all_assets_from_entries(*self.chunks).await? {
+            if let Some(chunk) =
+                ResolvedVc::try_downcast_type::<EcmascriptBuildNodeChunk>(asset).await?
+            {
+                inlined.push(chunk);
+            } else if ResolvedVc::try_downcast_type::<SourceMapAsset>(asset)
+                .await?
+                .is_none()
+            {
+                external.push(asset);
+            }
+        }
+        Ok(BundledAssets { inlined, external }.cell())
+    }
+
+    #[turbo_tasks::function]
+    async fn code(self: Vc<Self>) -> Result<Vc<Code>> {
+        let this = self.await?;
+
+        let output_root = this.chunking_context.output_root().await?;
+        let output_root_to_root_path = this.chunking_context.output_root_to_root_path().await?;
+        let bundle_path = this.path.await?;
+        let Some(bundle_public_path) = output_root.get_path_to(&bundle_path) else {
+            bail!(
+                "bundle path ({}) is not in output root ({})",
+                bundle_path.to_string(),
+                output_root.to_string()
+            );
+        };
+        let asset_prefix = this.chunking_context.asset_prefix().await?;
+        let asset_prefix = asset_prefix.as_deref().unwrap_or("/");
+
+        let mut code = CodeBuilder::default();
+
+        if this.format == BundleFormat::Esm {
+            writedoc!(
+                code,
+                r#"
+                    import {{ createRequire as __turbopack_create_require__ }} from "node:module";
+                    import {{ dirname as __turbopack_dirname__ }} from "node:path";
+                    import {{ fileURLToPath as __turbopack_file_url_to_path__ }} from "node:url";
+                    const require = __turbopack_create_require__(import.meta.url);
+                    const __filename = __turbopack_file_url_to_path__(import.meta.url);
+                    const __dirname = __turbopack_dirname__(__filename);
+                "#,
+            )?;
+        }
+
+        // The runtime is evaluated as if it was emitted at the path of the
+        // bundle, so that it resolves the other output assets from there.
+        writedoc!(
+            code,
+            r#"
+                const CHUNK_PUBLIC_PATH = {};
+                const runtime = (function (module) {{
+                const RUNTIME_PUBLIC_PATH = CHUNK_PUBLIC_PATH;
+                const RELATIVE_ROOT_PATH = {};
+                const ASSET_PREFIX = {};
+            "#,
+            StringifyJs(bundle_public_path),
+            StringifyJs(output_root_to_root_path.as_str()),
+            StringifyJs(asset_prefix),
+        )?;
+        code.push_code(
+            &*turbopack_ecmascript_runtime::get_nodejs_runtime_code(
+                this.chunking_context.environment(),
+            )
+            .await?,
+        );
+        writedoc!(
+            code,
+            r#"
+
+                return module.exports;
+                }})({{ exports: {{}} }});
+            "#,
+        )?;
+
+        for chunk in &self.bundled_assets().await?.inlined {
+            let chunk_path = chunk.ident().path().await?;
+            let Some(chunk_public_path) = output_root.get_path_to(&chunk_path) else {
+                bail!(
+                    "chunk path ({}) is not in output root ({})",
+                    chunk_path.to_string(),
+                    output_root.to_string()
+                );
+            };
+            writeln!(
+                code,
+                "runtime.registerChunk({}, (function (module) {{",
+                StringifyJs(chunk_public_path)
+            )?;
+            code.push_code(&*chunk.own_content().code().await?);
+            writedoc!(
+                code,
+                r#"
+
+                    return module.exports;
+                    }})({{ exports: {{}} }}));
+                "#,
+            )?;
+        }
+
+        for evaluatable_asset in &*this.evaluatable_assets.await? {
+            if let Some(placeable) =
+                ResolvedVc::try_sidecast::<Box<dyn EcmascriptChunkPlaceable>>(*evaluatable_asset)
+                    .await?
+            {
+                let runtime_module_id = placeable
+                    .as_chunk_item(Vc::upcast(*this.chunking_context))
+                    .id()
+                    .await?;
+
+                writedoc!(
+                    code,
+                    r#"
+                        runtime.getOrInstantiateRuntimeModule({}, CHUNK_PUBLIC_PATH);
+                    "#,
+                    StringifyJs(&*runtime_module_id),
+                )?;
+            }
+        }
+
+        let runtime_module_id = this
+            .exported_module
+            .as_chunk_item(Vc::upcast(*this.chunking_context))
+            .id()
+            .await?;
+
+        match this.format {
+            BundleFormat::CommonJs => {
+                writedoc!(
+                    code,
+                    r#"
+                        module.exports = runtime.getOrInstantiateRuntimeModule({}, CHUNK_PUBLIC_PATH).exports;
+                    "#,
+                    StringifyJs(&*runtime_module_id),
+                )?;
+            }
+            BundleFormat::Esm => {
+                writedoc!(
+                    code,
+                    r#"
+                        const __turbopack_exports__ = runtime.getOrInstantiateRuntimeModule({}, CHUNK_PUBLIC_PATH).exports;
+                    "#,
+                    StringifyJs(&*runtime_module_id),
+                )?;
+                let export_names = match &*this.exported_module.get_exports().await? {
+                    EcmascriptExports::EsmExports(exports) => Some(
+                        exports
+                            .expand_exports()
+                            .await?
+                            .exports
+                            .keys()
+                            .filter(|name| is_export_name(name))
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    ),
+                    _ => None,
+                };
+                if let Some(export_names) = export_names {
+                    for (index, name) in export_names.iter().enumerate() {
+                        writedoc!(
+                            code,
+                            r#"
+                                const __turbopack_export_{index}__ = __turbopack_exports__[{name}];
+                                export {{ __turbopack_export_{index}__ as {export} }};
+                            "#,
+                            index = index,
+                            name = StringifyJs(name),
+                            export = name,
+                        )?;
+                    }
+                } else {
+                    // CommonJS modules don't have statically known exports.
+                    writedoc!(
+                        code,
+                        r#"
+                            export default __turbopack_exports__;
+                        "#,
+                    )?;
+                }
+            }
+        }
+
+        if code.has_source_map() {
+            write!(
+                code,
+                "\n//# sourceMappingURL={}.map",
+                urlencoding::encode(bundle_path.file_name())
+            )?;
+        }
+
+        Ok(Code::cell(code.build()))
+    }
+}
+
+/// Whether `name` can be exported as an identifier. Other names are only
+/// available through the default export of CommonJS entries.
+fn is_export_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for EcmascriptBuildNodeBundle {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> Vc<RcStr> {
+        Vc::cell("Ecmascript Build Node Bundle".into())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl OutputAsset for EcmascriptBuildNodeBundle {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        AssetIdent::from_path(*self.path)
+    }
+
+    #[turbo_tasks::function]
+    async fn references(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
+        let this = self.await?;
+        let mut references = self.bundled_assets().await?.external.clone();
+
+        if *this
+            .chunking_context
+            .reference_chunk_source_maps(Vc::upcast(self))
+            .await?
+        {
+            references.push(ResolvedVc::upcast(
+                SourceMapAsset::new(Vc::upcast(self)).to_resolved().await?,
+            ))
+        }
+
+        Ok(Vc::cell(references))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for EcmascriptBuildNodeBundle {
+    #[turbo_tasks::function]
+    async fn content(self: Vc<Self>) -> Result<Vc<AssetContent>> {
+        let code = self.code().await?;
+        Ok(AssetContent::file(
+            File::from(code.source_code().clone()).into(),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl GenerateSourceMap for EcmascriptBuildNodeBundle {
+    #[turbo_tasks::function]
+    fn generate_source_map(self: Vc<Self>) -> Vc<OptionSourceMap> {
+        self.code().generate_source_map()
+    }
+}
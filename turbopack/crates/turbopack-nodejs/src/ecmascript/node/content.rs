@@ -13,7 +13,7 @@ use turbopack_core::{
     version::{Version, VersionedContent},
 };
 use turbopack_ecmascript::{
-    chunk::{EcmascriptChunkContent, EcmascriptChunkItemExt},
+    chunk::{chunk_item_provenance, EcmascriptChunkContent, EcmascriptChunkItemExt},
     minify::minify,
     utils::StringifyJs,
 };
@@ -65,7 +65,7 @@ pub(super) async fn chunk_items(
 #[turbo_tasks::value_impl]
 impl EcmascriptBuildNodeChunkContent {
     #[turbo_tasks::function]
-    async fn code(self: Vc<Self>) -> Result<Vc<Code>> {
+    pub(super) async fn code(self: Vc<Self>) -> Result<Vc<Code>> {
         let this = self.await?;
         let chunk_path_vc = this.chunk.ident().path();
         let chunk_path = chunk_path_vc.await?;
@@ -80,7 +80,25 @@ impl EcmascriptBuildNodeChunkContent {
             "#,
         )?;
 
-        for (id, item_code) in chunk_items(*this.content).await? {
+        let minify_type = this.chunking_context.await?.minify_type();
+        let provenances = if matches!(minify_type, MinifyType::NoMinify) {
+            this.content
+                .await?
+                .chunk_items
+                .iter()
+                .map(|&(chunk_item, _)| async move {
+                    chunk_item_provenance(Vc::upcast(chunk_item)).await
+                })
+                .try_join()
+                .await?
+        } else {
+            vec![]
+        };
+
+        for (index, (id, item_code)) in chunk_items(*this.content).await?.into_iter().enumerate() {
+            if let Some(provenance) = provenances.get(index) {
+                writeln!(code, "{}", &**provenance)?;
+            }
             write!(code, "{}: ", StringifyJs(&id))?;
             code.push_code(&item_code);
             writeln!(code, ",")?;
@@ -98,10 +116,7 @@ impl EcmascriptBuildNodeChunkContent {
         }
 
         let code = code.build().cell();
-        if matches!(
-            this.chunking_context.await?.minify_type(),
-            MinifyType::Minify
-        ) {
+        if matches!(minify_type, MinifyType::Minify) {
             return Ok(minify(chunk_path_vc, code));
         }
 
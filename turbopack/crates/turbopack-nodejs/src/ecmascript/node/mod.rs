@@ -1,3 +1,4 @@
+pub(crate) mod bundle;
 pub(crate) mod chunk;
 pub(crate) mod content;
 pub(crate) mod entry;
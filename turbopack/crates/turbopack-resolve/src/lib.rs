@@ -3,6 +3,8 @@
 
 pub mod ecmascript;
 pub mod node_native_binding;
+pub mod package_store;
+pub mod pnp;
 pub mod resolve;
 pub mod resolve_options_context;
 pub mod typescript;
@@ -0,0 +1,390 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, ValueToString, Vc};
+use turbo_tasks_fs::{FileContent, FileSystemEntryType, FileSystemPath};
+use turbopack_core::{
+    file_source::FileSource,
+    issue::{Issue, IssueExt, IssueStage, OptionStyledString, StyledString},
+    resolve::plugin::{PackageLookupPlugin, PackageLookupResult},
+    source::Source,
+};
+
+/// A global store of extracted packages, addressed by the integrity of their
+/// tarball, e.g. `sha512-3q2+7w==` is stored in `<store>/sha512/3q2-7w`. The
+/// digest is base64url encoded without padding.
+#[turbo_tasks::value(shared)]
+#[derive(Debug)]
+pub struct PackageStore {
+    /// The `package-lock.json` describing the `node_modules` layout the
+    /// packages are resolved with. Lockfile versions 2 and 3 are supported.
+    pub lockfile: ResolvedVc<FileSystemPath>,
+    /// The root directory of the store.
+    pub store_dir: ResolvedVc<FileSystemPath>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+enum PackageStoreEntry {
+    /// The directory of the package relative to the store.
+    Store(RcStr),
+    /// A workspace package or a `file:` dependency, relative to the lockfile.
+    Link(RcStr),
+    /// A package without an integrity, e.g. a git dependency, which can't be
+    /// located in the store.
+    Unavailable,
+}
+
+/// The mapping from the `node_modules` layout of a lockfile to the store.
+#[turbo_tasks::value]
+struct PackageStoreMapping {
+    /// The packages by their location in the `node_modules` layout, e.g.
+    /// `node_modules/a/node_modules/b`.
+    packages: BTreeMap<RcStr, PackageStoreEntry>,
+    /// The shallowest location of each store directory, used to find the
+    /// location of an issuer in the store. A package installed at several
+    /// locations resolves its dependencies like at the shallowest one, which
+    /// only differs when the lockfile has conflicting versions of its
+    /// dependencies.
+    store_locations: BTreeMap<RcStr, RcStr>,
+}
+
+impl PackageStoreMapping {
+    /// Finds the package `name` imported from `issuer` like Node.js, by looking
+    /// in the `node_modules` of the issuer and its ancestors.
+    fn find_dependency(
+        &self,
+        mut issuer: &str,
+        name: &str,
+    ) -> Option<(&RcStr, &PackageStoreEntry)> {
+        loop {
+            if issuer != "node_modules" && !issuer.ends_with("/node_modules") {
+                let location = if issuer.is_empty() {
+                    format!("node_modules/{name}")
+                } else {
+                    format!("{issuer}/node_modules/{name}")
+                };
+                if let Some(found) = self.packages.get_key_value(location.as_str()) {
+                    return Some(found);
+                }
+            }
+            if issuer.is_empty() {
+                return None;
+            }
+            issuer = issuer.rfind('/').map_or("", |index| &issuer[..index]);
+        }
+    }
+
+    /// Returns the location in the `node_modules` layout of a path relative to
+    /// the store, e.g. `sha512/3q2-7w/lib` to `node_modules/a/lib`.
+    fn store_location(&self, path: &str) -> Option<String> {
+        let mut segments = path.splitn(3, '/');
+        let dir = format!("{}/{}", segments.next()?, segments.next()?);
+        let location = self.store_locations.get(dir.as_str())?;
+        Some(match segments.next() {
+            Some(rest) if !rest.is_empty() => format!("{location}/{rest}"),
+            _ => location.to_string(),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedPackage {
+    resolved: Option<RcStr>,
+    integrity: Option<RcStr>,
+    #[serde(default)]
+    link: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedLockfile {
+    lockfile_version: u32,
+    #[serde(default)]
+    packages: BTreeMap<RcStr, SerializedPackage>,
+}
+
+/// Returns the store directory of a package from its integrity, e.g.
+/// `sha512-3q2+7w==` to `sha512/3q2-7w`. The first hash is used when there are
+/// several.
+fn store_dir_from_integrity(integrity: &str) -> Option<RcStr> {
+    let hash = integrity.split_whitespace().next()?;
+    let (algorithm, digest) = hash.split_once('-')?;
+    // Options can be appended to the digest, e.g. `sha512-...?foo`
+    let digest = digest.split('?').next()?.trim_end_matches('=');
+    if algorithm.is_empty() || digest.is_empty() {
+        return None;
+    }
+    Some(format!("{algorithm}/{}", digest.replace('+', "-").replace('/', "_")).into())
+}
+
+fn parse_lockfile(lockfile: SerializedLockfile) -> PackageStoreMapping {
+    let mut packages = BTreeMap::new();
+    let mut store_locations = BTreeMap::new();
+    for (location, package) in lockfile.packages {
+        // The root package and workspace packages are only listed by their
+        // path, they are linked into the `node_modules` layout separately
+        if !location.starts_with("node_modules/") && !location.contains("/node_modules/") {
+            continue;
+        }
+        let entry = if package.link {
+            match package.resolved {
+                Some(resolved) => PackageStoreEntry::Link(resolved),
+                None => PackageStoreEntry::Unavailable,
+            }
+        } else {
+            match package
+                .integrity
+                .as_deref()
+                .and_then(store_dir_from_integrity)
+            {
+                Some(dir) => {
+                    let depth = |location: &str| location.matches("/node_modules/").count();
+                    store_locations
+                        .entry(dir.clone())
+                        .and_modify(|existing: &mut RcStr| {
+                            if depth(&location) < depth(existing) {
+                                *existing = location.clone();
+                            }
+                        })
+                        .or_insert_with(|| location.clone());
+                    PackageStoreEntry::Store(dir)
+                }
+                None => PackageStoreEntry::Unavailable,
+            }
+        };
+        packages.insert(location, entry);
+    }
+    PackageStoreMapping {
+        packages,
+        store_locations,
+    }
+}
+
+/// Builds the mapping of a lockfile to the store. It's computed once and kept
+/// in the persistent cache until the lockfile changes.
+#[turbo_tasks::function]
+async fn package_store_mapping(
+    lockfile: ResolvedVc<FileSystemPath>,
+) -> Result<Vc<PackageStoreMapping>> {
+    let FileContent::Content(file) = &*lockfile.read().await? else {
+        bail!("the lockfile {} doesn't exist", lockfile.to_string().await?);
+    };
+    let lockfile_name = lockfile.to_string().await?;
+    let lockfile: SerializedLockfile = serde_json::from_str(&file.content().to_str()?)
+        .with_context(|| format!("failed to parse the lockfile {lockfile_name}"))?;
+    if lockfile.lockfile_version < 2 {
+        bail!(
+            "the lockfile {lockfile_name} has version {}, which doesn't list the location of \
+             packages. Run `npm install --lockfile-version 3` to upgrade it.",
+            lockfile.lockfile_version
+        );
+    }
+    Ok(parse_lockfile(lockfile).cell())
+}
+
+/// Finds packages in a global content-addressed store through a lockfile,
+/// without a `node_modules` directory being installed. Packages which aren't
+/// in the lockfile are left to the other module locations.
+#[turbo_tasks::value]
+pub struct PackageStoreLookupPlugin {
+    store: ResolvedVc<PackageStore>,
+}
+
+#[turbo_tasks::value_impl]
+impl PackageStoreLookupPlugin {
+    #[turbo_tasks::function]
+    pub fn new(store: ResolvedVc<PackageStore>) -> Vc<Self> {
+        PackageStoreLookupPlugin { store }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl PackageLookupPlugin for PackageStoreLookupPlugin {
+    #[turbo_tasks::function]
+    async fn find_package(
+        &self,
+        lookup_path: Vc<FileSystemPath>,
+        package_name: RcStr,
+    ) -> Result<Vc<PackageLookupResult>> {
+        let store = self.store.await?;
+        let lockfile_dir = store.lockfile.parent();
+        let lookup_path_value = lookup_path.await?;
+        let mapping = package_store_mapping(*store.lockfile).await?;
+
+        // The store can be located inside of the project, so it's checked first
+        let issuer = if let Some(path) = store.store_dir.await?.get_path_to(&lookup_path_value) {
+            mapping.store_location(path)
+        } else {
+            lockfile_dir
+                .await?
+                .get_path_to(&lookup_path_value)
+                .map(|path| path.to_string())
+        };
+        let Some(issuer) = issuer else {
+            return Ok(PackageLookupResult::Unhandled.cell());
+        };
+        let Some((location, entry)) = mapping.find_dependency(&issuer, &package_name) else {
+            return Ok(PackageLookupResult::Unhandled.cell());
+        };
+
+        let lookup_path = lookup_path.to_resolved().await?;
+        let affecting_sources = vec![ResolvedVc::upcast(
+            FileSource::new(*store.lockfile).to_resolved().await?,
+        )];
+        let not_found = |message: String| -> Result<Vc<PackageLookupResult>> {
+            PackageStoreIssue {
+                lookup_path,
+                message: message.into(),
+            }
+            .resolved_cell()
+            .emit();
+            Ok(PackageLookupResult::NotFound {
+                affecting_sources: affecting_sources.clone(),
+            }
+            .cell())
+        };
+
+        let package_dir = match entry {
+            PackageStoreEntry::Store(dir) => {
+                let package_dir = store.store_dir.join(dir.clone());
+                if *package_dir.get_type().await? != FileSystemEntryType::Directory {
+                    return not_found(format!(
+                        "{location} is missing from the package store at {}/{dir}. Populate the \
+                         store from the lockfile before building.",
+                        store.store_dir.to_string().await?
+                    ));
+                }
+                package_dir.to_resolved().await?
+            }
+            PackageStoreEntry::Link(target) => {
+                let Some(package_dir) = *lockfile_dir.try_join(target.clone()).await? else {
+                    return not_found(format!(
+                        "{location} links to {target}, which is outside of the project."
+                    ));
+                };
+                package_dir
+            }
+            PackageStoreEntry::Unavailable => {
+                return not_found(format!(
+                    "{location} has no integrity in the lockfile, so it can't be located in the \
+                     package store. Dependencies from git or tarball URLs need to be installed \
+                     into node_modules."
+                ));
+            }
+        };
+
+        Ok(PackageLookupResult::Found {
+            package_dir,
+            affecting_sources,
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::value(shared)]
+struct PackageStoreIssue {
+    lookup_path: ResolvedVc<FileSystemPath>,
+    message: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for PackageStoreIssue {
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("Package not available in the package store".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.lookup_path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(self.message.clone()).resolved_cell(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_integrity_to_store_dir() {
+        assert_eq!(
+            store_dir_from_integrity("sha512-3q2+7w/a==").as_deref(),
+            Some("sha512/3q2-7w_a")
+        );
+        assert_eq!(
+            store_dir_from_integrity("sha1-abc= sha512-def==").as_deref(),
+            Some("sha1/abc")
+        );
+        assert_eq!(store_dir_from_integrity("invalid"), None);
+    }
+
+    #[test]
+    fn finds_dependencies() {
+        let lockfile: SerializedLockfile = serde_json::from_value(serde_json::json!({
+            "lockfileVersion": 3,
+            "packages": {
+                "": { "name": "app" },
+                "node_modules/react": { "integrity": "sha512-react==" },
+                "node_modules/lib": { "resolved": "packages/lib", "link": true },
+                "node_modules/a": { "integrity": "sha512-a==" },
+                "node_modules/a/node_modules/react": { "integrity": "sha512-react17==" },
+                "node_modules/a/node_modules/c": { "integrity": "sha512-c==" },
+                "node_modules/c": { "integrity": "sha512-c==" },
+                "node_modules/@scope/b": { "integrity": "sha512-b==" },
+                "node_modules/git": { "resolved": "git+ssh://git@github.com/a/git.git" },
+                "packages/lib": { "name": "lib" },
+            },
+        }))
+        .unwrap();
+        let mapping = parse_lockfile(lockfile);
+
+        assert_eq!(
+            mapping.find_dependency("src/app", "react").unwrap().1,
+            &PackageStoreEntry::Store("sha512/react".into())
+        );
+        assert_eq!(
+            mapping
+                .find_dependency("packages/lib/src", "lib")
+                .unwrap()
+                .1,
+            &PackageStoreEntry::Link("packages/lib".into())
+        );
+        assert_eq!(
+            mapping.find_dependency("src", "git").unwrap().1,
+            &PackageStoreEntry::Unavailable
+        );
+        assert!(mapping.find_dependency("src", "missing").is_none());
+
+        let a = mapping.store_location("sha512/a/dist").unwrap();
+        assert_eq!(a, "node_modules/a/dist");
+        assert_eq!(
+            mapping.find_dependency(&a, "react").unwrap().1,
+            &PackageStoreEntry::Store("sha512/react17".into())
+        );
+        let b = mapping.store_location("sha512/b").unwrap();
+        assert_eq!(b, "node_modules/@scope/b");
+        assert_eq!(
+            mapping.find_dependency(&b, "react").unwrap().1,
+            &PackageStoreEntry::Store("sha512/react".into())
+        );
+        assert_eq!(
+            mapping.store_location("sha512/c").unwrap(),
+            "node_modules/c"
+        );
+        assert!(mapping.store_location("sha512/unknown").is_none());
+    }
+}
@@ -0,0 +1,570 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use turbo_rcstr::RcStr;
+use turbo_tasks::{trace::TraceRawVcs, NonLocalValue, ResolvedVc, ValueToString, Vc};
+use turbo_tasks_fs::{FileContent, FileSystemPath, ZipFileSystem};
+use turbopack_core::{
+    file_source::FileSource,
+    issue::{Issue, IssueExt, IssueStage, OptionStyledString, StyledString},
+    resolve::plugin::{PackageLookupPlugin, PackageLookupResult},
+    source::Source,
+};
+
+/// The files of a Yarn Plug'n'Play install containing the manifest. Yarn
+/// writes `.pnp.data.json` when `pnpEnableInlining` is disabled and inlines
+/// the manifest into `.pnp.cjs` otherwise.
+#[turbo_tasks::function]
+pub fn pnp_manifest_files() -> Vc<Vec<RcStr>> {
+    Vc::cell(vec![".pnp.data.json".into(), ".pnp.cjs".into()])
+}
+
+/// A package in the manifest, identified by its name and reference, e.g.
+/// `react` and `npm:18.2.0`. Both are empty for the top-level workspace.
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TraceRawVcs, NonLocalValue,
+)]
+struct PnpLocator {
+    name: RcStr,
+    reference: RcStr,
+}
+
+impl std::fmt::Display for PnpLocator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "the top-level workspace")
+        } else {
+            write!(f, "{}@{}", self.name, self.reference)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, NonLocalValue)]
+struct PnpPackage {
+    /// The location relative to the manifest, without the leading `./` and
+    /// the trailing slash.
+    location: RcStr,
+    /// The packages each dependency resolves to, `None` for peer dependencies
+    /// which are not provided by an ancestor.
+    dependencies: BTreeMap<RcStr, Option<PnpLocator>>,
+}
+
+/// The parsed manifest of a Yarn Plug'n'Play install.
+#[turbo_tasks::value]
+struct PnpManifest {
+    packages: BTreeMap<PnpLocator, PnpPackage>,
+    /// The package at each location, used to find the package of an issuer.
+    locations: BTreeMap<RcStr, PnpLocator>,
+    enable_top_level_fallback: bool,
+    fallback_pool: BTreeMap<RcStr, Option<PnpLocator>>,
+    fallback_exclusion_list: BTreeMap<RcStr, Vec<RcStr>>,
+    ignore_pattern: Option<RcStr>,
+}
+
+enum PnpDependency<'a> {
+    Found(&'a PnpLocator),
+    MissingPeer,
+    Undeclared,
+}
+
+impl PnpManifest {
+    /// Finds the package containing the given path relative to the manifest.
+    fn find_locator(&self, mut path: &str) -> Option<&PnpLocator> {
+        loop {
+            if let Some(locator) = self.locations.get(path) {
+                return Some(locator);
+            }
+            if path.is_empty() {
+                return None;
+            }
+            path = path.rfind('/').map_or("", |index| &path[..index]);
+        }
+    }
+
+    fn find_dependency(&self, issuer: &PnpLocator, name: &str) -> PnpDependency<'_> {
+        if let Some(package) = self.packages.get(issuer) {
+            match package.dependencies.get(name) {
+                Some(Some(locator)) => return PnpDependency::Found(locator),
+                Some(None) => return PnpDependency::MissingPeer,
+                None => {}
+            }
+        }
+
+        let excluded = self
+            .fallback_exclusion_list
+            .get(&issuer.name)
+            .is_some_and(|references| references.contains(&issuer.reference));
+        if self.enable_top_level_fallback && !excluded {
+            if let Some(Some(locator)) = self.fallback_pool.get(name) {
+                return PnpDependency::Found(locator);
+            }
+        }
+        PnpDependency::Undeclared
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SerializedDependencyTarget {
+    Reference(RcStr),
+    /// An aliased dependency, e.g. `"lodash": "npm:lodash-es@4"`.
+    Alias(RcStr, RcStr),
+}
+
+impl SerializedDependencyTarget {
+    fn into_locator(self, name: &RcStr) -> PnpLocator {
+        match self {
+            SerializedDependencyTarget::Reference(reference) => PnpLocator {
+                name: name.clone(),
+                reference,
+            },
+            SerializedDependencyTarget::Alias(name, reference) => PnpLocator { name, reference },
+        }
+    }
+}
+
+type SerializedDependencies = Vec<(RcStr, Option<SerializedDependencyTarget>)>;
+
+fn into_dependencies(dependencies: SerializedDependencies) -> BTreeMap<RcStr, Option<PnpLocator>> {
+    dependencies
+        .into_iter()
+        .map(|(name, target)| {
+            let locator = target.map(|target| target.into_locator(&name));
+            (name, locator)
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedPackageInformation {
+    package_location: RcStr,
+    #[serde(default)]
+    package_dependencies: SerializedDependencies,
+}
+
+/// The packages by name and reference.
+type SerializedPackageRegistry = Vec<(
+    Option<RcStr>,
+    Vec<(Option<RcStr>, SerializedPackageInformation)>,
+)>;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedPnpManifest {
+    #[serde(default)]
+    enable_top_level_fallback: bool,
+    #[serde(default)]
+    fallback_pool: SerializedDependencies,
+    #[serde(default)]
+    fallback_exclusion_list: Vec<(RcStr, Vec<RcStr>)>,
+    #[serde(default)]
+    ignore_pattern_data: Option<RcStr>,
+    package_registry_data: SerializedPackageRegistry,
+}
+
+/// Extracts the manifest which Yarn inlines into `.pnp.cjs` as a string
+/// literal assigned to `RAW_RUNTIME_STATE`.
+fn extract_inlined_manifest(script: &str) -> Option<String> {
+    let script = &script[script.find("RAW_RUNTIME_STATE")?..];
+    let start = script.find(['\'', '"'])?;
+    let quote = script[start..].chars().next()?;
+    let mut chars = script[start + 1..].chars();
+    let mut manifest = String::new();
+    while let Some(char) = chars.next() {
+        match char {
+            '\\' => match chars.next()? {
+                // Line continuation
+                '\n' => {}
+                'n' => manifest.push('\n'),
+                'r' => manifest.push('\r'),
+                't' => manifest.push('\t'),
+                escaped => manifest.push(escaped),
+            },
+            char if char == quote => return Some(manifest),
+            char => manifest.push(char),
+        }
+    }
+    None
+}
+
+/// Normalizes a package location from the manifest, e.g. `./packages/app/`
+/// to `packages/app`.
+fn normalize_location(location: &str) -> RcStr {
+    let location = location.strip_prefix("./").unwrap_or(location);
+    location.strip_suffix('/').unwrap_or(location).into()
+}
+
+fn parse_manifest(manifest: SerializedPnpManifest) -> PnpManifest {
+    let mut packages = BTreeMap::new();
+    let mut locations = BTreeMap::new();
+    for (name, references) in manifest.package_registry_data {
+        for (reference, information) in references {
+            let locator = PnpLocator {
+                name: name.clone().unwrap_or_default(),
+                reference: reference.unwrap_or_default(),
+            };
+            let location = normalize_location(&information.package_location);
+            // Packages can share a location, e.g. a workspace with peer
+            // dependencies, the first one registered owns the files
+            locations
+                .entry(location.clone())
+                .or_insert_with(|| locator.clone());
+            packages.insert(
+                locator,
+                PnpPackage {
+                    location,
+                    dependencies: into_dependencies(information.package_dependencies),
+                },
+            );
+        }
+    }
+
+    PnpManifest {
+        packages,
+        locations,
+        enable_top_level_fallback: manifest.enable_top_level_fallback,
+        fallback_pool: into_dependencies(manifest.fallback_pool),
+        fallback_exclusion_list: manifest.fallback_exclusion_list.into_iter().collect(),
+        ignore_pattern: manifest.ignore_pattern_data,
+    }
+}
+
+#[turbo_tasks::function]
+async fn pnp_manifest(manifest_path: ResolvedVc<FileSystemPath>) -> Result<Vc<PnpManifest>> {
+    let FileContent::Content(file) = &*manifest_path.read().await? else {
+        bail!(
+            "the Yarn Plug'n'Play manifest {} doesn't exist",
+            manifest_path.to_string().await?
+        );
+    };
+    let manifest_name = manifest_path.to_string().await?;
+    let content = file.content().to_str()?;
+    let manifest = if manifest_path.await?.path.ends_with(".cjs") {
+        extract_inlined_manifest(&content)
+            .context("the manifest isn't inlined into .pnp.cjs, is pnpEnableInlining disabled?")?
+    } else {
+        content.into_owned()
+    };
+    let manifest = serde_json::from_str(&manifest).with_context(|| {
+        format!("failed to parse the Yarn Plug'n'Play manifest {manifest_name}")
+    })?;
+    Ok(parse_manifest(manifest).cell())
+}
+
+/// Resolves the `__virtual__` segments of a location. Yarn gives packages
+/// with peer dependencies a virtual location per set of provided peers, in
+/// the form `<base>/__virtual__/<hash>/<depth>/<path>`, which points to
+/// `<path>` relative to `<depth>` directories above `<base>`.
+fn resolve_virtual_location(location: &str) -> String {
+    let mut segments = location.split('/');
+    let mut resolved = Vec::new();
+    while let Some(segment) = segments.next() {
+        if segment == "__virtual__" || segment == "$$virtual" {
+            segments.next();
+            let depth = segments
+                .next()
+                .and_then(|depth| depth.parse().ok())
+                .unwrap_or(0);
+            resolved.extend(std::iter::repeat_n("..", depth));
+        } else {
+            resolved.push(segment);
+        }
+    }
+    resolved.join("/")
+}
+
+/// Splits a location into the zip archive containing it, if any, and the path
+/// inside of the archive.
+fn split_archive(location: &str) -> (Option<&str>, &str) {
+    if let Some(index) = location.find(".zip/") {
+        (Some(&location[..index + 4]), &location[index + 5..])
+    } else if location.ends_with(".zip") {
+        (Some(location), "")
+    } else {
+        (None, location)
+    }
+}
+
+/// Returns the location of `path` relative to the manifest directory. Paths in
+/// archives are located through the name of their [ZipFileSystem], which is
+/// the location of the archive.
+async fn issuer_location(
+    manifest_dir: Vc<FileSystemPath>,
+    path: Vc<FileSystemPath>,
+) -> Result<Option<String>> {
+    let manifest_dir = manifest_dir.await?;
+    let path_value = path.await?;
+    if let Some(zip_fs) = Vc::try_resolve_downcast_type::<ZipFileSystem>(path.fs()).await? {
+        if zip_fs.archive().await?.fs != manifest_dir.fs {
+            return Ok(None);
+        }
+        let archive_location = zip_fs.to_string().await?;
+        return Ok(Some(if path_value.path.is_empty() {
+            archive_location.to_string()
+        } else {
+            format!("{archive_location}/{}", path_value.path)
+        }));
+    }
+    Ok(manifest_dir
+        .get_path_to(&path_value)
+        .map(|path| path.to_string()))
+}
+
+/// Returns the directory of the package at `location`. Packages in archives
+/// get a [ZipFileSystem] per virtual location, so that each instance resolves
+/// its own peer dependencies.
+async fn package_dir(
+    manifest_dir: Vc<FileSystemPath>,
+    location: &RcStr,
+) -> Result<Option<ResolvedVc<FileSystemPath>>> {
+    let (archive, path) = split_archive(location);
+    let Some(archive) = archive else {
+        return Ok(*manifest_dir
+            .try_join(resolve_virtual_location(location).into())
+            .await?);
+    };
+    let Some(archive_path) = *manifest_dir
+        .try_join(resolve_virtual_location(archive).into())
+        .await?
+    else {
+        return Ok(None);
+    };
+    let zip_fs = ZipFileSystem::new(archive.into(), *archive_path);
+    Ok(Some(zip_fs.root().join(path.into()).to_resolved().await?))
+}
+
+/// Finds packages through the manifest of a Yarn Plug'n'Play install, including
+/// packages inside of the zip archives in the Yarn cache, without unplugging
+/// them. Paths outside of the dependency tree are left to the other module
+/// locations.
+#[turbo_tasks::value]
+pub struct PnpPackageLookupPlugin {
+    manifest_path: ResolvedVc<FileSystemPath>,
+}
+
+#[turbo_tasks::value_impl]
+impl PnpPackageLookupPlugin {
+    #[turbo_tasks::function]
+    pub fn new(manifest_path: ResolvedVc<FileSystemPath>) -> Vc<Self> {
+        PnpPackageLookupPlugin { manifest_path }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl PackageLookupPlugin for PnpPackageLookupPlugin {
+    #[turbo_tasks::function]
+    async fn find_package(
+        &self,
+        lookup_path: Vc<FileSystemPath>,
+        package_name: RcStr,
+    ) -> Result<Vc<PackageLookupResult>> {
+        let manifest_dir = self.manifest_path.parent();
+        let Some(issuer_path) = issuer_location(manifest_dir, lookup_path).await? else {
+            return Ok(PackageLookupResult::Unhandled.cell());
+        };
+        let manifest = pnp_manifest(*self.manifest_path).await?;
+        if let Some(ignore_pattern) = &manifest.ignore_pattern {
+            let ignore_pattern = Regex::new(ignore_pattern)
+                .context("invalid ignorePatternData in the Yarn Plug'n'Play manifest")?;
+            if ignore_pattern.is_match(&issuer_path) {
+                return Ok(PackageLookupResult::Unhandled.cell());
+            }
+        }
+        let Some(issuer) = manifest.find_locator(&issuer_path) else {
+            return Ok(PackageLookupResult::Unhandled.cell());
+        };
+
+        let lookup_path = lookup_path.to_resolved().await?;
+        let affecting_sources = vec![ResolvedVc::upcast(
+            FileSource::new(*self.manifest_path).to_resolved().await?,
+        )];
+        let not_found = |message: String| -> Result<Vc<PackageLookupResult>> {
+            PnpIssue {
+                lookup_path,
+                message: message.into(),
+            }
+            .resolved_cell()
+            .emit();
+            Ok(PackageLookupResult::NotFound {
+                affecting_sources: affecting_sources.clone(),
+            }
+            .cell())
+        };
+
+        let locator = match manifest.find_dependency(issuer, &package_name) {
+            PnpDependency::Found(locator) => locator,
+            PnpDependency::MissingPeer => {
+                return not_found(format!(
+                    "{issuer} tried to access {package_name}, a peer dependency, but it isn't \
+                     provided by its ancestors. Add {package_name} to the dependencies of the \
+                     package depending on {issuer}."
+                ));
+            }
+            PnpDependency::Undeclared => {
+                return not_found(format!(
+                    "{issuer} tried to access {package_name}, but it isn't declared in its \
+                     dependencies. Add {package_name} to its dependencies, or use \
+                     packageExtensions in .yarnrc.yml for third-party packages."
+                ));
+            }
+        };
+        let Some(package) = manifest.packages.get(locator) else {
+            return not_found(format!(
+                "{locator}, the {package_name} dependency of {issuer}, is missing from the \
+                 manifest. Try running `yarn install`."
+            ));
+        };
+        let Some(package_dir) = package_dir(manifest_dir, &package.location).await? else {
+            return not_found(format!(
+                "{locator} is located outside of the project at {}. Set `enableGlobalCache: \
+                 false` in .yarnrc.yml to keep the Yarn cache inside of the project.",
+                package.location
+            ));
+        };
+
+        Ok(PackageLookupResult::Found {
+            package_dir,
+            affecting_sources,
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::value(shared)]
+struct PnpIssue {
+    lookup_path: ResolvedVc<FileSystemPath>,
+    message: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for PnpIssue {
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("Package not available through Yarn Plug'n'Play".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        *self.lookup_path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(self.message.clone()).resolved_cell(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_inlined_manifest() {
+        let script = r#"#!/usr/bin/env node
+/* eslint-disable */
+"use strict";
+
+const RAW_RUNTIME_STATE =
+'{\
+  "__info": [],\
+  "ignorePatternData": "(^(?:\\\\.yarn\\\\/sdks)$)",\
+  "name": "it\'s"\
+}';
+"#;
+        let manifest = extract_inlined_manifest(script).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+        assert_eq!(json["name"], "it's");
+        assert_eq!(json["ignorePatternData"], r"(^(?:\.yarn\/sdks)$)");
+    }
+
+    #[test]
+    fn resolves_virtual_locations() {
+        assert_eq!(
+            resolve_virtual_location(
+                ".yarn/__virtual__/react-dom-virtual-c2a2dc1d34/0/cache/react-dom-npm-18.2.\
+                 0-dd675bca1c-7d323310be.zip/node_modules/react-dom"
+            ),
+            ".yarn/cache/react-dom-npm-18.2.0-dd675bca1c-7d323310be.zip/node_modules/react-dom"
+        );
+        assert_eq!(
+            resolve_virtual_location(".yarn/__virtual__/lib-virtual-8a3c2e/1/packages/lib"),
+            ".yarn/../packages/lib"
+        );
+        assert_eq!(
+            split_archive(".yarn/cache/react-npm-18.2.0-1a2b3c.zip/node_modules/react"),
+            (
+                Some(".yarn/cache/react-npm-18.2.0-1a2b3c.zip"),
+                "node_modules/react"
+            )
+        );
+        assert_eq!(split_archive("packages/lib"), (None, "packages/lib"));
+    }
+
+    #[test]
+    fn finds_dependencies() {
+        let manifest: SerializedPnpManifest = serde_json::from_value(serde_json::json!({
+            "enableTopLevelFallback": true,
+            "fallbackPool": [["lodash", "npm:4.17.21"]],
+            "fallbackExclusionList": [["app", ["workspace:packages/app"]]],
+            "packageRegistryData": [
+                [null, [[null, {
+                    "packageLocation": "./",
+                    "packageDependencies": [["app", "workspace:packages/app"]],
+                }]]],
+                ["app", [["workspace:packages/app", {
+                    "packageLocation": "./packages/app/",
+                    "packageDependencies": [
+                        ["react", "npm:18.2.0"],
+                        ["underscore", ["lodash", "npm:4.17.21"]],
+                    ],
+                }]]],
+                ["react", [["npm:18.2.0", {
+                    "packageLocation": "./.yarn/cache/react-npm-18.2.0-1a2b3c.zip/node_modules/react/",
+                    "packageDependencies": [["react", "npm:18.2.0"], ["scheduler", null]],
+                }]]],
+                ["lodash", [["npm:4.17.21", {
+                    "packageLocation": "./.yarn/cache/lodash-npm-4.17.21-6382451519.zip/node_modules/lodash/",
+                }]]],
+            ],
+        }))
+        .unwrap();
+        let manifest = parse_manifest(manifest);
+
+        let app = manifest.find_locator("packages/app/src/index.js").unwrap();
+        assert_eq!(app.name, "app");
+        let PnpDependency::Found(underscore) = manifest.find_dependency(app, "underscore") else {
+            panic!("expected the aliased dependency to be found");
+        };
+        assert_eq!(underscore.name, "lodash");
+        assert!(matches!(
+            manifest.find_dependency(app, "lodash"),
+            PnpDependency::Undeclared
+        ));
+
+        let react = manifest
+            .find_locator(".yarn/cache/react-npm-18.2.0-1a2b3c.zip/node_modules/react/index.js")
+            .unwrap();
+        assert_eq!(react.name, "react");
+        assert!(matches!(
+            manifest.find_dependency(react, "scheduler"),
+            PnpDependency::MissingPeer
+        ));
+        assert!(matches!(
+            manifest.find_dependency(react, "lodash"),
+            PnpDependency::Found(_)
+        ));
+
+        let root = manifest.find_locator("scripts/build.js").unwrap();
+        assert_eq!(root.to_string(), "the top-level workspace");
+    }
+}
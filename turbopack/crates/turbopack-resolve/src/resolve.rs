@@ -1,5 +1,5 @@
 use anyhow::Result;
-use turbo_tasks::Vc;
+use turbo_tasks::{ResolvedVc, Vc};
 use turbo_tasks_fs::{FileSystem, FileSystemPath};
 use turbopack_core::resolve::{
     find_context_file,
@@ -11,6 +11,8 @@ use turbopack_core::resolve::{
 };
 
 use crate::{
+    package_store::PackageStoreLookupPlugin,
+    pnp::{pnp_manifest_files, PnpPackageLookupPlugin},
     resolve_options_context::ResolveOptionsContext,
     typescript::{apply_tsconfig_resolve_options, tsconfig, tsconfig_resolve_options},
 };
@@ -219,16 +221,32 @@ async fn base_resolve_options(
     Ok(ResolveOptions {
         extensions,
         modules: if let Some(environment) = emulating {
+            let mut mods = opt.modules.clone();
             if *environment.resolve_node_modules().await? {
-                vec![ResolveModules::Nested(
+                mods.push(ResolveModules::Nested(
                     root.to_resolved().await?,
                     vec!["node_modules".into()],
-                )]
-            } else {
-                Vec::new()
+                ));
             }
+            mods
         } else {
-            let mut mods = Vec::new();
+            let mut mods = opt.modules.clone();
+            if let Some(store) = opt.package_store {
+                mods.push(ResolveModules::Plugin(ResolvedVc::upcast(
+                    PackageStoreLookupPlugin::new(*store).to_resolved().await?,
+                )));
+            }
+            if let Some(dir) = opt.enable_yarn_pnp {
+                if let FindContextFileResult::Found(manifest_path, _) =
+                    &*find_context_file(*dir, pnp_manifest_files()).await?
+                {
+                    mods.push(ResolveModules::Plugin(ResolvedVc::upcast(
+                        PnpPackageLookupPlugin::new(**manifest_path)
+                            .to_resolved()
+                            .await?,
+                    )));
+                }
+            }
             if let Some(dir) = opt.enable_node_modules {
                 mods.push(ResolveModules::Nested(dir, vec!["node_modules".into()]));
             }
@@ -270,6 +288,27 @@ async fn base_resolve_options(
         plugins,
         before_resolve_plugins: opt.before_resolve_plugins.clone(),
         loose_errors: opt.loose_errors,
+        preserve_symlinks: opt.preserve_symlinks,
+        case_sensitive_paths: opt.case_sensitive_paths,
+        restricted_imports: opt.restricted_imports.clone(),
+        remote_imports: opt.remote_imports,
+        pnpm_injected_from_source: opt.pnpm_injected_from_source,
+        package_conditions: opt
+            .package_conditions
+            .iter()
+            .map(|(package, package_conditions)| {
+                let mut conditions: ResolutionConditions = [
+                    ("import".into(), ConditionValue::Unknown),
+                    ("require".into(), ConditionValue::Unknown),
+                ]
+                .into_iter()
+                .collect();
+                for condition in package_conditions.iter() {
+                    conditions.insert(condition.clone(), ConditionValue::Set);
+                }
+                (package.clone(), conditions)
+            })
+            .collect(),
         ..Default::default()
     }
     .into())
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{ResolvedVc, ValueDefault, Vc};
@@ -5,12 +7,15 @@ use turbo_tasks_fs::FileSystemPath;
 use turbopack_core::{
     condition::ContextCondition,
     environment::Environment,
+    remote_source::RemoteImports,
     resolve::{
-        options::{ImportMap, ResolvedMap},
+        options::{ImportMap, ResolveModules, ResolvedMap, RestrictedImport},
         plugin::{AfterResolvePlugin, BeforeResolvePlugin},
     },
 };
 
+use crate::package_store::PackageStore;
+
 #[turbo_tasks::value(shared, local)]
 #[derive(Default, Clone)]
 pub struct ResolveOptionsContext {
@@ -32,6 +37,20 @@ pub struct ResolveOptionsContext {
     /// directory
     pub enable_node_modules: Option<ResolvedVc<FileSystemPath>>,
     #[serde(default)]
+    /// Enable resolving packages through the Yarn Plug'n'Play manifest found
+    /// in the provided directory or one of its parents, if any
+    pub enable_yarn_pnp: Option<ResolvedVc<FileSystemPath>>,
+    #[serde(default)]
+    /// Enable resolving the packages of a lockfile from a global
+    /// content-addressed store, before the Yarn Plug'n'Play manifest and the
+    /// node_modules folders
+    pub package_store: Option<ResolvedVc<PackageStore>>,
+    #[serde(default)]
+    /// Additional locations to resolve packages in, searched in order before
+    /// the package store, the Yarn Plug'n'Play manifest and the node_modules
+    /// folders
+    pub modules: Vec<ResolveModules>,
+    #[serde(default)]
     /// Mark well-known Node.js modules as external imports and load them using
     /// native `require`. e.g. url, querystring, os
     pub enable_node_externals: bool,
@@ -49,6 +68,11 @@ pub struct ResolveOptionsContext {
     #[serde(default)]
     pub custom_extensions: Option<Vec<RcStr>>,
     #[serde(default)]
+    /// Conditions to resolve the exports and imports fields of specific
+    /// packages with, replacing the conditions derived from the other options.
+    /// Keyed by package name.
+    pub package_conditions: BTreeMap<RcStr, Vec<RcStr>>,
+    #[serde(default)]
     /// An additional import map to use when resolving modules.
     ///
     /// If set, this import map will be applied to `ResolveOption::import_map`.
@@ -75,6 +99,24 @@ pub struct ResolveOptionsContext {
     pub before_resolve_plugins: Vec<ResolvedVc<Box<dyn BeforeResolvePlugin>>>,
     /// Warn instead of error for resolve errors
     pub loose_errors: bool,
+    #[serde(default)]
+    /// Keep symlinked paths of resolved modules instead of following them to
+    /// the real path.
+    pub preserve_symlinks: bool,
+    #[serde(default)]
+    /// Emit an issue when the casing of a resolved path differs from the
+    /// entries on disk.
+    pub case_sensitive_paths: bool,
+    #[serde(default)]
+    /// Imports that are forbidden from some modules.
+    pub restricted_imports: Vec<RestrictedImport>,
+    #[serde(default)]
+    /// Fetch modules imported from `https://` URLs.
+    pub remote_imports: Option<ResolvedVc<RemoteImports>>,
+    #[serde(default)]
+    /// Read the files of pnpm's injected dependencies from the workspace
+    /// package they were copied from.
+    pub pnpm_injected_from_source: bool,
 
     #[serde(default)]
     pub placeholder_for_future_extensions: (),
@@ -1,10 +1,14 @@
-use std::{collections::HashMap, fmt::Write, mem::take};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Write,
+    mem::take,
+};
 
 use anyhow::Result;
 use serde_json::Value as JsonValue;
 use turbo_rcstr::RcStr;
 use turbo_tasks::{fxindexset, ResolvedVc, Value, ValueDefault, Vc};
-use turbo_tasks_fs::{FileContent, FileJsonContent, FileSystemPath};
+use turbo_tasks_fs::{FileContent, FileJsonContent, FileSystemEntryType, FileSystemPath};
 use turbopack_core::{
     asset::Asset,
     context::AssetContext,
@@ -261,6 +265,45 @@ pub async fn tsconfig_resolve_options(
     };
 
     let mut all_paths = HashMap::new();
+    // The aliases of referenced projects have a lower precedence than the ones
+    // of the project itself.
+    for reference_configs in read_tsconfig_references(&configs).await? {
+        collect_tsconfig_paths(&reference_configs, &mut all_paths).await?;
+    }
+    collect_tsconfig_paths(&configs, &mut all_paths).await?;
+
+    let import_map = if !all_paths.is_empty() {
+        let mut import_map = ImportMap::empty();
+        for (key, value) in all_paths {
+            import_map.insert_alias(AliasPattern::parse(key), value.resolved_cell());
+        }
+        Some(import_map.resolved_cell())
+    } else {
+        None
+    };
+
+    let is_module_resolution_nodenext = read_from_tsconfigs(&configs, |json, _| {
+        json["compilerOptions"]["moduleResolution"]
+            .as_str()
+            .map(|module_resolution| module_resolution.eq_ignore_ascii_case("nodenext"))
+    })
+    .await?
+    .unwrap_or_default();
+
+    Ok(TsConfigResolveOptions {
+        base_url,
+        import_map,
+        is_module_resolution_nodenext,
+    }
+    .cell())
+}
+
+/// Collects the `compilerOptions.paths` aliases of the `configs`, overriding
+/// the aliases collected before.
+async fn collect_tsconfig_paths(
+    configs: &[TsConfig],
+    all_paths: &mut HashMap<String, ImportMapping>,
+) -> Result<()> {
     for (content, source) in configs.iter().rev() {
         if let FileJsonContent::Content(json) = &*content.await? {
             if let JsonValue::Object(paths) = &json["compilerOptions"]["paths"] {
@@ -292,9 +335,10 @@ pub async fn tsconfig_resolve_options(
                                 })
                             })
                             .collect();
+                        // Like tsc, the first of the targets which resolves is used
                         all_paths.insert(
                             key.to_string(),
-                            ImportMapping::primary_alternatives(entries, Some(context_dir)),
+                            ImportMapping::primary_fallbacks(entries, Some(context_dir)),
                         );
                     } else {
                         TsConfigIssue {
@@ -315,31 +359,86 @@ pub async fn tsconfig_resolve_options(
             }
         }
     }
+    Ok(())
+}
 
-    let import_map = if !all_paths.is_empty() {
-        let mut import_map = ImportMap::empty();
-        for (key, value) in all_paths {
-            import_map.insert_alias(AliasPattern::parse(key), value.resolved_cell());
+/// Reads the tsconfigs of the projects in the `references` of the tsconfig
+/// and, transitively, of the referenced projects. `references` aren't
+/// inherited through `extends`.
+async fn read_tsconfig_references(configs: &[TsConfig]) -> Result<Vec<Vec<TsConfig>>> {
+    let mut references = Vec::new();
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    if let Some((_, source)) = configs.first() {
+        visited.insert(source.ident().path().to_resolved().await?);
+        queue.push_back(configs.to_vec());
+    }
+    while let Some(configs) = queue.pop_front() {
+        let Some((content, source)) = configs.first() else {
+            continue;
+        };
+        if let FileJsonContent::Content(json) = &*content.await? {
+            if let JsonValue::Array(reference_list) = &json["references"] {
+                for reference in reference_list {
+                    let Some(reference_path) = reference["path"].as_str() else {
+                        TsConfigIssue {
+                            severity: IssueSeverity::Warning.resolved_cell(),
+                            source_ident: source.ident().to_resolved().await?,
+                            message: format!(
+                                "references contains an entry without a \"path\"\n{reference:#}"
+                            )
+                            .into(),
+                        }
+                        .resolved_cell()
+                        .emit();
+                        continue;
+                    };
+                    let Some(path) = *source
+                        .ident()
+                        .path()
+                        .parent()
+                        .try_join(reference_path.into())
+                        .await?
+                    else {
+                        continue;
+                    };
+                    // A reference points to a tsconfig or a directory containing a
+                    // tsconfig.json
+                    let path = if matches!(&*path.get_type().await?, FileSystemEntryType::Directory)
+                    {
+                        path.join("tsconfig.json".into()).to_resolved().await?
+                    } else {
+                        path
+                    };
+                    if !visited.insert(path) {
+                        continue;
+                    }
+                    if !matches!(&*path.get_type().await?, FileSystemEntryType::File) {
+                        TsConfigIssue {
+                            severity: IssueSeverity::Warning.resolved_cell(),
+                            source_ident: source.ident().to_resolved().await?,
+                            message: format!(
+                                "references: \"{reference_path}\" doesn't point to a tsconfig"
+                            )
+                            .into(),
+                        }
+                        .resolved_cell()
+                        .emit();
+                        continue;
+                    }
+                    let reference_configs = read_tsconfigs(
+                        path.read(),
+                        ResolvedVc::upcast(FileSource::new(*path).to_resolved().await?),
+                        node_cjs_resolve_options(path.root()),
+                    )
+                    .await?;
+                    references.push(reference_configs.clone());
+                    queue.push_back(reference_configs);
+                }
+            }
         }
-        Some(import_map.resolved_cell())
-    } else {
-        None
-    };
-
-    let is_module_resolution_nodenext = read_from_tsconfigs(&configs, |json, _| {
-        json["compilerOptions"]["moduleResolution"]
-            .as_str()
-            .map(|module_resolution| module_resolution.eq_ignore_ascii_case("nodenext"))
-    })
-    .await?
-    .unwrap_or_default();
-
-    Ok(TsConfigResolveOptions {
-        base_url,
-        import_map,
-        is_module_resolution_nodenext,
     }
-    .cell())
+    Ok(references)
 }
 
 #[turbo_tasks::function]
@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use base64::{display::Base64Display, engine::general_purpose::STANDARD};
+use turbo_rcstr::RcStr;
+use turbo_tasks::Vc;
+use turbo_tasks_fs::{FileContent, FileSystemPath};
+use turbopack_core::asset::AssetContent;
+
+/// Sizes below which static assets are inlined as `data:` URLs into the JS
+/// and CSS referencing them instead of being emitted, like webpack's
+/// `url-loader`.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug, Default)]
+pub struct AssetInlineLimit {
+    /// The limit in bytes for extensions without a limit of their own.
+    pub default: Option<u64>,
+    /// The limits in bytes by file extension, without the leading dot, e.g.
+    /// `svg`. A limit of `0` disables inlining for the extension.
+    pub extensions: BTreeMap<RcStr, u64>,
+}
+
+impl AssetInlineLimit {
+    pub fn limit_for(&self, extension: Option<&str>) -> Option<u64> {
+        extension
+            .and_then(|extension| self.extensions.get(extension))
+            .copied()
+            .or(self.default)
+    }
+}
+
+/// Returns the `data:` URL of the asset at `path` with `content` when it's
+/// smaller than the limit for its extension.
+pub(crate) async fn inlined_url(
+    limit: &AssetInlineLimit,
+    path: Vc<FileSystemPath>,
+    content: Vc<AssetContent>,
+) -> Result<Option<RcStr>> {
+    let path = path.await?;
+    let Some(limit) = limit.limit_for(path.extension_ref()) else {
+        return Ok(None);
+    };
+    let AssetContent::File(file) = &*content.await? else {
+        return Ok(None);
+    };
+    let FileContent::Content(file) = &*file.await? else {
+        return Ok(None);
+    };
+    let bytes = file.content().to_bytes()?;
+    if bytes.len() as u64 >= limit {
+        return Ok(None);
+    }
+    let mime = mime_guess::from_path(&*path.path).first_or_octet_stream();
+    Ok(Some(
+        format!(
+            "data:{mime};base64,{}",
+            Base64Display::new(&bytes, &STANDARD)
+        )
+        .into(),
+    ))
+}
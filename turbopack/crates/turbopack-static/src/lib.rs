@@ -7,12 +7,16 @@
 //!
 //! When referred to from CSS assets, the reference is replaced with the asset's
 //! path.
+//!
+//! Assets below the configured [AssetInlineLimit] are not emitted, they are
+//! inlined as `data:` URLs in place of their path instead.
 
 #![feature(min_specialization)]
 #![feature(arbitrary_self_types)]
 #![feature(arbitrary_self_types_pointers)]
 
 pub mod fixed;
+pub mod inline;
 pub mod output_asset;
 
 use anyhow::Result;
@@ -36,7 +40,10 @@ use turbopack_ecmascript::{
     utils::StringifyJs,
 };
 
-use self::output_asset::StaticAsset;
+use self::{
+    inline::{inlined_url, AssetInlineLimit},
+    output_asset::StaticAsset,
+};
 
 #[turbo_tasks::function]
 fn modifier() -> Vc<RcStr> {
@@ -48,6 +55,7 @@ fn modifier() -> Vc<RcStr> {
 pub struct StaticModuleAsset {
     pub source: ResolvedVc<Box<dyn Source>>,
     pub asset_context: ResolvedVc<Box<dyn AssetContext>>,
+    pub inline_limit: Option<ResolvedVc<AssetInlineLimit>>,
 }
 
 #[turbo_tasks::value_impl]
@@ -60,9 +68,41 @@ impl StaticModuleAsset {
         Self::cell(StaticModuleAsset {
             source,
             asset_context,
+            inline_limit: None,
         })
     }
 
+    /// Like [StaticModuleAsset::new], inlining the asset as a `data:` URL when
+    /// it's below `inline_limit`.
+    #[turbo_tasks::function]
+    pub fn new_with_inline_limit(
+        source: ResolvedVc<Box<dyn Source>>,
+        asset_context: ResolvedVc<Box<dyn AssetContext>>,
+        inline_limit: ResolvedVc<AssetInlineLimit>,
+    ) -> Vc<Self> {
+        Self::cell(StaticModuleAsset {
+            source,
+            asset_context,
+            inline_limit: Some(inline_limit),
+        })
+    }
+
+    /// The `data:` URL of the asset when it's inlined instead of emitted.
+    #[turbo_tasks::function]
+    pub async fn inlined_url(&self) -> Result<Vc<Option<RcStr>>> {
+        let Some(inline_limit) = self.inline_limit else {
+            return Ok(Vc::cell(None));
+        };
+        Ok(Vc::cell(
+            inlined_url(
+                &*inline_limit.await?,
+                self.source.ident().path(),
+                self.source.content(),
+            )
+            .await?,
+        ))
+    }
+
     #[turbo_tasks::function]
     async fn static_asset(
         &self,
@@ -132,8 +172,11 @@ impl ChunkItem for ModuleChunkItem {
     }
 
     #[turbo_tasks::function]
-    fn references(&self) -> Vc<OutputAssets> {
-        Vc::cell(vec![ResolvedVc::upcast(self.static_asset)])
+    async fn references(&self) -> Result<Vc<OutputAssets>> {
+        if self.module.inlined_url().await?.is_some() {
+            return Ok(OutputAssets::empty());
+        }
+        Ok(Vc::cell(vec![ResolvedVc::upcast(self.static_asset)]))
     }
 
     #[turbo_tasks::function]
@@ -163,15 +206,18 @@ impl EcmascriptChunkItem for ModuleChunkItem {
 
     #[turbo_tasks::function]
     async fn content(&self) -> Result<Vc<EcmascriptChunkItemContent>> {
+        let url = match &*self.module.inlined_url().await? {
+            Some(url) => url.clone(),
+            None => self
+                .chunking_context
+                .asset_url(self.static_asset.ident())
+                .await?
+                .clone_value(),
+        };
         Ok(EcmascriptChunkItemContent {
             inner_code: format!(
                 "__turbopack_export_value__({path});",
-                path = StringifyJs(
-                    &self
-                        .chunking_context
-                        .asset_url(self.static_asset.ident())
-                        .await?
-                )
+                path = StringifyJs(&url)
             )
             .into(),
             ..Default::default()
@@ -186,6 +232,11 @@ impl CssEmbed for ModuleChunkItem {
     fn embedded_asset(&self) -> Vc<Box<dyn OutputAsset>> {
         *ResolvedVc::upcast(self.static_asset)
     }
+
+    #[turbo_tasks::function]
+    fn inlined_url(&self) -> Vc<Option<RcStr>> {
+        self.module.inlined_url()
+    }
 }
 
 pub fn register() {
@@ -5,9 +5,9 @@
 
 mod util;
 
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use dunce::canonicalize;
 use serde::{Deserialize, Serialize};
 use turbo_rcstr::RcStr;
@@ -19,15 +19,27 @@ use turbo_tasks_bytes::stream::SingleValue;
 use turbo_tasks_env::CommandLineProcessEnv;
 use turbo_tasks_fs::{
     json::parse_json_with_source_context, util::sys_to_unix, DiskFileSystem, FileContent,
-    FileSystem, FileSystemEntryType, FileSystemPath,
+    FileJsonContent, FileSystem, FileSystemEntryType, FileSystemPath,
 };
 use turbo_tasks_memory::MemoryBackend;
 use turbopack::{
-    ecmascript::TreeShakingMode,
-    module_options::{EcmascriptOptionsContext, ModuleOptionsContext, TypescriptTransformOptions},
+    ecmascript::{
+        federation::{
+            container_code,
+            remote_module::RemoteModule,
+            resolve::{ModuleFederationResolvePlugin, REMOTE_MODULE_EXTENSION},
+            ModuleFederationOptions, SharedModule,
+        },
+        TreeShakingMode,
+    },
+    module_options::{
+        CustomModuleType, EcmascriptOptionsContext, ModuleOptionsContext, ModuleRule,
+        ModuleRuleEffect, ModuleType, RuleCondition, TypescriptTransformOptions,
+    },
     ModuleAssetContext,
 };
 use turbopack_core::{
+    asset::AssetContent,
     compile_time_defines,
     compile_time_info::CompileTimeInfo,
     condition::ContextCondition,
@@ -35,12 +47,14 @@ use turbopack_core::{
     environment::{Environment, ExecutionEnvironment, NodeJsEnvironment},
     file_source::FileSource,
     issue::{Issue, IssueDescriptionExt},
+    module::Module,
     reference_type::{InnerAssets, ReferenceType},
     resolve::{
         options::{ImportMap, ImportMapping},
-        ExternalTraced, ExternalType,
+        ExternalTraced, ExternalType, ModulePart, ResolveResult,
     },
     source::Source,
+    virtual_source::VirtualSource,
 };
 use turbopack_ecmascript_runtime::RuntimeType;
 use turbopack_node::{debug::should_debug, evaluate::evaluate};
@@ -207,6 +221,70 @@ async fn run_inner(
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 struct TestOptions {
     tree_shaking_mode: Option<TreeShakingMode>,
+    /// Conditions to resolve the exports and imports fields of packages with,
+    /// keyed by package name.
+    #[serde(default)]
+    package_conditions: BTreeMap<RcStr, Vec<RcStr>>,
+    module_federation: Option<ModuleFederationTestOptions>,
+}
+
+/// Module federation options of a test. The container of the test, exposing
+/// `exposes`, can be imported as `federation-container`.
+#[derive(
+    PartialEq, Eq, Debug, Serialize, Deserialize, TraceRawVcs, ValueDebugFormat, NonLocalValue,
+)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ModuleFederationTestOptions {
+    name: RcStr,
+    /// Exposed modules, by their request, to their path relative to `input`.
+    #[serde(default)]
+    exposes: BTreeMap<RcStr, RcStr>,
+    #[serde(default)]
+    remotes: BTreeMap<RcStr, RcStr>,
+    #[serde(default)]
+    shared: BTreeMap<RcStr, SharedModuleTestOptions>,
+}
+
+#[derive(
+    PartialEq, Eq, Debug, Serialize, Deserialize, TraceRawVcs, ValueDebugFormat, NonLocalValue,
+)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct SharedModuleTestOptions {
+    version: RcStr,
+    required_version: Option<RcStr>,
+    #[serde(default)]
+    singleton: bool,
+}
+
+/// Turns the sources created by the [ModuleFederationResolvePlugin] for
+/// modules of remotes into [RemoteModule]s, like Next.js does.
+#[turbo_tasks::value]
+struct RemoteModuleType {}
+
+#[turbo_tasks::value_impl]
+impl CustomModuleType for RemoteModuleType {
+    #[turbo_tasks::function]
+    async fn create_module(
+        &self,
+        source: Vc<Box<dyn Source>>,
+        _module_asset_context: Vc<ModuleAssetContext>,
+        _part: Option<Vc<ModulePart>>,
+    ) -> Result<Vc<Box<dyn Module>>> {
+        let FileJsonContent::Content(json) = &*source.content().parse_json().await? else {
+            bail!("Invalid module federation remote module");
+        };
+        let field = |name: &str| -> Result<RcStr> {
+            Ok(json[name]
+                .as_str()
+                .with_context(|| format!("Module federation remote module is missing `{name}`"))?
+                .into())
+        };
+        Ok(Vc::upcast(RemoteModule::new(
+            field("remote")?,
+            field("entry")?,
+            field("module")?,
+        )))
+    }
 }
 
 #[turbo_tasks::value]
@@ -325,6 +403,71 @@ async fn run_test(prepared_test: Vc<PreparedTest>) -> Result<Vc<RunTestResult>>
             .resolved_cell(),
     );
 
+    let mut before_resolve_plugins = vec![];
+    let mut module_rules = vec![];
+    if let Some(federation) = &options.module_federation {
+        let federation_options = ModuleFederationOptions {
+            name: Some(federation.name.clone()),
+            exposes: federation
+                .exposes
+                .iter()
+                .map(|(module, path)| (module.clone(), path.clone()))
+                .collect(),
+            remotes: federation
+                .remotes
+                .iter()
+                .map(|(name, entry)| (name.clone(), entry.clone()))
+                .collect(),
+            shared: federation
+                .shared
+                .iter()
+                .map(|(name, shared)| {
+                    (
+                        name.clone(),
+                        SharedModule {
+                            version: shared.version.clone(),
+                            required_version: shared.required_version.clone(),
+                            singleton: shared.singleton,
+                        },
+                    )
+                })
+                .collect(),
+        }
+        .cell();
+        before_resolve_plugins.push(ResolvedVc::upcast(
+            ModuleFederationResolvePlugin::new(*project_path, federation_options)
+                .to_resolved()
+                .await?,
+        ));
+        module_rules.push(ModuleRule::new(
+            RuleCondition::ResourcePathEndsWith(REMOTE_MODULE_EXTENSION.to_string()),
+            vec![ModuleRuleEffect::ModuleType(ModuleType::Custom(
+                ResolvedVc::upcast(RemoteModuleType {}.resolved_cell()),
+            ))],
+        ));
+
+        // The container is placed in `input`, so exposed modules are imported
+        // relative to it.
+        let container = VirtualSource::new(
+            project_path.join("input/container.js".into()),
+            AssetContent::file(
+                FileContent::Content(
+                    container_code(&federation.name, &federation_options.await?.exposes)?.into(),
+                )
+                .cell(),
+            ),
+        )
+        .to_resolved()
+        .await?;
+        import_map.insert_exact_alias(
+            "federation-container",
+            ImportMapping::Direct(
+                ResolveResult::source(ResolvedVc::upcast(container)).resolved_cell(),
+            )
+            .resolved_cell(),
+        );
+    }
+
     let asset_context: Vc<Box<dyn AssetContext>> = Vc::upcast(ModuleAssetContext::new(
         Default::default(),
         compile_time_info,
@@ -338,6 +481,7 @@ async fn run_test(prepared_test: Vc<PreparedTest>) -> Result<Vc<RunTestResult>>
             },
             preset_env_versions: Some(env),
             tree_shaking_mode: options.tree_shaking_mode,
+            module_rules,
             rules: vec![(
                 ContextCondition::InDirectory("node_modules".into()),
                 ModuleOptionsContext {
@@ -358,14 +502,17 @@ async fn run_test(prepared_test: Vc<PreparedTest>) -> Result<Vc<RunTestResult>>
                 ResolveOptionsContext {
                     enable_node_modules: Some(project_root),
                     custom_conditions: vec!["development".into()],
+                    package_conditions: options.package_conditions.clone(),
                     browser: true,
                     ..Default::default()
                 }
                 .resolved_cell(),
             )],
+            package_conditions: options.package_conditions.clone(),
             browser: true,
             module: true,
             import_map: Some(import_map.resolved_cell()),
+            before_resolve_plugins,
             ..Default::default()
         }
         .cell(),
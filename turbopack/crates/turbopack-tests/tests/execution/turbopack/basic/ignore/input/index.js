@@ -1,6 +1,12 @@
-import { file, file2 } from "package";
+import { file, file2, file3, file3Namespace, named } from "package";
 
 it("should ignore the package", async () => {
   await expect(file).resolves.toEqual({});
   expect(file2).toEqual({});
 });
+
+it("should import an ignored file like an empty module", () => {
+  expect(file3).toEqual({});
+  expect(file3Namespace.default).toEqual({});
+  expect(named).toBeUndefined();
+});
@@ -0,0 +1,5 @@
+import sharedLib from "shared-lib";
+
+export default "app1 button";
+
+export const sharedLibVersion = sharedLib.version;
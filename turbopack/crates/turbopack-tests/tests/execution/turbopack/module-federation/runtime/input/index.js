@@ -0,0 +1,105 @@
+import sharedLib from "shared-lib";
+
+// Installed by the modules loading shared modules and remotes
+const federation = globalThis.__TURBOPACK_FEDERATION__;
+
+// Registers the `versions` of the shared module `name` other builds provide,
+// and returns the version selected for this build.
+function select(name, versions, options) {
+  for (const version of versions) {
+    federation.registerShared("default", name, version, () => version);
+  }
+  const fallback = () => options.version;
+  return federation.loadShared("default", name, options, fallback);
+}
+
+it("uses the shared modules of this build without other builds", () => {
+  expect(federation).toBeDefined();
+  expect(sharedLib.version).toBe("1.2.0");
+});
+
+it("uses the highest version of a singleton any build provides", () => {
+  federation.registerShared("default", "singleton-lib", "1.5.0", () => ({
+    version: "1.5.0",
+  }));
+  expect(require("singleton-lib").version).toBe("1.5.0");
+});
+
+it("keeps using the version of a singleton which was used first", () => {
+  const warnings = [];
+  const warn = console.warn;
+  console.warn = (message) => warnings.push(message);
+  try {
+    expect(
+      select("singleton", ["1.0.0"], { version: "1.0.0", singleton: true })
+    ).toBe("1.0.0");
+    expect(
+      select("singleton", ["2.0.0"], {
+        version: "2.0.0",
+        requiredVersion: "^2.0.0",
+        singleton: true,
+      })
+    ).toBe("1.0.0");
+  } finally {
+    console.warn = warn;
+  }
+  expect(warnings).toEqual([
+    "Shared singleton singleton is used in version 1.0.0, which doesn't satisfy the required version ^2.0.0",
+  ]);
+});
+
+it("uses the highest version satisfying the required version", () => {
+  const versions = ["1.0.0", "1.0.5", "1.4.0", "2.0.0"];
+  const selectIn = (name, requiredVersion) =>
+    select(name, versions, { version: "1.0.0", requiredVersion });
+  expect(selectIn("caret", "^1.2.0")).toBe("1.4.0");
+  expect(selectIn("tilde", "~1.0.0")).toBe("1.0.5");
+  expect(selectIn("greater", ">=1.4.0")).toBe("2.0.0");
+  expect(selectIn("any", "*")).toBe("2.0.0");
+  expect(selectIn("unset", undefined)).toBe("2.0.0");
+  expect(selectIn("exact", "1.4.0")).toBe("1.4.0");
+});
+
+it("only accepts the same minor or patch version for ^0.x ranges", () => {
+  const minor = { version: "0.0.3", requiredVersion: "^0.2.0" };
+  expect(select("zero-minor", ["0.2.1", "0.3.0"], minor)).toBe("0.2.1");
+  const patch = { version: "0.0.3", requiredVersion: "^0.0.3" };
+  expect(select("zero-patch", ["0.0.4"], patch)).toBe("0.0.3");
+});
+
+it("compares the parts of versions as numbers", () => {
+  const options = { version: "1.2.0", requiredVersion: "^1.0.0" };
+  expect(select("numeric", ["1.9.0", "1.10.0"], options)).toBe("1.10.0");
+});
+
+it("falls back to this build when no version satisfies the range", () => {
+  const options = { version: "3.0.0", requiredVersion: "^4.0.0" };
+  expect(select("fallback", ["2.0.0"], options)).toBe("3.0.0");
+});
+
+it("exposes modules through the container", async () => {
+  await import("federation-container");
+  const button = await federation.loadRemote("app1", "unused", "./Button");
+  expect(button.default).toBe("app1 button");
+  // Exposed modules use the shared modules of the page
+  expect(button.sharedLibVersion).toBe("1.2.0");
+
+  const missing = federation.loadRemote("app1", "unused", "./Missing");
+  await expect(missing).rejects.toThrow(
+    "Module ./Missing is not exposed by the remote app1"
+  );
+});
+
+it("consumes the modules exposed by remotes", async () => {
+  federation.registerContainer("app2", {
+    "./Header": () => Promise.resolve({ default: "app2 header" }),
+  });
+  const header = await import("app2/Header");
+  expect(header.default).toBe("app2 header");
+});
+
+it("fails to load remote entries outside of the browser", async () => {
+  await expect(import("offline/Footer")).rejects.toThrow(
+    "Remote entries can only be loaded in the browser: https://offline.example.com/remoteEntry.js"
+  );
+});
@@ -25,3 +25,16 @@ it("should not cycle when following the alias field", () => {
   expect(otherPackage).toBe("other-package/index");
   expect(otherPackageSubPath).toBe("other-package/sub-path");
 });
+
+import { file6, serverOnly, sub } from "package/dir";
+it("should follow the alias field for a missing file with an extension in the key", () => {
+  expect(file6).toBe("file6");
+});
+
+it("should ignore a missing file aliased to false", () => {
+  expect(serverOnly).toEqual({});
+});
+
+it("should follow the alias field for a folder request to its default file", () => {
+  expect(sub).toBe("sub");
+});
@@ -0,0 +1,37 @@
+// Adapted from the `pkgimports` fixture of Node.js
+// https://github.com/nodejs/node/blob/main/test/fixtures/es-modules/pkgimports/package.json
+import importBranch from "#branch";
+import nested from "#nested";
+import sub from "#subpath/file.js";
+import subNested from "#subpath/nested/file";
+import test from "#test";
+import external from "#external";
+import externalSub from "#external/file";
+import fallback from "#fallback";
+import fallbackConditions from "#fallback-conditions";
+
+it("should resolve conditions", () => {
+  expect(importBranch).toBe("import");
+  expect(require("#branch")).toBe("require");
+});
+
+it("should resolve nested conditions", () => {
+  expect(nested).toBe("import");
+  expect(require("#nested")).toBe("require");
+});
+
+it("should resolve the most specific pattern", () => {
+  expect(sub).toBe("sub");
+  expect(subNested).toBe("nested");
+  expect(test).toBe("test");
+});
+
+it("should resolve package requests", () => {
+  expect(external).toBe("dep");
+  expect(externalSub).toBe("dep/sub");
+});
+
+it("should fall back to the next valid target", () => {
+  expect(fallback).toBe("fallback");
+  expect(fallbackConditions).toBe("fallback");
+});
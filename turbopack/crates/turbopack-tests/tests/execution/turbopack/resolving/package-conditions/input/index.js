@@ -0,0 +1,14 @@
+import conditional from "conditional";
+import unconfigured from "unconfigured";
+import conditionalImports from "conditional-imports";
+import unconfiguredImports from "unconfigured-imports";
+
+it("should resolve the exports field with the package conditions", () => {
+  expect(conditional).toBe("worker");
+  expect(unconfigured).toBe("browser");
+});
+
+it("should resolve the imports field with the package conditions", () => {
+  expect(conditionalImports).toBe("worker");
+  expect(unconfiguredImports).toBe("browser");
+});
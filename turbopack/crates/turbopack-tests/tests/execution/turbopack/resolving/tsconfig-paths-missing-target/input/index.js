@@ -0,0 +1,12 @@
+import util from "@lib/util";
+import value from "@both/value";
+
+it("should fall back to the next target when the first one is missing", () => {
+  expect(util).toBe("lib/util");
+  expect(require("@lib/util")).toHaveProperty("default", "lib/util");
+});
+
+it("should use the first target which resolves", () => {
+  expect(value).toBe("first/value");
+  expect(require("@both/value")).toHaveProperty("default", "first/value");
+});
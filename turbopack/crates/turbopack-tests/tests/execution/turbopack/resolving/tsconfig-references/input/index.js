@@ -0,0 +1,19 @@
+import greeting from "@shared/greeting";
+import override from "@override";
+import page from "@app/page";
+
+it("should resolve an alias of a project referenced by its directory", () => {
+  expect(greeting).toBe("shared/greeting");
+  expect(require("@shared/greeting")).toHaveProperty(
+    "default",
+    "shared/greeting"
+  );
+});
+
+it("should resolve an alias of a project referenced by its tsconfig", () => {
+  expect(page).toBe("app/page");
+});
+
+it("should prefer the aliases of the project over the referenced ones", () => {
+  expect(override).toBe("override");
+});
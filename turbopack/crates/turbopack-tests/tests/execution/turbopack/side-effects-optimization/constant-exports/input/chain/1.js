@@ -0,0 +1 @@
+export { DISABLED } from "./2.js";
@@ -0,0 +1 @@
+export { DISABLED } from "./3.js";
@@ -0,0 +1 @@
+export { DISABLED } from "./4.js";
@@ -0,0 +1 @@
+export { DISABLED } from "./5.js";
@@ -0,0 +1 @@
+export { DISABLED } from "./6.js";
@@ -0,0 +1 @@
+export { DISABLED } from "./7.js";
@@ -0,0 +1 @@
+export { DISABLED } from "./8.js";
@@ -0,0 +1 @@
+export { DISABLED } from "./9.js";
@@ -0,0 +1,3 @@
+export { B as A } from "./cycle-b.js";
+
+export const C = false;
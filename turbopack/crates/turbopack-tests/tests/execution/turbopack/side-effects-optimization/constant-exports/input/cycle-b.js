@@ -0,0 +1 @@
+export { C as B } from "./cycle-a.js";
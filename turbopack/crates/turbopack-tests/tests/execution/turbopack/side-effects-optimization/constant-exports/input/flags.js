@@ -0,0 +1,7 @@
+export const DISABLED = process.env.NODE_ENV === "production";
+
+export let MUTABLE = false;
+
+export function enable() {
+  MUTABLE = true;
+}
@@ -0,0 +1,59 @@
+// Evaluates the flag module before the module reading its flag
+import "./tdz/b.js";
+import { readyDuringEvaluation } from "./tdz/a.js";
+import { DISABLED, MUTABLE, enable } from "./flags.js";
+import { REEXPORTED } from "./reexport.js";
+import { DISABLED as WITHIN_DEPTH } from "./chain/2.js";
+import { DISABLED as BEYOND_DEPTH } from "./chain/1.js";
+import { A } from "./cycle-a.js";
+import { constant } from "package/const.js";
+import { reexport } from "package/reexport.js";
+import { chain } from "package/chain.js";
+import { cycle } from "package/cycle.js";
+import { mutated } from "package/let.js";
+import { beyondDepth } from "package/beyond-depth.js";
+
+it("should eliminate branches on an imported const", () => {
+  if (DISABLED) {
+    constant();
+  }
+  expect(DISABLED).toBe(false);
+});
+
+it("should follow re-exports", () => {
+  if (REEXPORTED) {
+    reexport();
+  }
+  expect(REEXPORTED).toBe(false);
+});
+
+it("should follow re-exports up to the depth limit", () => {
+  if (WITHIN_DEPTH) {
+    chain();
+  }
+  expect(WITHIN_DEPTH).toBe(false);
+});
+
+it("should keep branches on constants beyond the depth limit", () => {
+  if (BEYOND_DEPTH) {
+    beyondDepth();
+  }
+  expect(BEYOND_DEPTH).toBe(false);
+  expect(globalThis.beyondDepthEvaluated).toBe(true);
+});
+
+it("should follow re-exports through cyclic imports", () => {
+  if (A) {
+    cycle();
+  }
+  expect(A).toBe(false);
+});
+
+it("should keep branches on an imported let", () => {
+  enable();
+  expect(MUTABLE ? mutated() : "unchanged").toBe("mutated");
+});
+
+it("should not inline a flag read before its initialization", () => {
+  expect(readyDuringEvaluation).toBe("ReferenceError");
+});
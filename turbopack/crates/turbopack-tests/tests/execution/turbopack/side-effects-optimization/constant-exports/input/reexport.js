@@ -0,0 +1 @@
+export { DISABLED as REEXPORTED } from "./flags.js";
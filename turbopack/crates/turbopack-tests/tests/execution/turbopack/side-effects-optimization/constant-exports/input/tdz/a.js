@@ -0,0 +1,10 @@
+import { READY } from "./b.js";
+
+let ready;
+try {
+  ready = READY ? "ready" : "not ready";
+} catch (e) {
+  ready = e.name;
+}
+
+export const readyDuringEvaluation = ready;
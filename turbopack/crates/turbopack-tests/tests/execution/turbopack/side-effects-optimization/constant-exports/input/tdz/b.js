@@ -0,0 +1,3 @@
+import "./a.js";
+
+export const READY = true;
@@ -444,6 +444,7 @@ const url = require("url");
 const fs = require("fs/promises");
 const moduleFactories = Object.create(null);
 const moduleCache = Object.create(null);
+const registeredChunks = new Set();
 /**
  * Returns an absolute path to the given module's id.
  */ function createResolvePathFromModule(resolver) {
@@ -465,12 +466,26 @@ function loadChunk(chunkData, source) {
         return loadChunkPath(chunkData.path, source);
     }
 }
+/**
+ * Registers the module factories of a chunk which is inlined in the same file
+ * as the runtime (single-file bundles), so that loading it is a no-op.
+ */ function registerChunk(chunkPath, chunkModules) {
+    registeredChunks.add(chunkPath);
+    for (const [moduleId, moduleFactory] of Object.entries(chunkModules)){
+        if (!moduleFactories[moduleId]) {
+            moduleFactories[moduleId] = moduleFactory;
+        }
+    }
+}
 function loadChunkPath(chunkPath, source) {
     if (!chunkPath.endsWith(".js")) {
         // We only support loading JS chunks in Node.js.
         // This branch can be hit when trying to load a CSS chunk.
         return;
     }
+    if (registeredChunks.has(chunkPath)) {
+        return;
+    }
     try {
         const resolved = path.resolve(RUNTIME_ROOT, chunkPath);
         const chunkModules = require(resolved);
@@ -496,6 +511,9 @@ async function loadChunkAsync(source, chunkData) {
         // This branch can be hit when trying to load a CSS chunk.
         return;
     }
+    if (registeredChunks.has(chunkPath)) {
+        return;
+    }
     const resolved = path.resolve(RUNTIME_ROOT, chunkPath);
     try {
         const contents = await fs.readFile(resolved, "utf-8");
@@ -537,8 +555,8 @@ function loadWebAssemblyModule(chunkPath) {
     const resolved = path.resolve(RUNTIME_ROOT, chunkPath);
     return compileWebAssemblyFromPath(resolved);
 }
-function getWorkerBlobURL(_chunks) {
-    throw new Error("Worker blobs are not implemented yet for Node.js");
+function getWorkerURL(_chunks, _type) {
+    throw new Error("Workers are not implemented yet for Node.js");
 }
 function instantiateModule(id, source) {
     const moduleFactory = moduleFactories[id];
@@ -613,7 +631,7 @@ function instantiateModule(id, source) {
             P: resolveAbsolutePath,
             U: relativeURL,
             R: createResolvePathFromModule(r),
-            b: getWorkerBlobURL,
+            b: getWorkerURL,
             z: requireStub,
             __dirname: typeof module1.id === "string" ? module1.id.replace(/(^|\/)\/+$/, "") : module1.id
         });
@@ -670,5 +688,6 @@ function getOrInstantiateRuntimeModule(moduleId, chunkPath) {
 }
 module.exports = {
     getOrInstantiateRuntimeModule,
-    loadChunk
+    loadChunk,
+    registerChunk
 };
@@ -9,8 +9,11 @@ if (!Array.isArray(globalThis.TURBOPACK)) {
 }
 
 const CHUNK_BASE_PATH = "";
+const CHUNK_FALLBACK_BASE_PATH = null;
 const RELATIVE_ROOT_PATH = "../../../../../../..";
 const RUNTIME_PUBLIC_PATH = "";
+const CHUNK_FORMAT_ESM = false;
+const CHUNK_LISTS_GLOBAL = "TURBOPACK_CHUNK_LISTS";
 /**
  * This file contains runtime types and functions that are shared between all
  * TurboPack ECMAScript runtimes.
@@ -488,14 +491,37 @@ async function loadChunkPath(source, chunkPath) {
  */ function resolveAbsolutePath(modulePath) {
     return `/ROOT/${modulePath ?? ""}`;
 }
-function getWorkerBlobURL(chunks) {
-    let bootstrap = `self.TURBOPACK_WORKER_LOCATION = ${JSON.stringify(location.origin)};importScripts(${chunks.map((c)=>`self.TURBOPACK_WORKER_LOCATION + ${JSON.stringify(getChunkRelativeUrl(c))}`).join(", ")});`;
-    let blob = new Blob([
+/**
+ * Returns the URL to construct a worker or to add a worklet module with, which
+ * loads `chunks`.
+ *
+ * Workers are loaded from blob or data URLs, which chunk URLs can't be resolved
+ * against, so the chunks are loaded from absolute URLs and the worker resolves
+ * the chunks it loads later against `TURBOPACK_WORKER_LOCATION`. Shared workers
+ * are loaded from a data URL, so that all pages get the same URL and share the
+ * worker. Worklets only support module scripts, so they import the chunks.
+ * ES module chunks can't be loaded with `importScripts`, so workers import
+ * them one after another as well.
+ */ function getWorkerURL(chunks, type) {
+    const urls = chunks.map((c)=>new URL(getChunkRelativeUrl(c), location.origin).href);
+    if (type === "worklet") {
+        const bootstrap = urls.map((url)=>`import ${JSON.stringify(url)};`).join("");
+        return URL.createObjectURL(new Blob([
+            bootstrap
+        ], {
+            type: "text/javascript"
+        }));
+    }
+    const loadChunks = CHUNK_FORMAT_ESM ? `(async () => {for (const url of ${JSON.stringify(urls)}) await import(url);})();` : `importScripts(${urls.map((url)=>JSON.stringify(url)).join(", ")});`;
+    const bootstrap = `self.TURBOPACK_WORKER_LOCATION = ${JSON.stringify(location.origin)};${loadChunks}`;
+    if (type === "shared-worker") {
+        return `data:text/javascript,${encodeURIComponent(bootstrap)}`;
+    }
+    return URL.createObjectURL(new Blob([
         bootstrap
     ], {
         type: "text/javascript"
-    });
-    return URL.createObjectURL(blob);
+    }));
 }
 /**
  * Adds a module to a chunk.
@@ -541,7 +567,22 @@ function getWorkerBlobURL(chunks) {
 /**
  * Returns the URL relative to the origin where a chunk can be fetched from.
  */ function getChunkRelativeUrl(chunkPath) {
-    return `${CHUNK_BASE_PATH}${chunkPath.split("/").map((p)=>encodeURIComponent(p)).join("/")}`;
+    return `${CHUNK_BASE_PATH}${encodeChunkPath(chunkPath)}`;
+}
+/**
+ * Returns the URL a chunk can be fetched from when fetching it from
+ * `getChunkRelativeUrl` failed, e.g. because the CDN serving chunks is
+ * unreachable or the chunk failed its integrity check.
+ *
+ * Returns `null` when no fallback is configured.
+ */ function getChunkFallbackUrl(chunkPath) {
+    if (CHUNK_FALLBACK_BASE_PATH == null || CHUNK_FALLBACK_BASE_PATH === CHUNK_BASE_PATH) {
+        return null;
+    }
+    return `${CHUNK_FALLBACK_BASE_PATH}${encodeChunkPath(chunkPath)}`;
+}
+function encodeChunkPath(chunkPath) {
+    return chunkPath.split("/").map((p)=>encodeURIComponent(p)).join("/");
 }
 /**
  * Marks a chunk list as a runtime chunk list. There can be more than one
@@ -712,7 +753,7 @@ function instantiateModule(id, source) {
                 U: relativeURL,
                 k: refresh,
                 R: createResolvePathFromModule(r),
-                b: getWorkerBlobURL,
+                b: getWorkerURL,
                 z: requireStub,
                 __dirname: typeof module.id === "string" ? module.id.replace(/(^|\/)\/+$/, "") : module.id
             }));
@@ -1010,7 +1051,13 @@ function applyChunkListUpdate(update) {
                     DEV_BACKEND.unloadChunk?.(chunkPath);
                     break;
                 case "partial":
-                    invariant(chunkUpdate.instruction, (instruction)=>`Unknown partial instruction: ${JSON.stringify(instruction)}.`);
+                    switch(chunkUpdate.instruction.type){
+                        case "CssInsertRulesUpdate":
+                            DEV_BACKEND.insertCssRules?.(chunkPath, chunkUpdate.instruction);
+                            break;
+                        default:
+                            invariant(chunkUpdate.instruction, (instruction)=>`Unknown partial instruction: ${JSON.stringify(instruction)}.`);
+                    }
                     break;
                 default:
                     invariant(chunkUpdate, (chunkUpdate)=>`Unknown chunk update type: ${chunkUpdate.type}`);
@@ -1379,13 +1426,13 @@ function createModuleHot(moduleId, hotData) {
     }
 }
 globalThis.TURBOPACK_CHUNK_UPDATE_LISTENERS ??= [];
-const chunkListsToRegister = globalThis.TURBOPACK_CHUNK_LISTS;
+const chunkListsToRegister = globalThis[CHUNK_LISTS_GLOBAL];
 if (Array.isArray(chunkListsToRegister)) {
     for (const chunkList of chunkListsToRegister){
         registerChunkList(globalThis.TURBOPACK_CHUNK_UPDATE_LISTENERS, chunkList);
     }
 }
-globalThis.TURBOPACK_CHUNK_LISTS = {
+globalThis[CHUNK_LISTS_GLOBAL] = {
     push: (chunkList)=>{
         registerChunkList(globalThis.TURBOPACK_CHUNK_UPDATE_LISTENERS, chunkList);
     }
@@ -1401,8 +1448,20 @@ let BACKEND;
 function augmentContext(context) {
     return context;
 }
-function fetchWebAssembly(wasmChunkPath) {
-    return fetch(getChunkRelativeUrl(wasmChunkPath));
+async function fetchWebAssembly(wasmChunkPath) {
+    const fallbackUrl = getChunkFallbackUrl(wasmChunkPath);
+    if (fallbackUrl == null) {
+        return fetch(getChunkRelativeUrl(wasmChunkPath));
+    }
+    try {
+        const response = await fetch(getChunkRelativeUrl(wasmChunkPath));
+        if (response.ok) {
+            return response;
+        }
+    } catch  {
+    // Retried from the fallback location below.
+    }
+    return fetch(fallbackUrl);
 }
 async function loadWebAssembly(_source, wasmChunkPath, importsObj) {
     const req = fetchWebAssembly(wasmChunkPath);
@@ -1416,6 +1475,11 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
 /**
  * Maps chunk paths to the corresponding resolver.
  */ const chunkResolvers = new Map();
+/**
+ * The chunks which were imported as ES modules. Browsers only evaluate a module
+ * URL once, so a chunk which is loaded again after HMR unloaded it is imported
+ * with a query string.
+ */ const importedChunks = new Set();
 (()=>{
     BACKEND = {
         async registerChunk (chunkPath, params) {
@@ -1466,6 +1530,99 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
         }
         return resolver;
     }
+    let chunkIntegrity;
+    /**
+   * Fetches `static/chunks/integrity-manifest.json` from
+   * `CHUNK_FALLBACK_BASE_PATH` once. It maps the path of each chunk to the
+   * integrity it was built with, so chunks retried from the same origin are
+   * verified even when the failed element had no integrity attribute.
+   */ function loadChunkIntegrity() {
+        if (chunkIntegrity == null) {
+            const manifestUrl = getChunkFallbackUrl("static/chunks/integrity-manifest.json");
+            chunkIntegrity = manifestUrl == null ? Promise.resolve({}) : fetch(manifestUrl).then((response)=>response.ok ? response.json() : {}).catch(()=>({}));
+        }
+        return chunkIntegrity;
+    }
+    /**
+   * Retries loading a script chunk from `CHUNK_FALLBACK_BASE_PATH` after
+   * loading it from `CHUNK_BASE_PATH` failed. The fallback is verified with
+   * the integrity of the chunk in the integrity manifest, or with the one of
+   * the failed script when the manifest doesn't list the chunk.
+   */ async function loadFallbackScript(chunkPath, resolver, failedIntegrity) {
+        const fallbackUrl = getChunkFallbackUrl(chunkPath);
+        if (fallbackUrl == null) {
+            resolver.reject();
+            return;
+        }
+        if (CHUNK_FORMAT_ESM) {
+            // The integrity of imported modules can only be checked through an
+            // import map.
+            importedChunks.add(chunkPath);
+            import(fallbackUrl).catch((error)=>resolver.reject(error));
+            return;
+        }
+        const integrity = (await loadChunkIntegrity())[chunkPath] ?? failedIntegrity;
+        if (document.querySelector(`script[src="${fallbackUrl}"]`) != null) {
+            // Another failed script for this chunk already triggered the fallback.
+            return;
+        }
+        const script = document.createElement("script");
+        script.src = fallbackUrl;
+        if (integrity) {
+            script.integrity = integrity;
+        }
+        script.onerror = ()=>{
+            resolver.reject();
+        };
+        document.body.appendChild(script);
+    }
+    /**
+   * Imports an ES module chunk, retrying from `CHUNK_FALLBACK_BASE_PATH` when
+   * importing it from `CHUNK_BASE_PATH` fails. The chunk resolves its resolver
+   * in `registerChunk` when it's evaluated.
+   */ async function importChunk(chunkPath, resolver) {
+        const query = importedChunks.has(chunkPath) ? `?ts=${Date.now()}` : "";
+        importedChunks.add(chunkPath);
+        const resolveUrl = (url)=>typeof importScripts === "function" ? new URL(url, TURBOPACK_WORKER_LOCATION).href : url;
+        try {
+            await import(resolveUrl(getChunkRelativeUrl(chunkPath) + query));
+        } catch (error) {
+            const fallbackUrl = getChunkFallbackUrl(chunkPath);
+            if (fallbackUrl == null) {
+                resolver.reject(error);
+                return;
+            }
+            try {
+                await import(resolveUrl(fallbackUrl + query));
+            } catch (error) {
+                resolver.reject(error);
+            }
+        }
+    }
+    /**
+   * Retries loading a CSS chunk from `CHUNK_FALLBACK_BASE_PATH` after loading
+   * it from `CHUNK_BASE_PATH` failed, verified like `loadFallbackScript`.
+   */ async function loadFallbackStylesheet(chunkPath, resolver, failedIntegrity) {
+        const fallbackUrl = getChunkFallbackUrl(chunkPath);
+        if (fallbackUrl == null) {
+            resolver.reject();
+            return;
+        }
+        const integrity = (await loadChunkIntegrity())[chunkPath] ?? failedIntegrity;
+        const link = document.createElement("link");
+        link.rel = "stylesheet";
+        link.href = fallbackUrl;
+        if (integrity) {
+            link.integrity = integrity;
+        }
+        link.onerror = ()=>{
+            resolver.reject();
+        };
+        link.onload = ()=>{
+            resolver.resolve();
+        };
+        document.body.appendChild(link);
+    }
     /**
    * Loads the given chunk, and returns a promise that resolves once the chunk
    * has been loaded.
@@ -1489,12 +1646,27 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
         }
         const chunkUrl = getChunkRelativeUrl(chunkPath);
         const decodedChunkUrl = decodeURI(chunkUrl);
+        if (CHUNK_FORMAT_ESM && chunkPath.endsWith(".js") && (typeof importScripts === "function" || document.querySelector(`script[src="${chunkUrl}"],script[src^="${chunkUrl}?"],script[src="${decodedChunkUrl}"],script[src^="${decodedChunkUrl}?"]`) == null)) {
+            // ES module chunks are imported instead of appending a
+            // `<script type="module">` element, which works in workers as well.
+            // Chunks rendered by the server are handled like classic scripts below.
+            importChunk(chunkPath, resolver);
+            return resolver.promise;
+        }
         if (typeof importScripts === "function") {
             // We're in a web worker
             if (chunkPath.endsWith(".css")) {
             // ignore
             } else if (chunkPath.endsWith(".js")) {
-                importScripts(TURBOPACK_WORKER_LOCATION + chunkUrl);
+                try {
+                    importScripts(new URL(chunkUrl, TURBOPACK_WORKER_LOCATION).href);
+                } catch (error) {
+                    const fallbackUrl = getChunkFallbackUrl(chunkPath);
+                    if (fallbackUrl == null) {
+                        throw error;
+                    }
+                    importScripts(new URL(fallbackUrl, TURBOPACK_WORKER_LOCATION).href);
+                }
             } else {
                 throw new Error(`can't infer type of chunk from path ${chunkPath} in worker`);
             }
@@ -1510,7 +1682,7 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
                     link.rel = "stylesheet";
                     link.href = chunkUrl;
                     link.onerror = ()=>{
-                        resolver.reject();
+                        loadFallbackStylesheet(chunkPath, resolver, link.integrity);
                     };
                     link.onload = ()=>{
                         // CSS chunks do not register themselves, and as such must be marked as
@@ -1525,8 +1697,10 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
                     // There is this edge where the script already failed loading, but we
                     // can't detect that. The Promise will never resolve in this case.
                     for (const script of Array.from(previousScripts)){
+                        // Scripts rendered by the server can carry an integrity attribute,
+                        // in which case a rejected integrity check also ends up here.
                         script.addEventListener("error", ()=>{
-                            resolver.reject();
+                            loadFallbackScript(chunkPath, resolver, script.integrity);
                         });
                     }
                 } else {
@@ -1536,7 +1710,7 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
                     // which happens in `registerChunk`. Hence the absence of `resolve()` in
                     // this branch.
                     script.onerror = ()=>{
-                        resolver.reject();
+                        loadFallbackScript(chunkPath, resolver, script.integrity);
                     };
                     document.body.appendChild(script);
                 }
@@ -1578,6 +1752,11 @@ let DEV_BACKEND;
                 for (const script of Array.from(scripts)){
                     script.remove();
                 }
+                if (CHUNK_FORMAT_ESM) {
+                    // The module stays in the module map of the browser, so loading the
+                    // chunk again has to import it from another URL.
+                    importedChunks.add(chunkPath);
+                }
             } else {
                 throw new Error(`can't infer type of chunk from path ${chunkPath}`);
             }
@@ -1626,6 +1805,29 @@ let DEV_BACKEND;
                 previousLinks[0].parentElement.insertBefore(link, previousLinks[0].nextSibling);
             });
         },
+        async insertCssRules (chunkPath, update) {
+            const chunkUrl = getChunkRelativeUrl(chunkPath);
+            const decodedChunkUrl = decodeURI(chunkUrl);
+            const links = document.querySelectorAll(`link[rel=stylesheet][href="${chunkUrl}"],link[rel=stylesheet][href^="${chunkUrl}?"],link[rel=stylesheet][href="${decodedChunkUrl}"],link[rel=stylesheet][href^="${decodedChunkUrl}?"]`);
+            try {
+                for (const link of Array.from(links)){
+                    const sheet = link.sheet;
+                    // Rules dropped by the browser while parsing would shift the indices
+                    // of the insertions, so only patch stylesheets matching the previous
+                    // version.
+                    if (sheet == null || sheet.cssRules.length !== update.previousRuleCount) {
+                        throw new Error(`Stylesheet of chunk ${chunkPath} is out of sync`);
+                    }
+                    for (const { index, rule } of update.insertions){
+                        sheet.insertRule(rule, index);
+                    }
+                }
+            } catch  {
+                // This also covers cross-origin stylesheets, whose rules can't be
+                // accessed.
+                await DEV_BACKEND.reloadChunk(chunkPath);
+            }
+        },
         restart: ()=>self.location.reload()
     };
     function deleteResolver(chunkPath) {
@@ -0,0 +1,168 @@
+use std::cmp::{max, min};
+
+use serde::{Deserialize, Serialize};
+
+use crate::FxIndexMap;
+
+/// How busy the threads and the scheduler were over time, to tell whether a
+/// build is bound by CPU, waiting for something or starved of tasks.
+#[derive(Default)]
+pub struct Concurrency {
+    /// The intervals each thread has spent in any span, in order.
+    threads: FxIndexMap<u64, ThreadLane>,
+    /// Changes of the number of queued tasks, in order.
+    queue_depth: Vec<(u64, u64)>,
+    /// The latest timestamp seen, which is where busy threads are busy until.
+    end: u64,
+}
+
+#[derive(Default)]
+struct ThreadLane {
+    busy: Vec<(u64, u64)>,
+    busy_since: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ConcurrencyLane {
+    pub thread_id: u64,
+    /// The percentage of each bucket the thread was busy.
+    pub occupancy: Vec<u8>,
+}
+
+pub struct ConcurrencyBuckets {
+    pub lanes: Vec<ConcurrencyLane>,
+    /// The maximum number of queued tasks within each bucket.
+    pub queue_depth: Vec<u64>,
+}
+
+impl Concurrency {
+    pub fn thread_busy(&mut self, thread_id: u64, ts: u64) {
+        self.end = max(self.end, ts);
+        let lane = self.threads.entry(thread_id).or_default();
+        lane.busy_since.get_or_insert(ts);
+    }
+
+    pub fn thread_idle(&mut self, thread_id: u64, ts: u64) {
+        self.end = max(self.end, ts);
+        let lane = self.threads.entry(thread_id).or_default();
+        if let Some(start) = lane.busy_since.take() {
+            if ts > start {
+                lane.busy.push((start, ts));
+            }
+        }
+    }
+
+    pub fn set_queue_depth(&mut self, ts: u64, depth: u64) {
+        self.end = max(self.end, ts);
+        self.queue_depth.push((ts, depth));
+    }
+
+    /// Splits `start..end` into `count` buckets of equal length.
+    pub fn buckets(&self, start: u64, end: u64, count: usize) -> ConcurrencyBuckets {
+        let count = max(count, 1);
+        let bucket_len = max(end.saturating_sub(start).div_ceil(count as u64), 1);
+        let bucket_start = |index: usize| start + index as u64 * bucket_len;
+
+        let mut lanes = Vec::with_capacity(self.threads.len());
+        for (&thread_id, lane) in self.threads.iter() {
+            let mut busy_time = vec![0; count];
+            let open = lane.busy_since.map(|since| (since, self.end));
+            let first = lane
+                .busy
+                .partition_point(|&(_, busy_end)| busy_end <= start);
+            for &(busy_start, busy_end) in lane.busy[first..].iter().chain(open.iter()) {
+                if busy_start >= end {
+                    break;
+                }
+                let first_bucket = (busy_start.saturating_sub(start) / bucket_len) as usize;
+                for (index, time) in busy_time.iter_mut().enumerate().skip(first_bucket) {
+                    let from = max(busy_start, bucket_start(index));
+                    let to = min(busy_end, bucket_start(index + 1));
+                    if from >= to {
+                        break;
+                    }
+                    *time += to - from;
+                }
+            }
+            if busy_time.iter().all(|&time| time == 0) {
+                continue;
+            }
+            lanes.push(ConcurrencyLane {
+                thread_id,
+                occupancy: busy_time
+                    .into_iter()
+                    .map(|time| (time * 100 / bucket_len) as u8)
+                    .collect(),
+            });
+        }
+
+        let mut queue_depth = vec![0; count];
+        let first = self.queue_depth.partition_point(|&(ts, _)| ts <= start);
+        if let Some(&(_, depth)) = first.checked_sub(1).and_then(|i| self.queue_depth.get(i)) {
+            queue_depth[0] = depth;
+        }
+        let mut current = queue_depth[0];
+        let mut index = 0;
+        for &(ts, depth) in self.queue_depth[first..].iter() {
+            if ts >= end {
+                break;
+            }
+            let bucket = ((ts - start) / bucket_len) as usize;
+            // Buckets without any change keep the depth of the one before.
+            while index < bucket {
+                index += 1;
+                queue_depth[index] = current;
+            }
+            current = depth;
+            queue_depth[index] = max(queue_depth[index], depth);
+        }
+        while index + 1 < count {
+            index += 1;
+            queue_depth[index] = current;
+        }
+
+        ConcurrencyBuckets { lanes, queue_depth }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Concurrency;
+
+    #[test]
+    fn occupancy() {
+        let mut concurrency = Concurrency::default();
+        concurrency.thread_busy(1, 0);
+        concurrency.thread_idle(1, 15);
+        concurrency.thread_busy(2, 10);
+        concurrency.thread_idle(2, 20);
+        concurrency.thread_busy(2, 30);
+        concurrency.thread_busy(3, 50);
+        concurrency.thread_idle(3, 60);
+        concurrency.set_queue_depth(40, 0);
+
+        let buckets = concurrency.buckets(0, 40, 4);
+        let lanes = buckets
+            .lanes
+            .iter()
+            .map(|lane| (lane.thread_id, lane.occupancy.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lanes,
+            vec![(1, vec![100, 50, 0, 0]), (2, vec![0, 100, 0, 100])]
+        );
+    }
+
+    #[test]
+    fn queue_depth() {
+        let mut concurrency = Concurrency::default();
+        concurrency.set_queue_depth(5, 3);
+        concurrency.set_queue_depth(12, 7);
+        concurrency.set_queue_depth(14, 2);
+        concurrency.set_queue_depth(35, 0);
+
+        let buckets = concurrency.buckets(10, 50, 4);
+        assert_eq!(buckets.queue_depth, vec![7, 2, 2, 0]);
+    }
+}
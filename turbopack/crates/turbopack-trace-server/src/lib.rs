@@ -9,6 +9,7 @@ use rustc_hash::FxHasher;
 use self::{reader::TraceReader, server::serve, store_container::StoreContainer};
 
 mod bottom_up;
+mod concurrency;
 mod reader;
 mod self_time_tree;
 mod server;
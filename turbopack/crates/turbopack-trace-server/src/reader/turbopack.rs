@@ -194,6 +194,9 @@ impl TurbopackFormat {
             }
             TraceRow::Enter { ts, id, thread_id } => {
                 let stack = self.thread_stacks.entry(thread_id).or_default();
+                if stack.is_empty() {
+                    store.concurrency.thread_busy(thread_id, ts);
+                }
                 if let Some(&parent) = stack.last() {
                     if let Some(parent_start) = self.self_time_started.remove(&(parent, thread_id))
                     {
@@ -225,6 +228,9 @@ impl TurbopackFormat {
                         let parent = stack[stack_index - 1];
                         self.self_time_started.insert((parent, thread_id), ts);
                     }
+                    if stack.is_empty() {
+                        store.concurrency.thread_idle(thread_id, ts);
+                    }
                 }
                 if let Some(start) = self.self_time_started.remove(&(id, thread_id)) {
                     self.process_internal_row(
@@ -245,6 +251,9 @@ impl TurbopackFormat {
                     },
                 );
             }
+            TraceRow::QueueDepth { ts, depth } => {
+                store.concurrency.set_queue_depth(ts, depth);
+            }
             TraceRow::Allocation {
                 ts: _,
                 thread_id,
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use tungstenite::{accept, Message};
 
 use crate::{
+    concurrency::{ConcurrencyBuckets, ConcurrencyLane},
     store::SpanId,
     store_container::StoreContainer,
     u64_string,
@@ -43,6 +44,13 @@ pub enum ServerToClientMessage {
         args: Vec<(String, String)>,
         path: Vec<String>,
     },
+    #[serde(rename_all = "camelCase")]
+    ConcurrencyResult {
+        start: u64,
+        end: u64,
+        lanes: Vec<ConcurrencyLane>,
+        queue_depth: Vec<u64>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -67,6 +75,13 @@ pub enum ClientToServerMessage {
         #[serde(with = "u64_string")]
         id: SpanId,
     },
+    /// Requests the thread occupancy and the queue depth between `start` and
+    /// `end`, split into `buckets`.
+    Concurrency {
+        start: u64,
+        end: u64,
+        buckets: usize,
+    },
     Ack,
     CheckForMoreData,
 }
@@ -337,6 +352,30 @@ fn handle_connection(
 
                         continue;
                     }
+                    ClientToServerMessage::Concurrency {
+                        start,
+                        end,
+                        buckets,
+                    } => {
+                        let message = {
+                            let store = state.store.read();
+                            let root_start = store.root_span().start();
+                            let ConcurrencyBuckets { lanes, queue_depth } =
+                                store.concurrency.buckets(
+                                    root_start.saturating_add(start),
+                                    root_start.saturating_add(end),
+                                    buckets,
+                                );
+                            ServerToClientMessage::ConcurrencyResult {
+                                start,
+                                end,
+                                lanes,
+                                queue_depth,
+                            }
+                        };
+                        let message = serde_json::to_string(&message).unwrap();
+                        websocket.send(Message::Text(message))?;
+                    }
                     ClientToServerMessage::Ack => {
                         ready_for_update = true;
                         if update_skipped {
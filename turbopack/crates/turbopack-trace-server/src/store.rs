@@ -8,6 +8,7 @@ use std::{
 };
 
 use crate::{
+    concurrency::Concurrency,
     self_time_tree::SelfTimeTree,
     span::{Span, SpanEvent, SpanIndex},
     span_ref::SpanRef,
@@ -21,6 +22,7 @@ pub struct Store {
     pub(crate) spans: Vec<Span>,
     pub(crate) self_time_tree: Option<SelfTimeTree<SpanIndex>>,
     max_self_time_lookup_time: AtomicU64,
+    pub(crate) concurrency: Concurrency,
 }
 
 fn new_root_span() -> Span {
@@ -58,6 +60,7 @@ impl Store {
                 .is_none()
                 .then(SelfTimeTree::new),
             max_self_time_lookup_time: AtomicU64::new(0),
+            concurrency: Concurrency::default(),
         }
     }
 
@@ -68,6 +71,7 @@ impl Store {
             *tree = SelfTimeTree::new();
         }
         *self.max_self_time_lookup_time.get_mut() = 0;
+        self.concurrency = Concurrency::default();
     }
 
     pub fn has_time_info(&self) -> bool {
@@ -17,6 +17,10 @@ use crate::{
 
 pub struct RawTraceLayerOptions {}
 
+/// Events of this target report the depth of the task queue of the scheduler
+/// in a `queue_depth` field. They are written as [TraceRow::QueueDepth].
+const QUEUE_DEPTH_TARGET: &str = "turbo_tasks::queue_depth";
+
 struct RawTraceLayerExtension {
     id: u64,
 }
@@ -137,6 +141,17 @@ impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for RawTraceLayer<S> {
         let ts = self.start.elapsed().as_micros() as u64;
         let mut values = ValuesVisitor::new();
         event.record(&mut values);
+        if event.metadata().target() == QUEUE_DEPTH_TARGET {
+            if let Some(depth) = values
+                .values
+                .iter()
+                .find(|(name, _)| name == "queue_depth")
+                .and_then(|(_, value)| value.as_u64())
+            {
+                self.write(TraceRow::QueueDepth { ts, depth });
+                return;
+            }
+        }
         self.write(TraceRow::Event {
             ts,
             parent: if event.is_contextual() {
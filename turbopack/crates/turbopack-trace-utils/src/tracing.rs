@@ -100,6 +100,14 @@ pub enum TraceRow<'a> {
         /// Deallocation count
         deallocation_count: u64,
     },
+    /// The number of tasks that have been scheduled but are still waiting for
+    /// a thread to execute on.
+    QueueDepth {
+        /// Timestamp
+        ts: u64,
+        /// Number of queued tasks
+        depth: u64,
+    },
 }
 
 #[derive(Debug, Serialize, Deserialize)]
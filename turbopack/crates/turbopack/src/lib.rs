@@ -61,7 +61,7 @@ use turbopack_ecmascript::{
     tree_shake::asset::EcmascriptModulePartAsset,
 };
 use turbopack_json::JsonModuleAsset;
-pub use turbopack_resolve::{resolve::resolve_options, resolve_options_context};
+pub use turbopack_resolve::{package_store, resolve::resolve_options, resolve_options_context};
 use turbopack_resolve::{resolve_options_context::ResolveOptionsContext, typescript::type_resolve};
 use turbopack_static::StaticModuleAsset;
 use turbopack_wasm::{module_asset::WebAssemblyModuleAsset, source::WebAssemblySource};
@@ -260,9 +260,20 @@ async fn apply_module_type(
             .await?,
         ),
         ModuleType::Static => ResolvedVc::upcast(
-            StaticModuleAsset::new(*source, Vc::upcast(module_asset_context))
-                .to_resolved()
-                .await?,
+            match module_asset_context
+                .module_options_context()
+                .await?
+                .asset_inline_limit
+            {
+                Some(inline_limit) => StaticModuleAsset::new_with_inline_limit(
+                    *source,
+                    Vc::upcast(module_asset_context),
+                    *inline_limit,
+                ),
+                None => StaticModuleAsset::new(*source, Vc::upcast(module_asset_context)),
+            }
+            .to_resolved()
+            .await?,
         ),
         ModuleType::WebAssembly { source_ty } => ResolvedVc::upcast(
             WebAssemblyModuleAsset::new(
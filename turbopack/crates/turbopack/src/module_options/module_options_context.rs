@@ -12,6 +12,7 @@ use turbopack_node::{
     execution_context::ExecutionContext,
     transforms::{postcss::PostCssTransformOptions, webpack::WebpackLoaderItems},
 };
+use turbopack_static::inline::AssetInlineLimit;
 
 use super::ModuleRule;
 
@@ -133,6 +134,10 @@ pub struct ModuleOptionsContext {
     /// runtime.
     pub enable_externals_tracing: Option<ResolvedVc<FileSystemPath>>,
 
+    /// Inline static assets below these sizes as `data:` URLs instead of
+    /// emitting them.
+    pub asset_inline_limit: Option<ResolvedVc<AssetInlineLimit>>,
+
     /// Custom rules to be applied after all default rules.
     pub module_rules: Vec<ModuleRule>,
     /// A list of rules to use a different module option context for certain
@@ -0,0 +1,489 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env::{current_dir, var_os},
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use serde::Deserialize;
+use toml_edit::{DocumentMut, InlineTable, Item, TableLike, Value};
+use walkdir::WalkDir;
+
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Finds dependencies of the workspace crates that are unused, declared with versions that could
+/// be shared through `[workspace.dependencies]`, or enabled with inconsistent features.
+#[derive(Parser)]
+#[command(name = "check-deps")]
+pub struct CheckDepsArgs {
+    /// Write the edits for duplicated versions to the Cargo.toml files. Unused dependencies are
+    /// only reported, since they're found by searching the sources for their names, and so are
+    /// inconsistent features.
+    #[arg(long)]
+    fix: bool,
+}
+
+/// A subset of the output of `cargo metadata --no-deps`.
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<Package>,
+    workspace_root: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: String,
+    manifest_path: PathBuf,
+}
+
+/// A dependency as declared in the manifest of a workspace crate.
+struct DeclaredDependency {
+    crate_name: String,
+    manifest_path: PathBuf,
+    /// e.g. `["target", "cfg(windows)", "dependencies"]`
+    table_path: Vec<String>,
+    /// The name the dependency is used with in code, before replacing dashes.
+    key: String,
+    /// The name of the dependency on crates.io, which differs from `key` for renamed dependencies.
+    package: String,
+    source: DependencySource,
+    features: BTreeSet<String>,
+    optional: bool,
+}
+
+enum DependencySource {
+    Workspace,
+    Registry(String),
+    /// `path` or `git` dependencies, which aren't unified.
+    Other,
+}
+
+pub fn run(args: &CheckDepsArgs) -> Result<()> {
+    let workspace_dir = var_os("CARGO_WORKSPACE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| current_dir().unwrap());
+
+    let metadata = read_metadata(&workspace_dir)?;
+    let root_manifest_path = metadata.workspace_root.join("Cargo.toml");
+    let mut root_manifest = read_manifest(&root_manifest_path)?;
+
+    let mut declared = vec![];
+    for package in &metadata.packages {
+        let manifest = read_manifest(&package.manifest_path)?;
+        collect_dependencies(package, &manifest, &mut declared);
+    }
+
+    // The dependencies to turn into `{ workspace = true }`, by manifest
+    let mut fixes: BTreeMap<PathBuf, Vec<&DeclaredDependency>> = BTreeMap::new();
+
+    println!("Possibly unused dependencies:");
+    for package in &metadata.packages {
+        let package_dir = package
+            .manifest_path
+            .parent()
+            .context("manifest must be in a directory")?;
+        let sources = read_rust_sources(package_dir);
+        for dependency in declared.iter().filter(|d| d.crate_name == package.name) {
+            // Optional dependencies are referenced by features instead
+            if dependency.optional || is_used(&sources, &dependency.key) {
+                continue;
+            }
+            println!("  {}: {}", dependency.crate_name, dependency.key);
+        }
+    }
+
+    let workspace_dependencies = root_manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"))
+        .and_then(Item::as_table_like)
+        .map(|table| {
+            table
+                .iter()
+                .map(|(name, item)| (name.to_string(), item.clone()))
+                .collect::<BTreeMap<_, _>>()
+        })
+        .unwrap_or_default();
+
+    println!("\nVersions that could be shared through [workspace.dependencies]:");
+    let mut new_workspace_dependencies = vec![];
+    for (package, dependencies) in group_by_package(&declared) {
+        let registry_versions = dependencies
+            .iter()
+            .filter_map(|d| match &d.source {
+                DependencySource::Registry(version) => Some((*d, version.as_str())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if registry_versions.is_empty() {
+            continue;
+        }
+
+        let workspace_entry = workspace_dependencies.get(package);
+        let in_workspace = workspace_entry.is_some();
+        let crates = registry_versions
+            .iter()
+            .map(|(d, _)| d.crate_name.as_str())
+            .collect::<BTreeSet<_>>();
+        if !in_workspace && crates.len() < 2 {
+            continue;
+        }
+
+        let versions = registry_versions
+            .iter()
+            .map(|(_, version)| *version)
+            .collect::<BTreeSet<_>>();
+        println!(
+            "  {package} ({}): declared as {} in {}",
+            if in_workspace {
+                "in workspace"
+            } else {
+                "not in workspace"
+            },
+            versions.iter().copied().collect::<Vec<_>>().join(", "),
+            crates.into_iter().collect::<Vec<_>>().join(", ")
+        );
+
+        // Only fix unambiguous cases, picking between versions needs a human
+        let workspace_entry = match workspace_entry {
+            Some(entry) => entry.clone(),
+            None => {
+                let [version] = versions.into_iter().collect::<Vec<_>>()[..] else {
+                    continue;
+                };
+                new_workspace_dependencies.push((package, version));
+                toml_edit::value(version)
+            }
+        };
+        for (dependency, _) in registry_versions {
+            let manifest = read_manifest(&dependency.manifest_path)?;
+            let Some(entry) = get_table(manifest.as_item(), &dependency.table_path)
+                .and_then(|table| table.get(&dependency.key))
+            else {
+                continue;
+            };
+            if can_inherit(entry, &workspace_entry) {
+                fixes
+                    .entry(dependency.manifest_path.clone())
+                    .or_default()
+                    .push(dependency);
+            }
+        }
+    }
+
+    println!("\nDependencies enabled with inconsistent features:");
+    for (package, dependencies) in group_by_package(&declared) {
+        let feature_sets = dependencies
+            .iter()
+            .map(|d| &d.features)
+            .collect::<BTreeSet<_>>();
+        if feature_sets.len() < 2 {
+            continue;
+        }
+        println!("  {package}:");
+        for dependency in dependencies {
+            println!(
+                "    {}: [{}]",
+                dependency.crate_name,
+                dependency
+                    .features
+                    .iter()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+
+    if !args.fix {
+        if !fixes.is_empty() {
+            println!("\nRun with --fix to apply the edits for duplicated dependencies.");
+        }
+        return Ok(());
+    }
+
+    // A new workspace dependency is only needed when a crate inherits it
+    new_workspace_dependencies.retain(|(package, _)| {
+        fixes
+            .values()
+            .flatten()
+            .any(|dependency| dependency.package == *package)
+    });
+    if !new_workspace_dependencies.is_empty() {
+        let table = root_manifest["workspace"]["dependencies"]
+            .as_table_like_mut()
+            .context("[workspace.dependencies] must be a table")?;
+        for (package, version) in new_workspace_dependencies {
+            table.insert(package, toml_edit::value(version));
+        }
+        fs::write(&root_manifest_path, root_manifest.to_string())?;
+    }
+
+    for (manifest_path, fixes) in &fixes {
+        let mut manifest = read_manifest(manifest_path)?;
+        for dependency in fixes {
+            inherit_workspace(&mut manifest, dependency)?;
+        }
+        fs::write(manifest_path, manifest.to_string())?;
+        println!("Updated {manifest_path:?}");
+    }
+
+    Ok(())
+}
+
+fn read_metadata(workspace_dir: &Path) -> Result<Metadata> {
+    let output = process::Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .current_dir(workspace_dir)
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Running cargo metadata failed")?;
+    if !output.status.success() {
+        bail!("cargo metadata exited with {}", output.status);
+    }
+
+    serde_json::from_slice(&output.stdout).context("failed to parse the output of cargo metadata")
+}
+
+fn read_manifest(path: &Path) -> Result<DocumentMut> {
+    fs::read_to_string(path)
+        .with_context(|| format!("could not read {path:?}"))?
+        .parse()
+        .with_context(|| format!("failed to parse {path:?}"))
+}
+
+fn collect_dependencies(
+    package: &Package,
+    manifest: &DocumentMut,
+    declared: &mut Vec<DeclaredDependency>,
+) {
+    let mut tables = DEPENDENCY_TABLES
+        .iter()
+        .map(|table| vec![table.to_string()])
+        .collect::<Vec<_>>();
+    if let Some(targets) = manifest.get("target").and_then(Item::as_table_like) {
+        for (target, _) in targets.iter() {
+            for table in DEPENDENCY_TABLES {
+                tables.push(vec!["target".into(), target.into(), table.to_string()]);
+            }
+        }
+    }
+
+    for table_path in tables {
+        let Some(table) = get_table(manifest.as_item(), &table_path) else {
+            continue;
+        };
+        for (key, item) in table.iter() {
+            let entry = item.as_table_like();
+            let get = |name: &str| entry.and_then(|entry| entry.get(name));
+
+            let source = if get("workspace").and_then(Item::as_bool) == Some(true) {
+                DependencySource::Workspace
+            } else if get("path").is_some() || get("git").is_some() {
+                DependencySource::Other
+            } else if let Some(version) = item.as_str().or_else(|| get("version")?.as_str()) {
+                DependencySource::Registry(version.to_string())
+            } else {
+                DependencySource::Other
+            };
+
+            declared.push(DeclaredDependency {
+                crate_name: package.name.clone(),
+                manifest_path: package.manifest_path.clone(),
+                table_path: table_path.clone(),
+                key: key.to_string(),
+                package: get("package")
+                    .and_then(Item::as_str)
+                    .unwrap_or(key)
+                    .to_string(),
+                source,
+                features: get("features")
+                    .and_then(Item::as_array)
+                    .map(|features| {
+                        features
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                optional: get("optional").and_then(Item::as_bool).unwrap_or(false),
+            });
+        }
+    }
+}
+
+fn get_table<'a>(item: &'a Item, path: &[String]) -> Option<&'a dyn TableLike> {
+    path.iter()
+        .try_fold(item, |item, segment| item.get(segment))?
+        .as_table_like()
+}
+
+fn group_by_package(declared: &[DeclaredDependency]) -> BTreeMap<&str, Vec<&DeclaredDependency>> {
+    let mut grouped: BTreeMap<&str, Vec<&DeclaredDependency>> = BTreeMap::new();
+    for dependency in declared {
+        grouped
+            .entry(dependency.package.as_str())
+            .or_default()
+            .push(dependency);
+    }
+    grouped
+}
+
+/// Concatenates all Rust sources of a crate, including its tests, benches and build script.
+fn read_rust_sources(package_dir: &Path) -> String {
+    WalkDir::new(package_dir)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != "target" && entry.file_name() != "node_modules")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .collect()
+}
+
+fn is_used(sources: &str, key: &str) -> bool {
+    let ident = key.replace('-', "_");
+    [
+        format!("{ident}::"),
+        format!("use {ident}"),
+        format!("extern crate {ident}"),
+    ]
+    .iter()
+    .any(|pattern| sources.contains(pattern.as_str()))
+}
+
+/// The keys of a dependency entry which `{ workspace = true }` can be combined with, or which
+/// [can_inherit] compares with the workspace entry.
+const INHERITABLE_KEYS: &[&str] = &["version", "features", "default-features", "optional"];
+
+fn get_entry_value<'a>(entry: &'a Item, key: &str) -> Option<&'a Value> {
+    entry.as_table_like()?.get(key)?.as_value()
+}
+
+fn entry_version(entry: &Item) -> Option<&str> {
+    entry
+        .as_str()
+        .or_else(|| get_entry_value(entry, "version")?.as_str())
+}
+
+fn entry_features(entry: &Item) -> BTreeSet<&str> {
+    get_entry_value(entry, "features")
+        .and_then(Value::as_array)
+        .map(|features| features.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn entry_default_features(entry: &Item) -> bool {
+    get_entry_value(entry, "default-features")
+        .and_then(Value::as_bool)
+        .unwrap_or(true)
+}
+
+/// Whether the dependency `entry` of a crate can be turned into `{ workspace = true }` without
+/// changing its meaning: it must declare exactly the version and the default features of the
+/// `workspace_entry`, and its features must include those of the workspace entry, since inherited
+/// features are added to them. Renamed dependencies and entries with other settings are left to a
+/// human.
+fn can_inherit(entry: &Item, workspace_entry: &Item) -> bool {
+    let has_other_keys = |entry: &Item| {
+        entry.as_table_like().is_some_and(|table| {
+            table
+                .iter()
+                .any(|(key, _)| !INHERITABLE_KEYS.contains(&key))
+        })
+    };
+    !has_other_keys(entry)
+        && !has_other_keys(workspace_entry)
+        && entry_version(entry).is_some()
+        && entry_version(entry) == entry_version(workspace_entry)
+        && entry_default_features(entry) == entry_default_features(workspace_entry)
+        && entry_features(workspace_entry).is_subset(&entry_features(entry))
+}
+
+fn inherit_workspace(manifest: &mut DocumentMut, dependency: &DeclaredDependency) -> Result<()> {
+    let mut item = manifest.as_item_mut();
+    for segment in &dependency.table_path {
+        item = &mut item[segment.as_str()];
+    }
+    let table = item
+        .as_table_like_mut()
+        .with_context(|| format!("{} must be a table", dependency.table_path.join(".")))?;
+
+    let mut inherited = InlineTable::new();
+    inherited.insert("workspace", true.into());
+    if let Some(features) = table
+        .get(&dependency.key)
+        .and_then(|entry| entry.get("features"))
+        .and_then(Item::as_value)
+    {
+        inherited.insert("features", features.clone());
+    }
+    if dependency.optional {
+        inherited.insert("optional", true.into());
+    }
+    table.insert(&dependency.key, Item::Value(Value::InlineTable(inherited)));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use toml_edit::{DocumentMut, Item};
+
+    use super::can_inherit;
+
+    fn entry(toml: &str) -> Item {
+        let document = format!("dependency = {toml}")
+            .parse::<DocumentMut>()
+            .unwrap();
+        document["dependency"].clone()
+    }
+
+    #[test]
+    fn inherits_matching_versions() {
+        assert!(can_inherit(&entry(r#""1.0.1""#), &entry(r#""1.0.1""#)));
+        assert!(can_inherit(
+            &entry(r#"{ version = "1.0.1", optional = true }"#),
+            &entry(r#""1.0.1""#)
+        ));
+        assert!(!can_inherit(&entry(r#""1.0""#), &entry(r#""1.0.1""#)));
+        assert!(!can_inherit(
+            &entry(r#"{ version = "1.0.1", package = "other" }"#),
+            &entry(r#""1.0.1""#)
+        ));
+        // Workspace dependencies from a path aren't the same dependency
+        assert!(!can_inherit(
+            &entry(r#""1.0.1""#),
+            &entry(r#"{ version = "1.0.1", path = "crates/dependency" }"#)
+        ));
+    }
+
+    #[test]
+    fn inherits_matching_default_features() {
+        let workspace = entry(r#"{ version = "1.0.1", default-features = false }"#);
+        assert!(!can_inherit(&entry(r#""1.0.1""#), &workspace));
+        assert!(can_inherit(
+            &entry(r#"{ version = "1.0.1", default-features = false }"#),
+            &workspace
+        ));
+        assert!(!can_inherit(
+            &entry(r#"{ version = "1.0.1", default-features = false }"#),
+            &entry(r#""1.0.1""#)
+        ));
+    }
+
+    #[test]
+    fn inherits_when_workspace_features_are_included() {
+        let workspace = entry(r#"{ version = "1.0.1", features = ["a"] }"#);
+        assert!(!can_inherit(&entry(r#""1.0.1""#), &workspace));
+        assert!(can_inherit(
+            &entry(r#"{ version = "1.0.1", features = ["a", "b"] }"#),
+            &workspace
+        ));
+        assert!(can_inherit(
+            &entry(r#"{ version = "1.0.1", features = ["b"] }"#),
+            &entry(r#""1.0.1""#)
+        ));
+    }
+}
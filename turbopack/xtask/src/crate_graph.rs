@@ -0,0 +1,267 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    env::{current_dir, var_os},
+    fmt::Write as _,
+    fs::{self, File},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use num_format::{Locale, ToFormattedString};
+use serde::Deserialize;
+use walkdir::WalkDir;
+
+/// Renders the dependency graph between the crates of the workspace, annotated with their size
+/// and, optionally, their build times.
+#[derive(Parser)]
+#[command(name = "crate-graph")]
+pub struct CrateGraphArgs {
+    /// Only include crates whose name starts with this prefix, e.g. `turbopack` or `next`.
+    #[arg(long)]
+    filter: Option<String>,
+
+    #[arg(long, value_enum, default_value_t = CrateGraphFormat::Svg)]
+    format: CrateGraphFormat,
+
+    /// Where to write the graph. Defaults to `crate-graph.{dot,svg}` in the current directory.
+    #[arg(long, short, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// The output of `cargo build --timings=json -Zunstable-options`, used to annotate crates with
+    /// their build times.
+    #[arg(long, value_name = "FILE")]
+    timings: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CrateGraphFormat {
+    Dot,
+    /// Requires graphviz's `dot` to be installed.
+    Svg,
+}
+
+/// A subset of the output of `cargo metadata`.
+#[derive(Deserialize)]
+struct Metadata {
+    packages: Vec<Package>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: String,
+    manifest_path: PathBuf,
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Deserialize)]
+struct Dependency {
+    name: String,
+    kind: Option<String>,
+}
+
+/// A `timing-info` message from `cargo build --timings=json`.
+#[derive(Deserialize)]
+struct TimingInfo {
+    reason: String,
+    target: Option<TimingTarget>,
+    duration: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct TimingTarget {
+    name: String,
+}
+
+struct CrateNode {
+    lines: usize,
+    build_time: Option<f64>,
+    dependencies: Vec<String>,
+}
+
+pub fn run(args: &CrateGraphArgs) -> Result<()> {
+    let workspace_dir = var_os("CARGO_WORKSPACE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| current_dir().unwrap());
+
+    let metadata = read_metadata(&workspace_dir)?;
+    let timings = args
+        .timings
+        .as_deref()
+        .map(read_timings)
+        .transpose()?
+        .unwrap_or_default();
+
+    let included = |name: &str| {
+        args.filter
+            .as_deref()
+            .is_none_or(|filter| name.starts_with(filter))
+    };
+
+    let nodes = metadata
+        .packages
+        .iter()
+        .filter(|package| included(&package.name))
+        .map(|package| {
+            let package_dir = package
+                .manifest_path
+                .parent()
+                .context("manifest must be in a directory")?;
+            let node = CrateNode {
+                lines: count_source_lines(package_dir),
+                build_time: timings.get(&package.name.replace('-', "_")).copied(),
+                dependencies: package
+                    .dependencies
+                    .iter()
+                    // dev-dependencies don't affect the build of the crate itself
+                    .filter(|dep| dep.kind.as_deref() != Some("dev"))
+                    .filter(|dep| {
+                        included(&dep.name) && metadata.packages.iter().any(|p| p.name == dep.name)
+                    })
+                    .map(|dep| dep.name.clone())
+                    .collect(),
+            };
+            Ok((package.name.clone(), node))
+        })
+        .collect::<Result<BTreeMap<_, _>>>()?;
+
+    if nodes.is_empty() {
+        bail!("No crates matched the filter");
+    }
+
+    let dot = render_dot(&nodes)?;
+    let output = args.output.clone().unwrap_or_else(|| match args.format {
+        CrateGraphFormat::Dot => PathBuf::from("crate-graph.dot"),
+        CrateGraphFormat::Svg => PathBuf::from("crate-graph.svg"),
+    });
+
+    match args.format {
+        CrateGraphFormat::Dot => fs::write(&output, dot)?,
+        CrateGraphFormat::Svg => render_svg(&dot, &output)?,
+    }
+
+    println!("Wrote the graph of {} crates to {output:?}", nodes.len());
+    Ok(())
+}
+
+fn read_metadata(workspace_dir: &Path) -> Result<Metadata> {
+    let output = process::Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .current_dir(workspace_dir)
+        .stderr(Stdio::inherit())
+        .output()
+        .context("Running cargo metadata failed")?;
+    if !output.status.success() {
+        bail!("cargo metadata exited with {}", output.status);
+    }
+
+    serde_json::from_slice(&output.stdout).context("failed to parse the output of cargo metadata")
+}
+
+/// Sums the build time of every target of each crate, keyed by the crate name with dashes
+/// replaced by underscores as that's how cargo names the targets.
+fn read_timings(path: &Path) -> Result<HashMap<String, f64>> {
+    let reader =
+        BufReader::new(File::open(path).with_context(|| format!("could not read {path:?}"))?);
+
+    let mut timings = HashMap::new();
+    for line in reader.lines() {
+        let line = line?;
+        // The output also contains other messages and plain text, only timing info is relevant
+        let Ok(info) = serde_json::from_str::<TimingInfo>(&line) else {
+            continue;
+        };
+        if info.reason != "timing-info" {
+            continue;
+        }
+        if let (Some(target), Some(duration)) = (info.target, info.duration) {
+            *timings.entry(target.name.replace('-', "_")).or_default() += duration;
+        }
+    }
+
+    Ok(timings)
+}
+
+fn count_source_lines(package_dir: &Path) -> usize {
+    WalkDir::new(package_dir)
+        .into_iter()
+        // skip nested build output and fixtures of other crates
+        .filter_entry(|entry| entry.file_name() != "target" && entry.file_name() != "node_modules")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .map(|source| source.lines().count())
+        .sum()
+}
+
+fn render_dot(nodes: &BTreeMap<String, CrateNode>) -> Result<String> {
+    let max_build_time = nodes
+        .values()
+        .filter_map(|node| node.build_time)
+        .fold(0.0, f64::max);
+
+    let mut dot = String::new();
+    writeln!(dot, "digraph crates {{")?;
+    writeln!(dot, "  rankdir=LR;")?;
+    writeln!(
+        dot,
+        "  node [shape=box, style=filled, fontname=\"Helvetica\"];"
+    )?;
+
+    for (name, node) in nodes {
+        let mut label = format!(
+            "{name}\\n{} lines",
+            node.lines.to_formatted_string(&Locale::en)
+        );
+        // Slower crates are drawn in a more saturated red
+        let saturation = match node.build_time {
+            Some(build_time) => {
+                write!(label, "\\n{build_time:.1}s")?;
+                if max_build_time > 0.0 {
+                    build_time / max_build_time
+                } else {
+                    0.0
+                }
+            }
+            None => 0.0,
+        };
+        writeln!(
+            dot,
+            "  \"{name}\" [label=\"{label}\", fillcolor=\"0.0 {saturation:.3} 1.0\"];"
+        )?;
+    }
+
+    for (name, node) in nodes {
+        for dependency in &node.dependencies {
+            writeln!(dot, "  \"{name}\" -> \"{dependency}\";")?;
+        }
+    }
+
+    writeln!(dot, "}}")?;
+    Ok(dot)
+}
+
+fn render_svg(dot: &str, output: &Path) -> Result<()> {
+    let mut child = process::Command::new("dot")
+        .arg("-Tsvg")
+        .arg("-o")
+        .arg(output)
+        .stdin(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("Running dot failed, is graphviz installed?")?;
+
+    child
+        .stdin
+        .take()
+        .context("dot must have a stdin")?
+        .write_all(dot.as_bytes())?;
+
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("dot exited with {status}");
+    }
+    Ok(())
+}
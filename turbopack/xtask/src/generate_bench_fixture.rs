@@ -0,0 +1,339 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use serde_json::json;
+
+/// Generates a React app for bundler benchmarks, with a module graph of a configurable shape. The
+/// same arguments always generate the same app.
+#[derive(Parser)]
+#[command(name = "generate-bench-fixture")]
+pub struct GenerateBenchFixtureArgs {
+    /// The directory to generate the app in. Must not exist or be empty.
+    output: PathBuf,
+
+    /// The number of modules, including the root module.
+    #[arg(long, default_value_t = 1000)]
+    modules: usize,
+
+    #[arg(long, value_enum, default_value_t = GraphShape::Tree)]
+    shape: GraphShape,
+
+    /// The number of modules each module imports with the `tree` shape.
+    #[arg(long, default_value_t = 3)]
+    fan_out: usize,
+
+    /// The number of modules importing each module. Modules are imported by their parent in the
+    /// shape and by `fan-in - 1` other random modules before them, which turns the graph into a
+    /// DAG with shared modules.
+    #[arg(long, default_value_t = 1)]
+    fan_in: usize,
+
+    /// The share of modules written in TypeScript instead of JavaScript.
+    #[arg(long, default_value_t = 0.0)]
+    ts_ratio: f64,
+
+    /// The share of modules importing a CSS module of their own.
+    #[arg(long, default_value_t = 0.0)]
+    css_ratio: f64,
+
+    /// The share of imports that are dynamic `import()`s, which creates async chunks.
+    #[arg(long, default_value_t = 0.0)]
+    dynamic_import_ratio: f64,
+
+    /// The number of modules per directory.
+    #[arg(long, default_value_t = 20)]
+    modules_per_directory: usize,
+
+    /// Seeds the random choices, e.g. which modules are written in TypeScript.
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum GraphShape {
+    /// Each module imports the next one.
+    Chain,
+    /// Each module imports `fan-out` modules.
+    Tree,
+    /// The root module imports all other modules.
+    Star,
+}
+
+/// A xorshift generator, so that fixtures don't depend on the implementation of a random crate.
+struct Random(u64);
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        // xorshift never leaves zero
+        Random(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..end`.
+    fn below(&mut self, end: usize) -> usize {
+        (self.next() % end as u64) as usize
+    }
+
+    fn chance(&mut self, ratio: f64) -> bool {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64 < ratio
+    }
+}
+
+struct Import {
+    module: usize,
+    dynamic: bool,
+    /// Modules are rendered by their parent only, other importers only reference them. Rendering
+    /// them everywhere would grow the rendered tree exponentially with the fan-in.
+    rendered: bool,
+}
+
+struct Module {
+    typescript: bool,
+    css: bool,
+    imports: Vec<Import>,
+}
+
+pub fn run(args: &GenerateBenchFixtureArgs) -> Result<()> {
+    if args.modules == 0 || args.fan_out == 0 || args.fan_in == 0 {
+        bail!("--modules, --fan-out and --fan-in must be at least 1");
+    }
+    if args.modules_per_directory == 0 {
+        bail!("--modules-per-directory must be at least 1");
+    }
+    for (name, ratio) in [
+        ("--ts-ratio", args.ts_ratio),
+        ("--css-ratio", args.css_ratio),
+        ("--dynamic-import-ratio", args.dynamic_import_ratio),
+    ] {
+        if !(0.0..=1.0).contains(&ratio) {
+            bail!("{name} must be between 0 and 1, got {ratio}");
+        }
+    }
+    if fs::read_dir(&args.output).is_ok_and(|mut entries| entries.next().is_some()) {
+        bail!("{} is not empty", args.output.display());
+    }
+
+    let modules = generate_graph(args);
+    write_app(args, &modules)?;
+
+    let edges = modules.iter().map(|module| module.imports.len()).sum::<usize>();
+    let dynamic_imports = modules
+        .iter()
+        .flat_map(|module| &module.imports)
+        .filter(|import| import.dynamic)
+        .count();
+    println!(
+        "Generated {} modules with {edges} imports ({dynamic_imports} dynamic) in {}",
+        modules.len(),
+        args.output.display()
+    );
+    Ok(())
+}
+
+fn generate_graph(args: &GenerateBenchFixtureArgs) -> Vec<Module> {
+    let mut random = Random::new(args.seed);
+    let mut modules = (0..args.modules)
+        .map(|_| Module {
+            typescript: random.chance(args.ts_ratio),
+            css: random.chance(args.css_ratio),
+            imports: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+    // Modules only import modules after them, so the graph has no cycles.
+    for index in 1..args.modules {
+        let parent = match args.shape {
+            GraphShape::Chain => index - 1,
+            GraphShape::Tree => (index - 1) / args.fan_out,
+            GraphShape::Star => 0,
+        };
+        let mut importers = vec![parent];
+        let other_importers = (args.fan_in - 1).min(index - 1);
+        while importers.len() < other_importers + 1 {
+            let importer = random.below(index);
+            if !importers.contains(&importer) {
+                importers.push(importer);
+            }
+        }
+        for importer in importers {
+            modules[importer].imports.push(Import {
+                module: index,
+                dynamic: random.chance(args.dynamic_import_ratio),
+                rendered: importer == parent,
+            });
+        }
+    }
+    modules
+}
+
+fn write_app(args: &GenerateBenchFixtureArgs, modules: &[Module]) -> Result<()> {
+    let src = args.output.join("src");
+    for (index, module) in modules.iter().enumerate() {
+        let path = src.join(module_path(args, modules, index));
+        fs::create_dir_all(path.parent().unwrap())?;
+        write(&path, &module_source(args, modules, index))?;
+        if module.css {
+            write(
+                &path.with_extension("module.css"),
+                &format!(".root{index} {{\n  padding: {}px;\n}}\n", index % 8),
+            )?;
+        }
+    }
+
+    write(
+        &src.join("index.jsx"),
+        &format!(
+            "import React from \"react\";\nimport {{ createRoot }} from \"react-dom/client\";\nimport \
+             M0 from \"./{}\";\n\ncreateRoot(document.getElementById(\"root\")).render(<M0 />);\n",
+            module_import_path(args, 0)
+        ),
+    )?;
+    write(
+        &args.output.join("package.json"),
+        &serde_json::to_string_pretty(&json!({
+            "name": "bench-fixture",
+            "private": true,
+            "dependencies": {
+                "react": "^18.2.0",
+                "react-dom": "^18.2.0",
+            },
+        }))?,
+    )?;
+    if modules.iter().any(|module| module.typescript) {
+        write(
+            &args.output.join("tsconfig.json"),
+            &serde_json::to_string_pretty(&json!({
+                "compilerOptions": {
+                    "jsx": "preserve",
+                    "module": "esnext",
+                    "moduleResolution": "bundler",
+                    "strict": true,
+                    "allowJs": true,
+                    "skipLibCheck": true,
+                },
+                "include": ["src"],
+            }))?,
+        )?;
+        write(
+            &src.join("css-modules.d.ts"),
+            "declare module \"*.module.css\" {\n  const styles: Record<string, string>;\n  export \
+             default styles;\n}\n",
+        )?;
+    }
+    // Describes the fixture, so benchmark results can be labeled with its shape.
+    write(
+        &args.output.join("fixture.json"),
+        &serde_json::to_string_pretty(&json!({
+            "modules": args.modules,
+            "shape": format!("{:?}", args.shape).to_lowercase(),
+            "fanOut": args.fan_out,
+            "fanIn": args.fan_in,
+            "tsRatio": args.ts_ratio,
+            "cssRatio": args.css_ratio,
+            "dynamicImportRatio": args.dynamic_import_ratio,
+            "seed": args.seed,
+        }))?,
+    )?;
+    Ok(())
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).with_context(|| format!("writing {}", path.display()))
+}
+
+fn module_import_path(args: &GenerateBenchFixtureArgs, index: usize) -> String {
+    format!("d{}/m{index}", index / args.modules_per_directory)
+}
+
+fn module_path(args: &GenerateBenchFixtureArgs, modules: &[Module], index: usize) -> String {
+    let extension = if modules[index].typescript {
+        "tsx"
+    } else {
+        "jsx"
+    };
+    format!("{}.{extension}", module_import_path(args, index))
+}
+
+fn module_source(args: &GenerateBenchFixtureArgs, modules: &[Module], index: usize) -> String {
+    let module = &modules[index];
+    let mut source = String::from("import React from \"react\";\n");
+    if module.css {
+        writeln!(source, "import styles from \"./m{index}.module.css\";").unwrap();
+    }
+    for import in &module.imports {
+        let path = if import.module / args.modules_per_directory
+            == index / args.modules_per_directory
+        {
+            format!("./m{}", import.module)
+        } else {
+            format!("../{}", module_import_path(args, import.module))
+        };
+        if import.dynamic {
+            writeln!(
+                source,
+                "const M{} = React.lazy(() => import(\"{path}\"));",
+                import.module
+            )
+            .unwrap();
+        } else {
+            writeln!(source, "import M{} from \"{path}\";", import.module).unwrap();
+        }
+    }
+
+    let referenced = module
+        .imports
+        .iter()
+        .filter(|import| !import.rendered)
+        .map(|import| format!("M{}", import.module))
+        .collect::<Vec<_>>();
+    if !referenced.is_empty() {
+        write!(
+            source,
+            "\n// Imported by other modules too, which render them\nexport const shared = [{}];\n",
+            referenced.join(", ")
+        )
+        .unwrap();
+    }
+
+    let return_type = if module.typescript {
+        ": React.ReactElement"
+    } else {
+        ""
+    };
+    let class_name = if module.css {
+        format!(" className={{styles.root{index}}}")
+    } else {
+        String::new()
+    };
+    write!(
+        source,
+        "\nexport default function M{index}(){return_type} {{\n  return (\n    \
+         <div{class_name}>\n      {index}\n"
+    )
+    .unwrap();
+    for import in module.imports.iter().filter(|import| import.rendered) {
+        if import.dynamic {
+            writeln!(
+                source,
+                "      <React.Suspense fallback={{null}}>\n        <M{} />\n      \
+                 </React.Suspense>",
+                import.module
+            )
+            .unwrap();
+        } else {
+            writeln!(source, "      <M{} />", import.module).unwrap();
+        }
+    }
+    source.push_str("    </div>\n  );\n}\n");
+    source
+}
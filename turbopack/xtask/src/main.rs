@@ -8,16 +8,24 @@ use std::{
 use anyhow::Result;
 use clap::{arg, Command, CommandFactory, FromArgMatches};
 
+mod check_deps;
 mod command;
+mod crate_graph;
+mod generate_bench_fixture;
 mod nft_bench;
 mod patch_package_json;
 mod publish;
+mod publish_bench_dashboard;
 mod summarize_bench;
 mod visualize_bundler_bench;
 
+use check_deps::CheckDepsArgs;
+use crate_graph::CrateGraphArgs;
+use generate_bench_fixture::GenerateBenchFixtureArgs;
 use nft_bench::show_result;
 use patch_package_json::PatchPackageJsonArgs;
 use publish::{publish_workspace, run_bump, run_publish};
+use publish_bench_dashboard::PublishBenchDashboardArgs;
 
 fn cli() -> Command {
     Command::new("xtask")
@@ -60,6 +68,8 @@ fn cli() -> Command {
                 .long_about(
                     "Generates visualizations of bundler benchmarks. Currently supports:
     * Scaling: shows how each bundler scales with varying module counts
+    * Stacked area/bar: shows the time of each measurement phase (startup, hydration, HMR) of a \
+                     bundler, stacked, with varying module counts
 
 To generate the summary json file:
     * Check out this repository at the `benchmark-data` branch. An additional shallow clone or git \
@@ -72,9 +82,14 @@ Visualizations generated by this command will appear in a sibling directory to t
                      file.",
                 )
                 .arg(arg!(<PATH_TO_SUMMARY_JSON> "the path to the benchmark summary json file"))
-                .arg(arg!(--bundlers <BUNDLERS> "comma separated list of bundlers to include in the visualization")),
+                .arg(arg!(--bundlers <BUNDLERS> "comma separated list of bundlers to include in the visualization"))
+                .arg(arg!(--charts <CHARTS> "comma separated list of charts to generate: scaling, stacked-area, stacked-bar (default: scaling)")),
         )
         .subcommand(PatchPackageJsonArgs::command())
+        .subcommand(CrateGraphArgs::command())
+        .subcommand(CheckDepsArgs::command())
+        .subcommand(GenerateBenchFixtureArgs::command())
+        .subcommand(PublishBenchDashboardArgs::command())
 }
 
 fn main() -> Result<()> {
@@ -179,14 +194,31 @@ fn main() -> Result<()> {
             let bundlers: Option<HashSet<&str>> = sub_matches
                 .get_one::<String>("bundlers")
                 .map(|s| s.split(',').collect());
+            let charts = sub_matches
+                .get_one::<String>("charts")
+                .map(|s| s.split(',').map(str::parse).collect::<Result<Vec<_>>>())
+                .transpose()?
+                .unwrap_or_else(|| vec![visualize_bundler_bench::Chart::Scaling]);
 
             let path = PathBuf::from(path);
             let path = path.canonicalize().unwrap();
-            visualize_bundler_bench::generate(path, bundlers)
+            visualize_bundler_bench::generate(path, bundlers, &charts)
         }
         Some(("patch-package-json", sub_matches)) => {
             patch_package_json::run(&PatchPackageJsonArgs::from_arg_matches(sub_matches)?)
         }
+        Some(("crate-graph", sub_matches)) => {
+            crate_graph::run(&CrateGraphArgs::from_arg_matches(sub_matches)?)
+        }
+        Some(("check-deps", sub_matches)) => {
+            check_deps::run(&CheckDepsArgs::from_arg_matches(sub_matches)?)
+        }
+        Some(("generate-bench-fixture", sub_matches)) => {
+            generate_bench_fixture::run(&GenerateBenchFixtureArgs::from_arg_matches(sub_matches)?)
+        }
+        Some(("publish-bench-dashboard", sub_matches)) => {
+            publish_bench_dashboard::run(&PublishBenchDashboardArgs::from_arg_matches(sub_matches)?)
+        }
         _ => {
             anyhow::bail!("Unknown command {:?}", matches.subcommand().map(|c| c.0));
         }
@@ -0,0 +1,425 @@
+use std::{
+    collections::BTreeMap,
+    env::{current_dir, temp_dir},
+    fmt::Write as _,
+    fs::{self, File},
+    io::BufReader,
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+};
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use clap::Parser;
+use walkdir::WalkDir;
+
+use crate::summarize_bench::data::{BaseBenchmarks, Benchmark};
+
+/// The directories `visualize-bundler-benchmarks` writes the charts to, next to the summary.
+const CHART_DIRS: &[&str] = &["scaling", "phases"];
+
+/// Assembles a static dashboard site from a benchmark summary and the charts generated from it,
+/// and commits it to a branch served by GitHub Pages, so benchmark results can be browsed without
+/// checking out the `benchmark-data` branch.
+///
+/// Each system gets a page of its own, listing the latest result of each benchmark and its change
+/// against a baseline summary. Pages of other systems already on the branch are kept, and the
+/// index of the site links all of them.
+#[derive(Parser)]
+#[command(name = "publish-bench-dashboard")]
+pub struct PublishBenchDashboardArgs {
+    /// The summary json file generated by `summarize-benchmarks`, e.g.
+    /// `data/ubuntu-latest-16-core.json`. The file name is used as the name of the system.
+    summary: PathBuf,
+
+    /// A summary to compare the latest results against, e.g. the summary of a commit generated
+    /// by `summarize-benchmarks`.
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// The directory containing the charts generated by `visualize-bundler-benchmarks`. Defaults
+    /// to the directory of the summary.
+    #[arg(long)]
+    visualizations: Option<PathBuf>,
+
+    /// Changes against the baseline larger than this percentage are highlighted.
+    #[arg(long, default_value_t = 5.0)]
+    threshold: f64,
+
+    /// The branch the site is committed to.
+    #[arg(long, default_value = "gh-pages")]
+    branch: String,
+
+    /// Push the branch to this remote after committing.
+    #[arg(long)]
+    push: Option<String>,
+}
+
+/// A benchmark result on the dashboard.
+struct Row {
+    name: String,
+    latest: f64,
+    baseline: Option<f64>,
+}
+
+impl Row {
+    /// The change against the baseline in percent, positive when the benchmark got slower.
+    fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?;
+        (baseline > 0.0).then(|| (self.latest - baseline) / baseline * 100.0)
+    }
+}
+
+pub fn run(args: &PublishBenchDashboardArgs) -> Result<()> {
+    let system = args
+        .summary
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .context("the summary needs a file name")?
+        .to_string();
+    let summary = read_summary(&args.summary)?;
+    let baseline = args.baseline.as_deref().map(read_summary).transpose()?;
+    let visualizations = match &args.visualizations {
+        Some(visualizations) => visualizations.clone(),
+        None => args
+            .summary
+            .parent()
+            .context("the summary needs a parent directory")?
+            .to_path_buf(),
+    };
+
+    let repo_dir = current_dir()?;
+    let site_dir = temp_dir().join(format!("bench-dashboard-{}", process::id()));
+    checkout_branch(&repo_dir, &site_dir, &args.branch)?;
+    let result = (|| {
+        write_system_page(
+            &site_dir.join(&system),
+            &system,
+            &summary,
+            baseline.as_ref(),
+            &visualizations,
+            args.threshold,
+        )?;
+        write_index(&site_dir)?;
+        // Serve the site as is, without processing it with Jekyll
+        fs::write(site_dir.join(".nojekyll"), "")?;
+        commit(
+            &site_dir,
+            &format!("Update benchmark dashboard of {system}"),
+        )
+    })();
+    let committed = result.and_then(|committed| {
+        if let (true, Some(remote)) = (committed, &args.push) {
+            git(&site_dir, &["push", remote, &args.branch])?;
+        }
+        Ok(committed)
+    });
+    git(
+        &repo_dir,
+        &["worktree", "remove", "--force", &site_dir.to_string_lossy()],
+    )?;
+
+    if committed? {
+        println!("Committed the dashboard of {system} to {}", args.branch);
+    } else {
+        println!("The dashboard of {system} is up to date");
+    }
+    Ok(())
+}
+
+fn read_summary(path: &Path) -> Result<BaseBenchmarks> {
+    let file = File::open(path).with_context(|| format!("could not read {path:?}"))?;
+    serde_json::from_reader(BufReader::new(file))
+        .with_context(|| format!("failed to parse {path:?}"))
+}
+
+/// The result of a benchmark. The slope is a better estimation than the mean when it's available,
+/// as it's the time per iteration when the number of iterations goes to infinity.
+fn estimate(bench: &Benchmark) -> f64 {
+    bench
+        .estimates
+        .slope
+        .as_ref()
+        .unwrap_or(&bench.estimates.mean)
+        .point_estimate
+}
+
+fn rows(summary: &BaseBenchmarks, baseline: Option<&BaseBenchmarks>) -> Vec<Row> {
+    // The keys of the summaries contain the time and commit of the measurement, so benchmarks
+    // are matched by their id
+    let baseline: BTreeMap<&str, f64> = baseline
+        .iter()
+        .flat_map(|baseline| baseline.benchmarks.values())
+        .map(|bench| (bench.info.full_id.as_str(), estimate(bench)))
+        .collect();
+    let mut rows = summary
+        .benchmarks
+        .values()
+        .map(|bench| Row {
+            name: bench.info.full_id.clone(),
+            latest: estimate(bench),
+            baseline: baseline.get(bench.info.full_id.as_str()).copied(),
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by(|a, b| a.name.cmp(&b.name));
+    rows
+}
+
+/// Formats a duration measured in nanoseconds.
+fn format_duration(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.2}s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.2}ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.2}µs", ns / 1e3)
+    } else {
+        format!("{ns:.0}ns")
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const STYLE: &str = "body { font-family: ui-sans-serif, system-ui, sans-serif; margin: 2rem; }
+table { border-collapse: collapse; }
+th, td { padding: 0.25rem 0.75rem; text-align: right; border-bottom: 1px solid #ddd; }
+th:first-child, td:first-child { text-align: left; }
+.regression { color: #c62828; font-weight: bold; }
+.improvement { color: #2e7d32; font-weight: bold; }
+img { max-width: 100%; }
+@media (prefers-color-scheme: dark) { body { background: #111; color: #eee; } }";
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        concat!(
+            "<!DOCTYPE html>\n",
+            "<html>\n",
+            "<head>\n",
+            "<meta charset=\"utf-8\">\n",
+            "<title>{title}</title>\n",
+            "<style>\n{style}\n</style>\n",
+            "</head>\n",
+            "<body>\n{body}</body>\n",
+            "</html>\n",
+        ),
+        title = escape_html(title),
+        style = STYLE,
+        body = body,
+    )
+}
+
+fn write_system_page(
+    dir: &Path,
+    system: &str,
+    summary: &BaseBenchmarks,
+    baseline: Option<&BaseBenchmarks>,
+    visualizations: &Path,
+    threshold: f64,
+) -> Result<()> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    fs::create_dir_all(dir)?;
+
+    let rows = rows(summary, baseline);
+    let mut body = String::new();
+    writeln!(body, "<p><a href=\"../\">All systems</a></p>")?;
+    writeln!(body, "<h1>{}</h1>", escape_html(system))?;
+    writeln!(
+        body,
+        "<p>Updated {}.</p>",
+        Utc::now().format("%Y-%m-%d %H:%M UTC")
+    )?;
+
+    let highlights = rows
+        .iter()
+        .filter(|row| row.change().is_some_and(|change| change.abs() > threshold))
+        .collect::<Vec<_>>();
+    if let Some(baseline) = baseline {
+        writeln!(
+            body,
+            "<h2>Changes against {}</h2>",
+            escape_html(&baseline.name)
+        )?;
+        if highlights.is_empty() {
+            writeln!(
+                body,
+                "<p>No benchmark changed by more than {threshold}%.</p>"
+            )?;
+        } else {
+            writeln!(body, "<ul>")?;
+            for row in highlights {
+                let change = row.change().unwrap_or_default();
+                writeln!(
+                    body,
+                    "<li class=\"{}\">{}: {:+.1}% ({} → {})</li>",
+                    if change > 0.0 {
+                        "regression"
+                    } else {
+                        "improvement"
+                    },
+                    escape_html(&row.name),
+                    change,
+                    format_duration(row.baseline.unwrap_or_default()),
+                    format_duration(row.latest),
+                )?;
+            }
+            writeln!(body, "</ul>")?;
+        }
+    }
+
+    writeln!(body, "<h2>Latest results</h2>")?;
+    writeln!(
+        body,
+        "<table>\n<tr><th>Benchmark</th><th>Latest</th><th>Baseline</th><th>Change</th></tr>"
+    )?;
+    for row in &rows {
+        let change = row.change();
+        let class = match change {
+            Some(change) if change > threshold => " class=\"regression\"",
+            Some(change) if change < -threshold => " class=\"improvement\"",
+            _ => "",
+        };
+        writeln!(
+            body,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td{class}>{}</td></tr>",
+            escape_html(&row.name),
+            format_duration(row.latest),
+            row.baseline.map(format_duration).unwrap_or_default(),
+            change
+                .map(|change| format!("{change:+.1}%"))
+                .unwrap_or_default(),
+        )?;
+    }
+    writeln!(body, "</table>")?;
+
+    for chart_dir in CHART_DIRS {
+        let charts = copy_charts(&visualizations.join(chart_dir), &dir.join(chart_dir))?;
+        if charts.is_empty() {
+            continue;
+        }
+        writeln!(body, "<h2>{}</h2>", escape_html(chart_dir))?;
+        for chart in charts {
+            writeln!(
+                body,
+                "<p><img src=\"{chart_dir}/{}\" alt=\"{}\"></p>",
+                escape_html(&chart),
+                escape_html(&chart)
+            )?;
+        }
+    }
+
+    fs::write(
+        dir.join("summary.json"),
+        serde_json::to_string_pretty(summary)?,
+    )?;
+    fs::write(dir.join("index.html"), page(system, &body))?;
+    Ok(())
+}
+
+/// Copies the light theme charts of a directory generated by `visualize-bundler-benchmarks`,
+/// returning their file names.
+fn copy_charts(from: &Path, to: &Path) -> Result<Vec<String>> {
+    if !from.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut charts = vec![];
+    for entry in WalkDir::new(from)
+        .max_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(Result::ok)
+    {
+        let Some(name) = entry.file_name().to_str() else {
+            continue;
+        };
+        if !name.ends_with("_light.svg") {
+            continue;
+        }
+        fs::create_dir_all(to)?;
+        fs::copy(entry.path(), to.join(name))?;
+        charts.push(name.to_string());
+    }
+    Ok(charts)
+}
+
+/// Writes the index of the site, linking the pages of all systems on the branch.
+fn write_index(site_dir: &Path) -> Result<()> {
+    let mut systems = vec![];
+    for entry in fs::read_dir(site_dir)? {
+        let entry = entry?;
+        if entry.path().join("index.html").is_file() {
+            systems.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    systems.sort();
+
+    let mut body = String::new();
+    writeln!(body, "<h1>Benchmarks</h1>\n<ul>")?;
+    for system in systems {
+        writeln!(
+            body,
+            "<li><a href=\"{0}/\">{0}</a></li>",
+            escape_html(&system)
+        )?;
+    }
+    writeln!(body, "</ul>")?;
+    fs::write(site_dir.join("index.html"), page("Benchmarks", &body))?;
+    Ok(())
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<()> {
+    let status = process::Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .context("Running git failed")?;
+    if !status.success() {
+        bail!("git {} exited with {status}", args.join(" "));
+    }
+    Ok(())
+}
+
+/// Checks out `branch` into a worktree at `dir`, creating it as an empty orphan branch when it
+/// doesn't exist yet.
+fn checkout_branch(repo_dir: &Path, dir: &Path, branch: &str) -> Result<()> {
+    let dir = dir.to_string_lossy();
+    let exists = process::Command::new("git")
+        .args(["rev-parse", "--verify", "--quiet", branch])
+        .current_dir(repo_dir)
+        .stdout(Stdio::null())
+        .status()
+        .context("Running git failed")?
+        .success();
+    if exists {
+        return git(repo_dir, &["worktree", "add", &dir, branch]);
+    }
+
+    git(repo_dir, &["worktree", "add", "--detach", &dir])?;
+    let dir = Path::new(&*dir);
+    git(dir, &["checkout", "--orphan", branch])?;
+    git(dir, &["rm", "-r", "--quiet", "--force", "."])
+}
+
+/// Commits all changes of the worktree, returning whether there were any.
+fn commit(dir: &Path, message: &str) -> Result<bool> {
+    git(dir, &["add", "--all"])?;
+    let unchanged = process::Command::new("git")
+        .args(["diff", "--cached", "--quiet"])
+        .current_dir(dir)
+        .status()
+        .context("Running git failed")?
+        .success();
+    if unchanged {
+        return Ok(false);
+    }
+    git(dir, &["commit", "--quiet", "--message", message])?;
+    Ok(true)
+}
@@ -1,18 +1,21 @@
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     fs::{self, File},
     io::BufReader,
     path::PathBuf,
     str::FromStr,
 };
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use num_format::{Locale, ToFormattedString};
 use plotters::{
     backend::SVGBackend,
     data::fitting_range,
-    prelude::{BindKeyPoints, ChartBuilder, IntoDrawingArea, PathElement, SeriesLabelPosition},
-    series::LineSeries,
+    prelude::{
+        BindKeyPoints, ChartBuilder, IntoDrawingArea, IntoSegmentedCoord, PathElement, Rectangle,
+        SegmentValue, SeriesLabelPosition,
+    },
+    series::{AreaSeries, LineSeries},
     style::{Color, RGBAColor, RGBColor},
 };
 
@@ -22,6 +25,31 @@ type ByModuleCount = BTreeMap<u32, CStats>;
 type ByBundler = BTreeMap<Bundler, ByModuleCount>;
 type ByBench = BTreeMap<String, ByBundler>;
 
+/// A kind of chart generated by [generate].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chart {
+    /// A line per bundler, for each benchmark.
+    Scaling,
+    /// A stacked area per benchmark (measurement phase), for each bundler.
+    StackedArea,
+    /// A stacked bar per module count, split by benchmark (measurement phase),
+    /// for each bundler.
+    StackedBar,
+}
+
+impl FromStr for Chart {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "scaling" => Ok(Self::Scaling),
+            "stacked-area" => Ok(Self::StackedArea),
+            "stacked-bar" => Ok(Self::StackedBar),
+            _ => bail!("unknown chart {s:?}, expected one of: scaling, stacked-area, stacked-bar"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 enum Bundler {
     NextJs11Ssr,
@@ -117,7 +145,11 @@ impl Bundler {
     }
 }
 
-pub fn generate(summary_path: PathBuf, filter_bundlers: Option<HashSet<&str>>) -> Result<()> {
+pub fn generate(
+    summary_path: PathBuf,
+    filter_bundlers: Option<HashSet<&str>>,
+    charts: &[Chart],
+) -> Result<()> {
     let summary_file = File::open(&summary_path)?;
     let reader = BufReader::new(summary_file);
     let summary: BaseBenchmarks = serde_json::from_reader(reader)?;
@@ -165,7 +197,14 @@ pub fn generate(summary_path: PathBuf, filter_bundlers: Option<HashSet<&str>>) -
     }
 
     let output_path = summary_path.parent().context("summary_path needs parent")?;
-    generate_scaling(output_path.join("scaling"), &by_bench)?;
+    for chart in charts {
+        match chart {
+            Chart::Scaling => generate_scaling(output_path.join("scaling"), &by_bench)?,
+            Chart::StackedArea | Chart::StackedBar => {
+                generate_phases(output_path.join("phases"), &by_bench, *chart)?
+            }
+        }
+    }
 
     Ok(())
 }
@@ -255,6 +294,19 @@ impl Theme {
 
 const THEMES: [Theme; 2] = [Theme::Light, Theme::Dark];
 
+// This is the font used by the turbo.build website.
+const FONT: &str = r#"ui-sans-serif, system-ui, -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Helvetica Neue", Arial, "Noto Sans", sans-serif, "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Noto Color Emoji""#;
+
+/// Colors of the measurement phases, in the order in which they are stacked.
+const PHASE_COLORS: [RGBColor; 6] = [
+    plotters::style::full_palette::BLUE,
+    plotters::style::full_palette::ORANGE,
+    plotters::style::full_palette::GREEN,
+    plotters::style::full_palette::PURPLE,
+    plotters::style::full_palette::TEAL,
+    plotters::style::full_palette::PINK,
+];
+
 fn generate_scaling(output_path: PathBuf, by_bench: &ByBench) -> Result<()> {
     fs::create_dir_all(&output_path)?;
 
@@ -313,18 +365,15 @@ fn generate_scaling(output_path: PathBuf, by_bench: &ByBench) -> Result<()> {
                     });
             }
 
-            // This is the font used by the turbo.build website.
-            let font = r#"ui-sans-serif, system-ui, -apple-system, BlinkMacSystemFont, "Segoe UI", Roboto, "Helvetica Neue", Arial, "Noto Sans", sans-serif, "Apple Color Emoji", "Segoe UI Emoji", "Segoe UI Symbol", "Noto Color Emoji""#;
-
             chart
                 .configure_mesh()
                 .x_labels(10)
                 .y_labels(10)
                 .x_desc("Number of modules")
                 .y_desc("Mean time — lower is better")
-                .x_label_style((font, 20, &theme.label_color()))
-                .y_label_style((font, 20, &theme.label_color()))
-                .axis_desc_style((font, 24, &theme.axis_desc_color()))
+                .x_label_style((FONT, 20, &theme.label_color()))
+                .y_label_style((FONT, 20, &theme.label_color()))
+                .axis_desc_style((FONT, 24, &theme.axis_desc_color()))
                 .x_label_formatter(&|v| v.to_formatted_string(&Locale::en))
                 .y_label_formatter(&|v| format_time_style.format(*v))
                 .bold_line_style(theme.bold_line_color())
@@ -336,7 +385,7 @@ fn generate_scaling(output_path: PathBuf, by_bench: &ByBench) -> Result<()> {
                 .configure_series_labels()
                 .background_style(theme.legend_background_color())
                 .border_style(theme.bold_line_color())
-                .label_font((font, 20, &theme.axis_desc_color()))
+                .label_font((FONT, 20, &theme.axis_desc_color()))
                 .position(SeriesLabelPosition::UpperLeft)
                 .margin(16)
                 .draw()?;
@@ -347,3 +396,231 @@ fn generate_scaling(output_path: PathBuf, by_bench: &ByBench) -> Result<()> {
 
     Ok(())
 }
+
+/// The time of each measurement phase (benchmark) by module count, for a
+/// single bundler, in the order in which the phases are stacked.
+type ByPhase<'a> = Vec<(&'a str, &'a ByModuleCount)>;
+
+/// The order in which the measurement phases are stacked. Unknown phases are
+/// stacked last.
+const PHASES: [&str; 6] = [
+    "startup",
+    "hydration",
+    "hmr_to_eval",
+    "hmr_to_commit",
+    "startup_cached",
+    "hydration_cached",
+];
+
+fn generate_phases(output_path: PathBuf, by_bench: &ByBench, kind: Chart) -> Result<()> {
+    fs::create_dir_all(&output_path)?;
+
+    let mut by_bundler: BTreeMap<Bundler, ByPhase> = BTreeMap::new();
+    for (bench_name, bundlers) in by_bench {
+        for (bundler, by_module_count) in bundlers {
+            by_bundler.entry(*bundler).or_default().push((
+                bench_name.strip_prefix("bench_").unwrap_or(bench_name),
+                by_module_count,
+            ));
+        }
+    }
+
+    for by_phase in by_bundler.values_mut() {
+        by_phase.sort_by_key(|(phase, _)| {
+            PHASES
+                .iter()
+                .position(|known| known == phase)
+                .unwrap_or(PHASES.len())
+        });
+    }
+
+    let suffix = match kind {
+        Chart::StackedArea => "stacked_area",
+        Chart::StackedBar => "stacked_bar",
+        Chart::Scaling => unreachable!("scaling charts don't show phases"),
+    };
+
+    for theme in THEMES {
+        for (bundler, by_phase) in &by_bundler {
+            let module_counts: Vec<u32> = by_phase
+                .values()
+                .flat_map(|by_module_count| by_module_count.keys())
+                .copied()
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect();
+
+            // The upper bound of each phase, i.e. the sum of its time and the
+            // times of the phases below it, for every module count.
+            let mut stacked: Vec<(&str, Vec<f64>)> = Vec::with_capacity(by_phase.len());
+            let mut totals = vec![0.0; module_counts.len()];
+            for &(phase, by_module_count) in by_phase {
+                for (total, module_count) in totals.iter_mut().zip(&module_counts) {
+                    *total += by_module_count
+                        .get(module_count)
+                        .map_or(0.0, |stats| stats.point_estimate);
+                }
+                stacked.push((phase, totals.clone()));
+            }
+
+            // Make the time range end 5% higher than the maximum total so the
+            // highest point is not cut off.
+            let time_range_end = totals
+                .iter()
+                .fold(0.0, |max, &time| if time > max { time } else { max })
+                * 1.05;
+            let time_range = 0.0..time_range_end;
+            let format_time_style = if time_range.end > 10e8 {
+                FormatTimeStyle::Seconds
+            } else {
+                FormatTimeStyle::Milliseconds
+            };
+
+            let file_name = output_path.join(format!(
+                "{}_{}_{}.svg",
+                bundler_file_name(*bundler),
+                suffix,
+                theme.name()
+            ));
+            let root = SVGBackend::new(&file_name, (960, 720)).into_drawing_area();
+            let mut builder = ChartBuilder::on(&root);
+            builder
+                .caption(bundler.as_str(), (FONT, 28, &theme.axis_desc_color()))
+                .x_label_area_size(60)
+                // The y labels are horizontal and have units, so they take some room.
+                .y_label_area_size(80)
+                .margin(30);
+
+            match kind {
+                Chart::StackedArea => {
+                    let module_count_range =
+                        fitting_range(module_counts.iter()).with_key_points(module_counts.clone());
+                    let mut chart = builder.build_cartesian_2d(module_count_range, time_range)?;
+
+                    // Draw the topmost phase first, so that the phases below
+                    // are drawn over it.
+                    for (index, (phase, upper)) in stacked.iter().enumerate().rev() {
+                        let color = PHASE_COLORS[index % PHASE_COLORS.len()];
+                        let points = module_counts.iter().copied().zip(upper.iter().copied());
+                        chart
+                            .draw_series(
+                                AreaSeries::new(points, 0.0, color.mix(0.8))
+                                    .border_style(color.stroke_width(2)),
+                            )?
+                            .label(*phase)
+                            .legend(move |(x, y)| {
+                                Rectangle::new([(x, y - 6), (x + 20, y + 6)], color.filled())
+                            });
+                    }
+
+                    chart
+                        .configure_mesh()
+                        .x_labels(10)
+                        .y_labels(10)
+                        .x_desc("Number of modules")
+                        .y_desc("Mean time — lower is better")
+                        .x_label_style((FONT, 20, &theme.label_color()))
+                        .y_label_style((FONT, 20, &theme.label_color()))
+                        .axis_desc_style((FONT, 24, &theme.axis_desc_color()))
+                        .x_label_formatter(&|v| v.to_formatted_string(&Locale::en))
+                        .y_label_formatter(&|v| format_time_style.format(*v))
+                        .bold_line_style(theme.bold_line_color())
+                        .light_line_style(theme.light_line_color())
+                        .axis_style(theme.axis_line_color())
+                        .draw()?;
+
+                    chart
+                        .configure_series_labels()
+                        .background_style(theme.legend_background_color())
+                        .border_style(theme.bold_line_color())
+                        .label_font((FONT, 20, &theme.axis_desc_color()))
+                        .position(SeriesLabelPosition::UpperLeft)
+                        .margin(16)
+                        .draw()?;
+                }
+                Chart::StackedBar => {
+                    // Bars are evenly spaced, so the module counts are
+                    // indexed instead of being plotted on a linear axis.
+                    let mut chart = builder.build_cartesian_2d(
+                        (0..module_counts.len()).into_segmented(),
+                        time_range,
+                    )?;
+
+                    for (index, (phase, upper)) in stacked.iter().enumerate() {
+                        let color = PHASE_COLORS[index % PHASE_COLORS.len()];
+                        let lower = if index == 0 {
+                            vec![0.0; module_counts.len()]
+                        } else {
+                            stacked[index - 1].1.clone()
+                        };
+                        chart
+                            .draw_series(upper.iter().zip(lower).enumerate().map(
+                                |(column, (&upper, lower))| {
+                                    let mut bar = Rectangle::new(
+                                        [
+                                            (SegmentValue::Exact(column), lower),
+                                            (SegmentValue::Exact(column + 1), upper),
+                                        ],
+                                        color.filled(),
+                                    );
+                                    bar.set_margin(0, 0, 12, 12);
+                                    bar
+                                },
+                            ))?
+                            .label(*phase)
+                            .legend(move |(x, y)| {
+                                Rectangle::new([(x, y - 6), (x + 20, y + 6)], color.filled())
+                            });
+                    }
+
+                    chart
+                        .configure_mesh()
+                        .disable_x_mesh()
+                        .y_labels(10)
+                        .x_desc("Number of modules")
+                        .y_desc("Mean time — lower is better")
+                        .x_label_style((FONT, 20, &theme.label_color()))
+                        .y_label_style((FONT, 20, &theme.label_color()))
+                        .axis_desc_style((FONT, 24, &theme.axis_desc_color()))
+                        .x_label_formatter(&|v| match v {
+                            SegmentValue::CenterOf(column) => module_counts
+                                .get(*column)
+                                .map(|count| count.to_formatted_string(&Locale::en))
+                                .unwrap_or_default(),
+                            _ => String::new(),
+                        })
+                        .y_label_formatter(&|v| format_time_style.format(*v))
+                        .bold_line_style(theme.bold_line_color())
+                        .light_line_style(theme.light_line_color())
+                        .axis_style(theme.axis_line_color())
+                        .draw()?;
+
+                    chart
+                        .configure_series_labels()
+                        .background_style(theme.legend_background_color())
+                        .border_style(theme.bold_line_color())
+                        .label_font((FONT, 20, &theme.axis_desc_color()))
+                        .position(SeriesLabelPosition::UpperLeft)
+                        .margin(16)
+                        .draw()?;
+                }
+                Chart::Scaling => unreachable!(),
+            }
+
+            root.present()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A file name for the charts of `bundler`, e.g. `turbopack-ssr`.
+fn bundler_file_name(bundler: Bundler) -> String {
+    bundler
+        .as_str()
+        .to_ascii_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}